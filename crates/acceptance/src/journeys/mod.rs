@@ -43,6 +43,7 @@ pub fn all_journeys() -> Vec<Box<dyn UserJourney>> {
         Box::new(tier3::ProviderLifecycleJourney::new()),
         // ── Tier 4: Advanced ─────────────────────────────────────────────────
         Box::new(tier4::FunctionLifecycleJourney::new()),
+        Box::new(tier4::DeltaManagedCommitsJourney::new()),
         Box::new(cross_resource::LakehouseHierarchyJourney::new()),
         Box::new(cross_resource::GovernanceSetupJourney::new()),
     ]
@@ -79,6 +80,7 @@ mod tests {
         assert!(names.contains(&"recipient_lifecycle"));
         assert!(names.contains(&"provider_lifecycle"));
         assert!(names.contains(&"function_lifecycle"));
+        assert!(names.contains(&"delta_managed_commits"));
         assert!(names.contains(&"lakehouse_hierarchy"));
     }
 
@@ -0,0 +1,369 @@
+//! Delta Managed Commits Journey
+//!
+//! Drives a catalog-managed Delta table through the `/delta/v1` API the way a
+//! writer does:
+//! create catalog → create schema → createStagingTable → write `0.json` →
+//! createTable → stage + add-commit v1 → publish + set-latest-backfilled-version
+//! → loadTable.
+//!
+//! The commit coordinator is the source of truth between ratification and
+//! backfill, so this is the journey to run against `uc server --backend sqlite`
+//! to check that the SQLite coordinator serves the whole flow. The log files
+//! are written directly to the staging location, which must therefore be a
+//! `file://` path on the machine running the journey.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde_json::json;
+use unitycatalog_common::models::delta::v1::{
+    DeltaCommit, DeltaCreateStagingTableRequest, DeltaCreateTableRequest, DeltaDataSourceFormat,
+    DeltaDataType, DeltaStagingTableResponse, DeltaStructField, DeltaStructType,
+    DeltaTableRequirement, DeltaTableType, DeltaTableUpdate, DeltaUpdateTableRequest,
+};
+use url::Url;
+
+use crate::execution::{
+    ImplementationTag, JourneyContext, JourneyMetadata, JourneyState, JourneyTier, ResourceTag,
+    UserJourney,
+};
+use crate::{AcceptanceError, AcceptanceResult};
+
+/// Fixed timestamps keep the recorded request bodies stable.
+const CREATED_AT: i64 = 1_704_067_200_000;
+const COMMITTED_AT: i64 = CREATED_AT + 1_000;
+/// Staged commit file names carry a writer-chosen UUID.
+const COMMIT_UUID: &str = "00000000-0000-0000-0000-0000000000a1";
+
+pub struct DeltaManagedCommitsJourney {
+    catalog_name: String,
+    schema_name: String,
+    table_name: String,
+}
+
+impl DeltaManagedCommitsJourney {
+    pub fn new() -> Self {
+        let timestamp = chrono::Utc::now().timestamp();
+        Self {
+            catalog_name: format!("delta_cc_catalog_{}", timestamp),
+            schema_name: format!("delta_cc_schema_{}", timestamp),
+            table_name: format!("delta_cc_{}", timestamp),
+        }
+    }
+}
+
+impl Default for DeltaManagedCommitsJourney {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn journey_err(step: &str) -> impl Fn(unitycatalog_client::Error) -> AcceptanceError + '_ {
+    move |e| AcceptanceError::JourneyExecution(format!("Failed to {step}: {e}"))
+}
+
+/// The directory behind a `file://` staging location, or `None` for cloud
+/// storage (in which case the log files are not written).
+fn local_dir(location: &str) -> Option<PathBuf> {
+    Url::parse(location)
+        .ok()
+        .filter(|url| url.scheme() == "file")
+        .and_then(|url| url.to_file_path().ok())
+}
+
+fn write_file(path: PathBuf, contents: &str) -> AcceptanceResult<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, contents)?;
+    Ok(())
+}
+
+fn columns() -> DeltaStructType {
+    DeltaStructType {
+        type_tag: Default::default(),
+        fields: vec![DeltaStructField {
+            name: "id".into(),
+            data_type: DeltaDataType::Primitive("long".into()),
+            nullable: true,
+            metadata: Default::default(),
+        }],
+    }
+}
+
+/// The table properties the staging response requires, with the table id.
+fn properties(staging: &DeltaStagingTableResponse) -> BTreeMap<String, String> {
+    let mut properties: BTreeMap<String, String> = staging
+        .required_properties
+        .iter()
+        .filter_map(|(k, v)| v.clone().map(|v| (k.clone(), v)))
+        .collect();
+    properties.insert("io.unitycatalog.tableId".into(), staging.table_id.clone());
+    properties
+}
+
+/// `_delta_log/00000000000000000000.json`: commitInfo, protocol and metaData.
+fn initial_commit(staging: &DeltaStagingTableResponse, table_name: &str) -> String {
+    let protocol = &staging.required_protocol;
+    let schema_string = json!({
+        "type": "struct",
+        "fields": [{"name": "id", "type": "long", "nullable": true, "metadata": {}}],
+    });
+    [
+        json!({"commitInfo": {
+            "timestamp": CREATED_AT,
+            "inCommitTimestamp": CREATED_AT,
+            "operation": "CREATE TABLE",
+            "operationParameters": {},
+        }}),
+        json!({"protocol": {
+            "minReaderVersion": protocol.min_reader_version,
+            "minWriterVersion": protocol.min_writer_version,
+            "readerFeatures": protocol.reader_features,
+            "writerFeatures": protocol.writer_features,
+        }}),
+        json!({"metaData": {
+            "id": staging.table_id,
+            "name": table_name,
+            "format": {"provider": "parquet", "options": {}},
+            "schemaString": schema_string.to_string(),
+            "partitionColumns": [],
+            "configuration": properties(staging),
+            "createdTime": CREATED_AT,
+        }}),
+    ]
+    .map(|action| action.to_string())
+    .join("\n")
+}
+
+/// A staged commit that appends a single (not materialized) data file.
+fn data_commit() -> String {
+    [
+        json!({"commitInfo": {
+            "timestamp": COMMITTED_AT,
+            "inCommitTimestamp": COMMITTED_AT,
+            "operation": "WRITE",
+            "operationParameters": {"mode": "Append"},
+        }}),
+        json!({"add": {
+            "path": "part-00000.parquet",
+            "partitionValues": {},
+            "size": 512,
+            "modificationTime": COMMITTED_AT,
+            "dataChange": true,
+        }}),
+    ]
+    .map(|action| action.to_string())
+    .join("\n")
+}
+
+#[async_trait]
+impl UserJourney for DeltaManagedCommitsJourney {
+    fn name(&self) -> &str {
+        "delta_managed_commits"
+    }
+
+    fn description(&self) -> &str {
+        "Catalog-managed Delta commits: staging table, createTable, add-commit, backfill, loadTable"
+    }
+
+    fn metadata(&self) -> JourneyMetadata {
+        JourneyMetadata {
+            resources: vec![
+                ResourceTag::Catalogs,
+                ResourceTag::Schemas,
+                ResourceTag::Tables,
+            ],
+            // The journey writes the Delta log itself, so it needs the server's
+            // managed storage on the local filesystem.
+            implementations: vec![ImplementationTag::OssRust],
+            tier: JourneyTier::Tier4Advanced,
+            requires_external_storage: false,
+        }
+    }
+
+    fn save_state(&self) -> AcceptanceResult<JourneyState> {
+        let mut state = JourneyState::empty();
+        state.set_string("catalog_name", self.catalog_name.clone());
+        state.set_string("schema_name", self.schema_name.clone());
+        state.set_string("table_name", self.table_name.clone());
+        Ok(state)
+    }
+
+    fn load_state(&mut self, state: &JourneyState) -> AcceptanceResult<()> {
+        if let Some(v) = state.get_string("catalog_name") {
+            self.catalog_name = v;
+        }
+        if let Some(v) = state.get_string("schema_name") {
+            self.schema_name = v;
+        }
+        if let Some(v) = state.get_string("table_name") {
+            self.table_name = v;
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &JourneyContext) -> AcceptanceResult<()> {
+        let delta = ctx.client().delta_v1();
+        let (catalog, schema, table) = (
+            self.catalog_name.as_str(),
+            self.schema_name.as_str(),
+            self.table_name.as_str(),
+        );
+
+        // Step 1: Create catalog + schema
+        println!(
+            "  📁 Creating catalog '{}' and schema '{}'",
+            catalog, schema
+        );
+        ctx.client()
+            .create_catalog(catalog)
+            .with_storage_root(ctx.storage_root.clone())
+            .await
+            .map_err(journey_err("create catalog"))?;
+        ctx.client()
+            .create_schema(schema, catalog)
+            .await
+            .map_err(journey_err("create schema"))?;
+
+        // Step 2: Reserve the table and its managed location
+        let staging = delta
+            .create_staging_table(
+                catalog,
+                schema,
+                &DeltaCreateStagingTableRequest { name: table.into() },
+            )
+            .await
+            .map_err(journey_err("create staging table"))?;
+        let table_id = staging.table_id.clone();
+        println!("  ✓ Staged table {} at {}", table_id, staging.location);
+
+        // Step 3: Write the initial commit and register the table at v0
+        let log_dir = local_dir(&staging.location).map(|dir| dir.join("_delta_log"));
+        if let Some(log_dir) = &log_dir {
+            write_file(
+                log_dir.join(format!("{:020}.json", 0)),
+                &initial_commit(&staging, table),
+            )?;
+        }
+        delta
+            .create_table(
+                catalog,
+                schema,
+                &DeltaCreateTableRequest {
+                    name: table.into(),
+                    location: staging.location.clone(),
+                    table_type: DeltaTableType::Managed,
+                    data_source_format: Some(DeltaDataSourceFormat::Delta),
+                    comment: None,
+                    columns: columns(),
+                    partition_columns: None,
+                    protocol: staging.required_protocol.clone(),
+                    properties: properties(&staging),
+                    domain_metadata: None,
+                    last_commit_timestamp_ms: CREATED_AT,
+                    uniform: None,
+                },
+            )
+            .await
+            .map_err(journey_err("create table"))?;
+        println!("  ✓ Table created at version 0");
+
+        // Step 4: Stage v1 and have the catalog ratify it
+        let commit_file = format!("{:020}.{COMMIT_UUID}.json", 1);
+        let commit = data_commit();
+        if let Some(log_dir) = &log_dir {
+            write_file(log_dir.join("_staged_commits").join(&commit_file), &commit)?;
+        }
+        let ratified = delta
+            .update_table(
+                catalog,
+                schema,
+                table,
+                &DeltaUpdateTableRequest {
+                    requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                        uuid: table_id.clone(),
+                    }],
+                    updates: vec![DeltaTableUpdate::AddCommit {
+                        commit: DeltaCommit {
+                            version: 1,
+                            timestamp: COMMITTED_AT,
+                            file_name: commit_file.clone(),
+                            file_size: commit.len() as i64,
+                            file_modification_timestamp: COMMITTED_AT,
+                        },
+                        uniform: None,
+                    }],
+                },
+            )
+            .await
+            .map_err(journey_err("add commit"))?;
+        assert_eq!(ratified.latest_table_version, Some(1));
+        assert!(
+            ratified
+                .commits
+                .iter()
+                .flatten()
+                .any(|c| c.version == 1 && c.file_name == commit_file),
+            "Ratified commit missing from the commit tail"
+        );
+        println!("  ✓ Commit v1 ratified");
+
+        // Step 5: Publish v1 and record the backfill
+        if let Some(log_dir) = &log_dir {
+            write_file(log_dir.join(format!("{:020}.json", 1)), &commit)?;
+        }
+        delta
+            .update_table(
+                catalog,
+                schema,
+                table,
+                &DeltaUpdateTableRequest {
+                    requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                        uuid: table_id.clone(),
+                    }],
+                    updates: vec![DeltaTableUpdate::SetLatestBackfilledVersion {
+                        latest_published_version: 1,
+                    }],
+                },
+            )
+            .await
+            .map_err(journey_err("set latest backfilled version"))?;
+        println!("  ✓ Commit v1 backfilled");
+
+        // Step 6: Load the table back from the catalog
+        let loaded = delta
+            .load_table(catalog, schema, table)
+            .await
+            .map_err(journey_err("load table"))?;
+        assert_eq!(loaded.metadata.table_uuid, table_id);
+        assert_eq!(loaded.latest_table_version, Some(1));
+        assert!(
+            loaded.commits.iter().flatten().all(|c| c.version <= 1),
+            "Commit tail reaches past the latest version"
+        );
+        println!(
+            "  ✓ Loaded table at version 1 ({} tracked commit(s))",
+            loaded.commits.as_deref().unwrap_or_default().len()
+        );
+
+        Ok(())
+    }
+
+    async fn cleanup(&self, ctx: &JourneyContext) -> AcceptanceResult<()> {
+        let _ = ctx
+            .client()
+            .delta_v1()
+            .delete_table(&self.catalog_name, &self.schema_name, &self.table_name)
+            .await;
+        let _ = ctx
+            .client()
+            .schema(&self.catalog_name, &self.schema_name)
+            .delete()
+            .await;
+        let _ = ctx.client().catalog(&self.catalog_name).delete().await;
+
+        Ok(())
+    }
+}
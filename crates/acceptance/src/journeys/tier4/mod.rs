@@ -1,5 +1,7 @@
 //! Tier 4 journeys — advanced features (UDFs, complex workflows)
 
+mod delta_managed_commits;
 mod function_lifecycle;
 
+pub use delta_managed_commits::DeltaManagedCommitsJourney;
 pub use function_lifecycle::FunctionLifecycleJourney;
@@ -114,6 +114,25 @@ enum TableCommands {
         /// The name of the table to get
         name: String,
     },
+
+    /// Show the commit history of a Delta table, newest first
+    History {
+        /// The name of the parent catalog
+        catalog_name: String,
+        /// The name of the parent schema
+        schema_name: String,
+        /// The name of the table
+        name: String,
+        /// Oldest version to show
+        #[clap(long)]
+        start_version: Option<i64>,
+        /// Newest version to show
+        #[clap(long)]
+        end_version: Option<i64>,
+        /// Maximum number of commits to show
+        #[clap(long)]
+        max_results: Option<i32>,
+    },
 }
 
 #[derive(Debug, Args)]
@@ -250,6 +269,7 @@ async fn handle_tables(
         }) => {
             let tables = client
                 .list_tables(catalog_name, schema_name)
+                .with_include_delta_metadata(true)
                 .into_stream()
                 .try_collect::<Vec<_>>()
                 .await?;
@@ -260,9 +280,32 @@ async fn handle_tables(
             schema_name,
             name,
         }) => {
-            let table = client.table(catalog_name, schema_name, name).get().await?;
+            let table = client
+                .table(catalog_name, schema_name, name)
+                .get()
+                .with_include_delta_metadata(true)
+                .await?;
             render_one(&table, fmt)?;
         }
+        Some(TableCommands::History {
+            catalog_name,
+            schema_name,
+            name,
+            start_version,
+            end_version,
+            max_results,
+        }) => {
+            let entries = client
+                .table(catalog_name, schema_name, name)
+                .history()
+                .with_start_version(*start_version)
+                .with_end_version(*end_version)
+                .with_max_results(*max_results)
+                .into_stream()
+                .try_collect::<Vec<_>>()
+                .await?;
+            render_list(&entries, fmt)?;
+        }
         None => status::error("no subcommand provided; see `uc client tables --help`"),
     }
     Ok(())
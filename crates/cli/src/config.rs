@@ -77,6 +77,84 @@ pub struct Config {
     /// [`local_storage`](Config::local_storage) root.
     #[serde(default)]
     pub managed_storage_root: Option<String>,
+
+    /// Server-side publishing of ratified Delta commits.
+    ///
+    /// When set, the server copies the ratified commits of catalog-managed
+    /// tables to their published `_delta_log` names and records the backfill,
+    /// so a writer that never publishes cannot pin a table at the
+    /// unbackfilled-commit cap. Disabled by default.
+    #[serde(default)]
+    pub commit_backfill: Option<CommitBackfillConfig>,
+
+    /// Server-side maintenance of managed Delta tables.
+    ///
+    /// When set, the server plans compaction and vacuum jobs from the commit
+    /// reports writers send, and runs them. Disabled by default.
+    #[serde(default)]
+    pub table_maintenance: Option<TableMaintenanceConfig>,
+}
+
+/// Configuration for the background commit publisher.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct CommitBackfillConfig {
+    /// Seconds between two sweeps over all managed tables.
+    #[serde(default = "CommitBackfillConfig::default_interval_secs")]
+    pub interval_secs: u64,
+    /// Seconds a replica holds a table's lease before another may take over.
+    #[serde(default = "CommitBackfillConfig::default_lease_ttl_secs")]
+    pub lease_ttl_secs: u64,
+}
+
+impl CommitBackfillConfig {
+    fn default_interval_secs() -> u64 {
+        30
+    }
+
+    fn default_lease_ttl_secs() -> u64 {
+        60
+    }
+}
+
+/// Configuration for the background table-maintenance scheduler.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMaintenanceConfig {
+    /// Seconds between two sweeps over all managed tables.
+    #[serde(default = "TableMaintenanceConfig::default_interval_secs")]
+    pub interval_secs: u64,
+    /// Seconds a replica holds a table's lease before another may take over.
+    #[serde(default = "TableMaintenanceConfig::default_lease_ttl_secs")]
+    pub lease_ttl_secs: u64,
+    /// Hours an unreferenced data file is kept before vacuum deletes it.
+    #[serde(default = "TableMaintenanceConfig::default_vacuum_retention_hours")]
+    pub vacuum_retention_hours: u64,
+    /// Commits after which the server writes a new checkpoint.
+    #[serde(default = "TableMaintenanceConfig::default_checkpoint_interval")]
+    pub checkpoint_interval: usize,
+    /// Commits after which the server writes a log-compaction file between
+    /// checkpoints. Unset disables log compaction.
+    #[serde(default)]
+    pub log_compaction_interval: Option<usize>,
+}
+
+impl TableMaintenanceConfig {
+    fn default_interval_secs() -> u64 {
+        300
+    }
+
+    fn default_lease_ttl_secs() -> u64 {
+        600
+    }
+
+    fn default_vacuum_retention_hours() -> u64 {
+        168
+    }
+
+    fn default_checkpoint_interval() -> usize {
+        10
+    }
 }
 
 /// Configuration for local (`file://`) storage locations.
@@ -109,6 +187,8 @@ impl Default for Config {
             routing: RoutingConfig::default(),
             local_storage: LocalStorageConfig::default(),
             managed_storage_root: None,
+            commit_backfill: None,
+            table_maintenance: None,
         }
     }
 }
@@ -246,6 +326,55 @@ impl Default for Backend {
     }
 }
 
+/// Backend engine selected on the command line (`uc server --backend ...`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum BackendEngine {
+    Sqlite,
+    Postgres,
+}
+
+impl Config {
+    /// Database file used by `--backend sqlite` when neither `--database` nor
+    /// a configured SQLite path is given.
+    pub const DEFAULT_SQLITE_PATH: &str = "unitycatalog.db";
+
+    /// Apply a `--backend` / `--database` override from the command line.
+    ///
+    /// `sqlite` uses `database` if given, else keeps a configured SQLite path,
+    /// else opens [`Config::DEFAULT_SQLITE_PATH`]. Postgres needs connection
+    /// settings, so `postgres` only confirms a configured Postgres backend.
+    pub fn override_backend(
+        &mut self,
+        engine: BackendEngine,
+        database: Option<String>,
+    ) -> Result<(), String> {
+        match engine {
+            BackendEngine::Sqlite => {
+                let path = match (database, &self.backend) {
+                    (Some(path), _) => path,
+                    (None, Backend::Sqlite(_)) => return Ok(()),
+                    (None, Backend::Postgres(_)) => Self::DEFAULT_SQLITE_PATH.to_string(),
+                };
+                self.backend = Backend::Sqlite(SqliteBackendConfig {
+                    path: ConfigValue::Value(path),
+                });
+            }
+            BackendEngine::Postgres => {
+                if database.is_some() {
+                    return Err("`--database` only applies to the sqlite backend".to_string());
+                }
+                if !matches!(self.backend, Backend::Postgres(_)) {
+                    return Err(
+                        "`--backend postgres` requires a postgres `backend` section in the config"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// SQLite backend configuration.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SqliteBackendConfig {
@@ -461,6 +590,52 @@ mod tests {
         assert!(!config.routing.any_upstream());
     }
 
+    #[test]
+    fn test_commit_backfill_config() {
+        let config: Config = serde_yml::from_str("{}").unwrap();
+        assert!(config.commit_backfill.is_none());
+
+        let config: Config = serde_yml::from_str(
+            r#"
+            commit_backfill:
+              interval-secs: 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.commit_backfill,
+            Some(CommitBackfillConfig {
+                interval_secs: 5,
+                lease_ttl_secs: 60,
+            })
+        );
+    }
+
+    #[test]
+    fn test_table_maintenance_config() {
+        let config: Config = serde_yml::from_str("{}").unwrap();
+        assert!(config.table_maintenance.is_none());
+
+        let config: Config = serde_yml::from_str(
+            r#"
+            table_maintenance:
+              vacuum-retention-hours: 24
+              log-compaction-interval: 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.table_maintenance,
+            Some(TableMaintenanceConfig {
+                interval_secs: 300,
+                lease_ttl_secs: 600,
+                vacuum_retention_hours: 24,
+                checkpoint_interval: 10,
+                log_compaction_interval: Some(5),
+            })
+        );
+    }
+
     #[test]
     fn test_encryption_config_builds_encryptor() {
         use base64::Engine as _;
@@ -586,4 +761,48 @@ mod tests {
             vec!["functions", "shares"]
         );
     }
+
+    #[test]
+    fn test_override_backend() {
+        let mut config = Config::default();
+        config
+            .override_backend(BackendEngine::Sqlite, Some("/tmp/uc.db".to_string()))
+            .unwrap();
+        match &config.backend {
+            Backend::Sqlite(b) => assert_eq!(b.database_path().as_deref(), Some("/tmp/uc.db")),
+            other => panic!("expected sqlite backend, got {other:?}"),
+        }
+        assert!(
+            config
+                .override_backend(BackendEngine::Postgres, None)
+                .is_err()
+        );
+
+        let mut config: Config = serde_yml::from_str(
+            r#"
+            backend:
+              engine: postgres
+              host: localhost
+              port: "5432"
+              user: user
+              password: password
+              database: postgres
+            "#,
+        )
+        .unwrap();
+        config
+            .override_backend(BackendEngine::Postgres, None)
+            .unwrap();
+        assert!(matches!(config.backend, Backend::Postgres(_)));
+        config
+            .override_backend(BackendEngine::Sqlite, None)
+            .unwrap();
+        match &config.backend {
+            Backend::Sqlite(b) => assert_eq!(
+                b.database_path().as_deref(),
+                Some(Config::DEFAULT_SQLITE_PATH)
+            ),
+            other => panic!("expected sqlite backend, got {other:?}"),
+        }
+    }
 }
@@ -9,6 +9,9 @@ pub enum Error {
     #[error("Client error: {0}")]
     Client(#[from] unitycatalog_client::Error),
 
+    #[error("Sharing error: {0}")]
+    Sharing(#[from] unitycatalog_sharing_client::Error),
+
     #[error("Server error: {0}")]
     Server(#[from] unitycatalog_server::Error),
 
@@ -297,6 +297,7 @@ impl App {
         tokio::spawn(async move {
             let tables = client
                 .list_tables(&catalog, &schema)
+                .with_include_delta_metadata(true)
                 .into_stream()
                 .try_collect::<Vec<_>>()
                 .await;
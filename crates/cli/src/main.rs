@@ -6,6 +6,7 @@ use crate::error::{Error, Result};
 use crate::explore::{ExploreCommand, handle_explore};
 use crate::render::OutputFormat;
 use crate::server::{ServerArgs, handle_server};
+use crate::sharing::{SharingCommand, handle_sharing};
 
 /// REST path prefix under which the Unity Catalog 2.1 API is served. The client
 /// resolves resource paths relative to its base URL, so the base must include
@@ -18,6 +19,7 @@ mod error;
 mod explore;
 mod render;
 mod server;
+mod sharing;
 // mod test;
 
 #[derive(Parser)]
@@ -83,6 +85,12 @@ enum Commands {
     )]
     Client(ClientCommand),
 
+    #[clap(
+        arg_required_else_help = true,
+        about = "browse and preview tables shared via a Delta Sharing profile"
+    )]
+    Sharing(SharingCommand),
+
     #[clap(about = "interactively browse the catalog hierarchy in a TUI")]
     Explore(ExploreCommand),
 
@@ -104,6 +112,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Client(client_args) => {
             handle_client(client_args, args.global_opts).await?;
         }
+        Commands::Sharing(cmd) => {
+            handle_sharing(cmd, args.global_opts).await?;
+        }
         Commands::Explore(cmd) => {
             handle_explore(cmd, args.global_opts).await?;
         }
@@ -12,9 +12,14 @@ use std::io::IsTerminal;
 
 use comfy_table::{Cell, Color, ContentArrangement, Table, presets::UTF8_FULL};
 use console::{Emoji, style};
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::json::ArrayWriter;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::arrow::util::pretty::pretty_format_batches;
 use serde::Serialize;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 /// User-selectable output format (the `--output/-o` flag).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
@@ -95,6 +100,60 @@ where
     Ok(())
 }
 
+/// Render record batches (e.g. a shared table preview) in the resolved format.
+pub fn render_batches(batches: &[RecordBatch], fmt: ResolvedFormat) -> Result<()> {
+    let arrow_error = |e: ArrowError| Error::Generic(e.to_string());
+    match fmt {
+        ResolvedFormat::Json => {
+            let mut writer = ArrayWriter::new(Vec::new());
+            writer
+                .write_batches(&batches.iter().collect::<Vec<_>>())
+                .map_err(arrow_error)?;
+            writer.finish().map_err(arrow_error)?;
+            let rows: serde_json::Value = serde_json::from_slice(&writer.into_inner())?;
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+        ResolvedFormat::Table => {
+            if batches.iter().all(|b| b.num_rows() == 0) {
+                status::info("No results");
+            } else {
+                println!("{}", pretty_format_batches(batches).map_err(arrow_error)?);
+            }
+        }
+        ResolvedFormat::Plain => {
+            let Some(schema) = batches.first().map(|b| b.schema()) else {
+                return Ok(());
+            };
+            println!(
+                "{}",
+                schema
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().as_str())
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            );
+            let options = FormatOptions::default();
+            for batch in batches {
+                let formatters = batch
+                    .columns()
+                    .iter()
+                    .map(|c| ArrayFormatter::try_new(c.as_ref(), &options))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(arrow_error)?;
+                for row in 0..batch.num_rows() {
+                    let values: Vec<_> = formatters
+                        .iter()
+                        .map(|f| f.value(row).to_string())
+                        .collect();
+                    println!("{}", values.join("\t"));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 fn print_table(headers: Vec<&'static str>, rows: impl Iterator<Item = Vec<String>>) {
     let mut table = Table::new();
     table
@@ -215,7 +274,17 @@ impl TableView for unitycatalog_common::Schema {
 
 impl TableView for unitycatalog_common::Table {
     fn headers() -> Vec<&'static str> {
-        vec!["Name", "Full Name", "Type", "Format", "Columns"]
+        vec![
+            "Name",
+            "Full Name",
+            "Type",
+            "Format",
+            "Columns",
+            "Version",
+            "Size",
+            "Files",
+            "Rows",
+        ]
     }
 
     fn row(&self) -> Vec<String> {
@@ -226,12 +295,60 @@ impl TableView for unitycatalog_common::Table {
         let format = DataSourceFormat::try_from(self.data_source_format)
             .map(|f| f.as_str_name().to_string())
             .unwrap_or_else(|_| NONE.into());
-        vec![
+        let mut row = vec![
             self.name.clone(),
             self.full_name.clone(),
             table_type,
             format,
             self.columns.len().to_string(),
+        ];
+        match &self.statistics {
+            Some(stats) => row.extend([
+                stats.version.to_string(),
+                format_bytes(stats.size_in_bytes),
+                stats.num_files.to_string(),
+                stats
+                    .num_rows
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| NONE.into()),
+            ]),
+            None => row.extend(std::iter::repeat_n(NONE.to_string(), 4)),
+        }
+        row
+    }
+}
+
+/// Render a byte count with a binary unit, e.g. `1.5 MiB`.
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+impl TableView for unitycatalog_common::models::table_history::v1::TableHistoryEntry {
+    fn headers() -> Vec<&'static str> {
+        vec!["Version", "Timestamp", "Operation", "Engine", "Published"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        let timestamp = chrono::DateTime::from_timestamp_millis(self.timestamp)
+            .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+            .unwrap_or_else(|| self.timestamp.to_string());
+        vec![
+            self.version.to_string(),
+            timestamp,
+            self.operation.clone().unwrap_or_else(|| NONE.into()),
+            self.engine_info.clone().unwrap_or_else(|| NONE.into()),
+            if self.published { "yes" } else { "no" }.into(),
         ]
     }
 }
@@ -269,3 +386,42 @@ impl TableView for unitycatalog_common::Function {
         ]
     }
 }
+
+impl TableView for unitycatalog_sharing_client::models::open_sharing::v1::Share {
+    fn headers() -> Vec<&'static str> {
+        vec!["Name", "ID", "Comment"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.id.clone().unwrap_or_else(|| NONE.into()),
+            self.comment.clone().unwrap_or_else(|| NONE.into()),
+        ]
+    }
+}
+
+impl TableView for unitycatalog_sharing_client::models::open_sharing::v1::Schema {
+    fn headers() -> Vec<&'static str> {
+        vec!["Name", "Share"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![self.name.clone(), self.share.clone()]
+    }
+}
+
+impl TableView for unitycatalog_sharing_client::models::open_sharing::v1::Table {
+    fn headers() -> Vec<&'static str> {
+        vec!["Name", "Schema", "Share", "Full Name"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.schema.clone(),
+            self.share.clone(),
+            format!("{}.{}.{}", self.share, self.schema, self.name),
+        ]
+    }
+}
@@ -19,9 +19,10 @@ use unitycatalog_server::policy::Policy;
 use unitycatalog_server::rest::{
     AuthenticationLayer, Authenticator, create_catalogs_router, create_commits_router,
     create_credentials_router, create_delta_router, create_entity_tag_assignments_router,
-    create_external_locations_router, create_functions_router, create_open_sharing_router,
-    create_providers_router, create_recipients_router, create_schemas_router, create_shares_router,
-    create_sharing_router, create_staging_tables_router, create_tables_router,
+    create_external_locations_router, create_functions_router, create_iceberg_router,
+    create_open_sharing_router, create_providers_router, create_recipients_router,
+    create_schemas_router, create_shares_router, create_sharing_router,
+    create_staging_tables_router, create_table_history_router, create_tables_router,
     create_tag_policies_router, create_temporary_credentials_router,
 };
 use unitycatalog_server::services::ServerHandler;
@@ -112,6 +113,7 @@ where
     let api_routes = catalogs
         .merge(schemas)
         .merge(tables)
+        .merge(create_table_history_router(handler.clone()))
         .merge(create_staging_tables_router(handler.clone()))
         .merge(create_credentials_router(handler.clone()))
         .merge(create_external_locations_router(handler.clone()))
@@ -122,6 +124,7 @@ where
         .merge(create_shares_router(handler.clone()))
         .merge(create_commits_router(handler.clone()))
         .merge(create_delta_router(handler.clone()))
+        .merge(create_iceberg_router(handler.clone()))
         .merge(create_entity_tag_assignments_router(handler.clone()));
 
     let router = Router::new()
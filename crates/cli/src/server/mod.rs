@@ -1,5 +1,6 @@
 use std::io::IsTerminal;
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use clap::Parser;
 use comfy_table::{Cell, ContentArrangement, Table, presets::UTF8_FULL};
@@ -10,11 +11,14 @@ use unitycatalog_server::api::RequestContext;
 use unitycatalog_server::policy::{ConstantPolicy, Policy};
 use unitycatalog_server::{
     rest::AnonymousAuthenticator,
-    services::{LocalStoragePolicy, ServerHandler, location::StorageLocationUrl},
+    services::{
+        LocalStoragePolicy, ServerHandler, backfill::BackfillConfig, location::StorageLocationUrl,
+        maintenance::MaintenanceConfig,
+    },
 };
 use unitycatalog_sqlite::SqliteStore;
 
-use crate::config::{Backend, Config, PostgresBackendConfig, SqliteBackendConfig};
+use crate::config::{Backend, BackendEngine, Config, PostgresBackendConfig, SqliteBackendConfig};
 use crate::error::{Error, Result};
 use unitycatalog_common::services::encryption::EnvelopeEncryptor;
 
@@ -44,6 +48,16 @@ pub struct ServerArgs {
     #[arg(short, long, default_value = "config.yaml")]
     config: String,
 
+    #[clap(long, value_enum, help = "override the backend engine from the config")]
+    backend: Option<BackendEngine>,
+
+    #[clap(
+        long,
+        requires = "backend",
+        help = "database file for `--backend sqlite`"
+    )]
+    database: Option<String>,
+
     #[clap(long, help = "expose rest API", default_value_t = true)]
     rest: bool,
 
@@ -90,7 +104,12 @@ async fn handle_rest(args: &ServerArgs) -> Result<()> {
         println!("{}", WELCOME.as_str());
     }
 
-    let config = load_config(&args.config)?;
+    let mut config = load_config(&args.config)?;
+    if let Some(engine) = args.backend {
+        config
+            .override_backend(engine, args.database.clone())
+            .map_err(Error::Generic)?;
+    }
 
     let host = args
         .host
@@ -144,6 +163,29 @@ async fn handle_rest(args: &ServerArgs) -> Result<()> {
         .with_local_storage_policy(local_storage_policy)
         .with_managed_storage_root(config.managed_storage_root.clone());
 
+    if let Some(backfill) = &config.commit_backfill {
+        handler
+            .backfill_publisher(BackfillConfig {
+                interval: Duration::from_secs(backfill.interval_secs),
+                lease_ttl: Duration::from_secs(backfill.lease_ttl_secs),
+                ..Default::default()
+            })
+            .spawn();
+    }
+
+    if let Some(maintenance) = &config.table_maintenance {
+        handler
+            .maintenance_scheduler(MaintenanceConfig {
+                interval: Duration::from_secs(maintenance.interval_secs),
+                lease_ttl: Duration::from_secs(maintenance.lease_ttl_secs),
+                vacuum_retention: Duration::from_secs(maintenance.vacuum_retention_hours * 3600),
+                checkpoint_interval: maintenance.checkpoint_interval,
+                log_compaction_interval: maintenance.log_compaction_interval,
+                ..Default::default()
+            })
+            .spawn();
+    }
+
     if config.routing.any_upstream() {
         let unsupported = config.routing.unsupported_upstream();
         if !unsupported.is_empty() {
@@ -196,8 +238,8 @@ fn print_startup_summary(host: &str, port: u16, config: &Config) {
     let backend = match &config.backend {
         Backend::Postgres(_) => "postgres".to_string(),
         Backend::Sqlite(cfg) => match cfg.database_path().as_deref() {
-            Some(":memory:") => "sqlite (:memory:)".to_string(),
-            _ => "sqlite".to_string(),
+            Some(path) => format!("sqlite ({path})"),
+            None => "sqlite".to_string(),
         },
     };
 
@@ -249,14 +291,16 @@ async fn get_db_handler(
         .migrate()
         .await
         .map_err(|e| Error::Generic(format!("running migrations: {e}")))?;
-    // The Postgres store also implements `CommitCoordinator`, so Delta
-    // catalog-managed commits are persisted in the database rather than memory.
+    // The Postgres store also implements `CommitCoordinator` and
+    // `MaintenanceStore`, so Delta catalog-managed commits, commit reports and
+    // maintenance jobs are persisted in the database rather than memory.
     let handler = ServerHandler::try_new_tokio_with_coordinator(
         policy.clone(),
         store.clone(),
         store.clone(),
-        store,
-    )?;
+        store.clone(),
+    )?
+    .with_maintenance_store(store);
     Ok((handler, policy))
 }
 
@@ -280,18 +324,20 @@ async fn get_sqlite_handler(
 
     let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
     // `SqliteStore` implements the generic object/association stores (lifted to
-    // `ResourceStore` by `ObjectStoreAdapter`), `SecretManager`, and
-    // `CommitCoordinator`, but the adapter does not forward the latter two — so
-    // those roles are wired from the same shared store separately. Like the
-    // Postgres backend, Delta catalog-managed commits are persisted in the
-    // database rather than in memory.
+    // `ResourceStore` by `ObjectStoreAdapter`), `SecretManager`,
+    // `CommitCoordinator` and `MaintenanceStore`, but the adapter does not
+    // forward the latter three — so those roles are wired from the same shared
+    // store separately. Like the Postgres backend, Delta catalog-managed
+    // commits, backfill leases, commit reports and maintenance jobs are
+    // persisted in the database rather than in memory.
     let resource_store = Arc::new(ObjectStoreAdapter::new(store.clone()));
     let handler = ServerHandler::try_new_tokio_with_coordinator(
         policy.clone(),
         resource_store,
         store.clone(),
-        store,
-    )?;
+        store.clone(),
+    )?
+    .with_maintenance_store(store);
     Ok((handler, policy))
 }
 
@@ -308,3 +354,267 @@ static WELCOME: LazyLock<String> = LazyLock::new(|| {
         env!("CARGO_PKG_VERSION")
     )
 });
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use unitycatalog_common::models::catalogs::v1::CreateCatalogRequest;
+    use unitycatalog_common::models::credentials::v1::{
+        AwsIamRoleConfig, CreateCredentialRequest, Purpose,
+    };
+    use unitycatalog_common::models::delta::v1::{
+        DeltaCommit, DeltaCreateTableRequest, DeltaDataSourceFormat, DeltaDataType, DeltaProtocol,
+        DeltaStructField, DeltaStructType, DeltaTableRequirement, DeltaTableType, DeltaTableUpdate,
+        DeltaUpdateTableRequest,
+    };
+    use unitycatalog_common::models::external_locations::v1::CreateExternalLocationRequest;
+    use unitycatalog_common::models::schemas::v1::CreateSchemaRequest;
+    use unitycatalog_common::models::staging_tables::v1::CreateStagingTableRequest;
+    use unitycatalog_common::services::encryption::LocalKeyProvider;
+    use unitycatalog_server::api::delta::{SchemaPath, TablePath};
+    use unitycatalog_server::api::{
+        CatalogHandler, CredentialHandler, DeltaApiHandler, ExternalLocationHandler, SchemaHandler,
+        StagingTableHandler,
+    };
+    use unitycatalog_server::policy::Principal;
+
+    use super::*;
+    use crate::config::ConfigValue;
+
+    /// A temp-file SQLite database that is removed on drop.
+    struct TempDb(std::path::PathBuf);
+
+    impl TempDb {
+        fn new() -> Self {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos();
+            Self(
+                std::env::temp_dir()
+                    .join(format!("uc-cli-server-{}-{nanos}.db", std::process::id())),
+            )
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            for suffix in ["", "-wal", "-shm"] {
+                let _ = std::fs::remove_file(format!("{}{suffix}", self.0.display()));
+            }
+        }
+    }
+
+    async fn start(db: &TempDb) -> ServerHandler<RequestContext> {
+        let cfg = SqliteBackendConfig {
+            path: ConfigValue::Value(db.0.to_string_lossy().into_owned()),
+        };
+        let encryptor =
+            EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+        get_sqlite_handler(&cfg, encryptor).await.unwrap().0
+    }
+
+    fn ctx() -> RequestContext {
+        RequestContext {
+            recipient: Principal::anonymous(),
+        }
+    }
+
+    fn table_path() -> TablePath {
+        TablePath {
+            catalog: "cat".into(),
+            schema: "sch".into(),
+            table: "t".into(),
+        }
+    }
+
+    /// Create `cat.sch.t` as a catalog-managed table at version 0 and return
+    /// its id.
+    async fn create_managed_table(h: &ServerHandler<RequestContext>) -> String {
+        h.create_credential(
+            CreateCredentialRequest {
+                name: "cred".into(),
+                purpose: Purpose::Storage as i32,
+                aws_iam_role: Some(AwsIamRoleConfig {
+                    role_arn: "arn:aws:iam::123456789012:role/test".into(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        h.create_external_location(
+            CreateExternalLocationRequest {
+                name: "el".into(),
+                url: "s3://bucket/cat".into(),
+                credential_name: "cred".into(),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        h.create_catalog(
+            CreateCatalogRequest {
+                name: "cat".into(),
+                storage_root: Some("s3://bucket/cat".into()),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        h.create_schema(
+            CreateSchemaRequest {
+                name: "sch".into(),
+                catalog_name: "cat".into(),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        let staging = StagingTableHandler::create_staging_table(
+            h,
+            CreateStagingTableRequest {
+                name: "t".into(),
+                catalog_name: "cat".into(),
+                schema_name: "sch".into(),
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+
+        let features = |features: &[&str]| features.iter().map(|f| f.to_string()).collect();
+        let mut properties: BTreeMap<String, String> = [
+            ("delta.enableDeletionVectors", "true"),
+            ("delta.checkpointPolicy", "v2"),
+            ("delta.enableInCommitTimestamps", "true"),
+            ("delta.checkpoint.writeStatsAsStruct", "true"),
+            ("delta.checkpoint.writeStatsAsJson", "true"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        properties.insert("io.unitycatalog.tableId".into(), staging.id.clone());
+        DeltaApiHandler::create_table(
+            h,
+            SchemaPath {
+                catalog: "cat".into(),
+                schema: "sch".into(),
+            },
+            DeltaCreateTableRequest {
+                name: "t".into(),
+                location: staging.staging_location,
+                table_type: DeltaTableType::Managed,
+                data_source_format: Some(DeltaDataSourceFormat::Delta),
+                comment: None,
+                columns: DeltaStructType {
+                    type_tag: Default::default(),
+                    fields: vec![DeltaStructField {
+                        name: "id".into(),
+                        data_type: DeltaDataType::Primitive("long".into()),
+                        nullable: true,
+                        metadata: Default::default(),
+                    }],
+                },
+                partition_columns: None,
+                protocol: DeltaProtocol {
+                    min_reader_version: 3,
+                    min_writer_version: 7,
+                    reader_features: Some(features(&[
+                        "catalogManaged",
+                        "v2Checkpoint",
+                        "vacuumProtocolCheck",
+                        "deletionVectors",
+                    ])),
+                    writer_features: Some(features(&[
+                        "catalogManaged",
+                        "v2Checkpoint",
+                        "vacuumProtocolCheck",
+                        "deletionVectors",
+                        "inCommitTimestamp",
+                    ])),
+                },
+                properties,
+                domain_metadata: None,
+                last_commit_timestamp_ms: 1_000,
+                uniform: None,
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        staging.id
+    }
+
+    fn commit(version: i64, table_id: &str) -> DeltaCommit {
+        DeltaCommit {
+            version,
+            timestamp: 1_000 + version,
+            file_name: format!("{version:020}.{table_id}.json"),
+            file_size: 256,
+            file_modification_timestamp: 1_000 + version,
+        }
+    }
+
+    #[tokio::test]
+    async fn sqlite_backend_keeps_commits_across_restarts() {
+        let db = TempDb::new();
+        let h = start(&db).await;
+        let table_id = create_managed_table(&h).await;
+        for version in [1, 2] {
+            DeltaApiHandler::update_table(
+                &h,
+                table_path(),
+                DeltaUpdateTableRequest {
+                    requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                        uuid: table_id.clone(),
+                    }],
+                    updates: vec![DeltaTableUpdate::AddCommit {
+                        commit: commit(version, &table_id),
+                        uniform: None,
+                    }],
+                },
+                ctx(),
+            )
+            .await
+            .unwrap();
+        }
+        drop(h);
+
+        // A restarted server serves the ratified commits from the database.
+        let h = start(&db).await;
+        let loaded = DeltaApiHandler::load_table(&h, table_path(), ctx())
+            .await
+            .unwrap();
+        assert_eq!(loaded.metadata.table_uuid, table_id);
+        assert_eq!(loaded.latest_table_version, Some(2));
+        assert_eq!(
+            loaded.commits,
+            Some(vec![commit(1, &table_id), commit(2, &table_id)])
+        );
+
+        // ... and keeps arbitrating versions where the previous run left off.
+        let replay = DeltaApiHandler::update_table(
+            &h,
+            table_path(),
+            DeltaUpdateTableRequest {
+                requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                    uuid: table_id.clone(),
+                }],
+                updates: vec![DeltaTableUpdate::AddCommit {
+                    commit: commit(2, &table_id),
+                    uniform: None,
+                }],
+            },
+            ctx(),
+        )
+        .await;
+        assert!(replay.is_err());
+    }
+}
@@ -15,12 +15,14 @@ use unitycatalog_server::api::delta::DeltaApiHandler;
 use unitycatalog_server::api::entity_tag_assignments::EntityTagAssignmentHandler;
 use unitycatalog_server::api::external_locations::ExternalLocationHandler;
 use unitycatalog_server::api::functions::FunctionHandler;
+use unitycatalog_server::api::iceberg::IcebergRestHandler;
 use unitycatalog_server::api::providers::ProviderHandler;
 use unitycatalog_server::api::recipients::RecipientHandler;
 use unitycatalog_server::api::schemas::SchemaHandler;
 use unitycatalog_server::api::shares::ShareHandler;
 use unitycatalog_server::api::sharing::{SharingHandler, SharingQueryHandler};
 use unitycatalog_server::api::staging_tables::StagingTableHandler;
+use unitycatalog_server::api::table_history::TableHistoryHandler;
 use unitycatalog_server::api::tables::TableHandler;
 use unitycatalog_server::api::tag_policies::TagPolicyHandler;
 use unitycatalog_server::api::temporary_credentials::TemporaryCredentialHandler;
@@ -29,10 +31,11 @@ use unitycatalog_server::rest::{
     AuthenticationLayer, Authenticator, create_agent_skills_router, create_agents_router,
     create_catalogs_router, create_commits_router, create_credentials_router, create_delta_router,
     create_entity_tag_assignments_router, create_external_locations_router,
-    create_functions_router, create_open_sharing_router, create_providers_router,
-    create_recipients_router, create_schemas_router, create_shares_router, create_sharing_router,
-    create_staging_tables_router, create_tables_router, create_tag_policies_router,
-    create_temporary_credentials_router, create_volumes_router,
+    create_functions_router, create_iceberg_router, create_open_sharing_router,
+    create_providers_router, create_recipients_router, create_schemas_router, create_shares_router,
+    create_sharing_router, create_staging_tables_router, create_table_history_router,
+    create_tables_router, create_tag_policies_router, create_temporary_credentials_router,
+    create_volumes_router,
 };
 use unitycatalog_server::sharing::{SharingSkillHandler, SharingVolumeHandler};
 
@@ -54,6 +57,7 @@ where
         + SchemaHandler<Cx>
         + StagingTableHandler<Cx>
         + TableHandler<Cx>
+        + TableHistoryHandler<Cx>
         + VolumeHandler<Cx>
         + AgentSkillHandler<Cx>
         + AgentHandler<Cx>
@@ -62,6 +66,7 @@ where
         + ProviderHandler<Cx>
         + DeltaCommitHandler<Cx>
         + DeltaApiHandler<Cx>
+        + IcebergRestHandler<Cx>
         + TagPolicyHandler<Cx>
         + EntityTagAssignmentHandler<Cx>
         + TemporaryCredentialHandler<Cx>
@@ -101,6 +106,7 @@ where
         .merge(create_schemas_router(handler.clone()))
         .merge(create_staging_tables_router(handler.clone()))
         .merge(create_tables_router(handler.clone()))
+        .merge(create_table_history_router(handler.clone()))
         .merge(create_volumes_router(handler.clone()))
         .merge(create_agent_skills_router(handler.clone()))
         .merge(create_agents_router(handler.clone()))
@@ -113,6 +119,7 @@ where
         .merge(create_shares_router(handler.clone()))
         .merge(create_commits_router(handler.clone()))
         .merge(create_delta_router(handler.clone()))
+        .merge(create_iceberg_router(handler.clone()))
         .merge(create_entity_tag_assignments_router(handler.clone()));
 
     let router = Router::new()
@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use futures::TryStreamExt;
+use unitycatalog_sharing_client::client::DeltaSharingClient;
+use unitycatalog_sharing_client::models::open_sharing::v1::QueryTableRequest;
+
+use crate::GlobalOpts;
+use crate::error::{Error, Result};
+use crate::render::{ResolvedFormat, render_batches, render_list};
+
+#[derive(Debug, Args)]
+pub struct SharingCommand {
+    /// Path to the Delta Sharing profile file (`config.share`)
+    #[clap(long, short, env = "UC_SHARING_PROFILE")]
+    profile: PathBuf,
+
+    #[command(subcommand)]
+    command: Option<SharingCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+enum SharingCommands {
+    /// List the shares the profile can access
+    Shares,
+
+    /// List the schemas in a share
+    Schemas {
+        /// The name of the share
+        share: String,
+    },
+
+    /// List the tables in a share
+    Tables {
+        /// The name of the share
+        share: String,
+        /// Only list the tables in this schema
+        #[clap(long, short)]
+        schema: Option<String>,
+    },
+
+    /// Show the first rows of a shared table
+    Preview {
+        /// The table to preview, as `share.schema.table`
+        name: String,
+        /// The maximum number of rows to show
+        #[clap(long, short = 'n', default_value_t = 20)]
+        limit: usize,
+        /// Read the table as of this version
+        #[clap(long)]
+        version: Option<i64>,
+    },
+}
+
+pub async fn handle_sharing(cmd: &SharingCommand, opts: GlobalOpts) -> Result<()> {
+    let fmt = opts.output.resolve();
+    let client = DeltaSharingClient::from_profile(&cmd.profile)?;
+
+    match &cmd.command {
+        Some(SharingCommands::Shares) => {
+            let shares = client.list_shares(None).try_collect::<Vec<_>>().await?;
+            render_list(&shares, fmt)?;
+        }
+        Some(SharingCommands::Schemas { share }) => {
+            let schemas = client
+                .list_share_schemas(share, None)
+                .try_collect::<Vec<_>>()
+                .await?;
+            render_list(&schemas, fmt)?;
+        }
+        Some(SharingCommands::Tables { share, schema }) => {
+            let tables = match schema {
+                Some(schema) => client.list_schema_tables(share, schema, None),
+                None => client.list_all_tables(share, None),
+            }
+            .try_collect::<Vec<_>>()
+            .await?;
+            render_list(&tables, fmt)?;
+        }
+        Some(SharingCommands::Preview {
+            name,
+            limit,
+            version,
+        }) => preview(&client, name, *limit, *version, fmt).await?,
+        None => {
+            return Err(Error::Generic(
+                "no subcommand provided; see `uc sharing --help`".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+async fn preview(
+    client: &DeltaSharingClient,
+    name: &str,
+    limit: usize,
+    version: Option<i64>,
+    fmt: ResolvedFormat,
+) -> Result<()> {
+    let parts: Vec<_> = name.split('.').collect();
+    let [share, schema, table] = parts.as_slice() else {
+        return Err(Error::Generic(format!(
+            "expected a table name of the form `share.schema.table`, got `{name}`"
+        )));
+    };
+    let request = QueryTableRequest {
+        version,
+        limit_hint: i32::try_from(limit).ok(),
+        ..Default::default()
+    };
+    let mut stream = client
+        .table_reader(*share, *schema, *table)
+        .read(request)
+        .await?;
+
+    // The limit is only a hint to the server, so stop reading once enough
+    // rows have arrived.
+    let mut batches = Vec::new();
+    let mut rows = 0;
+    while rows < limit
+        && let Some(batch) = stream
+            .try_next()
+            .await
+            .map_err(|e| Error::Generic(e.to_string()))?
+    {
+        let batch = batch.slice(0, batch.num_rows().min(limit - rows));
+        rows += batch.num_rows();
+        batches.push(batch);
+    }
+    render_batches(&batches, fmt)
+}
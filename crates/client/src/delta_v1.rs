@@ -8,15 +8,16 @@
 //! lifecycle (`createStagingTable`, `createTable`, `loadTable`, `updateTable`,
 //! `deleteTable`, `tableExists`, `renameTable`), credential vending
 //! (`getTableCredentials`, `getStagingTableCredentials`,
-//! `getTemporaryPathCredentials`), and commit-metrics reporting (`reportMetrics`).
+//! `getTemporaryPathCredentials`), and commit-metrics reporting (`reportMetrics`),
+//! plus the server's multi-table commit extension (`POST /delta/v1/commits`).
 
 use olai_http::CloudClient;
 use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use unitycatalog_common::models::delta::v1::{
-    DeltaCatalogConfig, DeltaCreateStagingTableRequest, DeltaCreateTableRequest,
-    DeltaCredentialOperation, DeltaCredentialsResponse, DeltaLoadTableResponse,
-    DeltaRenameTableRequest, DeltaReportMetricsRequest, DeltaStagingTableResponse,
-    DeltaUpdateTableRequest,
+    DeltaBatchCommitRequest, DeltaBatchCommitResponse, DeltaCatalogConfig,
+    DeltaCreateStagingTableRequest, DeltaCreateTableRequest, DeltaCredentialOperation,
+    DeltaCredentialsResponse, DeltaLoadTableResponse, DeltaRenameTableRequest,
+    DeltaReportMetricsRequest, DeltaStagingTableResponse, DeltaUpdateTableRequest,
 };
 use url::Url;
 
@@ -335,6 +336,25 @@ impl DeltaV1Client {
         Ok(())
     }
 
+    /// Ratify one commit on each of several managed tables atomically —
+    /// `POST /delta/v1/commits`.
+    ///
+    /// A server extension, not part of `delta.yaml`. Either every commit wins
+    /// its version or none is recorded; a lost race on any table fails the whole
+    /// batch with `409`. Each staged commit file must already be written.
+    pub async fn batch_commit(
+        &self,
+        request: &DeltaBatchCommitRequest,
+    ) -> Result<DeltaBatchCommitResponse> {
+        let url = self.url("commits")?;
+        let response = self.client.post(url).json(request).send().await?;
+        if !response.status().is_success() {
+            return Err(crate::error::parse_delta_error_response(response).await);
+        }
+        let result = response.bytes().await?;
+        Ok(serde_json::from_slice(&result)?)
+    }
+
     /// Build a `catalogs/{c}/schemas/{s}/tables/{t}{suffix}` URL with each name
     /// percent-encoded. `suffix` is a literal sub-path (e.g. `"/rename"`) or empty.
     fn table_url(&self, catalog: &str, schema: &str, table: &str, suffix: &str) -> Result<Url> {
@@ -415,6 +435,26 @@ mod tests {
         assert!(err.is_commit_conflict());
     }
 
+    #[tokio::test]
+    async fn batch_commit_maps_commit_conflict() {
+        let mut server = Server::new_async().await;
+        let m = server
+            .mock("POST", "/delta/v1/commits")
+            .with_status(409)
+            .with_body(
+                r#"{"error":{"message":"conflict","type":"CommitVersionConflictException","code":409}}"#,
+            )
+            .create_async()
+            .await;
+
+        let err = test_client(&server)
+            .batch_commit(&DeltaBatchCommitRequest { commits: vec![] })
+            .await
+            .unwrap_err();
+        m.assert_async().await;
+        assert!(err.is_commit_conflict());
+    }
+
     #[tokio::test]
     async fn url_segments_are_percent_encoded() {
         let mut server = Server::new_async().await;
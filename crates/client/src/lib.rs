@@ -15,11 +15,15 @@ pub use codegen::tag_policies::TagPolicyClient;
 pub use codegen::volumes::VolumeClient;
 pub use delta_v1::DeltaV1Client;
 pub use error::*;
+pub use permissions::PermissionsClient;
+pub use table_history::TableHistoryBuilder;
 pub use temporary_credentials::*;
 
 pub mod codegen;
 mod delta_v1;
 pub mod error;
+mod permissions;
+mod table_history;
 mod temporary_credentials;
 
 impl UnityCatalogClient {
@@ -40,4 +44,15 @@ impl UnityCatalogClient {
         let base = self.delta_commits_client();
         DeltaV1Client::new(base.client, base.base_url)
     }
+
+    /// Client for the privileges granted on the securable `full_name` of
+    /// `securable_type` (`catalog`, `schema`, `table`, `volume`, `function`).
+    pub fn permissions(
+        &self,
+        securable_type: impl Into<String>,
+        full_name: impl Into<String>,
+    ) -> PermissionsClient {
+        let base = self.catalogs_client();
+        PermissionsClient::new(base.client, base.base_url, securable_type, full_name)
+    }
 }
@@ -0,0 +1,130 @@
+//! Hand-written client for the permissions API
+//! (`/permissions/{securable_type}/{full_name}`).
+//!
+//! Permissions span every securable type rather than belonging to one
+//! generated resource API, so — like [`crate::delta_v1`] — the client is
+//! maintained by hand. It is reached via [`UnityCatalogClient::permissions`];
+//! the wire DTOs are shared with the server via
+//! [`unitycatalog_common::models::permissions::v1`].
+//!
+//! [`UnityCatalogClient::permissions`]: crate::UnityCatalogClient::permissions
+
+use olai_http::CloudClient;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use unitycatalog_common::models::permissions::v1::{
+    PermissionsChange, PermissionsList, UpdatePermissions,
+};
+use url::Url;
+
+use crate::Result;
+
+/// Path-segment encoding that keeps the `.` separating a full name's parts
+/// (and `-`/`_`) readable while escaping everything else.
+const SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'.').remove(b'-').remove(b'_');
+
+/// Client for the privileges granted on one securable.
+#[derive(Clone)]
+pub struct PermissionsClient {
+    client: CloudClient,
+    base_url: Url,
+    securable_type: String,
+    full_name: String,
+}
+
+impl PermissionsClient {
+    /// Create a client for the securable `full_name` of `securable_type`
+    /// (`catalog`, `schema`, `table`, `volume`, `function`, …).
+    pub fn new(
+        client: CloudClient,
+        mut base_url: Url,
+        securable_type: impl Into<String>,
+        full_name: impl Into<String>,
+    ) -> Self {
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        Self {
+            client,
+            base_url,
+            securable_type: securable_type.into(),
+            full_name: full_name.into(),
+        }
+    }
+
+    /// List the privileges granted on the securable —
+    /// `GET /permissions/{securable_type}/{full_name}`, optionally only those
+    /// held by `principal`.
+    pub async fn get(&self, principal: Option<&str>) -> Result<PermissionsList> {
+        let mut url = self.url()?;
+        if let Some(principal) = principal {
+            url.query_pairs_mut().append_pair("principal", principal);
+        }
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(crate::error::parse_error_response(response).await);
+        }
+        let result = response.bytes().await?;
+        Ok(serde_json::from_slice(&result)?)
+    }
+
+    /// Grant and revoke privileges in one request —
+    /// `PATCH /permissions/{securable_type}/{full_name}`. Returns the
+    /// privileges on the securable after the change.
+    pub async fn update(&self, changes: Vec<PermissionsChange>) -> Result<PermissionsList> {
+        let url = self.url()?;
+        let request = UpdatePermissions { changes };
+        let response = self.client.patch(url).json(&request).send().await?;
+        if !response.status().is_success() {
+            return Err(crate::error::parse_error_response(response).await);
+        }
+        let result = response.bytes().await?;
+        Ok(serde_json::from_slice(&result)?)
+    }
+
+    fn url(&self) -> Result<Url> {
+        Ok(self.base_url.join(&format!(
+            "permissions/{}/{}",
+            utf8_percent_encode(&self.securable_type, SEGMENT),
+            utf8_percent_encode(&self.full_name, SEGMENT),
+        ))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn update_patches_changes_and_returns_assignments() {
+        let mut server = Server::new_async().await;
+        let m = server
+            .mock("PATCH", "/permissions/table/c.s.t")
+            .match_body(Matcher::Json(serde_json::json!({
+                "changes": [{ "principal": "analysts", "add": ["SELECT"] }]
+            })))
+            .with_status(200)
+            .with_body(
+                r#"{"privilege_assignments":[{"principal":"analysts","privileges":["SELECT"]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = PermissionsClient::new(
+            CloudClient::new_unauthenticated(),
+            Url::parse(&server.url()).unwrap(),
+            "table",
+            "c.s.t",
+        );
+        let list = client
+            .update(vec![PermissionsChange {
+                principal: "analysts".to_string(),
+                add: vec!["SELECT".to_string()],
+                remove: vec![],
+            }])
+            .await
+            .unwrap();
+        m.assert_async().await;
+        assert_eq!(list.privilege_assignments[0].principal, "analysts");
+    }
+}
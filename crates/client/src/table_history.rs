@@ -0,0 +1,130 @@
+//! Hand-written client for the table history API (`GET /tables/{full_name}/history`).
+//!
+//! The history endpoint is not part of the generated tables API, so — like
+//! [`crate::delta_v1`] — its builder is maintained by hand. It is reached via
+//! [`TableClient::history`]; the wire DTOs are shared with the server via
+//! [`unitycatalog_common::models::table_history::v1`].
+
+use std::future::IntoFuture;
+
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use unitycatalog_common::models::table_history::v1::{
+    GetTableHistoryRequest, GetTableHistoryResponse, TableHistoryEntry,
+};
+
+use crate::Result;
+use crate::TableClient;
+use crate::codegen::stream_paginated;
+use crate::codegen::tables::TableServiceClient;
+
+impl TableClient {
+    /// List the commits of this table, newest first.
+    pub fn history(&self) -> TableHistoryBuilder {
+        TableHistoryBuilder::new(self.client.clone(), self.full_name())
+    }
+}
+
+/// Builder for table history
+pub struct TableHistoryBuilder {
+    client: TableServiceClient,
+    request: GetTableHistoryRequest,
+}
+
+impl TableHistoryBuilder {
+    pub(crate) fn new(client: TableServiceClient, full_name: impl Into<String>) -> Self {
+        let request = GetTableHistoryRequest {
+            full_name: full_name.into(),
+            ..Default::default()
+        };
+        Self { client, request }
+    }
+
+    /// Oldest version to include.
+    pub fn with_start_version(mut self, start_version: impl Into<Option<i64>>) -> Self {
+        self.request.start_version = start_version.into();
+        self
+    }
+
+    /// Newest version to include.
+    pub fn with_end_version(mut self, end_version: impl Into<Option<i64>>) -> Self {
+        self.request.end_version = end_version.into();
+        self
+    }
+
+    /// Maximum number of entries to return.
+    pub fn with_max_results(mut self, max_results: impl Into<Option<i32>>) -> Self {
+        self.request.max_results = max_results.into();
+        self
+    }
+
+    /// Opaque token to continue a previous listing.
+    pub fn with_page_token(mut self, page_token: impl Into<Option<String>>) -> Self {
+        self.request.page_token = page_token.into();
+        self
+    }
+
+    /// Convert paginated request into stream of results
+    pub fn into_stream(self) -> BoxStream<'static, Result<TableHistoryEntry>> {
+        let remaining = self.request.max_results;
+        let stream = stream_paginated(
+            (self, remaining),
+            move |(mut builder, mut remaining), page_token| async move {
+                builder.request.page_token = page_token;
+                let res = get_table_history(&builder.client, &builder.request).await?;
+                if let Some(ref mut rem) = remaining {
+                    *rem -= res.entries.len() as i32;
+                }
+                let next_page_token = if remaining.is_some_and(|r| r <= 0) {
+                    None
+                } else {
+                    res.next_page_token.clone()
+                };
+                Ok((res, (builder, remaining), next_page_token))
+            },
+        )
+        .map_ok(|resp| futures::stream::iter(resp.entries.into_iter().map(Ok)))
+        .try_flatten();
+        stream.boxed()
+    }
+}
+
+impl IntoFuture for TableHistoryBuilder {
+    type Output = Result<GetTableHistoryResponse>;
+    type IntoFuture = BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move { get_table_history(&self.client, &self.request).await })
+    }
+}
+
+async fn get_table_history(
+    client: &TableServiceClient,
+    request: &GetTableHistoryRequest,
+) -> Result<GetTableHistoryResponse> {
+    let mut url = client
+        .base_url
+        .join(&format!("tables/{}/history", request.full_name))?;
+    if let Some(value) = request.start_version {
+        url.query_pairs_mut()
+            .append_pair("start_version", &value.to_string());
+    }
+    if let Some(value) = request.end_version {
+        url.query_pairs_mut()
+            .append_pair("end_version", &value.to_string());
+    }
+    if let Some(value) = request.max_results {
+        url.query_pairs_mut()
+            .append_pair("max_results", &value.to_string());
+    }
+    if let Some(ref value) = request.page_token {
+        url.query_pairs_mut().append_pair("page_token", value);
+    }
+    let response = client.client.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(crate::error::parse_error_response(response).await);
+    }
+    let result = response.bytes().await?;
+    Ok(serde_json::from_slice(&result)?)
+}
@@ -41,16 +41,20 @@ pub enum DependencyError {
 }
 
 /// Derive the [`DependencyList`] a metric view reads from its `source` and
-/// `joins`. The result is deduplicated and ordered by first appearance.
+/// `joins`, including nested snowflake joins. The result is deduplicated and ordered by first appearance.
 ///
 /// Returns an error if any relation cannot be resolved (strict policy).
 pub fn dependencies(view: &MetricView) -> Result<DependencyList, DependencyError> {
     let mut tables: Vec<String> = Vec::new();
     collect_source(&view.source, &mut tables)?;
-    for join in &view.joins {
+    for join in view.all_joins() {
         collect_source(&join.source, &mut tables)?;
     }
 
+    Ok(to_dependency_list(tables))
+}
+
+fn to_dependency_list(tables: Vec<String>) -> DependencyList {
     let dependencies = tables
         .into_iter()
         .map(|table_full_name| Dependency {
@@ -59,37 +63,54 @@ pub fn dependencies(view: &MetricView) -> Result<DependencyList, DependencyError
             })),
         })
         .collect();
-    Ok(DependencyList { dependencies })
+    DependencyList { dependencies }
 }
 
-/// Resolve a single metric-view `source` string into table dependencies,
-/// appending each `catalog.schema.table` name to `out` (deduplicated).
-fn collect_source(source: &str, out: &mut Vec<String>) -> Result<(), DependencyError> {
-    // Fast path: a bare three-part name is by far the common case and needs no
-    // SQL parser.
-    if let Some(name) = three_part_name(source) {
-        push_unique(out, name);
-        return Ok(());
+/// Derive the [`DependencyList`] of a SQL view from its query text.
+///
+/// Applies the same strict policy as [`dependencies`]: every relation the query
+/// reads must be a three-part `catalog.schema.table` name. The result is
+/// deduplicated and ordered by first appearance.
+pub fn query_dependencies(sql: &str) -> Result<DependencyList, DependencyError> {
+    let mut tables = Vec::new();
+    for name in parse_query_relations(sql)? {
+        let full = object_name_three_part(&name)
+            .ok_or_else(|| DependencyError::UnresolvedRelation(name.to_string()))?;
+        push_unique(&mut tables, full);
     }
+    Ok(to_dependency_list(tables))
+}
 
-    // A bare dotted identifier path that isn't exactly three parts (e.g.
-    // `schema.table`) is a name, not a query — report it as unresolvable rather
-    // than feeding it to the SQL parser, where it would surface as a confusing
-    // parse error.
+/// Classify a metric-view `source` string: `Some` three-part table name for a
+/// bare name, `None` for inline SQL. A bare name of any other length is
+/// [`DependencyError::UnresolvedRelation`].
+pub fn source_table(source: &str) -> Result<Option<String>, DependencyError> {
+    if let Some(name) = three_part_name(source) {
+        return Ok(Some(name));
+    }
     if is_bare_name(source) {
         return Err(DependencyError::UnresolvedRelation(
             source.trim().to_string(),
         ));
     }
+    Ok(None)
+}
+
+/// Resolve a single metric-view `source` string into table dependencies,
+/// appending each `catalog.schema.table` name to `out` (deduplicated).
+fn collect_source(source: &str, out: &mut Vec<String>) -> Result<(), DependencyError> {
+    // Fast path: a bare three-part name is by far the common case and needs no
+    // SQL parser. A bare dotted identifier path that isn't exactly three parts
+    // (e.g. `schema.table`) is a name, not a query — it is reported as
+    // unresolvable rather than fed to the SQL parser, where it would surface as
+    // a confusing parse error.
+    if let Some(name) = source_table(source)? {
+        push_unique(out, name);
+        return Ok(());
+    }
 
     // Otherwise treat the source as inline SQL and collect its base relations.
-    let dialect = GenericDialect {};
-    let statements = Parser::parse_sql(&dialect, source).map_err(DependencyError::Sql)?;
-    let [Statement::Query(query)] = statements.as_slice() else {
-        return Err(DependencyError::NotAQuery);
-    };
-    let mut names = Vec::new();
-    collect_query_relations(query, &mut names);
+    let names = parse_query_relations(source)?;
     if names.is_empty() {
         return Err(DependencyError::UnresolvedRelation(source.to_string()));
     }
@@ -101,6 +122,18 @@ fn collect_source(source: &str, out: &mut Vec<String>) -> Result<(), DependencyE
     Ok(())
 }
 
+/// Parse `sql` as a single query and return every base relation it reads.
+fn parse_query_relations(sql: &str) -> Result<Vec<ObjectName>, DependencyError> {
+    let dialect = GenericDialect {};
+    let statements = Parser::parse_sql(&dialect, sql).map_err(DependencyError::Sql)?;
+    let [Statement::Query(query)] = statements.as_slice() else {
+        return Err(DependencyError::NotAQuery);
+    };
+    let mut names = Vec::new();
+    collect_query_relations(query, &mut names);
+    Ok(names)
+}
+
 /// If `s` is a dotted three-part `catalog.schema.table` identifier (no SQL
 /// syntax, no quoting), return it normalized; otherwise `None`.
 fn three_part_name(s: &str) -> Option<String> {
@@ -196,6 +229,7 @@ fn push_unique(out: &mut Vec<String>, name: String) {
 
 #[cfg(test)]
 mod tests {
+    use super::super::model::Join;
     use super::*;
 
     fn view(source: &str, join_sources: &[&str]) -> MetricView {
@@ -206,12 +240,13 @@ mod tests {
             joins: join_sources
                 .iter()
                 .enumerate()
-                .map(|(i, s)| super::super::model::Join {
+                .map(|(i, s)| Join {
                     name: format!("j{i}"),
                     source: s.to_string(),
                     on: None,
                     using: vec![],
                     cardinality: None,
+                    joins: vec![],
                 })
                 .collect(),
             dimensions: vec![],
@@ -248,6 +283,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn snowflake_joins_included() {
+        let mut view = view("main.sales.orders", &["main.sales.customers"]);
+        view.joins[0].joins.push(Join {
+            name: "nation".to_string(),
+            source: "SELECT * FROM main.geo.nations".to_string(),
+            on: Some("j0.c_nationkey = nation.n_nationkey".to_string()),
+            using: vec![],
+            cardinality: None,
+            joins: vec![],
+        });
+        let deps = dependencies(&view).unwrap();
+        assert_eq!(
+            table_names(&deps),
+            vec![
+                "main.sales.orders",
+                "main.sales.customers",
+                "main.geo.nations"
+            ]
+        );
+    }
+
+    #[test]
+    fn source_table_classifies_sources() {
+        assert_eq!(
+            source_table(" main.sales.orders ").unwrap().as_deref(),
+            Some("main.sales.orders")
+        );
+        assert_eq!(
+            source_table("SELECT * FROM main.sales.orders").unwrap(),
+            None
+        );
+        assert!(matches!(
+            source_table("sales.orders"),
+            Err(DependencyError::UnresolvedRelation(_))
+        ));
+    }
+
     #[test]
     fn inline_sql_source_resolved() {
         let deps = dependencies(&view(
@@ -273,6 +346,25 @@ mod tests {
         assert!(matches!(err, DependencyError::UnresolvedRelation(_)));
     }
 
+    #[test]
+    fn view_query_dependencies() {
+        let deps = query_dependencies(
+            "SELECT o.id FROM main.sales.orders o \
+             JOIN (SELECT * FROM main.sales.customers) c ON o.c_id = c.id \
+             UNION ALL SELECT id FROM main.sales.orders",
+        )
+        .unwrap();
+        assert_eq!(
+            table_names(&deps),
+            vec!["main.sales.orders", "main.sales.customers"]
+        );
+
+        let err = query_dependencies("SELECT * FROM orders").unwrap_err();
+        assert!(matches!(err, DependencyError::UnresolvedRelation(_)));
+        let err = query_dependencies("DROP TABLE main.sales.orders").unwrap_err();
+        assert!(matches!(err, DependencyError::NotAQuery));
+    }
+
     #[test]
     fn malformed_inline_sql_errors() {
         let err = dependencies(&view("SELECT * FROM (", &[])).unwrap_err();
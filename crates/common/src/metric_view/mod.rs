@@ -14,6 +14,9 @@
 //! 3. [`deps`] — deriving the view's [`DependencyList`] (the tables/functions it
 //!    reads) from the definition, using [`sqlparser`] for non-trivial sources.
 //!
+//! [`rename`] rewrites definitions and dependency lists when a table they read
+//! is renamed.
+//!
 //! The catalog server uses (2)+(3) to derive and store `view_dependencies` on
 //! create; the DataFusion integration re-exports this module and reuses it to
 //! lower the view into a query plan. There is exactly one parser.
@@ -25,7 +28,9 @@
 pub mod deps;
 pub mod detect;
 pub mod model;
+pub mod rename;
 
-pub use deps::{DependencyError, dependencies};
+pub use deps::{DependencyError, dependencies, query_dependencies, source_table};
 pub use detect::{MetricViewDetectError, metric_view_of};
-pub use model::{Dimension, Join, Measure, MetricView};
+pub use model::{Dimension, Join, Measure, MeasureWindow, MetricView};
+pub use rename::{RenameError, rename_dependency, rename_in_metric_view, rename_in_query};
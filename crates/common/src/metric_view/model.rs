@@ -10,7 +10,7 @@
 //!
 //! This models the field surface needed to lower a metric view into a query plan
 //! and to derive its dependencies ([`super::deps`]); fields not yet exercised by
-//! the lowering ([`Join`], `window`, `materialization`) are captured loosely.
+//! the lowering (`materialization`) are not modeled.
 
 use serde::Deserialize;
 
@@ -58,14 +58,42 @@ pub struct Dimension {
 pub struct Measure {
     /// Output column name for the measure.
     pub name: String,
-    /// SQL aggregate expression, e.g. `SUM(o_totalprice)`.
+    /// SQL aggregate expression, e.g. `SUM(o_totalprice)`. A *composed* measure
+    /// combines other measures instead, e.g.
+    /// `MEASURE(revenue) / MEASURE(order_count)`.
     pub expr: String,
+    /// Evaluate the measure over a window of a dimension rather than over each
+    /// group alone: running totals, trailing periods and semi-additive
+    /// measures. At most one window is supported.
+    #[serde(default)]
+    pub window: Vec<MeasureWindow>,
+}
+
+/// The window a measure is evaluated over.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MeasureWindow {
+    /// The dimension the window runs along, e.g. a date.
+    pub order: String,
+    /// Which rows of `order` a group's value covers: `current`, `cumulative`,
+    /// `all`, `trailing <n> [<unit>]` (the `n` preceding values, excluding the
+    /// current one) or `leading <n> [<unit>]`. Units are `day`, `week`,
+    /// `month`, `quarter` and `year`.
+    pub range: String,
+    /// `first` or `last`: when a query does not group by `order`, take the
+    /// value at its first or last position instead of aggregating across it —
+    /// e.g. the closing balance of a period.
+    #[serde(default)]
+    pub semiadditive: Option<String>,
 }
 
 /// A join in a star/snowflake metric view.
+///
+/// The view's `source` is addressed as `source` in join conditions and
+/// expressions; each joined relation by its `name`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Join {
-    /// Alias for the joined relation.
+    /// Alias for the joined relation. Unique across the view, including nested
+    /// joins.
     pub name: String,
     /// Joined relation: three-part name or inline SQL.
     pub source: String,
@@ -78,6 +106,24 @@ pub struct Join {
     /// `many_to_one` (default) or `one_to_many`.
     #[serde(default)]
     pub cardinality: Option<String>,
+    /// Snowflake joins off this relation; their conditions address it by
+    /// `name`.
+    #[serde(default)]
+    pub joins: Vec<Join>,
+}
+
+impl Join {
+    /// Whether each row of the parent may match several rows of this relation,
+    /// repeating the parent's rows in the joined result. `None` for an
+    /// unrecognized cardinality.
+    pub fn fans_out(&self) -> Option<bool> {
+        match self.cardinality.as_deref() {
+            None => Some(false),
+            Some(c) if c.eq_ignore_ascii_case("many_to_one") => Some(false),
+            Some(c) if c.eq_ignore_ascii_case("one_to_many") => Some(true),
+            Some(_) => None,
+        }
+    }
 }
 
 impl MetricView {
@@ -85,4 +131,17 @@ impl MetricView {
     pub fn from_yaml(yaml: &str) -> Result<Self, serde_yml::Error> {
         serde_yml::from_str(yaml)
     }
+
+    /// Every join of the view, nested snowflake joins following their parent.
+    pub fn all_joins(&self) -> Vec<&Join> {
+        fn walk<'a>(joins: &'a [Join], out: &mut Vec<&'a Join>) {
+            for join in joins {
+                out.push(join);
+                walk(&join.joins, out);
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.joins, &mut out);
+        out
+    }
 }
@@ -0,0 +1,267 @@
+//! Following a table rename through view definitions.
+//!
+//! Views and metric views name the tables they read by their three-part
+//! `catalog.schema.table` name, both in the definition and in the derived
+//! `view_dependencies`. When a table is renamed the server rewrites every
+//! reference to the old name so dependents keep resolving.
+//!
+//! Inline SQL is rewritten through the [`sqlparser`] AST, so a rewritten query
+//! comes back in the parser's canonical formatting. Definitions that do not
+//! reference the renamed table are left untouched.
+
+use sqlparser::ast::{ObjectName, ObjectNamePart, Query, SetExpr, Statement, TableFactor};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+use super::deps::DependencyError;
+use crate::models::tables::v1::{DependencyList, dependency};
+
+/// Failure rewriting a view definition.
+#[derive(Debug, thiserror::Error)]
+pub enum RenameError {
+    /// The metric-view definition is not valid YAML.
+    #[error("invalid metric-view YAML: {0}")]
+    Yaml(#[from] serde_yml::Error),
+
+    /// A SQL source or view query could not be parsed.
+    #[error(transparent)]
+    Dependency(#[from] DependencyError),
+}
+
+/// Replace table dependencies on `from` with `to`.
+///
+/// Names are compared case-insensitively. Returns whether anything changed.
+pub fn rename_dependency(list: &mut DependencyList, from: &str, to: &str) -> bool {
+    let mut changed = false;
+    for dep in &mut list.dependencies {
+        if let Some(dependency::Dependency::Table(table)) = &mut dep.dependency
+            && table.table_full_name.eq_ignore_ascii_case(from)
+        {
+            table.table_full_name = to.to_string();
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Rewrite references to the table `from` in a SQL view query.
+///
+/// Returns the rewritten query, or `None` if the query does not read `from`.
+pub fn rename_in_query(sql: &str, from: &str, to: &str) -> Result<Option<String>, RenameError> {
+    let dialect = GenericDialect {};
+    let mut statements = Parser::parse_sql(&dialect, sql).map_err(DependencyError::Sql)?;
+    let [Statement::Query(query)] = statements.as_mut_slice() else {
+        return Err(DependencyError::NotAQuery.into());
+    };
+    let to: Vec<&str> = to.split('.').collect();
+    Ok(rename_query(query, from, &to).then(|| query.to_string()))
+}
+
+/// Rewrite references to the table `from` in a metric-view YAML definition.
+///
+/// Both the view's `source` and the `source` of each join, nested joins
+/// included, are rewritten, whether they are bare three-part names or inline
+/// SQL. Returns the re-serialized
+/// definition, or `None` if the view does not read `from`.
+pub fn rename_in_metric_view(
+    yaml: &str,
+    from: &str,
+    to: &str,
+) -> Result<Option<String>, RenameError> {
+    let mut view: serde_yml::Value = serde_yml::from_str(yaml)?;
+    let changed = rename_source(view.get_mut("source"), from, to)?;
+    let changed = rename_joins(&mut view, from, to)? || changed;
+    Ok(if changed {
+        Some(serde_yml::to_string(&view)?)
+    } else {
+        None
+    })
+}
+
+/// Rewrite the `source` of every join under `parent` (the view or a join).
+fn rename_joins(parent: &mut serde_yml::Value, from: &str, to: &str) -> Result<bool, RenameError> {
+    let mut changed = false;
+    if let Some(serde_yml::Value::Sequence(joins)) = parent.get_mut("joins") {
+        for join in joins {
+            changed |= rename_source(join.get_mut("source"), from, to)?;
+            changed |= rename_joins(join, from, to)?;
+        }
+    }
+    Ok(changed)
+}
+
+/// Rewrite a single metric-view `source` value in place.
+fn rename_source(
+    source: Option<&mut serde_yml::Value>,
+    from: &str,
+    to: &str,
+) -> Result<bool, RenameError> {
+    let Some(serde_yml::Value::String(source)) = source else {
+        return Ok(false);
+    };
+    if source.trim().eq_ignore_ascii_case(from) {
+        *source = to.to_string();
+        return Ok(true);
+    }
+    // Bare names that don't match need no parsing; anything else is inline SQL.
+    if source.trim().split('.').all(is_plain_ident) {
+        return Ok(false);
+    }
+    match rename_in_query(source, from, to)? {
+        Some(rewritten) => {
+            *source = rewritten;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn is_plain_ident(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Walk the same `FROM` / `JOIN` relations as dependency derivation, renaming
+/// every reference to `from`.
+fn rename_query(query: &mut Query, from: &str, to: &[&str]) -> bool {
+    rename_set_expr(&mut query.body, from, to)
+}
+
+fn rename_set_expr(set_expr: &mut SetExpr, from: &str, to: &[&str]) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let mut changed = false;
+            for twj in &mut select.from {
+                changed |= rename_table_factor(&mut twj.relation, from, to);
+                for join in &mut twj.joins {
+                    changed |= rename_table_factor(&mut join.relation, from, to);
+                }
+            }
+            changed
+        }
+        SetExpr::Query(q) => rename_query(q, from, to),
+        SetExpr::SetOperation { left, right, .. } => {
+            let left = rename_set_expr(left, from, to);
+            rename_set_expr(right, from, to) || left
+        }
+        _ => false,
+    }
+}
+
+fn rename_table_factor(factor: &mut TableFactor, from: &str, to: &[&str]) -> bool {
+    match factor {
+        TableFactor::Table { name, .. } => rename_object_name(name, from, to),
+        TableFactor::Derived { subquery, .. } => rename_query(subquery, from, to),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => {
+            let mut changed = rename_table_factor(&mut table_with_joins.relation, from, to);
+            for join in &mut table_with_joins.joins {
+                changed |= rename_table_factor(&mut join.relation, from, to);
+            }
+            changed
+        }
+        _ => false,
+    }
+}
+
+/// Rename a three-part identifier naming `from`, keeping each part's quoting.
+fn rename_object_name(name: &mut ObjectName, from: &str, to: &[&str]) -> bool {
+    let parts: Vec<&str> = from.split('.').collect();
+    if name.0.len() != 3 || to.len() != 3 {
+        return false;
+    }
+    let matches = name.0.iter().zip(&parts).all(|(part, expected)| {
+        part.as_ident()
+            .is_some_and(|ident| ident.value.eq_ignore_ascii_case(expected))
+    });
+    if !matches {
+        return false;
+    }
+    for (part, new) in name.0.iter_mut().zip(to) {
+        if let ObjectNamePart::Identifier(ident) = part {
+            ident.value = new.to_string();
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric_view::{MetricView, dependencies, query_dependencies};
+    use crate::models::tables::v1::{Dependency, TableDependency};
+
+    #[test]
+    fn renames_dependency_case_insensitively() {
+        let mut list = DependencyList {
+            dependencies: vec![Dependency {
+                dependency: Some(dependency::Dependency::Table(TableDependency {
+                    table_full_name: "Main.Sales.Orders".into(),
+                })),
+            }],
+        };
+        assert!(rename_dependency(
+            &mut list,
+            "main.sales.orders",
+            "main.sales.orders_v2"
+        ));
+        assert!(!rename_dependency(&mut list, "main.sales.orders", "x.y.z"));
+        assert_eq!(
+            list,
+            query_dependencies("SELECT * FROM main.sales.orders_v2").unwrap()
+        );
+    }
+
+    #[test]
+    fn renames_query_relations() {
+        let sql = "SELECT o.id FROM main.sales.orders o JOIN main.sales.customers c ON o.c = c.id";
+        let rewritten = rename_in_query(sql, "main.sales.orders", "main.sales.orders_v2")
+            .unwrap()
+            .unwrap();
+        let deps = query_dependencies(&rewritten).unwrap();
+        assert_eq!(
+            deps,
+            query_dependencies(
+                "SELECT * FROM main.sales.orders_v2 JOIN main.sales.customers ON true"
+            )
+            .unwrap()
+        );
+        assert!(
+            rename_in_query(sql, "main.sales.other", "main.sales.x")
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn renames_metric_view_sources() {
+        let yaml = r#"
+version: "1.1"
+source: main.sales.orders
+joins:
+  - name: c
+    source: SELECT * FROM main.sales.orders WHERE active
+    on: source.id = c.id
+dimensions:
+  - name: id
+    expr: id
+measures:
+  - name: n
+    expr: COUNT(1)
+"#;
+        let rewritten = rename_in_metric_view(yaml, "main.sales.orders", "main.sales.orders_v2")
+            .unwrap()
+            .unwrap();
+        let view = MetricView::from_yaml(&rewritten).unwrap();
+        assert_eq!(view.source, "main.sales.orders_v2");
+        assert_eq!(view.dimensions.len(), 1);
+        let deps = dependencies(&view).unwrap();
+        assert_eq!(deps.dependencies.len(), 1);
+
+        assert!(
+            rename_in_metric_view(yaml, "main.sales.other", "main.sales.x")
+                .unwrap()
+                .is_none()
+        );
+    }
+}
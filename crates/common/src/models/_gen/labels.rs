@@ -1950,6 +1950,10 @@ pub static RESOURCE_DESCRIPTORS: &[::olai_store::ResourceTypeDescriptor<ObjectLa
                 name: "table_id",
                 role: ::olai_store::FieldRole::Identifier,
             },
+            ::olai_store::ResourceFieldDescriptor {
+                name: "statistics",
+                role: ::olai_store::FieldRole::Data,
+            },
         ],
         path_names: &["catalog_name", "schema_name", "name"],
         parent_label: Some(ObjectLabel::Catalog),
@@ -557,6 +557,111 @@ pub struct DeltaReportMetricsRequest {
     pub report: Option<DeltaReport>,
 }
 
+// ===================================================================
+// Maintenance (server extension, not part of delta.yaml)
+// ===================================================================
+
+/// The kind of a table-maintenance job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeltaMaintenanceJobKind {
+    /// Write a checkpoint so readers replay fewer commits.
+    Checkpoint,
+    /// Write a log-compaction file aggregating the commits since the last
+    /// checkpoint.
+    LogCompaction,
+    /// Rewrite small data files into fewer, larger ones.
+    Compaction,
+    /// Delete data files no longer referenced by the table.
+    Vacuum,
+}
+
+/// The lifecycle state of a table-maintenance job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeltaMaintenanceJobStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    /// The job was not applicable when it ran (e.g. nothing to compact).
+    Skipped,
+}
+
+/// A maintenance job scheduled for a managed table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeltaMaintenanceJob {
+    pub job_id: i64,
+    pub table_id: String,
+    pub kind: DeltaMaintenanceJobKind,
+    pub status: DeltaMaintenanceJobStatus,
+    /// Why the planner scheduled the job.
+    pub reason: String,
+    /// What the job did, or why it failed or was skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The table version the job committed, if it committed one. For
+    /// checkpoints and log compactions, the last version they cover.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committed_version: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_files_added: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_files_removed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_bytes_removed: Option<i64>,
+    /// Epoch millis.
+    pub created_at: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<i64>,
+}
+
+/// Response of the table maintenance-job history endpoint, newest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeltaListMaintenanceJobsResponse {
+    pub jobs: Vec<DeltaMaintenanceJob>,
+}
+
+// ===================================================================
+// Multi-table commits (server extension, not part of delta.yaml)
+// ===================================================================
+
+/// One table's part of a multi-table commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeltaTableCommit {
+    pub catalog: String,
+    pub schema: String,
+    pub table: String,
+    /// Must match the table's UUID, as for an `assert-table-uuid` requirement.
+    pub table_uuid: String,
+    pub commit: DeltaCommit,
+    /// Optionally record a backfill alongside the commit, as
+    /// `set-latest-backfilled-version` does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_published_version: Option<i64>,
+}
+
+/// Request to ratify one commit on each of several managed tables atomically:
+/// either every commit wins its version or none is recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeltaBatchCommitRequest {
+    pub commits: Vec<DeltaTableCommit>,
+}
+
+/// Response of a multi-table commit: each table as `loadTable` would return it
+/// after the commit, in request order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeltaBatchCommitResponse {
+    pub tables: Vec<DeltaLoadTableResponse>,
+}
+
 // ===================================================================
 // Errors (the /delta/v1 envelope)
 // ===================================================================
@@ -918,6 +1023,45 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn maintenance_jobs_response() {
+        round_trip::<DeltaListMaintenanceJobsResponse>(json!({
+            "jobs": [{
+                "job-id": 7,
+                "table-id": "123e4567-e89b-12d3-a456-426614174000",
+                "kind": "COMPACTION",
+                "status": "SUCCEEDED",
+                "reason": "12 files smaller than 8388608 bytes",
+                "committed-version": 9,
+                "num-files-added": 1,
+                "num-files-removed": 12,
+                "created-at": 1704067200000_i64,
+                "started-at": 1704067201000_i64,
+                "finished-at": 1704067202000_i64
+            }]
+        }));
+    }
+
+    #[test]
+    fn batch_commit_request() {
+        round_trip::<DeltaBatchCommitRequest>(json!({
+            "commits": [{
+                "catalog": "main",
+                "schema": "sales",
+                "table": "orders",
+                "table-uuid": "123e4567-e89b-12d3-a456-426614174000",
+                "commit": {
+                    "version": 4,
+                    "timestamp": 1704067200000_i64,
+                    "file-name": "00000000000000000004.uuid.json",
+                    "file-size": 512,
+                    "file-modification-timestamp": 1704067200000_i64
+                },
+                "latest-published-version": 3
+            }]
+        }));
+    }
+
     #[test]
     fn error_response_round_trips() {
         round_trip::<DeltaErrorResponse>(json!({
@@ -116,7 +116,8 @@ pub enum DataObjectType {
     Unspecified = 0,
     Table = 1,
     Schema = 2,
-    // VIEW = 3;
+    /// A SQL view, served to recipients from a materialized snapshot.
+    View = 3,
 
     // MATERIALIZED_VIEW = 4;
 
@@ -146,6 +147,7 @@ impl DataObjectType {
             Self::Unspecified => "DATA_OBJECT_TYPE_UNSPECIFIED",
             Self::Table => "TABLE",
             Self::Schema => "SCHEMA",
+            Self::View => "VIEW",
             Self::Volume => "VOLUME",
             Self::AgentSkill => "AGENT_SKILL",
         }
@@ -156,6 +158,7 @@ impl DataObjectType {
             "DATA_OBJECT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
             "TABLE" => Some(Self::Table),
             "SCHEMA" => Some(Self::Schema),
+            "VIEW" => Some(Self::View),
             "VOLUME" => Some(Self::Volume),
             "AGENT_SKILL" => Some(Self::AgentSkill),
             _ => None,
@@ -465,6 +465,7 @@ impl serde::Serialize for DataObjectType {
             Self::Unspecified => "DATA_OBJECT_TYPE_UNSPECIFIED",
             Self::Table => "TABLE",
             Self::Schema => "SCHEMA",
+            Self::View => "VIEW",
             Self::Volume => "VOLUME",
             Self::AgentSkill => "AGENT_SKILL",
         };
@@ -481,6 +482,7 @@ impl<'de> serde::Deserialize<'de> for DataObjectType {
             "DATA_OBJECT_TYPE_UNSPECIFIED",
             "TABLE",
             "SCHEMA",
+            "VIEW",
             "VOLUME",
             "AGENT_SKILL",
         ];
@@ -526,6 +528,7 @@ impl<'de> serde::Deserialize<'de> for DataObjectType {
                     "DATA_OBJECT_TYPE_UNSPECIFIED" => Ok(DataObjectType::Unspecified),
                     "TABLE" => Ok(DataObjectType::Table),
                     "SCHEMA" => Ok(DataObjectType::Schema),
+                    "VIEW" => Ok(DataObjectType::View),
                     "VOLUME" => Ok(DataObjectType::Volume),
                     "AGENT_SKILL" => Ok(DataObjectType::AgentSkill),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
@@ -93,6 +93,26 @@ pub struct DependencyList {
     #[prost(message, repeated, tag="1")]
     pub dependencies: ::prost::alloc::vec::Vec<Dependency>,
 }
+/// Size and shape of a Delta table at one version, derived from its snapshot.
+#[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TableStatistics {
+    /// Table version the statistics describe.
+    #[prost(int64, tag="1")]
+    pub version: i64,
+    /// Total size of the table's live data files, in bytes.
+    #[prost(int64, tag="2")]
+    pub size_in_bytes: i64,
+    /// Number of live data files.
+    #[prost(int64, tag="3")]
+    pub num_files: i64,
+    /// Number of rows. Omitted when some data file carries no row count.
+    #[prost(int64, optional, tag="4")]
+    pub num_rows: ::core::option::Option<i64>,
+    /// Time of the commit that produced the version, in epoch milliseconds.
+    #[prost(int64, tag="5")]
+    pub last_modified: i64,
+}
 #[cfg_attr(feature = "python", ::pyo3::pyclass(get_all, set_all))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Table {
@@ -166,6 +186,10 @@ pub struct Table {
     /// Unique identifier for the table.
     #[prost(string, optional, tag="23")]
     pub table_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Statistics of the latest table version. Only set for Delta tables, and
+    /// only when the request asks for delta metadata.
+    #[prost(message, optional, tag="24")]
+    pub statistics: ::core::option::Option<TableStatistics>,
 }
 /// The type of the table.
 #[cfg_attr(feature = "python", ::pyo3::pyclass)]
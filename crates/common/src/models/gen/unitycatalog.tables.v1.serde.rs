@@ -2296,6 +2296,9 @@ impl serde::Serialize for Table {
         if self.table_id.is_some() {
             len += 1;
         }
+        if self.statistics.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.Table", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -2367,6 +2370,9 @@ impl serde::Serialize for Table {
         if let Some(v) = self.table_id.as_ref() {
             struct_ser.serialize_field("table_id", v)?;
         }
+        if let Some(v) = self.statistics.as_ref() {
+            struct_ser.serialize_field("statistics", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2412,6 +2418,7 @@ impl<'de> serde::Deserialize<'de> for Table {
             "deletedAt",
             "table_id",
             "tableId",
+            "statistics",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2436,6 +2443,7 @@ impl<'de> serde::Deserialize<'de> for Table {
             UpdatedBy,
             DeletedAt,
             TableId,
+            Statistics,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2478,6 +2486,7 @@ impl<'de> serde::Deserialize<'de> for Table {
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "deletedAt" | "deleted_at" => Ok(GeneratedField::DeletedAt),
                             "tableId" | "table_id" => Ok(GeneratedField::TableId),
+                            "statistics" => Ok(GeneratedField::Statistics),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2517,6 +2526,7 @@ impl<'de> serde::Deserialize<'de> for Table {
                 let mut updated_by__ = None;
                 let mut deleted_at__ = None;
                 let mut table_id__ = None;
+                let mut statistics__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -2647,6 +2657,12 @@ impl<'de> serde::Deserialize<'de> for Table {
                             }
                             table_id__ = map_.next_value()?;
                         }
+                        GeneratedField::Statistics => {
+                            if statistics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statistics"));
+                            }
+                            statistics__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2673,6 +2689,7 @@ impl<'de> serde::Deserialize<'de> for Table {
                     updated_by: updated_by__,
                     deleted_at: deleted_at__,
                     table_id: table_id__,
+                    statistics: statistics__,
                 })
             }
         }
@@ -2775,6 +2792,193 @@ impl<'de> serde::Deserialize<'de> for TableDependency {
         deserializer.deserialize_struct("unitycatalog.tables.v1.TableDependency", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TableStatistics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.version != 0 {
+            len += 1;
+        }
+        if self.size_in_bytes != 0 {
+            len += 1;
+        }
+        if self.num_files != 0 {
+            len += 1;
+        }
+        if self.num_rows.is_some() {
+            len += 1;
+        }
+        if self.last_modified != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("unitycatalog.tables.v1.TableStatistics", len)?;
+        if self.version != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        if self.size_in_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("size_in_bytes", ToString::to_string(&self.size_in_bytes).as_str())?;
+        }
+        if self.num_files != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("num_files", ToString::to_string(&self.num_files).as_str())?;
+        }
+        if let Some(v) = self.num_rows.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("num_rows", ToString::to_string(&v).as_str())?;
+        }
+        if self.last_modified != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("last_modified", ToString::to_string(&self.last_modified).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TableStatistics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "size_in_bytes",
+            "sizeInBytes",
+            "num_files",
+            "numFiles",
+            "num_rows",
+            "numRows",
+            "last_modified",
+            "lastModified",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            SizeInBytes,
+            NumFiles,
+            NumRows,
+            LastModified,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "sizeInBytes" | "size_in_bytes" => Ok(GeneratedField::SizeInBytes),
+                            "numFiles" | "num_files" => Ok(GeneratedField::NumFiles),
+                            "numRows" | "num_rows" => Ok(GeneratedField::NumRows),
+                            "lastModified" | "last_modified" => Ok(GeneratedField::LastModified),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TableStatistics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct unitycatalog.tables.v1.TableStatistics")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TableStatistics, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut size_in_bytes__ = None;
+                let mut num_files__ = None;
+                let mut num_rows__ = None;
+                let mut last_modified__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SizeInBytes => {
+                            if size_in_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sizeInBytes"));
+                            }
+                            size_in_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NumFiles => {
+                            if num_files__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numFiles"));
+                            }
+                            num_files__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NumRows => {
+                            if num_rows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numRows"));
+                            }
+                            num_rows__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::LastModified => {
+                            if last_modified__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastModified"));
+                            }
+                            last_modified__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TableStatistics {
+                    version: version__.unwrap_or_default(),
+                    size_in_bytes: size_in_bytes__.unwrap_or_default(),
+                    num_files: num_files__.unwrap_or_default(),
+                    num_rows: num_rows__,
+                    last_modified: last_modified__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("unitycatalog.tables.v1.TableStatistics", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TableSummary {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
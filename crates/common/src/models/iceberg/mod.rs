@@ -0,0 +1,9 @@
+//! Hand-written serde models for the Iceberg REST Catalog API (`/iceberg/v1/...`).
+//!
+//! The server exposes UniForm-enabled Delta tables to Iceberg engines through
+//! the read-only subset of the Iceberg REST Catalog protocol. Like the Delta
+//! API, it is a standalone REST protocol rather than a generated resource API,
+//! so its wire types are hand-maintained here, where the server router and any
+//! client can share one definition.
+
+pub mod v1;
@@ -0,0 +1,184 @@
+//! Hand-written serde models for the Iceberg REST Catalog API (`/iceberg/v1/...`).
+//!
+//! These mirror the component schemas of the Apache Iceberg REST Catalog
+//! OpenAPI spec (`rest-catalog-open-api.yaml`) for the operations the server
+//! implements: `getConfig`, `listNamespaces`, `loadNamespaceMetadata`,
+//! `listTables` and `loadTable`. The wire format is kebab-case JSON. Table
+//! metadata is passed through verbatim from the `metadata.json` the UniForm
+//! writer produced, so it is kept as an untyped [`serde_json::Value`].
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Response from `getConfig`: catalog properties merged into the client's own.
+///
+/// `overrides.prefix` routes every later request to one catalog, so Iceberg
+/// clients address a Unity Catalog catalog through the `warehouse` they pass
+/// to `getConfig`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcebergCatalogConfig {
+    /// Properties used as defaults, overridden by the client's configuration.
+    pub defaults: BTreeMap<String, String>,
+    /// Properties that override the client's configuration.
+    pub overrides: BTreeMap<String, String>,
+    /// The endpoints the server supports, e.g. `GET /v1/{prefix}/namespaces`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<Vec<String>>,
+}
+
+/// Response from `listNamespaces`. A namespace is a list of levels; Unity
+/// Catalog schemas are always single-level.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergListNamespacesResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    pub namespaces: Vec<Vec<String>>,
+}
+
+/// Response from `loadNamespaceMetadata`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcebergGetNamespaceResponse {
+    pub namespace: Vec<String>,
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// A table's namespace and name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcebergTableIdentifier {
+    pub namespace: Vec<String>,
+    pub name: String,
+}
+
+/// Response from `listTables`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergListTablesResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    pub identifiers: Vec<IcebergTableIdentifier>,
+}
+
+/// Storage credentials for the paths under `prefix`, keyed by Iceberg FileIO
+/// property names (e.g. `s3.access-key-id`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcebergStorageCredential {
+    pub prefix: String,
+    pub config: BTreeMap<String, String>,
+}
+
+/// Response from `loadTable`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergLoadTableResult {
+    /// Location of the `metadata.json` the table metadata was read from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
+    /// The Iceberg table metadata.
+    pub metadata: serde_json::Value,
+    /// Table-specific FileIO configuration, including vended credentials.
+    #[serde(default)]
+    pub config: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_credentials: Option<Vec<IcebergStorageCredential>>,
+}
+
+// ===================================================================
+// Errors
+// ===================================================================
+
+/// The `type` of an Iceberg REST error. Iceberg clients map these onto their
+/// own exception classes, so the names follow the Java reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IcebergErrorType {
+    BadRequestException,
+    NotAuthorizedException,
+    ForbiddenException,
+    NotFoundException,
+    NoSuchNamespaceException,
+    NoSuchTableException,
+    UnsupportedOperationException,
+    ServiceUnavailableException,
+    InternalServerErrorException,
+}
+
+/// The JSON error payload (`ErrorModel`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IcebergErrorModel {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: IcebergErrorType,
+    /// HTTP response code.
+    pub code: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<String>>,
+}
+
+/// The JSON wrapper for all Iceberg REST error responses
+/// (`IcebergErrorResponse`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IcebergErrorResponse {
+    pub error: IcebergErrorModel,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn round_trip<T>(value: Value)
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let parsed: T = serde_json::from_value(value.clone())
+            .unwrap_or_else(|e| panic!("deserialize failed: {e}\njson: {value:#}"));
+        let reserialized = serde_json::to_value(&parsed).expect("serialize");
+        assert_eq!(reserialized, value);
+    }
+
+    #[test]
+    fn catalog_config() {
+        round_trip::<IcebergCatalogConfig>(json!({
+            "defaults": {},
+            "overrides": { "prefix": "catalogs/main" },
+            "endpoints": ["GET /v1/{prefix}/namespaces"]
+        }));
+    }
+
+    #[test]
+    fn list_responses() {
+        round_trip::<IcebergListNamespacesResponse>(json!({
+            "next-page-token": "abc",
+            "namespaces": [["sales"], ["ops"]]
+        }));
+        round_trip::<IcebergListTablesResponse>(json!({
+            "identifiers": [{ "namespace": ["sales"], "name": "orders" }]
+        }));
+    }
+
+    #[test]
+    fn load_table_result() {
+        round_trip::<IcebergLoadTableResult>(json!({
+            "metadata-location": "s3://b/t/metadata/v2.metadata.json",
+            "metadata": { "format-version": 2, "table-uuid": "u" },
+            "config": { "s3.access-key-id": "AK" },
+            "storage-credentials": [{
+                "prefix": "s3://b/t",
+                "config": { "s3.access-key-id": "AK" }
+            }]
+        }));
+    }
+
+    #[test]
+    fn error_response() {
+        round_trip::<IcebergErrorResponse>(json!({
+            "error": {
+                "message": "namespace not found",
+                "type": "NoSuchNamespaceException",
+                "code": 404
+            }
+        }));
+    }
+}
@@ -2,8 +2,11 @@ mod _gen;
 mod association;
 pub mod delta;
 mod error;
+pub mod iceberg;
 mod object;
+pub mod permissions;
 mod resources;
+pub mod table_history;
 
 pub use _gen::*;
 pub use association::AssociationLabel;
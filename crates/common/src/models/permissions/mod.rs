@@ -0,0 +1,9 @@
+//! Hand-written serde models for the permissions API
+//! (`GET`/`PATCH /permissions/{securable_type}/{full_name}`).
+//!
+//! Permissions are an access-control surface over every securable rather than
+//! a generated resource API, so the request and response envelopes are
+//! maintained by hand here, where the server router and the client share one
+//! definition. The per-principal entries are the shares API's proto messages.
+
+pub mod v1;
@@ -0,0 +1,56 @@
+//! Serde models for the permissions API.
+//!
+//! The wire format is snake_case JSON, like the rest of the Unity Catalog REST
+//! API. Privileges are carried as their upper-case wire names with underscores
+//! (`USE_CATALOG`, `SELECT`, `ALL_PRIVILEGES`, …) rather than a closed enum, so
+//! a client does not reject privileges a newer server introduces.
+
+use serde::{Deserialize, Serialize};
+
+/// The per-principal entries have the same shape as the shares permissions
+/// API, so the proto messages (and their generated serde) are reused.
+pub use crate::models::shares::v1::{PermissionsChange, PrivilegeAssignment};
+
+/// Response from `GET /permissions/{securable_type}/{full_name}`, and from the
+/// `PATCH` that updates them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionsList {
+    #[serde(default)]
+    pub privilege_assignments: Vec<PrivilegeAssignment>,
+}
+
+/// Body of `PATCH /permissions/{securable_type}/{full_name}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdatePermissions {
+    pub changes: Vec<PermissionsChange>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn permissions_round_trip() {
+        let value = json!({
+            "privilege_assignments": [
+                { "principal": "analysts", "privileges": ["USE_CATALOG", "SELECT"] }
+            ]
+        });
+        let parsed: PermissionsList = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.privilege_assignments[0].privileges.len(), 2);
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+
+        let update = UpdatePermissions {
+            changes: vec![PermissionsChange {
+                principal: "analysts".to_string(),
+                add: vec!["SELECT".to_string()],
+                remove: vec![],
+            }],
+        };
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "changes": [{ "principal": "analysts", "add": ["SELECT"] }] })
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! Hand-written serde models for the table history API
+//! (`GET /tables/{full_name}/history`).
+//!
+//! The history endpoint is a read-only inspection surface over a table's Delta
+//! log rather than a generated resource API, so its wire types are maintained
+//! by hand here, where the server router and the client share one definition.
+
+pub mod v1;
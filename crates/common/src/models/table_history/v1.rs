@@ -0,0 +1,95 @@
+//! Hand-written serde models for the table history API.
+//!
+//! The wire format is snake_case JSON, like the rest of the Unity Catalog REST
+//! API. Each [`TableHistoryEntry`] describes one commit of a Delta table, taken
+//! from the `commitInfo` action of its log entry. Commits that the catalog has
+//! ratified but that have not been published to `_delta_log/<version>.json`
+//! yet are included and flagged with `published: false`.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Query for `GET /tables/{full_name}/history`.
+///
+/// Entries are returned newest first. `start_version` and `end_version` bound
+/// the returned versions (both inclusive); `page_token` continues a previous
+/// listing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetTableHistoryRequest {
+    /// Full name of the table, `catalog.schema.table`.
+    pub full_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_version: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_version: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<String>,
+}
+
+/// One commit in a table's history.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableHistoryEntry {
+    /// The table version the commit produced.
+    pub version: i64,
+    /// Commit time in milliseconds since the epoch. The in-commit timestamp
+    /// when the writer recorded one, else the commit's file timestamp.
+    pub timestamp: i64,
+    /// The operation the writer reported, e.g. `WRITE` or `MERGE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub operation_parameters: BTreeMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub operation_metrics: BTreeMap<String, serde_json::Value>,
+    /// The engine that wrote the commit, e.g. `Apache-Spark/3.5.0 Delta-Lake/3.2.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine_info: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    /// Whether the commit has been published to `_delta_log/<version>.json`.
+    /// `false` for commits the catalog ratified that are not published yet.
+    pub published: bool,
+}
+
+/// Response from `GET /tables/{full_name}/history`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GetTableHistoryResponse {
+    pub entries: Vec<TableHistoryEntry>,
+    /// Token for the next page; absent on the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn history_response_round_trips() {
+        let value = json!({
+            "entries": [
+                {
+                    "version": 2,
+                    "timestamp": 1700000000000i64,
+                    "operation": "WRITE",
+                    "operation_parameters": { "mode": "Append" },
+                    "operation_metrics": { "numOutputRows": "10" },
+                    "engine_info": "Apache-Spark/3.5.0",
+                    "published": false
+                },
+                { "version": 1, "timestamp": 1699999999000i64, "published": true }
+            ],
+            "next_page_token": "0"
+        });
+        let parsed: GetTableHistoryResponse = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.entries.len(), 2);
+        assert!(!parsed.entries[0].published);
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    }
+}
@@ -25,12 +25,25 @@
 //! 5. There is a cap on unbackfilled commits per table (OSS hardcodes 10); exceeding
 //!    it rejects the commit with a resource-exhausted (429) error.
 //!
+//! [`commit_batch`](CommitCoordinator::commit_batch) ratifies one commit on each
+//! of several tables at once: every commit is checked against the same rules as
+//! a single-table commit, and either all of them are recorded or none is. This
+//! is an extension over UC OSS for writers that must keep several tables (e.g. a
+//! fact table and its dimensions) in step.
+//!
+//! Ratified commits are published (copied to `_delta_log/<version>.json`) either
+//! by the writer or by a server-side publisher. Publishers running on several
+//! server replicas coordinate through a per-table *backfill lease*
+//! ([`acquire_backfill_lease`](CommitCoordinator::acquire_backfill_lease)), so
+//! only one of them works on a given table at a time.
+//!
 //! Wire-format note: this matches the *shipped* UC OSS server, not the prose
 //! `ManagedTablesSpec.md`, which disagree in places (path `/delta/preview/commits`
 //! vs `/delta/commit`, field `latest_backfilled_version` vs `latest_published_version`).
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 use crate::models::delta_commits::v1::CommitInfo;
 
@@ -63,9 +76,34 @@ pub enum CommitError {
     Backend(String),
 }
 
+impl CommitError {
+    /// Prefix the message with the table it concerns, keeping the variant (and
+    /// thus the status). Used to attribute a failed batch commit.
+    pub fn for_table(self, table_id: &str) -> Self {
+        match self {
+            Self::VersionConflict(msg) => Self::VersionConflict(format!("table {table_id}: {msg}")),
+            Self::InvalidArgument(msg) => Self::InvalidArgument(format!("table {table_id}: {msg}")),
+            Self::ResourceExhausted(msg) => {
+                Self::ResourceExhausted(format!("table {table_id}: {msg}"))
+            }
+            Self::Backend(msg) => Self::Backend(format!("table {table_id}: {msg}")),
+        }
+    }
+}
+
 /// Result type for commit-coordinator operations.
 pub type CommitResult<T> = Result<T, CommitError>;
 
+/// One table's part of a [`commit_batch`](CommitCoordinator::commit_batch).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCommit {
+    pub table_id: String,
+    pub commit_info: CommitInfo,
+    /// An optional backfill notification piggy-backed on the commit, as for
+    /// [`commit`](CommitCoordinator::commit).
+    pub latest_backfilled_version: Option<i64>,
+}
+
 /// Backend-agnostic Delta commit coordinator.
 ///
 /// Implementations persist ratified commits per table and arbitrate the
@@ -83,6 +121,14 @@ pub trait CommitCoordinator: Send + Sync + 'static {
         latest_backfilled_version: Option<i64>,
     ) -> CommitResult<()>;
 
+    /// Ratify one commit per table atomically: either every commit wins its
+    /// version or none is recorded.
+    ///
+    /// Each commit is subject to the same rules as [`commit`](Self::commit); the
+    /// first failure aborts the batch and is returned attributed to its table.
+    /// A batch must be non-empty and name each table at most once.
+    async fn commit_batch(&self, commits: Vec<TableCommit>) -> CommitResult<()>;
+
     /// Return ratified-but-unpublished commits for `table_id` in
     /// `[start_version, end_version]`, plus `latest_table_version`.
     ///
@@ -95,6 +141,28 @@ pub trait CommitCoordinator: Send + Sync + 'static {
         start_version: i64,
         end_version: Option<i64>,
     ) -> CommitResult<(Vec<CommitInfo>, i64)>;
+
+    /// Take or renew the backfill lease on `table_id` for `owner`.
+    ///
+    /// Returns `true` if `owner` now holds the lease for the next `ttl`, and
+    /// `false` if another owner holds an unexpired lease. The default grants
+    /// every request, which is only correct for backends served by a single
+    /// process.
+    async fn acquire_backfill_lease(
+        &self,
+        table_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        let _ = (table_id, owner, ttl);
+        Ok(true)
+    }
+
+    /// Give up the backfill lease on `table_id` if `owner` holds it.
+    async fn release_backfill_lease(&self, table_id: &str, owner: &str) -> CommitResult<()> {
+        let _ = (table_id, owner);
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -110,6 +178,10 @@ impl<T: CommitCoordinator> CommitCoordinator for Arc<T> {
             .await
     }
 
+    async fn commit_batch(&self, commits: Vec<TableCommit>) -> CommitResult<()> {
+        self.as_ref().commit_batch(commits).await
+    }
+
     async fn get_commits(
         &self,
         table_id: &str,
@@ -120,6 +192,21 @@ impl<T: CommitCoordinator> CommitCoordinator for Arc<T> {
             .get_commits(table_id, start_version, end_version)
             .await
     }
+
+    async fn acquire_backfill_lease(
+        &self,
+        table_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        self.as_ref()
+            .acquire_backfill_lease(table_id, owner, ttl)
+            .await
+    }
+
+    async fn release_backfill_lease(&self, table_id: &str, owner: &str) -> CommitResult<()> {
+        self.as_ref().release_backfill_lease(table_id, owner).await
+    }
 }
 
 /// Auxiliary trait for handlers that carry a [`CommitCoordinator`].
@@ -158,6 +245,27 @@ pub fn validate_commit_info(info: &CommitInfo) -> CommitResult<()> {
     Ok(())
 }
 
+/// Validate a batch commit request and sort it by table id, the order backends
+/// take their per-table locks in. Shared by all backends.
+pub fn validate_batch(commits: &mut [TableCommit]) -> CommitResult<()> {
+    if commits.is_empty() {
+        return Err(CommitError::InvalidArgument(
+            "a batch commit needs at least one commit".to_string(),
+        ));
+    }
+    commits.sort_by(|a, b| a.table_id.cmp(&b.table_id));
+    if let Some(pair) = commits.windows(2).find(|w| w[0].table_id == w[1].table_id) {
+        return Err(CommitError::InvalidArgument(format!(
+            "table {} appears more than once in the batch",
+            pair[0].table_id
+        )));
+    }
+    for commit in commits.iter() {
+        validate_commit_info(&commit.commit_info).map_err(|e| e.for_table(&commit.table_id))?;
+    }
+    Ok(())
+}
+
 /// A ratified commit plus the marker flag used during backfill.
 #[derive(Debug, Clone)]
 struct StoredCommit {
@@ -192,6 +300,8 @@ impl TableCommitState {
 #[derive(Debug)]
 pub struct InMemoryCommitCoordinator {
     tables: RwLock<HashMap<String, Arc<Mutex<TableCommitState>>>>,
+    /// Backfill leases: table id -> (owner, expiry).
+    leases: Mutex<HashMap<String, (String, Instant)>>,
     max_unbackfilled_commits: i64,
 }
 
@@ -206,6 +316,7 @@ impl InMemoryCommitCoordinator {
     pub fn new(max_unbackfilled_commits: i64) -> Self {
         Self {
             tables: RwLock::new(HashMap::new()),
+            leases: Mutex::new(HashMap::new()),
             max_unbackfilled_commits,
         }
     }
@@ -249,26 +360,24 @@ impl CommitCoordinator for InMemoryCommitCoordinator {
         let entry = self.table_state(table_id);
         let mut state = entry.lock().expect("commit state mutex poisoned");
 
-        match (state.last_version(), commit_info) {
-            // No commits yet.
-            (None, Some(info)) => {
-                // Onboarding commit: accept the first posted commit as-is.
-                let version = info.version;
-                state.commits.insert(
-                    version,
-                    StoredCommit {
-                        info,
-                        is_backfilled_latest: false,
-                    },
-                );
+        match commit_info {
+            Some(info) => {
+                check_commit(
+                    &state,
+                    &info,
+                    latest_backfilled_version,
+                    self.max_unbackfilled_commits,
+                )?;
+                apply_commit(&mut state, info, latest_backfilled_version);
                 Ok(())
             }
-            (None, None) => Err(CommitError::InvalidArgument(
-                "cannot backfill a table with no commits".to_string(),
-            )),
-
             // Backfill-only notification.
-            (Some(last), None) => {
+            None => {
+                let Some(last) = state.last_version() else {
+                    return Err(CommitError::InvalidArgument(
+                        "cannot backfill a table with no commits".to_string(),
+                    ));
+                };
                 let lbv = latest_backfilled_version.expect("checked above");
                 if lbv > last {
                     return Err(CommitError::InvalidArgument(format!(
@@ -278,52 +387,35 @@ impl CommitCoordinator for InMemoryCommitCoordinator {
                 backfill(&mut state, lbv);
                 Ok(())
             }
+        }
+    }
 
-            // Normal commit to an existing table.
-            (Some(last), Some(info)) => {
-                let version = info.version;
-                if version <= last {
-                    return Err(CommitError::VersionConflict(format!(
-                        "commit version {version} was already accepted; current table version is {last}"
-                    )));
-                }
-                if version > last + 1 {
-                    return Err(CommitError::InvalidArgument(format!(
-                        "commit version must be the next version after {last}, but got {version}"
-                    )));
-                }
-                if let Some(lbv) = latest_backfilled_version
-                    && lbv > last
-                {
-                    return Err(CommitError::InvalidArgument(format!(
-                        "latest_backfilled_version {lbv} is greater than the latest commit {last}"
-                    )));
-                }
-
-                // Enforce the unbackfilled-commit cap. The effective backfilled
-                // watermark accounts for a backfill piggy-backed on this request.
-                let eff_backfill = effective_backfilled_version(&state, latest_backfilled_version);
-                let expected_count = version - (eff_backfill + 1) + 1;
-                if expected_count > self.max_unbackfilled_commits {
-                    return Err(CommitError::ResourceExhausted(format!(
-                        "max number of unbackfilled commits per table reached: {} (limit {})",
-                        expected_count, self.max_unbackfilled_commits
-                    )));
-                }
+    async fn commit_batch(&self, mut commits: Vec<TableCommit>) -> CommitResult<()> {
+        validate_batch(&mut commits)?;
 
-                state.commits.insert(
-                    version,
-                    StoredCommit {
-                        info,
-                        is_backfilled_latest: false,
-                    },
-                );
-                if let Some(lbv) = latest_backfilled_version {
-                    backfill(&mut state, lbv);
-                }
-                Ok(())
-            }
+        // Lock every table in id order (the batch is sorted), check all commits,
+        // and only then apply them, so a failure leaves every table untouched.
+        let entries: Vec<_> = commits
+            .iter()
+            .map(|c| self.table_state(&c.table_id))
+            .collect();
+        let mut states: Vec<_> = entries
+            .iter()
+            .map(|e| e.lock().expect("commit state mutex poisoned"))
+            .collect();
+        for (state, commit) in states.iter().zip(&commits) {
+            check_commit(
+                state,
+                &commit.commit_info,
+                commit.latest_backfilled_version,
+                self.max_unbackfilled_commits,
+            )
+            .map_err(|e| e.for_table(&commit.table_id))?;
+        }
+        for (state, commit) in states.iter_mut().zip(commits) {
+            apply_commit(state, commit.commit_info, commit.latest_backfilled_version);
         }
+        Ok(())
     }
 
     async fn get_commits(
@@ -384,6 +476,98 @@ impl CommitCoordinator for InMemoryCommitCoordinator {
 
         Ok((commits, latest_table_version))
     }
+
+    async fn acquire_backfill_lease(
+        &self,
+        table_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        let now = Instant::now();
+        let mut leases = self.leases.lock().expect("lease mutex poisoned");
+        match leases.get(table_id) {
+            Some((holder, expires_at)) if holder != owner && *expires_at > now => Ok(false),
+            _ => {
+                leases.insert(table_id.to_string(), (owner.to_string(), now + ttl));
+                Ok(true)
+            }
+        }
+    }
+
+    async fn release_backfill_lease(&self, table_id: &str, owner: &str) -> CommitResult<()> {
+        let mut leases = self.leases.lock().expect("lease mutex poisoned");
+        if leases
+            .get(table_id)
+            .is_some_and(|(holder, _)| holder == owner)
+        {
+            leases.remove(table_id);
+        }
+        Ok(())
+    }
+}
+
+/// Check that `info` can be ratified as the next version of the table, without
+/// changing any state. Port of the checks in UC OSS `handleNormalCommit`; the
+/// first posted commit (onboarding) is accepted as-is.
+fn check_commit(
+    state: &TableCommitState,
+    info: &CommitInfo,
+    latest_backfilled_version: Option<i64>,
+    max_unbackfilled_commits: i64,
+) -> CommitResult<()> {
+    let Some(last) = state.last_version() else {
+        return Ok(());
+    };
+    let version = info.version;
+    if version <= last {
+        return Err(CommitError::VersionConflict(format!(
+            "commit version {version} was already accepted; current table version is {last}"
+        )));
+    }
+    if version > last + 1 {
+        return Err(CommitError::InvalidArgument(format!(
+            "commit version must be the next version after {last}, but got {version}"
+        )));
+    }
+    if let Some(lbv) = latest_backfilled_version
+        && lbv > last
+    {
+        return Err(CommitError::InvalidArgument(format!(
+            "latest_backfilled_version {lbv} is greater than the latest commit {last}"
+        )));
+    }
+
+    // Enforce the unbackfilled-commit cap. The effective backfilled
+    // watermark accounts for a backfill piggy-backed on this request.
+    let eff_backfill = effective_backfilled_version(state, latest_backfilled_version);
+    let expected_count = version - (eff_backfill + 1) + 1;
+    if expected_count > max_unbackfilled_commits {
+        return Err(CommitError::ResourceExhausted(format!(
+            "max number of unbackfilled commits per table reached: {} (limit {})",
+            expected_count, max_unbackfilled_commits
+        )));
+    }
+    Ok(())
+}
+
+/// Record a commit that passed [`check_commit`], plus any piggy-backed
+/// backfill. An onboarding commit ignores the backfill, as in UC OSS.
+fn apply_commit(
+    state: &mut TableCommitState,
+    info: CommitInfo,
+    latest_backfilled_version: Option<i64>,
+) {
+    let onboarding = state.commits.is_empty();
+    state.commits.insert(
+        info.version,
+        StoredCommit {
+            info,
+            is_backfilled_latest: false,
+        },
+    );
+    if !onboarding && let Some(lbv) = latest_backfilled_version {
+        backfill(state, lbv);
+    }
 }
 
 /// Backfill commits up to `up_to`, preserving the highest-version row.
@@ -642,4 +826,80 @@ mod tests {
         assert_eq!(wins, 1, "exactly one writer wins version 2");
         assert_eq!(conflicts, 15, "all other writers conflict");
     }
+
+    fn table_commit(table_id: &str, version: i64) -> TableCommit {
+        TableCommit {
+            table_id: table_id.to_string(),
+            commit_info: commit_info(version),
+            latest_backfilled_version: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_commit_ratifies_every_table() {
+        let cc = InMemoryCommitCoordinator::default();
+        cc.commit("fact", Some(commit_info(1)), None).await.unwrap();
+        cc.commit_batch(vec![table_commit("fact", 2), table_commit("dim", 1)])
+            .await
+            .unwrap();
+        assert_eq!(cc.get_commits("fact", 0, None).await.unwrap().1, 2);
+        assert_eq!(cc.get_commits("dim", 0, None).await.unwrap().1, 1);
+    }
+
+    #[tokio::test]
+    async fn batch_commit_is_all_or_nothing() {
+        let cc = InMemoryCommitCoordinator::default();
+        cc.commit("fact", Some(commit_info(1)), None).await.unwrap();
+        cc.commit("dim", Some(commit_info(1)), None).await.unwrap();
+
+        // `dim` already has version 1, so `fact` v2 must not be recorded either.
+        let err = cc
+            .commit_batch(vec![table_commit("fact", 2), table_commit("dim", 1)])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CommitError::VersionConflict(ref m) if m.contains("dim")));
+        assert_eq!(cc.get_commits("fact", 0, None).await.unwrap().1, 1);
+    }
+
+    #[tokio::test]
+    async fn batch_commit_rejects_empty_and_duplicate_tables() {
+        let cc = InMemoryCommitCoordinator::default();
+        assert!(matches!(
+            cc.commit_batch(vec![]).await.unwrap_err(),
+            CommitError::InvalidArgument(_)
+        ));
+        assert!(matches!(
+            cc.commit_batch(vec![table_commit("t", 1), table_commit("t", 2)])
+                .await
+                .unwrap_err(),
+            CommitError::InvalidArgument(_)
+        ));
+        assert_eq!(cc.get_commits("t", 0, None).await.unwrap().1, 0);
+    }
+
+    #[tokio::test]
+    async fn backfill_lease_is_exclusive_until_released_or_expired() {
+        let cc = InMemoryCommitCoordinator::default();
+        let ttl = Duration::from_secs(60);
+        assert!(cc.acquire_backfill_lease("t", "a", ttl).await.unwrap());
+        // The holder may renew; another owner is turned away.
+        assert!(cc.acquire_backfill_lease("t", "a", ttl).await.unwrap());
+        assert!(!cc.acquire_backfill_lease("t", "b", ttl).await.unwrap());
+        // Leases are per table.
+        assert!(cc.acquire_backfill_lease("u", "b", ttl).await.unwrap());
+
+        // Only the holder can release.
+        cc.release_backfill_lease("t", "b").await.unwrap();
+        assert!(!cc.acquire_backfill_lease("t", "b", ttl).await.unwrap());
+        cc.release_backfill_lease("t", "a").await.unwrap();
+        assert!(cc.acquire_backfill_lease("t", "b", ttl).await.unwrap());
+
+        // An expired lease can be taken over.
+        assert!(
+            cc.acquire_backfill_lease("v", "a", Duration::ZERO)
+                .await
+                .unwrap()
+        );
+        assert!(cc.acquire_backfill_lease("v", "b", ttl).await.unwrap());
+    }
 }
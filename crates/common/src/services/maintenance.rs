@@ -0,0 +1,682 @@
+//! Table-maintenance bookkeeping for managed Delta tables.
+//!
+//! Writers report commit metrics through the Delta API's `report_metrics` after
+//! each commit: how many files and bytes were added or removed, and the table's
+//! file-size histogram. [`MaintenanceStore`] persists those reports per table,
+//! together with the maintenance jobs scheduled from them.
+//!
+//! A [`MaintenancePlanner`] turns a table's [`MaintenanceState`] — the reports
+//! received since each kind of job last succeeded, and the job history — into
+//! jobs: a checkpoint once enough commits accumulated, a compaction once the
+//! table has many small files, a vacuum once enough files were removed.
+//! [`ThresholdPlanner`] is the default. Running the jobs is up to the server.
+//!
+//! [`InMemoryMaintenanceStore`] is the default store; a Postgres-backed one
+//! lives in `unitycatalog-postgres`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::models::delta::v1::{
+    DeltaCommitReport, DeltaMaintenanceJob, DeltaMaintenanceJobKind, DeltaMaintenanceJobStatus,
+};
+
+/// Error returned by a [`MaintenanceStore`].
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceError {
+    /// The job to update does not exist.
+    #[error("maintenance job {0} not found")]
+    JobNotFound(i64),
+
+    /// An unexpected backend error (e.g. database failure).
+    #[error("maintenance store backend error: {0}")]
+    Backend(String),
+}
+
+/// Result type for maintenance-store operations.
+pub type MaintenanceResult<T> = Result<T, MaintenanceError>;
+
+/// A commit report received for a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredCommitReport {
+    pub table_id: String,
+    /// When the report was received, in epoch millis.
+    pub reported_at: i64,
+    pub report: DeltaCommitReport,
+}
+
+/// Persistence for commit reports and maintenance jobs.
+#[async_trait::async_trait]
+pub trait MaintenanceStore: Send + Sync + 'static {
+    /// Persist a commit report for `table_id`.
+    async fn record_report(
+        &self,
+        table_id: &str,
+        report: DeltaCommitReport,
+    ) -> MaintenanceResult<()>;
+
+    /// Reports for `table_id` received after `since` (epoch millis), oldest first.
+    async fn list_reports(
+        &self,
+        table_id: &str,
+        since: Option<i64>,
+    ) -> MaintenanceResult<Vec<StoredCommitReport>>;
+
+    /// Drop reports for `table_id` received before `before` (epoch millis).
+    async fn prune_reports(&self, table_id: &str, before: i64) -> MaintenanceResult<()>;
+
+    /// Schedule a pending job of `kind` for `table_id`.
+    async fn create_job(
+        &self,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        reason: String,
+    ) -> MaintenanceResult<DeltaMaintenanceJob>;
+
+    /// Persist the mutable fields (status, outcome, timestamps) of `job`.
+    async fn update_job(&self, job: &DeltaMaintenanceJob) -> MaintenanceResult<()>;
+
+    /// Jobs for `table_id`, newest first, at most `limit`.
+    async fn list_jobs(
+        &self,
+        table_id: &str,
+        limit: Option<usize>,
+    ) -> MaintenanceResult<Vec<DeltaMaintenanceJob>>;
+}
+
+#[async_trait::async_trait]
+impl<T: MaintenanceStore> MaintenanceStore for Arc<T> {
+    async fn record_report(
+        &self,
+        table_id: &str,
+        report: DeltaCommitReport,
+    ) -> MaintenanceResult<()> {
+        self.as_ref().record_report(table_id, report).await
+    }
+
+    async fn list_reports(
+        &self,
+        table_id: &str,
+        since: Option<i64>,
+    ) -> MaintenanceResult<Vec<StoredCommitReport>> {
+        self.as_ref().list_reports(table_id, since).await
+    }
+
+    async fn prune_reports(&self, table_id: &str, before: i64) -> MaintenanceResult<()> {
+        self.as_ref().prune_reports(table_id, before).await
+    }
+
+    async fn create_job(
+        &self,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        reason: String,
+    ) -> MaintenanceResult<DeltaMaintenanceJob> {
+        self.as_ref().create_job(table_id, kind, reason).await
+    }
+
+    async fn update_job(&self, job: &DeltaMaintenanceJob) -> MaintenanceResult<()> {
+        self.as_ref().update_job(job).await
+    }
+
+    async fn list_jobs(
+        &self,
+        table_id: &str,
+        limit: Option<usize>,
+    ) -> MaintenanceResult<Vec<DeltaMaintenanceJob>> {
+        self.as_ref().list_jobs(table_id, limit).await
+    }
+}
+
+/// Auxiliary trait for handlers that carry a [`MaintenanceStore`].
+pub trait ProvidesMaintenanceStore: Send + Sync + 'static {
+    fn maintenance_store(&self) -> &dyn MaintenanceStore;
+}
+
+/// The current time in epoch millis.
+pub fn now_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// The maintenance history of one table, as seen by a [`MaintenancePlanner`].
+#[derive(Debug, Clone, Copy)]
+pub struct MaintenanceState<'a> {
+    /// Commit reports, oldest first. Must cover at least every report since
+    /// the oldest last success among the job kinds.
+    pub reports: &'a [StoredCommitReport],
+    /// Jobs, newest first.
+    pub jobs: &'a [DeltaMaintenanceJob],
+    /// The table's latest ratified version, if known.
+    pub latest_version: Option<i64>,
+}
+
+impl MaintenanceState<'_> {
+    /// When a job of `kind` last succeeded, in epoch millis.
+    pub fn last_success(&self, kind: DeltaMaintenanceJobKind) -> Option<i64> {
+        self.jobs
+            .iter()
+            .filter(|j| j.kind == kind && j.status == DeltaMaintenanceJobStatus::Succeeded)
+            .filter_map(|j| j.finished_at)
+            .max()
+    }
+
+    /// The version covered by the newest successful job of `kind`, e.g. the
+    /// version of the last checkpoint.
+    pub fn last_version(&self, kind: DeltaMaintenanceJobKind) -> Option<i64> {
+        self.jobs
+            .iter()
+            .filter(|j| j.kind == kind && j.status == DeltaMaintenanceJobStatus::Succeeded)
+            .find_map(|j| j.committed_version)
+    }
+
+    /// Whether a job of `kind` is pending or running.
+    pub fn has_open_job(&self, kind: DeltaMaintenanceJobKind) -> bool {
+        self.jobs.iter().any(|j| {
+            j.kind == kind
+                && matches!(
+                    j.status,
+                    DeltaMaintenanceJobStatus::Pending | DeltaMaintenanceJobStatus::Running
+                )
+        })
+    }
+
+    /// Reports received since a job of `kind` last succeeded.
+    pub fn reports_since(
+        &self,
+        kind: DeltaMaintenanceJobKind,
+    ) -> impl Iterator<Item = &StoredCommitReport> {
+        let since = self.last_success(kind);
+        self.reports
+            .iter()
+            .filter(move |r| since.is_none_or(|since| r.reported_at > since))
+    }
+
+    /// The oldest report any planner decision can still depend on: the oldest
+    /// last success across the report-driven job kinds. `None` while some kind
+    /// never ran.
+    pub fn oldest_relevant_report(&self) -> Option<i64> {
+        [
+            DeltaMaintenanceJobKind::Checkpoint,
+            DeltaMaintenanceJobKind::Compaction,
+            DeltaMaintenanceJobKind::Vacuum,
+        ]
+        .into_iter()
+        .map(|kind| self.last_success(kind))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .min()
+    }
+}
+
+/// A job a [`MaintenancePlanner`] wants scheduled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedJob {
+    pub kind: DeltaMaintenanceJobKind,
+    /// Human-readable reason, recorded on the job.
+    pub reason: String,
+}
+
+/// Decides which maintenance a table needs.
+///
+/// Implementations should not plan a kind that already has an open job (see
+/// [`MaintenanceState::has_open_job`]).
+pub trait MaintenancePlanner: Send + Sync + 'static {
+    fn plan(&self, state: &MaintenanceState<'_>) -> Vec<PlannedJob>;
+}
+
+/// Plans jobs when simple per-kind thresholds are crossed.
+#[derive(Debug, Clone)]
+pub struct ThresholdPlanner {
+    /// Commits since the last checkpoint that trigger a new one. Matches the
+    /// Delta default `delta.checkpointInterval`.
+    pub checkpoint_interval: usize,
+    /// Commits since the last checkpoint or log compaction that trigger a
+    /// log compaction. `None` disables log compaction.
+    pub log_compaction_interval: Option<usize>,
+    /// Files smaller than this many bytes count as small.
+    pub small_file_bytes: i64,
+    /// Small files that trigger a compaction.
+    pub min_small_files: i64,
+    /// Files removed since the last vacuum that trigger a new one.
+    pub vacuum_removed_files: i64,
+}
+
+impl Default for ThresholdPlanner {
+    fn default() -> Self {
+        Self {
+            checkpoint_interval: 10,
+            log_compaction_interval: None,
+            small_file_bytes: 32 * 1024 * 1024,
+            min_small_files: 16,
+            vacuum_removed_files: 100,
+        }
+    }
+}
+
+impl ThresholdPlanner {
+    /// Commits since the last checkpoint.
+    ///
+    /// Counted from the table version when both it and the last checkpoint's
+    /// version are known, so writers that do not report metrics still count.
+    /// A table never checkpointed by the server counts all its versions; the
+    /// executor recognizes checkpoints written by clients.
+    fn commits_since_checkpoint(&self, state: &MaintenanceState<'_>) -> usize {
+        let kind = DeltaMaintenanceJobKind::Checkpoint;
+        match (state.latest_version, state.last_version(kind)) {
+            (Some(latest), Some(checkpoint)) => (latest - checkpoint).max(0) as usize,
+            (Some(latest), None) => (latest + 1).max(0) as usize,
+            (None, _) => state.reports_since(kind).count(),
+        }
+    }
+
+    /// Small files in the table, from the newest report carrying a histogram.
+    ///
+    /// The histogram describes the whole table after a commit, so only the
+    /// newest one matters. Without one, every file added since the last
+    /// compaction is assumed to be small.
+    fn small_files(&self, state: &MaintenanceState<'_>) -> i64 {
+        let mut reports = state.reports_since(DeltaMaintenanceJobKind::Compaction);
+        let histogram = reports
+            .clone()
+            .filter_map(|r| r.report.file_size_histogram.as_ref())
+            .next_back();
+        match histogram {
+            Some(h) => h
+                .sorted_bin_boundaries
+                .iter()
+                .skip(1)
+                .zip(&h.file_counts)
+                .take_while(|(upper, _)| **upper <= self.small_file_bytes)
+                .map(|(_, count)| *count)
+                .sum(),
+            None => reports
+                .by_ref()
+                .filter_map(|r| r.report.num_files_added)
+                .sum(),
+        }
+    }
+}
+
+impl MaintenancePlanner for ThresholdPlanner {
+    fn plan(&self, state: &MaintenanceState<'_>) -> Vec<PlannedJob> {
+        use DeltaMaintenanceJobKind::*;
+        let mut jobs = Vec::new();
+
+        let commits = self.commits_since_checkpoint(state);
+        if !state.has_open_job(Checkpoint) && commits >= self.checkpoint_interval {
+            jobs.push(PlannedJob {
+                kind: Checkpoint,
+                reason: format!("{commits} commits since the last checkpoint"),
+            });
+        } else if let (Some(interval), Some(latest)) =
+            (self.log_compaction_interval, state.latest_version)
+        {
+            // A log compaction covers the commits after the last checkpoint,
+            // so only the newer of the two marks where the next one starts.
+            let covered = state
+                .last_version(Checkpoint)
+                .max(state.last_version(LogCompaction))
+                .unwrap_or(-1);
+            let commits = (latest - covered).max(0) as usize;
+            if !state.has_open_job(LogCompaction) && commits >= interval {
+                jobs.push(PlannedJob {
+                    kind: LogCompaction,
+                    reason: format!("{commits} commits since the last log compaction"),
+                });
+            }
+        }
+
+        let small = self.small_files(state);
+        if !state.has_open_job(Compaction) && small >= self.min_small_files {
+            jobs.push(PlannedJob {
+                kind: Compaction,
+                reason: format!("{small} files smaller than {} bytes", self.small_file_bytes),
+            });
+        }
+
+        let removed: i64 = state
+            .reports_since(Vacuum)
+            .filter_map(|r| r.report.num_files_removed)
+            .sum();
+        if !state.has_open_job(Vacuum) && removed >= self.vacuum_removed_files {
+            jobs.push(PlannedJob {
+                kind: Vacuum,
+                reason: format!("{removed} files removed since the last vacuum"),
+            });
+        }
+
+        jobs
+    }
+}
+
+/// Per-table reports and jobs held by [`InMemoryMaintenanceStore`].
+#[derive(Debug, Default)]
+struct TableMaintenance {
+    reports: Vec<StoredCommitReport>,
+    jobs: Vec<DeltaMaintenanceJob>,
+}
+
+/// In-memory [`MaintenanceStore`].
+#[derive(Debug, Default)]
+pub struct InMemoryMaintenanceStore {
+    tables: Mutex<HashMap<String, TableMaintenance>>,
+    next_job_id: Mutex<i64>,
+}
+
+#[async_trait::async_trait]
+impl MaintenanceStore for InMemoryMaintenanceStore {
+    async fn record_report(
+        &self,
+        table_id: &str,
+        report: DeltaCommitReport,
+    ) -> MaintenanceResult<()> {
+        let mut tables = self.tables.lock().expect("maintenance mutex poisoned");
+        tables
+            .entry(table_id.to_string())
+            .or_default()
+            .reports
+            .push(StoredCommitReport {
+                table_id: table_id.to_string(),
+                reported_at: now_millis(),
+                report,
+            });
+        Ok(())
+    }
+
+    async fn list_reports(
+        &self,
+        table_id: &str,
+        since: Option<i64>,
+    ) -> MaintenanceResult<Vec<StoredCommitReport>> {
+        let tables = self.tables.lock().expect("maintenance mutex poisoned");
+        Ok(tables
+            .get(table_id)
+            .map(|t| {
+                t.reports
+                    .iter()
+                    .filter(|r| since.is_none_or(|since| r.reported_at > since))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn prune_reports(&self, table_id: &str, before: i64) -> MaintenanceResult<()> {
+        let mut tables = self.tables.lock().expect("maintenance mutex poisoned");
+        if let Some(table) = tables.get_mut(table_id) {
+            table.reports.retain(|r| r.reported_at >= before);
+        }
+        Ok(())
+    }
+
+    async fn create_job(
+        &self,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        reason: String,
+    ) -> MaintenanceResult<DeltaMaintenanceJob> {
+        let job_id = {
+            let mut next = self.next_job_id.lock().expect("maintenance mutex poisoned");
+            *next += 1;
+            *next
+        };
+        let job = DeltaMaintenanceJob {
+            job_id,
+            table_id: table_id.to_string(),
+            kind,
+            status: DeltaMaintenanceJobStatus::Pending,
+            reason,
+            message: None,
+            committed_version: None,
+            num_files_added: None,
+            num_files_removed: None,
+            num_bytes_removed: None,
+            created_at: now_millis(),
+            started_at: None,
+            finished_at: None,
+        };
+        let mut tables = self.tables.lock().expect("maintenance mutex poisoned");
+        tables
+            .entry(table_id.to_string())
+            .or_default()
+            .jobs
+            .push(job.clone());
+        Ok(job)
+    }
+
+    async fn update_job(&self, job: &DeltaMaintenanceJob) -> MaintenanceResult<()> {
+        let mut tables = self.tables.lock().expect("maintenance mutex poisoned");
+        let stored = tables
+            .get_mut(&job.table_id)
+            .and_then(|t| t.jobs.iter_mut().find(|j| j.job_id == job.job_id))
+            .ok_or(MaintenanceError::JobNotFound(job.job_id))?;
+        *stored = job.clone();
+        Ok(())
+    }
+
+    async fn list_jobs(
+        &self,
+        table_id: &str,
+        limit: Option<usize>,
+    ) -> MaintenanceResult<Vec<DeltaMaintenanceJob>> {
+        let tables = self.tables.lock().expect("maintenance mutex poisoned");
+        Ok(tables
+            .get(table_id)
+            .map(|t| {
+                t.jobs
+                    .iter()
+                    .rev()
+                    .take(limit.unwrap_or(usize::MAX))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::delta::v1::DeltaFileSizeHistogram;
+
+    fn report(files_added: i64, files_removed: i64) -> DeltaCommitReport {
+        DeltaCommitReport {
+            num_files_added: Some(files_added),
+            num_files_removed: Some(files_removed),
+            ..Default::default()
+        }
+    }
+
+    fn job(job_id: i64, kind: DeltaMaintenanceJobKind) -> DeltaMaintenanceJob {
+        DeltaMaintenanceJob {
+            job_id,
+            table_id: "t".into(),
+            kind,
+            status: DeltaMaintenanceJobStatus::Pending,
+            reason: String::new(),
+            message: None,
+            committed_version: None,
+            num_files_added: None,
+            num_files_removed: None,
+            num_bytes_removed: None,
+            created_at: 0,
+            started_at: None,
+            finished_at: None,
+        }
+    }
+
+    fn succeeded(job: &mut DeltaMaintenanceJob, at: i64) {
+        job.status = DeltaMaintenanceJobStatus::Succeeded;
+        job.finished_at = Some(at);
+    }
+
+    #[tokio::test]
+    async fn stores_reports_and_jobs_per_table() {
+        let store = InMemoryMaintenanceStore::default();
+        store.record_report("t", report(1, 0)).await.unwrap();
+        store.record_report("t", report(2, 0)).await.unwrap();
+        store.record_report("u", report(3, 0)).await.unwrap();
+        assert_eq!(store.list_reports("t", None).await.unwrap().len(), 2);
+        assert!(store.list_reports("v", None).await.unwrap().is_empty());
+
+        let first = store
+            .create_job("t", DeltaMaintenanceJobKind::Vacuum, "a".into())
+            .await
+            .unwrap();
+        let mut second = store
+            .create_job("t", DeltaMaintenanceJobKind::Compaction, "b".into())
+            .await
+            .unwrap();
+        assert!(second.job_id > first.job_id);
+        second.status = DeltaMaintenanceJobStatus::Running;
+        store.update_job(&second).await.unwrap();
+
+        let jobs = store.list_jobs("t", None).await.unwrap();
+        assert_eq!(jobs, vec![second.clone(), first]);
+        assert_eq!(store.list_jobs("t", Some(1)).await.unwrap(), vec![second]);
+
+        let mut unknown = jobs[0].clone();
+        unknown.job_id = 99;
+        assert!(matches!(
+            store.update_job(&unknown).await,
+            Err(MaintenanceError::JobNotFound(99))
+        ));
+    }
+
+    #[test]
+    fn plans_checkpoint_after_interval() {
+        let planner = ThresholdPlanner {
+            checkpoint_interval: 3,
+            ..Default::default()
+        };
+        let reports: Vec<_> = (1..=3)
+            .map(|i| StoredCommitReport {
+                table_id: "t".into(),
+                reported_at: i,
+                report: report(0, 0),
+            })
+            .collect();
+        let mut job = job(1, DeltaMaintenanceJobKind::Checkpoint);
+
+        let state = MaintenanceState {
+            reports: &reports,
+            jobs: &[],
+            latest_version: None,
+        };
+        let planned = planner.plan(&state);
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].kind, DeltaMaintenanceJobKind::Checkpoint);
+
+        // An open checkpoint job suppresses another one.
+        let jobs = [job.clone()];
+        let state = MaintenanceState {
+            reports: &reports,
+            jobs: &jobs,
+            latest_version: None,
+        };
+        assert!(planner.plan(&state).is_empty());
+
+        // Only commits after the last successful checkpoint count.
+        succeeded(&mut job, 1);
+        let jobs = [job];
+        let state = MaintenanceState {
+            reports: &reports,
+            jobs: &jobs,
+            latest_version: None,
+        };
+        assert_eq!(
+            state
+                .reports_since(DeltaMaintenanceJobKind::Checkpoint)
+                .count(),
+            2
+        );
+        assert!(planner.plan(&state).is_empty());
+    }
+
+    #[test]
+    fn plans_checkpoint_and_log_compaction_from_versions() {
+        let planner = ThresholdPlanner {
+            checkpoint_interval: 10,
+            log_compaction_interval: Some(4),
+            ..Default::default()
+        };
+        let mut checkpoint = job(1, DeltaMaintenanceJobKind::Checkpoint);
+        succeeded(&mut checkpoint, 1);
+        checkpoint.committed_version = Some(20);
+        let kinds = |jobs: &[DeltaMaintenanceJob], latest| {
+            let state = MaintenanceState {
+                reports: &[],
+                jobs,
+                latest_version: Some(latest),
+            };
+            planner
+                .plan(&state)
+                .into_iter()
+                .map(|j| j.kind)
+                .collect::<Vec<_>>()
+        };
+
+        // Never checkpointed: every version counts.
+        assert_eq!(kinds(&[], 9), vec![DeltaMaintenanceJobKind::Checkpoint]);
+        assert!(kinds(std::slice::from_ref(&checkpoint), 23).is_empty());
+        assert_eq!(
+            kinds(std::slice::from_ref(&checkpoint), 24),
+            vec![DeltaMaintenanceJobKind::LogCompaction]
+        );
+        assert_eq!(
+            kinds(std::slice::from_ref(&checkpoint), 30),
+            vec![DeltaMaintenanceJobKind::Checkpoint]
+        );
+
+        // A log compaction moves the start of the next one.
+        let mut compaction = job(2, DeltaMaintenanceJobKind::LogCompaction);
+        succeeded(&mut compaction, 2);
+        compaction.committed_version = Some(24);
+        assert!(kinds(&[compaction, checkpoint], 27).is_empty());
+    }
+
+    #[test]
+    fn plans_compaction_from_histogram_and_vacuum_from_removed_files() {
+        let planner = ThresholdPlanner {
+            checkpoint_interval: usize::MAX,
+            small_file_bytes: 1024,
+            min_small_files: 10,
+            vacuum_removed_files: 5,
+        };
+        let histogram = DeltaFileSizeHistogram {
+            sorted_bin_boundaries: vec![0, 512, 1024, 4096],
+            file_counts: vec![6, 5, 100, 1],
+            total_bytes: vec![0, 0, 0, 0],
+            commit_version: Some(4),
+        };
+        let reports = vec![
+            StoredCommitReport {
+                table_id: "t".into(),
+                reported_at: 1,
+                report: report(1, 3),
+            },
+            StoredCommitReport {
+                table_id: "t".into(),
+                reported_at: 2,
+                report: DeltaCommitReport {
+                    file_size_histogram: Some(histogram),
+                    ..report(1, 2)
+                },
+            },
+        ];
+        let state = MaintenanceState {
+            reports: &reports,
+            jobs: &[],
+            latest_version: None,
+        };
+        let kinds: Vec<_> = planner.plan(&state).into_iter().map(|j| j.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DeltaMaintenanceJobKind::Compaction,
+                DeltaMaintenanceJobKind::Vacuum
+            ]
+        );
+        assert_eq!(planner.small_files(&state), 11);
+    }
+}
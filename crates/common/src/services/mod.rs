@@ -1,3 +1,4 @@
 pub mod commit_coordinator;
 pub mod encryption;
+pub mod maintenance;
 pub mod secrets;
@@ -58,6 +58,27 @@ pub trait ResourceStoreReader: Send + Sync + 'static {
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)>;
+
+    /// List all resources, following page tokens until the listing is exhausted.
+    ///
+    /// Meant for internal sweeps that must see every resource of a label; API
+    /// handlers should page through [`list`](Self::list) instead.
+    async fn list_all(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+    ) -> Result<Vec<Resource>> {
+        let mut resources = Vec::new();
+        let mut page_token = None;
+        loop {
+            let (page, next) = self.list(label, namespace, None, page_token).await?;
+            resources.extend(page);
+            match next {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(resources),
+            }
+        }
+    }
 }
 
 /// Generic store that can be used to store and retrieve resources.
@@ -99,6 +120,36 @@ pub trait ResourceStore: ResourceStoreReader + Send + Sync + 'static {
         resource: Resource,
     ) -> Result<(Resource, ResourceRef)>;
 
+    /// Rename a resource within its namespace.
+    ///
+    /// Unlike [`update`](Self::update), implementations must move the resource's
+    /// name index entry to the new name and reject the rename if the name is
+    /// taken. The resource keeps its id, so associations and any state keyed by
+    /// the id carry over unchanged.
+    ///
+    /// Resources that refer to the renamed one by name are rewritten in the same
+    /// transaction: either the rename and every update in `dependents` are
+    /// applied, or none is.
+    ///
+    /// ## Arguments
+    /// - `id`: The identifier of the resource to rename.
+    /// - `resource`: The updated resource carrying the new name.
+    /// - `dependents`: Updates to other resources, applied as by [`update`](Self::update).
+    ///
+    /// ## Returns
+    /// The renamed resource.
+    ///
+    /// ## Errors
+    /// - [AlreadyExists](crate::Error::AlreadyExists) If another resource of the same
+    ///   type already has the new name.
+    /// - [NotFound](crate::Error::NotFound) If the resource or a dependent does not exist.
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)>;
+
     /// Add an association between two resources.
     ///
     /// Associations are directed edges between resources with a label and optional properties.
@@ -187,6 +238,27 @@ pub trait ResourceStore: ResourceStoreReader + Send + Sync + 'static {
     }
 }
 
+/// Renaming support for stores lifted to a [`ResourceStore`] by [`ObjectStoreAdapter`].
+///
+/// The generic [`ObjectStore::update`] only replaces an object's properties, so
+/// backends expose moving an object to a new name separately.
+#[async_trait::async_trait]
+pub trait RenameObject: Send + Sync + 'static {
+    /// Move the object `id` to `name`, replacing its properties if given, and
+    /// replace the properties of each object in `dependents` in the same
+    /// transaction.
+    ///
+    /// Fails with `AlreadyExists` if another object with the same label already
+    /// has `name`.
+    async fn rename(
+        &self,
+        id: &Uuid,
+        name: &ResourceName,
+        properties: Option<serde_json::Value>,
+        dependents: &[(Uuid, Option<serde_json::Value>)],
+    ) -> olai_store::Result<Object>;
+}
+
 pub trait ProvidesResourceStore: Send + Sync + 'static {
     fn store(&self) -> &dyn ResourceStore;
 }
@@ -282,7 +354,12 @@ where
 #[async_trait::async_trait]
 impl<S> ResourceStore for ObjectStoreAdapter<S>
 where
-    S: ObjectStore<ObjectLabel> + AssociationStore<ObjectLabel> + Send + Sync + 'static,
+    S: ObjectStore<ObjectLabel>
+        + AssociationStore<ObjectLabel>
+        + RenameObject
+        + Send
+        + Sync
+        + 'static,
 {
     async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
         let object: Object = resource.try_into()?;
@@ -317,6 +394,30 @@ where
         Ok((updated.try_into()?, uuid.into()))
     }
 
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)> {
+        let uuid = self.resolve_ident(id).await?;
+        let object: Object = resource.try_into()?;
+        let mut updates = Vec::with_capacity(dependents.len());
+        for (ident, resource) in dependents {
+            let object: Object = resource.try_into()?;
+            updates.push((self.resolve_ident(&ident).await?, object.properties));
+        }
+        let renamed = RenameObject::rename(
+            &self.store,
+            &uuid,
+            &object.name,
+            object.properties,
+            &updates,
+        )
+        .await?;
+        Ok((renamed.try_into()?, uuid.into()))
+    }
+
     async fn add_association(
         &self,
         from: &ResourceIdent,
@@ -440,6 +541,15 @@ impl<T: ResourceStore> ResourceStore for Arc<T> {
         T::update(self, id, resource).await
     }
 
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)> {
+        T::rename(self, id, resource, dependents).await
+    }
+
     async fn add_association(
         &self,
         from: &ResourceIdent,
@@ -531,6 +641,15 @@ impl<T: ProvidesResourceStore> ResourceStore for T {
         self.store().update(id, resource).await
     }
 
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)> {
+        self.store().rename(id, resource, dependents).await
+    }
+
     async fn add_association(
         &self,
         from: &ResourceIdent,
@@ -85,7 +85,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // The provider list owns the session's runtime, so resolving a table
     // registers the credential-vended routing store on the session as a side
     // effect.
-    let providers = UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder);
+    // Views are planned with the session's own configuration and functions.
+    let providers =
+        UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder).with_session(&ctx);
 
     // `ctx.sql(&sql)` cannot drive async catalog resolution, so we use the same
     // lower-level `SessionState` APIs that DataFusion's "Remote Catalog" example
@@ -4,6 +4,8 @@ use datafusion::catalog::TableProvider;
 use unitycatalog_common::models::tables::v1::Table;
 use url::Url;
 
+use super::time_travel::TimeTravel;
+
 /// Error returned while turning a Unity Catalog table into a [`TableProvider`].
 pub type TableProviderError = datafusion::error::DataFusionError;
 
@@ -28,14 +30,35 @@ pub trait TableProviderBuilder: Send + Sync + std::fmt::Debug {
         table: &Table,
     ) -> Result<Arc<dyn TableProvider>, TableProviderError>;
 
+    /// Build a read-only provider for the Delta table rooted at `location` as
+    /// of an earlier point in its history: a `table@v<version>` or
+    /// `table@<timestamp>` reference (see [`TimeTravel`]).
+    ///
+    /// The default errors: only builders that can read a Delta log at an
+    /// arbitrary version support time travel.
+    async fn build_delta_at(
+        &self,
+        location: &Url,
+        table: &Table,
+        at: TimeTravel,
+    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+        let _ = (location, at);
+        Err(TableProviderError::NotImplemented(format!(
+            "this TableProviderBuilder does not support time travel on '{}'",
+            table.full_name
+        )))
+    }
+
     /// Build a provider for a metric view.
     ///
     /// `view` is the parsed metric-view definition and `source` is the
-    /// already-resolved provider for the view's source relation (the resolver
-    /// resolves it through the same Unity Catalog path, so its credentials and
-    /// object store are registered). Implementations turn `source` into a
-    /// logical plan and lower the view into a
-    /// [`MetricViewTableProvider`](crate::metric_view::MetricViewTableProvider).
+    /// already-resolved provider for the view's source relation, to be scanned
+    /// as `source_name`; `joins` holds the provider of every join, nested ones
+    /// included, keyed by join name. The resolver resolves them through the
+    /// same Unity Catalog path, so their credentials and object stores are
+    /// registered. Implementations turn each provider into a logical plan and
+    /// lower the view with
+    /// [`MetricViewTableProvider::try_new_with_joins`](crate::metric_view::MetricViewTableProvider::try_new_with_joins).
     ///
     /// The default errors: only embedders that build a session with metric-view
     /// support (see [`crate::metric_view::session`]) can resolve metric views.
@@ -45,8 +68,9 @@ pub trait TableProviderBuilder: Send + Sync + std::fmt::Debug {
         view: &crate::metric_view::MetricView,
         source: Arc<dyn TableProvider>,
         source_name: &str,
+        joins: std::collections::HashMap<String, Arc<dyn TableProvider>>,
     ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
-        let _ = (view, source, source_name);
+        let _ = (view, source, source_name, joins);
         Err(TableProviderError::NotImplemented(
             "this TableProviderBuilder does not support metric views".to_string(),
         ))
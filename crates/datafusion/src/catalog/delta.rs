@@ -11,6 +11,10 @@
 //! `loadTable` endpoint to fetch the commit tail + latest version and builds a
 //! catalog-managed kernel snapshot from them (see [`super::kernel`]). External
 //! Delta tables keep the plain filesystem snapshot path.
+//!
+//! Time-travel reads ([`TableProviderBuilder::build_delta_at`]) take the same
+//! two paths, resolving the requested version or timestamp against the commit
+//! tail and the published log of a managed table.
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -18,6 +22,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use datafusion::catalog::TableProvider;
 use datafusion::common::DataFusionError;
 use datafusion::execution::context::SessionContext;
+use datafusion::execution::runtime_env::RuntimeEnv;
 use deltalake_core::DeltaTableConfig;
 use deltalake_core::delta_datafusion::DeltaScanNext;
 use deltalake_core::delta_datafusion::engine::DataFusionEngine;
@@ -29,7 +34,11 @@ use unitycatalog_common::models::tables::v1::Table;
 use url::Url;
 
 use super::builder::{TableProviderBuilder, TableProviderError};
-use super::kernel::{ManagedReadState, build_catalog_managed_snapshot, resolve_managed_read_state};
+use super::kernel::{
+    ManagedReadState, build_catalog_managed_snapshot, build_snapshot_as_of,
+    resolve_managed_read_state,
+};
+use super::time_travel::TimeTravel;
 
 /// Builds Delta [`TableProvider`]s for Unity Catalog tables.
 ///
@@ -64,29 +73,8 @@ impl DeltaTableProviderBuilder {
     }
 
     /// Build the log store for `location` from the resolver-registered object store.
-    ///
-    /// Built directly rather than via `logstore_with` (which dispatches on the URL
-    /// scheme to a registered logstore factory) because we depend only on
-    /// `deltalake-core`, so no cloud-scheme factories (`s3`/`gs`/`az`) are
-    /// registered. The prefixed store roots paths at the table location; the root
-    /// store stays bucket-rooted.
     fn log_store_for(&self, location: &Url) -> Result<LogStoreRef, TableProviderError> {
-        let root_store = self
-            .ctx
-            .runtime_env()
-            .object_store_registry
-            .get_store(location)
-            .map_err(|e| DataFusionError::External(Box::new(e)))?;
-        let config = StorageConfig::default();
-        let prefixed_store = config
-            .decorate_store(root_store.clone(), location)
-            .map_err(|e| DataFusionError::External(Box::new(e)))?;
-        Ok(default_logstore(
-            Arc::from(prefixed_store),
-            root_store,
-            location,
-            &config,
-        ))
+        log_store_for(&self.ctx.runtime_env(), location)
     }
 
     /// Build the plain filesystem snapshot for a non-catalog-managed table (external
@@ -105,47 +93,31 @@ impl DeltaTableProviderBuilder {
         snapshot: Snapshot,
         log_store: LogStoreRef,
     ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
-        DeltaScanNext::builder()
-            .with_snapshot(Arc::new(snapshot))
-            .with_log_store(log_store)
-            .await
+        provider_from_snapshot(snapshot, log_store).await
     }
-}
-
-impl std::fmt::Debug for DeltaTableProviderBuilder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DeltaTableProviderBuilder")
-            .finish_non_exhaustive()
-    }
-}
-
-#[async_trait::async_trait]
-impl TableProviderBuilder for DeltaTableProviderBuilder {
-    async fn build_delta(
-        &self,
-        location: &Url,
-        table: &Table,
-    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
-        let log_store = self.log_store_for(location)?;
 
+    /// Ask the catalog how to read `table`: whether it is a managed
+    /// (coordinated-commit) table and, if so, for its ratified commit tail.
+    ///
+    /// Reports [`ManagedReadState::NotManaged`] — read the filesystem log — when
+    /// this deployment doesn't serve `/delta/v1`.
+    async fn read_state(&self, table: &Table) -> Result<ManagedReadState, TableProviderError> {
         // A prior table already showed this deployment doesn't serve `/delta/v1`:
         // skip the loadTable round-trip and read the filesystem log directly.
         if self.delta_v1_unsupported.load(Ordering::Relaxed) {
-            let snapshot = self.filesystem_snapshot(location).await?;
-            return self.provider_from_snapshot(snapshot, log_store).await;
+            return Ok(ManagedReadState::NotManaged);
         }
 
-        // Ask the catalog whether this is a managed (coordinated-commit) table and,
-        // if so, for its ratified commit tail. The `/delta/v1` loadTable response
-        // carries the table type, the unbackfilled commits, and the latest ratified
-        // version a reader needs to materialize the catalog's snapshot.
-        let loaded = match self
+        // The `/delta/v1` loadTable response carries the table type, the
+        // unbackfilled commits, and the latest ratified version a reader needs to
+        // materialize the catalog's snapshot.
+        match self
             .client
             .delta_v1()
             .load_table(&table.catalog_name, &table.schema_name, &table.name)
             .await
         {
-            Ok(loaded) => loaded,
+            Ok(loaded) => resolve_managed_read_state(&loaded),
             // A6: the `/delta/v1` endpoint is unavailable on this deployment (older
             // OSS / production Databricks). The legacy `tables` API already gave us
             // the storage location, so fall back to the filesystem snapshot rather
@@ -157,13 +129,68 @@ impl TableProviderBuilder for DeltaTableProviderBuilder {
                     "/delta/v1 loadTable unavailable; falling back to filesystem snapshot"
                 );
                 self.delta_v1_unsupported.store(true, Ordering::Relaxed);
-                let snapshot = self.filesystem_snapshot(location).await?;
-                return self.provider_from_snapshot(snapshot, log_store).await;
+                Ok(ManagedReadState::NotManaged)
             }
-            Err(e) => return Err(DataFusionError::External(Box::new(e))),
-        };
+            Err(e) => Err(DataFusionError::External(Box::new(e))),
+        }
+    }
+}
+
+/// Build the log store for `location` from the object store registered on `runtime`.
+///
+/// Built directly rather than via `logstore_with` (which dispatches on the URL
+/// scheme to a registered logstore factory) because we depend only on
+/// `deltalake-core`, so no cloud-scheme factories (`s3`/`gs`/`az`) are
+/// registered. The prefixed store roots paths at the table location; the root
+/// store stays bucket-rooted.
+pub(crate) fn log_store_for(
+    runtime: &RuntimeEnv,
+    location: &Url,
+) -> Result<LogStoreRef, TableProviderError> {
+    let root_store = runtime
+        .object_store_registry
+        .get_store(location)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let config = StorageConfig::default();
+    let prefixed_store = config
+        .decorate_store(root_store.clone(), location)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    Ok(default_logstore(
+        Arc::from(prefixed_store),
+        root_store,
+        location,
+        &config,
+    ))
+}
+
+/// Assemble a Delta provider from a built snapshot and the table's log store.
+pub(crate) async fn provider_from_snapshot(
+    snapshot: Snapshot,
+    log_store: LogStoreRef,
+) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+    DeltaScanNext::builder()
+        .with_snapshot(Arc::new(snapshot))
+        .with_log_store(log_store)
+        .await
+}
 
-        let snapshot = match resolve_managed_read_state(&loaded)? {
+impl std::fmt::Debug for DeltaTableProviderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeltaTableProviderBuilder")
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl TableProviderBuilder for DeltaTableProviderBuilder {
+    async fn build_delta(
+        &self,
+        location: &Url,
+        table: &Table,
+    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+        let log_store = self.log_store_for(location)?;
+
+        let snapshot = match self.read_state(table).await? {
             // The catalog is the source of truth: build from the ratified commit
             // tail + latest version rather than scanning `_delta_log/`.
             ManagedReadState::Managed { commits, latest } => {
@@ -184,25 +211,65 @@ impl TableProviderBuilder for DeltaTableProviderBuilder {
         self.provider_from_snapshot(snapshot, log_store).await
     }
 
+    async fn build_delta_at(
+        &self,
+        location: &Url,
+        table: &Table,
+        at: TimeTravel,
+    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+        let log_store = self.log_store_for(location)?;
+
+        // A managed table's history runs through the catalog's commit tail: its
+        // newest versions may not be backfilled into `_delta_log/` yet.
+        let engine = DataFusionEngine::new_from_context(self.ctx.task_ctx());
+        let snapshot = match self.read_state(table).await? {
+            ManagedReadState::Managed { commits, latest } => build_snapshot_as_of(
+                engine.as_ref(),
+                location,
+                Some((commits.as_slice(), latest)),
+                at,
+            )?,
+            ManagedReadState::NotManaged => {
+                build_snapshot_as_of(engine.as_ref(), location, None, at)?
+            }
+        };
+
+        self.provider_from_snapshot(snapshot, log_store).await
+    }
+
     #[cfg(feature = "metric-view")]
     async fn build_metric_view(
         &self,
         view: &crate::metric_view::MetricView,
         source: Arc<dyn TableProvider>,
         source_name: &str,
+        joins: std::collections::HashMap<String, Arc<dyn TableProvider>>,
     ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
         use datafusion::datasource::provider_as_source;
         use datafusion::logical_expr::LogicalPlanBuilder;
 
         use crate::metric_view::MetricViewTableProvider;
 
-        // Build a scan over the resolved source provider as the view's input
-        // plan. The metric view's dimension/measure expressions are written
-        // against this relation's columns.
+        // Build a scan over each resolved provider as the view's input plans.
+        // The metric view's dimension/measure expressions are written against
+        // these relations' columns; joins are scanned under their own names.
         let source_plan =
             LogicalPlanBuilder::scan(source_name, provider_as_source(source), None)?.build()?;
+        let join_plans = joins
+            .into_iter()
+            .map(|(name, provider)| {
+                let plan =
+                    LogicalPlanBuilder::scan(&name, provider_as_source(provider), None)?.build()?;
+                Ok((name, plan))
+            })
+            .collect::<Result<_, TableProviderError>>()?;
 
-        let provider = MetricViewTableProvider::try_new(&self.ctx.state(), view, source_plan)?;
+        let provider = MetricViewTableProvider::try_new_with_joins(
+            &self.ctx.state(),
+            view,
+            source_plan,
+            join_plans,
+        )?;
         Ok(Arc::new(provider))
     }
 }
@@ -0,0 +1,610 @@
+//! Unity Catalog SQL functions as DataFusion UDFs.
+//!
+//! DataFusion looks functions up by name, synchronously, while planning, so a
+//! Unity Catalog function has to be registered on the session before a query
+//! calling it is planned (see [`UnityCatalogProviderList::resolve_functions`]).
+//! `LANGUAGE SQL` functions are lowered rather than interpreted:
+//!
+//! - a scalar function becomes a [`ScalarUDF`](datafusion::logical_expr::ScalarUDF)
+//!   that inlines its `routine_definition` when the call is simplified, with
+//!   the parameters replaced by the arguments cast to their declared types;
+//! - a table-valued function becomes a [`TableFunctionImpl`] whose body is
+//!   planned once with the parameters as placeholders, and bound to the
+//!   (constant) arguments on each call.
+//!
+//! DataFusion has no compound function names — table functions are even looked
+//! up by the first name part alone — so three-part names in the statement are
+//! rewritten to a single identifier holding the lowercased full name, which is
+//! also the name the function is registered under.
+//!
+//! [`UnityCatalogProviderList::resolve_functions`]: super::UnityCatalogProviderList::resolve_functions
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{RecordBatch, RecordBatchOptions};
+use datafusion::arrow::compute::can_cast_types;
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::catalog::{TableFunctionImpl, TableProvider};
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{DFSchema, ScalarValue, internal_err, plan_datafusion_err, plan_err};
+use datafusion::datasource::ViewTable;
+use datafusion::error::Result;
+use datafusion::execution::SessionState;
+use datafusion::logical_expr::execution_props::ExecutionProps;
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyContext};
+use datafusion::logical_expr::{
+    ColumnarValue, ExprSchemable, LogicalPlan, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility, cast,
+};
+use datafusion::physical_expr::create_physical_expr;
+use datafusion::prelude::{Expr, lit};
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use datafusion::sql::sqlparser::ast::{
+    Expr as SqlExpr, Ident, ObjectName, TableFactor, VisitMut, VisitorMut,
+};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+use unitycatalog_common::models::functions::v1::Function;
+
+/// Rewrite the three-part function calls in `statement` — scalar calls and
+/// table functions in `FROM` — to the single-identifier names they are
+/// registered under, returning those names once each, in order of appearance.
+pub(super) fn rewrite_function_references(statement: &mut DFStatement) -> Vec<String> {
+    let mut references = FunctionReferences::default();
+    rewrite_statement(statement, &mut references);
+    references.names
+}
+
+fn rewrite_statement(statement: &mut DFStatement, references: &mut FunctionReferences) {
+    match statement {
+        DFStatement::Statement(statement) => {
+            let _ = statement.visit(references);
+        }
+        DFStatement::Explain(explain) => rewrite_statement(&mut explain.statement, references),
+        _ => {}
+    }
+}
+
+#[derive(Default)]
+struct FunctionReferences {
+    names: Vec<String>,
+}
+
+impl FunctionReferences {
+    fn rewrite(&mut self, name: &mut ObjectName) {
+        if name.0.len() != 3 {
+            return;
+        }
+        let parts: Option<Vec<&str>> = name
+            .0
+            .iter()
+            .map(|part| part.as_ident().map(|ident| ident.value.as_str()))
+            .collect();
+        let Some(parts) = parts else { return };
+        let full_name = parts.join(".").to_ascii_lowercase();
+        *name = ObjectName::from(vec![Ident::new(full_name.clone())]);
+        if !self.names.contains(&full_name) {
+            self.names.push(full_name);
+        }
+    }
+}
+
+impl VisitorMut for FunctionReferences {
+    type Break = ();
+
+    fn pre_visit_expr(&mut self, expr: &mut SqlExpr) -> ControlFlow<()> {
+        if let SqlExpr::Function(function) = expr {
+            self.rewrite(&mut function.name);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_table_factor(&mut self, table_factor: &mut TableFactor) -> ControlFlow<()> {
+        if let TableFactor::Table {
+            name,
+            args: Some(_),
+            ..
+        } = table_factor
+        {
+            self.rewrite(name);
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Whether `function` returns a table rather than a scalar.
+pub(super) fn is_table_function(function: &Function) -> bool {
+    function.data_type.eq_ignore_ascii_case("TABLE_TYPE")
+        || function
+            .full_data_type
+            .trim_start()
+            .to_ascii_uppercase()
+            .starts_with("TABLE")
+}
+
+/// A declared parameter of a SQL function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct SqlParam {
+    name: String,
+    /// The declared type as SQL text, e.g. `INT`.
+    type_text: String,
+    data_type: DataType,
+    /// The `DEFAULT`, evaluated and cast to `data_type`.
+    default: Option<ScalarValue>,
+}
+
+/// The parameters of `function` in position order, with their types and
+/// defaults resolved. Parameters with a default must come last, since
+/// arguments are positional.
+pub(super) fn sql_params(state: &SessionState, function: &Function) -> Result<Vec<SqlParam>> {
+    let mut infos: Vec<_> = function
+        .input_params
+        .iter()
+        .flat_map(|params| params.parameters.iter())
+        .collect();
+    infos.sort_by_key(|info| info.position.unwrap_or(i32::MAX));
+
+    let mut params: Vec<SqlParam> = Vec::with_capacity(infos.len());
+    for info in infos {
+        let data_type = sql_type(state, &info.type_text).map_err(|e| {
+            plan_datafusion_err!(
+                "parameter '{}' of '{}' has unsupported type '{}': {e}",
+                info.name,
+                function.full_name,
+                info.type_text
+            )
+        })?;
+        let default = match info.parameter_default.as_deref() {
+            Some(default) => {
+                let expr = state.create_logical_expr(default, &DFSchema::empty())?;
+                Some(const_value(&expr)?.cast_to(&data_type).map_err(|e| {
+                    plan_datafusion_err!(
+                        "default of parameter '{}' of '{}' is not a valid {}: {e}",
+                        info.name,
+                        function.full_name,
+                        info.type_text
+                    )
+                })?)
+            }
+            None if params.last().is_some_and(|p| p.default.is_some()) => {
+                return plan_err!(
+                    "parameter '{}' of '{}' has no default but follows one that does",
+                    info.name,
+                    function.full_name
+                );
+            }
+            None => None,
+        };
+        params.push(SqlParam {
+            name: info.name.clone(),
+            type_text: info.type_text.clone(),
+            data_type,
+            default,
+        });
+    }
+    Ok(params)
+}
+
+/// Parse a scalar function body, returning it as SQL with its own function
+/// calls rewritten (see [`rewrite_function_references`]) together with the
+/// functions it calls.
+pub(super) fn parse_scalar_body(definition: &str) -> Result<(String, Vec<String>)> {
+    let mut expr = Parser::new(&GenericDialect {})
+        .try_with_sql(definition)?
+        .parse_expr()?;
+    let mut references = FunctionReferences::default();
+    let _ = expr.visit(&mut references);
+    Ok((expr.to_string(), references.names))
+}
+
+/// Parse a table function body into a statement whose parameter references
+/// are numbered placeholders cast to the declared types (`CAST($1 AS INT)`),
+/// returning it together with the functions it calls.
+pub(super) fn parse_table_body(
+    definition: &str,
+    params: &[SqlParam],
+) -> Result<(DFStatement, Vec<String>)> {
+    let mut statements = DFParser::parse_sql(definition)?;
+    let (Some(mut statement), None) = (statements.pop_front(), statements.pop_front()) else {
+        return plan_err!("a table function must be defined by a single query");
+    };
+    let mut placeholders = HashMap::new();
+    for (i, param) in params.iter().enumerate() {
+        let sql = format!("CAST(${} AS {})", i + 1, param.type_text);
+        let expr = Parser::new(&GenericDialect {})
+            .try_with_sql(&sql)?
+            .parse_expr()?;
+        placeholders.insert(param.name.to_ascii_lowercase(), expr);
+    }
+    if let DFStatement::Statement(statement) = &mut statement {
+        let _ = statement.visit(&mut ParamPlaceholders {
+            placeholders: &placeholders,
+        });
+    }
+    let references = rewrite_function_references(&mut statement);
+    Ok((statement, references))
+}
+
+struct ParamPlaceholders<'a> {
+    placeholders: &'a HashMap<String, SqlExpr>,
+}
+
+impl VisitorMut for ParamPlaceholders<'_> {
+    type Break = ();
+
+    fn post_visit_expr(&mut self, expr: &mut SqlExpr) -> ControlFlow<()> {
+        if let SqlExpr::Identifier(ident) = expr
+            && let Some(placeholder) = self.placeholders.get(&ident.value.to_ascii_lowercase())
+        {
+            *expr = placeholder.clone();
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// The Arrow type DataFusion plans SQL type text such as `INT` or
+/// `DECIMAL(10,2)` to.
+fn sql_type(state: &SessionState, type_text: &str) -> Result<DataType> {
+    let schema = DFSchema::empty();
+    let expr = state.create_logical_expr(&format!("CAST(NULL AS {type_text})"), &schema)?;
+    expr.get_type(&schema)
+}
+
+/// Evaluate a constant expression.
+fn const_value(expr: &Expr) -> Result<ScalarValue> {
+    let physical = create_physical_expr(expr, &DFSchema::empty(), &ExecutionProps::new())?;
+    let batch = RecordBatch::try_new_with_options(
+        Arc::new(Schema::empty()),
+        vec![],
+        &RecordBatchOptions::new().with_row_count(Some(1)),
+    )?;
+    match physical.evaluate(&batch)? {
+        ColumnarValue::Scalar(value) => Ok(value),
+        ColumnarValue::Array(array) => ScalarValue::try_from_array(&array, 0),
+    }
+}
+
+/// A scalar `LANGUAGE SQL` function, inlined into the calling query when the
+/// call is simplified.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct SqlScalarFunction {
+    name: String,
+    signature: Signature,
+    params: Vec<SqlParam>,
+    return_type: DataType,
+    /// The body, over unqualified columns named after the parameters.
+    body: Expr,
+}
+
+impl SqlScalarFunction {
+    /// Lower `function`, whose body is `body` as returned by
+    /// [`parse_scalar_body`]. The functions the body calls must already be
+    /// registered on `state`.
+    pub(super) fn try_new(
+        state: &SessionState,
+        function: &Function,
+        params: Vec<SqlParam>,
+        body: &str,
+    ) -> Result<Self> {
+        let fields = params
+            .iter()
+            .map(|p| {
+                (
+                    None,
+                    Arc::new(Field::new(&p.name, p.data_type.clone(), true)),
+                )
+            })
+            .collect();
+        let schema = DFSchema::new_with_metadata(fields, HashMap::new())?;
+        let body = state.create_logical_expr(body, &schema)?;
+        let return_text = if function.full_data_type.is_empty() {
+            &function.data_type
+        } else {
+            &function.full_data_type
+        };
+        let return_type = sql_type(state, return_text).map_err(|e| {
+            plan_datafusion_err!(
+                "'{}' has unsupported return type '{return_text}': {e}",
+                function.full_name
+            )
+        })?;
+        let volatility = if function.is_deterministic {
+            Volatility::Immutable
+        } else {
+            Volatility::Volatile
+        };
+        Ok(Self {
+            name: function.full_name.to_ascii_lowercase(),
+            signature: Signature::user_defined(volatility),
+            params,
+            return_type,
+            body,
+        })
+    }
+}
+
+impl ScalarUDFImpl for SqlScalarFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    /// Accept every arity from the required parameters (those before the
+    /// first default) up to all of them, coercing each argument to its
+    /// parameter's declared type.
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let required = self
+            .params
+            .iter()
+            .position(|p| p.default.is_some())
+            .unwrap_or(self.params.len());
+        if !(required..=self.params.len()).contains(&arg_types.len()) {
+            return plan_err!(
+                "'{}' takes {required} to {} arguments, got {}",
+                self.name,
+                self.params.len(),
+                arg_types.len()
+            );
+        }
+        arg_types
+            .iter()
+            .zip(&self.params)
+            .map(|(arg_type, param)| {
+                if can_cast_types(arg_type, &param.data_type) {
+                    Ok(param.data_type.clone())
+                } else {
+                    plan_err!(
+                        "argument '{}' of '{}' must be {}, got {arg_type}",
+                        param.name,
+                        self.name,
+                        param.type_text
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        internal_err!(
+            "SQL function '{}' is inlined when simplified and cannot be invoked",
+            self.name
+        )
+    }
+
+    fn simplify(&self, mut args: Vec<Expr>, _info: &SimplifyContext) -> Result<ExprSimplifyResult> {
+        for param in &self.params[args.len().min(self.params.len())..] {
+            let default = param.default.clone().ok_or_else(|| {
+                plan_datafusion_err!("'{}' is missing argument '{}'", self.name, param.name)
+            })?;
+            args.push(lit(default));
+        }
+        let body = self
+            .body
+            .clone()
+            .transform(|expr| {
+                if let Expr::Column(column) = &expr
+                    && column.relation.is_none()
+                    && let Some(i) = self.params.iter().position(|p| p.name == column.name)
+                {
+                    let arg = cast(args[i].clone(), self.params[i].data_type.clone());
+                    return Ok(Transformed::yes(arg));
+                }
+                Ok(Transformed::no(expr))
+            })?
+            .data;
+        Ok(ExprSimplifyResult::Simplified(cast(
+            body,
+            self.return_type.clone(),
+        )))
+    }
+}
+
+/// A table-valued `LANGUAGE SQL` function: its body planned once with the
+/// parameters as placeholders, bound to the arguments on each call.
+#[derive(Debug)]
+pub(super) struct SqlTableFunction {
+    name: String,
+    params: Vec<SqlParam>,
+    plan: LogicalPlan,
+}
+
+impl SqlTableFunction {
+    /// `plan` is the body returned by [`parse_table_body`], planned.
+    pub(super) fn new(function: &Function, params: Vec<SqlParam>, plan: LogicalPlan) -> Self {
+        Self {
+            name: function.full_name.to_ascii_lowercase(),
+            params,
+            plan,
+        }
+    }
+}
+
+impl TableFunctionImpl for SqlTableFunction {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        if args.len() > self.params.len() {
+            return plan_err!(
+                "'{}' takes at most {} arguments, got {}",
+                self.name,
+                self.params.len(),
+                args.len()
+            );
+        }
+        let values = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let value = match args.get(i) {
+                    Some(arg) => const_value(arg).map_err(|e| {
+                        plan_datafusion_err!(
+                            "argument '{}' of '{}' must be a constant: {e}",
+                            param.name,
+                            self.name
+                        )
+                    })?,
+                    None => param.default.clone().ok_or_else(|| {
+                        plan_datafusion_err!("'{}' is missing argument '{}'", self.name, param.name)
+                    })?,
+                };
+                value.cast_to(&param.data_type)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let plan = self.plan.clone().with_param_values(values)?;
+        Ok(Arc::new(ViewTable::new(plan, None)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{AsArray, Int64Array};
+    use datafusion::arrow::datatypes::Int32Type;
+    use datafusion::logical_expr::ScalarUDF;
+    use datafusion::prelude::SessionContext;
+    use unitycatalog_common::models::functions::v1::{
+        FunctionParameterInfo, FunctionParameterInfos,
+    };
+
+    use super::*;
+
+    fn param(
+        name: &str,
+        type_text: &str,
+        position: i32,
+        default: Option<&str>,
+    ) -> FunctionParameterInfo {
+        FunctionParameterInfo {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            position: Some(position),
+            parameter_default: default.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn function(name: &str, data_type: &str, params: Vec<FunctionParameterInfo>) -> Function {
+        Function {
+            name: name.to_string(),
+            catalog_name: "main".to_string(),
+            schema_name: "default".to_string(),
+            full_name: format!("main.default.{name}"),
+            data_type: data_type.to_string(),
+            full_data_type: data_type.to_string(),
+            input_params: Some(FunctionParameterInfos { parameters: params }),
+            is_deterministic: true,
+            ..Default::default()
+        }
+    }
+
+    async fn run(ctx: &SessionContext, sql: &str) -> Vec<RecordBatch> {
+        let mut statement = DFParser::parse_sql(sql).unwrap().pop_front().unwrap();
+        rewrite_function_references(&mut statement);
+        let plan = ctx.state().statement_to_plan(statement).await.unwrap();
+        ctx.execute_logical_plan(plan)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn three_part_calls_are_rewritten_to_registered_names() {
+        let mut statement = DFParser::parse_sql(
+            "SELECT Main.Default.Add_One(x), abs(x) FROM main.default.t, main.default.series(3)",
+        )
+        .unwrap()
+        .pop_front()
+        .unwrap();
+        let names = rewrite_function_references(&mut statement);
+        assert_eq!(names, ["main.default.add_one", "main.default.series"]);
+        let rendered = statement.to_string();
+        // Table references keep their compound names.
+        assert!(rendered.contains("main.default.t"), "{rendered}");
+    }
+
+    #[tokio::test]
+    async fn scalar_functions_inline_with_defaults() {
+        let ctx = SessionContext::new();
+        let state = ctx.state();
+        let add = function(
+            "add",
+            "INT",
+            vec![param("x", "INT", 0, None), param("y", "INT", 1, Some("10"))],
+        );
+        let params = sql_params(&state, &add).unwrap();
+        let (body, nested) = parse_scalar_body("x + y").unwrap();
+        assert!(nested.is_empty());
+        let udf = SqlScalarFunction::try_new(&state, &add, params, &body).unwrap();
+        ctx.register_udf(ScalarUDF::new_from_impl(udf));
+
+        let batches = run(&ctx, "SELECT main.default.add(1, 2), main.default.add(1)").await;
+        let batch = &batches[0];
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().value(0), 3);
+        assert_eq!(batch.column(1).as_primitive::<Int32Type>().value(0), 11);
+    }
+
+    #[test]
+    fn defaults_must_trail() {
+        let state = SessionContext::new().state();
+        let bad = function(
+            "bad",
+            "INT",
+            vec![param("x", "INT", 0, Some("1")), param("y", "INT", 1, None)],
+        );
+        let err = sql_params(&state, &bad).unwrap_err();
+        assert!(err.to_string().contains("has no default"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn table_functions_bind_arguments() {
+        let ctx = SessionContext::new();
+        let state = ctx.state();
+        let series = function(
+            "series",
+            "TABLE_TYPE",
+            vec![param("n", "INT", 0, Some("2"))],
+        );
+        assert!(is_table_function(&series));
+        let params = sql_params(&state, &series).unwrap();
+        let (statement, _) = parse_table_body(
+            "SELECT v FROM (VALUES (1), (2), (3)) AS t(v) WHERE v <= n",
+            &params,
+        )
+        .unwrap();
+        let plan = state.statement_to_plan(statement).await.unwrap();
+        ctx.register_udtf(
+            "main.default.series",
+            Arc::new(SqlTableFunction::new(&series, params, plan)),
+        );
+
+        let count = |batches: Vec<RecordBatch>| batches.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(
+            count(run(&ctx, "SELECT * FROM main.default.series(3)").await),
+            3
+        );
+        assert_eq!(
+            count(run(&ctx, "SELECT * FROM main.default.series()").await),
+            2
+        );
+        let batches = run(&ctx, "SELECT max(v) FROM main.default.series(1)").await;
+        let max = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(max.value(0), 1);
+    }
+}
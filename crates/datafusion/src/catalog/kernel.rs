@@ -10,9 +10,11 @@
 //! `_delta_log/_staged_commits/`). We build a `delta_kernel` snapshot from the
 //! supplied commit tail + catalog version and wrap it with [`Snapshot::new`],
 //! mirroring the reference `delta-kernel-unity-catalog` read path.
+//! [`build_snapshot_as_of`] reads a table — managed or external — at an earlier
+//! version or timestamp instead.
 
 use datafusion::common::DataFusionError;
-use delta_kernel::snapshot::Snapshot as KernelSnapshot;
+use delta_kernel::snapshot::{Snapshot as KernelSnapshot, SnapshotRef};
 use delta_kernel::{Engine, LogPath, Version};
 use deltalake_core::DeltaTableConfig;
 use deltalake_core::kernel::Snapshot;
@@ -20,6 +22,7 @@ use unitycatalog_common::models::delta::v1::{DeltaCommit, DeltaLoadTableResponse
 use url::Url;
 
 use super::builder::TableProviderError;
+use super::time_travel::TimeTravel;
 
 /// How a `/delta/v1` loadTable response says a Delta table should be read.
 ///
@@ -188,6 +191,199 @@ pub fn build_catalog_managed_snapshot(
     Ok(Snapshot::new(inner, DeltaTableConfig::default()))
 }
 
+/// Build the [`Snapshot`] of a Delta table at a point in its history.
+///
+/// `managed` is the catalog-ratified commit tail and latest version of a
+/// catalog-managed table (see [`ManagedReadState::Managed`]), or `None` to read
+/// the filesystem `_delta_log/` of an external table.
+///
+/// A version is read directly; [`build_catalog_managed_snapshot`] refuses one
+/// beyond the latest ratified version. A timestamp is resolved to the latest
+/// version committed at or before it, which must exist. For a managed table the
+/// newest versions may exist only as ratified commits in the tail, so they are
+/// matched on the timestamps the catalog recorded for them, and only older
+/// versions are looked up in the published log. There a commit's timestamp is
+/// its in-commit timestamp, or the modification time of its commit file when
+/// in-commit timestamps were not enabled at that version.
+pub fn build_snapshot_as_of(
+    engine: &dyn Engine,
+    location: &Url,
+    managed: Option<(&[DeltaCommit], Version)>,
+    at: TimeTravel,
+) -> Result<Snapshot, TableProviderError> {
+    let table_root = Url::parse(&ensure_trailing_slash(location.as_str()))
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let version = match at {
+        TimeTravel::Version(version) => version,
+        TimeTravel::Timestamp(timestamp) => {
+            version_at_timestamp(engine, &table_root, managed, timestamp)?
+        }
+    };
+    match managed {
+        Some((commits, latest)) => build_catalog_managed_snapshot(
+            engine,
+            &table_root,
+            commits,
+            latest as i64,
+            Some(version),
+        ),
+        None => {
+            let inner = kernel_snapshot(engine, &table_root, Some(version))?;
+            Ok(Snapshot::new(inner, DeltaTableConfig::default()))
+        }
+    }
+}
+
+/// The latest version of the table at `table_root` committed at or before
+/// `timestamp` (milliseconds since the Unix epoch). See [`build_snapshot_as_of`].
+fn version_at_timestamp(
+    engine: &dyn Engine,
+    table_root: &Url,
+    managed: Option<(&[DeltaCommit], Version)>,
+    timestamp: i64,
+) -> Result<Version, TableProviderError> {
+    // The ratified tail is newer than everything published, so a match there
+    // is the answer and the published log only needs searching below it.
+    let last_published = match managed {
+        Some((commits, latest)) => {
+            let mut commits: Vec<&DeltaCommit> = commits.iter().collect();
+            commits.sort_by_key(|c| c.version);
+            if let Some(commit) = commits.iter().rev().find(|c| c.timestamp <= timestamp) {
+                return Ok(commit.version as Version);
+            }
+            match commits.first() {
+                Some(first) if first.version == 0 => None,
+                Some(first) => Some(first.version as Version - 1),
+                None => Some(latest),
+            }
+        }
+        None => Some(kernel_snapshot(engine, table_root, None)?.version()),
+    };
+
+    let Some(mut high) = last_published else {
+        return Err(before_history(table_root, timestamp));
+    };
+    // Log cleanup may have removed the oldest commit files, so search from the
+    // earliest one still published rather than from version 0.
+    let Some(mut low) = earliest_published(engine, table_root)?.filter(|v| *v <= high) else {
+        return Err(before_history(table_root, timestamp));
+    };
+    let timestamp_of = |version| commit_timestamp(engine, table_root, version);
+    if timestamp_of(low)? > timestamp {
+        return Err(before_history(table_root, timestamp));
+    }
+    // Commit timestamps increase with the version: find the last one not after
+    // `timestamp`, keeping `timestamp_of(low) <= timestamp`.
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if timestamp_of(mid)? <= timestamp {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Ok(low)
+}
+
+fn before_history(table_root: &Url, timestamp: i64) -> DataFusionError {
+    DataFusionError::Plan(format!(
+        "timestamp {timestamp} is before the earliest version of '{table_root}'"
+    ))
+}
+
+/// The oldest version with a commit file in the published `_delta_log/`, or
+/// `None` when nothing has been published.
+fn earliest_published(
+    engine: &dyn Engine,
+    table_root: &Url,
+) -> Result<Option<Version>, TableProviderError> {
+    let start = table_root
+        .join("_delta_log/00000000000000000000")
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let files = engine
+        .storage_handler()
+        .list_from(&start)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    for file in files {
+        let file = file.map_err(|e| DataFusionError::External(Box::new(e)))?;
+        let name = file.location.path().rsplit('/').next().unwrap_or_default();
+        if let Some(version) = name.strip_suffix(".json")
+            && version.len() == 20
+            && let Ok(version) = version.parse()
+        {
+            return Ok(Some(version));
+        }
+    }
+    Ok(None)
+}
+
+/// Bytes read per request while looking for the end of a commit file's first
+/// line; a `commitInfo` action almost always fits in the first read.
+const FIRST_LINE_CHUNK: u64 = 4096;
+
+/// The commit timestamp of published `version`: the in-commit timestamp in the
+/// `commitInfo` of its commit file, or the modification time of that file
+/// before in-commit timestamps were enabled.
+///
+/// With in-commit timestamps enabled the protocol requires `commitInfo` to be
+/// the first action, so only the file's first line is read and parsed.
+fn commit_timestamp(
+    engine: &dyn Engine,
+    table_root: &Url,
+    version: Version,
+) -> Result<i64, TableProviderError> {
+    let commit_file = table_root
+        .join(&format!("_delta_log/{version:020}.json"))
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let storage = engine.storage_handler();
+    let meta = storage
+        .head(&commit_file)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let mut len = FIRST_LINE_CHUNK.min(meta.size);
+    let first_line = loop {
+        let contents = storage
+            .read_files(vec![(commit_file.clone(), Some(0..len))])
+            .map_err(|e| DataFusionError::External(Box::new(e)))?
+            .next()
+            .transpose()
+            .map_err(|e| DataFusionError::External(Box::new(e)))?
+            .unwrap_or_default();
+        if let Some(end) = contents.iter().position(|b| *b == b'\n') {
+            break contents.slice(..end);
+        }
+        if len >= meta.size {
+            break contents;
+        }
+        len = (len * 2).min(meta.size);
+    };
+    Ok(in_commit_timestamp(&first_line).unwrap_or(meta.last_modified))
+}
+
+/// The `commitInfo.inCommitTimestamp` of a commit file's first action.
+fn in_commit_timestamp(first_line: &[u8]) -> Option<i64> {
+    serde_json::from_slice::<serde_json::Value>(first_line)
+        .ok()?
+        .get("commitInfo")?
+        .get("inCommitTimestamp")?
+        .as_i64()
+}
+
+/// A kernel snapshot of the filesystem log at `table_root` at `version`, or at
+/// the latest version when `None`.
+fn kernel_snapshot(
+    engine: &dyn Engine,
+    table_root: &Url,
+    version: Option<Version>,
+) -> Result<SnapshotRef, TableProviderError> {
+    let mut builder = KernelSnapshot::builder_for(table_root.clone());
+    if let Some(version) = version {
+        builder = builder.at_version(version);
+    }
+    builder
+        .build(engine)
+        .map_err(|e| DataFusionError::External(Box::new(e)))
+}
+
 /// The staged-commit file name to hand the kernel, tolerating both forms the spec
 /// permits: the canonical `<20-digit-version>.<uuid>.json` (ManagedTablesSpec §
 /// Terminology) and the bare `<uuid>.json` shown in the getCommits example. The
@@ -261,6 +457,8 @@ pub fn ensure_trailing_slash(s: &str) -> String {
 mod tests {
     use std::collections::BTreeMap;
 
+    use datafusion::prelude::SessionContext;
+    use deltalake_core::delta_datafusion::engine::DataFusionEngine;
     use unitycatalog_common::models::delta::v1::{
         DeltaStructType, DeltaTableMetadata, StructTypeTag,
     };
@@ -368,6 +566,80 @@ mod tests {
         );
     }
 
+    fn timed_commit(version: i64, timestamp: i64) -> DeltaCommit {
+        DeltaCommit {
+            timestamp,
+            ..commit(version, &staged_name(version as u64), 10)
+        }
+    }
+
+    #[test]
+    fn timestamp_in_commit_tail_resolves_without_the_published_log() {
+        let engine = DataFusionEngine::new_from_context(SessionContext::new().task_ctx());
+        let root = Url::parse("s3://bucket/table/").unwrap();
+        let commits = vec![timed_commit(4, 400), timed_commit(3, 300)];
+        let managed = Some((commits.as_slice(), 4));
+
+        let at = |timestamp| version_at_timestamp(engine.as_ref(), &root, managed, timestamp);
+        assert_eq!(at(300).unwrap(), 3);
+        assert_eq!(at(399).unwrap(), 3);
+        assert_eq!(at(10_000).unwrap(), 4);
+    }
+
+    #[test]
+    fn timestamp_before_a_tail_from_version_zero_is_error() {
+        let engine = DataFusionEngine::new_from_context(SessionContext::new().task_ctx());
+        let root = Url::parse("s3://bucket/table/").unwrap();
+        let commits = vec![timed_commit(0, 100), timed_commit(1, 200)];
+
+        let err = version_at_timestamp(engine.as_ref(), &root, Some((commits.as_slice(), 1)), 99)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("before the earliest version"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn timestamp_between_published_log_and_commit_tail() {
+        let dir = std::env::temp_dir().join(format!("uc-time-travel-{}", std::process::id()));
+        let log = dir.join("_delta_log");
+        std::fs::create_dir_all(&log).unwrap();
+        // Versions 0 and 1 were cleaned up; 2 and 3 are published, 4 and 5
+        // exist only in the ratified tail. Version 3's `commitInfo` is longer
+        // than one read, so its first line takes several.
+        for (version, timestamp, engine_info) in [(2, 200, 1), (3, 300, 10_000)] {
+            let commit_info = serde_json::json!({
+                "commitInfo": {
+                    "inCommitTimestamp": timestamp,
+                    "operation": "WRITE",
+                    "engineInfo": "x".repeat(engine_info),
+                }
+            });
+            std::fs::write(
+                log.join(format!("{version:020}.json")),
+                format!("{commit_info}\n{{\"add\":{{}}}}\n"),
+            )
+            .unwrap();
+        }
+        let engine = DataFusionEngine::new_from_context(SessionContext::new().task_ctx());
+        let root = Url::from_directory_path(&dir).unwrap();
+        let commits = vec![timed_commit(4, 400), timed_commit(5, 500)];
+        let managed = Some((commits.as_slice(), 5));
+
+        let at = |timestamp| version_at_timestamp(engine.as_ref(), &root, managed, timestamp);
+        assert_eq!(at(250).unwrap(), 2);
+        assert_eq!(at(399).unwrap(), 3);
+        assert_eq!(at(450).unwrap(), 4);
+        let err = at(199).unwrap_err();
+        assert!(
+            err.to_string().contains("before the earliest version"),
+            "unexpected error: {err}"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn resolve_external_is_not_managed() {
         let r =
@@ -0,0 +1,271 @@
+//! Plain-file (Parquet, CSV, JSON, Avro) Unity Catalog tables as DataFusion
+//! [`ListingTable`]s.
+//!
+//! Unlike Delta, these formats carry no log to read the schema from, so the
+//! table schema is the one stored in Unity Catalog: each column's `type_json`
+//! (a Delta/Spark struct-field JSON) when present, otherwise its `type_name`
+//! plus precision/scale. Columns with a `partition_index` become Hive-style
+//! partition columns (`<column>=<value>/` directories), in index order.
+
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion::catalog::TableProvider;
+use datafusion::common::{DataFusionError, plan_datafusion_err};
+use datafusion::datasource::file_format::FileFormat;
+#[cfg(feature = "avro")]
+use datafusion::datasource::file_format::avro::AvroFormat;
+use datafusion::datasource::file_format::csv::CsvFormat;
+use datafusion::datasource::file_format::json::JsonFormat;
+use datafusion::datasource::file_format::parquet::ParquetFormat;
+use datafusion::datasource::listing::{
+    ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+};
+use datafusion::error::Result;
+use serde_json::Value;
+use unitycatalog_common::models::tables::v1::{Column, ColumnTypeName, DataSourceFormat, Table};
+use url::Url;
+
+use super::types::{decimal, json_to_arrow, primitive_to_arrow};
+
+/// Whether `format` is served by [`build_listing_table`].
+pub(super) fn is_listing_format(format: DataSourceFormat) -> bool {
+    match format {
+        DataSourceFormat::Parquet | DataSourceFormat::Csv | DataSourceFormat::Json => true,
+        DataSourceFormat::Avro => cfg!(feature = "avro"),
+        _ => false,
+    }
+}
+
+/// Build a [`ListingTable`] over the files under `location`.
+///
+/// The object store serving `location` must already be registered on the
+/// session runtime; the listing happens at scan time.
+pub(super) fn build_listing_table(
+    location: &Url,
+    table: &Table,
+    format: DataSourceFormat,
+) -> Result<Arc<dyn TableProvider>> {
+    let file_format: Arc<dyn FileFormat> = match format {
+        DataSourceFormat::Parquet => Arc::new(ParquetFormat::default()),
+        DataSourceFormat::Csv => Arc::new(csv_format(table)),
+        DataSourceFormat::Json => Arc::new(JsonFormat::default()),
+        #[cfg(feature = "avro")]
+        DataSourceFormat::Avro => Arc::new(AvroFormat),
+        other => {
+            return Err(DataFusionError::NotImplemented(format!(
+                "Unity Catalog table '{}' has unsupported data source format {other:?}",
+                table.full_name
+            )));
+        }
+    };
+
+    let (file_schema, partition_cols) = listing_schema(&table.columns)
+        .map_err(|e| plan_datafusion_err!("table '{}': {e}", table.full_name))?;
+
+    // A table location is a directory; without the trailing slash the listing
+    // would treat the last path segment as a file prefix.
+    let mut url = location.clone();
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+    let options = ListingOptions::new(file_format.clone())
+        .with_file_extension(file_format.get_ext())
+        .with_table_partition_cols(partition_cols);
+    let config = ListingTableConfig::new(ListingTableUrl::parse(url.as_str())?)
+        .with_listing_options(options)
+        .with_schema(file_schema);
+    Ok(Arc::new(ListingTable::try_new(config)?))
+}
+
+/// CSV options from the table properties, following Spark's data source
+/// option names (`header`, `delimiter` / `sep`). Spark's defaults apply
+/// otherwise: no header row, comma-separated.
+fn csv_format(table: &Table) -> CsvFormat {
+    let header = table
+        .properties
+        .get("header")
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+    let mut format = CsvFormat::default().with_has_header(header);
+    if let Some(&[delimiter]) = table
+        .properties
+        .get("delimiter")
+        .or_else(|| table.properties.get("sep"))
+        .map(|d| d.as_bytes())
+    {
+        format = format.with_delimiter(delimiter);
+    }
+    format
+}
+
+/// Split the stored UC columns into the file schema and the partition columns
+/// (in partition-index order).
+fn listing_schema(
+    columns: &[Column],
+) -> std::result::Result<(SchemaRef, Vec<(String, DataType)>), String> {
+    if columns.is_empty() {
+        return Err("no columns are registered in Unity Catalog".to_string());
+    }
+    let mut sorted: Vec<&Column> = columns.iter().collect();
+    sorted.sort_by_key(|c| c.position.unwrap_or(i32::MAX));
+
+    let mut fields = Vec::new();
+    let mut partitions = Vec::new();
+    for column in sorted {
+        let field = column_to_field(column)?;
+        match column.partition_index {
+            Some(index) => partitions.push((index, field)),
+            None => fields.push(field),
+        }
+    }
+    partitions.sort_by_key(|(index, _)| *index);
+    let partition_cols = partitions
+        .into_iter()
+        .map(|(_, field)| (field.name().clone(), field.data_type().clone()))
+        .collect();
+    Ok((Arc::new(Schema::new(fields)), partition_cols))
+}
+
+/// The Arrow field for a stored UC column.
+fn column_to_field(column: &Column) -> std::result::Result<Field, String> {
+    let nullable = column.nullable.unwrap_or(true);
+    if let Ok(Value::Object(field)) = serde_json::from_str::<Value>(&column.type_json)
+        && let Some(data_type) = field.get("type")
+    {
+        let data_type =
+            json_to_arrow(data_type).map_err(|e| format!("column '{}': {e}", column.name))?;
+        return Ok(Field::new(&column.name, data_type, nullable));
+    }
+    let data_type = match ColumnTypeName::try_from(column.type_name)
+        .unwrap_or(ColumnTypeName::Unspecified)
+    {
+        ColumnTypeName::Decimal => decimal(
+            column.type_precision.unwrap_or(10),
+            column.type_scale.unwrap_or(0),
+        )?,
+        // The other primitive names match Delta's once lowercased.
+        ColumnTypeName::Int => DataType::Int32,
+        other => primitive_to_arrow(&other.as_str_name().to_ascii_lowercase()).map_err(|_| {
+            format!(
+                "column '{}' has type '{}' without a usable type_json",
+                column.name, column.type_text
+            )
+        })?,
+    };
+    Ok(Field::new(&column.name, data_type, nullable))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, type_json: &str, partition_index: Option<i32>, position: i32) -> Column {
+        Column {
+            name: name.into(),
+            type_json: type_json.into(),
+            position: Some(position),
+            partition_index,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn partition_columns_are_split_out_in_index_order() {
+        let columns = vec![
+            column(
+                "month",
+                r#"{"name":"month","type":"integer","nullable":true,"metadata":{}}"#,
+                Some(1),
+                2,
+            ),
+            column(
+                "amount",
+                r#"{"name":"amount","type":"decimal(12,2)","nullable":true,"metadata":{}}"#,
+                None,
+                1,
+            ),
+            column(
+                "year",
+                r#"{"name":"year","type":"integer","nullable":true,"metadata":{}}"#,
+                Some(0),
+                3,
+            ),
+            column(
+                "id",
+                r#"{"name":"id","type":"long","nullable":false,"metadata":{}}"#,
+                None,
+                0,
+            ),
+        ];
+        let (schema, partitions) = listing_schema(&columns).unwrap();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|f| (f.name().as_str(), f.data_type().clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("id", DataType::Int64),
+                ("amount", DataType::Decimal128(12, 2)),
+            ]
+        );
+        assert_eq!(
+            partitions,
+            vec![
+                ("year".to_string(), DataType::Int32),
+                ("month".to_string(), DataType::Int32),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_types_come_from_type_json() {
+        // Spark's camelCase and the Delta API's kebab-case keys mix freely.
+        let tags = column(
+            "tags",
+            r#"{"name":"tags","type":{"type":"map","keyType":"string","valueType":{"type":"array","element-type":"long","contains-null":true},"valueContainsNull":true},"nullable":true,"metadata":{}}"#,
+            None,
+            0,
+        );
+        let DataType::Map(entries, _) = column_to_field(&tags).unwrap().data_type().clone() else {
+            panic!("expected a map");
+        };
+        let DataType::Struct(fields) = entries.data_type() else {
+            panic!("expected map entries");
+        };
+        assert_eq!(fields[0].data_type(), &DataType::Utf8);
+        assert!(
+            matches!(fields[1].data_type(), DataType::List(item) if item.data_type() == &DataType::Int64)
+        );
+    }
+
+    #[test]
+    fn type_name_is_the_fallback() {
+        let price = Column {
+            name: "price".into(),
+            type_name: ColumnTypeName::Decimal as i32,
+            type_precision: Some(9),
+            type_scale: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            column_to_field(&price).unwrap().data_type(),
+            &DataType::Decimal128(9, 3)
+        );
+        let count = Column {
+            name: "count".into(),
+            type_name: ColumnTypeName::Int as i32,
+            ..Default::default()
+        };
+        assert_eq!(
+            column_to_field(&count).unwrap().data_type(),
+            &DataType::Int32
+        );
+        let opaque = Column {
+            name: "opaque".into(),
+            type_name: ColumnTypeName::UserDefinedType as i32,
+            ..Default::default()
+        };
+        assert!(column_to_field(&opaque).is_err());
+    }
+}
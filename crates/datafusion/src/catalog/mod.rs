@@ -11,11 +11,31 @@
 //! 1. fetch the [`Table`] metadata (storage location, format, id) from UC,
 //! 2. vend credentials and register a per-table object store so the engine can
 //!    read the table's storage location at scan time (see [`crate::storage`]),
-//! 3. build a [`TableProvider`] for the table's data source format (Delta).
+//! 3. build a [`TableProvider`] for the table's data source format.
 //!
-//! Step 3 is delegated to a [`TableProviderBuilder`] supplied by the embedder,
-//! because constructing a Delta provider requires log-store / engine wiring
-//! that belongs to the host session rather than this generic crate.
+//! For Delta tables step 3 is delegated to a [`TableProviderBuilder`] supplied
+//! by the embedder, because constructing a Delta provider requires log-store /
+//! engine wiring that belongs to the host session rather than this generic
+//! crate. Parquet, CSV, JSON and Avro tables need no such wiring and resolve to
+//! DataFusion `ListingTable`s built from the schema stored in Unity Catalog.
+//! SQL views are planned over their references, resolved recursively through
+//! the same path, and become DataFusion `ViewTable`s.
+//!
+//! Functions are not part of DataFusion's table resolution: call
+//! [`UnityCatalogProviderList::resolve_functions`] on a statement before
+//! planning it to register the `LANGUAGE SQL` functions it calls — scalar
+//! functions inline their body, table-valued ones plan it over the arguments.
+//!
+//! A Delta table can be read at an earlier version or timestamp by suffixing
+//! its name with `@v<version>` or `@yyyyMMddHHmmssSSS` (see
+//! [`split_time_travel`]), at the top level or inside a view or table-valued
+//! function definition; such a read goes through
+//! [`TableProviderBuilder::build_delta_at`].
+//!
+//! Catalog-managed Delta tables are additionally wrapped in a
+//! [`ManagedDeltaTable`](crate::managed::ManagedDeltaTable) (`delta` feature), so
+//! `INSERT`, `DELETE` and `UPDATE` against them commit through the catalog.
+//! Time-travel reads are not: a past version is read-only.
 //!
 //! [`AsyncCatalogProviderList`]: datafusion::catalog::AsyncCatalogProviderList
 //! [`AsyncCatalogProvider`]: datafusion::catalog::AsyncCatalogProvider
@@ -25,16 +45,23 @@
 mod builder;
 #[cfg(feature = "delta")]
 mod delta;
+mod functions;
 #[cfg(feature = "delta")]
 mod kernel;
+mod listing;
 mod provider;
+mod time_travel;
+pub(crate) mod types;
 
 pub use builder::{TableProviderBuilder, TableProviderError};
 #[cfg(feature = "delta")]
 pub use delta::DeltaTableProviderBuilder;
 #[cfg(feature = "delta")]
+pub(crate) use delta::{log_store_for, provider_from_snapshot};
+#[cfg(feature = "delta")]
 pub use kernel::{
-    ManagedReadState, build_catalog_managed_snapshot, ensure_trailing_slash,
+    ManagedReadState, build_catalog_managed_snapshot, build_snapshot_as_of, ensure_trailing_slash,
     resolve_managed_read_state, to_log_tail,
 };
 pub use provider::{UnityCatalogProvider, UnityCatalogProviderList, UnityCatalogSchemaProvider};
+pub use time_travel::{TimeTravel, split_time_travel};
@@ -1,19 +1,33 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use dashmap::DashMap;
 use datafusion::catalog::{
-    AsyncCatalogProvider, AsyncCatalogProviderList, AsyncSchemaProvider, TableProvider,
+    AsyncCatalogProvider, AsyncCatalogProviderList, AsyncSchemaProvider, CatalogProvider,
+    MemoryCatalogProvider, MemoryCatalogProviderList, MemorySchemaProvider, SchemaProvider,
+    TableProvider,
 };
-use datafusion::common::{DataFusionError, plan_datafusion_err};
+use datafusion::common::{
+    DataFusionError, ResolvedTableReference, TableReference, plan_datafusion_err, plan_err,
+};
+use datafusion::datasource::ViewTable;
 use datafusion::error::Result;
 use datafusion::execution::runtime_env::RuntimeEnv;
+use datafusion::execution::{SessionState, SessionStateBuilder};
+use datafusion::logical_expr::{LogicalPlan, ScalarUDF};
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use futures::future::BoxFuture;
 use object_store::path::Path;
 use tracing::{debug, instrument};
-use unitycatalog_common::models::tables::v1::DataSourceFormat;
+use unitycatalog_common::models::functions::v1::RoutineBody;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table, TableType};
 use unitycatalog_object_store::{TableOperation, UnityObjectStoreFactory};
 use url::Url;
 
 use super::builder::TableProviderBuilder;
+use super::time_travel::{TimeTravel, split_time_travel};
+use super::{functions, listing};
 use crate::storage::RoutingObjectStore;
 
 /// Shared state used while resolving Unity Catalog references for a query.
@@ -29,6 +43,9 @@ struct UnityContext {
     builder: Arc<dyn TableProviderBuilder>,
     /// `scheme://host` -> routing store registered on `runtime` for that host.
     routers: Arc<DashMap<String, RoutingObjectStore>>,
+    /// The host session view definitions are planned against; see
+    /// [`UnityCatalogProviderList::with_session`].
+    session: Option<SessionContext>,
 }
 
 impl std::fmt::Debug for UnityContext {
@@ -38,6 +55,19 @@ impl std::fmt::Debug for UnityContext {
 }
 
 impl UnityContext {
+    /// A scratch session scoped to `catalog`.`schema`, derived from the host
+    /// session if one was given (see [`scoped_session`]).
+    fn scoped_session(&self, catalog: &str, schema: &str) -> SessionContext {
+        let state = match &self.session {
+            Some(session) => session.state(),
+            None => SessionStateBuilder::new()
+                .with_default_features()
+                .with_runtime_env(self.runtime.clone())
+                .build(),
+        };
+        scoped_session(&state, catalog, schema)
+    }
+
     /// Ensure a [`RoutingObjectStore`] is registered for the bucket of `url`,
     /// then route `url`'s prefix to `store` within it.
     ///
@@ -73,6 +103,53 @@ fn bucket_key(url: &Url) -> String {
     )
 }
 
+/// Register `provider` as `reference` in `ctx`, creating the in-memory catalog
+/// and schema on first use.
+fn register_resolved(
+    ctx: &SessionContext,
+    reference: &ResolvedTableReference,
+    provider: Arc<dyn TableProvider>,
+) -> Result<()> {
+    let catalog: Arc<dyn CatalogProvider> = match ctx.catalog(&reference.catalog) {
+        Some(catalog) => catalog,
+        None => {
+            let catalog = Arc::new(MemoryCatalogProvider::new());
+            ctx.register_catalog(reference.catalog.as_ref(), catalog.clone());
+            catalog
+        }
+    };
+    let schema: Arc<dyn SchemaProvider> = match catalog.schema(&reference.schema) {
+        Some(schema) => schema,
+        None => {
+            let schema = Arc::new(MemorySchemaProvider::new());
+            catalog.register_schema(&reference.schema, schema.clone())?;
+            schema
+        }
+    };
+    if !schema.table_exist(&reference.table) {
+        schema.register_table(reference.table.to_string(), provider)?;
+    }
+    Ok(())
+}
+
+/// A scratch session for planning a view or function definition, resolving
+/// unqualified names against `catalog`.`schema`.
+///
+/// Built from the host `state`, so the definition is planned with the host's
+/// configuration, functions and runtime; only the catalogs are its own, so the
+/// relations registered while planning do not leak into the host session.
+fn scoped_session(state: &SessionState, catalog: &str, schema: &str) -> SessionContext {
+    let config = state
+        .config()
+        .clone()
+        .with_default_catalog_and_schema(catalog, schema);
+    let state = SessionStateBuilder::new_from_existing(state.clone())
+        .with_config(config)
+        .with_catalog_list(Arc::new(MemoryCatalogProviderList::new()))
+        .build();
+    SessionContext::new_with_state(state)
+}
+
 /// Catalog list backed by a live Unity Catalog instance.
 ///
 /// Register with DataFusion via the async resolution flow: call
@@ -95,9 +172,153 @@ impl UnityCatalogProviderList {
                 runtime,
                 builder,
                 routers: Arc::new(DashMap::new()),
+                session: None,
             },
         }
     }
+
+    /// Plan view and function definitions against `session`'s configuration,
+    /// functions and runtime rather than a default session.
+    pub fn with_session(mut self, session: &SessionContext) -> Self {
+        self.ctx.session = Some(session.clone());
+        self
+    }
+}
+
+impl UnityCatalogProviderList {
+    /// Register the Unity Catalog functions `statement` calls on `session`, so
+    /// the statement can be planned there.
+    ///
+    /// Table references are resolved lazily during planning, but DataFusion
+    /// looks functions up synchronously, so call this first. Three-part
+    /// function names in `statement` are rewritten in place to the names the
+    /// functions are registered under; names Unity Catalog does not know are
+    /// left for planning to report. Only `LANGUAGE SQL` functions can run.
+    ///
+    /// Every call fetches the functions afresh and replaces what an earlier
+    /// statement registered, so a function altered or dropped in Unity Catalog
+    /// takes effect on the next statement.
+    pub async fn resolve_functions(
+        &self,
+        session: &SessionContext,
+        statement: &mut DFStatement,
+    ) -> Result<()> {
+        let mut resolved = HashSet::new();
+        for full_name in functions::rewrite_function_references(statement) {
+            resolve_function(
+                &self.ctx,
+                session,
+                &full_name,
+                &mut Vec::new(),
+                &mut resolved,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Fetch the function `full_name` and register it on `session`, after the
+/// functions its body calls. `visiting` holds the functions being resolved,
+/// to report recursion instead of looping; `resolved` holds those already
+/// fetched for the current statement.
+fn resolve_function<'a>(
+    ctx: &'a UnityContext,
+    session: &'a SessionContext,
+    full_name: &'a str,
+    visiting: &'a mut Vec<String>,
+    resolved: &'a mut HashSet<String>,
+) -> BoxFuture<'a, Result<()>> {
+    Box::pin(async move {
+        if resolved.contains(full_name) {
+            return Ok(());
+        }
+        if visiting.iter().any(|f| f == full_name) {
+            return plan_err!(
+                "function '{full_name}' calls itself: {} -> {full_name}",
+                visiting.join(" -> ")
+            );
+        }
+        let function = match ctx
+            .factory
+            .unity_client()
+            .function_from_full_name(full_name)
+            .get()
+            .await
+        {
+            Ok(function) => function,
+            Err(e) if e.is_not_found() => {
+                // Drop what an earlier statement registered, so planning
+                // reports the function as unknown rather than running it.
+                debug!("function '{full_name}' not found in Unity Catalog: {e}");
+                session.deregister_udf(full_name);
+                session.deregister_udtf(full_name);
+                resolved.insert(full_name.to_string());
+                return Ok(());
+            }
+            Err(e) => return Err(DataFusionError::External(Box::new(e))),
+        };
+        let state = session.state();
+        if function.routine_body != RoutineBody::Sql as i32 {
+            return Err(DataFusionError::NotImplemented(format!(
+                "function '{full_name}' is not a SQL function; \
+                 only LANGUAGE SQL functions can run in DataFusion"
+            )));
+        }
+        let definition = function.routine_definition.as_deref().ok_or_else(|| {
+            plan_datafusion_err!("function '{full_name}' has no routine_definition")
+        })?;
+        let params = functions::sql_params(&state, &function)?;
+
+        visiting.push(full_name.to_string());
+        if functions::is_table_function(&function) {
+            let (statement, calls) = functions::parse_table_body(definition, &params)?;
+            for call in &calls {
+                resolve_function(ctx, session, call, visiting, resolved).await?;
+            }
+            // The body is planned in a scratch session scoped to the
+            // function's schema; it inherits the functions the body calls,
+            // which were just registered on `session`.
+            let scratch = scoped_session(
+                &session.state(),
+                &function.catalog_name,
+                &function.schema_name,
+            );
+            let schema = UnityCatalogSchemaProvider {
+                ctx: ctx.clone(),
+                catalog: function.catalog_name.clone(),
+                schema: function.schema_name.clone(),
+            };
+            let plan = schema
+                .plan_scoped(
+                    &format!("function '{full_name}'"),
+                    &scratch,
+                    &function.catalog_name,
+                    &function.schema_name,
+                    statement,
+                    &mut Vec::new(),
+                )
+                .await;
+            visiting.pop();
+            session.deregister_udf(full_name);
+            session.register_udtf(
+                full_name,
+                Arc::new(functions::SqlTableFunction::new(&function, params, plan?)),
+            );
+        } else {
+            let (body, calls) = functions::parse_scalar_body(definition)?;
+            for call in &calls {
+                resolve_function(ctx, session, call, visiting, resolved).await?;
+            }
+            visiting.pop();
+            let udf =
+                functions::SqlScalarFunction::try_new(&session.state(), &function, params, &body)?;
+            session.deregister_udtf(full_name);
+            session.register_udf(ScalarUDF::new_from_impl(udf));
+        }
+        resolved.insert(full_name.to_string());
+        Ok(())
+    })
 }
 
 #[async_trait::async_trait]
@@ -170,6 +391,8 @@ impl AsyncSchemaProvider for UnityCatalogSchemaProvider {
         level = "info",
     )]
     async fn table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        // `orders@v42` reads table `orders` as of version 42.
+        let (name, at) = split_time_travel(name)?;
         let full_name = format!("{}.{}.{}", self.catalog, self.schema, name);
 
         // 1. Fetch table metadata from Unity Catalog.
@@ -190,37 +413,196 @@ impl AsyncSchemaProvider for UnityCatalogSchemaProvider {
             }
         };
 
-        // 2a. Metric views are resolved before the Delta-only check: they have
-        //     no storage location of their own, only a definition referencing a
-        //     source relation.
-        #[cfg(feature = "metric-view")]
-        if let Some(provider) = self.try_resolve_metric_view(&table).await? {
-            return Ok(Some(provider));
-        }
-
-        // 2. Otherwise resolve as a (Delta) base table.
-        let provider = self.build_base_table(&full_name, &table).await?;
+        // 2. Build a provider for it: a view, a metric view, or a base table.
+        let provider = self
+            .build_table_at(&full_name, &table, at, &mut Vec::new())
+            .await?;
         Ok(Some(provider))
     }
 }
 
 impl UnityCatalogSchemaProvider {
+    /// Resolve a fetched Unity Catalog table to a [`TableProvider`], as of `at`
+    /// when given. Only base tables have a history to read.
+    async fn build_table_at(
+        &self,
+        full_name: &str,
+        table: &Table,
+        at: Option<TimeTravel>,
+        visiting: &mut Vec<String>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let Some(at) = at else {
+            return self.build_table(full_name, table, visiting).await;
+        };
+        if table.table_type == TableType::View as i32 {
+            return Err(DataFusionError::NotImplemented(format!(
+                "time travel is not supported on view '{full_name}'"
+            )));
+        }
+        self.build_base_table(full_name, table, Some(at)).await
+    }
+
+    /// Resolve a fetched Unity Catalog table to a [`TableProvider`].
+    ///
+    /// `visiting` holds the views currently being expanded (outermost first),
+    /// so a view that reaches itself through its references is reported as a
+    /// cycle instead of recursing forever.
+    fn build_table<'a>(
+        &'a self,
+        full_name: &'a str,
+        table: &'a Table,
+        visiting: &'a mut Vec<String>,
+    ) -> BoxFuture<'a, Result<Arc<dyn TableProvider>>> {
+        Box::pin(async move {
+            if table.table_type == TableType::View as i32 {
+                return self.build_view(full_name, table, visiting).await;
+            }
+            // Metric views are resolved before the format check: they have no
+            // storage location of their own, only a definition referencing a
+            // source relation.
+            #[cfg(feature = "metric-view")]
+            if let Some(provider) = self
+                .try_resolve_metric_view(full_name, table, visiting)
+                .await?
+            {
+                return Ok(provider);
+            }
+            self.build_base_table(full_name, table, None).await
+        })
+    }
+
+    /// Resolve a SQL view to a DataFusion [`ViewTable`].
+    ///
+    /// The definition is planned in a scratch session whose catalog holds just
+    /// the relations it references, each resolved through Unity Catalog like a
+    /// top-level table (so views over views, metric views and non-Delta tables
+    /// all work). See [`Self::plan_scoped`].
+    ///
+    /// Every relation must be a three-part `catalog.schema.table` name: the
+    /// catalog derives a view's dependencies from its definition and rejects
+    /// anything else at create time, so an unqualified reference is refused
+    /// here too rather than guessed against the view's own schema.
+    async fn build_view(
+        &self,
+        full_name: &str,
+        table: &Table,
+        visiting: &mut Vec<String>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if visiting.iter().any(|v| v == full_name) {
+            return plan_err!(
+                "view '{full_name}' references itself: {} -> {full_name}",
+                visiting.join(" -> ")
+            );
+        }
+        let definition = table
+            .view_definition
+            .as_deref()
+            .ok_or_else(|| plan_datafusion_err!("view '{full_name}' has no view_definition"))?;
+        let mut statements = DFParser::parse_sql(definition)?;
+        let (Some(statement), None) = (statements.pop_front(), statements.pop_front()) else {
+            return plan_err!("view '{full_name}' must be defined by a single query");
+        };
+
+        let ctx = self
+            .ctx
+            .scoped_session(&table.catalog_name, &table.schema_name);
+        if let Some(reference) = ctx
+            .state()
+            .resolve_table_references(&statement)?
+            .into_iter()
+            .find(|r| !matches!(r, TableReference::Full { .. }))
+        {
+            return plan_err!(
+                "view '{full_name}' references '{reference}', which is not a three-part \
+                 catalog.schema.table name"
+            );
+        }
+        visiting.push(full_name.to_string());
+        let plan = self
+            .plan_scoped(
+                &format!("view '{full_name}'"),
+                &ctx,
+                &table.catalog_name,
+                &table.schema_name,
+                statement,
+                visiting,
+            )
+            .await;
+        visiting.pop();
+        Ok(Arc::new(ViewTable::new(
+            plan?,
+            Some(definition.to_string()),
+        )))
+    }
+
+    /// Plan `statement`, the definition of `owner` (e.g. `view 'c.s.v'`), in `ctx`:
+    /// the relations it references are resolved through Unity Catalog like
+    /// top-level tables and registered in `ctx` first. Unqualified references
+    /// resolve against `catalog` and `schema`.
+    ///
+    /// Referenced tables are read with credentials vended to the querying
+    /// principal: the catalog has no definer-rights credential vending, so the
+    /// reader needs access to the owner's sources too.
+    async fn plan_scoped(
+        &self,
+        owner: &str,
+        ctx: &SessionContext,
+        catalog: &str,
+        schema: &str,
+        statement: DFStatement,
+        visiting: &mut Vec<String>,
+    ) -> Result<LogicalPlan> {
+        let references = ctx.state().resolve_table_references(&statement)?;
+        for reference in references {
+            let reference = reference.resolve(catalog, schema);
+            // `orders@v42` reads table `orders` as of version 42, as at the top
+            // level; the provider is still registered under the name as written.
+            let (table, at) = split_time_travel(reference.table.as_ref())?;
+            let ref_name = format!("{}.{}.{table}", reference.catalog, reference.schema);
+            let ref_table = self
+                .ctx
+                .factory
+                .unity_client()
+                .table(reference.catalog.as_ref(), reference.schema.as_ref(), table)
+                .get()
+                .await
+                .map_err(|e| plan_datafusion_err!("{owner} references '{ref_name}': {e}"))?;
+            let provider = self
+                .build_table_at(&ref_name, &ref_table, at, visiting)
+                .await?;
+            register_resolved(ctx, &reference, provider)?;
+        }
+        ctx.state().statement_to_plan(statement).await
+    }
+
     /// Resolve a base (non-view) Unity Catalog table to a [`TableProvider`].
     ///
-    /// Vends credentials, registers the per-table object store, and delegates
-    /// provider construction to the host session's builder. Only Delta is
-    /// supported for now.
+    /// Vends credentials and registers the per-table object store. Delta
+    /// providers are built by the host session's builder, and managed Delta
+    /// tables are wrapped so they accept DML; Parquet, CSV, JSON and Avro
+    /// tables become [`ListingTable`]s over the stored UC schema.
+    ///
+    /// With `at`, a Delta table is read as of that point in its history and
+    /// is not wrapped: a past version is read-only. Other formats have no
+    /// history to read.
+    ///
+    /// [`ListingTable`]: datafusion::datasource::listing::ListingTable
     async fn build_base_table(
         &self,
         full_name: &str,
-        table: &unitycatalog_common::models::tables::v1::Table,
+        table: &Table,
+        at: Option<TimeTravel>,
     ) -> Result<Arc<dyn TableProvider>> {
         let format = DataSourceFormat::try_from(table.data_source_format)
             .unwrap_or(DataSourceFormat::Unspecified);
-        if format != DataSourceFormat::Delta {
+        if at.is_some() && format != DataSourceFormat::Delta {
+            return Err(DataFusionError::NotImplemented(format!(
+                "time travel is only supported on Delta tables; '{full_name}' is {format:?}"
+            )));
+        }
+        if format != DataSourceFormat::Delta && !listing::is_listing_format(format) {
             return Err(DataFusionError::NotImplemented(format!(
-                "Unity Catalog table '{full_name}' has unsupported data source format {format:?}; \
-                 only Delta is supported"
+                "Unity Catalog table '{full_name}' has unsupported data source format {format:?}"
             )));
         }
 
@@ -247,17 +629,38 @@ impl UnityCatalogSchemaProvider {
         self.ctx
             .register_table_store(uc_store.url(), uc_store.root());
 
-        // Delegate provider construction to the host session.
-        self.ctx.builder.build_delta(&location, table).await
+        if format != DataSourceFormat::Delta {
+            return listing::build_listing_table(&location, table, format);
+        }
+        // Delegate Delta provider construction to the host session.
+        if let Some(at) = at {
+            return self.ctx.builder.build_delta_at(&location, table, at).await;
+        }
+        let provider = self.ctx.builder.build_delta(&location, table).await?;
+        // Managed tables commit through the catalog, so they also accept DML.
+        #[cfg(feature = "delta")]
+        if table.table_type == TableType::Managed as i32 {
+            return Ok(Arc::new(crate::managed::ManagedDeltaTable::new(
+                provider,
+                self.ctx.factory.clone(),
+                &table.catalog_name,
+                &table.schema_name,
+                &table.name,
+            )));
+        }
+        Ok(provider)
     }
 
     /// If `table` is a metric view, resolve it: parse the definition, resolve
-    /// the source relation through the same Unity Catalog path, and build a
-    /// metric-view provider. Returns `Ok(None)` for non-metric-view tables.
+    /// the source relation and every join through the same Unity Catalog path,
+    /// and build a metric-view provider. Returns `Ok(None)` for non-metric-view
+    /// tables.
     #[cfg(feature = "metric-view")]
     async fn try_resolve_metric_view(
         &self,
-        table: &unitycatalog_common::models::tables::v1::Table,
+        full_name: &str,
+        table: &Table,
+        visiting: &mut Vec<String>,
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let view = match crate::metric_view::detect::metric_view_of(table) {
             Ok(Some(view)) => view,
@@ -269,34 +672,194 @@ impl UnityCatalogSchemaProvider {
                 ));
             }
         };
+        if visiting.iter().any(|v| v == full_name) {
+            return plan_err!(
+                "metric view '{full_name}' references itself: {} -> {full_name}",
+                visiting.join(" -> ")
+            );
+        }
 
-        // Resolve the source relation (a three-part `catalog.schema.table` name).
-        let source_name = view.source.trim();
-        let parts: Vec<&str> = source_name.split('.').collect();
-        let [catalog, schema, table_name] = parts.as_slice() else {
-            return Err(DataFusionError::NotImplemented(format!(
-                "metric-view source '{source_name}' must be a three-part \
-                 catalog.schema.table name (inline SQL sources are not yet supported)"
-            )));
-        };
-
-        let source_table = self
-            .ctx
-            .factory
-            .unity_client()
-            .table(*catalog, *schema, *table_name)
-            .get()
-            .await
-            .map_err(|e| {
-                plan_datafusion_err!("metric-view source '{source_name}' not found: {e}")
-            })?;
-        let source_provider = self.build_base_table(source_name, &source_table).await?;
+        visiting.push(full_name.to_string());
+        let relations = self
+            .resolve_metric_view_relations(full_name, table, &view, visiting)
+            .await;
+        visiting.pop();
+        let ((source_provider, source_name), joins) = relations?;
 
         let provider = self
             .ctx
             .builder
-            .build_metric_view(&view, source_provider, source_name)
+            .build_metric_view(&view, source_provider, &source_name, joins)
             .await?;
         Ok(Some(provider))
     }
+
+    /// Resolve a metric view's source and the relation of each (nested) join,
+    /// the latter keyed by join name.
+    #[cfg(feature = "metric-view")]
+    async fn resolve_metric_view_relations(
+        &self,
+        full_name: &str,
+        table: &Table,
+        view: &crate::metric_view::MetricView,
+        visiting: &mut Vec<String>,
+    ) -> Result<(
+        (Arc<dyn TableProvider>, String),
+        std::collections::HashMap<String, Arc<dyn TableProvider>>,
+    )> {
+        let source = self
+            .resolve_metric_view_relation(full_name, table, &view.source, visiting)
+            .await?;
+        let mut joins = std::collections::HashMap::new();
+        for join in view.all_joins() {
+            let (provider, _) = self
+                .resolve_metric_view_relation(full_name, table, &join.source, visiting)
+                .await?;
+            joins.insert(join.name.clone(), provider);
+        }
+        Ok((source, joins))
+    }
+
+    /// Resolve one metric-view `source` string to a provider and the name to
+    /// scan it under: a three-part name is resolved like a top-level table, and
+    /// inline SQL is planned like a view defined in the metric view's schema.
+    #[cfg(feature = "metric-view")]
+    async fn resolve_metric_view_relation(
+        &self,
+        full_name: &str,
+        table: &Table,
+        source: &str,
+        visiting: &mut Vec<String>,
+    ) -> Result<(Arc<dyn TableProvider>, String)> {
+        let source_table = crate::metric_view::source_table(source)
+            .map_err(|e| plan_datafusion_err!("metric view '{full_name}': {e}"))?;
+        if let Some(source_name) = source_table {
+            let (catalog, rest) = source_name.split_once('.').unwrap_or_default();
+            let (schema, table_name) = rest.split_once('.').unwrap_or_default();
+            let source_table = self
+                .ctx
+                .factory
+                .unity_client()
+                .table(catalog, schema, table_name)
+                .get()
+                .await
+                .map_err(|e| {
+                    plan_datafusion_err!("metric-view source '{source_name}' not found: {e}")
+                })?;
+            let provider = self
+                .build_table(&source_name, &source_table, visiting)
+                .await?;
+            return Ok((provider, source_name));
+        }
+
+        let mut statements = DFParser::parse_sql(source)?;
+        let (Some(statement), None) = (statements.pop_front(), statements.pop_front()) else {
+            return plan_err!("metric view '{full_name}': an inline source must be a single query");
+        };
+        let ctx = self
+            .ctx
+            .scoped_session(&table.catalog_name, &table.schema_name);
+        let plan = self
+            .plan_scoped(
+                &format!("metric view '{full_name}'"),
+                &ctx,
+                &table.catalog_name,
+                &table.schema_name,
+                statement,
+                visiting,
+            )
+            .await?;
+        let provider = Arc::new(ViewTable::new(plan, Some(source.to_string())));
+        Ok((
+            provider,
+            crate::metric_view::lower::SOURCE_ALIAS.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::logical_expr::{ColumnarValue, ScalarUDF, Volatility, create_udf};
+    use datafusion::prelude::SessionConfig;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct NoTables;
+
+    #[async_trait::async_trait]
+    impl TableProviderBuilder for NoTables {
+        async fn build_delta(&self, _: &Url, _: &Table) -> Result<Arc<dyn TableProvider>> {
+            unreachable!("no tables are resolved")
+        }
+    }
+
+    #[test]
+    fn scoped_session_inherits_the_host_session() {
+        let config = SessionConfig::new()
+            .set_bool("datafusion.sql_parser.enable_ident_normalization", false);
+        let host = SessionContext::new_with_config(config);
+        let udf: ScalarUDF = create_udf(
+            "host_fn",
+            vec![],
+            datafusion::arrow::datatypes::DataType::Int64,
+            Volatility::Immutable,
+            Arc::new(|_: &[ColumnarValue]| -> Result<ColumnarValue> { unreachable!() }),
+        );
+        host.register_udf(udf);
+
+        let scratch = scoped_session(&host.state(), "c", "s");
+        let state = scratch.state();
+        assert!(state.scalar_functions().contains_key("host_fn"));
+        assert!(
+            !state
+                .config()
+                .options()
+                .sql_parser
+                .enable_ident_normalization
+        );
+        assert_eq!(state.config().options().catalog.default_catalog, "c");
+        assert_eq!(state.config().options().catalog.default_schema, "s");
+
+        // Relations registered while planning stay in the scratch session.
+        let reference = ResolvedTableReference {
+            catalog: "c".into(),
+            schema: "s".into(),
+            table: "t".into(),
+        };
+        let provider = Arc::new(datafusion::datasource::empty::EmptyTable::new(Arc::new(
+            datafusion::arrow::datatypes::Schema::empty(),
+        )));
+        register_resolved(&scratch, &reference, provider).unwrap();
+        assert!(scratch.table_exist("c.s.t").unwrap());
+        assert!(host.catalog("c").is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_function_lookup_is_an_error() {
+        // Nothing listens on this port: the lookup fails, but not with a 404,
+        // so the statement must not plan as if the function did not exist.
+        let factory = UnityObjectStoreFactory::builder()
+            .with_uri("http://127.0.0.1:1/")
+            .with_allow_unauthenticated(true)
+            .build()
+            .await
+            .unwrap();
+        let session = SessionContext::new();
+        let providers = UnityCatalogProviderList::new(
+            Arc::new(factory),
+            session.runtime_env(),
+            Arc::new(NoTables),
+        );
+        let mut statement = DFParser::parse_sql("SELECT c.s.f(1)")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert!(
+            providers
+                .resolve_functions(&session, &mut statement)
+                .await
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,163 @@
+//! Time-travel table references.
+//!
+//! A table name may carry a Delta time-travel suffix, as in Databricks:
+//!
+//! - `orders@v42` reads version 42,
+//! - `orders@20240115103000000` reads the table as of
+//!   `2024-01-15T10:30:00.000Z` (`yyyyMMddHHmmssSSS`, UTC).
+//!
+//! `@` is not an identifier character, so in SQL the name is quoted:
+//! `SELECT * FROM main.sales."orders@v42"`. The resolver strips the suffix,
+//! looks the base table up in Unity Catalog and asks the
+//! [`TableProviderBuilder`](super::TableProviderBuilder) for a provider at
+//! that point in the table's history.
+
+use datafusion::common::{Result, plan_datafusion_err, plan_err};
+
+/// A point in a Delta table's history to read: `VERSION AS OF` or
+/// `TIMESTAMP AS OF`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeTravel {
+    /// Read this table version.
+    Version(u64),
+    /// Read the latest version committed at or before this instant, in
+    /// milliseconds since the Unix epoch.
+    Timestamp(i64),
+}
+
+/// Split a time-travel suffix off a table name.
+///
+/// Returns the base name and the requested [`TimeTravel`], or `name` unchanged
+/// and `None` when it carries no suffix. A suffix that has the shape of one but
+/// does not denote a version or a valid instant is an error rather than part of
+/// the name.
+pub fn split_time_travel(name: &str) -> Result<(&str, Option<TimeTravel>)> {
+    let Some((base, suffix)) = name.rsplit_once('@') else {
+        return Ok((name, None));
+    };
+    if base.is_empty() {
+        return Ok((name, None));
+    }
+    if let Some(version) = suffix.strip_prefix('v')
+        && is_digits(version)
+    {
+        let version = version
+            .parse()
+            .map_err(|_| plan_datafusion_err!("version '{version}' of '{base}' is out of range"))?;
+        return Ok((base, Some(TimeTravel::Version(version))));
+    }
+    if suffix.len() == 17 && is_digits(suffix) {
+        let timestamp = parse_timestamp(suffix)
+            .ok_or_else(|| plan_datafusion_err!("invalid timestamp '{suffix}' for '{base}'"))?;
+        return Ok((base, Some(TimeTravel::Timestamp(timestamp))));
+    }
+    if is_digits(suffix) {
+        return plan_err!(
+            "invalid time-travel suffix '@{suffix}' on '{base}': expected \
+             '@v<version>' or '@yyyyMMddHHmmssSSS'"
+        );
+    }
+    Ok((name, None))
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Milliseconds since the Unix epoch of a UTC `yyyyMMddHHmmssSSS` timestamp.
+fn parse_timestamp(s: &str) -> Option<i64> {
+    let field = |range: std::ops::Range<usize>| s[range].parse::<i64>().ok();
+    let (year, month, day) = (field(0..4)?, field(4..6)?, field(6..8)?);
+    let (hour, minute, second, millis) = (
+        field(8..10)?,
+        field(10..12)?,
+        field(12..14)?,
+        field(14..17)?,
+    );
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second;
+    Some(seconds * 1_000 + millis)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_names_have_no_time_travel() {
+        assert_eq!(split_time_travel("orders").unwrap(), ("orders", None));
+        assert_eq!(split_time_travel("a@b").unwrap(), ("a@b", None));
+        assert_eq!(split_time_travel("@v1").unwrap(), ("@v1", None));
+        assert_eq!(split_time_travel("a@version").unwrap(), ("a@version", None));
+    }
+
+    #[test]
+    fn splits_version_suffix() {
+        assert_eq!(
+            split_time_travel("orders@v42").unwrap(),
+            ("orders", Some(TimeTravel::Version(42)))
+        );
+        assert_eq!(
+            split_time_travel("a@b@v0").unwrap(),
+            ("a@b", Some(TimeTravel::Version(0)))
+        );
+    }
+
+    #[test]
+    fn splits_timestamp_suffix() {
+        assert_eq!(
+            split_time_travel("orders@19700101000000000").unwrap(),
+            ("orders", Some(TimeTravel::Timestamp(0)))
+        );
+        // 2024-02-29T10:30:15.250Z
+        assert_eq!(
+            split_time_travel("orders@20240229103015250").unwrap(),
+            ("orders", Some(TimeTravel::Timestamp(1_709_202_615_250)))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_suffixes() {
+        for name in [
+            "orders@20230229000000000",
+            "orders@20241301000000000",
+            "orders@20240101240000000",
+            "orders@2024010110",
+            "orders@v99999999999999999999",
+        ] {
+            assert!(
+                split_time_travel(name).is_err(),
+                "{name} should be rejected"
+            );
+        }
+    }
+}
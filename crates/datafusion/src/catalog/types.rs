@@ -0,0 +1,247 @@
+//! The one mapping between Arrow, Delta and Unity Catalog column types.
+//!
+//! Reading a plain-file table rebuilds its Arrow schema from the Delta type
+//! JSON stored in Unity Catalog; creating a table goes the other way, producing
+//! both the Delta type name for the `columns` payload and the UC column
+//! (`type_name`, Spark `type_text`, `type_json`). Keeping both directions here
+//! means a type that round-trips through a create reads back unchanged.
+//!
+//! Timestamps follow Delta: `timestamp` is instant-based (Arrow with a time
+//! zone, read back as UTC) and `timestamp_ntz` is wall-clock (Arrow without).
+
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use serde_json::Value;
+#[cfg(feature = "delta")]
+use unitycatalog_common::models::tables::v1::{Column, ColumnTypeName};
+
+/// Map a Delta schema-JSON data type to Arrow, matching the Delta connector's
+/// mapping. Spark writes nested-type keys in camelCase (`elementType`) while
+/// the Delta REST API uses kebab-case (`element-type`); both are accepted.
+pub(crate) fn json_to_arrow(data_type: &Value) -> Result<DataType, String> {
+    let object = match data_type {
+        Value::String(name) => return primitive_to_arrow(name),
+        Value::Object(object) => object,
+        other => return Err(format!("unsupported type JSON {other}")),
+    };
+    let get = |camel: &str, kebab: &str| {
+        object
+            .get(camel)
+            .or_else(|| object.get(kebab))
+            .ok_or_else(|| format!("type JSON is missing '{camel}'"))
+    };
+    let flag = |camel: &str, kebab: &str| get(camel, kebab).map(|v| v.as_bool().unwrap_or(true));
+    Ok(match object.get("type").and_then(Value::as_str) {
+        Some("array") => DataType::List(Arc::new(Field::new(
+            "element",
+            json_to_arrow(get("elementType", "element-type")?)?,
+            flag("containsNull", "contains-null")?,
+        ))),
+        Some("map") => {
+            let entries = Fields::from(vec![
+                Field::new("key", json_to_arrow(get("keyType", "key-type")?)?, false),
+                Field::new(
+                    "value",
+                    json_to_arrow(get("valueType", "value-type")?)?,
+                    flag("valueContainsNull", "value-contains-null")?,
+                ),
+            ]);
+            DataType::Map(
+                Arc::new(Field::new("key_value", DataType::Struct(entries), false)),
+                false,
+            )
+        }
+        Some("struct") => DataType::Struct(
+            get("fields", "fields")?
+                .as_array()
+                .ok_or("struct fields must be an array")?
+                .iter()
+                .map(|f| {
+                    let name = f
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .ok_or("struct field is missing 'name'")?;
+                    let data_type = f.get("type").ok_or("struct field is missing 'type'")?;
+                    let nullable = f.get("nullable").and_then(Value::as_bool).unwrap_or(true);
+                    Ok(Field::new(name, json_to_arrow(data_type)?, nullable))
+                })
+                .collect::<Result<Fields, String>>()?,
+        ),
+        Some("decimal") => {
+            let number = |key| {
+                object
+                    .get(key)
+                    .and_then(Value::as_i64)
+                    .and_then(|n| i32::try_from(n).ok())
+                    .ok_or_else(|| format!("decimal type JSON is missing '{key}'"))
+            };
+            decimal(number("precision")?, number("scale")?)?
+        }
+        _ => return Err(format!("unsupported type JSON {data_type}")),
+    })
+}
+
+/// Map a Delta primitive type name (`long`, `decimal(10,2)`, …) to Arrow.
+pub(crate) fn primitive_to_arrow(name: &str) -> Result<DataType, String> {
+    Ok(match name {
+        "boolean" => DataType::Boolean,
+        "byte" => DataType::Int8,
+        "short" => DataType::Int16,
+        "integer" => DataType::Int32,
+        "long" => DataType::Int64,
+        "float" => DataType::Float32,
+        "double" => DataType::Float64,
+        "string" | "char" | "varchar" => DataType::Utf8,
+        "binary" => DataType::Binary,
+        "date" => DataType::Date32,
+        "timestamp" => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        "timestamp_ntz" => DataType::Timestamp(TimeUnit::Microsecond, None),
+        other => {
+            let parsed = other
+                .strip_prefix("decimal(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|inner| inner.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)));
+            match parsed {
+                Some((precision, scale)) => decimal(precision, scale)?,
+                None => return Err(format!("unsupported column type '{other}'")),
+            }
+        }
+    })
+}
+
+/// The Arrow type of a Delta `decimal(precision, scale)`.
+pub(crate) fn decimal(precision: i32, scale: i32) -> Result<DataType, String> {
+    match (u8::try_from(precision), i8::try_from(scale)) {
+        (Ok(precision), Ok(scale))
+            if (1..=38).contains(&precision) && (0..=precision as i8).contains(&scale) =>
+        {
+            Ok(DataType::Decimal128(precision, scale))
+        }
+        _ => Err(format!("invalid decimal({precision},{scale})")),
+    }
+}
+
+/// Map an Arrow type to its Delta primitive type name, the inverse of
+/// [`primitive_to_arrow`]. Nested types are not supported: tables created
+/// through this crate have flat schemas.
+#[cfg(feature = "delta")]
+pub(crate) fn arrow_to_primitive(data_type: &DataType) -> Result<String, String> {
+    let name = match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int8 => "byte",
+        DataType::Int16 => "short",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "long",
+        DataType::Float32 => "float",
+        DataType::Float64 => "double",
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => "string",
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => "binary",
+        DataType::Date32 => "date",
+        DataType::Timestamp(_, Some(_)) => "timestamp",
+        DataType::Timestamp(_, None) => "timestamp_ntz",
+        DataType::Decimal128(precision, scale) => {
+            decimal(i32::from(*precision), i32::from(*scale))?;
+            return Ok(format!("decimal({precision},{scale})"));
+        }
+        other => return Err(format!("unsupported column type {other}")),
+    };
+    Ok(name.to_string())
+}
+
+/// The Unity Catalog column for an Arrow field at `position`, with the Spark
+/// `type_text` and a Delta struct-field `type_json` that [`json_to_arrow`]
+/// reads back to the same Arrow type.
+#[cfg(feature = "delta")]
+pub(crate) fn arrow_to_uc_column(field: &Field, position: usize) -> Result<Column, String> {
+    let delta = arrow_to_primitive(field.data_type())
+        .map_err(|e| format!("column '{}': {e}", field.name()))?;
+    let (type_name, type_text) = match delta.as_str() {
+        "boolean" => (ColumnTypeName::Boolean, "boolean"),
+        "byte" => (ColumnTypeName::Byte, "tinyint"),
+        "short" => (ColumnTypeName::Short, "smallint"),
+        "integer" => (ColumnTypeName::Int, "int"),
+        "long" => (ColumnTypeName::Long, "bigint"),
+        "float" => (ColumnTypeName::Float, "float"),
+        "double" => (ColumnTypeName::Double, "double"),
+        "string" => (ColumnTypeName::String, "string"),
+        "binary" => (ColumnTypeName::Binary, "binary"),
+        "date" => (ColumnTypeName::Date, "date"),
+        "timestamp" => (ColumnTypeName::Timestamp, "timestamp"),
+        "timestamp_ntz" => (ColumnTypeName::TimestampNtz, "timestamp_ntz"),
+        decimal => (ColumnTypeName::Decimal, decimal),
+    };
+    let (type_precision, type_scale) = match field.data_type() {
+        DataType::Decimal128(precision, scale) => {
+            (Some(i32::from(*precision)), Some(i32::from(*scale)))
+        }
+        _ => (None, None),
+    };
+    let type_json = serde_json::json!({
+        "name": field.name(),
+        "type": delta,
+        "nullable": field.is_nullable(),
+        "metadata": {},
+    });
+    Ok(Column {
+        name: field.name().clone(),
+        type_text: type_text.to_string(),
+        type_json: type_json.to_string(),
+        position: Some(position as i32),
+        type_name: type_name as i32,
+        type_precision,
+        type_scale,
+        nullable: Some(field.is_nullable()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_bounds_are_checked() {
+        assert_eq!(
+            primitive_to_arrow("decimal(10, 2)").unwrap(),
+            DataType::Decimal128(10, 2)
+        );
+        assert!(decimal(0, 0).is_err());
+        assert!(decimal(39, 0).is_err());
+        assert!(decimal(5, 6).is_err());
+    }
+
+    #[cfg(feature = "delta")]
+    #[test]
+    fn uc_columns_read_back_as_the_same_arrow_type() {
+        for data_type in [
+            DataType::Int64,
+            DataType::Utf8,
+            DataType::Decimal128(12, 2),
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+        ] {
+            let column = arrow_to_uc_column(&Field::new("c", data_type.clone(), true), 0).unwrap();
+            let json: Value = serde_json::from_str(&column.type_json).unwrap();
+            assert_eq!(json_to_arrow(&json["type"]).unwrap(), data_type);
+        }
+
+        let amount =
+            arrow_to_uc_column(&Field::new("amount", DataType::Decimal128(12, 2), true), 3)
+                .unwrap();
+        assert_eq!(amount.type_name, ColumnTypeName::Decimal as i32);
+        assert_eq!(amount.type_text, "decimal(12,2)");
+        assert_eq!(
+            (amount.type_precision, amount.type_scale),
+            (Some(12), Some(2))
+        );
+    }
+
+    #[cfg(feature = "delta")]
+    #[test]
+    fn nested_arrow_types_are_rejected() {
+        let list = DataType::List(Arc::new(Field::new("element", DataType::Int32, true)));
+        assert!(arrow_to_primitive(&list).is_err());
+    }
+}
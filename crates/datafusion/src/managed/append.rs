@@ -9,6 +9,13 @@
 //!    `DefaultEngine::write_parquet` → `add_files` → `commit`. The committer stages the
 //!    commit and ratifies it via UC `updateTable add-commit`.
 //!
+//! The loop itself ([`commit_with_retry`]) is shared with the DML paths in
+//! [`super::dml`]: each attempt asks a [`ManagedWrite`] to plan its data actions against the
+//! freshly loaded snapshot, so overwrites and row-level rewrites are recomputed after a
+//! conflict instead of replaying a stale plan. Planned rows arrive as a stream and are
+//! written into data files of about [`TARGET_FILE_BYTES`] each, so only one file's rows are
+//! held in memory at a time.
+//!
 //! ## Post-commit obligations (ManagedTablesSpec §"Write to the table", lines 75-77)
 //!
 //! After UC ratifies a commit the client **must** complete the commit lifecycle:
@@ -28,8 +35,8 @@
 //!
 //! The commit is wrapped in a bounded retry loop:
 //!
-//! - **409 conflict** (`CommitVersionConflictException` / `AlreadyExistsException`): a
-//!   concurrent writer took our version. Reload the table, rebuild the snapshot at the new
+//! - **409 conflict** (`CommitVersionConflictException` / `AlreadyExistsException` /
+//!   `UpdateRequirementConflictException`): a concurrent writer took our version. Reload the table, rebuild the snapshot at the new
 //!   latest version, re-stage, retry.
 //! - **429 resource-exhausted** (`ResourceExhaustedException` / `TooManyRequestsException`):
 //!   the unbackfilled tail is too long. Publish + backfill the pending tail, then retry.
@@ -37,15 +44,23 @@
 //!   Reload the table and look for the exact staged file we proposed — if present the commit
 //!   landed (success, never re-commit); if absent, retry.
 
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
-use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::array::{Array, AsArray, RecordBatch};
+use datafusion::arrow::compute::concat_batches;
+use datafusion::arrow::datatypes::Int64Type;
+use datafusion::error::DataFusionError;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use delta_kernel::engine::arrow_data::ArrowEngineData;
-use delta_kernel::engine::default::DefaultEngineBuilder;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::engine::default::{DefaultEngine, DefaultEngineBuilder};
 use delta_kernel::snapshot::{Snapshot as KernelSnapshot, SnapshotRef};
-use delta_kernel::transaction::CommitResult;
-use delta_kernel::{Engine, Version};
+use delta_kernel::transaction::{CommitResult, WriteContext};
+use delta_kernel::{Engine, FilteredEngineData, Version};
+use futures::TryStreamExt;
 use tracing::{debug, info, warn};
 use unitycatalog_client::DeltaV1Client;
 use unitycatalog_common::models::delta::v1::{
@@ -67,6 +82,8 @@ const MAX_COMMIT_ATTEMPTS: u32 = 5;
 /// Base backoff between retry attempts; jittered and capped per attempt.
 const RETRY_BASE_BACKOFF: Duration = Duration::from_millis(50);
 const RETRY_MAX_BACKOFF: Duration = Duration::from_secs(2);
+/// In-memory size of the rows buffered before they are written out as one data file.
+const TARGET_FILE_BYTES: usize = 128 * 1024 * 1024;
 
 /// Append `batch` as a new commit to the existing managed table `catalog.schema.table`.
 ///
@@ -80,9 +97,141 @@ pub async fn append_to_managed_table(
     table: &str,
     batch: RecordBatch,
     engine_info: &str,
+) -> Result<Version, CreateManagedTableError> {
+    let mut write = AppendBatch(batch);
+    commit_with_retry(factory, catalog, schema, table, engine_info, &mut write).await
+}
+
+/// The data actions of one managed-table commit attempt, planned against that attempt's
+/// snapshot by a [`ManagedWrite`].
+pub(super) struct PlannedWrite {
+    /// The Delta operation recorded in `commitInfo` (`WRITE`, `DELETE`, `MERGE`, …).
+    pub operation: &'static str,
+    /// The files live in the snapshot that the commit removes.
+    pub remove: RemoveFiles,
+    /// The commit's new data.
+    pub data: NewData,
+    /// Application transactions (`txn` actions) recorded in the same commit, as
+    /// `(app id, version)`. A commit carrying any is made even without data changes.
+    pub transactions: Vec<(String, i64)>,
+    /// Catalog table properties set by the `updateTable` request that ratifies the commit,
+    /// so catalog clients see them without reading the log.
+    pub properties: BTreeMap<String, String>,
+    /// Commit metrics reported to UC once the commit is ratified. [`commit_with_retry`] fills
+    /// in the file counts, and the inserted rows for [`NewData::Inserted`] and
+    /// [`NewData::Written`].
+    pub report: DeltaCommitReport,
+}
+
+/// Which of the snapshot's live files a [`PlannedWrite`] removes.
+pub(super) enum RemoveFiles {
+    None,
+    /// Every live file (overwrite, or a rewrite that could not be pruned).
+    All,
+    /// The live files with these paths, as they appear in the snapshot's scan files.
+    Paths(HashSet<String>),
+}
+
+impl RemoveFiles {
+    /// Which rows of a scan-file `batch` to remove, given the scan's `selection` of live
+    /// files. A selection vector shorter than the batch leaves the tail selected.
+    fn selection(
+        &self,
+        batch: &RecordBatch,
+        selection: &[bool],
+    ) -> Result<Vec<bool>, CreateManagedTableError> {
+        let live = (0..batch.num_rows()).map(|i| selection.get(i).copied().unwrap_or(true));
+        Ok(match self {
+            RemoveFiles::None => vec![false; batch.num_rows()],
+            RemoveFiles::All => live.collect(),
+            RemoveFiles::Paths(paths) => {
+                let column = batch
+                    .column_by_name("path")
+                    .and_then(|c| c.as_string_opt::<i32>())
+                    .ok_or_else(|| {
+                        CreateManagedTableError::other("scan files are missing `path`")
+                    })?;
+                live.enumerate()
+                    .map(|(i, live)| live && paths.contains(column.value(i)))
+                    .collect()
+            }
+        })
+    }
+}
+
+/// The new data of a [`PlannedWrite`].
+pub(super) enum NewData {
+    None,
+    /// New rows, reported as inserted.
+    Inserted(SendableRecordBatchStream),
+    /// Rows a rewrite carries over from the files it removes, plus any new ones.
+    Rewritten(SendableRecordBatchStream),
+    /// The add-file metadata of `rows` inserted rows an earlier attempt already wrote (see
+    /// [`ManagedWrite::files_written`]).
+    Written {
+        files: Vec<RecordBatch>,
+        rows: usize,
+    },
+}
+
+/// Plans the contents of a managed-table commit.
+///
+/// [`commit_with_retry`] calls [`plan`](Self::plan) once per attempt with the snapshot that
+/// attempt will commit against. A write whose contents depend on the table's current rows
+/// (DELETE, UPDATE, MERGE) therefore recomputes them after a conflict rather than replaying
+/// actions derived from a stale version. `Ok(None)` means there is nothing to commit.
+pub(super) trait ManagedWrite: Send {
+    fn plan(
+        &mut self,
+        snapshot: &SnapshotRef,
+    ) -> impl Future<Output = Result<Option<PlannedWrite>, CreateManagedTableError>> + Send;
+
+    /// Called with the add-file metadata of the files written for an attempt's rows and
+    /// their number, before the attempt commits. A write whose rows cannot be read twice keeps
+    /// them to plan [`NewData::Written`] on a retry.
+    fn files_written(&mut self, _files: &[RecordBatch], _rows: usize) {}
+}
+
+/// A stream of the single batch `batch`.
+pub(super) fn batch_stream(batch: RecordBatch) -> SendableRecordBatchStream {
+    Box::pin(RecordBatchStreamAdapter::new(
+        batch.schema(),
+        futures::stream::iter([Ok(batch)]),
+    ))
+}
+
+/// A blind append of one batch: the same rows on every attempt.
+struct AppendBatch(RecordBatch);
+
+impl ManagedWrite for AppendBatch {
+    async fn plan(
+        &mut self,
+        _snapshot: &SnapshotRef,
+    ) -> Result<Option<PlannedWrite>, CreateManagedTableError> {
+        Ok(Some(PlannedWrite {
+            operation: "WRITE",
+            remove: RemoveFiles::None,
+            data: NewData::Inserted(batch_stream(self.0.clone())),
+            transactions: Vec::new(),
+            properties: BTreeMap::new(),
+            report: DeltaCommitReport::default(),
+        }))
+    }
+}
+
+/// Commit `write` to the managed table `catalog.schema.table` under the bounded retry loop
+/// described in the module docs, then run the best-effort post-commit lifecycle.
+///
+/// Returns the committed version, or the snapshot's version when `write` planned nothing.
+pub(super) async fn commit_with_retry<W: ManagedWrite>(
+    factory: Arc<UnityObjectStoreFactory>,
+    catalog: &str,
+    schema: &str,
+    table: &str,
+    engine_info: &str,
+    write: &mut W,
 ) -> Result<Version, CreateManagedTableError> {
     let client = Arc::new(factory.unity_client().delta_v1());
-    let num_rows = batch.num_rows() as i64;
 
     // The credentialed store + engine are stable across retries (the table exists, so we vend
     // by name). Build them once.
@@ -103,35 +252,111 @@ pub async fn append_to_managed_table(
         let (table_id, location) = table_id_and_location(catalog, schema, table, &loaded)?;
         let snapshot = build_snapshot(&loaded, &location, &engine)?;
 
-        // 2. Write the batch through a transaction committed by our catalog committer.
-        let committer =
+        // 2. Plan this attempt's data actions against the snapshot.
+        let Some(planned) = write.plan(&snapshot).await? else {
+            debug!(
+                version = snapshot.version(),
+                "managed-table write planned no changes; skipping commit"
+            );
+            return Ok(snapshot.version());
+        };
+
+        // 3. Stage them in a transaction committed by our catalog committer.
+        let mut committer =
             UnityCatalogCommitter::new(client.clone(), catalog, schema, table, table_id.clone());
+        if !planned.properties.is_empty() {
+            committer = committer.with_catalog_updates(vec![DeltaTableUpdate::SetProperties {
+                updates: planned.properties.clone(),
+            }]);
+        }
         let mut txn = snapshot
+            .clone()
             .transaction(Box::new(committer.clone()), &engine)
             .map_err(CreateManagedTableError::Kernel)?
-            .with_engine_info(engine_info);
+            .with_engine_info(engine_info)
+            .with_operation(planned.operation.to_string());
+        for (app_id, version) in planned.transactions.iter().cloned() {
+            txn = txn.with_transaction_id(app_id, version);
+        }
 
-        let write_context = txn
-            .unpartitioned_write_context()
-            .map_err(CreateManagedTableError::Kernel)?;
-        let add_metadata = engine
-            .write_parquet(&ArrowEngineData::new(batch.clone()), &write_context)
-            .await
-            .map_err(CreateManagedTableError::Kernel)?;
-        txn.add_files(add_metadata);
+        let mut report = planned.report;
+        let mut removed = 0;
+        if !matches!(planned.remove, RemoveFiles::None) {
+            let scan = snapshot
+                .clone()
+                .scan_builder()
+                .build()
+                .map_err(CreateManagedTableError::Kernel)?;
+            for scan_metadata in scan
+                .scan_metadata(&engine)
+                .map_err(CreateManagedTableError::Kernel)?
+            {
+                let scan_metadata = scan_metadata.map_err(CreateManagedTableError::Kernel)?;
+                let (data, selection) = scan_metadata.scan_files.into_parts();
+                let batch = ArrowEngineData::try_from_engine_data(data)
+                    .map_err(CreateManagedTableError::Kernel)?
+                    .record_batch()
+                    .clone();
+                let remove = planned.remove.selection(&batch, &selection)?;
+                let count = remove.iter().filter(|r| **r).count();
+                if count == 0 {
+                    continue;
+                }
+                removed += count;
+                txn.remove_files(
+                    FilteredEngineData::try_new(Box::new(ArrowEngineData::new(batch)), remove)
+                        .map_err(CreateManagedTableError::Kernel)?,
+                );
+            }
+        }
+        let inserts = matches!(planned.data, NewData::Inserted(_) | NewData::Written { .. });
+        let (files, written) = match planned.data {
+            NewData::None => (Vec::new(), 0),
+            NewData::Written { files, rows } => (files, rows),
+            NewData::Inserted(rows) | NewData::Rewritten(rows) => {
+                let write_context = txn
+                    .unpartitioned_write_context()
+                    .map_err(CreateManagedTableError::Kernel)?;
+                let (files, written) = write_files(&engine, &write_context, rows).await?;
+                write.files_written(&files, written);
+                (files, written)
+            }
+        };
+        if removed == 0 && files.is_empty() && planned.transactions.is_empty() {
+            debug!(
+                version = snapshot.version(),
+                "managed-table write removed and added no files; skipping commit"
+            );
+            return Ok(snapshot.version());
+        }
+        let added = files.iter().map(RecordBatch::num_rows).sum::<usize>();
+        report.num_files_added = Some(added as i64);
+        report.num_files_removed = Some(removed as i64);
+        if inserts {
+            report.num_rows_inserted = Some(written as i64);
+        }
+        for file in files {
+            txn.add_files(Box::new(ArrowEngineData::new(file)));
+        }
 
         let outcome = match txn.commit(&engine) {
             Ok(CommitResult::CommittedTransaction(c)) => {
                 let version = c.commit_version();
-                info!(version, attempt, "committed managed-table append");
+                info!(
+                    version,
+                    attempt,
+                    operation = planned.operation,
+                    "committed managed-table write"
+                );
                 let post = c.post_commit_snapshot().cloned();
                 run_post_commit(
-                    &client, catalog, schema, table, &table_id, &engine, post, version, num_rows,
+                    &client, catalog, schema, table, &table_id, &engine, post, version, report,
                 )
                 .await;
                 return Ok(version);
             }
-            // 409: a concurrent writer ratified our version first. Reload + rebuild + retry.
+            // 409: a concurrent writer ratified our version first (or moved the table past a
+            // requirement we asserted). Reload + rebuild + retry.
             Ok(CommitResult::ConflictedTransaction(c)) => {
                 warn!(
                     conflict_version = c.conflict_version(),
@@ -169,6 +394,106 @@ pub async fn append_to_managed_table(
     }
 }
 
+/// Write `rows` into new data files of about [`TARGET_FILE_BYTES`] each, buffering one file's
+/// rows at a time. Returns the add-file metadata of every file and the number of rows written.
+async fn write_files(
+    engine: &DefaultEngine<TokioBackgroundExecutor>,
+    write_context: &WriteContext,
+    mut rows: SendableRecordBatchStream,
+) -> Result<(Vec<RecordBatch>, usize), CreateManagedTableError> {
+    let schema = rows.schema();
+    let mut files = Vec::new();
+    let mut written = 0;
+    let mut buffered = Vec::new();
+    let mut buffered_bytes = 0;
+    loop {
+        let batch = rows.try_next().await?;
+        let full = batch
+            .as_ref()
+            .is_none_or(|batch| buffered_bytes + batch.get_array_memory_size() > TARGET_FILE_BYTES);
+        if full && !buffered.is_empty() {
+            let data = concat_batches(&schema, &std::mem::take(&mut buffered))
+                .map_err(DataFusionError::from)?;
+            written += data.num_rows();
+            buffered_bytes = 0;
+            let add_metadata = engine
+                .write_parquet(&ArrowEngineData::new(data), write_context)
+                .await
+                .map_err(CreateManagedTableError::Kernel)?;
+            files.push(
+                ArrowEngineData::try_from_engine_data(add_metadata)
+                    .map_err(CreateManagedTableError::Kernel)?
+                    .record_batch()
+                    .clone(),
+            );
+        }
+        match batch {
+            Some(batch) if batch.num_rows() > 0 => {
+                buffered_bytes += batch.get_array_memory_size();
+                buffered.push(batch);
+            }
+            Some(_) => {}
+            None => return Ok((files, written)),
+        }
+    }
+}
+
+/// A data file live in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct LiveFile {
+    /// The file's size in bytes.
+    pub(super) size: u64,
+    /// The storage path or inline data of the file's deletion vector.
+    pub(super) deletion_vector: Option<String>,
+}
+
+/// The data files live in `snapshot`, by path.
+pub(super) fn live_files(
+    snapshot: &SnapshotRef,
+    engine: &dyn Engine,
+) -> Result<BTreeMap<String, LiveFile>, CreateManagedTableError> {
+    let missing =
+        |column: &str| CreateManagedTableError::other(format!("scan files are missing `{column}`"));
+    let scan = snapshot.clone().scan_builder().build()?;
+    let mut files = BTreeMap::new();
+    for metadata in scan.scan_metadata(engine)? {
+        let (data, selection) = metadata?.scan_files.into_parts();
+        let batch = ArrowEngineData::try_from_engine_data(data)?
+            .record_batch()
+            .clone();
+        let paths = batch
+            .column_by_name("path")
+            .and_then(|c| c.as_string_opt::<i32>())
+            .ok_or_else(|| missing("path"))?;
+        let sizes = batch
+            .column_by_name("size")
+            .and_then(|c| c.as_primitive_opt::<Int64Type>())
+            .ok_or_else(|| missing("size"))?;
+        let deletion_vectors = batch
+            .column_by_name("deletionVector")
+            .and_then(|c| c.as_struct_opt())
+            .ok_or_else(|| missing("deletionVector"))?;
+        let dv_paths = deletion_vectors
+            .column_by_name("pathOrInlineDv")
+            .and_then(|c| c.as_string_opt::<i32>())
+            .ok_or_else(|| missing("deletionVector.pathOrInlineDv"))?;
+        // A selection vector shorter than the batch leaves the tail selected.
+        for i in 0..batch.num_rows() {
+            if selection.get(i).copied().unwrap_or(true) {
+                let deletion_vector = deletion_vectors
+                    .is_valid(i)
+                    .then(|| dv_paths.value(i).to_string());
+                let file = LiveFile {
+                    size: u64::try_from(sizes.value(i)).unwrap_or_default(),
+                    deletion_vector,
+                };
+                files.insert(paths.value(i).to_string(), file);
+            }
+        }
+    }
+    Ok(files)
+}
+
 /// The result of inspecting a failed commit attempt.
 enum RetryOutcome {
     /// A `CommitStateUnknown` check confirmed the commit actually landed at this version.
@@ -290,7 +615,7 @@ async fn run_post_commit(
     engine: &dyn Engine,
     post_commit_snapshot: Option<SnapshotRef>,
     version: Version,
-    num_rows: i64,
+    report: DeltaCommitReport,
 ) {
     match post_commit_snapshot {
         Some(snapshot) => {
@@ -317,11 +642,7 @@ async fn run_post_commit(
     let request = DeltaReportMetricsRequest {
         table_id: table_id.to_string(),
         report: Some(DeltaReport {
-            commit_report: Some(DeltaCommitReport {
-                num_files_added: Some(1),
-                num_rows_inserted: Some(num_rows),
-                ..Default::default()
-            }),
+            commit_report: Some(report),
         }),
     };
     if let Err(e) = client
@@ -416,7 +737,7 @@ fn table_id_and_location(
 /// Shares [`resolve_managed_read_state`] with the read path, so the latest-version
 /// resolution is identical (and never substitutes `metadata.last-commit-version` — review
 /// finding A5). The table is known managed here, so a `NotManaged` result is a hard error.
-fn build_snapshot(
+pub(super) fn build_snapshot(
     loaded: &DeltaLoadTableResponse,
     location: &Url,
     engine: &dyn Engine,
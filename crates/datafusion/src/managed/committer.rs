@@ -22,12 +22,12 @@ use delta_kernel::committer::{
     CommitMetadata, CommitResponse, CommitType, Committer, PublishMetadata,
 };
 use delta_kernel::{
-    DeltaResult, DeltaResultIterator, Engine, Error as DeltaError, FilteredEngineData,
+    DeltaResult, DeltaResultIterator, Engine, Error as DeltaError, FileMeta, FilteredEngineData,
 };
 use tracing::{debug, info};
 use unitycatalog_client::DeltaV1Client;
 use unitycatalog_common::models::delta::v1::{
-    DeltaCommit, DeltaTableRequirement, DeltaTableUpdate, DeltaUpdateTableRequest,
+    DeltaCommit, DeltaTableCommit, DeltaTableRequirement, DeltaTableUpdate, DeltaUpdateTableRequest,
 };
 
 // UC catalog-managed contract identifiers (mirror the fork's `constants`).
@@ -70,6 +70,9 @@ pub struct UnityCatalogCommitter {
     /// Matching on the full UUID file name — not just the version — avoids mistaking a
     /// concurrent writer's commit at the same version for ours.
     last_proposed: Arc<Mutex<Option<(i64, String)>>>,
+    /// Updates sent in the same `updateTable` request as each `add-commit`, so UC ratifies
+    /// them atomically with the commit (e.g. `set-properties` describing what it wrote).
+    catalog_updates: Vec<DeltaTableUpdate>,
 }
 
 impl std::fmt::Debug for UnityCatalogCommitter {
@@ -101,9 +104,18 @@ impl UnityCatalogCommitter {
             table_id: table_id.into(),
             last_error: Arc::new(Mutex::new(None)),
             last_proposed: Arc::new(Mutex::new(None)),
+            catalog_updates: Vec::new(),
         }
     }
 
+    /// Send `updates` alongside the `add-commit` of every version >= 1 this committer
+    /// ratifies. Only catalog-side updates belong here: anything that changes the table's
+    /// Delta metadata must be committed to the log instead.
+    pub(crate) fn with_catalog_updates(mut self, updates: Vec<DeltaTableUpdate>) -> Self {
+        self.catalog_updates = updates;
+        self
+    }
+
     /// The `(version, staged_file_name)` of the last staged commit proposed to UC, if any.
     /// Used by the retry loop's commit-state-unknown recovery to detect a commit that
     /// actually landed despite an ambiguous (500) response.
@@ -239,15 +251,16 @@ impl UnityCatalogCommitter {
         }
     }
 
-    /// Version >= 1: write a staged commit, then `updateTable add-commit` to ratify it.
-    fn commit_version_non_zero(
+    /// Validate a version >= 1 commit and write it as a staged commit, returning the
+    /// `add-commit` payload and the staged file's metadata. Nothing is ratified yet.
+    pub(super) fn stage_commit(
         &self,
         engine: &dyn Engine,
         actions: DeltaResultIterator<'_, FilteredEngineData>,
-        commit_metadata: CommitMetadata,
-    ) -> DeltaResult<CommitResponse> {
-        self.validate_catalog_managed_state(&commit_metadata)?;
-        Self::validate_no_alter_table_changes(&commit_metadata)?;
+        commit_metadata: &CommitMetadata,
+    ) -> DeltaResult<(DeltaCommit, FileMeta)> {
+        self.validate_catalog_managed_state(commit_metadata)?;
+        Self::validate_no_alter_table_changes(commit_metadata)?;
 
         let staged = commit_metadata.staged_commit_path()?;
         engine
@@ -268,26 +281,52 @@ impl UnityCatalogCommitter {
             .size
             .try_into()
             .map_err(|_| DeltaError::generic("staged commit size does not fit into i64"))?;
+        let commit = DeltaCommit {
+            version,
+            timestamp: commit_metadata.in_commit_timestamp(),
+            file_name,
+            file_size,
+            file_modification_timestamp: committed.last_modified,
+        };
+        Ok((commit, committed))
+    }
+
+    /// This table's entry in a multi-table commit, carrying `commit`.
+    pub(super) fn table_commit(&self, commit: DeltaCommit) -> DeltaTableCommit {
+        DeltaTableCommit {
+            catalog: self.catalog.clone(),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            table_uuid: self.table_id.clone(),
+            commit,
+            latest_published_version: None,
+        }
+    }
+
+    /// Version >= 1: write a staged commit, then `updateTable add-commit` to ratify it.
+    fn commit_version_non_zero(
+        &self,
+        engine: &dyn Engine,
+        actions: DeltaResultIterator<'_, FilteredEngineData>,
+        commit_metadata: CommitMetadata,
+    ) -> DeltaResult<CommitResponse> {
+        let (commit, committed) = self.stage_commit(engine, actions, &commit_metadata)?;
 
         // Record what we're about to propose so the caller's commit-state-unknown recovery can
         // recognise this exact commit in the reloaded tail.
         *self.last_proposed.lock().expect("committer mutex poisoned") =
-            Some((version, file_name.clone()));
+            Some((commit.version, commit.file_name.clone()));
 
         let request = DeltaUpdateTableRequest {
             requirements: vec![DeltaTableRequirement::AssertTableUuid {
                 uuid: self.table_id.clone(),
             }],
-            updates: vec![DeltaTableUpdate::AddCommit {
-                commit: DeltaCommit {
-                    version,
-                    timestamp: commit_metadata.in_commit_timestamp(),
-                    file_name,
-                    file_size,
-                    file_modification_timestamp: committed.last_modified,
-                },
+            updates: std::iter::once(DeltaTableUpdate::AddCommit {
+                commit,
                 uniform: None,
-            }],
+            })
+            .chain(self.catalog_updates.iter().cloned())
+            .collect(),
         };
 
         match self.block_on_update(request) {
@@ -375,11 +414,15 @@ impl Committer for UnityCatalogCommitter {
 /// The `/delta/v1` server reports this through the typed Delta envelope as
 /// `CommitVersionConflictException` (now parsed into `Error::Delta`, matched by
 /// [`Error::is_commit_conflict`]) or, for a name collision, `AlreadyExistsException`
-/// ([`Error::is_already_exists`]). The legacy `http_status() == 409` arm is kept as
+/// ([`Error::is_already_exists`]). An `UpdateRequirementConflictException`
+/// ([`Error::is_update_requirement_conflict`]) means a requirement asserted against the
+/// state we loaded no longer holds — a concurrent writer changed the table underneath
+/// us — so it is retried the same way: reload, rebuild, re-stage. The legacy `http_status() == 409` arm is kept as
 /// a fallback for servers that still return an untyped 409 envelope.
 fn is_conflict(err: &unitycatalog_client::Error) -> bool {
     err.is_commit_conflict()
         || err.is_already_exists()
+        || err.is_update_requirement_conflict()
         || matches!(err, unitycatalog_client::Error::Api(api) if api.http_status() == 409)
 }
 
@@ -474,6 +517,12 @@ mod tests {
             code: 409,
             stack: None,
         })));
+        assert!(is_conflict(&Error::Delta(DeltaErrorModel {
+            message: "requirement failed".into(),
+            error_type: DeltaErrorType::UpdateRequirementConflictException,
+            code: 409,
+            stack: None,
+        })));
         // A 404 Delta envelope is not a conflict.
         assert!(!is_conflict(&Error::Delta(DeltaErrorModel {
             message: "no table".into(),
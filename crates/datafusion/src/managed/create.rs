@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use datafusion::arrow::datatypes::{DataType as ArrowDataType, SchemaRef as ArrowSchemaRef};
+use datafusion::arrow::datatypes::SchemaRef as ArrowSchemaRef;
 use delta_kernel::Engine;
 use delta_kernel::engine::arrow_conversion::TryIntoKernel;
 use delta_kernel::engine::default::DefaultEngineBuilder;
@@ -29,11 +29,12 @@ use object_store::local::LocalFileSystem;
 use unitycatalog_client::DeltaV1Client;
 use unitycatalog_common::models::delta::v1::{
     DeltaCreateStagingTableRequest, DeltaCreateTableRequest, DeltaDataSourceFormat, DeltaDataType,
-    DeltaStagingTableResponse, DeltaStructField, DeltaStructType, DeltaTableType,
+    DeltaProtocol, DeltaStagingTableResponse, DeltaStructField, DeltaStructType, DeltaTableType,
 };
 use url::Url;
 
 use crate::catalog::ensure_trailing_slash;
+use crate::catalog::types::arrow_to_primitive;
 
 use super::committer::UnityCatalogCommitter;
 
@@ -70,6 +71,8 @@ pub enum CreateManagedTableError {
     Kernel(#[from] delta_kernel::Error),
     #[error("object store error: {0}")]
     ObjectStore(#[from] object_store::Error),
+    #[error("datafusion error: {0}")]
+    DataFusion(#[from] datafusion::error::DataFusionError),
     #[error("{0}")]
     Other(String),
 }
@@ -103,6 +106,62 @@ pub async fn create_managed_table(
     partition_columns: Vec<String>,
     engine_info: &str,
 ) -> Result<ManagedTable, CreateManagedTableError> {
+    let staged = stage_managed_table(
+        client.clone(),
+        catalog,
+        schema_name,
+        table,
+        arrow_schema.clone(),
+        partition_columns.clone(),
+        engine_info,
+    )
+    .await?;
+
+    client
+        .create_table(
+            catalog,
+            schema_name,
+            &DeltaCreateTableRequest {
+                name: table.to_string(),
+                location: staged.table.location.to_string(),
+                table_type: DeltaTableType::Managed,
+                data_source_format: Some(DeltaDataSourceFormat::Delta),
+                comment: None,
+                columns: arrow_to_delta_columns(&arrow_schema)?,
+                partition_columns: (!partition_columns.is_empty()).then_some(partition_columns),
+                protocol: staged.protocol,
+                properties: staged.properties.into_iter().collect(),
+                domain_metadata: None,
+                last_commit_timestamp_ms: staged.last_commit_timestamp_ms,
+                uniform: None,
+            },
+        )
+        .await?;
+
+    Ok(staged.table)
+}
+
+/// A managed table whose version 0 is written at its staging location but which is not yet
+/// registered in Unity Catalog: what the `createTable` call that finalizes it needs.
+pub(crate) struct StagedManagedTable {
+    pub table: ManagedTable,
+    /// The UC-registration properties derived from the v0 snapshot.
+    pub properties: HashMap<String, String>,
+    pub protocol: DeltaProtocol,
+    pub last_commit_timestamp_ms: i64,
+}
+
+/// Steps 1–3 of [`create_managed_table`] plus the property derivation of step 4: reserve a
+/// staging table and commit its version 0, leaving the caller to register it.
+pub(crate) async fn stage_managed_table(
+    client: Arc<DeltaV1Client>,
+    catalog: &str,
+    schema_name: &str,
+    table: &str,
+    arrow_schema: ArrowSchemaRef,
+    partition_columns: Vec<String>,
+    engine_info: &str,
+) -> Result<StagedManagedTable, CreateManagedTableError> {
     // 1. Reserve a staging table: UC allocates the id + managed location and advertises the contract.
     let staging = client
         .create_staging_table(
@@ -153,39 +212,24 @@ pub async fn create_managed_table(
         }
     };
 
-    // 4. Derive UC-registration properties from the v0 snapshot, then finalize the table in UC.
-    //    Prefer the post-commit snapshot; fall back to a fresh load (the committer published
+    // 4. Derive UC-registration properties from the v0 snapshot (the caller finalizes the table
+    //    in UC). Prefer the post-commit snapshot; fall back to a fresh load (the committer published
     //    `0.json` directly, so a plain snapshot load reads v0).
     let snapshot = match committed.post_commit_snapshot() {
         Some(s) => s.clone(),
         None => Snapshot::builder_for(location.as_str()).build(&engine)?,
     };
-    let uc_properties = get_final_required_properties_for_uc(&snapshot, &engine)?;
-
-    client
-        .create_table(
-            catalog,
-            schema_name,
-            &DeltaCreateTableRequest {
-                name: table.to_string(),
-                location: location.to_string(),
-                table_type: DeltaTableType::Managed,
-                data_source_format: Some(DeltaDataSourceFormat::Delta),
-                comment: None,
-                columns: arrow_to_delta_columns(&arrow_schema)?,
-                partition_columns: (!partition_columns.is_empty()).then_some(partition_columns),
-                protocol: snapshot_protocol(&snapshot),
-                properties: uc_properties.into_iter().collect(),
-                domain_metadata: None,
-                last_commit_timestamp_ms: snapshot.get_in_commit_timestamp(&engine)?.ok_or_else(
-                    || CreateManagedTableError::other("v0 snapshot has no in-commit timestamp"),
-                )?,
-                uniform: None,
-            },
-        )
-        .await?;
+    let properties = get_final_required_properties_for_uc(&snapshot, &engine)?;
+    let last_commit_timestamp_ms = snapshot
+        .get_in_commit_timestamp(&engine)?
+        .ok_or_else(|| CreateManagedTableError::other("v0 snapshot has no in-commit timestamp"))?;
 
-    Ok(ManagedTable { table_id, location })
+    Ok(StagedManagedTable {
+        table: ManagedTable { table_id, location },
+        properties,
+        protocol: snapshot_protocol(&snapshot),
+        last_commit_timestamp_ms,
+    })
 }
 
 /// Table properties that must be written to disk (in `0.json`) for a UC catalog-managed table.
@@ -258,7 +302,8 @@ fn arrow_to_kernel_schema(arrow: &ArrowSchemaRef) -> Result<SchemaRef, CreateMan
 }
 
 /// Build the UC `columns` payload (Delta schema struct) from the Arrow schema. UC's
-/// `DeltaDataType::Primitive` strings are Delta type names; map the common primitives.
+/// `DeltaDataType::Primitive` strings are Delta type names (see [`arrow_to_primitive`]); the
+/// connector targets flat schemas, so nested types are rejected.
 fn arrow_to_delta_columns(
     arrow: &ArrowSchemaRef,
 ) -> Result<DeltaStructType, CreateManagedTableError> {
@@ -266,9 +311,15 @@ fn arrow_to_delta_columns(
         .fields()
         .iter()
         .map(|f| {
+            let data_type = arrow_to_primitive(f.data_type()).map_err(|e| {
+                CreateManagedTableError::other(format!(
+                    "managed-table create: column '{}': {e}",
+                    f.name()
+                ))
+            })?;
             Ok(DeltaStructField {
                 name: f.name().clone(),
-                data_type: DeltaDataType::Primitive(arrow_primitive_to_delta(f.data_type())?),
+                data_type: DeltaDataType::Primitive(data_type),
                 nullable: f.is_nullable(),
                 metadata: Default::default(),
             })
@@ -280,34 +331,9 @@ fn arrow_to_delta_columns(
     })
 }
 
-/// Map an Arrow primitive to its Delta type name (`long`, `string`, …). Errors on unsupported /
-/// nested types — the connector targets flat schemas for the initial implementation.
-fn arrow_primitive_to_delta(dt: &ArrowDataType) -> Result<String, CreateManagedTableError> {
-    let name = match dt {
-        ArrowDataType::Boolean => "boolean",
-        ArrowDataType::Int8 => "byte",
-        ArrowDataType::Int16 => "short",
-        ArrowDataType::Int32 => "integer",
-        ArrowDataType::Int64 => "long",
-        ArrowDataType::Float32 => "float",
-        ArrowDataType::Float64 => "double",
-        ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => "string",
-        ArrowDataType::Binary | ArrowDataType::LargeBinary => "binary",
-        ArrowDataType::Date32 => "date",
-        ArrowDataType::Timestamp(_, _) => "timestamp",
-        other => {
-            return Err(CreateManagedTableError::other(format!(
-                "unsupported column type for managed-table create: {other:?}"
-            )));
-        }
-    };
-    Ok(name.to_string())
-}
-
 /// The protocol to send in the UC createTable request, read off the committed v0 snapshot so it
 /// matches exactly what was written to `0.json`.
-fn snapshot_protocol(snapshot: &Snapshot) -> unitycatalog_common::models::delta::v1::DeltaProtocol {
-    use unitycatalog_common::models::delta::v1::DeltaProtocol;
+fn snapshot_protocol(snapshot: &Snapshot) -> DeltaProtocol {
     let p = snapshot.table_configuration().protocol();
     DeltaProtocol {
         min_reader_version: p.min_reader_version(),
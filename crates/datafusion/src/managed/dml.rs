@@ -0,0 +1,1334 @@
+//! DML against Unity Catalog catalog-managed Delta tables: `INSERT INTO`,
+//! `INSERT OVERWRITE`, `DELETE`, `UPDATE` and `MERGE INTO`.
+//!
+//! The resolver wraps every managed Delta table in a [`ManagedDeltaTable`]. Reads delegate to
+//! the Delta provider it was resolved with; writes plan to a single-partition exec that drives
+//! [`commit_with_retry`] — the same loop as the bulk append path — so each commit is staged and
+//! ratified through the catalog with its conflict, throttle and ambiguity handling.
+//!
+//! ## Rewrites
+//!
+//! `DELETE`, `UPDATE` and `MERGE` are copy-on-write: each commit attempt reads the snapshot it
+//! commits against (as a DataFusion plan over that exact version), finds the data files
+//! holding a row the statement acts on, and rewrites just those — it removes them and streams
+//! their new contents into new files. Because the rows are recomputed per attempt, a conflict
+//! — including an `UpdateRequirementConflictException` — reloads and replans instead of
+//! committing rows derived from a stale version. A statement that matches no rows commits
+//! nothing.
+//!
+//! Files are told apart by reading them in one Parquet scan that tags each row with its
+//! file, which needs a table without column mapping or deletion vectors. Any other table is
+//! read through the Delta provider and rewritten whole. Writing deletion vectors instead of
+//! rewriting files is a follow-up.
+//!
+//! ## Partitioned tables
+//!
+//! DML on a partitioned managed table fails with an error before anything is read or
+//! written: new files go through the kernel's unpartitioned write context, as on the append
+//! path, which cannot place rows under their partition directories. Writing partitioned
+//! files is a follow-up.
+//!
+//! `INSERT` streams its input into new files once; a retry after a conflict commits the same
+//! files again rather than re-running the input.
+//!
+//! ## MERGE
+//!
+//! DataFusion's SQL planner has no `MERGE` statement, so a merge is described with
+//! [`MergeInto`] (a source plan, the join condition and the `WHEN` clauses, with expressions
+//! qualified by the target and source aliases) and planned with
+//! [`ManagedDeltaTable::merge_into`]. As in Spark, a target row matched by more than one
+//! source row is an error when a clause would act on it.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{AsArray, RecordBatch, UInt64Array};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, Int64Type, Schema, SchemaRef};
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{Column, Constraints, JoinType, ScalarValue, not_impl_err, plan_err};
+use datafusion::dataframe::DataFrame;
+use datafusion::datasource::listing::PartitionedFile;
+use datafusion::datasource::physical_plan::{FileGroup, FileScanConfigBuilder, ParquetSource};
+use datafusion::datasource::source::DataSourceExec;
+use datafusion::datasource::table_schema::TableSchema;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::SessionContext;
+use datafusion::execution::object_store::ObjectStoreUrl;
+use datafusion::execution::session_state::SessionStateBuilder;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::functions_aggregate::expr_fn::count;
+use datafusion::functions_window::expr_fn::row_number;
+use datafusion::logical_expr::dml::InsertOp;
+use datafusion::logical_expr::utils::conjunction;
+use datafusion::logical_expr::{
+    Case, Expr, LogicalPlan, TableProviderFilterPushDown, TableType, lit,
+};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::{PartitionStream, StreamingTableExec};
+use datafusion::physical_plan::{ExecutionPlan, execute_stream};
+use delta_kernel::schema::SchemaRef as KernelSchemaRef;
+use delta_kernel::snapshot::SnapshotRef;
+use delta_kernel::table_features::ColumnMappingMode;
+use deltalake_core::DeltaTableConfig;
+use deltalake_core::delta_datafusion::engine::DataFusionEngine;
+use deltalake_core::kernel::Snapshot;
+use futures::StreamExt;
+use object_store::path::Path as ObjectPath;
+use unitycatalog_common::models::delta::v1::DeltaCommitReport;
+use unitycatalog_object_store::UnityObjectStoreFactory;
+use url::Url;
+
+use crate::catalog::{log_store_for, provider_from_snapshot};
+
+use super::append::{
+    ManagedWrite, NewData, PlannedWrite, RemoveFiles, commit_with_retry, live_files,
+};
+use super::create::CreateManagedTableError;
+
+const ENGINE_INFO: &str = concat!("datafusion-unitycatalog/", env!("CARGO_PKG_VERSION"));
+
+// Helper columns added while planning a merge; never written.
+const TARGET_MARKER: &str = "__uc_merge_target";
+const SOURCE_MARKER: &str = "__uc_merge_source";
+const TARGET_ROW_ID: &str = "__uc_merge_row_id";
+const MERGE_CLAUSE: &str = "__uc_merge_clause";
+// Added to the rows a change reads: the index of the data file each row was read from.
+const FILE_INDEX: &str = "__uc_file";
+
+/// A catalog-managed Delta table that accepts DML.
+///
+/// Scans delegate to the wrapped read provider. [`insert_into`](TableProvider::insert_into),
+/// [`delete_from`](TableProvider::delete_from), [`update`](TableProvider::update) and
+/// [`merge_into`](Self::merge_into) return a plan that commits through Unity Catalog when
+/// executed and yields the number of affected rows as a single `count` column.
+#[derive(Debug)]
+pub struct ManagedDeltaTable {
+    inner: Arc<dyn TableProvider>,
+    target: ManagedTarget,
+}
+
+#[derive(Clone)]
+struct ManagedTarget {
+    factory: Arc<UnityObjectStoreFactory>,
+    catalog: String,
+    schema: String,
+    table: String,
+}
+
+impl std::fmt::Debug for ManagedTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.catalog, self.schema, self.table)
+    }
+}
+
+impl ManagedDeltaTable {
+    /// Wrap `inner`, the read provider for managed table `catalog.schema.table`. Writes vend
+    /// read-write credentials for the table through `factory`.
+    pub fn new(
+        inner: Arc<dyn TableProvider>,
+        factory: Arc<UnityObjectStoreFactory>,
+        catalog: impl Into<String>,
+        schema: impl Into<String>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            inner,
+            target: ManagedTarget {
+                factory,
+                catalog: catalog.into(),
+                schema: schema.into(),
+                table: table.into(),
+            },
+        }
+    }
+
+    /// Plan a `MERGE INTO` this table. Executing the plan commits the merge and yields the
+    /// number of target rows updated, deleted or inserted.
+    pub fn merge_into(&self, merge: MergeInto) -> Result<Arc<dyn ExecutionPlan>> {
+        if merge.clauses.is_empty() {
+            return plan_err!("MERGE INTO requires at least one WHEN clause");
+        }
+        if merge.target_alias == merge.source_alias {
+            return plan_err!(
+                "MERGE INTO target and source must have different aliases, both are '{}'",
+                merge.target_alias
+            );
+        }
+        let schema = self.inner.schema();
+        for clause in &merge.clauses {
+            let columns = match clause {
+                MergeClause::MatchedUpdate { assignments, .. } => assignments,
+                MergeClause::NotMatchedInsert { values, .. } => values,
+                MergeClause::MatchedDelete { .. } => continue,
+            };
+            check_columns(&schema, columns)?;
+        }
+        self.dml_exec(DmlOperation::Change(RowChange::Merge(merge)))
+    }
+
+    fn dml_exec(&self, operation: DmlOperation) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = count_schema();
+        let partition = Arc::new(DmlPartitionStream {
+            target: self.target.clone(),
+            table_schema: self.inner.schema(),
+            operation,
+            schema: schema.clone(),
+        });
+        let exec = StreamingTableExec::try_new(
+            schema,
+            vec![partition],
+            None,  // no projection
+            None,  // no output ordering
+            false, // not infinite
+            None,  // no limit
+        )?;
+        Ok(Arc::new(exec))
+    }
+}
+
+#[async_trait::async_trait]
+impl TableProvider for ManagedDeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn constraints(&self) -> Option<&Constraints> {
+        self.inner.constraints()
+    }
+
+    fn table_type(&self) -> TableType {
+        self.inner.table_type()
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.inner.scan(state, projection, filters, limit).await
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.inner.supports_filters_pushdown(filters)
+    }
+
+    async fn insert_into(
+        &self,
+        _state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        insert_op: InsertOp,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let overwrite = match insert_op {
+            InsertOp::Append => false,
+            InsertOp::Overwrite => true,
+            InsertOp::Replace => {
+                return not_impl_err!("INSERT OR REPLACE is not supported for managed tables");
+            }
+        };
+        self.dml_exec(DmlOperation::Insert { input, overwrite })
+    }
+
+    async fn delete_from(
+        &self,
+        _state: &dyn Session,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let predicate = conjunction(filters).map(unqualify).transpose()?;
+        self.dml_exec(DmlOperation::Change(RowChange::Delete { predicate }))
+    }
+
+    async fn update(
+        &self,
+        _state: &dyn Session,
+        assignments: Vec<(String, Expr)>,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        check_columns(&self.inner.schema(), &assignments)?;
+        let assignments = assignments
+            .into_iter()
+            .map(|(name, value)| Ok((name, unqualify(value)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let predicate = conjunction(filters).map(unqualify).transpose()?;
+        self.dml_exec(DmlOperation::Change(RowChange::Update {
+            assignments,
+            predicate,
+        }))
+    }
+}
+
+/// A `MERGE INTO <target> USING <source> ON <on> WHEN …` statement.
+///
+/// Expressions reference target columns through `target_alias` and source columns through
+/// `source_alias` (e.g. `col("t.id").eq(col("s.id"))`). Clauses are evaluated in order; the
+/// first whose kind and condition match a row acts on it.
+#[derive(Debug, Clone)]
+pub struct MergeInto {
+    pub source: LogicalPlan,
+    pub target_alias: String,
+    pub source_alias: String,
+    pub on: Expr,
+    pub clauses: Vec<MergeClause>,
+}
+
+/// One `WHEN` clause of a [`MergeInto`].
+#[derive(Debug, Clone)]
+pub enum MergeClause {
+    /// `WHEN MATCHED [AND predicate] THEN UPDATE SET column = value, …`
+    MatchedUpdate {
+        predicate: Option<Expr>,
+        assignments: Vec<(String, Expr)>,
+    },
+    /// `WHEN MATCHED [AND predicate] THEN DELETE`
+    MatchedDelete { predicate: Option<Expr> },
+    /// `WHEN NOT MATCHED [AND predicate] THEN INSERT (column, …) VALUES (value, …)`.
+    /// Target columns without a value are inserted as `NULL`.
+    NotMatchedInsert {
+        predicate: Option<Expr>,
+        values: Vec<(String, Expr)>,
+    },
+}
+
+impl MergeInto {
+    /// Merge `source` into the target, joining rows where `on` holds.
+    pub fn new(
+        source: LogicalPlan,
+        target_alias: impl Into<String>,
+        source_alias: impl Into<String>,
+        on: Expr,
+    ) -> Self {
+        Self {
+            source,
+            target_alias: target_alias.into(),
+            source_alias: source_alias.into(),
+            on,
+            clauses: Vec::new(),
+        }
+    }
+
+    pub fn when_matched_update(
+        mut self,
+        predicate: Option<Expr>,
+        assignments: Vec<(String, Expr)>,
+    ) -> Self {
+        self.clauses.push(MergeClause::MatchedUpdate {
+            predicate,
+            assignments,
+        });
+        self
+    }
+
+    pub fn when_matched_delete(mut self, predicate: Option<Expr>) -> Self {
+        self.clauses.push(MergeClause::MatchedDelete { predicate });
+        self
+    }
+
+    pub fn when_not_matched_insert(
+        mut self,
+        predicate: Option<Expr>,
+        values: Vec<(String, Expr)>,
+    ) -> Self {
+        self.clauses
+            .push(MergeClause::NotMatchedInsert { predicate, values });
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum DmlOperation {
+    Insert {
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    },
+    Change(RowChange),
+}
+
+/// A change computed from the table's current rows.
+#[derive(Debug, Clone)]
+enum RowChange {
+    Delete {
+        predicate: Option<Expr>,
+    },
+    Update {
+        assignments: Vec<(String, Expr)>,
+        predicate: Option<Expr>,
+    },
+    Merge(MergeInto),
+}
+
+impl RowChange {
+    fn operation(&self) -> &'static str {
+        match self {
+            RowChange::Delete { .. } => "DELETE",
+            RowChange::Update { .. } => "UPDATE",
+            RowChange::Merge(_) => "MERGE",
+        }
+    }
+
+    fn report(&self, affected: usize) -> DeltaCommitReport {
+        let affected = Some(affected as i64);
+        match self {
+            RowChange::Delete { .. } => DeltaCommitReport {
+                num_rows_removed: affected,
+                ..Default::default()
+            },
+            RowChange::Update { .. } => DeltaCommitReport {
+                num_rows_updated: affected,
+                ..Default::default()
+            },
+            RowChange::Merge(_) => DeltaCommitReport::default(),
+        }
+    }
+
+    /// Compute the change from `current`, the table's rows at the commit's snapshot tagged
+    /// with the [`FILE_INDEX`] of the file each was read from.
+    async fn rewrite(&self, ctx: &SessionContext, current: DataFrame) -> Result<Rewrite> {
+        let file = Expr::Column(Column::new_unqualified(FILE_INDEX));
+        match self {
+            RowChange::Delete { predicate: None } => {
+                let (touched, affected) = files_of(current, file).await?;
+                Ok(Rewrite {
+                    affected,
+                    touched,
+                    rows: None,
+                })
+            }
+            RowChange::Delete {
+                predicate: Some(predicate),
+            } => {
+                let deleted = current.clone().filter(predicate.clone().is_true())?;
+                let (touched, affected) = files_of(deleted, file).await?;
+                if affected == 0 {
+                    return Ok(Rewrite::default());
+                }
+                let rows = in_files(current, &touched)?
+                    .filter(predicate.clone().is_not_true())?
+                    .drop_columns(&[FILE_INDEX])?;
+                Ok(Rewrite {
+                    affected,
+                    touched,
+                    rows: Some(rows),
+                })
+            }
+            RowChange::Update {
+                assignments,
+                predicate,
+            } => {
+                let matched = predicate
+                    .clone()
+                    .map(Expr::is_true)
+                    .unwrap_or_else(|| lit(true));
+                let updated = current.clone().filter(matched.clone())?;
+                let (touched, affected) = files_of(updated, file).await?;
+                if affected == 0 {
+                    return Ok(Rewrite::default());
+                }
+                let projection = current
+                    .schema()
+                    .fields()
+                    .iter()
+                    .filter(|field| field.name() != FILE_INDEX)
+                    .map(|field| {
+                        let column = Expr::Column(Column::new_unqualified(field.name()));
+                        match assignments.iter().find(|(name, _)| name == field.name()) {
+                            Some((_, value)) => {
+                                case(vec![(matched.clone(), value.clone())], column)
+                                    .alias(field.name())
+                            }
+                            None => column,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let rows = in_files(current, &touched)?.select(projection)?;
+                Ok(Rewrite {
+                    affected,
+                    touched,
+                    rows: Some(rows),
+                })
+            }
+            RowChange::Merge(merge) => merge_rows(ctx, current, merge).await,
+        }
+    }
+}
+
+/// The outcome of a [`RowChange`].
+#[derive(Debug, Default)]
+struct Rewrite {
+    /// Rows acted on.
+    affected: usize,
+    /// The [`FILE_INDEX`] of every file the change rewrites, ascending.
+    touched: Vec<i64>,
+    /// The new contents of the touched files, plus any inserted rows; `None` when nothing
+    /// is left of them.
+    rows: Option<DataFrame>,
+}
+
+/// The [`FILE_INDEX`]es of the files `rows` were read from, ascending, and the number of rows.
+async fn files_of(rows: DataFrame, file: Expr) -> Result<(Vec<i64>, usize)> {
+    let counts = rows
+        .aggregate(
+            vec![file.alias(FILE_INDEX)],
+            vec![count(lit(1)).alias("rows")],
+        )?
+        .collect()
+        .await?;
+    let (mut files, mut total) = (Vec::new(), 0);
+    for batch in &counts {
+        files.extend(
+            batch
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .iter()
+                .copied(),
+        );
+        total += batch
+            .column(1)
+            .as_primitive::<Int64Type>()
+            .values()
+            .iter()
+            .sum::<i64>();
+    }
+    files.sort_unstable();
+    Ok((files, total as usize))
+}
+
+/// The rows of `current` read from the files `touched`.
+fn in_files(current: DataFrame, touched: &[i64]) -> Result<DataFrame> {
+    let file = Expr::Column(Column::new_unqualified(FILE_INDEX));
+    current.filter(one_of(&file, touched.iter().map(|f| lit(*f)).collect()))
+}
+
+/// A merge's full outer join of target and source, with the index of the first clause acting
+/// on each joined row in [`MERGE_CLAUSE`] (NULL if none does).
+struct MergeJoin {
+    joined: DataFrame,
+    matched: Expr,
+    /// The acting clause's index.
+    clause: Expr,
+    /// Which joined rows are written: unmatched target rows, matched rows no delete clause
+    /// acts on, and source rows an insert clause acts on.
+    keep: Expr,
+}
+
+impl MergeJoin {
+    fn new(ctx: &SessionContext, target: DataFrame, merge: &MergeInto) -> Result<Self> {
+        let target = target
+            .with_column(TARGET_MARKER, lit(true))?
+            .with_column(TARGET_ROW_ID, row_number())?
+            .alias(&merge.target_alias)?;
+        let source = DataFrame::new(ctx.state(), merge.source.clone())
+            .with_column(SOURCE_MARKER, lit(true))?
+            .alias(&merge.source_alias)?;
+        let joined = target.join_on(source, JoinType::Full, [merge.on.clone()])?;
+
+        let in_target = qualified(&merge.target_alias, TARGET_MARKER).is_not_null();
+        let in_source = qualified(&merge.source_alias, SOURCE_MARKER).is_not_null();
+        let matched = in_target.clone().and(in_source.clone());
+        let target_only = in_source.not();
+        let source_only = in_target.not();
+
+        let mut choices = Vec::new();
+        let (mut deletes, mut inserts) = (Vec::new(), Vec::new());
+        for (index, clause) in merge.clauses.iter().enumerate() {
+            let (applies_to, predicate) = match clause {
+                MergeClause::MatchedUpdate { predicate, .. } => (&matched, predicate),
+                MergeClause::MatchedDelete { predicate } => {
+                    deletes.push(lit(index as i32));
+                    (&matched, predicate)
+                }
+                MergeClause::NotMatchedInsert { predicate, .. } => {
+                    inserts.push(lit(index as i32));
+                    (&source_only, predicate)
+                }
+            };
+            let condition = match predicate {
+                Some(predicate) => applies_to.clone().and(predicate.clone().is_true()),
+                None => applies_to.clone(),
+            };
+            choices.push((condition, lit(index as i32)));
+        }
+        let joined =
+            joined.with_column(MERGE_CLAUSE, case(choices, lit(ScalarValue::Int32(None))))?;
+        let clause = Expr::Column(Column::new_unqualified(MERGE_CLAUSE));
+        let keep = target_only
+            .or(matched.clone().and(one_of(&clause, deletes).is_not_true()))
+            .or(source_only.and(one_of(&clause, inserts).is_true()));
+        Ok(Self {
+            joined,
+            matched,
+            clause,
+            keep,
+        })
+    }
+}
+
+fn qualified(alias: &str, name: &str) -> Expr {
+    Expr::Column(Column::new(Some(alias), name))
+}
+
+/// Plan and evaluate a merge as a full outer join of the target and source, choosing the
+/// acting clause per joined row and projecting each target column through it.
+///
+/// Only the files holding a target row some source row matches are rewritten. Joined
+/// against just those files' rows, every source row still matches the same target rows, so
+/// the clauses act exactly as they would over the whole table.
+async fn merge_rows(
+    ctx: &SessionContext,
+    current: DataFrame,
+    merge: &MergeInto,
+) -> Result<Rewrite> {
+    let target_columns = current
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.name() != FILE_INDEX)
+        .map(|f| (f.name().clone(), f.data_type().clone()))
+        .collect::<Vec<_>>();
+
+    let join = MergeJoin::new(ctx, current.clone(), merge)?;
+    let duplicates = join
+        .joined
+        .clone()
+        .filter(join.matched.clone().and(join.clause.clone().is_not_null()))?
+        .aggregate(
+            vec![qualified(&merge.target_alias, TARGET_ROW_ID)],
+            vec![count(lit(1)).alias("matches")],
+        )?
+        .filter(Expr::Column(Column::new_unqualified("matches")).gt(lit(1i64)))?
+        .count()
+        .await?;
+    if duplicates > 0 {
+        return plan_err!(
+            "MERGE INTO matched {duplicates} target row(s) with more than one source row; \
+             deduplicate the source or tighten the ON condition"
+        );
+    }
+
+    let affected = join
+        .joined
+        .clone()
+        .filter(join.clause.clone().is_not_null())?
+        .count()
+        .await?;
+    if affected == 0 {
+        return Ok(Rewrite::default());
+    }
+    let (touched, _) = files_of(
+        join.joined.filter(join.matched)?,
+        qualified(&merge.target_alias, FILE_INDEX),
+    )
+    .await?;
+
+    let join = MergeJoin::new(ctx, in_files(current, &touched)?, merge)?;
+    let mut projection = Vec::with_capacity(target_columns.len());
+    for (name, data_type) in &target_columns {
+        let original = qualified(&merge.target_alias, name);
+        let mut branches = Vec::new();
+        for (index, clause_def) in merge.clauses.iter().enumerate() {
+            let chosen = join.clause.clone().eq(lit(index as i32));
+            match clause_def {
+                MergeClause::MatchedUpdate { assignments, .. } => {
+                    if let Some((_, value)) = assignments.iter().find(|(n, _)| n == name) {
+                        branches.push((chosen, value.clone()));
+                    }
+                }
+                MergeClause::NotMatchedInsert { values, .. } => {
+                    let value = match values.iter().find(|(n, _)| n == name) {
+                        Some((_, value)) => value.clone(),
+                        None => lit(ScalarValue::try_from(data_type)?),
+                    };
+                    branches.push((chosen, value));
+                }
+                MergeClause::MatchedDelete { .. } => {}
+            }
+        }
+        projection.push(case(branches, original).alias(name));
+    }
+    let rows = join.joined.filter(join.keep)?.select(projection)?;
+    Ok(Rewrite {
+        affected,
+        touched,
+        rows: Some(rows),
+    })
+}
+
+/// `expr IN (values)`, or `FALSE` when there are none.
+fn one_of(expr: &Expr, values: Vec<Expr>) -> Expr {
+    if values.is_empty() {
+        lit(false)
+    } else {
+        expr.clone().in_list(values, false)
+    }
+}
+
+/// `CASE WHEN … THEN … ELSE otherwise END`, or just `otherwise` without branches.
+fn case(branches: Vec<(Expr, Expr)>, otherwise: Expr) -> Expr {
+    if branches.is_empty() {
+        return otherwise;
+    }
+    Expr::Case(Case::new(
+        None,
+        branches
+            .into_iter()
+            .map(|(when, then)| (Box::new(when), Box::new(then)))
+            .collect(),
+        Some(Box::new(otherwise)),
+    ))
+}
+
+/// Drop table qualifiers from column references. The planner qualifies DELETE/UPDATE
+/// expressions with the statement's table reference, but they are evaluated against a scan
+/// of the commit's snapshot, which carries no such qualifier.
+fn unqualify(expr: Expr) -> Result<Expr> {
+    expr.transform(|expr| {
+        Ok(match expr {
+            Expr::Column(column) if column.relation.is_some() => {
+                Transformed::yes(Expr::Column(Column::new_unqualified(column.name)))
+            }
+            expr => Transformed::no(expr),
+        })
+    })
+    .map(|transformed| transformed.data)
+}
+
+fn check_columns(schema: &SchemaRef, columns: &[(String, Expr)]) -> Result<()> {
+    for (name, _) in columns {
+        if schema.field_with_name(name).is_err() {
+            return plan_err!("column '{name}' does not exist in the target table");
+        }
+    }
+    Ok(())
+}
+
+fn count_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![Field::new(
+        "count",
+        DataType::UInt64,
+        false,
+    )]))
+}
+
+/// `batch` as the table's schema, casting where the plan's output types differ.
+fn conform_batch(batch: RecordBatch, schema: &SchemaRef) -> Result<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            if column.data_type() == field.data_type() {
+                Ok(column.clone())
+            } else {
+                cast(column, field.data_type())
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
+
+/// `rows` as the table's schema, batch by batch (see [`conform_batch`]).
+pub(super) fn conform_stream(
+    rows: SendableRecordBatchStream,
+    schema: &SchemaRef,
+) -> SendableRecordBatchStream {
+    let target = schema.clone();
+    Box::pin(RecordBatchStreamAdapter::new(
+        schema.clone(),
+        rows.map(move |batch| conform_batch(batch?, &target)),
+    ))
+}
+
+/// A DML statement as a [`ManagedWrite`], planned once per commit attempt.
+struct DmlWrite {
+    ctx: SessionContext,
+    table_schema: SchemaRef,
+    kind: WriteKind,
+    /// Rows acted on by the most recently planned attempt.
+    affected: usize,
+}
+
+enum WriteKind {
+    Insert {
+        /// The statement's rows, taken by the first attempt.
+        rows: Option<SendableRecordBatchStream>,
+        /// The table schema the first attempt wrote its files for.
+        written_for: Option<KernelSchemaRef>,
+        /// The add-file metadata and row count of those files, committed again on a retry.
+        written: Option<(Vec<RecordBatch>, usize)>,
+        overwrite: bool,
+    },
+    Change(RowChange),
+}
+
+/// Scan exactly the snapshot a commit attempt commits against, tagging each row with the
+/// [`FILE_INDEX`] of the data file it was read from.
+///
+/// When the files read back as plain Parquet (see [`plain_files`]) they are read in a
+/// single scan ([`IndexedFiles`]) and their paths are returned in index order. Otherwise
+/// the table is read through the Delta provider as a single pseudo-file with index 0 and
+/// no path.
+async fn current_rows(
+    ctx: &SessionContext,
+    table_schema: &SchemaRef,
+    snapshot: &SnapshotRef,
+) -> Result<(Option<Vec<String>>, DataFrame), CreateManagedTableError> {
+    let engine = DataFusionEngine::new_from_context(ctx.task_ctx());
+    if let Some(files) = plain_files(snapshot, engine.as_ref())?
+        && !files.is_empty()
+    {
+        let scan = IndexedFiles::try_new(snapshot.table_root(), table_schema, &files)?;
+        let rows = ctx.read_table(Arc::new(scan))?;
+        return Ok((
+            Some(files.into_iter().map(|(path, _)| path).collect()),
+            rows,
+        ));
+    }
+    let log_store = log_store_for(&ctx.runtime_env(), snapshot.table_root())?;
+    let provider = provider_from_snapshot(
+        Snapshot::new(snapshot.clone(), DeltaTableConfig::default()),
+        log_store,
+    )
+    .await?;
+    let rows = ctx
+        .read_table(provider)?
+        .with_column(FILE_INDEX, lit(0i64))?;
+    Ok((None, rows))
+}
+
+/// The paths and sizes of `snapshot`'s live files if each reads back as plain Parquet: the
+/// table has no column mapping and no partition columns, and no file has a deletion vector.
+fn plain_files(
+    snapshot: &SnapshotRef,
+    engine: &dyn delta_kernel::Engine,
+) -> Result<Option<Vec<(String, u64)>>, CreateManagedTableError> {
+    let config = snapshot.table_configuration();
+    if config.column_mapping_mode() != ColumnMappingMode::None
+        || !config.metadata().partition_columns().is_empty()
+    {
+        return Ok(None);
+    }
+    let files = live_files(snapshot, engine)?;
+    if files.values().any(|file| file.deletion_vector.is_some()) {
+        return Ok(None);
+    }
+    Ok(Some(
+        files
+            .into_iter()
+            .map(|(path, file)| (path, file.size))
+            .collect(),
+    ))
+}
+
+/// A snapshot's data files read as one Parquet scan, each row tagged with the [`FILE_INDEX`]
+/// of its file: the index is carried as the file's partition value, so the scan needs no
+/// per-file plan.
+#[derive(Debug)]
+struct IndexedFiles {
+    store: ObjectStoreUrl,
+    file_schema: SchemaRef,
+    /// `file_schema` plus [`FILE_INDEX`].
+    schema: SchemaRef,
+    files: Vec<PartitionedFile>,
+}
+
+impl IndexedFiles {
+    /// Scan `files`, paths relative to `table_root` with their sizes, as `file_schema`.
+    fn try_new(
+        table_root: &Url,
+        file_schema: &SchemaRef,
+        files: &[(String, u64)],
+    ) -> Result<Self, CreateManagedTableError> {
+        let store = ObjectStoreUrl::parse(&table_root[..url::Position::BeforePath])?;
+        let files = files
+            .iter()
+            .enumerate()
+            .map(|(index, (path, size))| {
+                let url = table_root.join(path).map_err(|e| {
+                    CreateManagedTableError::other(format!("invalid file path '{path}': {e}"))
+                })?;
+                let location = ObjectPath::from_url_path(url.path()).map_err(|e| {
+                    CreateManagedTableError::other(format!("invalid file path '{path}': {e}"))
+                })?;
+                let mut file = PartitionedFile::new(location.to_string(), *size);
+                file.partition_values = vec![ScalarValue::Int64(Some(index as i64))];
+                Ok(file)
+            })
+            .collect::<Result<_, CreateManagedTableError>>()?;
+        let mut fields = file_schema.fields().to_vec();
+        fields.push(file_index_field());
+        Ok(Self {
+            store,
+            file_schema: file_schema.clone(),
+            schema: Arc::new(Schema::new(fields)),
+            files,
+        })
+    }
+}
+
+fn file_index_field() -> FieldRef {
+    Arc::new(Field::new(FILE_INDEX, DataType::Int64, false))
+}
+
+#[async_trait::async_trait]
+impl TableProvider for IndexedFiles {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = TableSchema::new(self.file_schema.clone(), vec![file_index_field()]);
+        let config =
+            FileScanConfigBuilder::new(self.store.clone(), Arc::new(ParquetSource::new(schema)))
+                .with_file_group(FileGroup::new(self.files.clone()))
+                .with_projection_indices(projection.cloned())?
+                .with_limit(limit)
+                .build();
+        Ok(DataSourceExec::from_data_source(config))
+    }
+}
+
+impl ManagedWrite for DmlWrite {
+    async fn plan(
+        &mut self,
+        snapshot: &SnapshotRef,
+    ) -> Result<Option<PlannedWrite>, CreateManagedTableError> {
+        // New files are written through the kernel's unpartitioned write context.
+        if !snapshot
+            .table_configuration()
+            .metadata()
+            .partition_columns()
+            .is_empty()
+        {
+            return Err(CreateManagedTableError::other(
+                "DML on partitioned managed tables is not supported yet",
+            ));
+        }
+        let planned = match &mut self.kind {
+            WriteKind::Insert {
+                rows,
+                written_for,
+                written,
+                overwrite,
+            } => {
+                let data = match (rows.take(), written) {
+                    (Some(rows), _) => {
+                        *written_for = Some(snapshot.schema());
+                        NewData::Inserted(conform_stream(rows, &self.table_schema))
+                    }
+                    (None, Some((files, rows))) => {
+                        if written_for.as_ref() != Some(&snapshot.schema()) {
+                            return Err(CreateManagedTableError::other(
+                                "the table's schema changed while INSERT was committing; \
+                                 run the statement again",
+                            ));
+                        }
+                        NewData::Written {
+                            files: files.clone(),
+                            rows: *rows,
+                        }
+                    }
+                    (None, None) => {
+                        return Err(CreateManagedTableError::other(
+                            "INSERT rows were consumed by an attempt that wrote no files",
+                        ));
+                    }
+                };
+                PlannedWrite {
+                    operation: "WRITE",
+                    remove: if *overwrite {
+                        RemoveFiles::All
+                    } else {
+                        RemoveFiles::None
+                    },
+                    data,
+                    transactions: Vec::new(),
+                    properties: BTreeMap::new(),
+                    report: DeltaCommitReport::default(),
+                }
+            }
+            WriteKind::Change(change) => {
+                let (paths, current) =
+                    current_rows(&self.ctx, &self.table_schema, snapshot).await?;
+                let rewrite = change.rewrite(&self.ctx, current).await?;
+                self.affected = rewrite.affected;
+                if rewrite.affected == 0 {
+                    return Ok(None);
+                }
+                let remove = match paths {
+                    Some(paths) => RemoveFiles::Paths(
+                        rewrite
+                            .touched
+                            .iter()
+                            .filter_map(|index| paths.get(usize::try_from(*index).ok()?))
+                            .cloned()
+                            .collect(),
+                    ),
+                    None if rewrite.touched.is_empty() => RemoveFiles::None,
+                    None => RemoveFiles::All,
+                };
+                let data = match rewrite.rows {
+                    Some(rows) => NewData::Rewritten(conform_stream(
+                        rows.execute_stream().await?,
+                        &self.table_schema,
+                    )),
+                    None => NewData::None,
+                };
+                PlannedWrite {
+                    operation: change.operation(),
+                    remove,
+                    data,
+                    transactions: Vec::new(),
+                    properties: BTreeMap::new(),
+                    report: change.report(rewrite.affected),
+                }
+            }
+        };
+        Ok(Some(planned))
+    }
+
+    fn files_written(&mut self, files: &[RecordBatch], rows: usize) {
+        if let WriteKind::Insert { written, .. } = &mut self.kind {
+            *written = Some((files.to_vec(), rows));
+            self.affected = rows;
+        }
+    }
+}
+
+/// A single-partition [`PartitionStream`] that runs one DML statement and yields its count.
+struct DmlPartitionStream {
+    target: ManagedTarget,
+    table_schema: SchemaRef,
+    operation: DmlOperation,
+    schema: SchemaRef,
+}
+
+impl std::fmt::Debug for DmlPartitionStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DmlPartitionStream")
+            .field("target", &self.target)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for DmlPartitionStream {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, task_ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let target = self.target.clone();
+        let table_schema = self.table_schema.clone();
+        let operation = self.operation.clone();
+        let schema = self.schema.clone();
+        let fut = async move {
+            let affected = run_dml(&target, table_schema, operation, task_ctx).await?;
+            Ok(RecordBatch::try_new(
+                count_schema(),
+                vec![Arc::new(UInt64Array::from(vec![affected as u64]))],
+            )?)
+        };
+        Box::pin(RecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(fut),
+        ))
+    }
+}
+
+async fn run_dml(
+    target: &ManagedTarget,
+    table_schema: SchemaRef,
+    operation: DmlOperation,
+    task_ctx: Arc<TaskContext>,
+) -> Result<usize> {
+    // Plan rewrites in a session over the same runtime, so the table's registered object
+    // store serves the snapshot scans.
+    let state = SessionStateBuilder::new()
+        .with_config(task_ctx.session_config().clone())
+        .with_runtime_env(task_ctx.runtime_env())
+        .with_default_features()
+        .build();
+    let kind = match operation {
+        DmlOperation::Insert { input, overwrite } => WriteKind::Insert {
+            rows: Some(execute_stream(input, task_ctx)?),
+            written_for: None,
+            written: None,
+            overwrite,
+        },
+        DmlOperation::Change(change) => WriteKind::Change(change),
+    };
+    let mut write = DmlWrite {
+        ctx: SessionContext::new_with_state(state),
+        table_schema,
+        kind,
+        affected: 0,
+    };
+    commit_with_retry(
+        target.factory.clone(),
+        &target.catalog,
+        &target.schema,
+        &target.table,
+        ENGINE_INFO,
+        &mut write,
+    )
+    .await
+    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    Ok(write.affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Int64Array, StringArray};
+    use datafusion::arrow::util::pretty::pretty_format_batches;
+    use datafusion::datasource::MemTable;
+    use datafusion::logical_expr::col;
+
+    use super::*;
+
+    fn table(ids: Vec<i64>, names: Vec<&str>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn frame(ctx: &SessionContext, batch: RecordBatch) -> DataFrame {
+        let provider = MemTable::try_new(batch.schema(), vec![vec![batch]]).unwrap();
+        ctx.read_table(Arc::new(provider)).unwrap()
+    }
+
+    /// The rows of `files`, tagged with each one's [`FILE_INDEX`].
+    fn files(ctx: &SessionContext, files: Vec<RecordBatch>) -> DataFrame {
+        files
+            .into_iter()
+            .enumerate()
+            .map(|(index, batch)| {
+                frame(ctx, batch)
+                    .with_column(FILE_INDEX, lit(index as i64))
+                    .unwrap()
+            })
+            .reduce(|all, file| all.union(file).unwrap())
+            .unwrap()
+    }
+
+    async fn rows(rewrite: &Rewrite) -> String {
+        let sorted = rewrite
+            .rows
+            .clone()
+            .unwrap()
+            .sort_by(vec![col("id")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        pretty_format_batches(&sorted).unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn delete_rewrites_only_matched_files() {
+        let ctx = SessionContext::new();
+        let current = files(
+            &ctx,
+            vec![table(vec![1], vec!["a"]), table(vec![2, 3], vec!["b", "c"])],
+        );
+        let change = RowChange::Delete {
+            predicate: Some(col("id").gt_eq(lit(3i64))),
+        };
+        let rewrite = change.rewrite(&ctx, current.clone()).await.unwrap();
+        assert_eq!(rewrite.affected, 1);
+        assert_eq!(rewrite.touched, vec![1]);
+        let expected = [
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 2  | b    |",
+            "+----+------+",
+        ]
+        .join("\n");
+        assert_eq!(rows(&rewrite).await, expected);
+
+        let change = RowChange::Delete {
+            predicate: Some(col("id").eq(lit(9i64))),
+        };
+        let rewrite = change.rewrite(&ctx, current.clone()).await.unwrap();
+        assert_eq!(rewrite.affected, 0);
+        assert!(rewrite.touched.is_empty());
+
+        let rewrite = RowChange::Delete { predicate: None }
+            .rewrite(&ctx, current)
+            .await
+            .unwrap();
+        assert_eq!(rewrite.affected, 3);
+        assert_eq!(rewrite.touched, vec![0, 1]);
+        assert!(rewrite.rows.is_none());
+    }
+
+    #[tokio::test]
+    async fn update_rewrites_only_matched_files() {
+        let ctx = SessionContext::new();
+        let current = files(
+            &ctx,
+            vec![table(vec![1], vec!["a"]), table(vec![2, 3], vec!["b", "c"])],
+        );
+        let change = RowChange::Update {
+            assignments: vec![("name".to_string(), lit("z"))],
+            predicate: Some(col("id").eq(lit(2i64))),
+        };
+        let rewrite = change.rewrite(&ctx, current).await.unwrap();
+        assert_eq!(rewrite.affected, 1);
+        assert_eq!(rewrite.touched, vec![1]);
+        let expected = [
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 2  | z    |",
+            "| 3  | c    |",
+            "+----+------+",
+        ]
+        .join("\n");
+        assert_eq!(rows(&rewrite).await, expected);
+    }
+
+    #[tokio::test]
+    async fn merge_updates_deletes_and_inserts() {
+        let ctx = SessionContext::new();
+        let current = files(
+            &ctx,
+            vec![table(vec![1], vec!["a"]), table(vec![2, 3], vec!["b", "c"])],
+        );
+        let source = frame(&ctx, table(vec![2, 3, 4], vec!["B", "delete", "D"]));
+        let merge = MergeInto::new(
+            source.logical_plan().clone(),
+            "t",
+            "s",
+            col("t.id").eq(col("s.id")),
+        )
+        .when_matched_delete(Some(col("s.name").eq(lit("delete"))))
+        .when_matched_update(None, vec![("name".to_string(), col("s.name"))])
+        .when_not_matched_insert(None, vec![("id".to_string(), col("s.id"))]);
+
+        let rewrite = RowChange::Merge(merge)
+            .rewrite(&ctx, current)
+            .await
+            .unwrap();
+        assert_eq!(rewrite.affected, 3);
+        assert_eq!(rewrite.touched, vec![1]);
+        let expected = [
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 2  | B    |",
+            "| 4  |      |",
+            "+----+------+",
+        ]
+        .join("\n");
+        assert_eq!(rows(&rewrite).await, expected);
+    }
+
+    #[tokio::test]
+    async fn merge_rewrites_files_matched_without_an_acting_clause() {
+        // Row 1 matches but no clause acts on it. Its file is still rewritten, so source row 1
+        // is not mistaken for an unmatched row and inserted again.
+        let ctx = SessionContext::new();
+        let current = files(
+            &ctx,
+            vec![table(vec![1], vec!["a"]), table(vec![2], vec!["b"])],
+        );
+        let source = frame(&ctx, table(vec![1, 3], vec!["y", "c"]));
+        let merge = MergeInto::new(
+            source.logical_plan().clone(),
+            "t",
+            "s",
+            col("t.id").eq(col("s.id")),
+        )
+        .when_matched_update(
+            Some(col("s.name").eq(lit("x"))),
+            vec![("name".to_string(), col("s.name"))],
+        )
+        .when_not_matched_insert(
+            None,
+            vec![
+                ("id".to_string(), col("s.id")),
+                ("name".to_string(), col("s.name")),
+            ],
+        );
+
+        let rewrite = RowChange::Merge(merge)
+            .rewrite(&ctx, current)
+            .await
+            .unwrap();
+        assert_eq!(rewrite.affected, 1);
+        assert_eq!(rewrite.touched, vec![0]);
+        let expected = [
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 1  | a    |",
+            "| 3  | c    |",
+            "+----+------+",
+        ]
+        .join("\n");
+        assert_eq!(rows(&rewrite).await, expected);
+    }
+
+    #[tokio::test]
+    async fn merge_rejects_ambiguous_matches() {
+        let ctx = SessionContext::new();
+        let current = files(&ctx, vec![table(vec![1], vec!["a"])]);
+        let source = frame(&ctx, table(vec![1, 1], vec!["x", "y"]));
+        let merge = MergeInto::new(
+            source.logical_plan().clone(),
+            "t",
+            "s",
+            col("t.id").eq(col("s.id")),
+        )
+        .when_matched_update(None, vec![("name".to_string(), col("s.name"))]);
+
+        let err = RowChange::Merge(merge)
+            .rewrite(&ctx, current)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("more than one source row"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn unqualify_strips_table_references() {
+        let expr = col("cat.sch.tbl.id").eq(col("name"));
+        assert_eq!(unqualify(expr).unwrap(), col("id").eq(col("name")));
+    }
+}
@@ -0,0 +1,1075 @@
+//! Materialized views: catalog-managed Delta tables holding the result of a defining query.
+//!
+//! A materialized view is stored exactly like a managed table — the server registers it as
+//! `MATERIALIZED_VIEW` with the query in `view_definition` (from which it derives the view's
+//! dependencies) — so every write to it goes through [`commit_with_retry`] and the
+//! [`UnityCatalogCommitter`](super::UnityCatalogCommitter).
+//!
+//! - [`create_materialized_view`] plans the query, stages and registers the table, then
+//!   streams the query's rows into version 1.
+//! - [`refresh_materialized_view`] recomputes it.
+//!
+//! ## Source versions
+//!
+//! The defining query is planned in a scratch session whose Delta tables are read through a
+//! builder that records the version of every snapshot it builds. The commit of a create or
+//! refresh records those versions, and the view version it creates, as Delta application
+//! transactions (`txn` actions): [`REFRESH_APP_ID`] carries the view's version and
+//! [`SOURCE_APP_ID_PREFIX`] followed by a source's full name that source's. The state is
+//! therefore committed atomically with the rows it describes.
+//!
+//! The same versions are set as the view's table properties, under the same keys, by the
+//! `updateTable` request that ratifies the commit, so catalog clients can see what a view
+//! was last refreshed from without reading its log. The log stays authoritative: refreshes
+//! decide from the `txn` actions. A create's first commit ratifies them too; until it does,
+//! the view has no such properties.
+//!
+//! ## Refresh
+//!
+//! A refresh compares the versions it reads now with the recorded ones:
+//!
+//! - **Up to date** — nothing changed and the view is still at the version the last refresh
+//!   left it at: no commit.
+//! - **Incremental** — exactly one source changed, the query is projections and filters over
+//!   that one table, and every change since the recorded version only added files (same
+//!   schema, unpartitioned, no column mapping, no file removed and no deletion vector
+//!   written). The query runs over just the added files and its rows are appended. The
+//!   commit asserts the view has not moved since planning.
+//! - **Full** — anything else, or when forced: the query's rows replace the view's contents.
+//!
+//! Either way the rows are streamed into data files as the query produces them, and a retried
+//! commit reuses the files the first attempt wrote rather than running the query again.
+//!
+//! Only direct Delta references count as tracked sources; a query that reads a view or a
+//! non-Delta table is always refreshed in full.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use datafusion::catalog::{AsyncCatalogProviderList, TableProvider};
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::dataframe::DataFrame;
+use datafusion::datasource::empty::EmptyTable;
+use datafusion::datasource::provider_as_source;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::execution::context::{SessionConfig, SessionContext};
+use datafusion::logical_expr::{Expr, LogicalPlan, TableScan};
+use datafusion::prelude::ParquetReadOptions;
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use datafusion::sql::sqlparser::ast::Statement as SqlStatement;
+use delta_kernel::engine::default::DefaultEngineBuilder;
+use delta_kernel::schema::SchemaRef as KernelSchemaRef;
+use delta_kernel::snapshot::{Snapshot as KernelSnapshot, SnapshotRef};
+use delta_kernel::table_features::ColumnMappingMode;
+use delta_kernel::{Engine, Version};
+use deltalake_core::DeltaTableConfig;
+use deltalake_core::delta_datafusion::engine::DataFusionEngine;
+use deltalake_core::kernel::Snapshot;
+use tracing::{debug, info};
+use unitycatalog_client::DeltaV1Client;
+use unitycatalog_common::models::delta::v1::{DeltaCommit, DeltaCommitReport};
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table, TableType};
+use unitycatalog_object_store::{TableOperation, UnityObjectStoreFactory};
+use url::Url;
+
+use crate::catalog::{
+    ManagedReadState, TableProviderBuilder, TableProviderError, UnityCatalogProviderList,
+    ensure_trailing_slash, log_store_for, provider_from_snapshot, resolve_managed_read_state,
+    to_log_tail,
+};
+
+use super::append::{
+    ManagedWrite, NewData, PlannedWrite, RemoveFiles, build_snapshot, commit_with_retry, live_files,
+};
+use super::create::{CreateManagedTableError, stage_managed_table};
+use super::dml::conform_stream;
+
+/// Application id of the `txn` action recording the materialized view's own version after
+/// its last refresh.
+pub const REFRESH_APP_ID: &str = "io.unitycatalog.materializedView.refresh";
+/// Prefix of the application ids of the `txn` actions recording the version of each source
+/// the last refresh read; the source's full name follows it.
+pub const SOURCE_APP_ID_PREFIX: &str = "io.unitycatalog.materializedView.source:";
+
+/// How [`refresh_materialized_view`] may bring a materialized view up to date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RefreshMode {
+    /// Skip an unchanged view and refresh incrementally where possible; otherwise in full.
+    #[default]
+    Auto,
+    /// Always recompute the whole view.
+    Full,
+}
+
+/// What a refresh did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshKind {
+    /// The query's rows replaced the view's contents.
+    Full,
+    /// The query's rows over newly appended source files were appended.
+    Incremental,
+    /// No source changed since the last refresh; nothing was committed.
+    UpToDate,
+}
+
+impl RefreshKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefreshKind::Full => "full",
+            RefreshKind::Incremental => "incremental",
+            RefreshKind::UpToDate => "up_to_date",
+        }
+    }
+}
+
+/// The outcome of creating or refreshing a materialized view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaterializedViewRefresh {
+    pub kind: RefreshKind,
+    /// The materialized view's table version after the refresh.
+    pub version: Version,
+    /// Rows committed by the refresh.
+    pub rows_written: usize,
+    /// The version of each Delta source the refresh read, by full name.
+    pub source_versions: BTreeMap<String, Version>,
+}
+
+/// Create the materialized view `catalog.schema.name` defined by `query` and populate it.
+///
+/// The query is planned with unqualified names resolving against `catalog.schema`; its
+/// output schema becomes the view's schema. The view is registered before its rows are
+/// committed, so if that commit fails the view exists empty and without recorded source
+/// versions — the next refresh recomputes it in full.
+pub async fn create_materialized_view(
+    factory: Arc<UnityObjectStoreFactory>,
+    catalog: &str,
+    schema: &str,
+    name: &str,
+    query: &str,
+    comment: Option<String>,
+    engine_info: &str,
+) -> Result<MaterializedViewRefresh, CreateManagedTableError> {
+    let full_name = format!("{catalog}.{schema}.{name}");
+    let definition = plan_definition(&factory, catalog, schema, &full_name, query).await?;
+    let table_schema = storage_schema(definition.plan.schema().as_arrow());
+    let rows = DataFrame::new(definition.ctx.state(), definition.plan.clone())
+        .execute_stream()
+        .await?;
+
+    let staged = stage_managed_table(
+        Arc::new(factory.unity_client().delta_v1()),
+        catalog,
+        schema,
+        name,
+        table_schema.clone(),
+        Vec::new(),
+        engine_info,
+    )
+    .await?;
+    factory
+        .unity_client()
+        .create_table(
+            name,
+            schema,
+            catalog,
+            TableType::MaterializedView,
+            DataSourceFormat::Delta,
+        )
+        .with_storage_location(staged.table.location.to_string())
+        .with_view_definition(query.to_string())
+        .with_comment(comment)
+        .with_properties(staged.properties)
+        .await?;
+
+    let source_versions = definition.sources.versions();
+    let mut write = RefreshWrite::new(rows, table_schema, source_versions.clone(), None);
+    let version = commit_with_retry(
+        factory.clone(),
+        catalog,
+        schema,
+        name,
+        engine_info,
+        &mut write,
+    )
+    .await?;
+    let rows_written = write.rows_written();
+    info!(materialized_view = %full_name, version, rows_written, "created materialized view");
+
+    Ok(MaterializedViewRefresh {
+        kind: RefreshKind::Full,
+        version,
+        rows_written,
+        source_versions,
+    })
+}
+
+/// Refresh the materialized view `catalog.schema.name` from its sources. See the module docs
+/// for how [`RefreshMode::Auto`] picks between an incremental and a full refresh.
+pub async fn refresh_materialized_view(
+    factory: Arc<UnityObjectStoreFactory>,
+    catalog: &str,
+    schema: &str,
+    name: &str,
+    mode: RefreshMode,
+    engine_info: &str,
+) -> Result<MaterializedViewRefresh, CreateManagedTableError> {
+    let full_name = format!("{catalog}.{schema}.{name}");
+    let table = factory
+        .unity_client()
+        .table(catalog, schema, name)
+        .get()
+        .await?;
+    if table.table_type != TableType::MaterializedView as i32 {
+        return Err(CreateManagedTableError::other(format!(
+            "'{full_name}' is not a materialized view"
+        )));
+    }
+    let query = table.view_definition.as_deref().ok_or_else(|| {
+        CreateManagedTableError::other(format!(
+            "materialized view '{full_name}' has no view_definition"
+        ))
+    })?;
+
+    // Read the view's log for the state its last create or refresh recorded.
+    let loaded = factory
+        .unity_client()
+        .delta_v1()
+        .load_table(catalog, schema, name)
+        .await?;
+    if matches!(
+        resolve_managed_read_state(&loaded)?,
+        ManagedReadState::NotManaged
+    ) {
+        return Err(CreateManagedTableError::other(format!(
+            "materialized view '{full_name}' is not catalog-managed"
+        )));
+    }
+    let location = Url::parse(&ensure_trailing_slash(&loaded.metadata.location))
+        .map_err(|e| CreateManagedTableError::other(format!("invalid table location: {e}")))?;
+    let store = factory
+        .for_table(full_name.clone(), TableOperation::Read)
+        .await?;
+    let engine = DefaultEngineBuilder::new(store.root()).build();
+    let view = build_snapshot(&loaded, &location, &engine)?;
+    let current = view.version();
+
+    let definition = plan_definition(&factory, catalog, schema, &full_name, query).await?;
+    let table_schema = storage_schema(definition.plan.schema().as_arrow());
+    let source_versions = definition.sources.versions();
+    let recorded = RefreshState::read(&view, &engine, source_versions.keys())?;
+
+    let incremental = match choose_refresh(
+        recorded.as_ref(),
+        current,
+        mode,
+        &definition.references,
+        &source_versions,
+    ) {
+        RefreshPlan::UpToDate => {
+            debug!(
+                materialized_view = %full_name,
+                version = current,
+                "materialized view is up to date"
+            );
+            return Ok(MaterializedViewRefresh {
+                kind: RefreshKind::UpToDate,
+                version: current,
+                rows_written: 0,
+                source_versions,
+            });
+        }
+        RefreshPlan::Incremental { source, from } => {
+            definition.incremental_plan(&source, from).await?
+        }
+        RefreshPlan::Full => None,
+    };
+    let (kind, plan, append_to) = match incremental {
+        Some(plan) => (RefreshKind::Incremental, plan, Some(current)),
+        None => (RefreshKind::Full, definition.plan.clone(), None),
+    };
+    let rows = DataFrame::new(definition.ctx.state(), plan)
+        .execute_stream()
+        .await?;
+
+    let mut write = RefreshWrite::new(rows, table_schema, source_versions.clone(), append_to);
+    let version = commit_with_retry(
+        factory.clone(),
+        catalog,
+        schema,
+        name,
+        engine_info,
+        &mut write,
+    )
+    .await?;
+    let rows_written = write.rows_written();
+    info!(
+        materialized_view = %full_name,
+        refresh = kind.as_str(),
+        version,
+        rows_written,
+        "refreshed materialized view"
+    );
+
+    Ok(MaterializedViewRefresh {
+        kind,
+        version,
+        rows_written,
+        source_versions,
+    })
+}
+
+/// The state a create or refresh records in the view's log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RefreshState {
+    /// The view's version after that refresh.
+    version: Version,
+    /// The version of each source it read.
+    sources: BTreeMap<String, Version>,
+}
+
+impl RefreshState {
+    /// The state the last refresh recorded in `view` for `sources`, or `None` if the view was
+    /// never refreshed or that refresh did not read one of `sources`.
+    fn read<'a>(
+        view: &SnapshotRef,
+        engine: &dyn Engine,
+        sources: impl IntoIterator<Item = &'a String>,
+    ) -> Result<Option<Self>, CreateManagedTableError> {
+        let recorded = |app_id: &str| -> Result<Option<Version>, CreateManagedTableError> {
+            Ok(view
+                .clone()
+                .get_app_id_version(app_id, engine)?
+                .and_then(|version| Version::try_from(version).ok()))
+        };
+        let Some(version) = recorded(REFRESH_APP_ID)? else {
+            return Ok(None);
+        };
+        let mut versions = BTreeMap::new();
+        for source in sources {
+            let Some(read) = recorded(&source_app_id(source))? else {
+                return Ok(None);
+            };
+            versions.insert(source.clone(), read);
+        }
+        Ok(Some(Self {
+            version,
+            sources: versions,
+        }))
+    }
+
+    /// The `txn` actions recording this state, as `(app id, version)`.
+    fn transactions(&self) -> Vec<(String, i64)> {
+        std::iter::once((REFRESH_APP_ID.to_string(), self.version))
+            .chain(
+                self.sources
+                    .iter()
+                    .map(|(name, version)| (source_app_id(name), *version)),
+            )
+            .map(|(app_id, version)| (app_id, version as i64))
+            .collect()
+    }
+
+    /// The catalog table properties surfacing this state: the `txn` app ids as keys and the
+    /// versions as values.
+    fn properties(&self) -> BTreeMap<String, String> {
+        self.transactions()
+            .into_iter()
+            .map(|(app_id, version)| (app_id, version.to_string()))
+            .collect()
+    }
+}
+
+/// The application id recording the version of `source` a refresh read.
+fn source_app_id(source: &str) -> String {
+    format!("{SOURCE_APP_ID_PREFIX}{source}")
+}
+
+/// How a refresh brings the view up to date; see the module docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RefreshPlan {
+    UpToDate,
+    /// Append the query's rows over the files added to `source` since version `from`, if
+    /// the query and those changes allow it.
+    Incremental {
+        source: String,
+        from: Version,
+    },
+    Full,
+}
+
+/// Choose how to refresh a view at version `current` whose query names `references` and now
+/// reads the Delta sources at `sources`, given the state the last refresh `recorded`.
+fn choose_refresh(
+    recorded: Option<&RefreshState>,
+    current: Version,
+    mode: RefreshMode,
+    references: &[String],
+    sources: &BTreeMap<String, Version>,
+) -> RefreshPlan {
+    // Compare against the recorded state only if nothing but a refresh moved the view, and
+    // only when every relation the query names is a Delta table whose version we track.
+    let Some(recorded) = recorded.filter(|recorded| {
+        mode == RefreshMode::Auto
+            && recorded.version == current
+            && references.iter().all(|r| sources.contains_key(r))
+    }) else {
+        return RefreshPlan::Full;
+    };
+    match changed_sources(&recorded.sources, sources).as_deref() {
+        Some([]) => RefreshPlan::UpToDate,
+        Some([source]) => match recorded.sources.get(source) {
+            Some(&from) => RefreshPlan::Incremental {
+                source: source.clone(),
+                from,
+            },
+            None => RefreshPlan::Full,
+        },
+        _ => RefreshPlan::Full,
+    }
+}
+
+/// The sources whose version differs between `recorded` and `current`, or `None` if the set
+/// of sources itself changed.
+fn changed_sources(
+    recorded: &BTreeMap<String, Version>,
+    current: &BTreeMap<String, Version>,
+) -> Option<Vec<String>> {
+    if !recorded.keys().eq(current.keys()) {
+        return None;
+    }
+    Some(
+        current
+            .iter()
+            .filter(|(name, version)| recorded.get(*name) != Some(version))
+            .map(|(name, _)| name.clone())
+            .collect(),
+    )
+}
+
+/// The commit of a create or refresh. The first attempt streams the query's rows into data
+/// files; a retry commits those files again, so the query runs once.
+struct RefreshWrite {
+    /// The query's rows, taken by the first attempt.
+    rows: Option<SendableRecordBatchStream>,
+    table_schema: SchemaRef,
+    /// The version of each source the rows were computed from.
+    sources: BTreeMap<String, Version>,
+    /// For an incremental refresh, the view version the rows were computed against: they are
+    /// appended only to that version. `None` overwrites the view's contents.
+    append_to: Option<Version>,
+    /// The view schema the first attempt wrote its files for.
+    written_for: Option<KernelSchemaRef>,
+    /// The add-file metadata and row count of those files.
+    written: Option<(Vec<RecordBatch>, usize)>,
+}
+
+impl RefreshWrite {
+    fn new(
+        rows: SendableRecordBatchStream,
+        table_schema: SchemaRef,
+        sources: BTreeMap<String, Version>,
+        append_to: Option<Version>,
+    ) -> Self {
+        Self {
+            rows: Some(rows),
+            table_schema,
+            sources,
+            append_to,
+            written_for: None,
+            written: None,
+        }
+    }
+
+    /// Rows written into the view's data files.
+    fn rows_written(&self) -> usize {
+        self.written.as_ref().map_or(0, |(_, rows)| *rows)
+    }
+}
+
+impl ManagedWrite for RefreshWrite {
+    async fn plan(
+        &mut self,
+        snapshot: &SnapshotRef,
+    ) -> Result<Option<PlannedWrite>, CreateManagedTableError> {
+        if let Some(expected) = self.append_to
+            && snapshot.version() != expected
+        {
+            return Err(CreateManagedTableError::other(format!(
+                "materialized view was modified concurrently (now at version {}, refresh \
+                 planned against {expected}); refresh it again",
+                snapshot.version()
+            )));
+        }
+        let data = match (self.rows.take(), &self.written) {
+            (Some(rows), _) => {
+                self.written_for = Some(snapshot.schema());
+                NewData::Inserted(conform_stream(rows, &self.table_schema))
+            }
+            (None, Some((files, rows))) => {
+                if self.written_for.as_ref() != Some(&snapshot.schema()) {
+                    return Err(CreateManagedTableError::other(
+                        "the materialized view's schema changed while it was refreshing; \
+                         refresh it again",
+                    ));
+                }
+                NewData::Written {
+                    files: files.clone(),
+                    rows: *rows,
+                }
+            }
+            (None, None) => {
+                return Err(CreateManagedTableError::other(
+                    "materialized view rows were consumed by an attempt that wrote no files",
+                ));
+            }
+        };
+        // The commit creates the version after `snapshot`'s; record it with the sources.
+        let state = RefreshState {
+            version: snapshot.version() + 1,
+            sources: self.sources.clone(),
+        };
+        Ok(Some(PlannedWrite {
+            operation: "WRITE",
+            remove: if self.append_to.is_none() {
+                RemoveFiles::All
+            } else {
+                RemoveFiles::None
+            },
+            data,
+            transactions: state.transactions(),
+            properties: state.properties(),
+            report: DeltaCommitReport::default(),
+        }))
+    }
+
+    fn files_written(&mut self, files: &[RecordBatch], rows: usize) {
+        self.written = Some((files.to_vec(), rows));
+    }
+}
+
+/// A materialized view's defining query, planned over the current version of its sources.
+struct Definition {
+    ctx: SessionContext,
+    plan: LogicalPlan,
+    /// The full names of the relations the query references directly.
+    references: Vec<String>,
+    /// The Delta snapshots the plan reads.
+    sources: Arc<SourceSnapshots>,
+    catalog: String,
+    schema: String,
+}
+
+/// Plan `query`, the definition of `owner`, in a scratch session scoped to `catalog.schema`
+/// whose relations resolve through Unity Catalog like a top-level query's.
+async fn plan_definition(
+    factory: &Arc<UnityObjectStoreFactory>,
+    catalog: &str,
+    schema: &str,
+    owner: &str,
+    query: &str,
+) -> Result<Definition, CreateManagedTableError> {
+    let mut statements = DFParser::parse_sql(query)?;
+    let (Some(mut statement), None) = (statements.pop_front(), statements.pop_front()) else {
+        return Err(CreateManagedTableError::other(format!(
+            "materialized view '{owner}' must be defined by a single query"
+        )));
+    };
+    if !matches!(&statement, DFStatement::Statement(s) if matches!(**s, SqlStatement::Query(_))) {
+        return Err(CreateManagedTableError::other(format!(
+            "materialized view '{owner}' must be defined by a SELECT query"
+        )));
+    }
+
+    let config = SessionConfig::new().with_default_catalog_and_schema(catalog, schema);
+    let ctx = SessionContext::new_with_config(config);
+    let sources = Arc::new(SourceSnapshots::new(
+        ctx.clone(),
+        factory.unity_client().delta_v1(),
+    ));
+    let providers =
+        UnityCatalogProviderList::new(factory.clone(), ctx.runtime_env(), sources.clone())
+            .with_session(&ctx);
+    providers.resolve_functions(&ctx, &mut statement).await?;
+    let references = ctx.state().resolve_table_references(&statement)?;
+    let resolved = providers.resolve(&references, ctx.state().config()).await?;
+    ctx.register_catalog_list(resolved);
+    let plan = ctx.state().statement_to_plan(statement).await?;
+
+    Ok(Definition {
+        ctx,
+        plan,
+        references: references
+            .into_iter()
+            .map(|r| r.resolve(catalog, schema).to_string())
+            .collect(),
+        sources,
+        catalog: catalog.to_string(),
+        schema: schema.to_string(),
+    })
+}
+
+impl Definition {
+    /// The query over only the files appended to `source` since version `from`, or `None` if
+    /// the query or the source's changes do not allow an incremental refresh.
+    async fn incremental_plan(
+        &self,
+        source: &str,
+        from: Version,
+    ) -> Result<Option<LogicalPlan>, CreateManagedTableError> {
+        let Some(scan) = linear_scan(&self.plan) else {
+            return Ok(None);
+        };
+        if scan
+            .table_name
+            .clone()
+            .resolve(&self.catalog, &self.schema)
+            .to_string()
+            != source
+        {
+            return Ok(None);
+        }
+        let Some(read) = self.sources.get(source) else {
+            return Ok(None);
+        };
+
+        let engine = DataFusionEngine::new_from_context(self.ctx.task_ctx());
+        let previous = read.snapshot_at(engine.as_ref(), from)?;
+        let Some(appended) = appended_files(&previous, &read.snapshot, engine.as_ref())? else {
+            debug!(
+                source,
+                from, "source changed by more than appends; refresh in full"
+            );
+            return Ok(None);
+        };
+        let urls = appended
+            .iter()
+            .map(|path| {
+                read.location
+                    .join(path)
+                    .map(|url| url.to_string())
+                    .map_err(|e| CreateManagedTableError::other(format!("invalid file path: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Read the appended files as the source's schema, empty when nothing was appended.
+        let schema = scan.source.schema();
+        let provider: Arc<dyn TableProvider> = if urls.is_empty() {
+            Arc::new(EmptyTable::new(schema.clone()))
+        } else {
+            self.ctx
+                .read_parquet(urls, ParquetReadOptions::default().schema(schema.as_ref()))
+                .await?
+                .into_view()
+        };
+        let source = provider_as_source(provider);
+        let plan = self
+            .plan
+            .clone()
+            .transform_up(|node| {
+                Ok(match node {
+                    LogicalPlan::TableScan(scan) => {
+                        Transformed::yes(LogicalPlan::TableScan(TableScan {
+                            source: source.clone(),
+                            ..scan
+                        }))
+                    }
+                    node => Transformed::no(node),
+                })
+            })?
+            .data;
+        Ok(Some(plan))
+    }
+}
+
+/// The scan a linear query reads — deterministic projections and filters (under any aliases)
+/// over a single table — or `None` for anything else: joins, aggregates, windows, sorts,
+/// limits, unions or subqueries. Only such a query distributes over appended rows.
+fn linear_scan(plan: &LogicalPlan) -> Option<&TableScan> {
+    fn deterministic(expr: &Expr) -> bool {
+        !expr.is_volatile()
+            && !expr
+                .exists(|e| {
+                    Ok(matches!(
+                        e,
+                        Expr::ScalarSubquery(_) | Expr::Exists(_) | Expr::InSubquery(_)
+                    ))
+                })
+                .unwrap_or(true)
+    }
+    match plan {
+        LogicalPlan::Projection(projection) if projection.expr.iter().all(deterministic) => {
+            linear_scan(&projection.input)
+        }
+        LogicalPlan::Filter(filter) if deterministic(&filter.predicate) => {
+            linear_scan(&filter.input)
+        }
+        LogicalPlan::SubqueryAlias(alias) => linear_scan(&alias.input),
+        LogicalPlan::TableScan(scan)
+            if scan.fetch.is_none() && scan.filters.iter().all(deterministic) =>
+        {
+            Some(scan)
+        }
+        _ => None,
+    }
+}
+
+/// The data files added between `previous` and `current` if the versions in between only
+/// appended: the schema is unchanged, the table is unpartitioned without column mapping (so
+/// its files read back as plain Parquet), every earlier file is still live with the same
+/// deletion vector, and no added file carries one. `None` otherwise.
+fn appended_files(
+    previous: &SnapshotRef,
+    current: &SnapshotRef,
+    engine: &dyn Engine,
+) -> Result<Option<Vec<String>>, CreateManagedTableError> {
+    let config = current.table_configuration();
+    if previous.schema() != current.schema()
+        || !config.metadata().partition_columns().is_empty()
+        || config.column_mapping_mode() != ColumnMappingMode::None
+    {
+        return Ok(None);
+    }
+    let mut added = live_files(current, engine)?;
+    for (path, file) in live_files(previous, engine)? {
+        if added.remove(&path) != Some(file) {
+            return Ok(None);
+        }
+    }
+    if added.values().any(|file| file.deletion_vector.is_some()) {
+        return Ok(None);
+    }
+    Ok(Some(added.into_keys().collect()))
+}
+
+/// The schema the view's table stores the query's output as: view and large string and
+/// binary types become their plain forms and timestamps microseconds, Delta's types.
+fn storage_schema(schema: &Schema) -> SchemaRef {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let data_type = match field.data_type() {
+                DataType::Utf8View | DataType::LargeUtf8 => DataType::Utf8,
+                DataType::BinaryView | DataType::LargeBinary => DataType::Binary,
+                DataType::Timestamp(_, tz) => {
+                    DataType::Timestamp(TimeUnit::Microsecond, tz.clone())
+                }
+                other => other.clone(),
+            };
+            field.as_ref().clone().with_data_type(data_type)
+        })
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// One Delta source as the definition read it.
+struct SourceRead {
+    location: Url,
+    /// The catalog's commit tail and latest version for a catalog-managed source.
+    managed: Option<(Vec<DeltaCommit>, Version)>,
+    snapshot: SnapshotRef,
+}
+
+impl SourceRead {
+    /// The source's snapshot at `version`.
+    fn snapshot_at(
+        &self,
+        engine: &dyn Engine,
+        version: Version,
+    ) -> Result<SnapshotRef, CreateManagedTableError> {
+        source_snapshot(engine, &self.location, self.managed.as_ref(), Some(version))
+    }
+}
+
+/// The snapshot of the Delta table at `location` at `version`, or at its latest version.
+/// A catalog-managed table is read through the catalog's commit tail and latest version.
+fn source_snapshot(
+    engine: &dyn Engine,
+    location: &Url,
+    managed: Option<&(Vec<DeltaCommit>, Version)>,
+    version: Option<Version>,
+) -> Result<SnapshotRef, CreateManagedTableError> {
+    let mut builder = KernelSnapshot::builder_for(location.as_str());
+    if let Some((commits, latest)) = managed {
+        builder = builder
+            .with_log_tail(to_log_tail(location, commits)?)
+            .with_max_catalog_version(*latest);
+    }
+    if let Some(version) = version {
+        builder = builder.at_version(version);
+    }
+    Ok(builder.build(engine)?)
+}
+
+/// A [`TableProviderBuilder`] for the sources of a materialized view: reads each Delta
+/// table at its latest version — through the catalog's commit tail when it is
+/// catalog-managed — and keeps the snapshot it read.
+struct SourceSnapshots {
+    ctx: SessionContext,
+    client: DeltaV1Client,
+    read: Mutex<BTreeMap<String, Arc<SourceRead>>>,
+}
+
+impl SourceSnapshots {
+    fn new(ctx: SessionContext, client: DeltaV1Client) -> Self {
+        Self {
+            ctx,
+            client,
+            read: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn get(&self, full_name: &str) -> Option<Arc<SourceRead>> {
+        self.read.lock().unwrap().get(full_name).cloned()
+    }
+
+    /// The version read of every Delta source, by full name.
+    fn versions(&self) -> BTreeMap<String, Version> {
+        self.read
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, read)| (name.clone(), read.snapshot.version()))
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for SourceSnapshots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: BTreeSet<String> = self.read.lock().unwrap().keys().cloned().collect();
+        f.debug_struct("SourceSnapshots")
+            .field("read", &names)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl TableProviderBuilder for SourceSnapshots {
+    async fn build_delta(
+        &self,
+        location: &Url,
+        table: &Table,
+    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+        let loaded = self
+            .client
+            .load_table(&table.catalog_name, &table.schema_name, &table.name)
+            .await
+            .map_err(|e| TableProviderError::External(Box::new(e)))?;
+        let managed = match resolve_managed_read_state(&loaded)? {
+            ManagedReadState::Managed { commits, latest } => Some((commits, latest)),
+            ManagedReadState::NotManaged => None,
+        };
+        let location = Url::parse(&ensure_trailing_slash(location.as_str()))
+            .map_err(|e| TableProviderError::External(Box::new(e)))?;
+        let engine = DataFusionEngine::new_from_context(self.ctx.task_ctx());
+        let snapshot = source_snapshot(engine.as_ref(), &location, managed.as_ref(), None)
+            .map_err(|e| TableProviderError::External(Box::new(e)))?;
+        let read = SourceRead {
+            location,
+            managed,
+            snapshot,
+        };
+
+        let log_store = log_store_for(&self.ctx.runtime_env(), &read.location)?;
+        let provider = provider_from_snapshot(
+            Snapshot::new(read.snapshot.clone(), DeltaTableConfig::default()),
+            log_store,
+        )
+        .await?;
+        self.read
+            .lock()
+            .unwrap()
+            .insert(table.full_name.clone(), Arc::new(read));
+        Ok(provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::datasource::MemTable;
+
+    use super::*;
+
+    fn versions(entries: &[(&str, Version)]) -> BTreeMap<String, Version> {
+        entries.iter().map(|(n, v)| (n.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn refresh_state_is_recorded_as_app_transactions() {
+        let state = RefreshState {
+            version: 7,
+            sources: versions(&[("c.s.orders", 12), ("c.s.customers", 3)]),
+        };
+        assert_eq!(
+            state.transactions(),
+            vec![
+                (REFRESH_APP_ID.to_string(), 7),
+                (format!("{SOURCE_APP_ID_PREFIX}c.s.customers"), 3),
+                (format!("{SOURCE_APP_ID_PREFIX}c.s.orders"), 12),
+            ]
+        );
+        assert_eq!(
+            state.properties(),
+            BTreeMap::from([
+                (REFRESH_APP_ID.to_string(), "7".to_string()),
+                (
+                    format!("{SOURCE_APP_ID_PREFIX}c.s.customers"),
+                    "3".to_string()
+                ),
+                (
+                    format!("{SOURCE_APP_ID_PREFIX}c.s.orders"),
+                    "12".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn chooses_up_to_date_incremental_or_full() {
+        let references = vec!["c.s.orders".to_string(), "c.s.customers".to_string()];
+        let recorded = RefreshState {
+            version: 4,
+            sources: versions(&[("c.s.orders", 12), ("c.s.customers", 3)]),
+        };
+        let choose = |recorded: Option<&RefreshState>,
+                      current: Version,
+                      mode: RefreshMode,
+                      sources: &BTreeMap<String, Version>| {
+            choose_refresh(recorded, current, mode, &references, sources)
+        };
+
+        // Nothing moved since the last refresh.
+        let unchanged = versions(&[("c.s.orders", 12), ("c.s.customers", 3)]);
+        assert_eq!(
+            choose(Some(&recorded), 4, RefreshMode::Auto, &unchanged),
+            RefreshPlan::UpToDate
+        );
+        // One source moved: try the files appended to it since the recorded version.
+        let one = versions(&[("c.s.orders", 15), ("c.s.customers", 3)]);
+        assert_eq!(
+            choose(Some(&recorded), 4, RefreshMode::Auto, &one),
+            RefreshPlan::Incremental {
+                source: "c.s.orders".to_string(),
+                from: 12,
+            }
+        );
+        // Forced, or never refreshed.
+        assert_eq!(
+            choose(Some(&recorded), 4, RefreshMode::Full, &unchanged),
+            RefreshPlan::Full
+        );
+        assert_eq!(
+            choose(None, 4, RefreshMode::Auto, &unchanged),
+            RefreshPlan::Full
+        );
+    }
+
+    #[test]
+    fn falls_back_to_full_when_the_recorded_state_does_not_apply() {
+        let references = vec!["c.s.orders".to_string(), "c.s.customers".to_string()];
+        let recorded = RefreshState {
+            version: 4,
+            sources: versions(&[("c.s.orders", 12), ("c.s.customers", 3)]),
+        };
+        let unchanged = versions(&[("c.s.orders", 12), ("c.s.customers", 3)]);
+        // Something other than a refresh wrote to the view.
+        assert_eq!(
+            choose_refresh(
+                Some(&recorded),
+                5,
+                RefreshMode::Auto,
+                &references,
+                &unchanged
+            ),
+            RefreshPlan::Full
+        );
+        // More than one source moved.
+        let both = versions(&[("c.s.orders", 13), ("c.s.customers", 4)]);
+        assert_eq!(
+            choose_refresh(Some(&recorded), 4, RefreshMode::Auto, &references, &both),
+            RefreshPlan::Full
+        );
+        // The query names a relation whose version is not tracked, e.g. a view.
+        let with_view = vec!["c.s.orders".to_string(), "c.s.recent".to_string()];
+        assert_eq!(
+            choose_refresh(
+                Some(&recorded),
+                4,
+                RefreshMode::Auto,
+                &with_view,
+                &unchanged
+            ),
+            RefreshPlan::Full
+        );
+    }
+
+    #[test]
+    fn changed_sources_compares_versions_of_the_same_sources() {
+        let recorded = versions(&[("c.s.a", 1), ("c.s.b", 4)]);
+        assert_eq!(changed_sources(&recorded, &recorded), Some(vec![]));
+        assert_eq!(
+            changed_sources(&recorded, &versions(&[("c.s.a", 2), ("c.s.b", 4)])),
+            Some(vec!["c.s.a".to_string()])
+        );
+        assert_eq!(changed_sources(&recorded, &versions(&[("c.s.a", 1)])), None);
+    }
+
+    async fn plan(sql: &str) -> LogicalPlan {
+        let ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("amount", DataType::Float64, true),
+        ]));
+        for name in ["orders", "customers"] {
+            let table = MemTable::try_new(schema.clone(), vec![vec![]]).unwrap();
+            ctx.register_table(name, Arc::new(table)).unwrap();
+        }
+        ctx.state().create_logical_plan(sql).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn only_linear_queries_refresh_incrementally() {
+        for sql in [
+            "SELECT id, amount * 2 AS doubled FROM orders WHERE amount > 10",
+            "SELECT o.id FROM orders AS o",
+        ] {
+            let plan = plan(sql).await;
+            let scan = linear_scan(&plan).unwrap_or_else(|| panic!("{sql}"));
+            assert_eq!(scan.table_name.table(), "orders");
+        }
+        for sql in [
+            "SELECT id, SUM(amount) FROM orders GROUP BY id",
+            "SELECT o.id FROM orders o JOIN customers c ON o.id = c.id",
+            "SELECT id FROM orders ORDER BY id",
+            "SELECT id FROM orders LIMIT 5",
+            "SELECT id, random() FROM orders",
+            "SELECT id FROM orders WHERE id IN (SELECT id FROM customers)",
+            "SELECT id FROM orders UNION ALL SELECT id FROM customers",
+        ] {
+            assert!(linear_scan(&plan(sql).await).is_none(), "{sql}");
+        }
+    }
+
+    #[test]
+    fn storage_schema_uses_delta_types() {
+        let schema = Schema::new(vec![
+            Field::new("name", DataType::Utf8View, true),
+            Field::new(
+                "at",
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                false,
+            ),
+            Field::new("n", DataType::Int64, false),
+        ]);
+        let stored = storage_schema(&schema);
+        assert_eq!(stored.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(
+            stored.field(1).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        assert!(!stored.field(1).is_nullable());
+        assert_eq!(stored.field(2).data_type(), &DataType::Int64);
+    }
+}
@@ -3,10 +3,18 @@
 //! unitycatalog-rs [`DeltaV1Client`](unitycatalog_client::DeltaV1Client).
 //!
 //! - [`UnityCatalogCommitter`] — the catalog-managed committer (v0 publish, v≥1 stage + ratify).
+//! - [`MultiTableCommitter`] — stages commits on several tables and ratifies them in one
+//!   atomic batch, so related tables never diverge.
 //! - [`create_managed_table`] — staging → `kernel::create_table` (writes `0.json`) → `createTable`.
 //! - [`append_to_managed_table`] — load snapshot → kernel write transaction → commit (v≥1)
 //!   with bounded conflict/throttle/ambiguity retry, then best-effort publish + backfill +
 //!   metrics (ManagedTablesSpec §"Write to the table").
+//! - [`ManagedDeltaTable`] — the provider the resolver returns for managed tables: `INSERT INTO`,
+//!   `INSERT OVERWRITE`, `DELETE`, `UPDATE` and [`MergeInto`] run as DataFusion plans and commit
+//!   through the same retry loop.
+//! - [`create_materialized_view`] / [`refresh_materialized_view`] — materialized views stored as
+//!   managed tables, refreshed in full or incrementally from append-only sources, with the
+//!   source versions each refresh read recorded in the view's properties.
 //!
 //! Design + rationale: see open-lakehouse `docs/adr/0010-catalog-managed-table-writes.md`.
 //!
@@ -29,6 +37,9 @@
 mod append;
 mod committer;
 mod create;
+mod dml;
+mod materialized_view;
+mod multi_table;
 
 pub use append::append_to_managed_table;
 pub use committer::UnityCatalogCommitter;
@@ -36,3 +47,9 @@ pub use create::{
     CreateManagedTableError, ManagedTable, create_managed_table,
     get_final_required_properties_for_uc, get_required_properties_for_disk,
 };
+pub use dml::{ManagedDeltaTable, MergeClause, MergeInto};
+pub use materialized_view::{
+    MaterializedViewRefresh, REFRESH_APP_ID, RefreshKind, RefreshMode, SOURCE_APP_ID_PREFIX,
+    create_materialized_view, refresh_materialized_view,
+};
+pub use multi_table::{BatchTableCommitter, MultiTableCommitter};
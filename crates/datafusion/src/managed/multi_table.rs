@@ -0,0 +1,276 @@
+//! Multi-table commits for catalog-managed Delta tables.
+//!
+//! A pipeline that writes a fact table and its dimension tables wants readers to
+//! see either all of its writes or none. [`MultiTableCommitter`] hands out one
+//! [`BatchTableCommitter`] per table; each kernel transaction committed through
+//! one of those only *stages* its commit file. [`MultiTableCommitter::commit`]
+//! then ratifies every staged commit in a single `POST /delta/v1/commits` call,
+//! which the server applies atomically.
+//!
+//! Until that call succeeds, a transaction the kernel reports as committed is
+//! provisional: its staged file exists, but no reader sees it, and
+//! [`BatchTableCommitter::publish`] refuses to publish it. If the batch fails,
+//! the staged files are simply never ratified, and the writer should reload
+//! each table and retry the whole pipeline step.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use delta_kernel::committer::{CommitMetadata, CommitResponse, Committer, PublishMetadata};
+use delta_kernel::{
+    DeltaResult, DeltaResultIterator, Engine, Error as DeltaError, FilteredEngineData,
+};
+use unitycatalog_client::DeltaV1Client;
+use unitycatalog_common::models::delta::v1::{
+    DeltaBatchCommitRequest, DeltaBatchCommitResponse, DeltaTableCommit,
+};
+
+use super::committer::UnityCatalogCommitter;
+
+/// Collects staged commits across several catalog-managed tables and ratifies
+/// them together.
+#[derive(Clone)]
+pub struct MultiTableCommitter {
+    client: Arc<DeltaV1Client>,
+    batch: Arc<Mutex<Batch>>,
+}
+
+/// State shared by a [`MultiTableCommitter`] and its table committers.
+#[derive(Debug, Default)]
+struct Batch {
+    /// Commits staged since the last [`MultiTableCommitter::commit`], in staging order.
+    pending: Vec<DeltaTableCommit>,
+    /// `(table id, version)` of every commit staged through this batch that has
+    /// not been ratified. A failed batch leaves its commits here for good.
+    unratified: HashSet<(String, i64)>,
+}
+
+impl std::fmt::Debug for MultiTableCommitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiTableCommitter")
+            .field("pending", &self.pending().len())
+            .finish()
+    }
+}
+
+impl MultiTableCommitter {
+    pub fn new(client: Arc<DeltaV1Client>) -> Self {
+        Self {
+            client,
+            batch: Arc::default(),
+        }
+    }
+
+    /// A [`Committer`] for `catalog.schema.table` (UC id `table_id`) whose
+    /// commits join this batch.
+    pub fn table(
+        &self,
+        catalog: impl Into<String>,
+        schema: impl Into<String>,
+        table: impl Into<String>,
+        table_id: impl Into<String>,
+    ) -> BatchTableCommitter {
+        let table_id = table_id.into();
+        BatchTableCommitter {
+            inner: UnityCatalogCommitter::new(
+                self.client.clone(),
+                catalog,
+                schema,
+                table,
+                table_id.clone(),
+            ),
+            table_id,
+            batch: self.batch.clone(),
+        }
+    }
+
+    /// The commits staged so far, in staging order.
+    pub fn pending(&self) -> Vec<DeltaTableCommit> {
+        self.batch
+            .lock()
+            .expect("committer mutex poisoned")
+            .pending
+            .clone()
+    }
+
+    /// Ratify every staged commit atomically. The staged list is cleared either
+    /// way; on error no commit was ratified, and the table committers keep
+    /// refusing to publish the staged versions.
+    pub async fn commit(&self) -> unitycatalog_client::Result<DeltaBatchCommitResponse> {
+        let commits =
+            std::mem::take(&mut self.batch.lock().expect("committer mutex poisoned").pending);
+        if commits.is_empty() {
+            return Err(unitycatalog_client::Error::Generic(
+                "no commits were staged for the multi-table commit".to_string(),
+            ));
+        }
+        let request = DeltaBatchCommitRequest { commits };
+        let response = self.client.batch_commit(&request).await?;
+        let mut batch = self.batch.lock().expect("committer mutex poisoned");
+        for commit in &request.commits {
+            batch
+                .unratified
+                .remove(&(commit.table_uuid.clone(), commit.commit.version));
+        }
+        Ok(response)
+    }
+}
+
+/// The [`Committer`] for one table of a [`MultiTableCommitter`] batch.
+///
+/// Validates and stages commits exactly like [`UnityCatalogCommitter`], but
+/// defers ratification to [`MultiTableCommitter::commit`]. Publishing a version
+/// staged through the batch fails until that call has succeeded. Table creation
+/// (version 0) is not batched: create tables before adding them to a batch.
+#[derive(Debug, Clone)]
+pub struct BatchTableCommitter {
+    inner: UnityCatalogCommitter,
+    table_id: String,
+    batch: Arc<Mutex<Batch>>,
+}
+
+impl BatchTableCommitter {
+    fn stage(&self, entry: DeltaTableCommit) {
+        let mut batch = self.batch.lock().expect("committer mutex poisoned");
+        batch
+            .unratified
+            .insert((entry.table_uuid.clone(), entry.commit.version));
+        batch.pending.push(entry);
+    }
+
+    /// Fail if any of `versions` was staged through the batch but not ratified.
+    fn ensure_ratified(&self, versions: impl IntoIterator<Item = i64>) -> DeltaResult<()> {
+        let batch = self.batch.lock().expect("committer mutex poisoned");
+        for version in versions {
+            if batch.unratified.contains(&(self.table_id.clone(), version)) {
+                return Err(DeltaError::generic(format!(
+                    "version {version} of table {} has not been ratified; \
+                     call MultiTableCommitter::commit before publishing",
+                    self.table_id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Committer for BatchTableCommitter {
+    fn commit(
+        &self,
+        engine: &dyn Engine,
+        actions: DeltaResultIterator<'_, FilteredEngineData>,
+        commit_metadata: CommitMetadata,
+    ) -> DeltaResult<CommitResponse> {
+        if commit_metadata.version() == 0 {
+            return Err(DeltaError::generic(
+                "table creation cannot join a multi-table commit; create the table first",
+            ));
+        }
+        let (commit, file_meta) = self.inner.stage_commit(engine, actions, &commit_metadata)?;
+        self.stage(self.inner.table_commit(commit));
+        Ok(CommitResponse::Committed { file_meta })
+    }
+
+    fn is_catalog_committer(&self) -> bool {
+        true
+    }
+
+    fn publish(&self, engine: &dyn Engine, publish_metadata: PublishMetadata) -> DeltaResult<()> {
+        self.ensure_ratified(
+            publish_metadata
+                .commits_to_publish()
+                .iter()
+                .filter_map(|commit| i64::try_from(commit.version()).ok()),
+        )?;
+        self.inner.publish(engine, publish_metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockito::Server;
+    use olai_http::CloudClient;
+    use unitycatalog_client::DeltaV1Client;
+    use unitycatalog_common::models::delta::v1::DeltaCommit;
+    use url::Url;
+
+    use super::MultiTableCommitter;
+
+    fn commit(version: i64) -> DeltaCommit {
+        DeltaCommit {
+            version,
+            timestamp: 1,
+            file_name: format!("{version:020}.uuid.json"),
+            file_size: 1,
+            file_modification_timestamp: 1,
+        }
+    }
+
+    /// A committer whose requests go to `base`.
+    fn committer_at(base: &str) -> MultiTableCommitter {
+        let client = DeltaV1Client::new(
+            CloudClient::new_unauthenticated(),
+            Url::parse(base).unwrap(),
+        );
+        MultiTableCommitter::new(Arc::new(client))
+    }
+
+    /// A committer for tests that never send a request.
+    fn test_committer() -> MultiTableCommitter {
+        committer_at("http://localhost/")
+    }
+
+    #[test]
+    fn table_committers_share_the_batch() {
+        let batch = test_committer();
+        let fact = batch.table("c", "s", "fact", "fact-id");
+        let dim = batch.table("c", "s", "dim", "dim-id");
+        for (committer, version) in [(&fact, 3), (&dim, 1)] {
+            committer.stage(committer.inner.table_commit(commit(version)));
+        }
+        let pending = batch.pending();
+        assert_eq!(
+            pending
+                .iter()
+                .map(|c| (c.table.as_str(), c.table_uuid.as_str(), c.commit.version))
+                .collect::<Vec<_>>(),
+            vec![("fact", "fact-id", 3), ("dim", "dim-id", 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn staged_versions_are_not_published_until_ratified() {
+        let mut server = Server::new_async().await;
+        let rejected = server
+            .mock("POST", "/delta/v1/commits")
+            .with_status(409)
+            .with_body(
+                r#"{"error":{"message":"conflict","type":"CommitVersionConflictException","code":409}}"#,
+            )
+            .create_async()
+            .await;
+        let batch = committer_at(&server.url());
+        let fact = batch.table("c", "s", "fact", "fact-id");
+        let dim = batch.table("c", "s", "dim", "dim-id");
+        fact.stage(fact.inner.table_commit(commit(3)));
+
+        assert!(fact.ensure_ratified([3]).is_err());
+        // Versions ratified outside the batch, and other tables, are unaffected.
+        assert!(fact.ensure_ratified([2]).is_ok());
+        assert!(dim.ensure_ratified([3]).is_ok());
+
+        // The catalog rejects the batch, so it stays unratified.
+        assert!(batch.commit().await.is_err());
+        rejected.assert_async().await;
+        assert!(batch.pending().is_empty());
+        assert!(fact.ensure_ratified([3]).is_err());
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_rejected_without_a_request() {
+        let err = test_committer().commit().await.unwrap_err();
+        assert!(matches!(err, unitycatalog_client::Error::Generic(_)));
+    }
+}
@@ -21,19 +21,34 @@
 //! dimensions, its `measure(col)` calls name the measures. For Form B, with no
 //! enclosing aggregate, the full set of dimensions and measures is materialized.
 //!
+//! For a view with joins, the aggregate reads only the joins the selected
+//! dimensions and measures (and the view filter) reference. A `one_to_many`
+//! join repeats source rows, so a query that reads one is rejected if it also
+//! selects a measure that does not read that join — the measure would be
+//! counted once per joined row.
+//!
+//! Window measures ([`super::window`]) and composed measures cannot be computed
+//! by one aggregate over the source: each window measure is planned on its own
+//! and joined onto the aggregate of the plain measures by the grouped
+//! dimensions, and composed measures are projected over the joined results.
+//!
 //! Mirrors Spark's `ResolveMetricView` (SPIP SPARK-54119).
 
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
 use datafusion::common::config::ConfigOptions;
 use datafusion::common::tree_node::{Transformed, TreeNode};
-use datafusion::common::{Column, Result, plan_err};
+use datafusion::common::{Column, NullEquality, Result, plan_err};
 use datafusion::logical_expr::expr::AggregateFunction;
-use datafusion::logical_expr::{Aggregate, Expr, LogicalPlan, LogicalPlanBuilder, SubqueryAlias};
+use datafusion::logical_expr::{
+    Aggregate, Expr, Filter, Join, JoinType, LogicalPlan, LogicalPlanBuilder, SubqueryAlias,
+};
 use datafusion::optimizer::AnalyzerRule;
 
 use super::measure::MEASURE_UDF_NAME;
 use super::placeholder::{MetricViewPlaceholder, NamedExpr};
+use super::window::plan_window_measure;
 
 /// Analyzer rule that resolves metric-view placeholders and `MEASURE()` markers.
 #[derive(Debug, Default)]
@@ -132,7 +147,7 @@ fn resolve_aggregate_over_view(
     // Group-by expressions must each reference a declared dimension by column
     // name; substitute the dimension's source expression, preserving the
     // original output name.
-    let mut group_expr = Vec::with_capacity(agg.group_expr.len());
+    let mut dimensions = Vec::with_capacity(agg.group_expr.len());
     for expr in &agg.group_expr {
         let Some(col) = single_column(expr) else {
             return plan_err!(
@@ -145,27 +160,28 @@ fn resolve_aggregate_over_view(
                 col.name
             );
         };
-        group_expr.push(dim.expr.clone().alias(expr.qualified_name().1));
+        dimensions.push((dim, expr.qualified_name().1));
     }
 
     // Aggregate expressions: substitute each MEASURE(measure_col) with the
     // measure's real aggregate expression. Any non-MEASURE aggregate is rejected
     // — measures are the only valid aggregations over a metric view.
-    let mut aggr_expr = Vec::with_capacity(agg.aggr_expr.len());
+    let mut measures = Vec::with_capacity(agg.aggr_expr.len());
     for expr in &agg.aggr_expr {
-        let resolved = resolve_measure_expr(expr, placeholder)?;
-        aggr_expr.push(resolved.alias(expr.qualified_name().1));
+        let measure = resolve_measure_expr(expr, placeholder)?;
+        measures.push((measure, expr.qualified_name().1));
     }
 
-    LogicalPlanBuilder::from(placeholder.source.as_ref().clone())
-        .aggregate(group_expr, aggr_expr)?
-        .build()
+    resolve_selection(placeholder, &dimensions, &measures)
 }
 
-/// Resolve an aggregate expression wrapping `MEASURE(measure_col)` into the
-/// measure's real aggregate expression (unaliased — the caller aliases it to the
+/// Resolve an aggregate expression wrapping `MEASURE(measure_col)` to the
+/// measure it names (the caller aliases the measure's expression to the
 /// original output name). Aliases on the input are unwrapped.
-fn resolve_measure_expr(expr: &Expr, placeholder: &MetricViewPlaceholder) -> Result<Expr> {
+fn resolve_measure_expr<'a>(
+    expr: &Expr,
+    placeholder: &'a MetricViewPlaceholder,
+) -> Result<&'a NamedExpr> {
     match expr {
         Expr::Alias(alias) => resolve_measure_expr(&alias.expr, placeholder),
         Expr::AggregateFunction(AggregateFunction { func, params })
@@ -185,7 +201,7 @@ fn resolve_measure_expr(expr: &Expr, placeholder: &MetricViewPlaceholder) -> Res
                     col.name
                 );
             };
-            Ok(measure.expr.clone())
+            Ok(measure)
         }
         other => plan_err!(
             "only MEASURE(<measure>) aggregations are valid over a metric view, found: {other}"
@@ -196,20 +212,250 @@ fn resolve_measure_expr(expr: &Expr, placeholder: &MetricViewPlaceholder) -> Res
 /// Expand a placeholder into the full aggregate over every dimension and
 /// measure (Form B: a bare scan with no enclosing aggregate).
 fn expand_full_aggregate(placeholder: &MetricViewPlaceholder) -> Result<LogicalPlan> {
-    let group_expr: Vec<Expr> = placeholder
+    let dimensions: Vec<_> = placeholder
         .dimensions
         .iter()
-        .map(named_to_aliased)
+        .map(|d| (d, d.name.clone()))
+        .collect();
+    let measures: Vec<_> = placeholder
+        .measures
+        .iter()
+        .chain(&placeholder.composed)
+        .map(|m| (m, m.name.clone()))
         .collect();
-    let aggr_expr: Vec<Expr> = placeholder.measures.iter().map(named_to_aliased).collect();
+    resolve_selection(placeholder, &dimensions, &measures)
+}
 
-    LogicalPlanBuilder::from(placeholder.source.as_ref().clone())
+/// Plan the selected `dimensions` and `measures` of the view, each under its
+/// output name, as one row per group of the dimensions.
+///
+/// Plain measures aggregate the source directly. Window measures are planned
+/// separately ([`plan_window_measure`]) and joined onto the plain aggregate by
+/// the grouped dimensions; composed measures are then evaluated over the
+/// results of the measures they combine.
+fn resolve_selection(
+    placeholder: &MetricViewPlaceholder,
+    dimensions: &[(&NamedExpr, String)],
+    measures: &[(&NamedExpr, String)],
+) -> Result<LogicalPlan> {
+    let group_expr: Vec<Expr> = dimensions
+        .iter()
+        .map(|(d, name)| d.expr.clone().alias(name))
+        .collect();
+    let dimension_exprs: Vec<&NamedExpr> = dimensions.iter().map(|(d, _)| *d).collect();
+    let plain =
+        |m: &NamedExpr| placeholder.window(&m.name).is_none() && !placeholder.is_composed(&m.name);
+    if measures.iter().all(|(m, _)| plain(m)) {
+        let aggr_expr: Vec<Expr> = measures
+            .iter()
+            .map(|(m, name)| m.expr.clone().alias(name))
+            .collect();
+        let measure_exprs: Vec<&NamedExpr> = measures.iter().map(|(m, _)| *m).collect();
+        return LogicalPlanBuilder::from(joined_source(
+            placeholder,
+            &dimension_exprs,
+            &measure_exprs,
+        )?)
         .aggregate(group_expr, aggr_expr)?
-        .build()
+        .build();
+    }
+
+    // The aggregate and window measures the selection reads, composed
+    // measures expanded to their inputs.
+    let mut inputs = Vec::new();
+    for (measure, _) in measures {
+        collect_measure_inputs(placeholder, measure, &mut inputs)?;
+    }
+    let (windowed, aggregated): (Vec<&NamedExpr>, Vec<&NamedExpr>) = inputs
+        .into_iter()
+        .partition(|m| placeholder.window(&m.name).is_some());
+
+    let aggr_expr: Vec<Expr> = aggregated
+        .iter()
+        .map(|m| m.expr.clone().alias(value_column(&m.name)))
+        .collect();
+    let mut plan = if dimensions.is_empty() && aggregated.is_empty() {
+        // Only window measures over the whole view: join them onto one row.
+        LogicalPlanBuilder::empty(true).alias(SELECTION_ALIAS)?
+    } else {
+        LogicalPlanBuilder::from(joined_source(placeholder, &dimension_exprs, &aggregated)?)
+            .aggregate(group_expr, aggr_expr)?
+            .alias(SELECTION_ALIAS)?
+    };
+    let mut values: HashMap<&str, Expr> = aggregated
+        .iter()
+        .map(|m| {
+            let column = Column::new(Some(SELECTION_ALIAS), value_column(&m.name));
+            (m.name.as_str(), Expr::Column(column))
+        })
+        .collect();
+    for (i, measure) in windowed.into_iter().enumerate() {
+        let window = placeholder
+            .window(&measure.name)
+            .expect("partitioned by window");
+        let alias = format!("__mv_window_{i}");
+        let window_plan = plan_window_measure(
+            placeholder,
+            measure,
+            window,
+            dimensions,
+            &value_column(&measure.name),
+        )?;
+        let window_plan = LogicalPlanBuilder::from(window_plan)
+            .alias(alias.as_str())?
+            .build()?;
+        plan = if dimensions.is_empty() {
+            plan.cross_join(window_plan)?
+        } else {
+            let keys = |relation: &str| -> Vec<Column> {
+                dimensions
+                    .iter()
+                    .map(|(_, name)| Column::new(Some(relation), name))
+                    .collect()
+            };
+            plan.join_detailed(
+                window_plan,
+                JoinType::Left,
+                (keys(SELECTION_ALIAS), keys(&alias)),
+                None,
+                NullEquality::NullEqualsNull,
+            )?
+        };
+        let column = Column::new(Some(alias.as_str()), value_column(&measure.name));
+        values.insert(measure.name.as_str(), Expr::Column(column));
+    }
+
+    let mut output: Vec<Expr> = dimensions
+        .iter()
+        .map(|(_, name)| Expr::Column(Column::new(Some(SELECTION_ALIAS), name)).alias(name))
+        .collect();
+    for (measure, name) in measures {
+        output.push(measure_value(placeholder, measure, &values)?.alias(name));
+    }
+    plan.project(output)?.build()
+}
+
+/// Alias of the plain aggregate in a plan joining window measures onto it.
+const SELECTION_ALIAS: &str = "__mv_selection";
+
+/// Column holding the measure `name`'s value before it is renamed to the
+/// query's output name.
+fn value_column(name: &str) -> String {
+    format!("__mv_measure_{name}")
 }
 
-fn named_to_aliased(ne: &NamedExpr) -> Expr {
-    ne.expr.clone().alias(&ne.name)
+/// Append the aggregate and window measures `measure` reads to `out`: itself,
+/// or for a composed measure, those of the measures it combines.
+fn collect_measure_inputs<'a>(
+    placeholder: &'a MetricViewPlaceholder,
+    measure: &'a NamedExpr,
+    out: &mut Vec<&'a NamedExpr>,
+) -> Result<()> {
+    if !placeholder.is_composed(&measure.name) {
+        if !out.iter().any(|m| m.name == measure.name) {
+            out.push(measure);
+        }
+        return Ok(());
+    }
+    for column in measure.expr.column_refs() {
+        let Some(input) = placeholder.measure(&column.name) else {
+            return plan_err!("'{}' is not a measure of the metric view", column.name);
+        };
+        collect_measure_inputs(placeholder, input, out)?;
+    }
+    Ok(())
+}
+
+/// The expression computing `measure` from the measure columns in `values`.
+fn measure_value(
+    placeholder: &MetricViewPlaceholder,
+    measure: &NamedExpr,
+    values: &HashMap<&str, Expr>,
+) -> Result<Expr> {
+    if let Some(value) = values.get(measure.name.as_str()) {
+        return Ok(value.clone());
+    }
+    measure
+        .expr
+        .clone()
+        .transform_up(|expr| match &expr {
+            Expr::Column(column) => match placeholder.measure(&column.name) {
+                Some(input) => Ok(Transformed::yes(measure_value(placeholder, input, values)?)),
+                None => Ok(Transformed::no(expr)),
+            },
+            _ => Ok(Transformed::no(expr)),
+        })
+        .map(|t| t.data)
+}
+
+/// The placeholder's source plan, joined only to the relations the selected
+/// `dimensions` and `measures` read. Errors if a `one_to_many` join would
+/// repeat the rows under a measure that does not read it.
+pub(super) fn joined_source(
+    placeholder: &MetricViewPlaceholder,
+    dimensions: &[&NamedExpr],
+    measures: &[&NamedExpr],
+) -> Result<LogicalPlan> {
+    if placeholder.joins.is_empty() {
+        return Ok(placeholder.source.as_ref().clone());
+    }
+    let mut needed: BTreeSet<String> = placeholder
+        .joins
+        .iter()
+        .filter(|j| j.required)
+        .map(|j| j.name.clone())
+        .collect();
+    for selected in dimensions.iter().chain(measures) {
+        needed.extend(placeholder.joins_referenced(&selected.expr));
+    }
+    for measure in measures {
+        let reads = placeholder.joins_referenced(&measure.expr);
+        let fanning = needed
+            .iter()
+            .filter_map(|name| placeholder.join(name))
+            .find(|join| join.fans_out && !reads.contains(&join.name));
+        if let Some(join) = fanning {
+            return plan_err!(
+                "measure '{}' cannot be combined with columns of '{}': the \
+                 one_to_many join would count its rows more than once",
+                measure.name,
+                join.name
+            );
+        }
+    }
+    prune_joins(&placeholder.source, placeholder, &needed)
+}
+
+/// Drop the joins not in `needed` from the left-deep join spine of `plan`.
+fn prune_joins(
+    plan: &LogicalPlan,
+    placeholder: &MetricViewPlaceholder,
+    needed: &BTreeSet<String>,
+) -> Result<LogicalPlan> {
+    match plan {
+        LogicalPlan::Filter(filter) => {
+            let input = prune_joins(&filter.input, placeholder, needed)?;
+            Ok(LogicalPlan::Filter(Filter::try_new(
+                filter.predicate.clone(),
+                Arc::new(input),
+            )?))
+        }
+        LogicalPlan::Join(join) => {
+            let left = prune_joins(&join.left, placeholder, needed)?;
+            if let LogicalPlan::SubqueryAlias(alias) = join.right.as_ref()
+                && placeholder.join(alias.alias.table()).is_some()
+                && !needed.contains(alias.alias.table())
+            {
+                return Ok(left);
+            }
+            LogicalPlan::Join(Join {
+                left: Arc::new(left),
+                ..join.clone()
+            })
+            .recompute_schema()
+        }
+        other => Ok(other.clone()),
+    }
 }
 
 /// Extract the single [`Column`] a grouping/argument expression refers to, if it
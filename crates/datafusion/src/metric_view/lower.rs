@@ -6,26 +6,55 @@
 //! aggregation is deferred to [`super::analyzer::ResolveMetricView`] at query
 //! time (Spark-style `MEASURE()` late binding). See the module docs in
 //! [`super`].
+//!
+//! Measures with a `window` are recorded with it for the analyzer to plan
+//! (see [`super::window`]); composed measures, which combine other measures
+//! via `MEASURE(<name>)`, are planned against the other measures' outputs.
+//!
+//! A view with joins is lowered over the source aliased as [`SOURCE_ALIAS`],
+//! left-joined to each joined relation under its declared name. Left joins keep
+//! every source row, so a `many_to_one` join never changes a measure; the
+//! analyzer uses the recorded cardinality to keep `one_to_many` joins from
+//! repeating rows under measures that do not read them.
 
+use std::collections::HashMap;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
-use datafusion::common::{DFSchema, Result, plan_err};
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{Column, DFSchema, Result, plan_err};
 use datafusion::execution::session_state::SessionState;
-use datafusion::logical_expr::{LogicalPlan, LogicalPlanBuilder};
+use datafusion::logical_expr::expr::AggregateFunction;
+use datafusion::logical_expr::{
+    Expr, JoinType, LogicalPlan, LogicalPlanBuilder, UserDefinedLogicalNodeCore, and, col,
+};
+use datafusion::sql::sqlparser::ast::{
+    Expr as SqlExpr, FunctionArg, FunctionArgExpr, FunctionArguments, visit_expressions,
+};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+
+use super::measure::MEASURE_UDF_NAME;
+use super::model::{Join, MetricView};
+use super::placeholder::{JoinedRelation, MetricViewPlaceholder, NamedExpr};
+use super::window::Window;
 
-use super::model::MetricView;
-use super::placeholder::{MetricViewPlaceholder, NamedExpr};
+/// The alias a view's `source` is addressed by in join conditions and
+/// expressions, once the view has joins.
+pub const SOURCE_ALIAS: &str = "source";
 
 /// Lower `view` over its resolved `source` plan into a [`MetricViewPlaceholder`].
 ///
-/// `state` supplies the SQL dialect and expression planner used to parse each
-/// dimension/measure/filter SQL string into an [`Expr`](datafusion::logical_expr::Expr)
-/// resolved against the source schema. The view's `filter`, if any, is applied
-/// beneath the placeholder so every query sees it.
+/// `joins` holds the resolved plan of every join, nested ones included, keyed
+/// by the join's name. `state` supplies the SQL dialect and expression planner
+/// used to parse each dimension/measure/filter SQL string into an
+/// [`Expr`] resolved against the (joined) source schema. The view's `filter`,
+/// if any, is applied beneath the placeholder so every query sees it.
 pub fn build_placeholder(
     state: &SessionState,
     view: &MetricView,
     source: LogicalPlan,
+    mut joins: HashMap<String, LogicalPlan>,
 ) -> Result<MetricViewPlaceholder> {
     if view.dimensions.is_empty() && view.measures.is_empty() {
         return plan_err!(
@@ -33,25 +62,37 @@ pub fn build_placeholder(
             view.source
         );
     }
-    if !view.joins.is_empty() {
-        return plan_err!(
-            "metric-view joins are not yet supported (source '{}' declares {} join(s))",
-            view.source,
-            view.joins.len()
-        );
-    }
+
+    let mut relations = Vec::new();
+    let source = if view.joins.is_empty() {
+        source
+    } else {
+        let source = LogicalPlanBuilder::from(source)
+            .alias(SOURCE_ALIAS)?
+            .build()?;
+        add_joins(
+            state,
+            source,
+            None,
+            &view.joins,
+            false,
+            &mut joins,
+            &mut relations,
+        )?
+    };
 
     // The view filter applies beneath the aggregation, exactly as Spark places
     // the view predicate below the grouping. Fold it into the source plan so it
     // travels with the placeholder's input.
-    let source = match &view.filter {
+    let (source, filter) = match &view.filter {
         Some(filter) => {
             let predicate = state.create_logical_expr(filter, source.schema())?;
-            LogicalPlanBuilder::from(source)
-                .filter(predicate)?
-                .build()?
+            let source = LogicalPlanBuilder::from(source)
+                .filter(predicate.clone())?
+                .build()?;
+            (source, Some(predicate))
         }
-        None => source,
+        None => (source, None),
     };
     let source_schema: &DFSchema = source.schema();
 
@@ -68,18 +109,221 @@ pub fn build_placeholder(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let measures = view
-        .measures
+    // Composed measures are typed against the measures they combine, so they
+    // are added once the placeholder exists.
+    let mut measures = Vec::with_capacity(view.measures.len());
+    let mut windows = Vec::new();
+    let mut composed = Vec::new();
+    for m in &view.measures {
+        let inputs = measure_references(&m.expr)?;
+        if !inputs.is_empty() {
+            if !m.window.is_empty() {
+                return plan_err!(
+                    "composed measure '{}' cannot declare a window; window the measures it combines",
+                    m.name
+                );
+            }
+            composed.push((m, inputs));
+            continue;
+        }
+        match m.window.as_slice() {
+            [] => {}
+            [window] => windows.push((m.name.clone(), Window::try_new(&m.name, window)?)),
+            more => {
+                return plan_err!(
+                    "measure '{}' declares {} windows; at most one is supported",
+                    m.name,
+                    more.len()
+                );
+            }
+        }
+        measures.push(NamedExpr {
+            name: m.name.clone(),
+            expr: state.create_logical_expr(&m.expr, source_schema)?,
+        });
+    }
+
+    let mut placeholder = MetricViewPlaceholder::try_new(Arc::new(source), dimensions, measures)?
+        .with_windows(windows)
+        .with_joins(relations);
+    for (measure, window) in &placeholder.windows {
+        if placeholder.dimension(&window.order).is_none() {
+            return plan_err!(
+                "window of measure '{measure}' orders by '{}', which is not a dimension of the metric view",
+                window.order
+            );
+        }
+    }
+    while !composed.is_empty() {
+        let ready = composed
+            .iter()
+            .position(|(_, inputs)| inputs.iter().all(|i| placeholder.measure(i).is_some()));
+        let Some(ready) = ready else {
+            let (m, inputs) = &composed[0];
+            return plan_err!(
+                "composed measure '{}' references unknown or cyclic measures: {}",
+                m.name,
+                inputs.join(", ")
+            );
+        };
+        let (m, _) = composed.remove(ready);
+        let expr = state.create_logical_expr(&m.expr, placeholder.schema())?;
+        placeholder = placeholder.with_composed(NamedExpr {
+            name: m.name.clone(),
+            expr: unwrap_measure_calls(&m.name, expr)?,
+        })?;
+    }
+
+    // Joins the filter reads must stay in every query's source plan.
+    let required = filter
+        .map(|filter| placeholder.joins_referenced(&filter))
+        .unwrap_or_default();
+    let joins = placeholder
+        .joins
         .iter()
-        .map(|m| {
-            state
-                .create_logical_expr(&m.expr, source_schema)
-                .map(|expr| NamedExpr {
-                    name: m.name.clone(),
-                    expr,
-                })
+        .cloned()
+        .map(|join| JoinedRelation {
+            required: required.contains(&join.name),
+            ..join
         })
-        .collect::<Result<Vec<_>>>()?;
+        .collect();
+    Ok(placeholder.with_joins(joins))
+}
+
+/// The measures a composed measure's SQL combines via `MEASURE(<name>)`; empty
+/// for an ordinary aggregate measure.
+fn measure_references(sql: &str) -> Result<Vec<String>> {
+    let expr = Parser::new(&GenericDialect {})
+        .try_with_sql(sql)?
+        .parse_expr()?;
+    let mut names = Vec::new();
+    let _ = visit_expressions(&expr, |expr| {
+        if let SqlExpr::Function(function) = expr
+            && function
+                .name
+                .to_string()
+                .eq_ignore_ascii_case(MEASURE_UDF_NAME)
+            && let FunctionArguments::List(list) = &function.args
+        {
+            for arg in &list.args {
+                if let FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Identifier(ident))) = arg
+                {
+                    names.push(ident.value.clone());
+                }
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    Ok(names)
+}
+
+/// Replace each `MEASURE(m)` in a composed measure's planned expression with
+/// the column `m`, rejecting any other aggregation.
+fn unwrap_measure_calls(measure: &str, expr: Expr) -> Result<Expr> {
+    let expr = expr
+        .transform_up(|expr| match expr {
+            Expr::AggregateFunction(AggregateFunction { func, params })
+                if func.name() == MEASURE_UDF_NAME =>
+            {
+                match <[Expr; 1]>::try_from(params.args) {
+                    Ok([arg @ Expr::Column(_)]) => Ok(Transformed::yes(arg)),
+                    _ => plan_err!("composed measure '{measure}': MEASURE() takes a measure name"),
+                }
+            }
+            Expr::AggregateFunction(_) => plan_err!(
+                "composed measure '{measure}' may only combine MEASURE() calls, found {expr}"
+            ),
+            other => Ok(Transformed::no(other)),
+        })?
+        .data;
+    Ok(expr)
+}
+
+/// Left-join each of `joins` onto `plan`, recursing into snowflake joins.
+/// `parent` is the join they hang off (`None` for the source), and
+/// `parent_fans_out` whether it may already repeat source rows.
+fn add_joins(
+    state: &SessionState,
+    mut plan: LogicalPlan,
+    parent: Option<&str>,
+    joins: &[Join],
+    parent_fans_out: bool,
+    plans: &mut HashMap<String, LogicalPlan>,
+    relations: &mut Vec<JoinedRelation>,
+) -> Result<LogicalPlan> {
+    for join in joins {
+        if join.name == SOURCE_ALIAS || relations.iter().any(|r| r.name == join.name) {
+            return plan_err!(
+                "metric-view join name '{}' is not unique (`{SOURCE_ALIAS}` names the view's source)",
+                join.name
+            );
+        }
+        let Some(fans_out) = join.fans_out() else {
+            return plan_err!(
+                "metric-view join '{}' has unknown cardinality '{}' \
+                 (expected many_to_one or one_to_many)",
+                join.name,
+                join.cardinality.as_deref().unwrap_or_default()
+            );
+        };
+        let Some(right) = plans.remove(&join.name) else {
+            return plan_err!(
+                "no relation was resolved for metric-view join '{}'",
+                join.name
+            );
+        };
+        let right = LogicalPlanBuilder::from(right)
+            .alias(join.name.as_str())?
+            .build()?;
+        let on = join_condition(state, &plan, &right, parent, join)?;
+        plan = LogicalPlanBuilder::from(plan)
+            .join_on(right, JoinType::Left, [on])?
+            .build()?;
+
+        let fans_out = parent_fans_out || fans_out;
+        relations.push(JoinedRelation {
+            name: join.name.clone(),
+            parent: parent.map(str::to_string),
+            fans_out,
+            required: false,
+        });
+        plan = add_joins(
+            state,
+            plan,
+            Some(&join.name),
+            &join.joins,
+            fans_out,
+            plans,
+            relations,
+        )?;
+    }
+    Ok(plan)
+}
 
-    MetricViewPlaceholder::try_new(Arc::new(source), dimensions, measures)
+/// The condition joining `right` onto `left`: the join's `on` expression, or
+/// equality of its `using` columns between the parent relation and the join.
+fn join_condition(
+    state: &SessionState,
+    left: &LogicalPlan,
+    right: &LogicalPlan,
+    parent: Option<&str>,
+    join: &Join,
+) -> Result<Expr> {
+    match (&join.on, join.using.as_slice()) {
+        (Some(on), []) => {
+            let schema: DFSchema = left.schema().join(right.schema())?;
+            state.create_logical_expr(on, &schema)
+        }
+        (None, [first, rest @ ..]) => {
+            let parent = parent.unwrap_or(SOURCE_ALIAS);
+            let eq = |c: &String| {
+                col(Column::new(Some(parent), c)).eq(col(Column::new(Some(join.name.as_str()), c)))
+            };
+            Ok(rest.iter().map(eq).fold(eq(first), and))
+        }
+        _ => plan_err!(
+            "metric-view join '{}' must declare exactly one of `on` or `using`",
+            join.name
+        ),
+    }
 }
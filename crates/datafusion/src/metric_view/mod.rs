@@ -34,6 +34,15 @@
 //! 4. [`session`] wires (2) and (3) onto a [`SessionContext`] in the correct
 //!    order (the rule must precede type coercion).
 //!
+//! Measures may declare a `window` along a dimension — running totals,
+//! trailing periods and semi-additive (first/last) measures — or be composed
+//! from other measures via `MEASURE(<name>)` in their own expression; the
+//! analyzer plans both ([`window`]).
+//!
+//! Star and snowflake `joins` are lowered as left joins onto the source
+//! ([`lower`]); the analyzer keeps only the joins a query reads and uses each
+//! join's cardinality to refuse measures a `one_to_many` join would repeat.
+//!
 //! Use [`metric_view_context`] to get a session with everything registered.
 //!
 //! [`SessionContext`]: datafusion::prelude::SessionContext
@@ -46,18 +55,20 @@ pub mod model;
 pub mod placeholder;
 pub mod provider;
 pub mod session;
+pub mod window;
 
 #[cfg(test)]
 mod tests;
 
 pub use analyzer::ResolveMetricView;
 pub use detect::metric_view_of;
-pub use lower::build_placeholder;
+pub use lower::{SOURCE_ALIAS, build_placeholder};
 pub use measure::{MEASURE_UDF_NAME, measure_udf};
-pub use model::{Dimension, Join, Measure, MetricView};
+pub use model::{Dimension, Join, Measure, MeasureWindow, MetricView};
 // Dependency derivation is owned by `unitycatalog-common`; re-export so the
 // DataFusion path can validate the relations it resolves against the same logic.
-pub use placeholder::{MetricViewPlaceholder, NamedExpr};
+pub use placeholder::{JoinedRelation, MetricViewPlaceholder, NamedExpr};
 pub use provider::MetricViewTableProvider;
 pub use session::{metric_view_context, metric_view_session_state};
-pub use unitycatalog_common::metric_view::{DependencyError, dependencies};
+pub use unitycatalog_common::metric_view::{DependencyError, dependencies, source_table};
+pub use window::{Semiadditive, Window, WindowRange, WindowUnit};
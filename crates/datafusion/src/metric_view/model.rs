@@ -3,4 +3,6 @@
 //! `view_dependencies` from it). Re-exported here so the DataFusion lowering
 //! keeps referring to `crate::metric_view::model::*`.
 
-pub use unitycatalog_common::metric_view::model::{Dimension, Join, Measure, MetricView};
+pub use unitycatalog_common::metric_view::model::{
+    Dimension, Join, Measure, MeasureWindow, MetricView,
+};
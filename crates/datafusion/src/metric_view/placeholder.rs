@@ -20,10 +20,14 @@
 //! materializing only the dimensions/measures the query actually references
 //! (Spark-style late binding).
 //!
+//! For a star/snowflake view the source plan is the view's `source` left-joined
+//! to each of its [`JoinedRelation`]s, which the analyzer drops again when the
+//! query references none of their columns.
+//!
 //! [`TableProvider`]: datafusion::catalog::TableProvider
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
@@ -31,6 +35,8 @@ use std::sync::Arc;
 use datafusion::common::{DFSchema, DFSchemaRef, Result};
 use datafusion::logical_expr::{Expr, ExprSchemable, LogicalPlan, UserDefinedLogicalNodeCore};
 
+use super::window::Window;
+
 /// Name used in `EXPLAIN` output and node identification.
 pub const METRIC_VIEW_NODE_NAME: &str = "MetricViewPlaceholder";
 
@@ -44,6 +50,21 @@ pub struct NamedExpr {
     pub expr: Expr,
 }
 
+/// A relation left-joined into the placeholder's source plan, under the alias
+/// `name`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct JoinedRelation {
+    /// The join's declared name, used as its alias in the source plan.
+    pub name: String,
+    /// The join this one hangs off in a snowflake, or `None` for the source.
+    pub parent: Option<String>,
+    /// Whether joining this relation (or one of its ancestors) may repeat rows
+    /// of the source — a `one_to_many` join.
+    pub fans_out: bool,
+    /// Referenced by the view's filter, so joined into every query.
+    pub required: bool,
+}
+
 /// Unresolved metric-view node: source + declared dimensions/measures, with the
 /// view's full relational schema as output. See module docs.
 #[derive(Debug, Clone)]
@@ -54,6 +75,15 @@ pub struct MetricViewPlaceholder {
     pub dimensions: Vec<NamedExpr>,
     /// Aggregate expressions, in declaration order.
     pub measures: Vec<NamedExpr>,
+    /// The windows of the `measures` that declare one, by measure name.
+    pub windows: Vec<(String, Window)>,
+    /// Composed measures, in dependency order: expressions over other
+    /// measures, which appear in them as unqualified columns named after the
+    /// measure.
+    pub composed: Vec<NamedExpr>,
+    /// The relations joined into `source`, parents before their snowflake
+    /// children. Empty for a view without joins.
+    pub joins: Vec<JoinedRelation>,
     /// Output schema: one field per dimension then per measure.
     schema: DFSchemaRef,
 }
@@ -80,13 +110,88 @@ impl MetricViewPlaceholder {
             source,
             dimensions,
             measures,
+            windows: vec![],
+            composed: vec![],
+            joins: vec![],
             schema,
         })
     }
 
+    /// Record the windows of measures that declare one.
+    pub fn with_windows(mut self, windows: Vec<(String, Window)>) -> Self {
+        self.windows = windows;
+        self
+    }
+
+    /// Add a composed measure, typed against the measures already declared.
+    pub fn with_composed(mut self, measure: NamedExpr) -> Result<Self> {
+        let (_, field) = measure.expr.to_field(&self.schema)?;
+        let field = field.as_ref().clone().with_name(&measure.name);
+        let mut schema = self.schema.as_ref().clone();
+        schema.merge(&DFSchema::new_with_metadata(
+            vec![(None, Arc::new(field))],
+            Default::default(),
+        )?);
+        self.schema = Arc::new(schema);
+        self.composed.push(measure);
+        Ok(self)
+    }
+
+    /// Record the relations joined into the source plan.
+    pub fn with_joins(mut self, joins: Vec<JoinedRelation>) -> Self {
+        self.joins = joins;
+        self
+    }
+
+    /// Look up a joined relation by name.
+    pub fn join(&self, name: &str) -> Option<&JoinedRelation> {
+        self.joins.iter().find(|j| j.name == name)
+    }
+
+    /// The joins `expr` reads columns of, with the ancestors they hang off.
+    pub fn joins_referenced(&self, expr: &Expr) -> BTreeSet<String> {
+        let mut referenced = BTreeSet::new();
+        if self.joins.is_empty() {
+            return referenced;
+        }
+        for column in expr.column_refs() {
+            let relation = match &column.relation {
+                Some(relation) => Some(relation.table().to_string()),
+                None => self
+                    .source
+                    .schema()
+                    .qualified_field_with_unqualified_name(&column.name)
+                    .ok()
+                    .and_then(|(qualifier, _)| qualifier.map(|q| q.table().to_string())),
+            };
+            let mut next = relation.and_then(|name| self.join(&name));
+            while let Some(join) = next {
+                referenced.insert(join.name.clone());
+                next = join.parent.as_deref().and_then(|p| self.join(p));
+            }
+        }
+        referenced
+    }
+
     /// Look up a measure by name (used by the analyzer to resolve `MEASURE`).
+    /// Composed measures are found too.
     pub fn measure(&self, name: &str) -> Option<&NamedExpr> {
-        self.measures.iter().find(|m| m.name == name)
+        self.measures
+            .iter()
+            .chain(&self.composed)
+            .find(|m| m.name == name)
+    }
+
+    /// The window of the measure `name`, if it declares one.
+    pub fn window(&self, name: &str) -> Option<&Window> {
+        self.windows
+            .iter()
+            .find_map(|(measure, window)| (measure == name).then_some(window))
+    }
+
+    /// Whether `name` is a composed measure.
+    pub fn is_composed(&self, name: &str) -> bool {
+        self.composed.iter().any(|m| m.name == name)
     }
 
     /// Look up a dimension by name.
@@ -131,13 +236,23 @@ impl UserDefinedLogicalNodeCore for MetricViewPlaceholder {
 
     fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let dims: Vec<&str> = self.dimensions.iter().map(|d| d.name.as_str()).collect();
-        let measures: Vec<&str> = self.measures.iter().map(|m| m.name.as_str()).collect();
+        let measures: Vec<&str> = self
+            .measures
+            .iter()
+            .chain(&self.composed)
+            .map(|m| m.name.as_str())
+            .collect();
         write!(
             f,
             "{METRIC_VIEW_NODE_NAME}: dimensions=[{}], measures=[{}]",
             dims.join(", "),
             measures.join(", ")
-        )
+        )?;
+        if !self.joins.is_empty() {
+            let joins: Vec<&str> = self.joins.iter().map(|j| j.name.as_str()).collect();
+            write!(f, ", joins=[{}]", joins.join(", "))?;
+        }
+        Ok(())
     }
 
     fn with_exprs_and_inputs(
@@ -173,19 +288,28 @@ impl UserDefinedLogicalNodeCore for MetricViewPlaceholder {
             })
             .collect();
 
-        Self::try_new(source, dimensions, measures)
+        self.composed.iter().cloned().try_fold(
+            Self::try_new(source, dimensions, measures)?
+                .with_windows(self.windows.clone())
+                .with_joins(self.joins.clone()),
+            Self::with_composed,
+        )
     }
 }
 
 // Manual Eq/Ord/Hash: `UserDefinedLogicalNodeCore` requires them, and the
 // derived `DFSchemaRef` does not implement `PartialOrd`/`Hash` usefully. Two
-// placeholders are equal when their source, dimensions, and measures match;
-// the schema is derived from those, so it need not participate.
+// placeholders are equal when their source, dimensions, measures (with their
+// windows and composition) and joins match; the schema is derived from those,
+// so it need not participate.
 impl PartialEq for MetricViewPlaceholder {
     fn eq(&self, other: &Self) -> bool {
         self.source == other.source
             && self.dimensions == other.dimensions
             && self.measures == other.measures
+            && self.windows == other.windows
+            && self.composed == other.composed
+            && self.joins == other.joins
     }
 }
 
@@ -207,6 +331,9 @@ impl Hash for MetricViewPlaceholder {
         self.source.hash(state);
         self.dimensions.hash(state);
         self.measures.hash(state);
+        self.windows.hash(state);
+        self.composed.hash(state);
+        self.joins.hash(state);
     }
 }
 
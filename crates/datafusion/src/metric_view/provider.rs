@@ -10,6 +10,7 @@
 
 use std::any::Any;
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use datafusion::arrow::datatypes::SchemaRef;
@@ -39,7 +40,18 @@ impl MetricViewTableProvider {
     /// `state` supplies the SQL expression planner used to parse the view's
     /// dimension/measure/filter strings against the `source` schema.
     pub fn try_new(state: &SessionState, view: &MetricView, source: LogicalPlan) -> Result<Self> {
-        let placeholder = build_placeholder(state, view, source)?;
+        Self::try_new_with_joins(state, view, source, HashMap::new())
+    }
+
+    /// Build a provider for a view with joins, given the resolved plan of each
+    /// join (nested ones included) keyed by the join's name.
+    pub fn try_new_with_joins(
+        state: &SessionState,
+        view: &MetricView,
+        source: LogicalPlan,
+        joins: HashMap<String, LogicalPlan>,
+    ) -> Result<Self> {
+        let placeholder = build_placeholder(state, view, source, joins)?;
         Self::from_placeholder(placeholder)
     }
 
@@ -2,6 +2,7 @@
 //! table, query it through `MEASURE()`, and assert both the resolved plan shape
 //! and the executed result.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use datafusion::arrow::array::{Date32Array, Float64Array, StringArray};
@@ -231,3 +232,237 @@ async fn bare_scan_materializes_full_aggregate() {
         &batches
     );
 }
+
+const STAR_METRICS_YAML: &str = r#"
+version: "1.1"
+source: main.sales.orders
+joins:
+  - name: customer
+    source: main.sales.customers
+    on: source.o_custkey = customer.c_custkey
+    joins:
+      - name: nation
+        source: main.geo.nations
+        on: customer.c_nationkey = nation.n_nationkey
+  - name: lineitem
+    source: main.sales.lineitems
+    on: source.o_orderkey = lineitem.l_orderkey
+    cardinality: one_to_many
+dimensions:
+  - name: nation
+    expr: nation.n_name
+measures:
+  - name: revenue
+    expr: SUM(o_totalprice)
+  - name: quantity
+    expr: SUM(lineitem.l_quantity)
+"#;
+
+/// Register `orders`, a snowflake of `customers` -> `nations`, and a
+/// one-to-many `lineitems` table, with the metric view `star_metrics` joining
+/// them.
+async fn ctx_with_star_metric_view() -> SessionContext {
+    let ctx = metric_view_context();
+    for ddl in [
+        "CREATE TABLE orders (o_orderkey BIGINT, o_custkey BIGINT, o_totalprice DOUBLE) \
+         AS VALUES (1, 10, 100.0), (2, 10, 50.0), (3, 20, 200.0)",
+        "CREATE TABLE customers (c_custkey BIGINT, c_nationkey BIGINT) \
+         AS VALUES (10, 1), (20, 2)",
+        "CREATE TABLE nations (n_nationkey BIGINT, n_name VARCHAR) \
+         AS VALUES (1, 'FRANCE'), (2, 'PERU')",
+        "CREATE TABLE lineitems (l_orderkey BIGINT, l_quantity BIGINT) \
+         AS VALUES (1, 5), (1, 3), (2, 1), (3, 2)",
+    ] {
+        ctx.sql(ddl).await.unwrap().collect().await.unwrap();
+    }
+
+    let mut plans = HashMap::new();
+    for name in ["orders", "customers", "nations", "lineitems"] {
+        let plan = ctx.table(name).await.unwrap().into_unoptimized_plan();
+        plans.insert(name, plan);
+    }
+    let joins = HashMap::from([
+        ("customer".to_string(), plans.remove("customers").unwrap()),
+        ("nation".to_string(), plans.remove("nations").unwrap()),
+        ("lineitem".to_string(), plans.remove("lineitems").unwrap()),
+    ]);
+    let view = MetricView::from_yaml(STAR_METRICS_YAML).unwrap();
+    let source = plans.remove("orders").unwrap();
+    let provider =
+        MetricViewTableProvider::try_new_with_joins(&ctx.state(), &view, source, joins).unwrap();
+    ctx.register_table("star_metrics", Arc::new(provider))
+        .unwrap();
+    ctx
+}
+
+#[tokio::test]
+async fn snowflake_joins_resolve_dimensions() {
+    let ctx = ctx_with_star_metric_view().await;
+
+    const QUERY: &str = "SELECT nation, MEASURE(revenue) AS rev FROM star_metrics GROUP BY nation";
+    // Only the joins the query reads are kept: the one-to-many line items
+    // would otherwise repeat each order's revenue.
+    let plan = ctx.sql(QUERY).await.unwrap().into_optimized_plan().unwrap();
+    let rendered = format!("{}", plan.display_indent());
+    assert!(
+        !rendered.contains("lineitem"),
+        "the unreferenced lineitem join must be pruned:\n{rendered}"
+    );
+
+    let batches = ctx.sql(QUERY).await.unwrap().collect().await.unwrap();
+    assert_batches_sorted_eq!(
+        [
+            "+--------+-------+",
+            "| nation | rev   |",
+            "+--------+-------+",
+            "| FRANCE | 150.0 |",
+            "| PERU   | 200.0 |",
+            "+--------+-------+",
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn one_to_many_join_feeds_its_own_measures() {
+    let ctx = ctx_with_star_metric_view().await;
+
+    let batches = ctx
+        .sql("SELECT nation, MEASURE(quantity) AS q FROM star_metrics GROUP BY nation")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_batches_sorted_eq!(
+        [
+            "+--------+---+",
+            "| nation | q |",
+            "+--------+---+",
+            "| FRANCE | 9 |",
+            "| PERU   | 2 |",
+            "+--------+---+",
+        ],
+        &batches
+    );
+
+    // Combining it with a measure over the orders would count each order once
+    // per line item.
+    let err = ctx
+        .sql(
+            "SELECT nation, MEASURE(revenue), MEASURE(quantity) \
+             FROM star_metrics GROUP BY nation",
+        )
+        .await
+        .unwrap()
+        .into_optimized_plan()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("one_to_many join"),
+        "unexpected error: {err}"
+    );
+}
+
+const WINDOW_METRICS_YAML: &str = r#"
+version: "1.1"
+source: main.sales.orders
+dimensions:
+  - name: order_date
+    expr: o_orderdate
+  - name: status
+    expr: o_orderstatus
+measures:
+  - name: revenue
+    expr: SUM(o_totalprice)
+  - name: order_count
+    expr: COUNT(1)
+  - name: avg_order
+    expr: MEASURE(revenue) / MEASURE(order_count)
+  - name: running_revenue
+    expr: SUM(o_totalprice)
+    window:
+      - order: order_date
+        range: cumulative
+  - name: prior_day_revenue
+    expr: SUM(o_totalprice)
+    window:
+      - order: order_date
+        range: trailing 1 day
+  - name: day_over_day
+    expr: MEASURE(revenue) - MEASURE(prior_day_revenue)
+  - name: closing_revenue
+    expr: SUM(o_totalprice)
+    window:
+      - order: order_date
+        range: current
+        semiadditive: last
+"#;
+
+#[tokio::test]
+async fn window_and_composed_measures_over_time() {
+    let ctx = ctx_with_metric_view(WINDOW_METRICS_YAML).await;
+
+    let batches = ctx
+        .sql(
+            "SELECT order_date, MEASURE(revenue) AS rev, MEASURE(running_revenue) AS running, \
+             MEASURE(day_over_day) AS dod FROM orders_metrics GROUP BY order_date",
+        )
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_batches_sorted_eq!(
+        [
+            "+------------+-------+---------+-------+",
+            "| order_date | rev   | running | dod   |",
+            "+------------+-------+---------+-------+",
+            "| 2022-01-08 | 150.0 | 150.0   |       |",
+            "| 2022-01-09 | 275.0 | 425.0   | 125.0 |",
+            "+------------+-------+---------+-------+",
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn semiadditive_measure_keeps_last_value() {
+    let ctx = ctx_with_metric_view(WINDOW_METRICS_YAML).await;
+
+    // Not grouped by date: the closing revenue is each status's revenue on
+    // its last order date, not the sum across dates.
+    let batches = ctx
+        .sql(
+            "SELECT status, MEASURE(closing_revenue) AS closing, MEASURE(avg_order) AS avg \
+             FROM orders_metrics GROUP BY status",
+        )
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_batches_sorted_eq!(
+        [
+            "+--------+---------+-------+",
+            "| status | closing | avg   |",
+            "+--------+---------+-------+",
+            "| F      | 50.0    | 50.0  |",
+            "| O      | 275.0   | 125.0 |",
+            "+--------+---------+-------+",
+        ],
+        &batches
+    );
+
+    // A running total has no single value across dates.
+    let err = ctx
+        .sql("SELECT status, MEASURE(running_revenue) FROM orders_metrics GROUP BY status")
+        .await
+        .unwrap()
+        .into_optimized_plan()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("must be grouped by its order dimension"),
+        "unexpected error: {err}"
+    );
+}
@@ -0,0 +1,332 @@
+//! Window measures: a measure evaluated over a range of one dimension (the
+//! window's `order`) rather than over each group alone.
+//!
+//! ```yaml
+//! measures:
+//!   - name: trailing_week_revenue
+//!     expr: SUM(o_totalprice)
+//!     window:
+//!       - order: order_date
+//!         range: trailing 7 day
+//!   - name: closing_balance
+//!     expr: SUM(balance)
+//!     window:
+//!       - order: balance_date
+//!         range: current
+//!         semiadditive: last
+//! ```
+//!
+//! A window measure is computed at the grain of the query's other dimensions
+//! plus the order dimension. Each group's value aggregates the source rows of
+//! the same partition whose order value falls in the range relative to the
+//! group's — so any aggregate works, not only additive ones. When the query
+//! does not group by the order dimension, a `semiadditive` measure keeps the
+//! value at the first or last order position of each partition instead of
+//! aggregating across it.
+//!
+//! [`plan_window_measure`] builds this plan; the analyzer joins it to the
+//! query's other measures on the grouped dimensions.
+
+use datafusion::arrow::datatypes::IntervalMonthDayNano;
+use datafusion::common::{
+    Column, NullEquality, Result, ScalarValue, plan_datafusion_err, plan_err,
+};
+use datafusion::functions_aggregate::first_last::{first_value_udaf, last_value_udaf};
+use datafusion::logical_expr::{
+    Expr, ExprFunctionExt, JoinType, LogicalPlan, LogicalPlanBuilder, and, col, lit,
+};
+
+use super::analyzer::joined_source;
+use super::model::MeasureWindow;
+use super::placeholder::{MetricViewPlaceholder, NamedExpr};
+
+/// Output column holding a window measure's value at each group.
+const VALUE: &str = "__mv_value";
+
+/// A parsed measure window. See the module docs.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Window {
+    /// The dimension the window runs along.
+    pub order: String,
+    pub range: WindowRange,
+    pub semiadditive: Option<Semiadditive>,
+}
+
+/// Which order values a group's window covers, relative to the group's own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum WindowRange {
+    /// Only the group's own order value.
+    Current,
+    /// Every order value up to and including the group's.
+    Cumulative,
+    /// Every order value.
+    All,
+    /// The `n` units before the group's order value, excluding it.
+    Trailing(u32, Option<WindowUnit>),
+    /// The `n` units after the group's order value, excluding it.
+    Leading(u32, Option<WindowUnit>),
+}
+
+/// The unit of a trailing or leading range over a date or timestamp. A range
+/// without a unit counts in the order dimension's own (numeric) values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum WindowUnit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// Which value a semi-additive measure keeps across the order dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum Semiadditive {
+    First,
+    Last,
+}
+
+impl Window {
+    /// Parse the window declared on `measure`.
+    pub fn try_new(measure: &str, window: &MeasureWindow) -> Result<Self> {
+        let semiadditive = match window.semiadditive.as_deref() {
+            None => None,
+            Some(s) if s.eq_ignore_ascii_case("first") => Some(Semiadditive::First),
+            Some(s) if s.eq_ignore_ascii_case("last") => Some(Semiadditive::Last),
+            Some(other) => {
+                return plan_err!(
+                    "measure '{measure}' has unknown semiadditive '{other}' (expected first or last)"
+                );
+            }
+        };
+        Ok(Self {
+            order: window.order.clone(),
+            range: WindowRange::parse(&window.range)
+                .map_err(|e| plan_datafusion_err!("measure '{measure}': {e}"))?,
+            semiadditive,
+        })
+    }
+}
+
+impl WindowRange {
+    fn parse(range: &str) -> Result<Self> {
+        let words: Vec<String> = range
+            .split_whitespace()
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let count = |n: &str, unit: Option<&str>| -> Result<(u32, Option<WindowUnit>)> {
+            let n = n
+                .parse()
+                .map_err(|_| plan_datafusion_err!("invalid window range '{range}'"))?;
+            Ok((n, unit.map(WindowUnit::parse).transpose()?))
+        };
+        match words.as_slice() {
+            ["current"] => Ok(Self::Current),
+            ["cumulative"] => Ok(Self::Cumulative),
+            ["all"] => Ok(Self::All),
+            ["trailing", n, unit @ ..] if unit.len() <= 1 => {
+                let (n, unit) = count(n, unit.first().copied())?;
+                Ok(Self::Trailing(n, unit))
+            }
+            ["leading", n, unit @ ..] if unit.len() <= 1 => {
+                let (n, unit) = count(n, unit.first().copied())?;
+                Ok(Self::Leading(n, unit))
+            }
+            _ => plan_err!(
+                "invalid window range '{range}' (expected current, cumulative, all, \
+                 trailing <n> [<unit>] or leading <n> [<unit>])"
+            ),
+        }
+    }
+
+    /// The condition a source row's order value `row` must meet to count
+    /// towards the group at order value `target`; `None` for every row.
+    fn condition(self, row: Expr, target: Expr) -> Option<Expr> {
+        match self {
+            Self::Current => Some(row.eq(target)),
+            Self::Cumulative => Some(row.lt_eq(target)),
+            Self::All => None,
+            Self::Trailing(n, unit) => {
+                let start = target.clone() - offset(n, unit);
+                Some(and(row.clone().gt_eq(start), row.lt(target)))
+            }
+            Self::Leading(n, unit) => {
+                let end = target.clone() + offset(n, unit);
+                Some(and(row.clone().gt(target), row.lt_eq(end)))
+            }
+        }
+    }
+}
+
+impl WindowUnit {
+    fn parse(unit: &str) -> Result<Self> {
+        match unit.strip_suffix('s').unwrap_or(unit) {
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "year" => Ok(Self::Year),
+            other => plan_err!("unknown window unit '{other}'"),
+        }
+    }
+}
+
+/// `n` units as a literal to shift an order value by.
+fn offset(n: u32, unit: Option<WindowUnit>) -> Expr {
+    let n = n as i32;
+    let (months, days) = match unit {
+        None => return lit(n as i64),
+        Some(WindowUnit::Day) => (0, n),
+        Some(WindowUnit::Week) => (0, 7 * n),
+        Some(WindowUnit::Month) => (n, 0),
+        Some(WindowUnit::Quarter) => (3 * n, 0),
+        Some(WindowUnit::Year) => (12 * n, 0),
+    };
+    lit(ScalarValue::IntervalMonthDayNano(Some(
+        IntervalMonthDayNano::new(months, days, 0),
+    )))
+}
+
+fn key(dimension: &NamedExpr) -> String {
+    format!("__mv_key_{}", dimension.name)
+}
+
+fn target(dimension: &NamedExpr) -> String {
+    format!("__mv_target_{}", dimension.name)
+}
+
+/// Plan the window `measure` for a query grouping by `dimensions` (each with
+/// its output name). The plan has one row per group: the dimensions under
+/// their output names, then the measure's value as `value`.
+pub(super) fn plan_window_measure(
+    placeholder: &MetricViewPlaceholder,
+    measure: &NamedExpr,
+    window: &Window,
+    dimensions: &[(&NamedExpr, String)],
+    value: &str,
+) -> Result<LogicalPlan> {
+    let Some(order) = placeholder.dimension(&window.order) else {
+        return plan_err!(
+            "window of measure '{}' orders by '{}', which is not a dimension of the metric view",
+            measure.name,
+            window.order
+        );
+    };
+    let grouped_by_order = dimensions.iter().any(|(d, _)| d.name == order.name);
+    if !grouped_by_order && window.semiadditive.is_none() {
+        return plan_err!(
+            "window measure '{}' must be grouped by its order dimension '{}' \
+             unless it declares `semiadditive`",
+            measure.name,
+            order.name
+        );
+    }
+    let partition: Vec<&NamedExpr> = dimensions
+        .iter()
+        .map(|(d, _)| *d)
+        .filter(|d| d.name != order.name)
+        .collect();
+    let grain: Vec<&NamedExpr> = partition.iter().copied().chain([order]).collect();
+
+    // Every source row, keyed by the grain's dimension values.
+    let source = joined_source(placeholder, &grain, &[measure])?;
+    let mut columns: Vec<Expr> = source
+        .schema()
+        .columns()
+        .into_iter()
+        .map(Expr::Column)
+        .collect();
+    columns.extend(grain.iter().map(|d| d.expr.clone().alias(key(d))));
+    let rows = LogicalPlanBuilder::from(source).project(columns)?.build()?;
+    let value_expr = measure.expr.clone().alias(VALUE);
+
+    // The measure at each group of the grain, over the rows its range covers.
+    let targets: Vec<Expr> = grain.iter().map(|d| col(key(d)).alias(target(d))).collect();
+    let values = match window.range {
+        WindowRange::Current => LogicalPlanBuilder::from(rows)
+            .aggregate(targets, [value_expr])?
+            .build()?,
+        range => {
+            let groups = LogicalPlanBuilder::from(rows.clone())
+                .aggregate(targets, Vec::<Expr>::new())?
+                .build()?;
+            let condition = range.condition(col(key(order)), col(target(order)));
+            let left: Vec<Column> = partition
+                .iter()
+                .map(|d| Column::from_name(key(d)))
+                .collect();
+            let right: Vec<Column> = partition
+                .iter()
+                .map(|d| Column::from_name(target(d)))
+                .collect();
+            let joined = if left.is_empty() && condition.is_none() {
+                LogicalPlanBuilder::from(rows).cross_join(groups)?
+            } else {
+                LogicalPlanBuilder::from(rows).join_detailed(
+                    groups,
+                    JoinType::Inner,
+                    (left, right),
+                    condition,
+                    NullEquality::NullEqualsNull,
+                )?
+            };
+            let group_expr: Vec<Expr> = grain.iter().map(|d| col(target(d))).collect();
+            joined.aggregate(group_expr, [value_expr])?.build()?
+        }
+    };
+
+    // Semi-additive: keep one order position's value per partition.
+    let values = match window.semiadditive {
+        Some(semiadditive) if !grouped_by_order => {
+            let udaf = match semiadditive {
+                Semiadditive::First => first_value_udaf(),
+                Semiadditive::Last => last_value_udaf(),
+            };
+            let picked = udaf
+                .call(vec![col(VALUE)])
+                .order_by(vec![col(target(order)).sort(true, false)])
+                .build()?;
+            let group_expr: Vec<Expr> = partition.iter().map(|d| col(target(d))).collect();
+            LogicalPlanBuilder::from(values)
+                .aggregate(group_expr, [picked.alias(VALUE)])?
+                .build()?
+        }
+        _ => values,
+    };
+
+    let mut output: Vec<Expr> = dimensions
+        .iter()
+        .map(|(d, name)| col(target(d)).alias(name))
+        .collect();
+    output.push(col(VALUE).alias(value));
+    LogicalPlanBuilder::from(values).project(output)?.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_parse() {
+        assert_eq!(WindowRange::parse("current").unwrap(), WindowRange::Current);
+        assert_eq!(
+            WindowRange::parse("Cumulative").unwrap(),
+            WindowRange::Cumulative
+        );
+        assert_eq!(
+            WindowRange::parse("trailing 7 days").unwrap(),
+            WindowRange::Trailing(7, Some(WindowUnit::Day))
+        );
+        assert_eq!(
+            WindowRange::parse("leading 1 quarter").unwrap(),
+            WindowRange::Leading(1, Some(WindowUnit::Quarter))
+        );
+        assert_eq!(
+            WindowRange::parse("trailing 3").unwrap(),
+            WindowRange::Trailing(3, None)
+        );
+        assert!(WindowRange::parse("trailing week").is_err());
+        assert!(WindowRange::parse("trailing 1 fortnight").is_err());
+        assert!(WindowRange::parse("previous").is_err());
+    }
+}
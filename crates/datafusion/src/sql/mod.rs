@@ -2,7 +2,9 @@
 //!
 //! This module owns the Unity Catalog DDL surface that runs *inside* a
 //! DataFusion plan: the statement types (`CREATE`/`DROP CATALOG`, `CREATE`/`DROP
-//! SCHEMA`, managed `CREATE TABLE`), their execution against a live
+//! SCHEMA`, managed and external `CREATE TABLE`, `DROP TABLE`, `CREATE VIEW`,
+//! `CREATE`/`REFRESH MATERIALIZED VIEW`, `CREATE`/`DROP VOLUME`, `ALTER`, `SHOW`,
+//! `DESCRIBE`, and `GRANT`/`REVOKE`/`SHOW GRANTS`), their execution against a live
 //! [`UnityCatalogClient`](unitycatalog_client::UnityCatalogClient), the
 //! [`ExecuteUnityCatalogPlanNode`] DataFusion `Extension` node, and the
 //! [`UnityCatalogPlanner`] that lowers it to a physical plan.
@@ -15,9 +17,14 @@
 //!
 //! Authorization for the DDL is the host's Cedar policy layer's responsibility.
 //! That layer matches the extension node purely by its `name()` string
-//! (`CreateCatalog`/`DropCatalog`/`CreateSchema`/`DropSchema`/`CreateManagedTable`)
+//! (`CreateCatalog`/`DropCatalog`/`CreateSchema`/`DropSchema`/`CreateManagedTable`/
+//! `CreateExternalTable`/`DropTable`/`CreateView`/`CreateMaterializedView`/
+//! `RefreshMaterializedView`/`CreateVolume`/`DropVolume`, and
+//! `Alter*`/`Show*`/`Describe*` suffixed with the securable kind, e.g.
+//! `AlterTable`/`ShowSchemas`/`DescribeVolume`, and `Grant`/`Revoke`/`ShowGrants`)
 //! and reads the securable from the `name=<...>` token in `fmt_for_explain` — a
-//! stable string contract this crate must preserve.
+//! stable string contract this crate must preserve. `ShowCatalogs` is the one
+//! node without a securable and renders no `name=` token.
 
 mod unity;
 
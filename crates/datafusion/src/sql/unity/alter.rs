@@ -0,0 +1,295 @@
+use std::collections::{BTreeMap, HashMap};
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::common::{DataFusionError, Result};
+use datafusion::sql::sqlparser::ast::{ObjectName, Value};
+use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::models::delta::v1::{
+    DeltaTableRequirement, DeltaTableUpdate, DeltaUpdateTableRequest,
+};
+use unitycatalog_common::models::tags::v1::EntityTagAssignment;
+
+use crate::sql::unity::schemas::split_schema_name;
+use crate::sql::unity::show::describe_securable;
+use crate::sql::unity::tables::split_table_name;
+use crate::sql::unity::{SecurableKind, split_three_part_name, value_to_string};
+
+/// What an [`AlterStatement`] changes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum AlterAction {
+    /// `SET TBLPROPERTIES` / `SET DBPROPERTIES` / `SET PROPERTIES (k = v, …)` —
+    /// merged into the existing properties.
+    SetProperties(Vec<(String, Value)>),
+    /// `UNSET TBLPROPERTIES (k, …)`.
+    UnsetProperties(Vec<String>),
+    /// `[SET] OWNER TO <principal>`.
+    SetOwner(String),
+    /// `COMMENT ON … IS <str>` / `SET COMMENT`; `None` clears the comment.
+    SetComment(Option<String>),
+    /// `SET TAGS (k = v, …)`; a tag may be set without a value.
+    SetTags(Vec<(String, Option<String>)>),
+    /// `UNSET TAGS (k, …)`.
+    UnsetTags(Vec<String>),
+}
+
+impl AlterAction {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            AlterAction::SetProperties(_) => "set_properties",
+            AlterAction::UnsetProperties(_) => "unset_properties",
+            AlterAction::SetOwner(_) => "set_owner",
+            AlterAction::SetComment(_) => "set_comment",
+            AlterAction::SetTags(_) => "set_tags",
+            AlterAction::UnsetTags(_) => "unset_tags",
+        }
+    }
+}
+
+/// `ALTER CATALOG|SCHEMA|TABLE|VOLUME <name> <action>`. Returns the securable
+/// as it stands after the change.
+///
+/// Catalogs and schemas are updated through their `update` APIs, which replace
+/// the comment and properties wholesale, so the current values are read first
+/// and the change applied on top. Tables have no such API: their properties and
+/// comment are changed through the Delta `updateTable` actions, so only Delta
+/// tables can be altered. Ownership can be transferred for catalogs and
+/// volumes only — the schema and table APIs carry no owner.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct AlterStatement {
+    pub securable: SecurableKind,
+    pub name: ObjectName,
+    pub action: AlterAction,
+}
+
+impl AlterStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        match &self.action {
+            AlterAction::SetTags(_) | AlterAction::UnsetTags(_) => self.alter_tags(&client).await?,
+            _ => match self.securable {
+                SecurableKind::Catalog => self.alter_catalog(&client).await?,
+                SecurableKind::Schema => self.alter_schema(&client).await?,
+                SecurableKind::Table => self.alter_table(&client).await?,
+                SecurableKind::Volume => self.alter_volume(&client).await?,
+                SecurableKind::Function => return Err(self.unsupported()),
+            },
+        }
+        describe_securable(&client, self.securable, &self.name).await
+    }
+
+    async fn alter_catalog(&self, client: &UnityCatalogClient) -> Result<()> {
+        let name = match self.name.0.as_slice() {
+            [name] => name.to_string(),
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Catalog name '{}' must be a single identifier",
+                    self.name
+                )));
+            }
+        };
+        let current = client
+            .catalog(&name)
+            .get()
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        let (comment, properties) =
+            apply_to_metadata(current.comment, current.properties, &self.action);
+        let owner = match &self.action {
+            AlterAction::SetOwner(owner) => Some(owner.clone()),
+            _ => None,
+        };
+        client
+            .catalog(&name)
+            .update()
+            .with_comment(comment)
+            .with_properties(properties)
+            .with_owner(owner)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn alter_schema(&self, client: &UnityCatalogClient) -> Result<()> {
+        if matches!(self.action, AlterAction::SetOwner(_)) {
+            return Err(self.unsupported());
+        }
+        let (catalog, schema) = split_schema_name(&self.name)?;
+        let current = client
+            .schema(&catalog, &schema)
+            .get()
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        let (comment, properties) =
+            apply_to_metadata(current.comment, current.properties, &self.action);
+        client
+            .schema(&catalog, &schema)
+            .update()
+            .with_comment(comment)
+            .with_properties(properties)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn alter_table(&self, client: &UnityCatalogClient) -> Result<()> {
+        let update = match &self.action {
+            AlterAction::SetProperties(properties) => DeltaTableUpdate::SetProperties {
+                updates: properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), value_to_string(v)))
+                    .collect::<BTreeMap<_, _>>(),
+            },
+            AlterAction::UnsetProperties(keys) => DeltaTableUpdate::RemoveProperties {
+                removals: keys.clone(),
+            },
+            AlterAction::SetComment(comment) => DeltaTableUpdate::SetTableComment {
+                comment: comment.clone().unwrap_or_default(),
+            },
+            _ => return Err(self.unsupported()),
+        };
+        let (catalog, schema, table) = split_table_name(&self.name)?;
+        let delta = client.delta_v1();
+        let loaded = delta
+            .load_table(&catalog, &schema, &table)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        let request = DeltaUpdateTableRequest {
+            requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                uuid: loaded.metadata.table_uuid,
+            }],
+            updates: vec![update],
+        };
+        delta
+            .update_table(&catalog, &schema, &table, &request)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn alter_volume(&self, client: &UnityCatalogClient) -> Result<()> {
+        let (catalog, schema, volume) = split_three_part_name(&self.name, "Volume")?;
+        let request = client.volume(catalog, schema, volume).update();
+        let request = match &self.action {
+            AlterAction::SetOwner(owner) => request.with_owner(Some(owner.clone())),
+            AlterAction::SetComment(comment) => request.with_comment(comment.clone()),
+            _ => return Err(self.unsupported()),
+        };
+        request
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(())
+    }
+
+    async fn alter_tags(&self, client: &UnityCatalogClient) -> Result<()> {
+        let entity_type = self
+            .securable
+            .tag_entity_type()
+            .ok_or_else(|| self.unsupported())?;
+        let entity_name = self.name.to_string();
+        match &self.action {
+            AlterAction::SetTags(tags) => {
+                for (key, value) in tags {
+                    let assignment = EntityTagAssignment {
+                        entity_type: entity_type.to_string(),
+                        entity_name: entity_name.clone(),
+                        tag_key: key.clone(),
+                        tag_value: value.clone(),
+                    };
+                    let created = client
+                        .create_entity_tag_assignment(assignment.clone())
+                        .await;
+                    match created {
+                        Ok(_) => {}
+                        Err(e) if e.is_already_exists() => {
+                            client
+                                .update_entity_tag_assignment(
+                                    entity_type,
+                                    &entity_name,
+                                    key,
+                                    assignment,
+                                )
+                                .await
+                                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                        }
+                        Err(e) => return Err(DataFusionError::External(Box::new(e))),
+                    }
+                }
+            }
+            AlterAction::UnsetTags(keys) => {
+                for key in keys {
+                    match client
+                        .delete_entity_tag_assignment(entity_type, &entity_name, key)
+                        .await
+                    {
+                        Ok(_) => {}
+                        Err(e) if e.is_not_found() => {}
+                        Err(e) => return Err(DataFusionError::External(Box::new(e))),
+                    }
+                }
+            }
+            _ => unreachable!("only tag actions are dispatched here"),
+        }
+        Ok(())
+    }
+
+    fn unsupported(&self) -> DataFusionError {
+        DataFusionError::NotImplemented(format!(
+            "{} is not supported for {} '{}'",
+            self.action.label(),
+            self.securable.as_str().to_ascii_lowercase(),
+            self.name
+        ))
+    }
+}
+
+/// Apply a property or comment change to a securable's current comment and
+/// properties, for update APIs that replace both wholesale.
+fn apply_to_metadata(
+    comment: Option<String>,
+    mut properties: HashMap<String, String>,
+    action: &AlterAction,
+) -> (Option<String>, HashMap<String, String>) {
+    match action {
+        AlterAction::SetProperties(updates) => {
+            properties.extend(updates.iter().map(|(k, v)| (k.clone(), value_to_string(v))));
+            (comment, properties)
+        }
+        AlterAction::UnsetProperties(keys) => {
+            for key in keys {
+                properties.remove(key);
+            }
+            (comment, properties)
+        }
+        AlterAction::SetComment(new_comment) => (new_comment.clone(), properties),
+        _ => (comment, properties),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_changes_merge_into_current_metadata() {
+        let current = HashMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string()),
+        ]);
+        let set = AlterAction::SetProperties(vec![(
+            "b".to_string(),
+            Value::SingleQuotedString("3".to_string()),
+        )]);
+        let (comment, props) = apply_to_metadata(Some("c".to_string()), current.clone(), &set);
+        assert_eq!(comment.as_deref(), Some("c"));
+        assert_eq!(props["a"], "1");
+        assert_eq!(props["b"], "3");
+
+        let unset = AlterAction::UnsetProperties(vec!["a".to_string(), "missing".to_string()]);
+        let (_, props) = apply_to_metadata(None, current.clone(), &unset);
+        assert_eq!(props.len(), 1);
+
+        let clear = AlterAction::SetComment(None);
+        let (comment, props) = apply_to_metadata(Some("c".to_string()), current, &clear);
+        assert_eq!(comment, None);
+        assert_eq!(props.len(), 2);
+    }
+}
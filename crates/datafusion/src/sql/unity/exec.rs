@@ -9,7 +9,9 @@
 //!
 //! The client is resolved at planning time from a [`UnityClientExtension`] set
 //! on the session config; if it is absent the planner errors rather than
-//! silently dropping the DDL.
+//! silently dropping the DDL. Materialized-view statements additionally need the
+//! [`UnityFactoryExt`], since they read their sources and commit through the
+//! catalog; they fail at execution without it.
 
 use std::sync::Arc;
 
@@ -36,7 +38,8 @@ use super::{ExecutableUnityCatalogStatement, ExecuteUnityCatalogPlanNode, UnityC
 pub struct UnityClientExtension(pub UnityCatalogClient);
 
 /// Session-config extension carrying the [`UnityObjectStoreFactory`] used by the
-/// managed-table write paths (bulk ingest append and managed `CREATE TABLE`).
+/// managed-table write paths (bulk ingest append, managed `CREATE TABLE` and
+/// materialized views).
 /// Unlike [`UnityClientExtension`] (which only exposes the catalog client), the
 /// factory also vends fresh per-table object-store credentials, which the kernel
 /// committer needs to stage and publish commits. Set on the session state
@@ -74,10 +77,16 @@ impl ExtensionPlanner for UnityCatalogPlanner {
                 )
             })?;
 
+        let factory = session_state
+            .config()
+            .get_extension::<UnityFactoryExt>()
+            .map(|ext| ext.0.clone());
+
         let schema: SchemaRef = Arc::new(node.statement.return_schema().as_arrow().clone());
         let partition = Arc::new(UnityCatalogPartitionStream {
             statement: node.statement.clone(),
             client: client.0.clone(),
+            factory,
             schema: schema.clone(),
         });
         let exec = StreamingTableExec::try_new(
@@ -97,6 +106,7 @@ impl ExtensionPlanner for UnityCatalogPlanner {
 struct UnityCatalogPartitionStream {
     statement: UnityCatalogStatement,
     client: UnityCatalogClient,
+    factory: Option<Arc<UnityObjectStoreFactory>>,
     schema: SchemaRef,
 }
 
@@ -116,8 +126,9 @@ impl PartitionStream for UnityCatalogPartitionStream {
     fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
         let statement = self.statement.clone();
         let client = self.client.clone();
+        let factory = self.factory.clone();
         let schema = self.schema.clone();
-        let fut = async move { statement.execute(client).await };
+        let fut = async move { statement.execute_with_factory(client, factory).await };
         Box::pin(RecordBatchStreamAdapter::new(
             schema,
             futures::stream::once(fut),
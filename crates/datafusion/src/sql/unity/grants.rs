@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{RecordBatch, StringArray};
+use datafusion::common::{DataFusionError, Result, plan_datafusion_err};
+use datafusion::sql::sqlparser::ast::{ObjectName, ObjectNamePart};
+use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::models::permissions::v1::{PermissionsChange, PermissionsList};
+
+use crate::sql::unity::{GRANTS_UC_RETURN_SCHEMA, SecurableKind};
+
+/// `GRANT <privilege>[, …] ON <securable> <name> TO <principal>`. Returns the
+/// grants on the securable after the change.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct GrantStatement {
+    /// Privileges as written, e.g. `SELECT` or `USE CATALOG`; normalized to
+    /// their wire names (`USE_CATALOG`) on execution.
+    pub privileges: Vec<String>,
+    pub securable: SecurableKind,
+    pub name: ObjectName,
+    pub principal: String,
+}
+
+impl GrantStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let change = PermissionsChange {
+            principal: self.principal.clone(),
+            add: self
+                .privileges
+                .iter()
+                .map(|p| normalize_privilege(p))
+                .collect(),
+            remove: vec![],
+        };
+        update_permissions(&client, self.securable, &self.name, change).await
+    }
+}
+
+/// `REVOKE <privilege>[, …] ON <securable> <name> FROM <principal>`. Returns
+/// the grants on the securable after the change.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct RevokeStatement {
+    pub privileges: Vec<String>,
+    pub securable: SecurableKind,
+    pub name: ObjectName,
+    pub principal: String,
+}
+
+impl RevokeStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let change = PermissionsChange {
+            principal: self.principal.clone(),
+            add: vec![],
+            remove: self
+                .privileges
+                .iter()
+                .map(|p| normalize_privilege(p))
+                .collect(),
+        };
+        update_permissions(&client, self.securable, &self.name, change).await
+    }
+}
+
+/// `SHOW GRANTS [<principal>] ON <securable> <name>` — one row per privilege
+/// held on the securable, optionally only those of `principal`.
+///
+/// Unity Catalog lists permissions per securable, so the `ON` clause is
+/// required; there is no API to list everything a principal holds.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct ShowGrantsStatement {
+    pub securable: SecurableKind,
+    pub name: ObjectName,
+    pub principal: Option<String>,
+}
+
+impl ShowGrantsStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let name = full_name(&self.name)?;
+        let list = client
+            .permissions(self.securable.permissions_type(), &name)
+            .get(self.principal.as_deref())
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        grants_to_batch(self.securable, &name, list)
+    }
+}
+
+async fn update_permissions(
+    client: &UnityCatalogClient,
+    securable: SecurableKind,
+    name: &ObjectName,
+    change: PermissionsChange,
+) -> Result<RecordBatch> {
+    let name = full_name(name)?;
+    let list = client
+        .permissions(securable.permissions_type(), &name)
+        .update(vec![change])
+        .await
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    grants_to_batch(securable, &name, list)
+}
+
+/// The securable's full name as Unity Catalog spells it: the identifiers'
+/// values joined by `.`, without the quotes [`ObjectName`]'s `Display` keeps.
+fn full_name(name: &ObjectName) -> Result<String> {
+    let parts = name
+        .0
+        .iter()
+        .map(|part| match part {
+            ObjectNamePart::Identifier(ident) => Ok(ident.value.as_str()),
+            _ => Err(plan_datafusion_err!("'{name}' is not a securable name")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(parts.join("."))
+}
+
+/// Flatten a permissions list into [`GRANTS_UC_RETURN_SCHEMA`] rows, one per
+/// `(principal, privilege)` pair.
+fn grants_to_batch(
+    securable: SecurableKind,
+    full_name: &str,
+    list: PermissionsList,
+) -> Result<RecordBatch> {
+    let (principals, privileges): (Vec<_>, Vec<_>) = list
+        .privilege_assignments
+        .into_iter()
+        .flat_map(|assignment| {
+            let principal = assignment.principal;
+            assignment
+                .privileges
+                .into_iter()
+                .map(move |privilege| (principal.clone(), privilege))
+        })
+        .unzip();
+    let types = vec![securable.as_str(); principals.len()];
+    let names = vec![full_name; principals.len()];
+    let schema = Arc::new(GRANTS_UC_RETURN_SCHEMA.as_arrow().clone());
+    Ok(RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(StringArray::from(principals)),
+            Arc::new(StringArray::from(privileges)),
+            Arc::new(StringArray::from(types)),
+            Arc::new(StringArray::from(names)),
+        ],
+    )?)
+}
+
+/// The wire name of a privilege as written in SQL: upper-cased, with the words
+/// of multi-word privileges (`USE CATALOG`, `ALL PRIVILEGES`) joined by `_`.
+pub(crate) fn normalize_privilege(privilege: &str) -> String {
+    privilege
+        .split_whitespace()
+        .map(str::to_ascii_uppercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::sql::sqlparser::ast::Ident;
+    use unitycatalog_common::models::permissions::v1::PrivilegeAssignment;
+
+    use super::*;
+
+    #[test]
+    fn privileges_normalize_to_wire_names() {
+        assert_eq!(normalize_privilege("select"), "SELECT");
+        assert_eq!(normalize_privilege("USE  catalog"), "USE_CATALOG");
+        assert_eq!(normalize_privilege("ALL PRIVILEGES"), "ALL_PRIVILEGES");
+        assert_eq!(normalize_privilege("CREATE_TABLE"), "CREATE_TABLE");
+    }
+
+    #[test]
+    fn grants_flatten_to_one_row_per_privilege() {
+        let list = PermissionsList {
+            privilege_assignments: vec![
+                PrivilegeAssignment {
+                    principal: "analysts".to_string(),
+                    privileges: vec!["USE_SCHEMA".to_string(), "SELECT".to_string()],
+                },
+                PrivilegeAssignment {
+                    principal: "etl".to_string(),
+                    privileges: vec!["MODIFY".to_string()],
+                },
+            ],
+        };
+        let batch = grants_to_batch(SecurableKind::Schema, "c.s", list).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        let principals = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(principals.value(2), "etl");
+        let names = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(names.value(0), "c.s");
+    }
+
+    #[test]
+    fn full_names_drop_identifier_quotes() {
+        let name: ObjectName = vec![Ident::with_quote('`', "my-cat"), Ident::new("s")].into();
+        assert_eq!(name.to_string(), "`my-cat`.s");
+        assert_eq!(full_name(&name).unwrap(), "my-cat.s");
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::common::{DataFusionError, Result};
+use datafusion::sql::sqlparser::ast::ObjectName;
+use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::models::tables::v1::TableType;
+use unitycatalog_object_store::UnityObjectStoreFactory;
+
+use crate::managed::{
+    MaterializedViewRefresh, RefreshMode, create_materialized_view, refresh_materialized_view,
+};
+use crate::sql::unity::create_response_to_batch;
+use crate::sql::unity::tables::split_table_name;
+
+/// Engine identifier recorded in the `commitInfo` of materialized-view refreshes.
+const ENGINE_INFO: &str = concat!("datafusion-unitycatalog/", env!("CARGO_PKG_VERSION"));
+
+/// `CREATE [OR REPLACE] MATERIALIZED VIEW [IF NOT EXISTS] <catalog>.<schema>.<view>
+/// [COMMENT str] AS <query>` — a Unity Catalog materialized view: a managed Delta
+/// table populated with the query's result. Lowered to an `Extension` node like
+/// the other UC DDL so it rides through the SQL DDL gate and is authorized by Cedar.
+///
+/// As for a [`CreateViewStatement`](super::CreateViewStatement) the query text is
+/// stored verbatim and the server derives the dependencies from it, so every
+/// relation it reads must be fully qualified.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct CreateMaterializedViewStatement {
+    pub name: ObjectName,
+    /// `OR REPLACE` — drops an existing materialized view of the same name first.
+    /// UC has no replace API, so the drop and the create are not atomic.
+    pub or_replace: bool,
+    pub if_not_exists: bool,
+    /// The raw `AS <query>` text.
+    pub query: String,
+    pub comment: Option<String>,
+}
+
+impl CreateMaterializedViewStatement {
+    pub(crate) async fn execute(
+        &self,
+        client: UnityCatalogClient,
+        factory: Arc<UnityObjectStoreFactory>,
+    ) -> Result<RecordBatch> {
+        let (catalog, schema, view) = split_table_name(&self.name)?;
+
+        if self.or_replace || self.if_not_exists {
+            let existing = match client.table(&catalog, &schema, &view).get().await {
+                Ok(existing) => Some(existing),
+                Err(e) if e.is_not_found() => None,
+                Err(e) => return Err(DataFusionError::External(Box::new(e))),
+            };
+            match existing {
+                Some(_) if self.if_not_exists => {
+                    return create_response_to_batch(
+                        self.name.to_string(),
+                        "MaterializedView",
+                        "exists",
+                    );
+                }
+                Some(existing) if existing.table_type != TableType::MaterializedView as i32 => {
+                    return Err(DataFusionError::Execution(format!(
+                        "cannot replace '{}': it is not a materialized view",
+                        self.name
+                    )));
+                }
+                Some(_) => match client.table(&catalog, &schema, &view).delete().await {
+                    Ok(()) => {}
+                    // Dropped concurrently; the create below still replaces it.
+                    Err(e) if e.is_not_found() => {}
+                    Err(e) => return Err(DataFusionError::External(Box::new(e))),
+                },
+                None => {}
+            }
+        }
+
+        let refresh = create_materialized_view(
+            factory,
+            &catalog,
+            &schema,
+            &view,
+            &self.query,
+            self.comment.clone(),
+            ENGINE_INFO,
+        )
+        .await
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        refresh_to_batch(&self.name, &refresh)
+    }
+}
+
+/// `REFRESH MATERIALIZED VIEW <catalog>.<schema>.<view> [FULL]` — recompute a
+/// materialized view from its sources. Without `FULL` an unchanged view is left
+/// as is and a view over append-only sources is refreshed incrementally (see
+/// [`refresh_materialized_view`]).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct RefreshMaterializedViewStatement {
+    pub name: ObjectName,
+    /// `FULL` — always recompute the whole view.
+    pub full: bool,
+}
+
+impl RefreshMaterializedViewStatement {
+    pub(crate) async fn execute(
+        &self,
+        factory: Arc<UnityObjectStoreFactory>,
+    ) -> Result<RecordBatch> {
+        let (catalog, schema, view) = split_table_name(&self.name)?;
+        let mode = if self.full {
+            RefreshMode::Full
+        } else {
+            RefreshMode::Auto
+        };
+        let refresh =
+            refresh_materialized_view(factory, &catalog, &schema, &view, mode, ENGINE_INFO)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        refresh_to_batch(&self.name, &refresh)
+    }
+}
+
+fn refresh_to_batch(name: &ObjectName, refresh: &MaterializedViewRefresh) -> Result<RecordBatch> {
+    create_response_to_batch(
+        name.to_string(),
+        "MaterializedView",
+        serde_json::json!({
+            "refresh": refresh.kind.as_str(),
+            "version": refresh.version,
+            "rows_written": refresh.rows_written,
+            "source_versions": refresh.source_versions,
+        }),
+    )
+}
@@ -8,25 +8,39 @@ use datafusion::arrow::{
     datatypes::{DataType, Field, Schema},
 };
 use datafusion::{
-    common::{DFSchema, DFSchemaRef, internal_err},
+    common::{DFSchema, DFSchemaRef, DataFusionError, internal_err},
     error::Result,
     logical_expr::{LogicalPlan, UserDefinedLogicalNodeCore},
     prelude::Expr,
+    sql::sqlparser::ast::{ObjectName, Value},
 };
 use serde::Serialize;
 use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_object_store::UnityObjectStoreFactory;
 
+pub use self::alter::*;
 pub use self::catalogs::*;
 pub use self::exec::*;
 pub use self::functions::*;
+pub use self::grants::*;
+pub use self::materialized_views::*;
 pub use self::schemas::*;
+pub use self::show::*;
 pub use self::tables::*;
+pub use self::views::*;
+pub use self::volumes::*;
 
+mod alter;
 mod catalogs;
 mod exec;
 mod functions;
+mod grants;
+mod materialized_views;
 mod schemas;
+mod show;
 mod tables;
+mod views;
+mod volumes;
 
 /// A Unity Catalog DDL statement that can be executed against a live Unity
 /// Catalog instance, returning a single result [`RecordBatch`].
@@ -57,6 +71,62 @@ pub(crate) static DROP_UC_RETURN_SCHEMA: LazyLock<DFSchemaRef> = LazyLock::new(|
     DFSchemaRef::new(DFSchema::try_from(arrow_schema).unwrap())
 });
 
+/// The kind of securable an `ALTER`, `SHOW` or `DESCRIBE` statement addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum SecurableKind {
+    Catalog,
+    Schema,
+    Table,
+    Volume,
+    Function,
+}
+
+impl SecurableKind {
+    /// The `securable_type` reported in result batches, e.g. `"Table"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecurableKind::Catalog => "Catalog",
+            SecurableKind::Schema => "Schema",
+            SecurableKind::Table => "Table",
+            SecurableKind::Volume => "Volume",
+            SecurableKind::Function => "Function",
+        }
+    }
+
+    /// The entity type tags are assigned under, or `None` if the kind cannot
+    /// be tagged.
+    pub(crate) fn tag_entity_type(&self) -> Option<&'static str> {
+        match self {
+            SecurableKind::Catalog => Some("catalogs"),
+            SecurableKind::Schema => Some("schemas"),
+            SecurableKind::Table => Some("tables"),
+            SecurableKind::Volume => Some("volumes"),
+            SecurableKind::Function => None,
+        }
+    }
+
+    /// The `securable_type` path segment of the permissions API.
+    pub(crate) fn permissions_type(&self) -> &'static str {
+        match self {
+            SecurableKind::Catalog => "catalog",
+            SecurableKind::Schema => "schema",
+            SecurableKind::Table => "table",
+            SecurableKind::Volume => "volume",
+            SecurableKind::Function => "function",
+        }
+    }
+}
+
+pub(crate) static GRANTS_UC_RETURN_SCHEMA: LazyLock<DFSchemaRef> = LazyLock::new(|| {
+    let arrow_schema = Schema::new(vec![
+        Field::new("principal", DataType::Utf8, false),
+        Field::new("privilege", DataType::Utf8, false),
+        Field::new("securable_type", DataType::Utf8, false),
+        Field::new("securable_name", DataType::Utf8, false),
+    ]);
+    DFSchemaRef::new(DFSchema::try_from(arrow_schema).unwrap())
+});
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 pub enum UnityCatalogStatement {
     CreateCatalog(CreateCatalogStatement),
@@ -66,6 +136,19 @@ pub enum UnityCatalogStatement {
     CreateManagedTable(CreateManagedTableStatement),
     CreateFunction(CreateFunctionStatement),
     DropFunction(DropFunctionStatement),
+    CreateView(CreateViewStatement),
+    CreateMaterializedView(CreateMaterializedViewStatement),
+    RefreshMaterializedView(RefreshMaterializedViewStatement),
+    CreateVolume(CreateVolumeStatement),
+    DropVolume(DropVolumeStatement),
+    CreateExternalTable(CreateExternalTableStatement),
+    DropTable(DropTableStatement),
+    Alter(AlterStatement),
+    Show(ShowStatement),
+    Describe(DescribeStatement),
+    Grant(GrantStatement),
+    Revoke(RevokeStatement),
+    ShowGrants(ShowGrantsStatement),
 }
 
 impl From<CreateCatalogStatement> for UnityCatalogStatement {
@@ -110,6 +193,84 @@ impl From<DropFunctionStatement> for UnityCatalogStatement {
     }
 }
 
+impl From<CreateViewStatement> for UnityCatalogStatement {
+    fn from(value: CreateViewStatement) -> Self {
+        UnityCatalogStatement::CreateView(value)
+    }
+}
+
+impl From<CreateMaterializedViewStatement> for UnityCatalogStatement {
+    fn from(value: CreateMaterializedViewStatement) -> Self {
+        UnityCatalogStatement::CreateMaterializedView(value)
+    }
+}
+
+impl From<RefreshMaterializedViewStatement> for UnityCatalogStatement {
+    fn from(value: RefreshMaterializedViewStatement) -> Self {
+        UnityCatalogStatement::RefreshMaterializedView(value)
+    }
+}
+
+impl From<CreateVolumeStatement> for UnityCatalogStatement {
+    fn from(value: CreateVolumeStatement) -> Self {
+        UnityCatalogStatement::CreateVolume(value)
+    }
+}
+
+impl From<DropVolumeStatement> for UnityCatalogStatement {
+    fn from(value: DropVolumeStatement) -> Self {
+        UnityCatalogStatement::DropVolume(value)
+    }
+}
+
+impl From<CreateExternalTableStatement> for UnityCatalogStatement {
+    fn from(value: CreateExternalTableStatement) -> Self {
+        UnityCatalogStatement::CreateExternalTable(value)
+    }
+}
+
+impl From<DropTableStatement> for UnityCatalogStatement {
+    fn from(value: DropTableStatement) -> Self {
+        UnityCatalogStatement::DropTable(value)
+    }
+}
+
+impl From<AlterStatement> for UnityCatalogStatement {
+    fn from(value: AlterStatement) -> Self {
+        UnityCatalogStatement::Alter(value)
+    }
+}
+
+impl From<ShowStatement> for UnityCatalogStatement {
+    fn from(value: ShowStatement) -> Self {
+        UnityCatalogStatement::Show(value)
+    }
+}
+
+impl From<DescribeStatement> for UnityCatalogStatement {
+    fn from(value: DescribeStatement) -> Self {
+        UnityCatalogStatement::Describe(value)
+    }
+}
+
+impl From<GrantStatement> for UnityCatalogStatement {
+    fn from(value: GrantStatement) -> Self {
+        UnityCatalogStatement::Grant(value)
+    }
+}
+
+impl From<RevokeStatement> for UnityCatalogStatement {
+    fn from(value: RevokeStatement) -> Self {
+        UnityCatalogStatement::Revoke(value)
+    }
+}
+
+impl From<ShowGrantsStatement> for UnityCatalogStatement {
+    fn from(value: ShowGrantsStatement) -> Self {
+        UnityCatalogStatement::ShowGrants(value)
+    }
+}
+
 impl UnityCatalogStatement {
     pub fn command_name(&self) -> &str {
         use UnityCatalogStatement::*;
@@ -122,6 +283,37 @@ impl UnityCatalogStatement {
             CreateManagedTable(_) => "CreateManagedTable",
             CreateFunction(_) => "CreateFunction",
             DropFunction(_) => "DropFunction",
+            CreateView(_) => "CreateView",
+            CreateMaterializedView(_) => "CreateMaterializedView",
+            RefreshMaterializedView(_) => "RefreshMaterializedView",
+            CreateVolume(_) => "CreateVolume",
+            DropVolume(_) => "DropVolume",
+            CreateExternalTable(_) => "CreateExternalTable",
+            DropTable(_) => "DropTable",
+            Alter(cmd) => match cmd.securable {
+                SecurableKind::Catalog => "AlterCatalog",
+                SecurableKind::Schema => "AlterSchema",
+                SecurableKind::Table => "AlterTable",
+                SecurableKind::Volume => "AlterVolume",
+                SecurableKind::Function => "AlterFunction",
+            },
+            Show(cmd) => match cmd.kind {
+                SecurableKind::Catalog => "ShowCatalogs",
+                SecurableKind::Schema => "ShowSchemas",
+                SecurableKind::Table => "ShowTables",
+                SecurableKind::Volume => "ShowVolumes",
+                SecurableKind::Function => "ShowFunctions",
+            },
+            Describe(cmd) => match cmd.kind {
+                SecurableKind::Catalog => "DescribeCatalog",
+                SecurableKind::Schema => "DescribeSchema",
+                SecurableKind::Table => "DescribeTable",
+                SecurableKind::Volume => "DescribeVolume",
+                SecurableKind::Function => "DescribeFunction",
+            },
+            Grant(_) => "Grant",
+            Revoke(_) => "Revoke",
+            ShowGrants(_) => "ShowGrants",
         }
     }
 
@@ -154,10 +346,127 @@ impl UnityCatalogStatement {
                 "DropFunction: name={} if_exists={}",
                 cmd.name, cmd.if_exists
             ),
+            CreateView(cmd) => write!(
+                f,
+                "CreateView: name={} or_replace={} if_not_exists={}",
+                cmd.name, cmd.or_replace, cmd.if_not_exists
+            ),
+            CreateMaterializedView(cmd) => write!(
+                f,
+                "CreateMaterializedView: name={} or_replace={} if_not_exists={}",
+                cmd.name, cmd.or_replace, cmd.if_not_exists
+            ),
+            RefreshMaterializedView(cmd) => write!(
+                f,
+                "RefreshMaterializedView: name={} full={}",
+                cmd.name, cmd.full
+            ),
+            CreateVolume(cmd) => write!(
+                f,
+                "CreateVolume: name={} external={} if_not_exists={}",
+                cmd.name,
+                cmd.location.is_some(),
+                cmd.if_not_exists
+            ),
+            DropVolume(cmd) => write!(
+                f,
+                "DropVolume: name={} if_exists={}",
+                cmd.name, cmd.if_exists
+            ),
+            CreateExternalTable(cmd) => write!(
+                f,
+                "CreateExternalTable: name={} format={} columns={} if_not_exists={}",
+                cmd.name,
+                cmd.format.as_str_name(),
+                cmd.columns.len(),
+                cmd.if_not_exists
+            ),
+            DropTable(cmd) => write!(
+                f,
+                "DropTable: name={} if_exists={}",
+                cmd.name, cmd.if_exists
+            ),
+            Alter(cmd) => write!(
+                f,
+                "{}: name={} action={}",
+                self.command_name(),
+                cmd.name,
+                cmd.action.label()
+            ),
+            // `SHOW CATALOGS` has no parent securable, so no `name=` token.
+            Show(cmd) => {
+                write!(f, "{}:", self.command_name())?;
+                if let Some(parent) = &cmd.parent {
+                    write!(f, " name={parent}")?;
+                }
+                if let Some(pattern) = &cmd.pattern {
+                    write!(f, " like={pattern:?}")?;
+                }
+                Ok(())
+            }
+            Describe(cmd) => write!(f, "{}: name={}", self.command_name(), cmd.name),
+            Grant(cmd) => write!(
+                f,
+                "Grant: name={} securable_type={} privileges={} principal={}",
+                cmd.name,
+                cmd.securable.permissions_type(),
+                cmd.privileges.join(","),
+                cmd.principal
+            ),
+            Revoke(cmd) => write!(
+                f,
+                "Revoke: name={} securable_type={} privileges={} principal={}",
+                cmd.name,
+                cmd.securable.permissions_type(),
+                cmd.privileges.join(","),
+                cmd.principal
+            ),
+            ShowGrants(cmd) => {
+                write!(
+                    f,
+                    "ShowGrants: name={} securable_type={}",
+                    cmd.name,
+                    cmd.securable.permissions_type()
+                )?;
+                if let Some(principal) = &cmd.principal {
+                    write!(f, " principal={principal}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+impl UnityCatalogStatement {
+    /// Execute the statement like [`ExecutableUnityCatalogStatement::execute`],
+    /// with the [`UnityObjectStoreFactory`] the materialized-view statements
+    /// need to read their sources and commit through the catalog.
+    pub(crate) async fn execute_with_factory(
+        &self,
+        client: UnityCatalogClient,
+        factory: Option<Arc<UnityObjectStoreFactory>>,
+    ) -> Result<RecordBatch> {
+        use UnityCatalogStatement::*;
+
+        match (self, factory) {
+            (CreateMaterializedView(cmd), Some(factory)) => cmd.execute(client, factory).await,
+            (RefreshMaterializedView(cmd), Some(factory)) => cmd.execute(factory).await,
+            (CreateMaterializedView(_) | RefreshMaterializedView(_), None) => {
+                Err(self.needs_factory())
+            }
+            _ => self.execute(client).await,
+        }
+    }
+
+    fn needs_factory(&self) -> DataFusionError {
+        DataFusionError::Execution(format!(
+            "{} requires the Unity Catalog object store factory (UnityFactoryExt) \
+             on the session",
+            self.command_name()
+        ))
+    }
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Hash, Debug, Clone)]
 pub struct ExecuteUnityCatalogPlanNode {
     pub statement: UnityCatalogStatement,
@@ -199,10 +508,22 @@ impl ExecutableUnityCatalogStatement for UnityCatalogStatement {
         use UnityCatalogStatement::*;
 
         match &self {
-            CreateCatalog(_) | CreateSchema(_) | CreateManagedTable(_) | CreateFunction(_) => {
-                &CREATE_UC_RETURN_SCHEMA
+            CreateCatalog(_)
+            | CreateSchema(_)
+            | CreateManagedTable(_)
+            | CreateFunction(_)
+            | CreateView(_)
+            | CreateMaterializedView(_)
+            | RefreshMaterializedView(_)
+            | CreateVolume(_)
+            | CreateExternalTable(_)
+            | Alter(_)
+            | Show(_)
+            | Describe(_) => &CREATE_UC_RETURN_SCHEMA,
+            DropCatalog(_) | DropSchema(_) | DropFunction(_) | DropVolume(_) | DropTable(_) => {
+                &DROP_UC_RETURN_SCHEMA
             }
-            DropCatalog(_) | DropSchema(_) | DropFunction(_) => &DROP_UC_RETURN_SCHEMA,
+            Grant(_) | Revoke(_) | ShowGrants(_) => &GRANTS_UC_RETURN_SCHEMA,
         }
     }
 
@@ -217,6 +538,20 @@ impl ExecutableUnityCatalogStatement for UnityCatalogStatement {
             CreateManagedTable(cmd) => cmd.execute(client).await,
             CreateFunction(cmd) => cmd.execute(client).await,
             DropFunction(cmd) => cmd.execute(client).await,
+            CreateView(cmd) => cmd.execute(client).await,
+            // Materialized views read their sources and commit through the catalog,
+            // which needs the object store factory: see `execute_with_factory`.
+            CreateMaterializedView(_) | RefreshMaterializedView(_) => Err(self.needs_factory()),
+            CreateVolume(cmd) => cmd.execute(client).await,
+            DropVolume(cmd) => cmd.execute(client).await,
+            CreateExternalTable(cmd) => cmd.execute(client).await,
+            DropTable(cmd) => cmd.execute(client).await,
+            Alter(cmd) => cmd.execute(client).await,
+            Show(cmd) => cmd.execute(client).await,
+            Describe(cmd) => cmd.execute(client).await,
+            Grant(cmd) => cmd.execute(client).await,
+            Revoke(cmd) => cmd.execute(client).await,
+            ShowGrants(cmd) => cmd.execute(client).await,
         }
     }
 }
@@ -226,9 +561,20 @@ pub(crate) fn create_response_to_batch(
     type_name: impl ToString,
     object: impl Serialize,
 ) -> Result<RecordBatch> {
-    let names = vec![name.to_string()];
-    let types = vec![type_name.to_string()];
-    let values = vec![serde_json::to_string(&object).unwrap()];
+    securables_to_batch(
+        type_name,
+        vec![(name.to_string(), serde_json::to_string(&object).unwrap())],
+    )
+}
+
+/// A batch in the [`CREATE_UC_RETURN_SCHEMA`] shape with one row per
+/// `(name, json)` securable, all of type `type_name`.
+pub(crate) fn securables_to_batch(
+    type_name: impl ToString,
+    securables: Vec<(String, String)>,
+) -> Result<RecordBatch> {
+    let types = vec![type_name.to_string(); securables.len()];
+    let (names, values): (Vec<_>, Vec<_>) = securables.into_iter().unzip();
     let schema = Arc::new(CREATE_UC_RETURN_SCHEMA.as_arrow().clone());
     Ok(RecordBatch::try_new(
         schema,
@@ -259,13 +605,41 @@ pub(crate) fn drop_response_to_batch(
     )?)
 }
 
+/// Split a three-part `<catalog>.<schema>.<name>` identifier; `kind` names the
+/// securable in the error message (e.g. `"Volume"`).
+pub(crate) fn split_three_part_name(
+    name: &ObjectName,
+    kind: &str,
+) -> Result<(String, String, String)> {
+    match name.0.as_slice() {
+        [catalog, schema, object] => {
+            Ok((catalog.to_string(), schema.to_string(), object.to_string()))
+        }
+        _ => Err(DataFusionError::Execution(format!(
+            "{kind} name '{name}' must be a three-part identifier (<catalog>.<schema>.<{}>)",
+            kind.to_ascii_lowercase()
+        ))),
+    }
+}
+
+/// Render a parsed option [`Value`] as the plain string UC properties expect.
+pub(crate) fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::SingleQuotedString(s)
+        | Value::DoubleQuotedString(s)
+        | Value::EscapedStringLiteral(s) => s.clone(),
+        Value::Number(n, _) => n.clone(),
+        other => other.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use datafusion::sql::sqlparser::ast::Ident;
 
     use super::*;
     use crate::sql::{
-        CreateCatalogStatement, CreateFunctionStatement, DropCatalogStatement,
+        CreateCatalogStatement, CreateFunctionStatement, CreateViewStatement, DropCatalogStatement,
         DropFunctionStatement, DropSchemaStatement, FunctionLanguage, SqlDataAccessKind,
     };
     use datafusion::sql::sqlparser::ast::DataType as SqlDataType;
@@ -286,6 +660,16 @@ mod tests {
         }
     }
 
+    fn sample_view() -> CreateViewStatement {
+        CreateViewStatement {
+            name: name(&["c", "s", "v"]),
+            or_replace: false,
+            if_not_exists: false,
+            query: "SELECT * FROM c.s.t".to_string(),
+            comment: None,
+        }
+    }
+
     fn name(parts: &[&str]) -> datafusion::sql::sqlparser::ast::ObjectName {
         parts
             .iter()
@@ -315,7 +699,8 @@ mod tests {
         }
         .into();
         let create_function: UnityCatalogStatement = sample_function().into();
-        for stmt in [create_catalog, create_schema, create_function] {
+        let create_view: UnityCatalogStatement = sample_view().into();
+        for stmt in [create_catalog, create_schema, create_function, create_view] {
             assert_eq!(stmt.return_schema(), &*CREATE_UC_RETURN_SCHEMA);
             // The logical node mirrors the statement's return schema.
             let node = ExecuteUnityCatalogPlanNode { statement: stmt };
@@ -350,7 +735,7 @@ mod tests {
     #[test]
     fn command_names_are_stable() {
         // These names are the contract the Cedar visitor matches on.
-        let cases: [(UnityCatalogStatement, &str); 4] = [
+        let cases: [(UnityCatalogStatement, &str); 5] = [
             (
                 CreateCatalogStatement {
                     name: name(&["c"]),
@@ -374,6 +759,7 @@ mod tests {
                 "DropSchema",
             ),
             (sample_function().into(), "CreateFunction"),
+            (sample_view().into(), "CreateView"),
             (
                 DropFunctionStatement {
                     name: name(&["c", "s", "f"]),
@@ -393,7 +779,7 @@ mod tests {
         // Cedar reads the securable from the `name=<...>` token in the node's
         // `Display`/`fmt_for_explain` output — this contract must hold across the
         // cross-repo boundary.
-        let cases: [(UnityCatalogStatement, &str, &str); 6] = [
+        let cases: [(UnityCatalogStatement, &str, &str); 7] = [
             (
                 CreateCatalogStatement {
                     name: name(&["my_catalog"]),
@@ -441,6 +827,7 @@ mod tests {
                 "c.s",
             ),
             (sample_function().into(), "CreateFunction", "c.s.f"),
+            (sample_view().into(), "CreateView", "c.s.v"),
             (
                 DropFunctionStatement {
                     name: name(&["c", "s", "f"]),
@@ -464,6 +851,205 @@ mod tests {
         }
     }
 
+    #[test]
+    fn extended_ddl_contract() {
+        // (statement, command name, securable in `name=`, uses the drop schema)
+        let cases: Vec<(UnityCatalogStatement, &str, &str, bool)> = vec![
+            (
+                CreateVolumeStatement {
+                    name: name(&["c", "s", "v"]),
+                    if_not_exists: false,
+                    location: None,
+                    comment: None,
+                }
+                .into(),
+                "CreateVolume",
+                "c.s.v",
+                false,
+            ),
+            (
+                DropVolumeStatement {
+                    name: name(&["c", "s", "v"]),
+                    if_exists: true,
+                }
+                .into(),
+                "DropVolume",
+                "c.s.v",
+                true,
+            ),
+            (
+                CreateExternalTableStatement {
+                    name: name(&["c", "s", "t"]),
+                    columns: vec![],
+                    format: unitycatalog_common::models::tables::v1::DataSourceFormat::Delta,
+                    location: url::Url::parse("s3://bucket/t").unwrap(),
+                    if_not_exists: false,
+                    comment: None,
+                    properties: None,
+                }
+                .into(),
+                "CreateExternalTable",
+                "c.s.t",
+                false,
+            ),
+            (
+                DropTableStatement {
+                    name: name(&["c", "s", "t"]),
+                    if_exists: false,
+                }
+                .into(),
+                "DropTable",
+                "c.s.t",
+                true,
+            ),
+            (
+                AlterStatement {
+                    securable: SecurableKind::Catalog,
+                    name: name(&["c"]),
+                    action: AlterAction::SetOwner("admins".to_string()),
+                }
+                .into(),
+                "AlterCatalog",
+                "c",
+                false,
+            ),
+            (
+                AlterStatement {
+                    securable: SecurableKind::Table,
+                    name: name(&["c", "s", "t"]),
+                    action: AlterAction::UnsetTags(vec!["pii".to_string()]),
+                }
+                .into(),
+                "AlterTable",
+                "c.s.t",
+                false,
+            ),
+            (
+                ShowStatement {
+                    kind: SecurableKind::Table,
+                    parent: Some(name(&["c", "s"])),
+                    pattern: Some("sales*".to_string()),
+                }
+                .into(),
+                "ShowTables",
+                "c.s",
+                false,
+            ),
+            (
+                ShowStatement {
+                    kind: SecurableKind::Catalog,
+                    parent: None,
+                    pattern: None,
+                }
+                .into(),
+                "ShowCatalogs",
+                "",
+                false,
+            ),
+            (
+                DescribeStatement {
+                    kind: SecurableKind::Volume,
+                    name: name(&["c", "s", "v"]),
+                }
+                .into(),
+                "DescribeVolume",
+                "c.s.v",
+                false,
+            ),
+            (
+                CreateMaterializedViewStatement {
+                    name: name(&["c", "s", "mv"]),
+                    or_replace: false,
+                    if_not_exists: true,
+                    query: "SELECT id FROM c.s.t".to_string(),
+                    comment: None,
+                }
+                .into(),
+                "CreateMaterializedView",
+                "c.s.mv",
+                false,
+            ),
+            (
+                RefreshMaterializedViewStatement {
+                    name: name(&["c", "s", "mv"]),
+                    full: true,
+                }
+                .into(),
+                "RefreshMaterializedView",
+                "c.s.mv",
+                false,
+            ),
+        ];
+        for (stmt, expected_name, expected_securable, drops) in cases {
+            let expected_schema = if drops {
+                &*DROP_UC_RETURN_SCHEMA
+            } else {
+                &*CREATE_UC_RETURN_SCHEMA
+            };
+            assert_eq!(stmt.return_schema(), expected_schema);
+            let node = ExecuteUnityCatalogPlanNode { statement: stmt };
+            assert_eq!(node.name(), expected_name);
+            let rendered = format!("{}", DisplayNode(&node));
+            let securable = rendered
+                .split("name=")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next())
+                .unwrap_or("");
+            assert_eq!(securable, expected_securable, "rendered: {rendered}");
+        }
+    }
+
+    #[test]
+    fn grant_statements_contract() {
+        let grant: UnityCatalogStatement = GrantStatement {
+            privileges: vec!["SELECT".to_string(), "USE SCHEMA".to_string()],
+            securable: SecurableKind::Schema,
+            name: name(&["c", "s"]),
+            principal: "analysts".to_string(),
+        }
+        .into();
+        let revoke: UnityCatalogStatement = RevokeStatement {
+            privileges: vec!["MODIFY".to_string()],
+            securable: SecurableKind::Table,
+            name: name(&["c", "s", "t"]),
+            principal: "etl".to_string(),
+        }
+        .into();
+        let show: UnityCatalogStatement = ShowGrantsStatement {
+            securable: SecurableKind::Catalog,
+            name: name(&["c"]),
+            principal: None,
+        }
+        .into();
+        for (stmt, expected_name, expected_securable) in [
+            (grant, "Grant", "c.s"),
+            (revoke, "Revoke", "c.s.t"),
+            (show, "ShowGrants", "c"),
+        ] {
+            assert_eq!(stmt.return_schema(), &*GRANTS_UC_RETURN_SCHEMA);
+            let node = ExecuteUnityCatalogPlanNode { statement: stmt };
+            assert_eq!(node.name(), expected_name);
+            let rendered = format!("{}", DisplayNode(&node));
+            let securable = rendered
+                .split("name=")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next())
+                .unwrap_or("");
+            assert_eq!(securable, expected_securable, "rendered: {rendered}");
+        }
+    }
+
+    #[test]
+    fn split_three_part_name_names_the_kind() {
+        let (c, s, v) = split_three_part_name(&name(&["c", "s", "v"]), "Volume").unwrap();
+        assert_eq!((c.as_str(), s.as_str(), v.as_str()), ("c", "s", "v"));
+        let err = split_three_part_name(&name(&["s", "v"]), "Volume").unwrap_err();
+        assert!(
+            err.to_string().contains("<catalog>.<schema>.<volume>"),
+            "{err}"
+        );
+    }
+
     /// Mirror the Cedar visitor's `DisplayNode` wrapper so the contract test
     /// exercises the same `fmt_for_explain` path Cedar relies on.
     struct DisplayNode<'a>(&'a ExecuteUnityCatalogPlanNode);
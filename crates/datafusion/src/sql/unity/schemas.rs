@@ -4,7 +4,7 @@ use datafusion::sql::sqlparser::ast::{ObjectName, Value};
 use unitycatalog_client::UnityCatalogClient;
 use url::Url;
 
-use crate::sql::unity::{create_response_to_batch, drop_response_to_batch};
+use crate::sql::unity::{create_response_to_batch, drop_response_to_batch, value_to_string};
 
 /// Split a schema [`ObjectName`] into `(catalog, schema)`.
 ///
@@ -12,7 +12,7 @@ use crate::sql::unity::{create_response_to_batch, drop_response_to_batch};
 /// Databricks SQL session, hydrofoil has no notion of a "current catalog" to
 /// resolve a bare schema name against, so a one-part name is rejected with a
 /// clear error directing the caller to qualify it.
-fn split_schema_name(name: &ObjectName) -> Result<(String, String)> {
+pub(crate) fn split_schema_name(name: &ObjectName) -> Result<(String, String)> {
     match name.0.as_slice() {
         [catalog, schema] => Ok((catalog.to_string(), schema.to_string())),
         [_] => Err(DataFusionError::Execution(format!(
@@ -81,14 +81,3 @@ impl DropSchemaStatement {
         drop_response_to_batch(self.name.to_string(), "Schema", "success")
     }
 }
-
-/// Render a parsed option [`Value`] as the plain string UC properties expect.
-fn value_to_string(value: &Value) -> String {
-    match value {
-        Value::SingleQuotedString(s)
-        | Value::DoubleQuotedString(s)
-        | Value::EscapedStringLiteral(s) => s.clone(),
-        Value::Number(n, _) => n.clone(),
-        other => other.to_string(),
-    }
-}
@@ -0,0 +1,235 @@
+use datafusion::arrow::array::RecordBatch;
+use datafusion::common::{DataFusionError, Result};
+use datafusion::sql::sqlparser::ast::ObjectName;
+use futures::TryStreamExt;
+use serde::Serialize;
+use unitycatalog_client::UnityCatalogClient;
+
+use crate::sql::unity::schemas::split_schema_name;
+use crate::sql::unity::tables::split_table_name;
+use crate::sql::unity::{
+    SecurableKind, create_response_to_batch, securables_to_batch, split_three_part_name,
+};
+
+/// `SHOW CATALOGS|SCHEMAS|TABLES|VOLUMES|FUNCTIONS [IN <parent>] [[LIKE] 'pattern']`.
+/// Returns one row per securable, filtered by name against the optional pattern
+/// (see [`matches_pattern`]).
+///
+/// Catalogs take no parent, schemas need a catalog, and the rest a
+/// `<catalog>.<schema>` parent.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct ShowStatement {
+    pub kind: SecurableKind,
+    pub parent: Option<ObjectName>,
+    pub pattern: Option<String>,
+}
+
+impl ShowStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let rows = match self.kind {
+            SecurableKind::Catalog => {
+                let items = client
+                    .list_catalogs()
+                    .into_stream()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                self.rows(items, |c| (c.name.clone(), c.name.clone()))
+            }
+            SecurableKind::Schema => {
+                let catalog = self.catalog_parent()?;
+                let items = client
+                    .list_schemas(catalog)
+                    .into_stream()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                self.rows(items, |s| (s.name.clone(), s.full_name.clone()))
+            }
+            SecurableKind::Table => {
+                let (catalog, schema) = self.schema_parent()?;
+                let items = client
+                    .list_tables(catalog, schema)
+                    .into_stream()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                self.rows(items, |t| (t.name.clone(), t.full_name.clone()))
+            }
+            SecurableKind::Volume => {
+                let (catalog, schema) = self.schema_parent()?;
+                let items = client
+                    .list_volumes(catalog, schema)
+                    .into_stream()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                self.rows(items, |v| (v.name.clone(), v.full_name.clone()))
+            }
+            SecurableKind::Function => {
+                let (catalog, schema) = self.schema_parent()?;
+                let items = client
+                    .list_functions(catalog, schema)
+                    .into_stream()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                self.rows(items, |f| (f.name.clone(), f.full_name.clone()))
+            }
+        };
+        securables_to_batch(self.kind.as_str(), rows)
+    }
+
+    /// Keep the items whose short name matches the pattern and render each as
+    /// `(full_name, json)`.
+    fn rows<T: Serialize>(
+        &self,
+        items: Vec<T>,
+        names: impl Fn(&T) -> (String, String),
+    ) -> Vec<(String, String)> {
+        items
+            .into_iter()
+            .filter_map(|item| {
+                let (name, full_name) = names(&item);
+                if let Some(pattern) = &self.pattern
+                    && !matches_pattern(pattern, &name)
+                {
+                    return None;
+                }
+                Some((full_name, serde_json::to_string(&item).unwrap()))
+            })
+            .collect()
+    }
+
+    fn catalog_parent(&self) -> Result<String> {
+        match self.parent.as_ref().map(|p| p.0.as_slice()) {
+            Some([catalog]) => Ok(catalog.to_string()),
+            _ => Err(DataFusionError::Execution(
+                "SHOW SCHEMAS requires a catalog (SHOW SCHEMAS IN <catalog>)".to_string(),
+            )),
+        }
+    }
+
+    fn schema_parent(&self) -> Result<(String, String)> {
+        match &self.parent {
+            Some(parent) => split_schema_name(parent),
+            None => Err(DataFusionError::Execution(format!(
+                "SHOW {}S requires a schema (IN <catalog>.<schema>)",
+                self.kind.as_str().to_ascii_uppercase()
+            ))),
+        }
+    }
+}
+
+/// `DESCRIBE CATALOG|SCHEMA|TABLE|VOLUME|FUNCTION <name>` — the securable as
+/// Unity Catalog returns it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct DescribeStatement {
+    pub kind: SecurableKind,
+    pub name: ObjectName,
+}
+
+impl DescribeStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        describe_securable(&client, self.kind, &self.name).await
+    }
+}
+
+/// Fetch the securable `name` of `kind` and return it as a single-row batch in
+/// the `CREATE` result shape.
+pub(crate) async fn describe_securable(
+    client: &UnityCatalogClient,
+    kind: SecurableKind,
+    name: &ObjectName,
+) -> Result<RecordBatch> {
+    let label = kind.as_str();
+    match kind {
+        SecurableKind::Catalog => {
+            let catalog = match name.0.as_slice() {
+                [catalog] => catalog.to_string(),
+                _ => {
+                    return Err(DataFusionError::Execution(format!(
+                        "Catalog name '{name}' must be a single identifier"
+                    )));
+                }
+            };
+            let info = client
+                .catalog(catalog)
+                .get()
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            create_response_to_batch(name, label, info)
+        }
+        SecurableKind::Schema => {
+            let (catalog, schema) = split_schema_name(name)?;
+            let info = client
+                .schema(catalog, schema)
+                .get()
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            create_response_to_batch(name, label, info)
+        }
+        SecurableKind::Table => {
+            let (catalog, schema, table) = split_table_name(name)?;
+            let info = client
+                .table(catalog, schema, table)
+                .get()
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            create_response_to_batch(name, label, info)
+        }
+        SecurableKind::Volume => {
+            let (catalog, schema, volume) = split_three_part_name(name, label)?;
+            let info = client
+                .volume(catalog, schema, volume)
+                .get()
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            create_response_to_batch(name, label, info)
+        }
+        SecurableKind::Function => {
+            let (catalog, schema, function) = split_three_part_name(name, label)?;
+            let info = client
+                .function(catalog, schema, function)
+                .get()
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            create_response_to_batch(name, label, info)
+        }
+    }
+}
+
+/// Whether `name` matches a `SHOW … LIKE` pattern, Databricks style: `*`
+/// matches any run of characters, `|` separates alternatives, and the match
+/// ignores case.
+pub(crate) fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    pattern.split('|').any(|alternative| {
+        let alternative = alternative.trim().to_ascii_lowercase();
+        glob(alternative.as_bytes(), name.as_bytes())
+    })
+}
+
+fn glob(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|i| glob(rest, &name[i..])),
+        Some((c, rest)) => name.first() == Some(c) && glob(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn show_patterns_follow_databricks_semantics() {
+        assert!(matches_pattern("sales", "Sales"));
+        assert!(matches_pattern("sal*", "sales_2024"));
+        assert!(matches_pattern("*_2024", "sales_2024"));
+        assert!(matches_pattern("*", ""));
+        assert!(matches_pattern("orders | sal*", "sales"));
+        assert!(!matches_pattern("sal", "sales"));
+        assert!(!matches_pattern("orders|returns", "sales"));
+    }
+}
@@ -3,11 +3,18 @@ use std::sync::Arc;
 use datafusion::arrow::array::RecordBatch;
 use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
 use datafusion::common::{DataFusionError, Result};
-use datafusion::sql::sqlparser::ast::{ColumnDef, DataType as SqlDataType, ObjectName};
+use datafusion::sql::sqlparser::ast::{
+    ColumnDef, DataType as SqlDataType, ExactNumberInfo, ObjectName, TimezoneInfo, Value,
+};
 use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::models::tables::v1::{Column, DataSourceFormat, TableType};
+use url::Url;
 
+use crate::catalog::types::{arrow_to_uc_column, decimal};
 use crate::managed::create_managed_table;
-use crate::sql::unity::create_response_to_batch;
+use crate::sql::unity::{
+    create_response_to_batch, drop_response_to_batch, split_three_part_name, value_to_string,
+};
 
 /// `CREATE TABLE <catalog>.<schema>.<table> (<cols>) USING DELTA` — a Unity
 /// Catalog **managed** Delta table (no `LOCATION`; UC allocates the storage
@@ -62,29 +69,118 @@ impl CreateManagedTableStatement {
     }
 }
 
-/// Split a table [`ObjectName`] into `(catalog, schema, table)`.
+/// `CREATE [EXTERNAL] TABLE [IF NOT EXISTS] <catalog>.<schema>.<table> [(<cols>)]
+/// USING <format> LOCATION <url> [COMMENT str] [TBLPROPERTIES (...)]` — an
+/// external table registered over existing data. Unlike the managed path no
+/// data is written: the table is only recorded in Unity Catalog.
 ///
-/// Managed tables must be fully qualified — hydrofoil has no "current catalog"
-/// to resolve a shorter name against (matching [`super::schemas`]' two-part
-/// requirement for schemas).
-fn split_table_name(name: &ObjectName) -> Result<(String, String, String)> {
-    match name.0.as_slice() {
-        [catalog, schema, table] => {
-            Ok((catalog.to_string(), schema.to_string(), table.to_string()))
+/// Delta tables may omit the column list (the log carries the schema); every
+/// other format needs it, since readers resolve them from the stored columns.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct CreateExternalTableStatement {
+    pub name: ObjectName,
+    pub columns: Vec<ColumnDef>,
+    pub format: DataSourceFormat,
+    pub location: Url,
+    pub if_not_exists: bool,
+    pub comment: Option<String>,
+    pub properties: Option<Vec<(String, Value)>>,
+}
+
+impl CreateExternalTableStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let (catalog, schema, table) = split_table_name(&self.name)?;
+
+        if self.if_not_exists && client.table(&catalog, &schema, &table).get().await.is_ok() {
+            return create_response_to_batch(self.name.to_string(), "Table", "exists");
+        }
+
+        let columns = if self.columns.is_empty() {
+            if self.format != DataSourceFormat::Delta {
+                return Err(DataFusionError::Execution(format!(
+                    "external {} table '{}' requires a column list",
+                    self.format.as_str_name(),
+                    self.name
+                )));
+            }
+            Vec::new()
+        } else {
+            arrow_schema_to_uc_columns(&sql_columns_to_arrow_schema(&self.columns)?)?
+        };
+
+        let mut request = client
+            .create_table(table, schema, catalog, TableType::External, self.format)
+            .with_storage_location(Some(self.location.to_string()))
+            .with_columns(columns)
+            .with_comment(self.comment.clone());
+        if let Some(properties) = self.properties.as_ref() {
+            request = request.with_properties(
+                properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), value_to_string(v))),
+            );
+        }
+        let info = request
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        create_response_to_batch(self.name.to_string(), "Table", info)
+    }
+}
+
+/// `DROP TABLE [IF EXISTS] <catalog>.<schema>.<table>`. Dropping an external
+/// table leaves its data in place; a managed table's data is reclaimed by the
+/// catalog.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct DropTableStatement {
+    pub name: ObjectName,
+    pub if_exists: bool,
+}
+
+impl DropTableStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let (catalog, schema, table) = split_table_name(&self.name)?;
+        match client.table(catalog, schema, table).delete().await {
+            Ok(_) => drop_response_to_batch(self.name.to_string(), "Table", "success"),
+            Err(e) if self.if_exists && e.is_not_found() => {
+                drop_response_to_batch(self.name.to_string(), "Table", "not found")
+            }
+            Err(e) => Err(DataFusionError::External(Box::new(e))),
         }
-        _ => Err(DataFusionError::Execution(format!(
-            "Managed table name '{name}' must be a three-part identifier (<catalog>.<schema>.<table>)"
-        ))),
     }
 }
 
+/// Split a table [`ObjectName`] into `(catalog, schema, table)`.
+///
+/// Tables must be fully qualified — hydrofoil has no "current catalog" to
+/// resolve a shorter name against (matching [`super::schemas`]' two-part
+/// requirement for schemas).
+pub(crate) fn split_table_name(name: &ObjectName) -> Result<(String, String, String)> {
+    split_three_part_name(name, "Table")
+}
+
+/// Map an Arrow schema produced by [`sql_columns_to_arrow_schema`] to the
+/// Unity Catalog column list, with Spark-style `type_json` so readers can
+/// rebuild the exact type (see [`arrow_to_uc_column`]).
+pub(crate) fn arrow_schema_to_uc_columns(schema: &Schema) -> Result<Vec<Column>> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(position, field)| {
+            arrow_to_uc_column(field, position).map_err(DataFusionError::NotImplemented)
+        })
+        .collect()
+}
+
 /// Map parsed SQL column definitions to an Arrow [`SchemaRef`].
 ///
 /// Only the primitive types the managed connector supports are accepted (see
-/// `arrow_primitive_to_delta` in the connector); nested / decimal / unsupported
-/// types are rejected here with a clear error rather than failing deeper in the
-/// create path. Recognizes both standard SQL names and the Spark aliases
-/// `LONG` / `STRING` (which the generic dialect surfaces as `Custom`).
+/// [`arrow_to_primitive`](crate::catalog::types::arrow_to_primitive)); nested
+/// and other unsupported types are rejected here with a clear error rather than
+/// failing deeper in the create path. Recognizes both standard SQL names and
+/// the Spark aliases `LONG` / `STRING` (which the generic dialect surfaces as
+/// `Custom`). `TIMESTAMP` is Spark's instant type; `TIMESTAMP_NTZ` (or
+/// `TIMESTAMP WITHOUT TIME ZONE`) is the wall-clock one.
 pub(crate) fn sql_columns_to_arrow_schema(columns: &[ColumnDef]) -> Result<SchemaRef> {
     if columns.is_empty() {
         return Err(DataFusionError::Execution(
@@ -114,7 +210,8 @@ fn sql_type_to_arrow(dt: &SqlDataType, col: &str) -> Result<DataType> {
         DataFusionError::NotImplemented(format!(
             "column '{col}' has unsupported type '{dt}' for a managed Delta table \
              (supported: boolean, tinyint/byte, smallint/short, int, bigint/long, \
-             real/float, double, string/varchar/char/text, binary, date, timestamp)"
+             real/float, double, decimal/numeric, string/varchar/char/text, binary, date, \
+             timestamp, timestamp_ntz)"
         ))
     };
     let mapped = match dt {
@@ -134,9 +231,24 @@ fn sql_type_to_arrow(dt: &SqlDataType, col: &str) -> Result<DataType> {
         | SqlDataType::String(_) => DataType::Utf8,
         SqlDataType::Binary(_) | SqlDataType::Bytea => DataType::Binary,
         SqlDataType::Date => DataType::Date32,
-        SqlDataType::Timestamp(_, _) | SqlDataType::TimestampNtz(_) => {
+        SqlDataType::Decimal(info) | SqlDataType::Numeric(info) | SqlDataType::Dec(info) => {
+            // Spark's `DECIMAL` defaults to `DECIMAL(10, 0)`.
+            let (precision, scale) = match info {
+                ExactNumberInfo::None => (10, 0),
+                ExactNumberInfo::Precision(p) => (*p, 0),
+                ExactNumberInfo::PrecisionAndScale(p, s) => (*p, *s),
+            };
+            let precision = i32::try_from(precision).map_err(|_| unsupported(dt))?;
+            let scale = i32::try_from(scale).map_err(|_| unsupported(dt))?;
+            decimal(precision, scale)
+                .map_err(|e| DataFusionError::Plan(format!("column '{col}' has an {e} type")))?
+        }
+        SqlDataType::Timestamp(_, TimezoneInfo::WithoutTimeZone) | SqlDataType::TimestampNtz(_) => {
             DataType::Timestamp(TimeUnit::Microsecond, None)
         }
+        SqlDataType::Timestamp(_, _) => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
         // Spark spells some types (`LONG`, `STRING`, `BYTE`, `SHORT`) as bare
         // identifiers the generic dialect parses as `Custom`. Match on the name.
         SqlDataType::Custom(name, _) => match name.to_string().to_ascii_lowercase().as_str() {
@@ -155,6 +267,7 @@ fn sql_type_to_arrow(dt: &SqlDataType, col: &str) -> Result<DataType> {
 mod tests {
     use super::*;
     use datafusion::sql::sqlparser::ast::Ident;
+    use unitycatalog_common::models::tables::v1::ColumnTypeName;
 
     fn col(name: &str, dt: SqlDataType) -> ColumnDef {
         ColumnDef {
@@ -189,20 +302,69 @@ mod tests {
 
     #[test]
     fn rejects_unsupported_type() {
-        let cols = vec![col(
-            "amount",
-            SqlDataType::Decimal(datafusion::sql::sqlparser::ast::ExactNumberInfo::None),
-        )];
+        let cols = vec![col("id", SqlDataType::Uuid)];
         let err = sql_columns_to_arrow_schema(&cols).unwrap_err();
         assert!(err.to_string().contains("unsupported type"));
     }
 
+    #[test]
+    fn maps_decimal_and_timestamps() {
+        let cols = vec![
+            col(
+                "amount",
+                SqlDataType::Decimal(ExactNumberInfo::PrecisionAndScale(12, 2)),
+            ),
+            col("price", SqlDataType::Numeric(ExactNumberInfo::None)),
+            col("at", SqlDataType::Timestamp(None, TimezoneInfo::None)),
+            col("local", SqlDataType::TimestampNtz(None)),
+        ];
+        let schema = sql_columns_to_arrow_schema(&cols).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Decimal128(12, 2));
+        assert_eq!(schema.field(1).data_type(), &DataType::Decimal128(10, 0));
+        let columns = arrow_schema_to_uc_columns(&schema).unwrap();
+        assert_eq!(
+            columns
+                .iter()
+                .map(|c| c.type_text.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "decimal(12,2)",
+                "decimal(10,0)",
+                "timestamp",
+                "timestamp_ntz"
+            ]
+        );
+
+        let too_wide = vec![col(
+            "amount",
+            SqlDataType::Decimal(ExactNumberInfo::PrecisionAndScale(40, 2)),
+        )];
+        let err = sql_columns_to_arrow_schema(&too_wide).unwrap_err();
+        assert!(err.to_string().contains("invalid decimal(40,2)"));
+    }
+
     #[test]
     fn rejects_empty_columns() {
         let err = sql_columns_to_arrow_schema(&[]).unwrap_err();
         assert!(err.to_string().contains("at least one column"));
     }
 
+    #[test]
+    fn uc_columns_carry_spark_type_json() {
+        let cols = vec![
+            col("id", SqlDataType::BigInt(None)),
+            col("n", SqlDataType::Int(None)),
+        ];
+        let columns =
+            arrow_schema_to_uc_columns(&sql_columns_to_arrow_schema(&cols).unwrap()).unwrap();
+        assert_eq!(columns[0].type_name, ColumnTypeName::Long as i32);
+        assert_eq!(columns[0].type_text, "bigint");
+        assert_eq!(columns[1].position, Some(1));
+        let json: serde_json::Value = serde_json::from_str(&columns[1].type_json).unwrap();
+        assert_eq!(json["type"], "integer");
+        assert_eq!(json["nullable"], true);
+    }
+
     #[test]
     fn requires_three_part_name() {
         let two: ObjectName = vec![Ident::new("s"), Ident::new("t")].into();
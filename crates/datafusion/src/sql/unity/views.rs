@@ -0,0 +1,139 @@
+use datafusion::arrow::array::RecordBatch;
+use datafusion::common::{DataFusionError, Result, plan_err};
+use datafusion::sql::sqlparser::ast::ObjectName;
+use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::metric_view::query_dependencies;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, TableType};
+
+use crate::sql::unity::create_response_to_batch;
+use crate::sql::unity::tables::split_table_name;
+
+/// `CREATE [OR REPLACE] VIEW [IF NOT EXISTS] <catalog>.<schema>.<view> [COMMENT
+/// str] AS <query>` — a Unity Catalog SQL view. Lowered to an `Extension` node
+/// like the other UC DDL so it rides through the SQL DDL gate and is authorized
+/// by Cedar.
+///
+/// The query text is stored verbatim as the view definition. The server derives
+/// the view's dependencies from it (recording a `DependsOn` association to each
+/// referenced table), so every relation it reads must be fully qualified.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct CreateViewStatement {
+    pub name: ObjectName,
+    /// `OR REPLACE` — drops an existing view of the same name first. UC has no
+    /// replace or update API for tables, so the drop and the create are not
+    /// atomic. The definition is checked against the server's dependency policy
+    /// before anything is dropped, and only a missing view is tolerated; any
+    /// other lookup or drop error fails the statement before anything is
+    /// changed or created.
+    pub or_replace: bool,
+    pub if_not_exists: bool,
+    /// The raw `AS <query>` text.
+    pub query: String,
+    pub comment: Option<String>,
+}
+
+impl CreateViewStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let (catalog, schema, view) = split_table_name(&self.name)?;
+
+        // The server derives dependencies with the same function and rejects a
+        // definition it cannot; check here so `OR REPLACE` never drops a view
+        // it then fails to recreate.
+        if let Err(e) = query_dependencies(&self.query) {
+            return plan_err!("invalid definition for view '{}': {e}", self.name);
+        }
+
+        if self.or_replace || self.if_not_exists {
+            let existing = match client.table(&catalog, &schema, &view).get().await {
+                Ok(existing) => Some(existing),
+                Err(e) if e.is_not_found() => None,
+                Err(e) => return Err(DataFusionError::External(Box::new(e))),
+            };
+            match existing {
+                Some(_) if self.if_not_exists => {
+                    return create_response_to_batch(self.name.to_string(), "View", "exists");
+                }
+                Some(existing) if existing.table_type != TableType::View as i32 => {
+                    return Err(DataFusionError::Execution(format!(
+                        "cannot replace '{}': it is not a view",
+                        self.name
+                    )));
+                }
+                Some(_) => match client.table(&catalog, &schema, &view).delete().await {
+                    Ok(()) => {}
+                    // Dropped concurrently; the create below still replaces it.
+                    Err(e) if e.is_not_found() => {}
+                    Err(e) => return Err(DataFusionError::External(Box::new(e))),
+                },
+                None => {}
+            }
+        }
+
+        let info = client
+            .create_table(
+                view,
+                schema,
+                catalog,
+                TableType::View,
+                DataSourceFormat::Unspecified,
+            )
+            .with_view_definition(Some(self.query.clone()))
+            .with_comment(self.comment.clone())
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+        create_response_to_batch(self.name.to_string(), "View", info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::sql::sqlparser::ast::Ident;
+    use mockito::Server;
+    use olai_http::CloudClient;
+    use url::Url;
+
+    use super::*;
+
+    fn replace_view(query: &str) -> CreateViewStatement {
+        CreateViewStatement {
+            name: ObjectName::from(vec![Ident::new("c"), Ident::new("s"), Ident::new("v")]),
+            or_replace: true,
+            if_not_exists: false,
+            query: query.to_string(),
+            comment: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn or_replace_keeps_the_view_when_the_definition_is_rejected() {
+        let mut server = Server::new_async().await;
+        let lookup = server
+            .mock("GET", "/api/2.1/unity-catalog/tables/c.s.v")
+            .match_query(mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let delete = server
+            .mock("DELETE", "/api/2.1/unity-catalog/tables/c.s.v")
+            .expect(0)
+            .create_async()
+            .await;
+        let client = UnityCatalogClient::new(
+            CloudClient::new_unauthenticated(),
+            Url::parse(&format!("{}/api/2.1/unity-catalog/", server.url())).unwrap(),
+        );
+
+        let err = replace_view("SELECT * FROM orders")
+            .execute(client)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid definition for view 'c.s.v'"),
+            "{err}"
+        );
+        lookup.assert_async().await;
+        delete.assert_async().await;
+    }
+}
@@ -0,0 +1,68 @@
+use datafusion::arrow::array::RecordBatch;
+use datafusion::common::{DataFusionError, Result};
+use datafusion::sql::sqlparser::ast::ObjectName;
+use unitycatalog_client::UnityCatalogClient;
+use unitycatalog_common::models::volumes::v1::VolumeType;
+use url::Url;
+
+use crate::sql::unity::{create_response_to_batch, drop_response_to_batch, split_three_part_name};
+
+/// `CREATE [EXTERNAL] VOLUME [IF NOT EXISTS] <catalog>.<schema>.<volume>
+/// [LOCATION url] [COMMENT str]` — a managed volume, or an external one when a
+/// `LOCATION` is given.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct CreateVolumeStatement {
+    pub name: ObjectName,
+    pub if_not_exists: bool,
+    pub location: Option<Url>,
+    pub comment: Option<String>,
+}
+
+impl CreateVolumeStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let (catalog, schema, volume) = split_three_part_name(&self.name, "Volume")?;
+
+        if self.if_not_exists
+            && client
+                .volume(&catalog, &schema, &volume)
+                .get()
+                .await
+                .is_ok()
+        {
+            return create_response_to_batch(self.name.to_string(), "Volume", "exists");
+        }
+
+        let volume_type = if self.location.is_some() {
+            VolumeType::External
+        } else {
+            VolumeType::Managed
+        };
+        let info = client
+            .create_volume(catalog, schema, volume, volume_type)
+            .with_storage_location(self.location.as_ref().map(Url::to_string))
+            .with_comment(self.comment.clone())
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        create_response_to_batch(self.name.to_string(), "Volume", info)
+    }
+}
+
+/// `DROP VOLUME [IF EXISTS] <catalog>.<schema>.<volume>`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct DropVolumeStatement {
+    pub name: ObjectName,
+    pub if_exists: bool,
+}
+
+impl DropVolumeStatement {
+    pub(crate) async fn execute(&self, client: UnityCatalogClient) -> Result<RecordBatch> {
+        let (catalog, schema, volume) = split_three_part_name(&self.name, "Volume")?;
+        match client.volume(catalog, schema, volume).delete().await {
+            Ok(_) => drop_response_to_batch(self.name.to_string(), "Volume", "success"),
+            Err(e) if self.if_exists && e.is_not_found() => {
+                drop_response_to_batch(self.name.to_string(), "Volume", "not found")
+            }
+            Err(e) => Err(DataFusionError::External(Box::new(e))),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Resolve plain-file (Parquet, CSV, JSON) Unity Catalog tables through
+//! [`UnityCatalogSchemaProvider`] and scan them.
+//!
+//! The catalog is a mock server answering the catalog, schema and table
+//! lookups; the tables live in a local directory, which the object store
+//! factory serves without vending credentials.
+#![cfg(not(windows))]
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use datafusion::arrow::array::{Int64Array, RecordBatch};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::catalog::{AsyncCatalogProviderList, TableProvider};
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::prelude::{DataFrame, SessionContext};
+use datafusion_unitycatalog::catalog::{
+    TableProviderBuilder, TableProviderError, UnityCatalogProviderList,
+};
+use mockito::{Matcher, Server, ServerGuard};
+use unitycatalog_common::models::catalogs::v1::Catalog;
+use unitycatalog_common::models::schemas::v1::Schema as UcSchema;
+use unitycatalog_common::models::tables::v1::{Column, DataSourceFormat, Table, TableType};
+use unitycatalog_object_store::UnityObjectStoreFactory;
+use url::Url;
+
+/// Only plain-file tables are resolved here, so no Delta provider is built.
+#[derive(Debug)]
+struct NoDelta;
+
+#[async_trait::async_trait]
+impl TableProviderBuilder for NoDelta {
+    async fn build_delta(
+        &self,
+        _location: &Url,
+        table: &Table,
+    ) -> Result<Arc<dyn TableProvider>, TableProviderError> {
+        Err(TableProviderError::NotImplemented(format!(
+            "unexpected Delta table '{}'",
+            table.full_name
+        )))
+    }
+}
+
+/// A temp directory that is removed on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(tag: &str) -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("uc-listing-{tag}-{}-{nanos}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir(path.canonicalize().unwrap())
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn write(path: &Path, contents: &[u8]) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn write_parquet(path: &Path, ids: &[i64], amounts: &[i64]) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("amount", DataType::Int64, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(ids.to_vec())),
+            Arc::new(Int64Array::from(amounts.to_vec())),
+        ],
+    )
+    .unwrap();
+    let mut bytes = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut bytes, schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    write(path, &bytes);
+}
+
+fn column(name: &str, type_name: &str, position: i32, partition_index: Option<i32>) -> Column {
+    Column {
+        name: name.to_string(),
+        type_json: format!(
+            r#"{{"name":"{name}","type":"{type_name}","nullable":true,"metadata":{{}}}}"#
+        ),
+        position: Some(position),
+        partition_index,
+        nullable: Some(true),
+        ..Default::default()
+    }
+}
+
+/// A mock Unity Catalog holding catalog `c` and schema `c.s`.
+async fn mock_catalog() -> ServerGuard {
+    let mut server = Server::new_async().await;
+    let catalog = Catalog {
+        name: "c".to_string(),
+        ..Default::default()
+    };
+    server
+        .mock("GET", "/api/2.1/unity-catalog/catalogs/c")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(serde_json::to_string(&catalog).unwrap())
+        .create_async()
+        .await;
+    let schema = UcSchema {
+        name: "s".to_string(),
+        catalog_name: "c".to_string(),
+        full_name: "c.s".to_string(),
+        ..Default::default()
+    };
+    server
+        .mock("GET", "/api/2.1/unity-catalog/schemas/c.s")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(serde_json::to_string(&schema).unwrap())
+        .create_async()
+        .await;
+    server
+}
+
+/// An external table named `c.s.<name>` at `location`, without a trailing
+/// slash, served by the mock catalog.
+async fn serve_table(
+    server: &mut ServerGuard,
+    name: &str,
+    format: DataSourceFormat,
+    location: &Path,
+    columns: Vec<Column>,
+    properties: &[(&str, &str)],
+) {
+    let table = Table {
+        name: name.to_string(),
+        catalog_name: "c".to_string(),
+        schema_name: "s".to_string(),
+        full_name: format!("c.s.{name}"),
+        table_type: TableType::External as i32,
+        data_source_format: format as i32,
+        storage_location: Some(
+            Url::from_directory_path(location)
+                .unwrap()
+                .as_str()
+                .trim_end_matches('/')
+                .to_string(),
+        ),
+        columns,
+        properties: properties
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        ..Default::default()
+    };
+    server
+        .mock(
+            "GET",
+            format!("/api/2.1/unity-catalog/tables/c.s.{name}").as_str(),
+        )
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(serde_json::to_string(&table).unwrap())
+        .create_async()
+        .await;
+}
+
+/// Run `sql`, resolving the tables it references through the Unity Catalog
+/// provider list as a host session would, and return the result
+/// pretty-printed.
+async fn query(server: &ServerGuard, sql: &str) -> String {
+    let factory = Arc::new(
+        UnityObjectStoreFactory::builder()
+            .with_uri(format!("{}/api/2.1/unity-catalog/", server.url()))
+            .with_allow_unauthenticated(true)
+            .build()
+            .await
+            .unwrap(),
+    );
+    let ctx = SessionContext::new();
+    let providers = UnityCatalogProviderList::new(factory, ctx.runtime_env(), Arc::new(NoDelta))
+        .with_session(&ctx);
+
+    let state = ctx.state();
+    let dialect = state.config().options().sql_parser.dialect;
+    let statement = state.sql_to_statement(sql, &dialect).unwrap();
+    let references = state.resolve_table_references(&statement).unwrap();
+    let resolved = providers
+        .resolve(&references, state.config())
+        .await
+        .unwrap();
+    ctx.register_catalog_list(resolved);
+    let plan = ctx.state().statement_to_plan(statement).await.unwrap();
+    let batches = DataFrame::new(ctx.state(), plan).collect().await.unwrap();
+    pretty_format_batches(&batches).unwrap().to_string()
+}
+
+#[tokio::test]
+async fn parquet_table_reads_partition_values_under_its_own_directory() {
+    let dir = TempDir::new("parquet");
+    let root = dir.0.join("sales");
+    write_parquet(&root.join("region=eu/part-0.parquet"), &[1, 2], &[10, 20]);
+    write_parquet(&root.join("region=us/part-0.parquet"), &[3], &[30]);
+    // A sibling sharing the location as a name prefix must not be listed.
+    write_parquet(&dir.0.join("sales_archive/part-0.parquet"), &[99], &[990]);
+
+    let mut server = mock_catalog().await;
+    serve_table(
+        &mut server,
+        "sales",
+        DataSourceFormat::Parquet,
+        &root,
+        vec![
+            column("id", "long", 0, None),
+            column("amount", "long", 1, None),
+            column("region", "string", 2, Some(0)),
+        ],
+        &[],
+    )
+    .await;
+
+    let out = query(
+        &server,
+        "SELECT region, id, amount FROM c.s.sales ORDER BY id",
+    )
+    .await;
+    assert_eq!(
+        out,
+        "+--------+----+--------+\n\
+         | region | id | amount |\n\
+         +--------+----+--------+\n\
+         | eu     | 1  | 10     |\n\
+         | eu     | 2  | 20     |\n\
+         | us     | 3  | 30     |\n\
+         +--------+----+--------+"
+    );
+}
+
+#[tokio::test]
+async fn csv_table_uses_header_and_delimiter_properties() {
+    let dir = TempDir::new("csv");
+    let root = dir.0.join("events");
+    write(&root.join("part-0.csv"), b"id;kind\n1;click\n2;view\n");
+
+    let mut server = mock_catalog().await;
+    serve_table(
+        &mut server,
+        "events",
+        DataSourceFormat::Csv,
+        &root,
+        vec![
+            column("id", "long", 0, None),
+            column("kind", "string", 1, None),
+        ],
+        &[("header", "true"), ("delimiter", ";")],
+    )
+    .await;
+
+    let out = query(&server, "SELECT id, kind FROM c.s.events ORDER BY id").await;
+    assert_eq!(
+        out,
+        "+----+-------+\n\
+         | id | kind  |\n\
+         +----+-------+\n\
+         | 1  | click |\n\
+         | 2  | view  |\n\
+         +----+-------+"
+    );
+}
+
+#[tokio::test]
+async fn json_table_reads_newline_delimited_records() {
+    let dir = TempDir::new("json");
+    let root = dir.0.join("logs");
+    write(
+        &root.join("part-0.json"),
+        b"{\"id\":1,\"level\":\"info\"}\n{\"id\":2,\"level\":\"warn\"}\n",
+    );
+
+    let mut server = mock_catalog().await;
+    serve_table(
+        &mut server,
+        "logs",
+        DataSourceFormat::Json,
+        &root,
+        vec![
+            column("id", "long", 0, None),
+            column("level", "string", 1, None),
+        ],
+        &[],
+    )
+    .await;
+
+    let out = query(&server, "SELECT level FROM c.s.logs WHERE id = 2").await;
+    assert_eq!(
+        out,
+        "+-------+\n\
+         | level |\n\
+         +-------+\n\
+         | warn  |\n\
+         +-------+"
+    );
+}
@@ -1,5 +1,6 @@
-//! Integration test for creating a Unity Catalog catalog-managed Delta table via the
-//! kernel-committer framework (`datafusion_unitycatalog::managed::create_managed_table`).
+//! Integration tests for creating a Unity Catalog catalog-managed Delta table via the
+//! kernel-committer framework (`datafusion_unitycatalog::managed::create_managed_table`),
+//! for DML committed to one through SQL, and for materialized views refreshed from one.
 //!
 //! Hits a live Java Unity Catalog OSS server + its backing object store, so it's
 //! `#[ignore]`d by default and requires the `delta` feature. Bring up the open-lakehouse
@@ -19,12 +20,22 @@
 
 use std::sync::Arc;
 
-use datafusion::arrow::array::{Int64Array, RecordBatch, StringArray};
-use datafusion::arrow::datatypes::{DataType, Field, Schema};
-use datafusion::prelude::SessionContext;
+use datafusion::arrow::array::{AsArray, Int64Array, RecordBatch, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, UInt64Type};
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::catalog::AsyncCatalogProviderList;
+use datafusion::common::TableReference;
+use datafusion::physical_plan::collect;
+use datafusion::prelude::{DataFrame, SessionContext, col};
 use datafusion_unitycatalog::RoutingObjectStore;
-use datafusion_unitycatalog::catalog::build_catalog_managed_snapshot;
-use datafusion_unitycatalog::managed::{append_to_managed_table, create_managed_table};
+use datafusion_unitycatalog::catalog::{
+    DeltaTableProviderBuilder, UnityCatalogProviderList, build_catalog_managed_snapshot,
+};
+use datafusion_unitycatalog::managed::{
+    ManagedDeltaTable, MergeInto, REFRESH_APP_ID, RefreshKind, RefreshMode, SOURCE_APP_ID_PREFIX,
+    append_to_managed_table, create_managed_table, create_materialized_view,
+    refresh_materialized_view,
+};
 use deltalake_core::delta_datafusion::DeltaScanNext;
 use deltalake_core::delta_datafusion::engine::DataFusionEngine;
 use deltalake_core::logstore::{StorageConfig, default_logstore};
@@ -182,3 +193,336 @@ async fn create_managed_table_round_trip() {
     println!("scanned {rows} rows from managed table (expected 3)");
     assert_eq!(rows, 3, "should read back the 3 appended rows");
 }
+
+/// Create a source table and two materialized views over it — a linear one and an aggregate
+/// — and walk them through each kind of refresh. Uses `UC_TABLE` as the name prefix.
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT)"]
+async fn materialized_view_refreshes() {
+    let Some(factory) = factory_from_env().await else {
+        eprintln!("UC_ENDPOINT not set; skipping");
+        return;
+    };
+    let factory = Arc::new(factory);
+    let catalog = std::env::var("UC_CATALOG").unwrap_or_else(|_| "demo".into());
+    let schema = std::env::var("UC_SCHEMA").unwrap_or_else(|_| "managed_demo".into());
+    let prefix = std::env::var("UC_TABLE").unwrap_or_else(|_| "mt_itest".into());
+    let (source, linear, totals) = (
+        format!("{prefix}_src"),
+        format!("{prefix}_mv"),
+        format!("{prefix}_mv_totals"),
+    );
+    let engine_info = "unitycatalog-rs-itest/0.1";
+
+    let arrow_schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let batch = |ids: Vec<i64>| {
+        let names: Vec<String> = ids.iter().map(|id| format!("n{id}")).collect();
+        RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    };
+    create_managed_table(
+        Arc::new(factory.unity_client().delta_v1()),
+        &catalog,
+        &schema,
+        &source,
+        arrow_schema.clone(),
+        vec![],
+        engine_info,
+    )
+    .await
+    .expect("create source table");
+    append_to_managed_table(
+        factory.clone(),
+        &catalog,
+        &schema,
+        &source,
+        batch(vec![1, 2, 3]),
+        engine_info,
+    )
+    .await
+    .expect("append to source");
+
+    let source_name = format!("{catalog}.{schema}.{source}");
+    for (view, query) in [
+        (
+            &linear,
+            format!("SELECT id, name FROM {source_name} WHERE id > 1"),
+        ),
+        (&totals, format!("SELECT COUNT(*) AS n FROM {source_name}")),
+    ] {
+        let created = create_materialized_view(
+            factory.clone(),
+            &catalog,
+            &schema,
+            view,
+            &query,
+            None,
+            engine_info,
+        )
+        .await
+        .expect("create materialized view");
+        assert_eq!(created.kind, RefreshKind::Full);
+    }
+    let refresh = |view: String, mode| {
+        let (factory, catalog, schema) = (factory.clone(), catalog.clone(), schema.clone());
+        async move {
+            refresh_materialized_view(factory, &catalog, &schema, &view, mode, engine_info)
+                .await
+                .expect("refresh materialized view")
+        }
+    };
+
+    // Nothing changed since the create.
+    let up_to_date = refresh(linear.clone(), RefreshMode::Auto).await;
+    assert_eq!(up_to_date.kind, RefreshKind::UpToDate);
+
+    // An append to the source is applied incrementally to the linear view...
+    append_to_managed_table(
+        factory.clone(),
+        &catalog,
+        &schema,
+        &source,
+        batch(vec![4, 5]),
+        engine_info,
+    )
+    .await
+    .expect("append to source");
+    let incremental = refresh(linear.clone(), RefreshMode::Auto).await;
+    assert_eq!(incremental.kind, RefreshKind::Incremental);
+    assert_eq!(incremental.rows_written, 2);
+
+    // ...while the aggregate falls back to a full refresh.
+    let fallback = refresh(totals.clone(), RefreshMode::Auto).await;
+    assert_eq!(fallback.kind, RefreshKind::Full);
+    assert_eq!(fallback.rows_written, 1);
+
+    // A forced refresh recomputes the linear view: ids 2..=5.
+    let full = refresh(linear.clone(), RefreshMode::Full).await;
+    assert_eq!(full.kind, RefreshKind::Full);
+    assert_eq!(full.rows_written, 4);
+    let after = refresh(linear.clone(), RefreshMode::Auto).await;
+    assert_eq!(after.kind, RefreshKind::UpToDate);
+    assert_eq!(after.version, full.version);
+
+    // The catalog surfaces the versions the last refresh recorded.
+    let view = factory
+        .unity_client()
+        .table(&catalog, &schema, &linear)
+        .get()
+        .await
+        .expect("get materialized view");
+    assert_eq!(
+        view.properties.get(REFRESH_APP_ID),
+        Some(&full.version.to_string())
+    );
+    assert_eq!(
+        view.properties
+            .get(&format!("{SOURCE_APP_ID_PREFIX}{source_name}")),
+        Some(&full.source_versions[&source_name].to_string())
+    );
+}
+
+/// A session whose statements resolve their tables through Unity Catalog, as a host would
+/// wire it: Delta tables are built by [`DeltaTableProviderBuilder`] and managed ones accept
+/// DML.
+struct UcSession {
+    ctx: SessionContext,
+    providers: UnityCatalogProviderList,
+}
+
+impl UcSession {
+    fn new(factory: Arc<UnityObjectStoreFactory>) -> Self {
+        let ctx = SessionContext::new();
+        let builder = Arc::new(DeltaTableProviderBuilder::new(
+            ctx.clone(),
+            factory.unity_client().clone(),
+        ));
+        let providers =
+            UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder).with_session(&ctx);
+        Self { ctx, providers }
+    }
+
+    /// Plan `sql` against freshly resolved tables, so it reads their latest versions.
+    async fn plan(&self, sql: &str) -> DataFrame {
+        let state = self.ctx.state();
+        let dialect = state.config().options().sql_parser.dialect;
+        let statement = state.sql_to_statement(sql, &dialect).unwrap();
+        let references = state.resolve_table_references(&statement).unwrap();
+        let resolved = self
+            .providers
+            .resolve(&references, state.config())
+            .await
+            .unwrap();
+        self.ctx.register_catalog_list(resolved);
+        let plan = self.ctx.state().statement_to_plan(statement).await.unwrap();
+        DataFrame::new(self.ctx.state(), plan)
+    }
+
+    /// Run DML `sql` and return the affected row count it reports.
+    async fn execute(&self, sql: &str) -> u64 {
+        let batches = self
+            .plan(sql)
+            .await
+            .collect()
+            .await
+            .unwrap_or_else(|e| panic!("{sql}: {e}"));
+        affected(&batches)
+    }
+
+    async fn rows(&self, sql: &str) -> String {
+        let batches = self.plan(sql).await.collect().await.unwrap();
+        pretty_format_batches(&batches).unwrap().to_string()
+    }
+
+    /// The managed table `name` as the resolver wraps it.
+    async fn managed_table(&self, name: &str) -> Arc<dyn datafusion::catalog::TableProvider> {
+        let reference = TableReference::parse_str(name);
+        let resolved = self
+            .providers
+            .resolve(std::slice::from_ref(&reference), self.ctx.state().config())
+            .await
+            .unwrap();
+        let full = reference.resolve("", "");
+        resolved
+            .catalog(&full.catalog)
+            .unwrap()
+            .schema(&full.schema)
+            .unwrap()
+            .table(&full.table)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("{name} was not resolved"))
+    }
+}
+
+/// The single `count` a DML plan yields.
+fn affected(batches: &[RecordBatch]) -> u64 {
+    batches
+        .iter()
+        .map(|b| {
+            b.column(0)
+                .as_primitive::<UInt64Type>()
+                .values()
+                .iter()
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Run `INSERT`, `INSERT OVERWRITE`, `DELETE`, `UPDATE` and `MERGE` against a managed table
+/// through SQL and check each commit, then race two `UPDATE`s planned against the same
+/// version: the loser of the commit race must replan against the winner's version in the
+/// retry loop, so both changes survive. Uses `UC_TABLE` as the name prefix.
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT)"]
+async fn managed_table_dml_commits() {
+    let Some(factory) = factory_from_env().await else {
+        eprintln!("UC_ENDPOINT not set; skipping");
+        return;
+    };
+    let factory = Arc::new(factory);
+    let catalog = std::env::var("UC_CATALOG").unwrap_or_else(|_| "demo".into());
+    let schema = std::env::var("UC_SCHEMA").unwrap_or_else(|_| "managed_demo".into());
+    let prefix = std::env::var("UC_TABLE").unwrap_or_else(|_| "mt_itest".into());
+    let table = format!("{prefix}_dml");
+    let name = format!("{catalog}.{schema}.{table}");
+
+    create_managed_table(
+        Arc::new(factory.unity_client().delta_v1()),
+        &catalog,
+        &schema,
+        &table,
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+        ])),
+        vec![],
+        "unitycatalog-rs-itest/0.1",
+    )
+    .await
+    .expect("create_managed_table failed");
+    let session = UcSession::new(factory);
+
+    let inserted = session
+        .execute(&format!("INSERT INTO {name} VALUES (1, 'a'), (2, 'b')"))
+        .await;
+    assert_eq!(inserted, 2);
+    let overwritten = session
+        .execute(&format!(
+            "INSERT OVERWRITE {name} VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')"
+        ))
+        .await;
+    assert_eq!(overwritten, 4);
+    let deleted = session
+        .execute(&format!("DELETE FROM {name} WHERE id = 4"))
+        .await;
+    assert_eq!(deleted, 1);
+    let updated = session
+        .execute(&format!("UPDATE {name} SET name = 'B' WHERE id = 2"))
+        .await;
+    assert_eq!(updated, 1);
+
+    // MERGE: update id 3 from the source and insert id 5.
+    let target = session.managed_table(&name).await;
+    let target = target
+        .as_any()
+        .downcast_ref::<ManagedDeltaTable>()
+        .expect("managed tables resolve to a ManagedDeltaTable");
+    let source = session
+        .ctx
+        .sql("SELECT * FROM (VALUES (3, 'C'), (5, 'e')) AS v(id, name)")
+        .await
+        .unwrap()
+        .into_unoptimized_plan();
+    let merge = MergeInto::new(source, "t", "s", col("t.id").eq(col("s.id")))
+        .when_matched_update(None, vec![("name".to_string(), col("s.name"))])
+        .when_not_matched_insert(
+            None,
+            vec![
+                ("id".to_string(), col("s.id")),
+                ("name".to_string(), col("s.name")),
+            ],
+        );
+    let merged = collect(target.merge_into(merge).unwrap(), session.ctx.task_ctx())
+        .await
+        .expect("MERGE failed");
+    assert_eq!(affected(&merged), 2);
+
+    // Conflict retry: both statements are planned before either commits.
+    let first = session
+        .plan(&format!("UPDATE {name} SET name = 'x1' WHERE id = 1"))
+        .await;
+    let second = session
+        .plan(&format!("UPDATE {name} SET name = 'x5' WHERE id = 5"))
+        .await;
+    let (first, second) = tokio::join!(
+        tokio::spawn(first.collect()),
+        tokio::spawn(second.collect())
+    );
+    assert_eq!(affected(&first.unwrap().expect("first UPDATE failed")), 1);
+    assert_eq!(affected(&second.unwrap().expect("second UPDATE failed")), 1);
+
+    let rows = session
+        .rows(&format!("SELECT id, name FROM {name} ORDER BY id"))
+        .await;
+    assert_eq!(
+        rows,
+        "+----+------+\n\
+         | id | name |\n\
+         +----+------+\n\
+         | 1  | x1   |\n\
+         | 2  | B    |\n\
+         | 3  | C    |\n\
+         | 5  | x5   |\n\
+         +----+------+"
+    );
+}
@@ -15,7 +15,13 @@
 //! Set `UC_TOKEN` for an authenticated server; omit it for a local
 //! unauthenticated OSS server. Set `UC_TEST_TABLE_2` to a second table in the
 //! *same bucket* to exercise the routing store's per-table credential
-//! disambiguation.
+//! disambiguation, `UC_TEST_VIEW` to a SQL view to exercise view resolution,
+//! and `UC_TEST_TABLE_V0_ROWS` to the number of rows `UC_TEST_TABLE` held at
+//! version 0 to exercise time travel.
+//!
+//! The time-travel tests at the end of the module run without a server: they
+//! resolve an external Delta table written to a local directory through a mock
+//! catalog.
 #![cfg(feature = "delta")]
 
 use std::sync::Arc;
@@ -50,26 +56,20 @@ impl UnityObjectStoreFactoryFut {
     }
 }
 
-/// Resolve a UC table to a provider and scan it, asserting we get rows back.
-#[tokio::test]
-#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT)"]
-async fn resolve_and_scan_uc_table() {
-    let Some(factory_fut) = factory_from_env() else {
-        eprintln!("UC_ENDPOINT not set; skipping");
-        return;
-    };
-    let full_name = std::env::var("UC_TEST_TABLE").expect("set UC_TEST_TABLE=catalog.schema.table");
-
+/// Resolve `full_name` through Unity Catalog, register it on a fresh session
+/// and scan it with SQL, returning the number of rows read.
+async fn resolve_and_scan(factory_fut: UnityObjectStoreFactoryFut, full_name: &str) -> usize {
     let factory = Arc::new(factory_fut.build().await);
     let ctx = SessionContext::new();
     let builder = Arc::new(DeltaTableProviderBuilder::new(
         ctx.clone(),
         factory.unity_client().clone(),
     ));
-    let resolver = UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder);
+    let resolver =
+        UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder).with_session(&ctx);
 
     // Drive resolution exactly as the session does at plan time.
-    let reference = TableReference::parse_str(&full_name);
+    let reference = TableReference::parse_str(full_name);
     let config = ctx.copied_config();
     let resolved = resolver
         .resolve(std::slice::from_ref(&reference), &config)
@@ -97,11 +97,66 @@ async fn resolve_and_scan_uc_table() {
         .await
         .expect("planning failed");
     let batches = df.collect().await.expect("scan failed");
-    let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    batches.iter().map(|b| b.num_rows()).sum()
+}
+
+/// Resolve a UC table to a provider and scan it, asserting we get rows back.
+#[tokio::test]
+#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT)"]
+async fn resolve_and_scan_uc_table() {
+    let Some(factory_fut) = factory_from_env() else {
+        eprintln!("UC_ENDPOINT not set; skipping");
+        return;
+    };
+    let full_name = std::env::var("UC_TEST_TABLE").expect("set UC_TEST_TABLE=catalog.schema.table");
+    let rows = resolve_and_scan(factory_fut, &full_name).await;
     println!("scanned {rows} rows from {full_name}");
     assert!(rows > 0, "expected at least one row from {full_name}");
 }
 
+/// Resolve `UC_TEST_TABLE` as of version 0 through a quoted `"<table>@v0"`
+/// time-travel reference and scan it, expecting `UC_TEST_TABLE_V0_ROWS` rows.
+#[tokio::test]
+#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT and UC_TEST_TABLE_V0_ROWS)"]
+async fn resolve_and_scan_uc_table_at_version() {
+    let Some(factory_fut) = factory_from_env() else {
+        eprintln!("UC_ENDPOINT not set; skipping");
+        return;
+    };
+    let Ok(expected) = std::env::var("UC_TEST_TABLE_V0_ROWS") else {
+        eprintln!("UC_TEST_TABLE_V0_ROWS not set; skipping");
+        return;
+    };
+    let expected: usize = expected
+        .parse()
+        .expect("UC_TEST_TABLE_V0_ROWS must be a row count");
+    let full_name = std::env::var("UC_TEST_TABLE").expect("set UC_TEST_TABLE=catalog.schema.table");
+    let (schema, table) = full_name
+        .rsplit_once('.')
+        .expect("table must be fully qualified");
+    let at_v0 = format!("{schema}.\"{table}@v0\"");
+    let rows = resolve_and_scan(factory_fut, &at_v0).await;
+    assert_eq!(rows, expected, "unexpected row count at {at_v0}");
+}
+
+/// Resolve a UC SQL view (`UC_TEST_VIEW`, e.g. one selecting from
+/// `UC_TEST_TABLE`) and scan it through its resolved references.
+#[tokio::test]
+#[ignore = "requires a live Unity Catalog server (set UC_ENDPOINT and UC_TEST_VIEW)"]
+async fn resolve_and_scan_uc_view() {
+    let Some(factory_fut) = factory_from_env() else {
+        eprintln!("UC_ENDPOINT not set; skipping");
+        return;
+    };
+    let Ok(full_name) = std::env::var("UC_TEST_VIEW") else {
+        eprintln!("UC_TEST_VIEW not set; skipping");
+        return;
+    };
+    let rows = resolve_and_scan(factory_fut, &full_name).await;
+    println!("scanned {rows} rows from view {full_name}");
+    assert!(rows > 0, "expected at least one row from {full_name}");
+}
+
 /// Smoke test the vended credential directly, before any DataFusion wiring, so
 /// AWS credential-validation failures against the custom UC image surface here
 /// rather than deep in a scan.
@@ -131,3 +186,233 @@ async fn vend_and_list_uc_table() {
     println!("listed {} objects under {full_name}", listing.len());
     assert!(!listing.is_empty(), "expected objects under {full_name}");
 }
+
+/// A temp directory that is removed on drop.
+struct TempDir(std::path::PathBuf);
+
+impl TempDir {
+    fn new(tag: &str) -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("uc-resolve-{tag}-{}-{nanos}", std::process::id()));
+        std::fs::create_dir_all(path.join("_delta_log")).unwrap();
+        TempDir(path.canonicalize().unwrap())
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Commit `ids` to the Delta log under `dir` as a new data file at `version`.
+/// Version 0 also creates the table with a single nullable `id: long` column.
+fn commit_ids(dir: &std::path::Path, version: u64, ids: &[i64]) {
+    use datafusion::arrow::array::{Int64Array, RecordBatch};
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::parquet::arrow::ArrowWriter;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from(ids.to_vec()))],
+    )
+    .unwrap();
+    let mut bytes = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut bytes, schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    let file = format!("part-{version}.parquet");
+    std::fs::write(dir.join(&file), &bytes).unwrap();
+
+    let mut actions = vec![serde_json::json!({
+        "commitInfo": { "timestamp": version, "operation": "WRITE" }
+    })];
+    if version == 0 {
+        let schema_string = serde_json::json!({
+            "type": "struct",
+            "fields": [{ "name": "id", "type": "long", "nullable": true, "metadata": {} }]
+        });
+        actions.push(serde_json::json!({
+            "protocol": { "minReaderVersion": 1, "minWriterVersion": 2 }
+        }));
+        actions.push(serde_json::json!({
+            "metaData": {
+                "id": "5d1b6b2c-0d8e-4a52-9a8e-2f0d6c7a4e11",
+                "format": { "provider": "parquet", "options": {} },
+                "schemaString": schema_string.to_string(),
+                "partitionColumns": [],
+                "configuration": {},
+                "createdTime": 0
+            }
+        }));
+    }
+    actions.push(serde_json::json!({
+        "add": {
+            "path": file,
+            "partitionValues": {},
+            "size": bytes.len(),
+            "modificationTime": 0,
+            "dataChange": true
+        }
+    }));
+    let log = actions
+        .iter()
+        .map(|action| format!("{action}\n"))
+        .collect::<String>();
+    std::fs::write(dir.join(format!("_delta_log/{version:020}.json")), log).unwrap();
+}
+
+/// A mock Unity Catalog holding catalog `c`, schema `c.s`, the external Delta
+/// table `c.s.numbers` stored at `location` and the SQL view `c.s.numbers_v0`
+/// over its first version.
+async fn mock_catalog(location: &std::path::Path) -> mockito::ServerGuard {
+    use mockito::Matcher;
+    use unitycatalog_common::models::catalogs::v1::Catalog;
+    use unitycatalog_common::models::schemas::v1::Schema;
+    use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table, TableType};
+
+    let mut server = mockito::Server::new_async().await;
+    let location = url::Url::from_directory_path(location).unwrap();
+    let catalog = Catalog {
+        name: "c".to_string(),
+        ..Default::default()
+    };
+    let schema = Schema {
+        name: "s".to_string(),
+        catalog_name: "c".to_string(),
+        full_name: "c.s".to_string(),
+        ..Default::default()
+    };
+    let table = Table {
+        name: "numbers".to_string(),
+        catalog_name: "c".to_string(),
+        schema_name: "s".to_string(),
+        full_name: "c.s.numbers".to_string(),
+        table_type: TableType::External as i32,
+        data_source_format: DataSourceFormat::Delta as i32,
+        storage_location: Some(location.to_string()),
+        ..Default::default()
+    };
+    let view = Table {
+        name: "numbers_v0".to_string(),
+        catalog_name: "c".to_string(),
+        schema_name: "s".to_string(),
+        full_name: "c.s.numbers_v0".to_string(),
+        table_type: TableType::View as i32,
+        view_definition: Some("SELECT id FROM c.s.\"numbers@v0\"".to_string()),
+        ..Default::default()
+    };
+    let load_table = serde_json::json!({
+        "metadata": {
+            "etag": "e",
+            "table-type": "EXTERNAL",
+            "table-uuid": "5d1b6b2c-0d8e-4a52-9a8e-2f0d6c7a4e11",
+            "location": location.as_str(),
+            "created-time": 0,
+            "updated-time": 0,
+            "columns": { "type": "struct", "fields": [] },
+            "properties": {}
+        }
+    });
+    for (path, body) in [
+        ("catalogs/c", serde_json::to_string(&catalog).unwrap()),
+        ("schemas/c.s", serde_json::to_string(&schema).unwrap()),
+        ("tables/c.s.numbers", serde_json::to_string(&table).unwrap()),
+        (
+            "tables/c.s.numbers_v0",
+            serde_json::to_string(&view).unwrap(),
+        ),
+        (
+            "delta/v1/catalogs/c/schemas/s/tables/numbers",
+            load_table.to_string(),
+        ),
+    ] {
+        server
+            .mock("GET", format!("/api/2.1/unity-catalog/{path}").as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+    }
+    server
+}
+
+/// Run `sql` against the mock catalog, resolving its references as a host
+/// session does at plan time, and return the `id` column.
+async fn query_ids(server: &mockito::ServerGuard, sql: &str) -> Vec<i64> {
+    use datafusion::arrow::array::{AsArray, RecordBatch};
+    use datafusion::arrow::datatypes::Int64Type;
+    use datafusion::prelude::DataFrame;
+
+    let factory = Arc::new(
+        UnityObjectStoreFactory::builder()
+            .with_uri(format!("{}/api/2.1/unity-catalog/", server.url()))
+            .with_allow_unauthenticated(true)
+            .build()
+            .await
+            .unwrap(),
+    );
+    let ctx = SessionContext::new();
+    let builder = Arc::new(DeltaTableProviderBuilder::new(
+        ctx.clone(),
+        factory.unity_client().clone(),
+    ));
+    let resolver =
+        UnityCatalogProviderList::new(factory, ctx.runtime_env(), builder).with_session(&ctx);
+
+    let state = ctx.state();
+    let dialect = state.config().options().sql_parser.dialect;
+    let statement = state.sql_to_statement(sql, &dialect).unwrap();
+    let references = state.resolve_table_references(&statement).unwrap();
+    let resolved = resolver
+        .resolve(&references, state.config())
+        .await
+        .expect("resolution failed");
+    ctx.register_catalog_list(resolved);
+    let plan = ctx.state().statement_to_plan(statement).await.unwrap();
+    let batches: Vec<RecordBatch> = DataFrame::new(ctx.state(), plan).collect().await.unwrap();
+    batches
+        .iter()
+        .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+        .collect()
+}
+
+#[tokio::test]
+async fn resolve_local_delta_table_at_version() {
+    let dir = TempDir::new("at-version");
+    commit_ids(&dir.0, 0, &[1, 2]);
+    commit_ids(&dir.0, 1, &[3]);
+    let server = mock_catalog(&dir.0).await;
+
+    assert_eq!(
+        query_ids(&server, "SELECT id FROM c.s.numbers ORDER BY id").await,
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        query_ids(&server, "SELECT id FROM c.s.\"numbers@v0\" ORDER BY id").await,
+        vec![1, 2]
+    );
+    assert_eq!(
+        query_ids(&server, "SELECT id FROM c.s.\"numbers@v1\" ORDER BY id").await,
+        vec![1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn resolve_view_over_a_table_version() {
+    let dir = TempDir::new("view-at-version");
+    commit_ids(&dir.0, 0, &[1, 2]);
+    commit_ids(&dir.0, 1, &[3]);
+    let server = mock_catalog(&dir.0).await;
+
+    assert_eq!(
+        query_ids(&server, "SELECT id FROM c.s.numbers_v0 ORDER BY id").await,
+        vec![1, 2]
+    );
+}
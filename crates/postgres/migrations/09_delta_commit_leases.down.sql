@@ -0,0 +1 @@
+drop table if exists delta_commit_leases;
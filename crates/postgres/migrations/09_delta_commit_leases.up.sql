@@ -0,0 +1,9 @@
+-- Per-table leases held by the server-side commit backfill publisher.
+--
+-- A replica may only publish a table's ratified commits while it holds the
+-- unexpired lease for that table, so replicas never race on the same log.
+create table delta_commit_leases (
+    table_id uuid primary key,
+    owner text not null,
+    expires_at timestamptz not null
+);
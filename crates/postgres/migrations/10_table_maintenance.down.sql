@@ -0,0 +1,2 @@
+drop table if exists table_maintenance_jobs;
+drop table if exists delta_commit_reports;
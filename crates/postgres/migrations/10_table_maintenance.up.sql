@@ -0,0 +1,29 @@
+-- Commit reports posted through the Delta API's `report_metrics`, and the
+-- maintenance jobs scheduled from them.
+create table delta_commit_reports (
+    id bigint generated always as identity primary key,
+    table_id uuid not null,
+    -- The `DeltaCommitReport` as sent by the client.
+    report jsonb not null,
+    reported_at timestamptz not null default now()
+);
+create index delta_commit_reports_table_index on delta_commit_reports (table_id, reported_at);
+
+create table table_maintenance_jobs (
+    id bigint generated always as identity primary key,
+    table_id uuid not null,
+    -- CHECKPOINT, COMPACTION, LOG_COMPACTION or VACUUM.
+    kind text not null,
+    -- PENDING, RUNNING, SUCCEEDED, FAILED or SKIPPED.
+    status text not null,
+    reason text not null,
+    message text,
+    committed_version bigint,
+    num_files_added bigint,
+    num_files_removed bigint,
+    num_bytes_removed bigint,
+    created_at timestamptz not null default now(),
+    started_at timestamptz,
+    finished_at timestamptz
+);
+create index table_maintenance_jobs_table_index on table_maintenance_jobs (table_id, id);
@@ -5,16 +5,24 @@
 //! persists ratified commits in the `delta_commits` table. The unique constraint
 //! on `(table_id, commit_version)` is the real first-writer-wins arbiter: each
 //! `commit` runs in a transaction, and a racing insert for the same version fails
-//! with a unique violation that maps to [`CommitError::VersionConflict`].
+//! with a unique violation that maps to [`CommitError::VersionConflict`]. A
+//! batch commit runs every table's state machine in one transaction, taking the
+//! tables in id order so concurrent batches cannot deadlock on each other.
 //!
 //! See the common module for the invariants (never-delete-highest backfill
 //! marker, unbackfilled cap, field validation, `latest_table_version` sentinels).
+//!
+//! Backfill leases live in `delta_commit_leases`, one row per table. Expiry is
+//! evaluated against the database clock, so replicas with skewed clocks still
+//! agree on who holds a lease.
 
+use std::time::Duration;
 use unitycatalog_common::models::delta_commits::v1::CommitInfo;
 use unitycatalog_common::services::commit_coordinator::{
-    CommitCoordinator, CommitError, CommitResult, DEFAULT_MAX_UNBACKFILLED_COMMITS,
-    validate_commit_info,
+    CommitCoordinator, CommitError, CommitResult, DEFAULT_MAX_UNBACKFILLED_COMMITS, TableCommit,
+    validate_batch, validate_commit_info,
 };
+
 use uuid::Uuid;
 
 use crate::GraphStore;
@@ -138,34 +146,25 @@ impl GraphStore {
         }
         Ok(())
     }
-}
 
-#[async_trait::async_trait]
-impl CommitCoordinator for GraphStore {
-    async fn commit(
+    /// The commit arbitration/backfill state machine, run inside `txn`.
+    ///
+    /// Mirrors the in-memory backend and UC OSS `postCommitCore`. The caller
+    /// owns the transaction; an error leaves it to be rolled back.
+    async fn commit_txn(
         &self,
-        table_id: &str,
+        txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        table_id: Uuid,
         commit_info: Option<CommitInfo>,
         latest_backfilled_version: Option<i64>,
     ) -> CommitResult<()> {
-        if commit_info.is_none() && latest_backfilled_version.is_none() {
-            return Err(CommitError::InvalidArgument(
-                "either commit_info or latest_backfilled_version must be provided".to_string(),
-            ));
-        }
-        if let Some(info) = &commit_info {
-            validate_commit_info(info)?;
-        }
-        let table_id = parse_table_id(table_id)?;
-
-        let mut txn = self.pool.begin().await.map_err(map_sqlx_err)?;
-        let bounds = self.commit_bounds(&mut txn, table_id).await?;
+        let bounds = self.commit_bounds(txn, table_id).await?;
 
         match (bounds, commit_info) {
             // No commits yet.
             (None, Some(info)) => {
                 // Onboarding commit: accept the first posted commit as-is.
-                self.insert_commit(&mut txn, table_id, &info).await?;
+                self.insert_commit(txn, table_id, &info).await?;
             }
             (None, None) => {
                 return Err(CommitError::InvalidArgument(
@@ -182,7 +181,7 @@ impl CommitCoordinator for GraphStore {
                         b.last
                     )));
                 }
-                self.backfill(&mut txn, table_id, b.last, lbv).await?;
+                self.backfill(txn, table_id, b.last, lbv).await?;
             }
 
             // Normal commit to an existing table.
@@ -224,13 +223,58 @@ impl CommitCoordinator for GraphStore {
                     )));
                 }
 
-                self.insert_commit(&mut txn, table_id, &info).await?;
+                self.insert_commit(txn, table_id, &info).await?;
                 if let Some(lbv) = latest_backfilled_version {
-                    self.backfill(&mut txn, table_id, b.last, lbv).await?;
+                    self.backfill(txn, table_id, b.last, lbv).await?;
                 }
             }
         }
 
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CommitCoordinator for GraphStore {
+    async fn commit(
+        &self,
+        table_id: &str,
+        commit_info: Option<CommitInfo>,
+        latest_backfilled_version: Option<i64>,
+    ) -> CommitResult<()> {
+        if commit_info.is_none() && latest_backfilled_version.is_none() {
+            return Err(CommitError::InvalidArgument(
+                "either commit_info or latest_backfilled_version must be provided".to_string(),
+            ));
+        }
+        if let Some(info) = &commit_info {
+            validate_commit_info(info)?;
+        }
+        let table_id = parse_table_id(table_id)?;
+
+        let mut txn = self.pool.begin().await.map_err(map_sqlx_err)?;
+        self.commit_txn(&mut txn, table_id, commit_info, latest_backfilled_version)
+            .await?;
+        txn.commit().await.map_err(map_sqlx_err)?;
+        Ok(())
+    }
+
+    async fn commit_batch(&self, mut commits: Vec<TableCommit>) -> CommitResult<()> {
+        validate_batch(&mut commits)?;
+        let mut txn = self.pool.begin().await.map_err(map_sqlx_err)?;
+        for commit in commits {
+            let table_id =
+                parse_table_id(&commit.table_id).map_err(|e| e.for_table(&commit.table_id))?;
+            self.commit_txn(
+                &mut txn,
+                table_id,
+                Some(commit.commit_info),
+                commit.latest_backfilled_version,
+            )
+            .await
+            .map_err(|e| e.for_table(&commit.table_id))?;
+        }
+        // Dropping `txn` on an early return rolls the whole batch back.
         txn.commit().await.map_err(map_sqlx_err)?;
         Ok(())
     }
@@ -311,4 +355,46 @@ impl CommitCoordinator for GraphStore {
 
         Ok((commits, latest_table_version))
     }
+
+    async fn acquire_backfill_lease(
+        &self,
+        table_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        let table_id = parse_table_id(table_id)?;
+        // `float8 * interval` is the multiplication Postgres defines.
+        let ttl_millis = ttl.as_millis() as f64;
+        // The upsert only takes effect for the current holder or once the
+        // previous lease has expired; otherwise no row is returned.
+        let row = sqlx::query!(
+            "INSERT INTO delta_commit_leases (table_id, owner, expires_at) \
+             VALUES ($1, $2, now() + $3 * interval '1 millisecond') \
+             ON CONFLICT (table_id) DO UPDATE \
+               SET owner = EXCLUDED.owner, expires_at = EXCLUDED.expires_at \
+               WHERE delta_commit_leases.owner = EXCLUDED.owner \
+                  OR delta_commit_leases.expires_at <= now() \
+             RETURNING table_id",
+            table_id,
+            owner,
+            ttl_millis
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(row.is_some())
+    }
+
+    async fn release_backfill_lease(&self, table_id: &str, owner: &str) -> CommitResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        sqlx::query!(
+            "DELETE FROM delta_commit_leases WHERE table_id = $1 AND owner = $2",
+            table_id,
+            owner
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(())
+    }
 }
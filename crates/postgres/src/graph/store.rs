@@ -155,6 +155,38 @@ impl Store {
         Ok(obj)
     }
 
+    /// Update several objects in one transaction.
+    ///
+    /// Each `(id, object)` pair is applied as by
+    /// [`update_object`](Self::update_object), taking the label, name and
+    /// properties from `object`. If any update fails, none is applied.
+    ///
+    /// ## Returns
+    /// The updated objects, in the order of `updates`.
+    ///
+    /// ## Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If an object does not exist.
+    /// - [AlreadyExists](crate::Error::AlreadyExists): If an update moves an
+    ///   object onto a name that is already taken.
+    pub async fn update_objects(&self, updates: Vec<(Uuid, Object)>) -> Result<Vec<Object>> {
+        let mut txn = self.pool.begin().await?;
+        let mut updated = Vec::with_capacity(updates.len());
+        for (id, object) in updates {
+            updated.push(
+                update_object(
+                    &id,
+                    Some(&object.label),
+                    Some(object.name.as_ref()),
+                    object.properties,
+                    &mut txn,
+                )
+                .await?,
+            );
+        }
+        txn.commit().await?;
+        Ok(updated)
+    }
+
     /// Delete an object from the store.
     ///
     /// ## Parameters
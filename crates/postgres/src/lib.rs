@@ -5,6 +5,7 @@ mod commit_coordinator;
 mod constants;
 mod error;
 mod graph;
+mod maintenance;
 mod pagination;
 mod resources;
 mod secrets;
@@ -0,0 +1,222 @@
+//! Postgres-backed [`MaintenanceStore`].
+//!
+//! Commit reports are kept verbatim as `jsonb` in `delta_commit_reports`; jobs
+//! live in `table_maintenance_jobs`, keyed by an identity column so job ids
+//! increase in scheduling order. Job kinds and statuses are stored as their
+//! wire (SCREAMING_SNAKE_CASE) names.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use unitycatalog_common::models::delta::v1::{
+    DeltaCommitReport, DeltaMaintenanceJob, DeltaMaintenanceJobKind,
+};
+use unitycatalog_common::services::maintenance::{
+    MaintenanceError, MaintenanceResult, MaintenanceStore, StoredCommitReport,
+};
+use uuid::Uuid;
+
+use crate::GraphStore;
+
+fn backend<E: std::fmt::Display>(e: E) -> MaintenanceError {
+    MaintenanceError::Backend(e.to_string())
+}
+
+fn parse_table_id(table_id: &str) -> MaintenanceResult<Uuid> {
+    Uuid::parse_str(table_id).map_err(|_| backend("table_id is not a valid UUID"))
+}
+
+/// The wire name of a unit enum variant, e.g. `COMPACTION`.
+fn enum_to_text<T: Serialize>(value: T) -> MaintenanceResult<String> {
+    match serde_json::to_value(value).map_err(backend)? {
+        serde_json::Value::String(s) => Ok(s),
+        other => Err(backend(format!("unexpected enum encoding: {other}"))),
+    }
+}
+
+fn enum_from_text<T: DeserializeOwned>(text: String) -> MaintenanceResult<T> {
+    serde_json::from_value(serde_json::Value::String(text)).map_err(backend)
+}
+
+fn millis_to_dt(millis: i64) -> MaintenanceResult<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| backend(format!("timestamp out of range: {millis}")))
+}
+
+/// Row shape shared by the job queries.
+struct JobRow {
+    id: i64,
+    table_id: Uuid,
+    kind: String,
+    status: String,
+    reason: String,
+    message: Option<String>,
+    committed_version: Option<i64>,
+    num_files_added: Option<i64>,
+    num_files_removed: Option<i64>,
+    num_bytes_removed: Option<i64>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    started_at: Option<chrono::DateTime<chrono::Utc>>,
+    finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TryFrom<JobRow> for DeltaMaintenanceJob {
+    type Error = MaintenanceError;
+
+    fn try_from(row: JobRow) -> MaintenanceResult<Self> {
+        Ok(DeltaMaintenanceJob {
+            job_id: row.id,
+            table_id: row.table_id.hyphenated().to_string(),
+            kind: enum_from_text(row.kind)?,
+            status: enum_from_text(row.status)?,
+            reason: row.reason,
+            message: row.message,
+            committed_version: row.committed_version,
+            num_files_added: row.num_files_added,
+            num_files_removed: row.num_files_removed,
+            num_bytes_removed: row.num_bytes_removed,
+            created_at: row.created_at.timestamp_millis(),
+            started_at: row.started_at.map(|dt| dt.timestamp_millis()),
+            finished_at: row.finished_at.map(|dt| dt.timestamp_millis()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl MaintenanceStore for GraphStore {
+    async fn record_report(
+        &self,
+        table_id: &str,
+        report: DeltaCommitReport,
+    ) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        let report = serde_json::to_value(&report).map_err(backend)?;
+        sqlx::query!(
+            "INSERT INTO delta_commit_reports (table_id, report) VALUES ($1, $2)",
+            table_id,
+            report
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(backend)?;
+        Ok(())
+    }
+
+    async fn list_reports(
+        &self,
+        table_id: &str,
+        since: Option<i64>,
+    ) -> MaintenanceResult<Vec<StoredCommitReport>> {
+        let table_uuid = parse_table_id(table_id)?;
+        let since = since.map(millis_to_dt).transpose()?;
+        let rows = sqlx::query!(
+            "SELECT report, reported_at FROM delta_commit_reports \
+             WHERE table_id = $1 AND ($2::timestamptz IS NULL OR reported_at > $2) \
+             ORDER BY reported_at ASC, id ASC",
+            table_uuid,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend)?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(StoredCommitReport {
+                    table_id: table_id.to_string(),
+                    reported_at: row.reported_at.timestamp_millis(),
+                    report: serde_json::from_value(row.report).map_err(backend)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn prune_reports(&self, table_id: &str, before: i64) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        let before = millis_to_dt(before)?;
+        sqlx::query!(
+            "DELETE FROM delta_commit_reports WHERE table_id = $1 AND reported_at < $2",
+            table_id,
+            before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(backend)?;
+        Ok(())
+    }
+
+    async fn create_job(
+        &self,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        reason: String,
+    ) -> MaintenanceResult<DeltaMaintenanceJob> {
+        let table_id = parse_table_id(table_id)?;
+        let row = sqlx::query_as!(
+            JobRow,
+            "INSERT INTO table_maintenance_jobs (table_id, kind, status, reason) \
+             VALUES ($1, $2, 'PENDING', $3) \
+             RETURNING id, table_id, kind, status, reason, message, committed_version, \
+                       num_files_added, num_files_removed, num_bytes_removed, \
+                       created_at, started_at, finished_at",
+            table_id,
+            enum_to_text(kind)?,
+            reason
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(backend)?;
+        row.try_into()
+    }
+
+    async fn update_job(&self, job: &DeltaMaintenanceJob) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(&job.table_id)?;
+        let started_at = job.started_at.map(millis_to_dt).transpose()?;
+        let finished_at = job.finished_at.map(millis_to_dt).transpose()?;
+        let result = sqlx::query!(
+            "UPDATE table_maintenance_jobs \
+             SET status = $3, message = $4, committed_version = $5, num_files_added = $6, \
+                 num_files_removed = $7, num_bytes_removed = $8, started_at = $9, \
+                 finished_at = $10 \
+             WHERE id = $1 AND table_id = $2",
+            job.job_id,
+            table_id,
+            enum_to_text(job.status)?,
+            job.message,
+            job.committed_version,
+            job.num_files_added,
+            job.num_files_removed,
+            job.num_bytes_removed,
+            started_at,
+            finished_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(backend)?;
+        if result.rows_affected() == 0 {
+            return Err(MaintenanceError::JobNotFound(job.job_id));
+        }
+        Ok(())
+    }
+
+    async fn list_jobs(
+        &self,
+        table_id: &str,
+        limit: Option<usize>,
+    ) -> MaintenanceResult<Vec<DeltaMaintenanceJob>> {
+        let table_id = parse_table_id(table_id)?;
+        let limit = limit.map(|l| l.min(i64::MAX as usize) as i64);
+        let rows = sqlx::query_as!(
+            JobRow,
+            "SELECT id, table_id, kind, status, reason, message, committed_version, \
+                    num_files_added, num_files_removed, num_bytes_removed, \
+                    created_at, started_at, finished_at \
+             FROM table_maintenance_jobs WHERE table_id = $1 \
+             ORDER BY id DESC LIMIT $2",
+            table_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend)?;
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -140,6 +140,36 @@ impl ResourceStore for GraphStore {
         ))
     }
 
+    /// Rename a resource and update its dependents in one transaction.
+    ///
+    /// The name column carries the `(label, name)` uniqueness constraint, so a
+    /// rename onto a taken name fails inside the update and rolls back the
+    /// dependents' updates with it.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the resource to rename.
+    /// - `resource`: The updated resource carrying the new name.
+    /// - `dependents`: Updates to resources referring to it by name.
+    ///
+    /// # Returns
+    /// The renamed resource.
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)> {
+        let (uuid, _object) = self.ident_to_uuid(id).await?;
+        let mut updates = Vec::with_capacity(dependents.len() + 1);
+        updates.push((uuid, resource.try_into()?));
+        for (ident, resource) in dependents {
+            let (dependent, _object) = self.ident_to_uuid(&ident).await?;
+            updates.push((dependent, resource.try_into()?));
+        }
+        let renamed = self.update_objects(updates).await?.swap_remove(0);
+        Ok((renamed.try_into()?, uuid.into()))
+    }
+
     /// Add an association between two resources.
     async fn add_association(
         &self,
@@ -12,7 +12,9 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use unitycatalog_common::models::delta_commits::v1::CommitInfo;
-use unitycatalog_common::services::commit_coordinator::{CommitCoordinator, CommitError};
+use unitycatalog_common::services::commit_coordinator::{
+    CommitCoordinator, CommitError, TableCommit,
+};
 use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
 use unitycatalog_postgres::GraphStore;
 use uuid::Uuid;
@@ -139,6 +141,35 @@ async fn unknown_table_reports_zero(pool: sqlx::PgPool) {
     assert_eq!(latest, 0);
 }
 
+fn table_commit(table_id: &str, version: i64) -> TableCommit {
+    TableCommit {
+        table_id: table_id.to_string(),
+        commit_info: commit_info(version),
+        latest_backfilled_version: None,
+    }
+}
+
+#[sqlx::test]
+async fn batch_commit_is_all_or_nothing(pool: sqlx::PgPool) {
+    let cc = store(pool);
+    let (fact, dim) = (table_id(), table_id());
+    cc.commit(&fact, Some(commit_info(1)), None).await.unwrap();
+
+    cc.commit_batch(vec![table_commit(&fact, 2), table_commit(&dim, 1)])
+        .await
+        .unwrap();
+    assert_eq!(cc.get_commits(&fact, 0, None).await.unwrap().1, 2);
+    assert_eq!(cc.get_commits(&dim, 0, None).await.unwrap().1, 1);
+
+    // `dim` v1 is taken, so `fact` v3 must be rolled back with it.
+    let err = cc
+        .commit_batch(vec![table_commit(&fact, 3), table_commit(&dim, 1)])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CommitError::VersionConflict(ref m) if m.contains(&dim)));
+    assert_eq!(cc.get_commits(&fact, 0, None).await.unwrap().1, 2);
+}
+
 #[sqlx::test]
 async fn first_writer_wins_under_concurrency(pool: sqlx::PgPool) {
     let cc = std::sync::Arc::new(store(pool));
@@ -166,3 +197,25 @@ async fn first_writer_wins_under_concurrency(pool: sqlx::PgPool) {
     assert_eq!(wins, 1, "exactly one writer wins version 2");
     assert_eq!(conflicts, 7);
 }
+
+#[sqlx::test]
+async fn backfill_lease_is_exclusive(pool: sqlx::PgPool) {
+    let cc = store(pool);
+    let t = table_id();
+    let ttl = std::time::Duration::from_secs(60);
+    assert!(cc.acquire_backfill_lease(&t, "a", ttl).await.unwrap());
+    assert!(cc.acquire_backfill_lease(&t, "a", ttl).await.unwrap());
+    assert!(!cc.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+
+    cc.release_backfill_lease(&t, "a").await.unwrap();
+    assert!(cc.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+
+    // An expired lease can be taken over.
+    let u = table_id();
+    assert!(
+        cc.acquire_backfill_lease(&u, "a", std::time::Duration::ZERO)
+            .await
+            .unwrap()
+    );
+    assert!(cc.acquire_backfill_lease(&u, "b", ttl).await.unwrap());
+}
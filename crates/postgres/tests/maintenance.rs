@@ -0,0 +1,76 @@
+//! Integration tests for the Postgres-backed maintenance store.
+//!
+//! Gated behind the `integration-pg` feature like the commit-coordinator tests.
+#![cfg(feature = "integration-pg")]
+
+use unitycatalog_common::models::delta::v1::{
+    DeltaCommitReport, DeltaMaintenanceJobKind, DeltaMaintenanceJobStatus,
+};
+use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+use unitycatalog_common::services::maintenance::{MaintenanceError, MaintenanceStore};
+use unitycatalog_postgres::GraphStore;
+
+fn store(pool: sqlx::PgPool) -> GraphStore {
+    let encryptor =
+        EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+    GraphStore::new(pool, encryptor)
+}
+
+const TABLE: &str = "00000000-0000-0000-0000-000000000001";
+
+#[sqlx::test]
+async fn reports_roundtrip(pool: sqlx::PgPool) {
+    let ms = store(pool);
+    let report = DeltaCommitReport {
+        num_files_added: Some(3),
+        num_bytes_added: Some(300),
+        ..Default::default()
+    };
+    ms.record_report(TABLE, report.clone()).await.unwrap();
+    ms.record_report(TABLE, DeltaCommitReport::default())
+        .await
+        .unwrap();
+
+    let reports = ms.list_reports(TABLE, None).await.unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].report, report);
+    assert_eq!(reports[0].table_id, TABLE);
+
+    ms.prune_reports(TABLE, reports[1].reported_at + 1)
+        .await
+        .unwrap();
+    assert!(ms.list_reports(TABLE, None).await.unwrap().is_empty());
+}
+
+#[sqlx::test]
+async fn jobs_roundtrip_newest_first(pool: sqlx::PgPool) {
+    let ms = store(pool);
+    let first = ms
+        .create_job(TABLE, DeltaMaintenanceJobKind::Checkpoint, "a".into())
+        .await
+        .unwrap();
+    let mut second = ms
+        .create_job(TABLE, DeltaMaintenanceJobKind::Vacuum, "b".into())
+        .await
+        .unwrap();
+    assert_eq!(first.status, DeltaMaintenanceJobStatus::Pending);
+
+    second.status = DeltaMaintenanceJobStatus::Succeeded;
+    second.num_files_removed = Some(4);
+    second.started_at = Some(second.created_at);
+    second.finished_at = Some(second.created_at + 10);
+    ms.update_job(&second).await.unwrap();
+
+    let jobs = ms.list_jobs(TABLE, None).await.unwrap();
+    assert_eq!(jobs, vec![second.clone(), first]);
+    assert_eq!(
+        ms.list_jobs(TABLE, Some(1)).await.unwrap(),
+        vec![second.clone()]
+    );
+
+    second.job_id += 100;
+    assert!(matches!(
+        ms.update_job(&second).await,
+        Err(MaintenanceError::JobNotFound(_))
+    ));
+}
@@ -0,0 +1,82 @@
+//! Integration tests for the Postgres-backed resource store.
+//!
+//! Gated behind the `integration-pg` feature like the commit-coordinator tests.
+#![cfg(feature = "integration-pg")]
+
+use unitycatalog_common::Error;
+use unitycatalog_common::models::tables::v1::Table;
+use unitycatalog_common::models::{ObjectLabel, ResourceIdent, ResourceName};
+use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+use unitycatalog_common::store::{ResourceStore, ResourceStoreReader};
+use unitycatalog_postgres::GraphStore;
+
+fn store(pool: sqlx::PgPool) -> GraphStore {
+    let encryptor =
+        EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+    GraphStore::new(pool, encryptor)
+}
+
+fn table(name: &str, definition: Option<&str>) -> Table {
+    Table {
+        name: name.into(),
+        catalog_name: "cat".into(),
+        schema_name: "sch".into(),
+        full_name: format!("cat.sch.{name}"),
+        view_definition: definition.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn by_name(name: &str) -> ResourceIdent {
+    ObjectLabel::Table.to_ident(ResourceName::new(["cat", "sch", name]))
+}
+
+async fn definition(store: &GraphStore, name: &str) -> Option<String> {
+    let (resource, _) = store.get(&by_name(name)).await.unwrap();
+    Table::try_from(resource).unwrap().view_definition
+}
+
+#[sqlx::test]
+async fn rename_moves_name_and_updates_dependents(pool: sqlx::PgPool) {
+    let store = store(pool);
+    let (_, reference) = store.create(table("a", None).into()).await.unwrap();
+    store.create(table("b", None).into()).await.unwrap();
+    store
+        .create(table("v", Some("SELECT * FROM cat.sch.a")).into())
+        .await
+        .unwrap();
+    let ident = ObjectLabel::Table.to_ident(reference.clone());
+    let dependents = |to: &str| {
+        vec![(
+            by_name("v"),
+            table("v", Some(&format!("SELECT * FROM cat.sch.{to}"))).into(),
+        )]
+    };
+
+    // The new name is taken: neither the table nor its dependent changes.
+    let err = store
+        .rename(&ident, table("b", None).into(), dependents("b"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::AlreadyExists), "{err}");
+    assert_eq!(store.get(&by_name("a")).await.unwrap().1, reference);
+    assert_eq!(
+        definition(&store, "v").await.as_deref(),
+        Some("SELECT * FROM cat.sch.a")
+    );
+
+    let (_, renamed) = store
+        .rename(&ident, table("c", None).into(), dependents("c"))
+        .await
+        .unwrap();
+    assert_eq!(renamed, reference);
+    assert_eq!(store.get(&by_name("c")).await.unwrap().1, reference);
+    assert!(matches!(
+        store.get(&by_name("a")).await.unwrap_err(),
+        Error::NotFound
+    ));
+    assert_eq!(
+        definition(&store, "v").await.as_deref(),
+        Some("SELECT * FROM cat.sch.c")
+    );
+}
@@ -7,13 +7,14 @@
 //! lives in [`crate::services::commit_coordinator`].
 
 use unitycatalog_common::models::delta_commits::v1::*;
-use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table, TableType};
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table};
 use unitycatalog_common::models::{ResourceIdent, ResourceRef};
 
 use super::RequestContext;
 pub use crate::codegen::delta_commits::DeltaCommitHandler;
 use crate::policy::{Permission, Policy};
 use crate::services::location::StorageLocationUrl;
+use crate::services::managed_delta_contract as contract;
 use crate::store::ResourceStore;
 use crate::{Error, Result};
 use unitycatalog_common::services::commit_coordinator::{CommitError, ProvidesCommitCoordinator};
@@ -45,7 +46,7 @@ where
     let (resource, _) = handler.get(&ident).await?;
     let table: Table = resource.try_into()?;
 
-    if table.table_type != TableType::Managed as i32 {
+    if !contract::is_catalog_managed(table.table_type) {
         return Err(Error::invalid_argument(
             "only managed tables support catalog-managed commits",
         ));
@@ -14,7 +14,9 @@ use std::collections::BTreeMap;
 
 use async_trait::async_trait;
 
-use unitycatalog_common::models::staging_tables::v1::CreateStagingTableRequest;
+use unitycatalog_common::metric_view;
+use unitycatalog_common::models::shares::v1::{DataObjectType, Share};
+use unitycatalog_common::models::staging_tables::v1::{CreateStagingTableRequest, StagingTable};
 use unitycatalog_common::models::tables::v1::{
     Column, CreateTableRequest, DataSourceFormat, DeleteTableRequest, GetTableRequest, Table,
     TableType,
@@ -25,8 +27,11 @@ use unitycatalog_common::models::temporary_credentials::v1::{
     generate_temporary_table_credentials_request::Operation as TableOp,
     temporary_credential::Credentials,
 };
-use unitycatalog_common::models::{ResourceIdent, ResourceRef};
-use unitycatalog_common::services::commit_coordinator::ProvidesCommitCoordinator;
+use unitycatalog_common::models::{
+    AssociationLabel, Resource, ResourceIdent, ResourceName, ResourceRef,
+};
+use unitycatalog_common::services::commit_coordinator::{ProvidesCommitCoordinator, TableCommit};
+use unitycatalog_common::services::maintenance::ProvidesMaintenanceStore;
 
 use crate::api::RequestContext;
 use crate::api::credentials::CredentialHandlerExt;
@@ -40,6 +45,7 @@ use crate::services::ProvidesLocalStoragePolicy;
 use crate::services::location::StorageLocationUrl;
 use crate::services::managed_delta_contract as contract;
 use crate::services::object_store::validate_external_storage_location;
+use crate::services::schema_evolution;
 use crate::store::ResourceStore;
 use crate::{Error, Result};
 
@@ -134,6 +140,27 @@ pub trait DeltaApiHandler<Cx = RequestContext>: Send + Sync + 'static {
         context: Cx,
     ) -> Result<()>;
 
+    /// `GET /delta/v1/catalogs/{catalog}/schemas/{schema}/tables/{table}/maintenance-jobs`
+    ///
+    /// Server extension (not in `delta.yaml`): the table's maintenance-job
+    /// history, newest first.
+    async fn list_maintenance_jobs(
+        &self,
+        path: TablePath,
+        max_results: Option<usize>,
+        context: Cx,
+    ) -> Result<DeltaListMaintenanceJobsResponse>;
+
+    /// `POST /delta/v1/commits`
+    ///
+    /// Server extension (not in `delta.yaml`): ratify one commit on each of
+    /// several managed tables atomically.
+    async fn batch_commit(
+        &self,
+        request: DeltaBatchCommitRequest,
+        context: Cx,
+    ) -> Result<DeltaBatchCommitResponse>;
+
     /// `GET /delta/v1/staging-tables/{table_id}/credentials`
     async fn get_staging_table_credentials(
         &self,
@@ -185,6 +212,7 @@ where
         + CredentialHandlerExt
         + TableManager
         + ProvidesCommitCoordinator
+        + ProvidesMaintenanceStore
         + ProvidesLocalStoragePolicy,
 {
     async fn get_config(
@@ -326,6 +354,7 @@ where
         let columns =
             contract::delta_columns_to_uc(&request.columns, request.partition_columns.as_deref())?;
         let stored_properties = contract::build_stored_properties(&request);
+        contract::validate_iceberg_properties(&stored_properties, Some(&request.location))?;
 
         // For MANAGED, finalize the staging reservation: enforce creator-match,
         // tableId identity, mark committed, and adopt the staging uuid.
@@ -445,13 +474,11 @@ where
 
     async fn rename_table(
         &self,
-        _path: TablePath,
-        _request: DeltaRenameTableRequest,
-        _context: RequestContext,
+        path: TablePath,
+        request: DeltaRenameTableRequest,
+        context: RequestContext,
     ) -> Result<()> {
-        // The UC table store has no rename op yet; surface as not-implemented until
-        // an UpdateTable/rename path exists (tracked as a follow-up).
-        Err(Error::NotImplemented("Delta API: renameTable"))
+        rename_table_impl(self, path, request, context).await
     }
 
     async fn get_table_credentials(
@@ -528,10 +555,51 @@ where
                 "commit-version must be non-negative",
             ));
         }
-        // Accept-and-ack: we have no maintenance scheduler yet (follow-up issue).
+        // Keep the commit report for the maintenance planner; reports without
+        // one carry nothing it can use.
+        if let Some(commit_report) = request.report.and_then(|r| r.commit_report) {
+            self.maintenance_store()
+                .record_report(&request.table_id, commit_report)
+                .await?;
+        }
         Ok(())
     }
 
+    async fn list_maintenance_jobs(
+        &self,
+        path: TablePath,
+        max_results: Option<usize>,
+        context: RequestContext,
+    ) -> Result<DeltaListMaintenanceJobsResponse> {
+        let table = TableHandler::get_table(
+            self,
+            GetTableRequest {
+                full_name: format!("{}.{}.{}", path.catalog, path.schema, path.table),
+                include_delta_metadata: None,
+                include_browse: None,
+                include_manifest_capabilities: None,
+            },
+            context,
+        )
+        .await?;
+        let table_id = table
+            .table_id
+            .ok_or_else(|| Error::invalid_argument("table has no id"))?;
+        let jobs = self
+            .maintenance_store()
+            .list_jobs(&table_id, max_results)
+            .await?;
+        Ok(DeltaListMaintenanceJobsResponse { jobs })
+    }
+
+    async fn batch_commit(
+        &self,
+        request: DeltaBatchCommitRequest,
+        context: RequestContext,
+    ) -> Result<DeltaBatchCommitResponse> {
+        batch_commit_impl(self, request, context).await
+    }
+
     async fn get_staging_table_credentials(
         &self,
         table_id: String,
@@ -627,7 +695,7 @@ where
     // of their own; the Delta API cannot serve them.
     if !matches!(
         TableType::try_from(table.table_type),
-        Ok(TableType::Managed | TableType::External)
+        Ok(TableType::Managed | TableType::MaterializedView | TableType::External)
     ) {
         return Err(Error::invalid_argument(format!(
             "table '{}' is not a Delta table and cannot be loaded via the Delta API",
@@ -636,8 +704,9 @@ where
     }
 
     let metadata = build_table_metadata(&table);
+    let uniform = contract::uniform_from_properties(&metadata.properties);
 
-    let (commits, latest_table_version) = if table.table_type == TableType::Managed as i32
+    let (commits, latest_table_version) = if contract::is_catalog_managed(table.table_type)
         && table.data_source_format == DataSourceFormat::Delta as i32
         && let Some(id) = table.table_id.as_deref()
     {
@@ -657,7 +726,7 @@ where
     Ok(DeltaLoadTableResponse {
         metadata,
         commits,
-        uniform: None,
+        uniform,
         latest_table_version,
     })
 }
@@ -851,11 +920,12 @@ where
     }
 
     let mut properties: BTreeMap<String, String> = table.properties.clone().into_iter().collect();
-    let is_managed = table.table_type == TableType::Managed as i32;
+    let is_managed = contract::is_catalog_managed(table.table_type);
     let mut metadata_changed = false;
 
     // Apply in canonical order (not request order). We make multiple passes.
     // 1. set-columns / set-partition-columns
+    let previous_schema = contract::uc_columns_to_delta(&table.columns);
     apply_schema_and_partitions(&mut table, &request.updates)?;
     if request.updates.iter().any(|u| {
         matches!(
@@ -897,6 +967,35 @@ where
         }
     }
 
+    // For MANAGED tables the catalog schema is authoritative, so the new
+    // columns must be a valid evolution of the stored ones and every CHECK
+    // constraint must still hold up against them.
+    if is_managed {
+        let proposed = request.updates.iter().find_map(|u| match u {
+            DeltaTableUpdate::SetColumns { columns } => Some(columns),
+            _ => None,
+        });
+        if let Some(proposed) = proposed {
+            schema_evolution::validate_schema_change(&previous_schema, proposed, &properties)?;
+        }
+        let constraints_changed = request.updates.iter().any(|u| match u {
+            DeltaTableUpdate::SetProperties { updates } => updates
+                .keys()
+                .any(|k| k.starts_with(schema_evolution::CONSTRAINT_PREFIX)),
+            DeltaTableUpdate::RemoveProperties { removals } => removals
+                .iter()
+                .any(|k| k.starts_with(schema_evolution::CONSTRAINT_PREFIX)),
+            DeltaTableUpdate::SetProtocol { .. } => true,
+            _ => false,
+        });
+        if proposed.is_some() || constraints_changed {
+            schema_evolution::validate_constraints(
+                &contract::uc_columns_to_delta(&table.columns),
+                &properties,
+            )?;
+        }
+    }
+
     // 5. set-domain-metadata / 6. remove-domain-metadata
     for update in &request.updates {
         match update {
@@ -960,16 +1059,33 @@ where
     }
 
     // 9. add-commit + set-latest-backfilled-version → commit coordinator
-    let add_commit = request.updates.iter().find_map(|u| match u {
-        DeltaTableUpdate::AddCommit { commit, .. } => Some(commit.clone()),
-        _ => None,
-    });
+    let (add_commit, uniform) = request
+        .updates
+        .iter()
+        .find_map(|u| match u {
+            DeltaTableUpdate::AddCommit { commit, uniform } => {
+                Some((Some(commit.clone()), uniform.clone()))
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
     let backfill = request.updates.iter().find_map(|u| match u {
         DeltaTableUpdate::SetLatestBackfilledVersion {
             latest_published_version,
         } => Some(*latest_published_version),
         _ => None,
     });
+    // A UniForm writer reports the Iceberg metadata it converted alongside the
+    // commit; keep it so the Iceberg REST catalog can serve the table.
+    if let Some(uniform) = uniform.as_ref() {
+        contract::derive_from_uniform(&mut properties, uniform);
+        metadata_changed = true;
+    }
+    // Checked before the commit is ratified, so a rejected update leaves
+    // neither the log nor the stored metadata changed.
+    if metadata_changed {
+        contract::validate_iceberg_properties(&properties, table.storage_location.as_deref())?;
+    }
     if add_commit.is_some() || backfill.is_some() {
         if !is_managed {
             return Err(Error::invalid_argument(
@@ -1001,6 +1117,87 @@ where
     build_load_table_response(handler, table).await
 }
 
+/// Ratify a multi-table commit. Every table is resolved, authorized for WRITE
+/// and checked against its asserted UUID before the coordinator sees the batch,
+/// which then records all commits or none.
+async fn batch_commit_impl<T>(
+    handler: &T,
+    request: DeltaBatchCommitRequest,
+    context: RequestContext,
+) -> Result<DeltaBatchCommitResponse>
+where
+    T: ResourceStore
+        + Policy<RequestContext>
+        + TableHandler<RequestContext>
+        + ProvidesCommitCoordinator,
+{
+    let mut tables = Vec::with_capacity(request.commits.len());
+    let mut commits = Vec::with_capacity(request.commits.len());
+    for entry in request.commits {
+        let full_name = format!("{}.{}.{}", entry.catalog, entry.schema, entry.table);
+        let table = TableHandler::get_table(
+            handler,
+            GetTableRequest {
+                full_name: full_name.clone(),
+                include_delta_metadata: None,
+                include_browse: None,
+                include_manifest_capabilities: None,
+            },
+            context.clone(),
+        )
+        .await?;
+        let table_uuid = table
+            .table_id
+            .clone()
+            .ok_or_else(|| Error::invalid_argument("table has no id"))?;
+        let uuid = uuid::Uuid::parse_str(&table_uuid)
+            .map_err(|_| Error::invalid_argument("table id is not a valid UUID"))?;
+        handler
+            .authorize_checked(
+                &ResourceIdent::Table(ResourceRef::Uuid(uuid)),
+                &Permission::Write,
+                &context,
+            )
+            .await?;
+        if entry.table_uuid != table_uuid {
+            return Err(Error::UpdateRequirementConflict(format!(
+                "table-uuid for {full_name} is {}, but the table has {table_uuid}",
+                entry.table_uuid
+            )));
+        }
+        if !contract::is_catalog_managed(table.table_type) {
+            return Err(Error::invalid_argument(format!(
+                "{full_name} is not a MANAGED table; only managed tables take commits"
+            )));
+        }
+        if table.data_source_format != DataSourceFormat::Delta as i32 {
+            return Err(Error::invalid_argument(format!(
+                "{full_name} is not a Delta table; only Delta tables take commits"
+            )));
+        }
+        commits.push(TableCommit {
+            table_id: table_uuid,
+            commit_info: unitycatalog_common::models::delta_commits::v1::CommitInfo {
+                version: entry.commit.version,
+                timestamp: entry.commit.timestamp,
+                file_name: entry.commit.file_name,
+                file_size: entry.commit.file_size,
+                file_modification_timestamp: entry.commit.file_modification_timestamp,
+            },
+            latest_backfilled_version: entry.latest_published_version,
+        });
+        tables.push(table);
+    }
+
+    handler.commit_coordinator().commit_batch(commits).await?;
+
+    let mut responses = Vec::with_capacity(tables.len());
+    for table in tables {
+        responses.push(build_load_table_response(handler, table).await?);
+    }
+    Ok(DeltaBatchCommitResponse { tables: responses })
+}
+
 /// Apply `set-columns` and `set-partition-columns` in a combined pass, mirroring the
 /// reference `applySchemaAndPartitionColumns`. Columns set replaces the schema;
 /// partition columns re-derive partition indices.
@@ -1057,6 +1254,219 @@ fn apply_schema_and_partitions(table: &mut Table, updates: &[DeltaTableUpdate])
     Ok(())
 }
 
+// ===================================================================
+// renameTable
+// ===================================================================
+
+/// Rename a table within its schema.
+///
+/// The table keeps its id, so tag assignments and commit-coordinator state (both
+/// keyed by id) carry over. References by name are rewritten: the committed
+/// staging reservation of a managed table, share data objects, and the
+/// definitions and dependency lists of views and metric views that read it.
+async fn rename_table_impl<T>(
+    handler: &T,
+    path: TablePath,
+    request: DeltaRenameTableRequest,
+    context: RequestContext,
+) -> Result<()>
+where
+    T: ResourceStore + Policy<RequestContext> + TableHandler<RequestContext>,
+{
+    let new_name = request.new_name.trim();
+    if new_name.is_empty() {
+        return Err(Error::invalid_argument("New table name is required."));
+    }
+    if new_name.contains('.') {
+        return Err(Error::invalid_argument(
+            "renameTable only renames within the same catalog and schema; \
+             cross-schema and cross-catalog moves are not supported",
+        ));
+    }
+
+    let old_full_name = format!("{}.{}.{}", path.catalog, path.schema, path.table);
+    let new_full_name = format!("{}.{}.{}", path.catalog, path.schema, new_name);
+    let table = TableHandler::get_table(
+        handler,
+        GetTableRequest {
+            full_name: old_full_name.clone(),
+            include_delta_metadata: None,
+            include_browse: None,
+            include_manifest_capabilities: None,
+        },
+        context.clone(),
+    )
+    .await?;
+    let table_uuid = table
+        .table_id
+        .as_deref()
+        .ok_or_else(|| Error::invalid_argument("table has no id"))?;
+    let uuid = uuid::Uuid::parse_str(table_uuid)
+        .map_err(|_| Error::invalid_argument("table id is not a valid UUID"))?;
+    let table_ident = ResourceIdent::Table(ResourceRef::Uuid(uuid));
+    handler
+        .authorize_checked(&table_ident, &Permission::Manage, &context)
+        .await?;
+    if table.name == new_name {
+        return Ok(());
+    }
+
+    // A pending staging reservation holds the name for a managed table that has
+    // not been created yet. Staging names are keyed by the bare table name.
+    let reservation = ResourceIdent::staging_table(ResourceName::new([new_name]));
+    match handler.get(&reservation).await {
+        Ok((resource, _)) => {
+            let staging = StagingTable::try_from(resource)?;
+            if !staging.stage_committed
+                && staging.catalog_name == path.catalog
+                && staging.schema_name == path.schema
+            {
+                return Err(Error::AlreadyExists);
+            }
+        }
+        Err(unitycatalog_common::Error::NotFound) => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    // Share objects and views referencing the table by name are rewritten in
+    // the same transaction as the rename, so a failure leaves no reference
+    // pointing at a name that does not exist.
+    let mut dependents =
+        rename_share_objects(handler, &table_ident, &old_full_name, &new_full_name).await?;
+    dependents.extend(
+        rename_view_dependencies(handler, &table_ident, &old_full_name, &new_full_name).await?,
+    );
+    let renamed = Table {
+        name: new_name.to_string(),
+        full_name: new_full_name.clone(),
+        ..table
+    };
+    handler
+        .rename(&table_ident, renamed.into(), dependents)
+        .await?;
+
+    // The committed reservation of a managed table shares the table's id.
+    let staging_ident = ResourceIdent::StagingTable(ResourceRef::Uuid(uuid));
+    if let Ok((resource, _)) = handler.get(&staging_ident).await
+        && let Ok(staging) = StagingTable::try_from(resource)
+    {
+        let staging = StagingTable {
+            name: new_name.to_string(),
+            ..staging
+        };
+        // Staging names are not scoped to a schema, so another reservation may
+        // already use the new name; the committed row is only looked up by id
+        // and location, so keeping its old name is harmless.
+        if let Err(err) = handler
+            .rename(&staging_ident, staging.into(), Vec::new())
+            .await
+        {
+            tracing::warn!(%err, table = %new_full_name, "failed to rename staging reservation");
+        }
+    }
+    Ok(())
+}
+
+/// Shares whose data objects name `from`, updated to point at `to`.
+///
+/// Shares are found through the `ReferencedBy` associations of `table`, which
+/// `update_share` records when a table or view is added. Both shared tables
+/// and shared views are matched. The `shared_as` name recipients see is left
+/// unchanged.
+async fn rename_share_objects<T: ResourceStore>(
+    handler: &T,
+    table: &ResourceIdent,
+    from: &str,
+    to: &str,
+) -> Result<Vec<(ResourceIdent, Resource)>> {
+    let mut updates = Vec::new();
+    for ident in associated(handler, table, AssociationLabel::ReferencedBy).await? {
+        if !matches!(ident, ResourceIdent::Share(_)) {
+            continue;
+        }
+        let mut share = Share::try_from(handler.get(&ident).await?.0)?;
+        let mut changed = false;
+        for object in &mut share.objects {
+            if matches!(
+                object.data_object_type(),
+                DataObjectType::Table | DataObjectType::View
+            ) && object.name.eq_ignore_ascii_case(from)
+            {
+                object.name = to.to_string();
+                changed = true;
+            }
+        }
+        if changed {
+            updates.push((ident, share.into()));
+        }
+    }
+    Ok(updates)
+}
+
+/// Views and metric views that read `from`, rewritten to read `to` instead.
+///
+/// Views are found through the `DependencyOf` associations of `table`, which
+/// are recorded when a view is created over an existing table; a view created
+/// before the table existed is not rewritten. Both the definition and the
+/// derived `view_dependencies` are updated. A definition that cannot be
+/// rewritten keeps its text; its dependency list is still updated so the view
+/// shows up as a dependent of the renamed table.
+async fn rename_view_dependencies<T: ResourceStore>(
+    handler: &T,
+    table: &ResourceIdent,
+    from: &str,
+    to: &str,
+) -> Result<Vec<(ResourceIdent, Resource)>> {
+    let mut updates = Vec::new();
+    for ident in associated(handler, table, AssociationLabel::DependencyOf).await? {
+        let mut view = Table::try_from(handler.get(&ident).await?.0)?;
+        let Some(dependencies) = view.view_dependencies.as_mut() else {
+            continue;
+        };
+        if !metric_view::rename_dependency(dependencies, from, to) {
+            continue;
+        }
+        if let Some(definition) = view.view_definition.as_deref() {
+            let rewritten = if view.table_type == TableType::MetricView as i32 {
+                metric_view::rename_in_metric_view(definition, from, to)
+            } else {
+                metric_view::rename_in_query(definition, from, to)
+            };
+            match rewritten {
+                Ok(Some(definition)) => view.view_definition = Some(definition),
+                Ok(None) => {}
+                Err(err) => tracing::warn!(
+                    %err,
+                    view = %view.name,
+                    "failed to rewrite view definition after table rename"
+                ),
+            }
+        }
+        updates.push((ident, view.into()));
+    }
+    Ok(updates)
+}
+
+/// Every resource associated with `resource` under `label`, across all pages.
+async fn associated<T: ResourceStore>(
+    handler: &T,
+    resource: &ResourceIdent,
+    label: AssociationLabel,
+) -> Result<Vec<ResourceIdent>> {
+    let mut idents = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (page, next) = handler
+            .list_associations(resource, &label, None, None, page_token)
+            .await?;
+        idents.extend(page);
+        match next {
+            Some(token) => page_token = Some(token),
+            None => return Ok(idents),
+        }
+    }
+}
+
 #[cfg(all(test, feature = "memory"))]
 mod tests {
     use std::sync::Arc;
@@ -1423,6 +1833,205 @@ mod tests {
         assert_eq!(commits[0].version, 1);
     }
 
+    fn table_commit(table: &str, table_uuid: &str, version: i64) -> DeltaTableCommit {
+        DeltaTableCommit {
+            catalog: "cat".into(),
+            schema: "sch".into(),
+            table: table.into(),
+            table_uuid: table_uuid.into(),
+            commit: DeltaCommit {
+                version,
+                timestamp: 1800 + version,
+                file_name: format!("{version:020}.{table_uuid}.json"),
+                file_size: 64,
+                file_modification_timestamp: 1800 + version,
+            },
+            latest_published_version: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_commit_is_all_or_nothing() {
+        let h = handler();
+        setup(&h).await;
+        let mut ids = Vec::new();
+        for name in ["fact", "dim"] {
+            let st = stage(&h, name).await;
+            DeltaApiHandler::create_table(
+                &h,
+                schema_path(),
+                create_req(name, &st.staging_location, &st.id),
+                ctx(),
+            )
+            .await
+            .unwrap();
+            ids.push(st.id);
+        }
+        let (fact, dim) = (&ids[0], &ids[1]);
+
+        let resp = h
+            .batch_commit(
+                DeltaBatchCommitRequest {
+                    commits: vec![table_commit("fact", fact, 1), table_commit("dim", dim, 1)],
+                },
+                ctx(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.tables.len(), 2);
+        assert_eq!(resp.tables[0].metadata.table_uuid, *fact);
+        assert!(
+            resp.tables
+                .iter()
+                .all(|t| t.latest_table_version == Some(1))
+        );
+
+        // `dim` v1 is already taken, so `fact` v2 must not be ratified either.
+        let err = h
+            .batch_commit(
+                DeltaBatchCommitRequest {
+                    commits: vec![table_commit("fact", fact, 2), table_commit("dim", dim, 1)],
+                },
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::CommitVersionConflict(_)), "{err:?}");
+        let loaded = h.load_table(table_path("fact"), ctx()).await.unwrap();
+        assert_eq!(loaded.latest_table_version, Some(1));
+
+        // A stale table UUID fails the batch before the coordinator is asked.
+        let err = h
+            .batch_commit(
+                DeltaBatchCommitRequest {
+                    commits: vec![table_commit("fact", dim, 2)],
+                },
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::UpdateRequirementConflict(_)),
+            "{err:?}"
+        );
+
+        // An external table is not catalog-managed, so it cannot join a batch.
+        let mut external = create_req("ext", "s3://bucket/cat/ext", &uuid::Uuid::nil().to_string());
+        external.table_type = DeltaTableType::External;
+        let ext = DeltaApiHandler::create_table(&h, schema_path(), external, ctx())
+            .await
+            .unwrap()
+            .metadata
+            .table_uuid;
+        let err = h
+            .batch_commit(
+                DeltaBatchCommitRequest {
+                    commits: vec![table_commit("fact", fact, 2), table_commit("ext", &ext, 1)],
+                },
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+        let loaded = h.load_table(table_path("fact"), ctx()).await.unwrap();
+        assert_eq!(loaded.latest_table_version, Some(1));
+    }
+
+    #[tokio::test]
+    async fn update_table_add_commit_persists_uniform_metadata() {
+        let h = handler();
+        setup(&h).await;
+        let st = stage(&h, "t").await;
+        DeltaApiHandler::create_table(
+            &h,
+            schema_path(),
+            create_req("t", &st.staging_location, &st.id),
+            ctx(),
+        )
+        .await
+        .unwrap();
+        let loaded = h.load_table(table_path("t"), ctx()).await.unwrap();
+        assert_eq!(loaded.uniform, None);
+
+        let uniform = DeltaUniformMetadata {
+            iceberg: DeltaIcebergMetadata {
+                metadata_location: Some(format!(
+                    "{}/metadata/00001-x.metadata.json",
+                    st.staging_location.trim_end_matches('/')
+                )),
+                converted_delta_version: Some(1),
+                converted_delta_timestamp: Some(1800),
+                base_converted_delta_version: None,
+            },
+        };
+        let req = DeltaUpdateTableRequest {
+            requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                uuid: st.id.clone(),
+            }],
+            updates: vec![DeltaTableUpdate::AddCommit {
+                commit: DeltaCommit {
+                    version: 1,
+                    timestamp: 1800,
+                    file_name: "00000000-0000-0000-0000-00000000002a.json".into(),
+                    file_size: 64,
+                    file_modification_timestamp: 1800,
+                },
+                uniform: Some(uniform.clone()),
+            }],
+        };
+        let updated = h.update_table(table_path("t"), req, ctx()).await.unwrap();
+        assert_eq!(updated.uniform.as_ref(), Some(&uniform));
+
+        let loaded = h.load_table(table_path("t"), ctx()).await.unwrap();
+        assert_eq!(loaded.uniform, Some(uniform));
+
+        // Metadata outside the table is rejected before the commit is ratified,
+        // whether reported as UniForm metadata or set as a property.
+        let outside = DeltaUniformMetadata {
+            iceberg: DeltaIcebergMetadata {
+                metadata_location: Some("file:///etc/00002-x.metadata.json".into()),
+                converted_delta_version: Some(2),
+                converted_delta_timestamp: None,
+                base_converted_delta_version: None,
+            },
+        };
+        let req = DeltaUpdateTableRequest {
+            requirements: vec![],
+            updates: vec![DeltaTableUpdate::AddCommit {
+                commit: DeltaCommit {
+                    version: 2,
+                    timestamp: 1900,
+                    file_name: "00000000-0000-0000-0000-00000000002b.json".into(),
+                    file_size: 64,
+                    file_modification_timestamp: 1900,
+                },
+                uniform: Some(outside),
+            }],
+        };
+        let err = h
+            .update_table(table_path("t"), req, ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+        let req = DeltaUpdateTableRequest {
+            requirements: vec![],
+            updates: vec![DeltaTableUpdate::SetProperties {
+                updates: BTreeMap::from([(
+                    contract::PROP_ICEBERG_METADATA_LOCATION.into(),
+                    "file:///etc/passwd".into(),
+                )]),
+            }],
+        };
+        let err = h
+            .update_table(table_path("t"), req, ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+        let loaded = h.load_table(table_path("t"), ctx()).await.unwrap();
+        assert_eq!(loaded.latest_table_version, Some(1));
+        assert_eq!(loaded.uniform, Some(uniform));
+    }
+
     #[tokio::test]
     async fn update_table_set_remove_property_overlap_rejected() {
         let h = handler();
@@ -1457,6 +2066,98 @@ mod tests {
         assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
     }
 
+    #[tokio::test]
+    async fn update_table_validates_schema_evolution_and_constraints() {
+        let h = handler();
+        setup(&h).await;
+        let st = stage(&h, "t").await;
+        DeltaApiHandler::create_table(
+            &h,
+            schema_path(),
+            create_req("t", &st.staging_location, &st.id),
+            ctx(),
+        )
+        .await
+        .unwrap();
+
+        let field = |name: &str, nullable| DeltaStructField {
+            name: name.into(),
+            data_type: DeltaDataType::Primitive("long".into()),
+            nullable,
+            metadata: Default::default(),
+        };
+        let update = |updates| DeltaUpdateTableRequest {
+            requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                uuid: st.id.clone(),
+            }],
+            updates,
+        };
+        let set_columns = |fields| DeltaTableUpdate::SetColumns {
+            columns: DeltaStructType {
+                type_tag: Default::default(),
+                fields,
+            },
+        };
+
+        // Dropping `id` needs column mapping; adding a NOT NULL column is never valid.
+        for fields in [
+            vec![field("other", true)],
+            vec![field("id", false), field("qty", false)],
+        ] {
+            let err = h
+                .update_table(table_path("t"), update(vec![set_columns(fields)]), ctx())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+        }
+
+        // A CHECK constraint needs the checkConstraints writer feature.
+        let constraint = DeltaTableUpdate::SetProperties {
+            updates: BTreeMap::from([("delta.constraints.positive".into(), "qty > 0".into())]),
+        };
+        let err = h
+            .update_table(
+                table_path("t"),
+                update(vec![
+                    set_columns(vec![field("id", false), field("qty", true)]),
+                    constraint.clone(),
+                ]),
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("checkConstraints"), "{err}");
+
+        // With the feature, a nullable column and a constraint over it are accepted.
+        let mut protocol = compliant_protocol();
+        protocol
+            .writer_features
+            .get_or_insert_with(Vec::new)
+            .push("checkConstraints".into());
+        let loaded = h
+            .update_table(
+                table_path("t"),
+                update(vec![
+                    set_columns(vec![field("id", false), field("qty", true)]),
+                    DeltaTableUpdate::SetProtocol { protocol },
+                    constraint,
+                ]),
+                ctx(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            loaded
+                .metadata
+                .columns
+                .fields
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "qty"]
+        );
+    }
+
     #[tokio::test]
     async fn report_metrics_table_id_mismatch_rejected() {
         let h = handler();
@@ -1481,11 +2182,270 @@ mod tests {
             .unwrap_err();
         assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
 
-        // Matching id succeeds (accept-and-ack).
+        // Matching id succeeds; there is no report to persist.
         let ok = DeltaReportMetricsRequest {
             table_id: st.id.clone(),
             report: None,
         };
         h.report_metrics(table_path("t"), ok, ctx()).await.unwrap();
+        assert!(
+            h.maintenance_store()
+                .list_reports(&st.id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn report_metrics_persists_commit_report_and_lists_jobs() {
+        let h = handler();
+        setup(&h).await;
+        let st = stage(&h, "t").await;
+        DeltaApiHandler::create_table(
+            &h,
+            schema_path(),
+            create_req("t", &st.staging_location, &st.id),
+            ctx(),
+        )
+        .await
+        .unwrap();
+
+        let commit_report = DeltaCommitReport {
+            num_files_added: Some(2),
+            num_bytes_added: Some(2048),
+            ..Default::default()
+        };
+        let req = DeltaReportMetricsRequest {
+            table_id: st.id.clone(),
+            report: Some(DeltaReport {
+                commit_report: Some(commit_report.clone()),
+            }),
+        };
+        h.report_metrics(table_path("t"), req, ctx()).await.unwrap();
+        let reports = h
+            .maintenance_store()
+            .list_reports(&st.id, None)
+            .await
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].report, commit_report);
+
+        let job = h
+            .maintenance_store()
+            .create_job(&st.id, DeltaMaintenanceJobKind::Vacuum, "test".into())
+            .await
+            .unwrap();
+        let listed = h
+            .list_maintenance_jobs(table_path("t"), None, ctx())
+            .await
+            .unwrap();
+        assert_eq!(listed.jobs, vec![job]);
+    }
+
+    fn rename_req(new_name: &str) -> DeltaRenameTableRequest {
+        DeltaRenameTableRequest {
+            new_name: new_name.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rename_table_keeps_id_commits_and_share_membership() {
+        use unitycatalog_common::models::shares::v1::{
+            Action, CreateShareRequest, DataObject, DataObjectUpdate, GetShareRequest,
+            UpdateShareRequest,
+        };
+
+        use crate::api::ShareHandler;
+
+        let h = handler();
+        setup(&h).await;
+        let st = stage(&h, "t").await;
+        DeltaApiHandler::create_table(
+            &h,
+            schema_path(),
+            create_req("t", &st.staging_location, &st.id),
+            ctx(),
+        )
+        .await
+        .unwrap();
+        let commit = DeltaUpdateTableRequest {
+            requirements: vec![DeltaTableRequirement::AssertTableUuid {
+                uuid: st.id.clone(),
+            }],
+            updates: vec![DeltaTableUpdate::AddCommit {
+                commit: DeltaCommit {
+                    version: 1,
+                    timestamp: 1800,
+                    file_name: "00000000-0000-0000-0000-00000000002a.json".into(),
+                    file_size: 64,
+                    file_modification_timestamp: 1800,
+                },
+                uniform: None,
+            }],
+        };
+        h.update_table(table_path("t"), commit, ctx())
+            .await
+            .unwrap();
+        h.create_share(
+            CreateShareRequest {
+                name: "share".into(),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        h.update_share(
+            UpdateShareRequest {
+                name: "share".into(),
+                updates: vec![DataObjectUpdate {
+                    action: Action::Add as i32,
+                    data_object: Some(DataObject {
+                        name: "cat.sch.t".into(),
+                        data_object_type: DataObjectType::Table as i32,
+                        shared_as: Some("sch.t".into()),
+                        ..Default::default()
+                    }),
+                }],
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+
+        h.rename_table(table_path("t"), rename_req("t2"), ctx())
+            .await
+            .unwrap();
+
+        let err = h.load_table(table_path("t"), ctx()).await.unwrap_err();
+        assert!(matches!(err, Error::NotFound), "{err:?}");
+        let loaded = h.load_table(table_path("t2"), ctx()).await.unwrap();
+        assert_eq!(loaded.metadata.table_uuid, st.id);
+        assert_eq!(loaded.latest_table_version, Some(1));
+
+        let share = h
+            .get_share(
+                GetShareRequest {
+                    name: "share".into(),
+                    ..Default::default()
+                },
+                ctx(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(share.objects.len(), 1);
+        assert_eq!(share.objects[0].name, "cat.sch.t2");
+        assert_eq!(share.objects[0].shared_as.as_deref(), Some("sch.t"));
+    }
+
+    #[tokio::test]
+    async fn rename_table_rejects_cross_schema_and_taken_names() {
+        let h = handler();
+        setup(&h).await;
+        for name in ["a", "b"] {
+            let st = stage(&h, name).await;
+            DeltaApiHandler::create_table(
+                &h,
+                schema_path(),
+                create_req(name, &st.staging_location, &st.id),
+                ctx(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let err = h
+            .rename_table(table_path("a"), rename_req("other.a"), ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+        let err = h
+            .rename_table(table_path("a"), rename_req("b"), ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::AlreadyExists), "{err:?}");
+        // A pending reservation holds its name too.
+        stage(&h, "c").await;
+        let err = h
+            .rename_table(table_path("a"), rename_req("c"), ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::AlreadyExists), "{err:?}");
+        let err = h
+            .rename_table(table_path("missing"), rename_req("d"), ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound), "{err:?}");
+
+        h.load_table(table_path("a"), ctx()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rename_table_rewrites_metric_view_dependencies() {
+        use unitycatalog_common::metric_view::MetricView;
+        use unitycatalog_common::models::tables::v1::dependency;
+
+        let h = handler();
+        setup(&h).await;
+        let st = stage(&h, "orders").await;
+        DeltaApiHandler::create_table(
+            &h,
+            schema_path(),
+            create_req("orders", &st.staging_location, &st.id),
+            ctx(),
+        )
+        .await
+        .unwrap();
+        TableHandler::create_table(
+            &h,
+            CreateTableRequest {
+                name: "orders_metrics".into(),
+                catalog_name: "cat".into(),
+                schema_name: "sch".into(),
+                table_type: TableType::MetricView as i32,
+                view_definition: Some(
+                    "version: \"1.1\"\nsource: cat.sch.orders\n\
+                     dimensions:\n  - name: id\n    expr: id\n\
+                     measures:\n  - name: n\n    expr: COUNT(1)\n"
+                        .into(),
+                ),
+                ..Default::default()
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+
+        h.rename_table(table_path("orders"), rename_req("orders_v2"), ctx())
+            .await
+            .unwrap();
+
+        let view = TableHandler::get_table(
+            &h,
+            GetTableRequest {
+                full_name: "cat.sch.orders_metrics".into(),
+                include_delta_metadata: None,
+                include_browse: None,
+                include_manifest_capabilities: None,
+            },
+            ctx(),
+        )
+        .await
+        .unwrap();
+        let deps: Vec<_> = view
+            .view_dependencies
+            .unwrap()
+            .dependencies
+            .into_iter()
+            .filter_map(|d| match d.dependency {
+                Some(dependency::Dependency::Table(t)) => Some(t.table_full_name),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(deps, vec!["cat.sch.orders_v2"]);
+        let definition = MetricView::from_yaml(view.view_definition.as_deref().unwrap()).unwrap();
+        assert_eq!(definition.source, "cat.sch.orders_v2");
     }
 }
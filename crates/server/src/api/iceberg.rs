@@ -0,0 +1,112 @@
+//! Handler trait for the Iceberg REST Catalog API (`/iceberg/v1/...`).
+//!
+//! Serves UniForm-enabled Delta tables to Iceberg engines. Like the Delta API,
+//! this is a standalone REST protocol, so the trait, router and models are
+//! maintained by hand. Only the read path is implemented: catalog config,
+//! namespace (schema) discovery, table listing and `loadTable`. The
+//! implementation lives in `services::iceberg` because `loadTable` reads the
+//! table's Iceberg metadata from storage.
+//!
+//! An Iceberg client first calls `getConfig` with `warehouse=<catalog>`; the
+//! returned `prefix` override (`catalogs/<catalog>`) scopes every later request
+//! to that catalog. Unity Catalog schemas map onto single-level namespaces.
+
+use async_trait::async_trait;
+
+use unitycatalog_common::models::iceberg::v1::{
+    IcebergCatalogConfig, IcebergGetNamespaceResponse, IcebergListNamespacesResponse,
+    IcebergListTablesResponse, IcebergLoadTableResult,
+};
+
+use crate::api::RequestContext;
+use crate::{Error, Result};
+
+/// Separator between the levels of a multi-level namespace in a path segment.
+pub const NAMESPACE_SEPARATOR: char = '\u{1f}';
+
+/// A namespace coordinate parsed from the request path.
+#[derive(Debug, Clone)]
+pub struct NamespacePath {
+    pub catalog: String,
+    pub schema: String,
+}
+
+impl NamespacePath {
+    /// Parse the `{namespace}` path segment. Unity Catalog schemas are always
+    /// single-level, so a multi-level namespace cannot exist.
+    pub fn try_new(catalog: String, namespace: &str) -> Result<Self> {
+        if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+            return Err(Error::NotFound);
+        }
+        Ok(Self {
+            catalog,
+            schema: namespace.to_string(),
+        })
+    }
+}
+
+/// A table coordinate parsed from the request path.
+#[derive(Debug, Clone)]
+pub struct IcebergTablePath {
+    pub namespace: NamespacePath,
+    pub table: String,
+}
+
+/// Pagination parameters shared by the list operations.
+#[derive(Debug, Clone, Default)]
+pub struct PageQuery {
+    pub page_token: Option<String>,
+    pub page_size: Option<i32>,
+}
+
+/// Handler for the Iceberg REST Catalog API. One method per implemented
+/// operation; method names match the spec `operationId`s.
+#[async_trait]
+pub trait IcebergRestHandler<Cx = RequestContext>: Send + Sync + 'static {
+    /// `GET /iceberg/v1/config?warehouse=<catalog>`
+    async fn get_config(
+        &self,
+        warehouse: Option<String>,
+        context: Cx,
+    ) -> Result<IcebergCatalogConfig>;
+
+    /// `GET /iceberg/v1/catalogs/{catalog}/namespaces`
+    ///
+    /// Schemas have no child namespaces, so listing under a `parent` returns
+    /// an empty page once the parent is known to exist.
+    async fn list_namespaces(
+        &self,
+        catalog: String,
+        parent: Option<String>,
+        page: PageQuery,
+        context: Cx,
+    ) -> Result<IcebergListNamespacesResponse>;
+
+    /// `GET /iceberg/v1/catalogs/{catalog}/namespaces/{namespace}`
+    async fn load_namespace_metadata(
+        &self,
+        path: NamespacePath,
+        context: Cx,
+    ) -> Result<IcebergGetNamespaceResponse>;
+
+    /// `GET /iceberg/v1/catalogs/{catalog}/namespaces/{namespace}/tables`
+    ///
+    /// Lists only the tables with Iceberg metadata to serve.
+    async fn list_tables(
+        &self,
+        path: NamespacePath,
+        page: PageQuery,
+        context: Cx,
+    ) -> Result<IcebergListTablesResponse>;
+
+    /// `GET /iceberg/v1/catalogs/{catalog}/namespaces/{namespace}/tables/{table}`
+    ///
+    /// `vend_credentials` is set when the client asked for
+    /// `X-Iceberg-Access-Delegation: vended-credentials`.
+    async fn load_table(
+        &self,
+        path: IcebergTablePath,
+        vend_credentials: bool,
+        context: Cx,
+    ) -> Result<IcebergLoadTableResult>;
+}
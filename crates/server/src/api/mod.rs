@@ -7,11 +7,13 @@ pub use delta::DeltaApiHandler;
 pub use entity_tag_assignments::EntityTagAssignmentHandler;
 pub use external_locations::ExternalLocationHandler;
 pub use functions::FunctionHandler;
+pub use iceberg::IcebergRestHandler;
 pub use providers::ProviderHandler;
 pub use recipients::RecipientHandler;
 pub use schemas::SchemaHandler;
 pub use shares::ShareHandler;
 pub use staging_tables::StagingTableHandler;
+pub use table_history::TableHistoryHandler;
 pub use tables::TableHandler;
 pub use tag_policies::TagPolicyHandler;
 pub use temporary_credentials::TemporaryCredentialHandler;
@@ -42,12 +44,14 @@ pub mod delta;
 pub mod entity_tag_assignments;
 pub mod external_locations;
 pub mod functions;
+pub mod iceberg;
 pub mod providers;
 pub mod recipients;
 pub mod schemas;
 pub mod shares;
 pub mod sharing;
 pub mod staging_tables;
+pub mod table_history;
 pub mod tables;
 pub mod tag_policies;
 pub mod temporary_credentials;
@@ -1,13 +1,14 @@
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use unitycatalog_common::models::ObjectLabel;
 use unitycatalog_common::models::shares::v1::*;
+use unitycatalog_common::models::{AssociationLabel, ObjectLabel};
 use unitycatalog_common::models::{ResourceIdent, ResourceName, ResourceRef};
 
 use super::{RequestContext, SecuredAction};
 pub use crate::codegen::shares::ShareHandler;
 use crate::policy::{Permission, Policy, process_resources};
+use crate::services::share_policy::SharedTablePolicy;
 use crate::store::ResourceStore;
 use crate::{Error, Result};
 
@@ -83,6 +84,7 @@ impl<T: ResourceStore + Policy<RequestContext>> ShareHandler<RequestContext> for
         let ident = request.resource();
         let current: Share = self.get(&ident).await?.0.try_into()?;
 
+        let before = shared_tables(&current.objects);
         // update the data_objects according to the actions defined in request
         let mut data_objects: HashMap<String, DataObject> = current
             .objects
@@ -90,6 +92,21 @@ impl<T: ResourceStore + Policy<RequestContext>> ShareHandler<RequestContext> for
             .map(|d| (d.name.clone(), d))
             .collect();
         for update in request.updates.iter() {
+            // Reject partition specs and start versions we could not enforce when
+            // serving the table, rather than failing every later query.
+            if let Some(obj) = update.data_object.as_ref()
+                && matches!(update.action(), Action::Add | Action::Update)
+            {
+                match obj.data_object_type() {
+                    DataObjectType::Table => {
+                        SharedTablePolicy::try_from_data_object(obj)?;
+                    }
+                    DataObjectType::View => {
+                        SharedTablePolicy::try_from_view_object(obj)?;
+                    }
+                    _ => {}
+                }
+            }
             match update.action() {
                 Action::Add => {
                     if let Some(obj) = update.data_object.as_ref() {
@@ -126,9 +143,12 @@ impl<T: ResourceStore + Policy<RequestContext>> ShareHandler<RequestContext> for
             objects: data_objects.into_values().collect(),
             ..Default::default()
         };
+        let after = shared_tables(&resource.objects);
         // TODO:
         // - add update_* relations
-        Ok(self.update(&ident, resource.into()).await?.0.try_into()?)
+        let (updated, share_ref) = self.update(&ident, resource.into()).await?;
+        record_shared_tables(self, &ResourceIdent::Share(share_ref), &before, &after).await?;
+        Ok(updated.try_into()?)
     }
 
     #[tracing::instrument(skip(self, context), fields(resource_name))]
@@ -255,3 +275,50 @@ impl SecuredAction for UpdatePermissionsRequest {
         &Permission::Manage
     }
 }
+
+/// The names of the tables and views among a share's data objects.
+fn shared_tables(objects: &[DataObject]) -> HashSet<String> {
+    objects
+        .iter()
+        .filter(|o| {
+            matches!(
+                o.data_object_type(),
+                DataObjectType::Table | DataObjectType::View
+            )
+        })
+        .map(|o| o.name.clone())
+        .collect()
+}
+
+/// Keep a `References` association from `share` to each table or view it
+/// shares, so renaming a table finds the shares naming it without listing
+/// every share. Objects naming a table that does not exist are skipped.
+async fn record_shared_tables<T: ResourceStore>(
+    store: &T,
+    share: &ResourceIdent,
+    before: &HashSet<String>,
+    after: &HashSet<String>,
+) -> Result<()> {
+    let table = |name: &str| ResourceIdent::table(ResourceName::from_naive_str_split(name));
+    for name in before.difference(after) {
+        match store
+            .remove_association(share, &table(name), &AssociationLabel::References)
+            .await
+        {
+            Ok(()) | Err(unitycatalog_common::Error::NotFound) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    for name in after.difference(before) {
+        match store
+            .add_association(share, &table(name), &AssociationLabel::References, None)
+            .await
+        {
+            Ok(())
+            | Err(unitycatalog_common::Error::NotFound)
+            | Err(unitycatalog_common::Error::AlreadyExists) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use futures::stream::BoxStream;
 use itertools::Itertools;
 
 use unitycatalog_common::models::ObjectLabel;
@@ -13,6 +14,12 @@ use crate::policy::{Permission, Policy, process_resources};
 pub use crate::sharing::SharingHandler;
 use crate::store::ResourceStore;
 
+/// A `query_table` response body: a stream of newline-delimited JSON chunks.
+///
+/// Each item holds one or more complete NDJSON lines, so chunks can be written
+/// to the wire as they are produced without buffering the full log replay.
+pub type NdJsonStream = BoxStream<'static, Result<Bytes>>;
+
 #[async_trait::async_trait]
 pub trait SharingQueryHandler<Cx = RequestContext>: Send + Sync + 'static {
     async fn get_table_version(
@@ -27,7 +34,7 @@ pub trait SharingQueryHandler<Cx = RequestContext>: Send + Sync + 'static {
         context: Cx,
     ) -> Result<Bytes>;
 
-    async fn query_table(&self, request: QueryTableRequest, context: Cx) -> Result<Bytes>;
+    async fn query_table(&self, request: QueryTableRequest, context: Cx) -> Result<NdJsonStream>;
 }
 
 #[async_trait::async_trait]
@@ -104,7 +111,10 @@ where
                 .objects
                 .into_iter()
                 .filter_map(|a| {
-                    if matches!(a.data_object_type(), DataObjectType::Table) {
+                    if matches!(
+                        a.data_object_type(),
+                        DataObjectType::Table | DataObjectType::View
+                    ) {
                         Some(Schema {
                             name: a.shared_as().split_once(".")?.0.to_string(),
                             share: share.name.clone(),
@@ -135,7 +145,10 @@ where
             .objects
             .into_iter()
             .filter_map(|a| {
-                if matches!(a.data_object_type(), DataObjectType::Table) {
+                if matches!(
+                    a.data_object_type(),
+                    DataObjectType::Table | DataObjectType::View
+                ) {
                     let (schema, name) = a.shared_as().split_once(".")?;
                     if schema == request.name {
                         Some(Table {
@@ -174,7 +187,10 @@ where
             .objects
             .into_iter()
             .filter_map(|a| {
-                if matches!(a.data_object_type(), DataObjectType::Table) {
+                if matches!(
+                    a.data_object_type(),
+                    DataObjectType::Table | DataObjectType::View
+                ) {
                     let (schema, name) = a.shared_as().split_once(".")?;
                     Some(Table {
                         name: name.to_string(),
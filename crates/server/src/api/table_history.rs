@@ -0,0 +1,28 @@
+//! Handler trait for the table history API (`GET /tables/{full_name}/history`).
+//!
+//! Lets operators inspect what happened to a Delta table: one entry per commit,
+//! read from the `commitInfo` action of the table's log. The router and models
+//! are maintained by hand, like the Delta API's. The implementation lives in
+//! `services::table_history` because it reads the log from storage and merges
+//! in the commits the catalog has ratified but not yet published.
+
+use async_trait::async_trait;
+
+use unitycatalog_common::models::table_history::v1::{
+    GetTableHistoryRequest, GetTableHistoryResponse,
+};
+
+use crate::Result;
+use crate::api::RequestContext;
+
+#[async_trait]
+pub trait TableHistoryHandler<Cx = RequestContext>: Send + Sync + 'static {
+    /// `GET /tables/{full_name}/history`
+    ///
+    /// Entries are returned newest first, paged by version.
+    async fn get_table_history(
+        &self,
+        request: GetTableHistoryRequest,
+        context: Cx,
+    ) -> Result<GetTableHistoryResponse>;
+}
@@ -2,14 +2,18 @@ use std::sync::Arc;
 
 use delta_kernel::schema::{DataType, PrimitiveType, Schema, StructField};
 use delta_kernel::{Snapshot, Version};
+use futures::StreamExt;
 use itertools::Itertools;
 
-use unitycatalog_common::ResourceIdent;
-use unitycatalog_common::metric_view::{MetricView, dependencies as metric_view_dependencies};
+use unitycatalog_common::metric_view::{
+    MetricView, dependencies as metric_view_dependencies, query_dependencies,
+};
+use unitycatalog_common::models::AssociationLabel;
 use unitycatalog_common::models::ObjectLabel;
 use unitycatalog_common::models::ResourceName;
 use unitycatalog_common::models::staging_tables::v1::StagingTable;
 use unitycatalog_common::models::tables::v1::*;
+use unitycatalog_common::{ResourceIdent, ResourceRef};
 
 use super::staging_tables::find_staging_table_by_location;
 use super::{RequestContext, SecuredAction};
@@ -17,12 +21,16 @@ pub use crate::codegen::tables::TableHandler;
 use crate::policy::{Permission, Policy, Principal, process_resources};
 use crate::services::ProvidesLocalStoragePolicy;
 use crate::services::location::StorageLocationUrl;
+use crate::services::managed_delta_contract as contract;
 use crate::services::object_store::validate_external_storage_location;
 use crate::store::ResourceStore;
 use crate::{Error, Result};
 
 const MAX_RESULTS_TABLES: usize = 50;
 
+/// How many tables' statistics a listing reads at once.
+const STATISTICS_CONCURRENCY: usize = 8;
+
 impl SecuredAction for CreateTableRequest {
     fn resource(&self) -> ResourceIdent {
         ResourceIdent::table(ResourceName::new([
@@ -98,6 +106,16 @@ pub trait TableManager: Send + Sync + 'static {
         format: &DataSourceFormat,
         version: Option<Version>,
     ) -> Result<Arc<Snapshot>>;
+
+    /// Statistics of the latest version of the Delta table at `location`.
+    ///
+    /// `table_id` is set for catalog-managed tables, whose latest commits may
+    /// be ratified by the catalog but not yet published to the log.
+    async fn table_statistics(
+        &self,
+        location: &StorageLocationUrl,
+        table_id: Option<&str>,
+    ) -> Result<TableStatistics>;
 }
 
 #[async_trait::async_trait]
@@ -151,8 +169,16 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
             )
             .await?;
         process_resources(self, &context, &Permission::Read, &mut resources).await?;
+        let mut tables: Vec<Table> = resources.into_iter().map(|r| r.try_into()).try_collect()?;
+        if request.include_delta_metadata == Some(true) {
+            tables = futures::stream::iter(tables)
+                .map(|table| with_statistics(self, table))
+                .buffered(STATISTICS_CONCURRENCY)
+                .collect()
+                .await;
+        }
         Ok(ListTablesResponse {
-            tables: resources.into_iter().map(|r| r.try_into()).try_collect()?,
+            tables,
             next_page_token,
         })
     }
@@ -165,6 +191,10 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
     ) -> Result<Table> {
         tracing::Span::current().record("resource_name", &request.name);
         self.check_required(&request, &context).await?;
+        contract::validate_iceberg_properties(
+            &request.properties.clone().into_iter().collect(),
+            request.storage_location.as_deref(),
+        )?;
         let info = if request.table_type == TableType::External as i32 {
             let Some(location) = request.storage_location.as_ref() else {
                 return Err(Error::invalid_argument("missing storage location"));
@@ -197,18 +227,29 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
                 )?,
                 ..Default::default()
             }
-        } else if request.table_type == TableType::Managed as i32 {
+        } else if request.table_type == TableType::Managed as i32
+            || request.table_type == TableType::MaterializedView as i32
+        {
             // Managed table: finalize a previously created staging table. The
             // client has written the initial Delta commit (`0.json`) at the
             // staging location; here we commit the staging reservation, adopt its
             // id as the table id, and register the table. The server never writes
             // the Delta log itself.
+            //
+            // A materialized view is stored exactly like a managed table; its
+            // defining query additionally lives in `view_definition`, which is
+            // the source of truth for its dependencies.
             if request.data_source_format != DataSourceFormat::Delta as i32 {
                 return Err(Error::invalid_argument(format!(
                     "managed tables must use the DELTA data source format, got {:?}",
                     request.data_source_format()
                 )));
             }
+            let view = if request.table_type == TableType::MaterializedView as i32 {
+                Some(view_query_dependencies(&request, "materialized views")?)
+            } else {
+                None
+            };
             let Some(location) = request.storage_location.as_ref() else {
                 return Err(Error::invalid_argument(
                     "managed tables require storage_location to be the staging location",
@@ -266,6 +307,8 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
                         .metadata()
                         .partition_columns(),
                 )?,
+                view_definition: view.as_ref().map(|(definition, _)| definition.clone()),
+                view_dependencies: view.map(|(_, dependencies)| dependencies),
                 ..Default::default()
             }
         } else if request.table_type == TableType::MetricView as i32 {
@@ -307,6 +350,25 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
                 view_dependencies: Some(view_dependencies),
                 ..Default::default()
             }
+        } else if request.table_type == TableType::View as i32 {
+            // SQL view: like a metric view it owns no storage. The query text is
+            // the source of truth for what it reads; when shared, the sharing
+            // server materializes it on demand (see `services::shared_view`).
+            let (view_definition, view_dependencies) = view_query_dependencies(&request, "views")?;
+
+            Table {
+                name: request.name,
+                catalog_name: request.catalog_name,
+                schema_name: request.schema_name,
+                table_type: request.table_type,
+                data_source_format: request.data_source_format,
+                columns: request.columns,
+                properties: request.properties,
+                comment: request.comment,
+                view_definition: Some(view_definition),
+                view_dependencies: Some(view_dependencies),
+                ..Default::default()
+            }
         } else {
             return Err(Error::invalid_argument(format!(
                 "unsupported table type: {:?}",
@@ -315,7 +377,10 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
         };
         // TODO: update the table with the current actor as owner
         // TODO: create updated_* relations
-        Ok(self.create(info.into()).await?.0.try_into()?)
+        let (resource, resource_ref) = self.create(info.into()).await?;
+        let table: Table = resource.try_into()?;
+        record_view_dependencies(self, resource_ref, table.view_dependencies.as_ref()).await?;
+        Ok(table)
     }
 
     #[tracing::instrument(skip(self, context), fields(resource_name))]
@@ -323,7 +388,11 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
         tracing::Span::current().record("resource_name", &request.full_name);
         self.check_required(&request, &context).await?;
         // TODO: get columns etc ...
-        Ok(self.get(&request.resource()).await?.0.try_into()?)
+        let table: Table = self.get(&request.resource()).await?.0.try_into()?;
+        if request.include_delta_metadata == Some(true) {
+            return Ok(with_statistics(self, table).await);
+        }
+        Ok(table)
     }
 
     #[tracing::instrument(skip(self, context), fields(resource_name))]
@@ -355,6 +424,61 @@ impl<T: ResourceStore + Policy<RequestContext> + TableManager + ProvidesLocalSto
     }
 }
 
+/// The SELECT query a SQL view or materialized view (`kind`, for errors) is
+/// defined by, and the dependencies derived from it. A client-supplied
+/// `view_dependencies` is only accepted if it matches (the definition wins).
+fn view_query_dependencies(
+    request: &CreateTableRequest,
+    kind: &str,
+) -> Result<(String, DependencyList)> {
+    let Some(view_definition) = request.view_definition.as_ref() else {
+        return Err(Error::invalid_argument(format!(
+            "{kind} require view_definition (the SELECT query)"
+        )));
+    };
+    let view_dependencies = query_dependencies(view_definition)
+        .map_err(|e| Error::invalid_argument(format!("cannot derive view dependencies: {e}")))?;
+    if let Some(supplied) = request.view_dependencies.as_ref()
+        && supplied != &view_dependencies
+    {
+        return Err(Error::invalid_argument(
+            "supplied view_dependencies diverges from the definition; \
+             omit it (the server derives dependencies from view_definition)",
+        ));
+    }
+    Ok((view_definition.clone(), view_dependencies))
+}
+
+/// Record a `DependsOn` association from a view-like table to each table its
+/// definition reads, so lineage can be walked in either direction. References
+/// to tables that do not exist (yet) are skipped; the definition stays the
+/// source of truth for them.
+async fn record_view_dependencies<T: ResourceStore>(
+    store: &T,
+    view: ResourceRef,
+    dependencies: Option<&DependencyList>,
+) -> Result<()> {
+    let view = ResourceIdent::Table(view);
+    let tables = dependencies
+        .into_iter()
+        .flat_map(|list| &list.dependencies)
+        .filter_map(|dep| match &dep.dependency {
+            Some(dependency::Dependency::Table(table)) => Some(&table.table_full_name),
+            _ => None,
+        });
+    for full_name in tables {
+        let target = ResourceIdent::table(ResourceName::from_naive_str_split(full_name));
+        match store
+            .add_association(&view, &target, &AssociationLabel::DependsOn, None)
+            .await
+        {
+            Ok(()) | Err(unitycatalog_common::Error::NotFound) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
 trait FieldExt {
     fn type_text(&self) -> String;
     fn type_json(&self) -> Result<String>;
@@ -412,6 +536,33 @@ impl FieldExt for StructField {
     }
 }
 
+/// Attach the current statistics to a Delta table.
+///
+/// Statistics are best effort: a table whose log cannot be read is still
+/// returned, just without them.
+async fn with_statistics<T: TableManager + ?Sized>(manager: &T, mut table: Table) -> Table {
+    if table.data_source_format() != DataSourceFormat::Delta {
+        return table;
+    }
+    let Some(location) = table.storage_location.as_deref() else {
+        return table;
+    };
+    let table_id = contract::is_catalog_managed(table.table_type)
+        .then_some(table.table_id.as_deref())
+        .flatten();
+    let statistics = match StorageLocationUrl::parse(location) {
+        Ok(location) => manager.table_statistics(&location, table_id).await,
+        Err(e) => Err(e),
+    };
+    match statistics {
+        Ok(statistics) => table.statistics = Some(statistics),
+        Err(e) => {
+            tracing::warn!(table = %table.full_name, error = %e, "failed to read table statistics");
+        }
+    }
+    table
+}
+
 fn schema_to_columns(schema: &Schema, partition_columns: &[String]) -> Result<Vec<Column>> {
     let partition_index = |name: &str| partition_columns.iter().position(|n| n == name);
     schema
@@ -450,6 +601,7 @@ mod tests {
     use crate::memory::InMemoryResourceStore;
     use crate::policy::ConstantPolicy;
     use crate::services::ServerHandler;
+    use crate::store::ResourceStoreReader;
 
     fn handler() -> ServerHandler<RequestContext> {
         let encryptor =
@@ -582,6 +734,30 @@ mod tests {
         assert!(matches!(res, Err(Error::InvalidArgument(_))), "{res:?}");
     }
 
+    /// A materialized view is a managed table with a defining query: without
+    /// `view_definition` it is rejected before any staging lookup.
+    #[tokio::test]
+    async fn materialized_view_without_definition_is_rejected() {
+        let h = handler();
+        let res = h
+            .create_table(
+                CreateTableRequest {
+                    name: "mv".to_string(),
+                    schema_name: "sch".to_string(),
+                    catalog_name: "cat".to_string(),
+                    table_type: TableType::MaterializedView as i32,
+                    data_source_format: DataSourceFormat::Delta as i32,
+                    storage_location: Some(
+                        "s3://bucket/cat/__unitystorage/tables/unknown".to_string(),
+                    ),
+                    ..Default::default()
+                },
+                ctx(),
+            )
+            .await;
+        assert!(matches!(res, Err(Error::InvalidArgument(_))), "{res:?}");
+    }
+
     const METRIC_VIEW_YAML: &str = "version: \"1.1\"\nsource: cat.sch.orders\n\
                                     measures:\n  - name: revenue\n    expr: SUM(price)\n";
 
@@ -745,4 +921,85 @@ mod tests {
             .await;
         assert!(matches!(res, Err(Error::InvalidArgument(_))), "{res:?}");
     }
+
+    /// A SQL view derives its dependencies from the query text; unqualified
+    /// relations are rejected.
+    #[tokio::test]
+    async fn view_dependencies_derived_from_query() {
+        let h = handler();
+        let request = CreateTableRequest {
+            name: "eu_orders".to_string(),
+            schema_name: "sch".to_string(),
+            catalog_name: "cat".to_string(),
+            table_type: TableType::View as i32,
+            view_definition: Some(
+                "SELECT o.* FROM cat.sch.orders o JOIN cat.sch.regions r ON o.region = r.id"
+                    .to_string(),
+            ),
+            ..Default::default()
+        };
+        let created = h
+            .create_table(request.clone(), ctx())
+            .await
+            .expect("create view");
+        assert_eq!(created.table_type, TableType::View as i32);
+        assert_eq!(
+            dep_names(created.view_dependencies.as_ref()),
+            vec!["cat.sch.orders", "cat.sch.regions"]
+        );
+
+        let res = h
+            .create_table(
+                CreateTableRequest {
+                    name: "bad".to_string(),
+                    view_definition: Some("SELECT * FROM orders".to_string()),
+                    ..request
+                },
+                ctx(),
+            )
+            .await;
+        assert!(matches!(res, Err(Error::InvalidArgument(_))), "{res:?}");
+    }
+
+    /// Creating a view records `DependsOn` edges to the referenced tables that
+    /// exist; missing ones are skipped.
+    #[tokio::test]
+    async fn view_creation_records_depends_on_associations() {
+        let h = handler();
+        let view = |name: &str, query: &str| CreateTableRequest {
+            name: name.to_string(),
+            schema_name: "sch".to_string(),
+            catalog_name: "cat".to_string(),
+            table_type: TableType::View as i32,
+            view_definition: Some(query.to_string()),
+            ..Default::default()
+        };
+        h.create_table(view("recent", "SELECT * FROM cat.sch.orders"), ctx())
+            .await
+            .expect("create view over a missing table");
+        h.create_table(
+            view(
+                "recent_eu",
+                "SELECT * FROM cat.sch.recent WHERE region = 'eu'",
+            ),
+            ctx(),
+        )
+        .await
+        .expect("create view over a view");
+
+        let (targets, _) = h
+            .list_associations(
+                &ResourceIdent::table(ResourceName::new(["cat", "sch", "recent_eu"])),
+                &AssociationLabel::DependsOn,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(targets.len(), 1);
+        let (resource, _) = h.get(&targets[0]).await.unwrap();
+        let target: Table = resource.try_into().unwrap();
+        assert_eq!(target.name, "recent");
+    }
 }
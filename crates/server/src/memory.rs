@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
 use uuid::Uuid;
 
 use unitycatalog_common::models::{AssociationLabel, ObjectLabel, PropertyMap, Resource};
@@ -78,12 +79,24 @@ impl InMemoryResourceStore {
     /// managed volumes and staging tables, which embed the id in their storage
     /// path) reserve that exact id here so the persisted row id matches the path.
     fn insert_uuid(&self, label: &ObjectLabel, name: &ResourceName, uuid: Uuid) -> Result<Uuid> {
-        if self.get_uuid(label, name).is_some() {
-            return Err(Error::AlreadyExists);
-        }
         let map = self.id_map.entry(*label).or_default();
-        map.insert(name.clone(), uuid);
-        Ok(uuid)
+        // The entry holds the shard lock, so checking and claiming the name is
+        // atomic with respect to concurrent creates and renames.
+        match map.entry(name.clone()) {
+            Entry::Occupied(_) => Err(Error::AlreadyExists),
+            Entry::Vacant(entry) => {
+                entry.insert(uuid);
+                Ok(uuid)
+            }
+        }
+    }
+
+    fn resolve_uuid(&self, id: &ResourceIdent) -> Result<Uuid> {
+        match id.as_ref() {
+            ResourceRef::Uuid(uuid) => Ok(*uuid),
+            ResourceRef::Name(name) => self.get_uuid(id.label(), name).ok_or(Error::NotFound),
+            ResourceRef::Undefined => Err(Error::NotFound),
+        }
     }
 }
 
@@ -233,6 +246,44 @@ impl ResourceStore for InMemoryResourceStore {
         Ok((resource, ResourceRef::Uuid(uuid)))
     }
 
+    async fn rename(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+        dependents: Vec<(ResourceIdent, Resource)>,
+    ) -> Result<(Resource, ResourceRef)> {
+        let label = *id.label();
+        let uuid = self.resolve_uuid(id)?;
+        let old_name = self
+            .resources
+            .get(&(label, uuid))
+            .ok_or(Error::NotFound)?
+            .resource_name();
+        // Resolve every dependent before changing anything, so a missing one
+        // leaves the store untouched.
+        let dependents = dependents
+            .into_iter()
+            .map(|(ident, resource)| {
+                let uuid = self.resolve_uuid(&ident)?;
+                if !self.resources.contains_key(&(*ident.label(), uuid)) {
+                    return Err(Error::NotFound);
+                }
+                Ok((ident.label().to_ident(uuid), resource))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let new_name = resource.resource_name();
+        if new_name != old_name {
+            self.insert_uuid(&label, &new_name, uuid)?;
+            self.remove_uuid(&label, &old_name);
+        }
+        self.resources.insert((label, uuid), resource.clone());
+        for (ident, resource) in dependents {
+            self.update(&ident, resource).await?;
+        }
+        Ok((resource, ResourceRef::Uuid(uuid)))
+    }
+
     async fn add_association(
         &self,
         from: &ResourceIdent,
@@ -712,4 +763,62 @@ mod tests {
             Error::NotFound
         ));
     }
+
+    #[tokio::test]
+    async fn rename_moves_name_and_keeps_id() {
+        use unitycatalog_common::models::tables::v1::Table;
+
+        let store = test_store();
+        let table = |name: &str| Table {
+            name: name.into(),
+            catalog_name: "cat".into(),
+            schema_name: "sch".into(),
+            ..Default::default()
+        };
+        let view = |reads: &str| Table {
+            view_definition: Some(format!("SELECT * FROM cat.sch.{reads}")),
+            ..table("v")
+        };
+        let view_ident = ObjectLabel::Table.to_ident(ResourceName::new(["cat", "sch", "v"]));
+        let definition = async |store: &InMemoryResourceStore| {
+            let (resource, _) = store.get(&view_ident).await.unwrap();
+            Table::try_from(resource).unwrap().view_definition.unwrap()
+        };
+        let (_, reference) = store.create(table("a").into()).await.unwrap();
+        store.create(table("b").into()).await.unwrap();
+        store.create(view("a").into()).await.unwrap();
+        let ident = ObjectLabel::Table.to_ident(reference.clone());
+
+        // The new name is taken by another table; the dependent is untouched.
+        assert!(matches!(
+            store
+                .rename(
+                    &ident,
+                    table("b").into(),
+                    vec![(view_ident.clone(), view("b").into())]
+                )
+                .await
+                .unwrap_err(),
+            Error::AlreadyExists
+        ));
+        assert_eq!(definition(&store).await, "SELECT * FROM cat.sch.a");
+
+        let (_, renamed) = store
+            .rename(
+                &ident,
+                table("c").into(),
+                vec![(view_ident.clone(), view("c").into())],
+            )
+            .await
+            .unwrap();
+        assert_eq!(definition(&store).await, "SELECT * FROM cat.sch.c");
+        assert_eq!(renamed, reference);
+        let by_name = ObjectLabel::Table.to_ident(ResourceName::new(["cat", "sch", "c"]));
+        assert_eq!(store.get(&by_name).await.unwrap().1, reference);
+        let old_name = ObjectLabel::Table.to_ident(ResourceName::new(["cat", "sch", "a"]));
+        assert!(matches!(
+            store.get(&old_name).await.unwrap_err(),
+            Error::NotFound
+        ));
+    }
 }
@@ -55,6 +55,11 @@ where
             "/delta/v1/catalogs/{catalog}/schemas/{schema}/tables/{table}/metrics",
             post(report_metrics::<T, Cx>),
         )
+        .route(
+            "/delta/v1/catalogs/{catalog}/schemas/{schema}/tables/{table}/maintenance-jobs",
+            get(list_maintenance_jobs::<T, Cx>),
+        )
+        .route("/delta/v1/commits", post(batch_commit::<T, Cx>))
         .route(
             "/delta/v1/staging-tables/{table_id}/credentials",
             get(get_staging_table_credentials::<T, Cx>),
@@ -80,6 +85,12 @@ struct OperationParam {
     operation: DeltaCredentialOperation,
 }
 
+#[derive(Debug, Deserialize)]
+struct MaintenanceJobsParams {
+    #[serde(rename = "max-results")]
+    max_results: Option<usize>,
+}
+
 #[derive(Debug, Deserialize)]
 struct PathCredentialParams {
     location: String,
@@ -269,6 +280,40 @@ where
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn list_maintenance_jobs<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Path((catalog, schema, table)): Path<(String, String, String)>,
+    Query(params): Query<MaintenanceJobsParams>,
+) -> DeltaResult<axum::Json<DeltaListMaintenanceJobsResponse>>
+where
+    T: DeltaApiHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    let path = TablePath {
+        catalog,
+        schema,
+        table,
+    };
+    Ok(axum::Json(
+        handler
+            .list_maintenance_jobs(path, params.max_results, context)
+            .await?,
+    ))
+}
+
+async fn batch_commit<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    axum::Json(request): axum::Json<DeltaBatchCommitRequest>,
+) -> DeltaResult<axum::Json<DeltaBatchCommitResponse>>
+where
+    T: DeltaApiHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    Ok(axum::Json(handler.batch_commit(request, context).await?))
+}
+
 async fn get_staging_table_credentials<T, Cx>(
     State(handler): State<T>,
     context: Cx,
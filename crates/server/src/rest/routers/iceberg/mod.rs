@@ -0,0 +1,196 @@
+//! Hand-written Axum router for the Iceberg REST Catalog API (`/iceberg/v1/...`).
+//!
+//! Mirrors the Delta API router (`super::delta`): a `get_router` that mounts
+//! every operation, plus per-operation handler functions that extract the
+//! request, call [`IcebergRestHandler`], and serialize the response. The
+//! `{prefix}` of the Iceberg spec is `catalogs/{catalog}`, as advertised by
+//! `getConfig`.
+
+pub mod models;
+
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::routing::{Router, get};
+use serde::Deserialize;
+
+use crate::api::iceberg::{IcebergRestHandler, IcebergTablePath, NamespacePath, PageQuery};
+use models::*;
+
+/// Handler result whose error half serializes as the Iceberg error envelope.
+type IcebergResult<T> = std::result::Result<T, IcebergError>;
+
+/// Header through which a client asks for vended credentials.
+const ACCESS_DELEGATION_HEADER: &str = "x-iceberg-access-delegation";
+
+/// Create a [`Router`] for the Iceberg REST Catalog API.
+pub fn get_router<T, Cx>(state: T) -> Router
+where
+    T: IcebergRestHandler<Cx> + Clone,
+    Cx: axum::extract::FromRequestParts<T> + Send + 'static,
+{
+    Router::new()
+        .route("/iceberg/v1/config", get(get_config::<T, Cx>))
+        .route(
+            "/iceberg/v1/catalogs/{catalog}/namespaces",
+            get(list_namespaces::<T, Cx>),
+        )
+        .route(
+            "/iceberg/v1/catalogs/{catalog}/namespaces/{namespace}",
+            get(load_namespace_metadata::<T, Cx>),
+        )
+        .route(
+            "/iceberg/v1/catalogs/{catalog}/namespaces/{namespace}/tables",
+            get(list_tables::<T, Cx>),
+        )
+        .route(
+            "/iceberg/v1/catalogs/{catalog}/namespaces/{namespace}/tables/{table}",
+            get(load_table::<T, Cx>),
+        )
+        .with_state(state)
+}
+
+// ----- Query parameter deserialization helpers -------------------------------
+
+#[derive(Debug, Deserialize)]
+struct ConfigParams {
+    warehouse: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PageParams {
+    #[serde(rename = "pageToken")]
+    page_token: Option<String>,
+    #[serde(rename = "pageSize")]
+    page_size: Option<i32>,
+}
+
+impl From<PageParams> for PageQuery {
+    fn from(params: PageParams) -> Self {
+        PageQuery {
+            page_token: params.page_token.filter(|t| !t.is_empty()),
+            page_size: params.page_size,
+        }
+    }
+}
+
+// Spelled out rather than flattening `PageParams`: serde's flatten buffers
+// query values as strings, which breaks the numeric `pageSize`.
+#[derive(Debug, Deserialize)]
+struct ListNamespacesParams {
+    parent: Option<String>,
+    #[serde(rename = "pageToken")]
+    page_token: Option<String>,
+    #[serde(rename = "pageSize")]
+    page_size: Option<i32>,
+}
+
+/// Whether the `X-Iceberg-Access-Delegation` header asks for vended credentials.
+fn wants_vended_credentials(headers: &HeaderMap) -> bool {
+    headers
+        .get(ACCESS_DELEGATION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.split(',').any(|m| m.trim() == "vended-credentials"))
+}
+
+// ----- Handlers --------------------------------------------------------------
+
+async fn get_config<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Query(params): Query<ConfigParams>,
+) -> IcebergResult<axum::Json<IcebergCatalogConfig>>
+where
+    T: IcebergRestHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    Ok(axum::Json(
+        handler
+            .get_config(params.warehouse, context)
+            .await
+            .map_err(IcebergError::namespace)?,
+    ))
+}
+
+async fn list_namespaces<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Path(catalog): Path<String>,
+    Query(params): Query<ListNamespacesParams>,
+) -> IcebergResult<axum::Json<IcebergListNamespacesResponse>>
+where
+    T: IcebergRestHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    Ok(axum::Json(
+        handler
+            .list_namespaces(
+                catalog,
+                params.parent,
+                PageParams {
+                    page_token: params.page_token,
+                    page_size: params.page_size,
+                }
+                .into(),
+                context,
+            )
+            .await
+            .map_err(IcebergError::namespace)?,
+    ))
+}
+
+async fn load_namespace_metadata<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Path((catalog, namespace)): Path<(String, String)>,
+) -> IcebergResult<axum::Json<IcebergGetNamespaceResponse>>
+where
+    T: IcebergRestHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    let path = NamespacePath::try_new(catalog, &namespace).map_err(IcebergError::namespace)?;
+    Ok(axum::Json(
+        handler
+            .load_namespace_metadata(path, context)
+            .await
+            .map_err(IcebergError::namespace)?,
+    ))
+}
+
+async fn list_tables<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Path((catalog, namespace)): Path<(String, String)>,
+    Query(params): Query<PageParams>,
+) -> IcebergResult<axum::Json<IcebergListTablesResponse>>
+where
+    T: IcebergRestHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    let path = NamespacePath::try_new(catalog, &namespace).map_err(IcebergError::namespace)?;
+    Ok(axum::Json(
+        handler
+            .list_tables(path, params.into(), context)
+            .await
+            .map_err(IcebergError::namespace)?,
+    ))
+}
+
+async fn load_table<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    headers: HeaderMap,
+    Path((catalog, namespace, table)): Path<(String, String, String)>,
+) -> IcebergResult<axum::Json<IcebergLoadTableResult>>
+where
+    T: IcebergRestHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    let namespace = NamespacePath::try_new(catalog, &namespace).map_err(IcebergError::namespace)?;
+    let path = IcebergTablePath { namespace, table };
+    Ok(axum::Json(
+        handler
+            .load_table(path, wants_vended_credentials(&headers), context)
+            .await
+            .map_err(IcebergError::table)?,
+    ))
+}
@@ -0,0 +1,82 @@
+//! Server-side glue for the Iceberg REST Catalog API (`/iceberg/v1/...`).
+//!
+//! The wire DTOs live in [`unitycatalog_common::models::iceberg::v1`] and are
+//! re-exported here. What stays server-only is [`IcebergError`]: the mapping
+//! from the server's internal [`Error`] onto the Iceberg error envelope, plus
+//! its axum [`IntoResponse`].
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::Error;
+
+pub use unitycatalog_common::models::iceberg::v1::*;
+
+/// Error wrapper used as the error half of every Iceberg handler's `Result`.
+///
+/// Iceberg clients pick their exception from the error `type`, and a 404 means
+/// a different thing on a namespace route than on a table route, so the
+/// wrapper carries which kind of resource the route addresses.
+#[derive(Debug)]
+pub struct IcebergError {
+    error: Error,
+    not_found: IcebergErrorType,
+}
+
+impl IcebergError {
+    /// An error from a route that addresses a namespace (or the catalog).
+    pub fn namespace(error: Error) -> Self {
+        Self {
+            error,
+            not_found: IcebergErrorType::NoSuchNamespaceException,
+        }
+    }
+
+    /// An error from a route that addresses a table.
+    pub fn table(error: Error) -> Self {
+        Self {
+            error,
+            not_found: IcebergErrorType::NoSuchTableException,
+        }
+    }
+
+    fn parts(&self) -> (StatusCode, IcebergErrorType) {
+        use IcebergErrorType::*;
+        match &self.error {
+            Error::NotFound | Error::ResourceStore { .. } => {
+                (StatusCode::NOT_FOUND, self.not_found)
+            }
+            Error::Common { source } => match source.error_code() {
+                "INVALID_PARAMETER_VALUE" => (StatusCode::BAD_REQUEST, BadRequestException),
+                "PERMISSION_DENIED" => (StatusCode::FORBIDDEN, ForbiddenException),
+                _ => (StatusCode::NOT_FOUND, self.not_found),
+            },
+            Error::NotAllowed => (StatusCode::FORBIDDEN, ForbiddenException),
+            Error::Unauthenticated => (StatusCode::UNAUTHORIZED, NotAuthorizedException),
+            Error::InvalidArgument(_) | Error::InvalidIdentifier(_) | Error::MissingRecipient => {
+                (StatusCode::BAD_REQUEST, BadRequestException)
+            }
+            Error::NotImplemented(_) => (StatusCode::NOT_ACCEPTABLE, UnsupportedOperationException),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                InternalServerErrorException,
+            ),
+        }
+    }
+}
+
+impl IntoResponse for IcebergError {
+    fn into_response(self) -> Response {
+        let (status, error_type) = self.parts();
+        let body = IcebergErrorResponse {
+            error: IcebergErrorModel {
+                message: self.error.to_string(),
+                error_type,
+                code: status.as_u16(),
+                stack: None,
+            },
+        };
+        (status, Json(body)).into_response()
+    }
+}
@@ -7,11 +7,15 @@ use crate::api::{
 use axum::routing::{delete, get, patch, post};
 
 pub use delta::get_router as create_delta_router;
+pub use iceberg::get_router as create_iceberg_router;
 pub use sharing::get_router as create_sharing_router;
 pub use sharing::open_sharing_router as create_open_sharing_router;
+pub use table_history::get_router as create_table_history_router;
 
 pub mod delta;
+pub mod iceberg;
 mod sharing;
+mod table_history;
 
 pub fn create_catalogs_router<T, Cx>(handler: T) -> axum::Router
 where
@@ -184,10 +184,13 @@ where
     T: SharingQueryHandler<Cx> + Clone + Send + Sync + 'static,
     Cx: axum::extract::FromRequestParts<T> + Send,
 {
-    let result = handler.query_table(request, context).await?;
+    // Stream the NDJSON lines straight into the response body: hyper only polls
+    // for the next chunk once the previous one has been written, which gives us
+    // backpressure all the way down to log replay.
+    let stream = handler.query_table(request, context).await?;
     Response::builder()
         .header(CONTENT_TYPE, "application/x-ndjson; charset=utf-8")
         .header(DELTA_SHARING_CAPABILITIES, DELTA_SHARING_CAPABILITIES_VALUE)
-        .body(Body::from(result))
+        .body(Body::from_stream(stream))
         .map_err(|e| Error::generic(e.to_string()))
 }
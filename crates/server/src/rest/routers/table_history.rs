@@ -0,0 +1,60 @@
+//! Hand-written Axum router for the table history API
+//! (`GET /tables/{full_name}/history`).
+//!
+//! The route sits next to the generated tables routes under the Unity Catalog
+//! base path, and errors use the regular Unity Catalog error envelope.
+
+use axum::extract::{Path, Query, State};
+use axum::routing::{Router, get};
+use serde::Deserialize;
+
+use unitycatalog_common::models::table_history::v1::{
+    GetTableHistoryRequest, GetTableHistoryResponse,
+};
+
+use crate::Result;
+use crate::api::TableHistoryHandler;
+
+/// Create a [`Router`] for the table history API.
+pub fn get_router<T, Cx>(state: T) -> Router
+where
+    T: TableHistoryHandler<Cx> + Clone,
+    Cx: axum::extract::FromRequestParts<T> + Send + 'static,
+{
+    Router::new()
+        .route(
+            "/tables/{full_name}/history",
+            get(get_table_history::<T, Cx>),
+        )
+        .with_state(state)
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoryParams {
+    start_version: Option<i64>,
+    end_version: Option<i64>,
+    max_results: Option<i32>,
+    page_token: Option<String>,
+}
+
+async fn get_table_history<T, Cx>(
+    State(handler): State<T>,
+    context: Cx,
+    Path(full_name): Path<String>,
+    Query(params): Query<HistoryParams>,
+) -> Result<axum::Json<GetTableHistoryResponse>>
+where
+    T: TableHistoryHandler<Cx> + Clone + Send + Sync + 'static,
+    Cx: axum::extract::FromRequestParts<T> + Send,
+{
+    let request = GetTableHistoryRequest {
+        full_name,
+        start_version: params.start_version,
+        end_version: params.end_version,
+        max_results: params.max_results,
+        page_token: params.page_token,
+    };
+    Ok(axum::Json(
+        handler.get_table_history(request, context).await?,
+    ))
+}
@@ -0,0 +1,472 @@
+//! Server-side publishing of catalog-managed Delta commits.
+//!
+//! Writers of catalog-managed tables stage each commit under
+//! `_delta_log/_staged_commits/` and have the catalog ratify it. Publishing the
+//! commit — copying it to its `_delta_log/<version>.json` name and reporting
+//! `latest_backfilled_version` to the [`CommitCoordinator`] — is normally left to
+//! the writer. A writer that never publishes leaves the table pinned at the
+//! unbackfilled-commit cap, rejecting every further commit with a 429.
+//!
+//! [`BackfillPublisher`] does the publishing on the writer's behalf: it
+//! periodically sweeps the managed Delta tables, copies each ratified commit to
+//! its published name through the server's object stores, and then records the
+//! backfill with the coordinator. A table is only touched while the publisher
+//! holds its backfill lease, so several server replicas can run a publisher
+//! against the same coordinator without racing on a table's log.
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use object_store::path::Path;
+use object_store::{ObjectStoreExt, PutMode};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+use unitycatalog_common::ObjectLabel;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table};
+use unitycatalog_common::services::commit_coordinator::CommitCoordinator;
+
+use super::kernel::ObjectStoreFactory;
+use super::location::StorageLocationUrl;
+use super::managed_delta_contract as contract;
+use crate::store::ResourceStore;
+use crate::{Error, Result};
+
+/// Settings for a [`BackfillPublisher`].
+#[derive(Debug, Clone)]
+pub struct BackfillConfig {
+    /// Time between two sweeps over all managed tables.
+    pub interval: Duration,
+    /// How long a table's lease is held before another publisher may take
+    /// it over. Must comfortably exceed the time needed to publish one batch.
+    pub lease_ttl: Duration,
+    /// Lease owner identifying this publisher; unique per server replica.
+    pub owner: String,
+}
+
+impl Default for BackfillConfig {
+    fn default() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        Self {
+            interval: Duration::from_secs(30),
+            lease_ttl: Duration::from_secs(60),
+            owner: format!("backfill-{}-{nanos:x}", std::process::id()),
+        }
+    }
+}
+
+/// Background publisher for ratified-but-unpublished Delta commits.
+///
+/// Build one with [`ServerHandler::backfill_publisher`](super::ServerHandler::backfill_publisher).
+pub struct BackfillPublisher {
+    store: Arc<dyn ResourceStore>,
+    coordinator: Arc<dyn CommitCoordinator>,
+    factory: Arc<dyn ObjectStoreFactory>,
+    config: BackfillConfig,
+}
+
+impl BackfillPublisher {
+    pub(crate) fn new(
+        store: Arc<dyn ResourceStore>,
+        coordinator: Arc<dyn CommitCoordinator>,
+        factory: Arc<dyn ObjectStoreFactory>,
+        config: BackfillConfig,
+    ) -> Self {
+        Self {
+            store,
+            coordinator,
+            factory,
+            config,
+        }
+    }
+
+    /// Spawn [`run`](Self::run) onto the current tokio runtime.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    /// Sweep all managed tables every [`interval`](BackfillConfig::interval),
+    /// forever. A failed sweep is logged and retried on the next tick.
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(0) => {}
+                Ok(published) => tracing::debug!(published, "published ratified commits"),
+                Err(e) => tracing::warn!(error = %e, "commit backfill sweep failed"),
+            }
+        }
+    }
+
+    /// Publish the ratified commits of every managed Delta table once.
+    ///
+    /// Returns the number of commits published. Failures on a single table are
+    /// logged and do not stop the sweep.
+    pub async fn run_once(&self) -> Result<usize> {
+        let tables = self.store.list_all(&ObjectLabel::Table, None).await?;
+        let mut published = 0;
+        for resource in tables {
+            let table: Table = resource.try_into()?;
+            if !contract::is_catalog_managed(table.table_type)
+                || table.data_source_format != DataSourceFormat::Delta as i32
+            {
+                continue;
+            }
+            match self.publish_table(&table).await {
+                Ok(count) => published += count,
+                Err(e) => tracing::warn!(
+                    table = %table.full_name,
+                    error = %e,
+                    "failed to publish ratified commits"
+                ),
+            }
+        }
+        Ok(published)
+    }
+
+    /// Publish one table's commits while holding its backfill lease.
+    async fn publish_table(&self, table: &Table) -> Result<usize> {
+        let (Some(table_id), Some(location)) = (&table.table_id, &table.storage_location) else {
+            return Ok(0);
+        };
+        let owner = &self.config.owner;
+        if !self
+            .coordinator
+            .acquire_backfill_lease(table_id, owner, self.config.lease_ttl)
+            .await?
+        {
+            return Ok(0);
+        }
+        let result = self.publish_commits(table_id, location).await;
+        if let Err(e) = self
+            .coordinator
+            .release_backfill_lease(table_id, owner)
+            .await
+        {
+            tracing::warn!(table_id, error = %e, "failed to release backfill lease");
+        }
+        result
+    }
+
+    async fn publish_commits(&self, table_id: &str, location: &str) -> Result<usize> {
+        let lease = BackfillLease {
+            owner: &self.config.owner,
+            ttl: self.config.lease_ttl,
+        };
+        publish_commits(
+            self.coordinator.as_ref(),
+            self.factory.as_ref(),
+            table_id,
+            location,
+            Some(&lease),
+        )
+        .await
+    }
+}
+
+/// A backfill lease held by the caller of [`publish_commits`].
+///
+/// Publishing renews the lease once half its ttl has passed, so a long backlog
+/// of commits is not taken over by another publisher halfway through.
+pub(crate) struct BackfillLease<'a> {
+    pub owner: &'a str,
+    pub ttl: Duration,
+}
+
+/// Copy the unpublished commits of a table to their `NNNN.json` names, then
+/// record the highest contiguous published version as backfilled.
+///
+/// Callers are expected to hold the table's backfill lease, or to otherwise
+/// know that no other publisher is working on the table. A given `lease` is
+/// renewed while publishing; if it is lost, publishing stops early.
+pub(crate) async fn publish_commits(
+    coordinator: &dyn CommitCoordinator,
+    factory: &dyn ObjectStoreFactory,
+    table_id: &str,
+    location: &str,
+    lease: Option<&BackfillLease<'_>>,
+) -> Result<usize> {
+    let (commits, _) = coordinator.get_commits(table_id, 0, None).await?;
+    if commits.is_empty() {
+        return Ok(0);
+    }
+
+    let location = StorageLocationUrl::parse(location)?;
+    let store = factory
+        .create_object_store(location.location())
+        .await
+        .map_err(Error::generic)?;
+    let log = Path::from_url_path(location.location().path())
+        .map_err(object_store::Error::from)?
+        .child("_delta_log");
+
+    let mut backfilled = None;
+    let mut published = 0;
+    let mut renewed = Instant::now();
+    for commit in &commits {
+        if let Some(lease) = lease
+            && renewed.elapsed() >= lease.ttl / 2
+        {
+            if !coordinator
+                .acquire_backfill_lease(table_id, lease.owner, lease.ttl)
+                .await?
+            {
+                tracing::warn!(table_id, "lost backfill lease while publishing");
+                break;
+            }
+            renewed = Instant::now();
+        }
+        let staged = log
+            .child("_staged_commits")
+            .child(commit.file_name.as_str());
+        let target = log.child(format!("{:020}.json", commit.version));
+        // Not every store supports `copy_if_not_exists`, so read the staged
+        // commit and create the published file only if it does not exist yet:
+        // the writer (or an earlier sweep that failed to record the backfill)
+        // may already have published this version.
+        let result: object_store::Result<()> = async {
+            let bytes = store.get(&staged).await?.bytes().await?;
+            store
+                .put_opts(&target, bytes.into(), PutMode::Create.into())
+                .await?;
+            Ok(())
+        }
+        .await;
+        match result {
+            Ok(()) => published += 1,
+            Err(object_store::Error::AlreadyExists { .. }) => {}
+            Err(e) => {
+                // Backfill must stay contiguous, so stop at the first gap.
+                tracing::warn!(
+                    table_id,
+                    version = commit.version,
+                    error = %e,
+                    "failed to publish ratified commit"
+                );
+                break;
+            }
+        }
+        backfilled = Some(commit.version);
+    }
+
+    if let Some(version) = backfilled {
+        coordinator.commit(table_id, None, Some(version)).await?;
+    }
+    Ok(published)
+}
+
+#[cfg(all(test, feature = "memory", not(windows)))]
+mod tests {
+    use object_store::PutPayload;
+    use object_store::local::LocalFileSystem;
+    use unitycatalog_common::models::ResourceRef;
+    use unitycatalog_common::models::delta_commits::v1::CommitInfo;
+    use unitycatalog_common::models::tables::v1::TableType;
+    use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+
+    use super::*;
+    use crate::api::RequestContext;
+    use crate::memory::InMemoryResourceStore;
+    use crate::policy::{ConstantPolicy, Policy};
+    use crate::services::{LocalStoragePolicy, ServerHandler};
+    use unitycatalog_common::services::commit_coordinator::{
+        DEFAULT_MAX_UNBACKFILLED_COMMITS, ProvidesCommitCoordinator,
+    };
+
+    async fn handler_with_table(root: &std::path::Path) -> (ServerHandler<RequestContext>, String) {
+        let encryptor =
+            EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+        let store = Arc::new(InMemoryResourceStore::new(encryptor));
+        let location = url::Url::from_directory_path(root.join("t")).unwrap();
+        let table = Table {
+            name: "t".to_string(),
+            catalog_name: "cat".to_string(),
+            schema_name: "sch".to_string(),
+            table_type: TableType::Managed as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            storage_location: Some(location.to_string()),
+            ..Default::default()
+        };
+        let (_, table_ref) = store.create(table.into()).await.unwrap();
+        let ResourceRef::Uuid(table_id) = table_ref else {
+            panic!("expected uuid");
+        };
+        let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
+        let handler = ServerHandler::try_new_tokio(policy, store.clone(), store)
+            .unwrap()
+            .with_local_storage_policy(LocalStoragePolicy::new([root]).unwrap());
+        (handler, table_id.to_string())
+    }
+
+    /// Stage and ratify commit `version`, returning the staged file's contents.
+    async fn stage_commit(
+        handler: &ServerHandler<RequestContext>,
+        root: &std::path::Path,
+        table_id: &str,
+        version: i64,
+    ) -> String {
+        let file_name = format!("{version:020}.{table_id}.json");
+        let body = format!("{{\"commitInfo\":{{\"version\":{version}}}}}\n");
+        let path = Path::from_url_path(
+            url::Url::from_file_path(root.join("t/_delta_log/_staged_commits").join(&file_name))
+                .unwrap()
+                .path(),
+        )
+        .unwrap();
+        LocalFileSystem::new()
+            .put(&path, PutPayload::from(body.clone()))
+            .await
+            .unwrap();
+        handler
+            .commit_coordinator()
+            .commit(
+                table_id,
+                Some(CommitInfo {
+                    version,
+                    timestamp: 1000 + version,
+                    file_name,
+                    file_size: body.len() as i64,
+                    file_modification_timestamp: 2000 + version,
+                }),
+                None,
+            )
+            .await
+            .unwrap();
+        body
+    }
+
+    fn publisher(handler: &ServerHandler<RequestContext>, owner: &str) -> BackfillPublisher {
+        handler.backfill_publisher(BackfillConfig {
+            owner: owner.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn publishes_ratified_commits_and_records_backfill() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+
+        let mut bodies = Vec::new();
+        for version in 1..=DEFAULT_MAX_UNBACKFILLED_COMMITS {
+            bodies.push(stage_commit(&handler, &root, &table_id, version).await);
+        }
+
+        assert_eq!(
+            publisher(&handler, "a").run_once().await.unwrap(),
+            bodies.len()
+        );
+        for (version, body) in (1..).zip(&bodies) {
+            let published = root.join(format!("t/_delta_log/{version:020}.json"));
+            assert_eq!(&std::fs::read_to_string(published).unwrap(), body);
+        }
+        let (commits, latest) = handler
+            .commit_coordinator()
+            .get_commits(&table_id, 0, None)
+            .await
+            .unwrap();
+        assert!(commits.is_empty());
+        assert_eq!(latest, DEFAULT_MAX_UNBACKFILLED_COMMITS);
+
+        // The table accepts commits again, and a second sweep publishes them.
+        stage_commit(
+            &handler,
+            &root,
+            &table_id,
+            DEFAULT_MAX_UNBACKFILLED_COMMITS + 1,
+        )
+        .await;
+        assert_eq!(publisher(&handler, "a").run_once().await.unwrap(), 1);
+        assert_eq!(publisher(&handler, "a").run_once().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn skips_tables_leased_by_another_publisher() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        stage_commit(&handler, &root, &table_id, 1).await;
+
+        let coordinator = handler.commit_coordinator();
+        assert!(
+            coordinator
+                .acquire_backfill_lease(&table_id, "other", Duration::from_secs(60))
+                .await
+                .unwrap()
+        );
+        assert_eq!(publisher(&handler, "a").run_once().await.unwrap(), 0);
+        assert!(!root.join("t/_delta_log/00000000000000000001.json").exists());
+
+        coordinator
+            .release_backfill_lease(&table_id, "other")
+            .await
+            .unwrap();
+        assert_eq!(publisher(&handler, "a").run_once().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn stops_when_the_lease_is_lost() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        stage_commit(&handler, &root, &table_id, 1).await;
+        let location = url::Url::from_directory_path(root.join("t")).unwrap();
+
+        // Another publisher took the lease over; a zero ttl makes the first
+        // renewal happen before any commit is published.
+        let coordinator = handler.commit_coordinator();
+        assert!(
+            coordinator
+                .acquire_backfill_lease(&table_id, "other", Duration::from_secs(60))
+                .await
+                .unwrap()
+        );
+        let lease = BackfillLease {
+            owner: "a",
+            ttl: Duration::ZERO,
+        };
+        let factory = publisher(&handler, "a").factory;
+        let published = publish_commits(
+            coordinator,
+            factory.as_ref(),
+            &table_id,
+            location.as_str(),
+            Some(&lease),
+        )
+        .await
+        .unwrap();
+        assert_eq!(published, 0);
+        assert!(!root.join("t/_delta_log/00000000000000000001.json").exists());
+    }
+
+    #[tokio::test]
+    async fn stops_at_first_missing_staged_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        stage_commit(&handler, &root, &table_id, 1).await;
+        stage_commit(&handler, &root, &table_id, 2).await;
+        stage_commit(&handler, &root, &table_id, 3).await;
+        std::fs::remove_file(root.join(format!(
+            "t/_delta_log/_staged_commits/{:020}.{table_id}.json",
+            2
+        )))
+        .unwrap();
+
+        assert_eq!(publisher(&handler, "a").run_once().await.unwrap(), 1);
+        let (commits, _) = handler
+            .commit_coordinator()
+            .get_commits(&table_id, 0, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            commits.iter().map(|c| c.version).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+}
@@ -0,0 +1,551 @@
+//! [`IcebergRestHandler`] for the server: serves UniForm-enabled Delta tables
+//! through the Iceberg REST Catalog protocol.
+//!
+//! Catalogs, schemas and tables are resolved through the regular handlers, so
+//! the Iceberg surface is subject to the same policy checks as the UC REST API.
+//! A table is visible to Iceberg once a UniForm writer has reported the
+//! location of the Iceberg metadata it converted (see
+//! [`managed_delta_contract::uniform_from_properties`]); `loadTable` passes that
+//! `metadata.json` through verbatim.
+
+use std::collections::BTreeMap;
+
+use object_store::ObjectStoreExt;
+use object_store::path::Path;
+
+use unitycatalog_common::models::catalogs::v1::GetCatalogRequest;
+use unitycatalog_common::models::iceberg::v1::*;
+use unitycatalog_common::models::schemas::v1::{GetSchemaRequest, ListSchemasRequest};
+use unitycatalog_common::models::tables::v1::{
+    DataSourceFormat, GetTableRequest, ListTablesRequest, Table,
+};
+use unitycatalog_common::models::temporary_credentials::v1::{
+    GenerateTemporaryTableCredentialsRequest, TemporaryCredential,
+    generate_temporary_table_credentials_request::Operation as TableOp,
+    temporary_credential::Credentials,
+};
+
+use super::ServerHandler;
+use super::kernel::ObjectStoreFactory;
+use super::location::StorageLocationUrl;
+use super::managed_delta_contract as contract;
+use crate::api::iceberg::{IcebergRestHandler, IcebergTablePath, NamespacePath, PageQuery};
+use crate::api::{
+    CatalogHandler, RequestContext, SchemaHandler, TableHandler, TemporaryCredentialHandler,
+};
+use crate::{Error, Result};
+
+/// The endpoints `getConfig` advertises, relative to the Iceberg base path.
+const ENDPOINTS: &[&str] = &[
+    "GET /v1/{prefix}/namespaces",
+    "GET /v1/{prefix}/namespaces/{namespace}",
+    "GET /v1/{prefix}/namespaces/{namespace}/tables",
+    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+];
+
+#[async_trait::async_trait]
+impl IcebergRestHandler for ServerHandler<RequestContext> {
+    async fn get_config(
+        &self,
+        warehouse: Option<String>,
+        context: RequestContext,
+    ) -> Result<IcebergCatalogConfig> {
+        let catalog = warehouse.filter(|w| !w.is_empty()).ok_or_else(|| {
+            Error::invalid_argument("warehouse is required and must name a catalog")
+        })?;
+        let catalog = CatalogHandler::get_catalog(
+            self,
+            GetCatalogRequest {
+                name: catalog,
+                include_browse: None,
+            },
+            context,
+        )
+        .await?;
+        Ok(IcebergCatalogConfig {
+            defaults: BTreeMap::new(),
+            overrides: BTreeMap::from([(
+                "prefix".to_string(),
+                format!("catalogs/{}", catalog.name),
+            )]),
+            endpoints: Some(ENDPOINTS.iter().map(|s| s.to_string()).collect()),
+        })
+    }
+
+    async fn list_namespaces(
+        &self,
+        catalog: String,
+        parent: Option<String>,
+        page: PageQuery,
+        context: RequestContext,
+    ) -> Result<IcebergListNamespacesResponse> {
+        if let Some(parent) = parent.filter(|p| !p.is_empty()) {
+            let path = NamespacePath::try_new(catalog, &parent)?;
+            get_schema(self, &path, context).await?;
+            return Ok(IcebergListNamespacesResponse::default());
+        }
+        let response = SchemaHandler::list_schemas(
+            self,
+            ListSchemasRequest {
+                catalog_name: catalog,
+                max_results: page.page_size,
+                page_token: page.page_token,
+                include_browse: None,
+            },
+            context,
+        )
+        .await?;
+        Ok(IcebergListNamespacesResponse {
+            next_page_token: response.next_page_token.filter(|t| !t.is_empty()),
+            namespaces: response
+                .schemas
+                .into_iter()
+                .map(|schema| vec![schema.name])
+                .collect(),
+        })
+    }
+
+    async fn load_namespace_metadata(
+        &self,
+        path: NamespacePath,
+        context: RequestContext,
+    ) -> Result<IcebergGetNamespaceResponse> {
+        let schema = get_schema(self, &path, context).await?;
+        let mut properties: BTreeMap<String, String> = schema.properties.into_iter().collect();
+        if let Some(comment) = schema.comment {
+            properties.insert("comment".to_string(), comment);
+        }
+        if let Some(location) = schema.storage_root {
+            properties.insert("location".to_string(), location);
+        }
+        Ok(IcebergGetNamespaceResponse {
+            namespace: vec![schema.name],
+            properties,
+        })
+    }
+
+    async fn list_tables(
+        &self,
+        path: NamespacePath,
+        page: PageQuery,
+        context: RequestContext,
+    ) -> Result<IcebergListTablesResponse> {
+        let response = TableHandler::list_tables(
+            self,
+            ListTablesRequest {
+                catalog_name: path.catalog,
+                schema_name: path.schema.clone(),
+                max_results: page.page_size,
+                page_token: page.page_token,
+                ..Default::default()
+            },
+            context,
+        )
+        .await?;
+        Ok(IcebergListTablesResponse {
+            next_page_token: response.next_page_token.filter(|t| !t.is_empty()),
+            identifiers: response
+                .tables
+                .into_iter()
+                .filter(|table| iceberg_metadata_location(table).is_some())
+                .map(|table| IcebergTableIdentifier {
+                    namespace: vec![path.schema.clone()],
+                    name: table.name,
+                })
+                .collect(),
+        })
+    }
+
+    async fn load_table(
+        &self,
+        path: IcebergTablePath,
+        vend_credentials: bool,
+        context: RequestContext,
+    ) -> Result<IcebergLoadTableResult> {
+        let table = TableHandler::get_table(
+            self,
+            GetTableRequest {
+                full_name: format!(
+                    "{}.{}.{}",
+                    path.namespace.catalog, path.namespace.schema, path.table
+                ),
+                include_delta_metadata: None,
+                include_browse: None,
+                include_manifest_capabilities: None,
+            },
+            context.clone(),
+        )
+        .await?;
+        // Without Iceberg metadata the table does not exist as far as Iceberg
+        // clients are concerned, just like it is absent from `list_tables`.
+        let Some(metadata_location) = iceberg_metadata_location(&table) else {
+            tracing::debug!(
+                table = %table.full_name,
+                uniform_enabled = contract::iceberg_enabled(
+                    &table.properties.clone().into_iter().collect()
+                ),
+                "no Iceberg metadata reported for table"
+            );
+            return Err(Error::NotFound);
+        };
+        // Checked on write as well, but a location stored before that check
+        // existed must not make the server read outside the table either.
+        contract::validate_iceberg_metadata_location(
+            &metadata_location,
+            table.storage_location.as_deref(),
+        )?;
+        let metadata = self.read_iceberg_metadata(&metadata_location).await?;
+
+        let (config, storage_credentials) = if vend_credentials {
+            let table_id = table
+                .table_id
+                .ok_or_else(|| Error::invalid_argument("table has no id"))?;
+            let creds = self
+                .generate_temporary_table_credentials(
+                    GenerateTemporaryTableCredentialsRequest {
+                        table_id,
+                        operation: TableOp::Read as i32,
+                    },
+                    context,
+                )
+                .await?;
+            let config = to_file_io_config(&creds);
+            let credential = IcebergStorageCredential {
+                prefix: creds.url.clone(),
+                config: config.clone(),
+            };
+            (config, Some(vec![credential]))
+        } else {
+            (BTreeMap::new(), None)
+        };
+
+        Ok(IcebergLoadTableResult {
+            metadata_location: Some(metadata_location),
+            metadata,
+            config,
+            storage_credentials,
+        })
+    }
+}
+
+impl ServerHandler<RequestContext> {
+    /// Read and parse the Iceberg `metadata.json` at `location`.
+    async fn read_iceberg_metadata(&self, location: &str) -> Result<serde_json::Value> {
+        let location = StorageLocationUrl::parse(location)?;
+        let store = self
+            .handler
+            .create_object_store(location.location())
+            .await
+            .map_err(Error::generic)?;
+        let path =
+            Path::from_url_path(location.location().path()).map_err(object_store::Error::from)?;
+        let bytes = store.get(&path).await?.bytes().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+async fn get_schema(
+    handler: &ServerHandler<RequestContext>,
+    path: &NamespacePath,
+    context: RequestContext,
+) -> Result<unitycatalog_common::models::schemas::v1::Schema> {
+    SchemaHandler::get_schema(
+        handler,
+        GetSchemaRequest {
+            full_name: format!("{}.{}", path.catalog, path.schema),
+        },
+        context,
+    )
+    .await
+}
+
+/// The Iceberg metadata location of a UniForm Delta table, if one was reported.
+fn iceberg_metadata_location(table: &Table) -> Option<String> {
+    if table.data_source_format != DataSourceFormat::Delta as i32 {
+        return None;
+    }
+    let properties: BTreeMap<String, String> = table.properties.clone().into_iter().collect();
+    contract::uniform_from_properties(&properties)?
+        .iceberg
+        .metadata_location
+}
+
+/// Map a vended [`TemporaryCredential`] onto Iceberg FileIO properties.
+fn to_file_io_config(creds: &TemporaryCredential) -> BTreeMap<String, String> {
+    let mut config = BTreeMap::new();
+    match &creds.credentials {
+        Some(Credentials::AwsTempCredentials(aws)) => {
+            config.insert("s3.access-key-id".to_string(), aws.access_key_id.clone());
+            config.insert(
+                "s3.secret-access-key".to_string(),
+                aws.secret_access_key.clone(),
+            );
+            if !aws.session_token.is_empty() {
+                config.insert("s3.session-token".to_string(), aws.session_token.clone());
+            }
+        }
+        // R2 speaks the S3 protocol.
+        Some(Credentials::R2TempCredentials(r2)) => {
+            config.insert("s3.access-key-id".to_string(), r2.access_key_id.clone());
+            config.insert(
+                "s3.secret-access-key".to_string(),
+                r2.secret_access_key.clone(),
+            );
+            if !r2.session_token.is_empty() {
+                config.insert("s3.session-token".to_string(), r2.session_token.clone());
+            }
+        }
+        Some(Credentials::AzureUserDelegationSas(az)) => {
+            // Iceberg keys SAS tokens by the storage account host.
+            let key = StorageLocationUrl::parse(&creds.url)
+                .ok()
+                .and_then(|url| url.azure_account())
+                .map(|account| format!("adls.sas-token.{account}.dfs.core.windows.net"))
+                .unwrap_or_else(|| "adls.sas-token".to_string());
+            config.insert(key, az.sas_token.clone());
+        }
+        Some(Credentials::GcpOauthToken(gcp)) => {
+            config.insert("gcs.oauth2.token".to_string(), gcp.oauth_token.clone());
+            config.insert(
+                "gcs.oauth2.token-expires-at".to_string(),
+                creds.expiration_time.to_string(),
+            );
+        }
+        _ => {}
+    }
+    config
+}
+
+#[cfg(all(test, feature = "memory", not(windows)))]
+mod tests {
+    use std::sync::Arc;
+
+    use object_store::PutPayload;
+    use object_store::local::LocalFileSystem;
+    use unitycatalog_common::models::catalogs::v1::CreateCatalogRequest;
+    use unitycatalog_common::models::schemas::v1::CreateSchemaRequest;
+    use unitycatalog_common::models::tables::v1::TableType;
+    use unitycatalog_common::models::{ResourceIdent, ResourceName};
+    use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::policy::{ConstantPolicy, Policy, Principal};
+    use crate::services::LocalStoragePolicy;
+    use crate::store::ResourceStore;
+
+    fn ctx() -> RequestContext {
+        RequestContext {
+            recipient: Principal::anonymous(),
+        }
+    }
+
+    /// A handler with catalog `cat`, schema `sch`, a UniForm table `uni` whose
+    /// Iceberg metadata is written under `root`, and a plain Delta table `plain`.
+    async fn setup(root: &std::path::Path) -> ServerHandler<RequestContext> {
+        let encryptor =
+            EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+        let store = Arc::new(InMemoryResourceStore::new(encryptor));
+        let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
+        let handler = ServerHandler::try_new_tokio(policy, store.clone(), store.clone())
+            .unwrap()
+            .with_local_storage_policy(LocalStoragePolicy::new([root]).unwrap())
+            .with_managed_storage_root(Some(
+                url::Url::from_directory_path(root.join("managed"))
+                    .unwrap()
+                    .to_string(),
+            ));
+
+        handler
+            .create_catalog(
+                CreateCatalogRequest {
+                    name: "cat".into(),
+                    ..Default::default()
+                },
+                ctx(),
+            )
+            .await
+            .unwrap();
+        handler
+            .create_schema(
+                CreateSchemaRequest {
+                    name: "sch".into(),
+                    catalog_name: "cat".into(),
+                    comment: Some("sales".into()),
+                    ..Default::default()
+                },
+                ctx(),
+            )
+            .await
+            .unwrap();
+
+        let metadata = root.join("uni/metadata/v1.metadata.json");
+        LocalFileSystem::new()
+            .put(
+                &Path::from_url_path(url::Url::from_file_path(&metadata).unwrap().path()).unwrap(),
+                PutPayload::from_static(br#"{"format-version":2,"table-uuid":"u"}"#),
+            )
+            .await
+            .unwrap();
+        let metadata_url = url::Url::from_file_path(&metadata).unwrap().to_string();
+        for (name, properties) in [
+            (
+                "uni",
+                vec![(
+                    contract::PROP_ICEBERG_METADATA_LOCATION.to_string(),
+                    metadata_url,
+                )],
+            ),
+            ("plain", vec![]),
+        ] {
+            let location = url::Url::from_directory_path(root.join(name)).unwrap();
+            let table = Table {
+                name: name.to_string(),
+                catalog_name: "cat".to_string(),
+                schema_name: "sch".to_string(),
+                full_name: format!("cat.sch.{name}"),
+                table_type: TableType::External as i32,
+                data_source_format: DataSourceFormat::Delta as i32,
+                storage_location: Some(location.to_string()),
+                properties: properties.into_iter().collect(),
+                ..Default::default()
+            };
+            store.create(table.into()).await.unwrap();
+        }
+        handler
+    }
+
+    fn namespace() -> NamespacePath {
+        NamespacePath::try_new("cat".to_string(), "sch").unwrap()
+    }
+
+    #[tokio::test]
+    async fn config_scopes_requests_to_the_warehouse_catalog() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = setup(dir.path()).await;
+        let config = handler
+            .get_config(Some("cat".to_string()), ctx())
+            .await
+            .unwrap();
+        assert_eq!(
+            config.overrides.get("prefix").map(String::as_str),
+            Some("catalogs/cat")
+        );
+        assert!(handler.get_config(None, ctx()).await.is_err());
+        assert!(
+            handler
+                .get_config(Some("missing".to_string()), ctx())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_schemas_as_namespaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = setup(dir.path()).await;
+        let response = handler
+            .list_namespaces("cat".to_string(), None, PageQuery::default(), ctx())
+            .await
+            .unwrap();
+        assert!(response.namespaces.contains(&vec!["sch".to_string()]));
+
+        // Schemas have no child namespaces.
+        let children = handler
+            .list_namespaces(
+                "cat".to_string(),
+                Some("sch".to_string()),
+                PageQuery::default(),
+                ctx(),
+            )
+            .await
+            .unwrap();
+        assert!(children.namespaces.is_empty());
+
+        let metadata = handler
+            .load_namespace_metadata(namespace(), ctx())
+            .await
+            .unwrap();
+        assert_eq!(metadata.namespace, vec!["sch".to_string()]);
+        assert_eq!(
+            metadata.properties.get("comment").map(String::as_str),
+            Some("sales")
+        );
+        assert!(NamespacePath::try_new("cat".to_string(), "a\u{1f}b").is_err());
+    }
+
+    #[tokio::test]
+    async fn lists_and_loads_only_uniform_tables() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = setup(dir.path()).await;
+
+        let tables = handler
+            .list_tables(namespace(), PageQuery::default(), ctx())
+            .await
+            .unwrap();
+        let names: Vec<_> = tables.identifiers.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["uni"]);
+
+        let loaded = handler
+            .load_table(
+                IcebergTablePath {
+                    namespace: namespace(),
+                    table: "uni".to_string(),
+                },
+                false,
+                ctx(),
+            )
+            .await
+            .unwrap();
+        assert!(
+            loaded
+                .metadata_location
+                .as_deref()
+                .is_some_and(|l| l.ends_with("uni/metadata/v1.metadata.json"))
+        );
+        assert_eq!(loaded.metadata["table-uuid"], "u");
+        assert!(loaded.storage_credentials.is_none());
+
+        let err = handler
+            .load_table(
+                IcebergTablePath {
+                    namespace: namespace(),
+                    table: "plain".to_string(),
+                },
+                false,
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn rejects_metadata_outside_the_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let handler = setup(dir.path()).await;
+        let ident = ResourceIdent::table(ResourceName::new(["cat", "sch", "uni"]));
+        let (resource, _) = handler.get(&ident).await.unwrap();
+        let mut table = Table::try_from(resource).unwrap();
+        // Point `uni` at the metadata file, but move its root elsewhere.
+        table.storage_location = Some(
+            url::Url::from_directory_path(dir.path().join("plain"))
+                .unwrap()
+                .to_string(),
+        );
+        handler.update(&ident, table.into()).await.unwrap();
+
+        let err = handler
+            .load_table(
+                IcebergTablePath {
+                    namespace: namespace(),
+                    table: "uni".to_string(),
+                },
+                false,
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+    }
+}
@@ -26,7 +26,6 @@ use delta_kernel::scan::{Scan, ScanMetadata, scan_row_schema};
 use delta_kernel::snapshot::Snapshot;
 use delta_kernel::{DeltaResult, Engine};
 use futures::Stream;
-use url::Url;
 
 static SCAN_ROW_SCHEMA: LazyLock<ArrowSchemaRef> =
     LazyLock::new(|| Arc::new((scan_row_schema().as_ref()).try_into_arrow().unwrap()));
@@ -34,27 +33,27 @@ static SCAN_ROW_SCHEMA: LazyLock<ArrowSchemaRef> =
 /// A DataFusion [`TableProvider`] that replays a Delta table's log and exposes
 /// the resulting scan-file rows (per [`scan_row_schema`]).
 ///
-/// The provider carries the delta_kernel [`Engine`] used to read the log, so it
-/// is fully self-contained and does not depend on any session extension.
+/// The provider carries the delta_kernel [`Engine`] used to read the log and
+/// the [`Snapshot`] it replays, so it is fully self-contained and does not
+/// depend on any session extension. Every scan sees exactly the files of the
+/// version (and catalog log tail) the snapshot was built at.
 pub(crate) struct DeltaLogReplayProvider {
-    table: Url,
     engine: Arc<dyn Engine>,
+    snapshot: Arc<Snapshot>,
 }
 
 impl std::fmt::Debug for DeltaLogReplayProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DeltaLogReplayProvider")
-            .field("table", &self.table)
+            .field("table", self.snapshot.table_root())
+            .field("version", &self.snapshot.version())
             .finish_non_exhaustive()
     }
 }
 
 impl DeltaLogReplayProvider {
-    pub(crate) fn new(mut table: Url, engine: Arc<dyn Engine>) -> Result<Self> {
-        if !table.path().ends_with('/') {
-            table.set_path(&format!("{}/", table.path()));
-        }
-        Ok(Self { table, engine })
+    pub(crate) fn new(snapshot: Arc<Snapshot>, engine: Arc<dyn Engine>) -> Self {
+        Self { engine, snapshot }
     }
 
     pub(crate) fn scan_row_schema() -> ArrowSchemaRef {
@@ -95,17 +94,6 @@ impl TableProvider for DeltaLogReplayProvider {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         // TODO: handle predicate - this needs to be applied in the stream where we produce the
         // record batches
-        let engine = self.engine.clone();
-        let table_root = self.table.clone();
-
-        let snapshot = tokio::task::spawn_blocking(move || {
-            Snapshot::builder_for(table_root.as_str())
-                .build(engine.as_ref())
-                .map_err(|e| DataFusionError::Execution(e.to_string()))
-        })
-        .await
-        .map_err(|e| DataFusionError::Execution(e.to_string()))??;
-
         let projected_arrow = projection
             .map(|p| {
                 Self::scan_row_schema()
@@ -116,7 +104,9 @@ impl TableProvider for DeltaLogReplayProvider {
             .transpose()?
             .unwrap_or_else(Self::scan_row_schema);
 
-        let scan = snapshot
+        let scan = self
+            .snapshot
+            .clone()
             .scan_builder()
             .build()
             .map_err(|e| DataFusionError::Execution(e.to_string()))?;
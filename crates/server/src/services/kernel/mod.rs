@@ -4,7 +4,8 @@
 //! actually relies on: an [`ObjectStoreFactory`] abstraction (implemented by the
 //! server to resolve credentialed object stores per storage location) and a
 //! self-contained [`DeltaLogReplayProvider`] used to serve the Delta Sharing
-//! `query_table` response.
+//! `query_table` response, plus a [`DeltaScanProvider`] reading a table's rows
+//! at a pinned snapshot (used to materialize shared views).
 //!
 //! Rather than registering a custom DataFusion-backed kernel engine as a session
 //! extension, we construct delta_kernel's built-in [`DefaultEngine`] directly from
@@ -19,8 +20,12 @@ use object_store::DynObjectStore;
 use url::Url;
 
 pub(crate) mod delta_log;
+mod scan;
+mod statistics;
 
 pub(crate) use delta_log::DeltaLogReplayProvider;
+pub(crate) use scan::DeltaScanProvider;
+pub(crate) use statistics::{StatisticsCache, catalog_log_tail, compute_statistics};
 
 /// Resolves an [`object_store`] for a given storage location.
 ///
@@ -0,0 +1,353 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::array::{RecordBatch, RecordBatchOptions};
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::common::error::Result;
+use datafusion::common::{DataFusionError, ScalarValue};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::{
+    BinaryExpr, Expr, Operator, TableProviderFilterPushDown, TableType,
+};
+use datafusion::physical_expr::EquivalenceProperties;
+use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::stream::RecordBatchReceiverStreamBuilder;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties,
+};
+use delta_kernel::engine::arrow_conversion::TryIntoArrow as _;
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::expressions::{Expression, Predicate, Scalar};
+use delta_kernel::scan::Scan;
+use delta_kernel::schema::StructType;
+use delta_kernel::snapshot::SnapshotRef;
+use delta_kernel::{Engine, Version};
+
+/// A DataFusion [`TableProvider`] reading the rows of a Delta table at a pinned
+/// snapshot.
+///
+/// Only the projected columns are read, and filters on a column compared with
+/// a literal are handed to the kernel to skip files by their statistics. The
+/// kernel does not filter rows, so those filters are reported as
+/// [`Inexact`](TableProviderFilterPushDown::Inexact) and DataFusion still
+/// applies them. Rows are streamed from a blocking task rather than collected.
+pub(crate) struct DeltaScanProvider {
+    engine: Arc<dyn Engine>,
+    snapshot: SnapshotRef,
+    schema: SchemaRef,
+}
+
+impl std::fmt::Debug for DeltaScanProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeltaScanProvider")
+            .field("table", &self.snapshot.table_root().as_str())
+            .field("version", &self.snapshot.version())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DeltaScanProvider {
+    pub(crate) fn try_new(engine: Arc<dyn Engine>, snapshot: SnapshotRef) -> Result<Self> {
+        let schema: Schema = snapshot
+            .schema()
+            .as_ref()
+            .try_into_arrow()
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(Self {
+            engine,
+            snapshot,
+            schema: Arc::new(schema),
+        })
+    }
+}
+
+#[async_trait]
+impl TableProvider for DeltaScanProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        Ok(filters
+            .iter()
+            .map(|filter| match to_kernel_predicate(filter, &self.schema) {
+                Some(_) => TableProviderFilterPushDown::Inexact,
+                None => TableProviderFilterPushDown::Unsupported,
+            })
+            .collect())
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let kernel_error = |e: delta_kernel::Error| DataFusionError::External(Box::new(e));
+        let table_schema = self.snapshot.schema();
+        let indices: Vec<usize> = match projection {
+            Some(projection) => projection.clone(),
+            None => (0..self.schema.fields().len()).collect(),
+        };
+        let schema = Arc::new(self.schema.project(&indices)?);
+
+        // The kernel cannot read zero columns, so a projection-free scan (e.g.
+        // `count(*)`) reads the first column and only keeps the row counts.
+        let read_indices = if indices.is_empty() { vec![0] } else { indices };
+        let fields: Vec<_> = table_schema.fields().cloned().collect();
+        let read_schema = StructType::try_new(read_indices.iter().map(|i| fields[*i].clone()))
+            .map_err(kernel_error)?;
+
+        let mut builder = self
+            .snapshot
+            .clone()
+            .scan_builder()
+            .with_schema(Arc::new(read_schema));
+        let predicates: Vec<Predicate> = filters
+            .iter()
+            .filter_map(|filter| to_kernel_predicate(filter, &self.schema))
+            .collect();
+        if !predicates.is_empty() {
+            builder = builder.with_predicate(Arc::new(Predicate::and_from(predicates)));
+        }
+        let scan = builder.build().map_err(kernel_error)?;
+
+        Ok(Arc::new(DeltaScanExec {
+            engine: self.engine.clone(),
+            scan: Arc::new(scan),
+            version: self.snapshot.version(),
+            properties: Arc::new(PlanProperties::new(
+                EquivalenceProperties::new(schema.clone()),
+                Partitioning::UnknownPartitioning(1),
+                EmissionType::Incremental,
+                Boundedness::Bounded,
+            )),
+            schema,
+        }))
+    }
+}
+
+/// Translate a filter into a kernel predicate for file skipping, if it only
+/// compares top-level columns with literals of the column's type.
+fn to_kernel_predicate(expr: &Expr, schema: &Schema) -> Option<Predicate> {
+    match expr {
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => match op {
+            Operator::And => Some(Predicate::and(
+                to_kernel_predicate(left, schema)?,
+                to_kernel_predicate(right, schema)?,
+            )),
+            Operator::Or => Some(Predicate::or(
+                to_kernel_predicate(left, schema)?,
+                to_kernel_predicate(right, schema)?,
+            )),
+            _ => {
+                let (column, op, value) = match (left.as_ref(), right.as_ref()) {
+                    (Expr::Column(column), Expr::Literal(value, _)) => (column, *op, value),
+                    (Expr::Literal(value, _), Expr::Column(column)) => (column, op.swap()?, value),
+                    _ => return None,
+                };
+                let field = schema.field_with_name(&column.name).ok()?;
+                if field.data_type() != &value.data_type() {
+                    return None;
+                }
+                let column = Expression::column([column.name.as_str()]);
+                let value = Expression::literal(to_kernel_scalar(value)?);
+                match op {
+                    Operator::Eq => Some(Predicate::eq(column, value)),
+                    Operator::NotEq => Some(Predicate::ne(column, value)),
+                    Operator::Lt => Some(Predicate::lt(column, value)),
+                    Operator::LtEq => Some(Predicate::le(column, value)),
+                    Operator::Gt => Some(Predicate::gt(column, value)),
+                    Operator::GtEq => Some(Predicate::ge(column, value)),
+                    _ => None,
+                }
+            }
+        },
+        Expr::IsNull(inner) | Expr::IsNotNull(inner) => {
+            let Expr::Column(column) = inner.as_ref() else {
+                return None;
+            };
+            schema.field_with_name(&column.name).ok()?;
+            let column = Expression::column([column.name.as_str()]);
+            match expr {
+                Expr::IsNull(_) => Some(Predicate::is_null(column)),
+                _ => Some(Predicate::is_not_null(column)),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn to_kernel_scalar(value: &ScalarValue) -> Option<Scalar> {
+    Some(match value {
+        ScalarValue::Boolean(Some(v)) => Scalar::Boolean(*v),
+        ScalarValue::Int8(Some(v)) => Scalar::Byte(*v),
+        ScalarValue::Int16(Some(v)) => Scalar::Short(*v),
+        ScalarValue::Int32(Some(v)) => Scalar::Integer(*v),
+        ScalarValue::Int64(Some(v)) => Scalar::Long(*v),
+        ScalarValue::Float32(Some(v)) => Scalar::Float(*v),
+        ScalarValue::Float64(Some(v)) => Scalar::Double(*v),
+        ScalarValue::Utf8(Some(v))
+        | ScalarValue::LargeUtf8(Some(v))
+        | ScalarValue::Utf8View(Some(v)) => Scalar::String(v.clone()),
+        ScalarValue::Date32(Some(v)) => Scalar::Date(*v),
+        ScalarValue::TimestampMicrosecond(Some(v), Some(_)) => Scalar::Timestamp(*v),
+        ScalarValue::TimestampMicrosecond(Some(v), None) => Scalar::TimestampNtz(*v),
+        _ => return None,
+    })
+}
+
+/// Streams the rows of a kernel [`Scan`] from a blocking task.
+struct DeltaScanExec {
+    engine: Arc<dyn Engine>,
+    scan: Arc<Scan>,
+    version: Version,
+    schema: SchemaRef,
+    properties: Arc<PlanProperties>,
+}
+
+impl std::fmt::Debug for DeltaScanExec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeltaScanExec")
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DisplayAs for DeltaScanExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default
+            | DisplayFormatType::Verbose
+            | DisplayFormatType::TreeRender => {
+                write!(f, "DeltaScanExec: version={}", self.version)
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for DeltaScanExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "DeltaScanExec"
+    }
+
+    fn properties(&self) -> &Arc<PlanProperties> {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Execution(
+                "DeltaScanExec only supports a single partition".into(),
+            ));
+        }
+        let engine = self.engine.clone();
+        let scan = self.scan.clone();
+        let schema = self.schema.clone();
+        let mut builder = RecordBatchReceiverStreamBuilder::new(schema.clone(), 2);
+        let tx = builder.tx();
+        builder.spawn_blocking(move || {
+            let kernel_error = |e: delta_kernel::Error| DataFusionError::External(Box::new(e));
+            for data in scan.execute(engine).map_err(kernel_error)? {
+                let data = ArrowEngineData::try_from_engine_data(data.map_err(kernel_error)?)
+                    .map_err(kernel_error)?;
+                let batch = data.record_batch();
+                let batch = if schema.fields().is_empty() {
+                    RecordBatch::try_new_with_options(
+                        schema.clone(),
+                        vec![],
+                        &RecordBatchOptions::new().with_row_count(Some(batch.num_rows())),
+                    )?
+                } else {
+                    RecordBatch::try_new(schema.clone(), batch.columns().to_vec())?
+                };
+                // The receiver is gone once the consumer stops reading.
+                if tx.blocking_send(Ok(batch)).is_err() {
+                    break;
+                }
+            }
+            Ok(())
+        });
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::datatypes::{DataType, Field};
+    use datafusion::prelude::{col, lit};
+
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("region", DataType::Utf8, true),
+        ])
+    }
+
+    #[test]
+    fn translates_column_literal_comparisons() {
+        let schema = schema();
+        for filter in [
+            col("id").gt(lit(3i64)),
+            lit(3i64).lt(col("id")),
+            col("region").eq(lit("eu")).and(col("id").lt_eq(lit(10i64))),
+            col("region").is_null().or(col("id").not_eq(lit(1i64))),
+        ] {
+            assert!(
+                to_kernel_predicate(&filter, &schema).is_some(),
+                "{filter} should be pushed down"
+            );
+        }
+    }
+
+    #[test]
+    fn leaves_other_filters_to_datafusion() {
+        let schema = schema();
+        for filter in [
+            col("id").gt(lit(3i32)),
+            col("missing").eq(lit(1i64)),
+            (col("id") + lit(1i64)).gt(lit(3i64)),
+            col("region").eq(lit("eu")).and(col("id").like(lit("1%"))),
+        ] {
+            assert!(
+                to_kernel_predicate(&filter, &schema).is_none(),
+                "{filter} should not be pushed down"
+            );
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Table statistics derived from a Delta snapshot.
+//!
+//! Sizes and file counts come straight from the `add` actions that survive log
+//! replay. Row counts come from the per-file `stats` (`numRecords`), less the
+//! rows marked deleted by each file's deletion vector; the count is only
+//! reported when every live file carries stats.
+//!
+//! For catalog-managed tables the latest versions may only exist as ratified
+//! staged commits; [`catalog_log_tail`] turns the coordinator's view of those
+//! into the log tail the snapshot is built with.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::Int64Type;
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::{Engine, LogPath, Snapshot};
+use serde::Deserialize;
+use unitycatalog_common::models::delta_commits::v1::CommitInfo;
+use unitycatalog_common::models::tables::v1::TableStatistics;
+use url::Url;
+
+use crate::{Error, Result};
+
+/// The slice of a file's `stats` JSON we need.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: Option<i64>,
+}
+
+/// Map ratified but unpublished coordinator commits onto the staged-commit log
+/// tail of the table at `table_root`, ordered by version.
+pub(crate) fn catalog_log_tail(table_root: &Url, commits: &[CommitInfo]) -> Result<Vec<LogPath>> {
+    let mut table_root = table_root.clone();
+    if !table_root.path().ends_with('/') {
+        table_root.set_path(&format!("{}/", table_root.path()));
+    }
+    let mut commits: Vec<_> = commits.iter().collect();
+    commits.sort_by_key(|commit| commit.version);
+    commits
+        .into_iter()
+        .map(|commit| {
+            let size = u64::try_from(commit.file_size).map_err(|_| {
+                Error::generic(format!(
+                    "negative size {} for staged commit {}",
+                    commit.file_size, commit.version
+                ))
+            })?;
+            LogPath::staged_commit(
+                table_root.clone(),
+                &commit.file_name,
+                commit.file_modification_timestamp,
+                size,
+            )
+            .map_err(Error::generic)
+        })
+        .collect()
+}
+
+/// Compute the statistics of the table version `snapshot` points at.
+///
+/// `commit_file` is the file holding that version's commit: the published
+/// `_delta_log/<version>.json`, or the staged commit when the version is not
+/// published yet. This replays the log and reads the commit file, so callers
+/// should run it on a blocking thread.
+pub(crate) fn compute_statistics(
+    snapshot: &Arc<Snapshot>,
+    commit_file: &Url,
+    engine: &dyn Engine,
+) -> Result<TableStatistics> {
+    let version = snapshot.version();
+    let scan = snapshot
+        .clone()
+        .scan_builder()
+        .build()
+        .map_err(Error::generic)?;
+
+    let mut size_in_bytes = 0;
+    let mut num_files = 0;
+    let mut num_rows = Some(0);
+    let mut max_modification_time = 0;
+    for metadata in scan.scan_metadata(engine).map_err(Error::generic)? {
+        let (data, selection) = metadata.map_err(Error::generic)?.scan_files.into_parts();
+        let batch = ArrowEngineData::try_from_engine_data(data)
+            .map_err(Error::generic)?
+            .record_batch()
+            .clone();
+        let sizes = batch
+            .column_by_name("size")
+            .and_then(|c| c.as_primitive_opt::<Int64Type>())
+            .ok_or_else(|| Error::generic("scan files are missing `size`"))?;
+        let modification_times = batch
+            .column_by_name("modificationTime")
+            .and_then(|c| c.as_primitive_opt::<Int64Type>())
+            .ok_or_else(|| Error::generic("scan files are missing `modificationTime`"))?;
+        let stats = batch
+            .column_by_name("stats")
+            .and_then(|c| c.as_string_opt::<i32>())
+            .ok_or_else(|| Error::generic("scan files are missing `stats`"))?;
+        let cardinalities = batch
+            .column_by_name("deletionVector")
+            .and_then(|c| c.as_struct_opt())
+            .and_then(|dv| dv.column_by_name("cardinality"))
+            .and_then(|c| c.as_primitive_opt::<Int64Type>())
+            .ok_or_else(|| Error::generic("scan files are missing `deletionVector`"))?;
+
+        for row in 0..batch.num_rows() {
+            // A selection vector shorter than the batch leaves the tail selected.
+            if !selection.get(row).copied().unwrap_or(true) {
+                continue;
+            }
+            num_files += 1;
+            size_in_bytes += sizes.value(row);
+            max_modification_time = max_modification_time.max(modification_times.value(row));
+
+            let records = stats
+                .is_valid(row)
+                .then(|| serde_json::from_str::<FileStats>(stats.value(row)))
+                .transpose()?
+                .and_then(|stats| stats.num_records);
+            let deleted = cardinalities
+                .is_valid(row)
+                .then(|| cardinalities.value(row))
+                .unwrap_or_default();
+            num_rows = num_rows.zip(records).map(|(total, n)| total + n - deleted);
+        }
+    }
+
+    // The commit file's timestamp is when the version was written; fall back
+    // to the newest data file if the commit was already cleaned up.
+    let last_modified = engine
+        .storage_handler()
+        .head(commit_file)
+        .map(|meta| meta.last_modified)
+        .unwrap_or(max_modification_time);
+
+    Ok(TableStatistics {
+        version: version as i64,
+        size_in_bytes,
+        num_files,
+        num_rows,
+        last_modified,
+    })
+}
+
+/// Statistics computed per table root, reused while the table stays at the
+/// same version.
+///
+/// The cache holds at most `capacity` tables; when full, the least recently
+/// used one is evicted.
+pub(crate) struct StatisticsCache {
+    capacity: usize,
+    /// Logical clock advanced on every access, for least-recently-used order.
+    clock: u64,
+    entries: HashMap<Url, (TableStatistics, u64)>,
+}
+
+impl StatisticsCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&mut self, table_root: &Url) -> Option<TableStatistics> {
+        self.clock += 1;
+        let (statistics, last_used) = self.entries.get_mut(table_root)?;
+        *last_used = self.clock;
+        Some(*statistics)
+    }
+
+    pub(crate) fn insert(&mut self, table_root: Url, statistics: TableStatistics) {
+        self.clock += 1;
+        if self.entries.len() >= self.capacity
+            && !self.entries.contains_key(&table_root)
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(root, _)| root.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(table_root, (statistics, self.clock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_kernel::engine::default::DefaultEngine;
+    use object_store::local::LocalFileSystem;
+    use serde_json::json;
+
+    use super::*;
+    use crate::services::managed_delta_contract as contract;
+
+    const SCHEMA: &str = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}"#;
+
+    fn write_commit(root: &std::path::Path, version: u64, actions: &[String]) {
+        let log = root.join("_delta_log");
+        std::fs::create_dir_all(&log).unwrap();
+        std::fs::write(log.join(format!("{version:020}.json")), actions.join("\n")).unwrap();
+    }
+
+    fn add(path: &str, size: i64, stats: Option<&str>) -> String {
+        let stats = stats
+            .map(|s| format!(r#","stats":"{}""#, s.replace('"', "\\\"")))
+            .unwrap_or_default();
+        format!(
+            r#"{{"add":{{"path":"{path}","partitionValues":{{}},"size":{size},"modificationTime":1000,"dataChange":true{stats}}}}}"#
+        )
+    }
+
+    fn statistics_at(root: &std::path::Path) -> TableStatistics {
+        let engine = DefaultEngine::builder(Arc::new(LocalFileSystem::new())).build();
+        let table_root = Url::from_directory_path(root).unwrap();
+        let snapshot = Snapshot::builder_for(table_root.as_str())
+            .build(&engine)
+            .unwrap();
+        let commit_file = table_root
+            .join(&format!("_delta_log/{:020}.json", snapshot.version()))
+            .unwrap();
+        compute_statistics(&snapshot, &commit_file, &engine).unwrap()
+    }
+
+    #[test]
+    fn sums_live_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_commit(
+            root,
+            0,
+            &[
+                r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.to_string(),
+                format!(
+                    r#"{{"metaData":{{"id":"t","format":{{"provider":"parquet","options":{{}}}},"schemaString":"{SCHEMA}","partitionColumns":[],"configuration":{{}},"createdTime":1}}}}"#
+                ),
+                add("a.parquet", 100, Some(r#"{"numRecords":10}"#)),
+                add("b.parquet", 50, Some(r#"{"numRecords":5}"#)),
+            ],
+        );
+        let stats = statistics_at(root);
+        assert_eq!(stats.version, 0);
+        assert_eq!(stats.num_files, 2);
+        assert_eq!(stats.size_in_bytes, 150);
+        assert_eq!(stats.num_rows, Some(15));
+        assert!(stats.last_modified > 0);
+
+        write_commit(
+            root,
+            1,
+            &[
+                r#"{"remove":{"path":"a.parquet","deletionTimestamp":2000,"dataChange":true}}"#
+                    .to_string(),
+                add("c.parquet", 25, None),
+            ],
+        );
+        let stats = statistics_at(root);
+        assert_eq!(stats.version, 1);
+        assert_eq!(stats.num_files, 2);
+        assert_eq!(stats.size_in_bytes, 75);
+        // c.parquet has no stats, so the row count is unknown.
+        assert_eq!(stats.num_rows, None);
+    }
+
+    #[test]
+    fn reads_ratified_commits_from_the_log_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let table_id = "4f2b6d1e-0c7a-4b8e-9d3f-5a6b7c8d9e0f";
+        let mut configuration: serde_json::Map<String, serde_json::Value> =
+            contract::REQUIRED_FIXED_PROPERTIES
+                .iter()
+                .map(|(k, v)| (k.to_string(), json!(v)))
+                .collect();
+        configuration.insert(contract::PROP_UC_TABLE_ID.into(), json!(table_id));
+        let commit_info = |timestamp: i64| {
+            json!({"commitInfo": {"timestamp": timestamp, "inCommitTimestamp": timestamp}})
+                .to_string()
+        };
+        write_commit(
+            root,
+            0,
+            &[
+                commit_info(1000),
+                json!({"protocol": {
+                    "minReaderVersion": contract::REQUIRED_MIN_READER_VERSION,
+                    "minWriterVersion": contract::REQUIRED_MIN_WRITER_VERSION,
+                    "readerFeatures": contract::REQUIRED_READER_FEATURES,
+                    "writerFeatures": contract::REQUIRED_WRITER_FEATURES,
+                }})
+                .to_string(),
+                json!({"metaData": {
+                    "id": table_id,
+                    "format": {"provider": "parquet", "options": {}},
+                    "schemaString": SCHEMA.replace("\\\"", "\""),
+                    "partitionColumns": [],
+                    "configuration": configuration,
+                    "createdTime": 1000,
+                }})
+                .to_string(),
+                add("a.parquet", 100, Some(r#"{"numRecords":10}"#)),
+            ],
+        );
+        // Version 1 is ratified by the catalog but not published yet.
+        let staged = root.join("_delta_log/_staged_commits");
+        std::fs::create_dir_all(&staged).unwrap();
+        let file_name = format!("{:020}.{table_id}.json", 1);
+        let contents = [
+            commit_info(2000),
+            add("b.parquet", 50, Some(r#"{"numRecords":5}"#)),
+        ]
+        .join("\n");
+        std::fs::write(staged.join(&file_name), &contents).unwrap();
+
+        let engine = DefaultEngine::builder(Arc::new(LocalFileSystem::new())).build();
+        let table_root = Url::from_directory_path(root).unwrap();
+        let commits = [CommitInfo {
+            version: 1,
+            timestamp: 2000,
+            file_name: file_name.clone(),
+            file_size: contents.len() as i64,
+            file_modification_timestamp: 2000,
+        }];
+        let snapshot = Snapshot::builder_for(table_root.as_str())
+            .with_log_tail(catalog_log_tail(&table_root, &commits).unwrap())
+            .with_max_catalog_version(1)
+            .build(&engine)
+            .unwrap();
+        let commit_file = Url::from_file_path(staged.join(&file_name)).unwrap();
+        let stats = compute_statistics(&snapshot, &commit_file, &engine).unwrap();
+        assert_eq!(stats.version, 1);
+        assert_eq!(stats.num_files, 2);
+        assert_eq!(stats.size_in_bytes, 150);
+        assert_eq!(stats.num_rows, Some(15));
+    }
+
+    #[test]
+    fn cache_evicts_the_least_recently_used_table() {
+        let root = |name: &str| Url::parse(&format!("file:///tables/{name}/")).unwrap();
+        let at = |version| TableStatistics {
+            version,
+            ..Default::default()
+        };
+        let mut cache = StatisticsCache::new(2);
+        cache.insert(root("a"), at(1));
+        cache.insert(root("b"), at(2));
+        // Touch `a`, so `b` is the least recently used once `c` arrives.
+        assert_eq!(cache.get(&root("a")), Some(at(1)));
+        cache.insert(root("c"), at(3));
+        assert_eq!(cache.get(&root("b")), None);
+        assert_eq!(cache.get(&root("a")), Some(at(1)));
+        assert_eq!(cache.get(&root("c")), Some(at(3)));
+
+        // Updating a cached table does not evict another one.
+        cache.insert(root("a"), at(4));
+        assert_eq!(cache.get(&root("a")), Some(at(4)));
+        assert_eq!(cache.get(&root("c")), Some(at(3)));
+    }
+}
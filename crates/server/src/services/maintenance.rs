@@ -0,0 +1,1434 @@
+//! Server-side maintenance of managed Delta tables.
+//!
+//! Writers report commit metrics after each commit (see
+//! [`DeltaApiHandler::report_metrics`](crate::api::delta::DeltaApiHandler::report_metrics)),
+//! which are persisted in the [`MaintenanceStore`]. [`MaintenanceScheduler`]
+//! periodically sweeps the managed Delta tables, asks a [`MaintenancePlanner`]
+//! which maintenance each one needs, records the planned jobs and runs them:
+//!
+//! - **Compaction** rewrites an unpartitioned table's small data files into
+//!   fewer, larger ones. The files are read with DataFusion, rewritten through
+//!   the kernel's parquet writer and swapped in by a single `dataChange=false`
+//!   commit, which the server ratifies through its own [`CommitCoordinator`] and
+//!   publishes right away.
+//! - **Vacuum** deletes data files under the table root that the current
+//!   snapshot no longer references and that the log removed longer ago than
+//!   the retention period, going by the `deletionTimestamp` of their remove
+//!   tombstones.
+//! - **Checkpoint** writes a checkpoint (V2 or classic, as the table's features
+//!   require) and `_last_checkpoint` through the kernel's checkpoint writer once
+//!   enough commits accumulated since the last one.
+//! - **Log compaction** aggregates the commits since the last checkpoint into a
+//!   `<start>.<end>.compacted.json` file, so readers replay fewer files between
+//!   checkpoints.
+//!
+//! Jobs only run while the scheduler holds the table's backfill lease, so
+//! replicas do not maintain the same table concurrently. Holding the lease, the
+//! scheduler first publishes any ratified commits itself; a job runs only once
+//! every commit is backfilled, so its snapshot — and any checkpoint written
+//! from it — agrees with the commit coordinator.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use datafusion::arrow::array::{Array, AsArray, RecordBatch};
+use datafusion::arrow::compute::{cast, concat_batches};
+use datafusion::arrow::datatypes::{DataType, Int64Type};
+use datafusion::prelude::{ParquetReadOptions, SessionContext};
+use delta_kernel::committer::{CommitMetadata, CommitResponse, Committer, PublishMetadata};
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::engine::default::DefaultEngine;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::table_features::ColumnMappingMode;
+use delta_kernel::transaction::CommitResult;
+use delta_kernel::{
+    DeltaResult, DeltaResultIterator, Engine, Error as DeltaError, FilteredEngineData, Snapshot,
+};
+use futures::TryStreamExt;
+use object_store::path::Path;
+use object_store::{DynObjectStore, ObjectStoreExt};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use unitycatalog_common::ObjectLabel;
+use unitycatalog_common::models::delta::v1::{
+    DeltaMaintenanceJob, DeltaMaintenanceJobKind, DeltaMaintenanceJobStatus,
+};
+use unitycatalog_common::models::delta_commits::v1::CommitInfo;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table};
+use unitycatalog_common::services::commit_coordinator::{CommitCoordinator, CommitError};
+use unitycatalog_common::services::maintenance::{
+    MaintenanceError, MaintenancePlanner, MaintenanceState, MaintenanceStore, ThresholdPlanner,
+    now_millis,
+};
+use url::Url;
+
+use super::backfill::{BackfillLease, publish_commits};
+use super::kernel::ObjectStoreFactory;
+use super::location::StorageLocationUrl;
+use super::managed_delta_contract as contract;
+use crate::store::ResourceStore;
+use crate::{Error, Result};
+
+/// Recorded as `engineInfo` on the commits written by maintenance jobs.
+const ENGINE_INFO: &str = "unitycatalog-server maintenance";
+
+impl From<MaintenanceError> for Error {
+    fn from(err: MaintenanceError) -> Self {
+        match err {
+            MaintenanceError::JobNotFound(_) => Error::NotFound,
+            MaintenanceError::Backend(msg) => Error::Generic(msg),
+        }
+    }
+}
+
+/// Settings for a [`MaintenanceScheduler`].
+#[derive(Debug, Clone)]
+pub struct MaintenanceConfig {
+    /// Time between two sweeps over all managed tables.
+    pub interval: Duration,
+    /// How long a table's lease is held before another scheduler (or backfill
+    /// publisher) may take it over. Must exceed the time one job takes.
+    pub lease_ttl: Duration,
+    /// Lease owner identifying this scheduler; unique per server replica.
+    pub owner: String,
+    /// Commits since the last checkpoint that trigger a new one.
+    pub checkpoint_interval: usize,
+    /// Commits since the last checkpoint or log compaction that trigger a log
+    /// compaction. `None` disables log compaction.
+    pub log_compaction_interval: Option<usize>,
+    /// Files smaller than this many bytes are compacted.
+    pub small_file_bytes: i64,
+    /// Size compacted files are binned up to.
+    pub target_file_bytes: i64,
+    /// Files removed from the table more recently than this are kept by
+    /// vacuum, so readers of recent table versions still find their files.
+    /// Matches the Delta default `delta.deletedFileRetentionDuration`.
+    pub vacuum_retention: Duration,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        Self {
+            interval: Duration::from_secs(300),
+            lease_ttl: Duration::from_secs(600),
+            owner: format!("maintenance-{}-{nanos:x}", std::process::id()),
+            checkpoint_interval: ThresholdPlanner::default().checkpoint_interval,
+            log_compaction_interval: None,
+            small_file_bytes: ThresholdPlanner::default().small_file_bytes,
+            target_file_bytes: 128 * 1024 * 1024,
+            vacuum_retention: Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// What a finished job did.
+#[derive(Debug, Default)]
+struct JobOutcome {
+    message: Option<String>,
+    /// The job had nothing to do, or could not run on the table.
+    skipped: bool,
+    committed_version: Option<i64>,
+    num_files_added: Option<i64>,
+    num_files_removed: Option<i64>,
+    num_bytes_removed: Option<i64>,
+}
+
+impl JobOutcome {
+    fn skipped(message: impl Into<String>) -> Self {
+        Self {
+            message: Some(message.into()),
+            skipped: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Background planner and executor for table maintenance.
+///
+/// Build one with [`ServerHandler::maintenance_scheduler`](super::ServerHandler::maintenance_scheduler).
+pub struct MaintenanceScheduler {
+    store: Arc<dyn ResourceStore>,
+    coordinator: Arc<dyn CommitCoordinator>,
+    maintenance: Arc<dyn MaintenanceStore>,
+    factory: Arc<dyn ObjectStoreFactory>,
+    planner: Arc<dyn MaintenancePlanner>,
+    config: MaintenanceConfig,
+}
+
+impl MaintenanceScheduler {
+    pub(crate) fn new(
+        store: Arc<dyn ResourceStore>,
+        coordinator: Arc<dyn CommitCoordinator>,
+        maintenance: Arc<dyn MaintenanceStore>,
+        factory: Arc<dyn ObjectStoreFactory>,
+        config: MaintenanceConfig,
+    ) -> Self {
+        let planner = Arc::new(ThresholdPlanner {
+            checkpoint_interval: config.checkpoint_interval,
+            log_compaction_interval: config.log_compaction_interval,
+            small_file_bytes: config.small_file_bytes,
+            ..Default::default()
+        });
+        Self {
+            store,
+            coordinator,
+            maintenance,
+            factory,
+            planner,
+            config,
+        }
+    }
+
+    /// Replace the default [`ThresholdPlanner`].
+    pub fn with_planner(mut self, planner: Arc<dyn MaintenancePlanner>) -> Self {
+        self.planner = planner;
+        self
+    }
+
+    /// Spawn [`run`](Self::run) onto the current tokio runtime.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    /// Sweep all managed tables every [`interval`](MaintenanceConfig::interval),
+    /// forever. A failed sweep is logged and retried on the next tick.
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(0) => {}
+                Ok(jobs) => tracing::debug!(jobs, "ran table maintenance jobs"),
+                Err(e) => tracing::warn!(error = %e, "table maintenance sweep failed"),
+            }
+        }
+    }
+
+    /// Plan and run the maintenance of every managed Delta table once.
+    ///
+    /// Returns the number of jobs run. Failures on a single table are logged
+    /// and do not stop the sweep.
+    pub async fn run_once(&self) -> Result<usize> {
+        let tables = self.store.list_all(&ObjectLabel::Table, None).await?;
+        let mut ran = 0;
+        for resource in tables {
+            let table: Table = resource.try_into()?;
+            if !contract::is_catalog_managed(table.table_type)
+                || table.data_source_format != DataSourceFormat::Delta as i32
+            {
+                continue;
+            }
+            match self.maintain_table(&table).await {
+                Ok(count) => ran += count,
+                Err(e) => tracing::warn!(
+                    table = %table.full_name,
+                    error = %e,
+                    "failed to maintain table"
+                ),
+            }
+        }
+        Ok(ran)
+    }
+
+    /// The table lease this scheduler takes before running jobs.
+    fn lease(&self) -> BackfillLease<'_> {
+        BackfillLease {
+            owner: &self.config.owner,
+            ttl: self.config.lease_ttl,
+        }
+    }
+
+    /// Plan and run one table's jobs while holding its lease.
+    async fn maintain_table(&self, table: &Table) -> Result<usize> {
+        let (Some(table_id), Some(location)) = (&table.table_id, &table.storage_location) else {
+            return Ok(0);
+        };
+        let owner = &self.config.owner;
+        if !self
+            .coordinator
+            .acquire_backfill_lease(table_id, owner, self.config.lease_ttl)
+            .await?
+        {
+            return Ok(0);
+        }
+        // Planning reads and writes the job history, so it runs under the lease
+        // too; otherwise two schedulers could both plan the same job.
+        let result = match self.plan_table(table_id).await {
+            Ok(()) => self.run_pending_jobs(table_id, location).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = self
+            .coordinator
+            .release_backfill_lease(table_id, owner)
+            .await
+        {
+            tracing::warn!(table_id, error = %e, "failed to release maintenance lease");
+        }
+        result
+    }
+
+    /// Record the jobs the planner wants for a table, and drop the reports no
+    /// future plan depends on.
+    async fn plan_table(&self, table_id: &str) -> Result<()> {
+        let reports = self.maintenance.list_reports(table_id, None).await?;
+        let jobs = self.maintenance.list_jobs(table_id, None).await?;
+        // The coordinator reports 0 for tables it has no commits for, which
+        // says nothing about the table's actual version.
+        let (_, latest) = self.coordinator.get_commits(table_id, 0, None).await?;
+        let state = MaintenanceState {
+            reports: &reports,
+            jobs: &jobs,
+            latest_version: (latest > 0).then_some(latest),
+        };
+        for planned in self.planner.plan(&state) {
+            tracing::debug!(table_id, kind = ?planned.kind, reason = %planned.reason, "planned maintenance");
+            self.maintenance
+                .create_job(table_id, planned.kind, planned.reason)
+                .await?;
+        }
+        if let Some(before) = state.oldest_relevant_report() {
+            self.maintenance.prune_reports(table_id, before).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the table's pending jobs, oldest first.
+    async fn run_pending_jobs(&self, table_id: &str, location: &str) -> Result<usize> {
+        let mut pending: Vec<_> = self
+            .maintenance
+            .list_jobs(table_id, None)
+            .await?
+            .into_iter()
+            .filter(|j| j.status == DeltaMaintenanceJobStatus::Pending)
+            .collect();
+        pending.reverse();
+
+        let mut ran = 0;
+        for mut job in pending {
+            job.status = DeltaMaintenanceJobStatus::Running;
+            job.started_at = Some(now_millis());
+            self.maintenance.update_job(&job).await?;
+
+            let outcome = self.run_job(&job, location).await;
+            finish_job(&mut job, outcome);
+            self.maintenance.update_job(&job).await?;
+            ran += 1;
+        }
+        Ok(ran)
+    }
+
+    async fn run_job(&self, job: &DeltaMaintenanceJob, location: &str) -> Result<JobOutcome> {
+        // We hold the table's lease, so no backfill publisher races us here.
+        publish_commits(
+            self.coordinator.as_ref(),
+            self.factory.as_ref(),
+            &job.table_id,
+            location,
+            Some(&self.lease()),
+        )
+        .await?;
+        let (commits, latest) = self.coordinator.get_commits(&job.table_id, 0, None).await?;
+        if !commits.is_empty() {
+            return Ok(JobOutcome::skipped(
+                "table has commits that could not be published yet",
+            ));
+        }
+        let executor = JobExecutor::try_new(self, &job.table_id, location, latest).await?;
+        match job.kind {
+            DeltaMaintenanceJobKind::Compaction => executor.compact().await,
+            DeltaMaintenanceJobKind::Vacuum => executor.vacuum().await,
+            DeltaMaintenanceJobKind::Checkpoint => executor.checkpoint().await,
+            DeltaMaintenanceJobKind::LogCompaction => executor.compact_log().await,
+        }
+    }
+}
+
+/// Fold a job's outcome into the job record.
+fn finish_job(job: &mut DeltaMaintenanceJob, outcome: Result<JobOutcome>) {
+    job.finished_at = Some(now_millis());
+    match outcome {
+        Ok(outcome) => {
+            job.status = if outcome.skipped {
+                DeltaMaintenanceJobStatus::Skipped
+            } else {
+                DeltaMaintenanceJobStatus::Succeeded
+            };
+            job.message = outcome.message;
+            job.committed_version = outcome.committed_version;
+            job.num_files_added = outcome.num_files_added;
+            job.num_files_removed = outcome.num_files_removed;
+            job.num_bytes_removed = outcome.num_bytes_removed;
+        }
+        Err(e) => {
+            tracing::warn!(job_id = job.job_id, table_id = %job.table_id, error = %e, "maintenance job failed");
+            job.status = DeltaMaintenanceJobStatus::Failed;
+            job.message = Some(e.to_string());
+        }
+    }
+}
+
+/// A data file referenced by the current snapshot.
+struct DataFile {
+    /// Path relative to the table root, URL-encoded as in the Delta log.
+    path: String,
+    size: i64,
+    has_deletion_vector: bool,
+}
+
+/// Runs one job against one table.
+struct JobExecutor<'a> {
+    scheduler: &'a MaintenanceScheduler,
+    table_id: &'a str,
+    location: &'a str,
+    root: Url,
+    object_store: Arc<DynObjectStore>,
+    engine: DefaultEngine<TokioBackgroundExecutor>,
+    snapshot: Arc<Snapshot>,
+}
+
+impl<'a> JobExecutor<'a> {
+    async fn try_new(
+        scheduler: &'a MaintenanceScheduler,
+        table_id: &'a str,
+        location: &'a str,
+        latest: i64,
+    ) -> Result<Self> {
+        let mut root = StorageLocationUrl::parse(location)?.location().clone();
+        if !root.path().ends_with('/') {
+            root.set_path(&format!("{}/", root.path()));
+        }
+        let object_store = scheduler
+            .factory
+            .create_object_store(&root)
+            .await
+            .map_err(Error::generic)?;
+        let engine = DefaultEngine::builder(object_store.clone()).build();
+        let latest = u64::try_from(latest)
+            .map_err(|_| Error::generic(format!("negative table version {latest}")))?;
+        let snapshot = Snapshot::builder_for(root.as_str())
+            .with_max_catalog_version(latest)
+            .build(&engine)
+            .map_err(Error::generic)?;
+        Ok(Self {
+            scheduler,
+            table_id,
+            location,
+            root,
+            object_store,
+            engine,
+            snapshot,
+        })
+    }
+
+    /// Replay the snapshot's log into its scan-file batches (all rows selected
+    /// are live files) plus the per-file view of the selected rows.
+    fn scan_files(&self) -> Result<Vec<(RecordBatch, Vec<bool>, Vec<Option<DataFile>>)>> {
+        let scan = self
+            .snapshot
+            .clone()
+            .scan_builder()
+            .build()
+            .map_err(Error::generic)?;
+        let mut batches = Vec::new();
+        for metadata in scan.scan_metadata(&self.engine).map_err(Error::generic)? {
+            let (data, selection) = metadata.map_err(Error::generic)?.scan_files.into_parts();
+            let batch = ArrowEngineData::try_from_engine_data(data)
+                .map_err(Error::generic)?
+                .record_batch()
+                .clone();
+            let paths = batch
+                .column_by_name("path")
+                .and_then(|c| c.as_string_opt::<i32>())
+                .ok_or_else(|| Error::generic("scan files are missing `path`"))?;
+            let sizes = batch
+                .column_by_name("size")
+                .and_then(|c| c.as_primitive_opt::<Int64Type>())
+                .ok_or_else(|| Error::generic("scan files are missing `size`"))?;
+            let dvs = batch
+                .column_by_name("deletionVector")
+                .ok_or_else(|| Error::generic("scan files are missing `deletionVector`"))?;
+            // A selection vector shorter than the batch leaves the tail selected.
+            let selection: Vec<bool> = (0..batch.num_rows())
+                .map(|i| selection.get(i).copied().unwrap_or(true))
+                .collect();
+            let files = selection
+                .iter()
+                .enumerate()
+                .map(|(i, selected)| {
+                    selected.then(|| DataFile {
+                        path: paths.value(i).to_string(),
+                        size: sizes.value(i),
+                        has_deletion_vector: dvs.is_valid(i),
+                    })
+                })
+                .collect();
+            batches.push((batch, selection, files));
+        }
+        Ok(batches)
+    }
+
+    /// Rewrite small files into files of up to `target_file_bytes`.
+    async fn compact(&self) -> Result<JobOutcome> {
+        let config = self.snapshot.table_configuration();
+        if !config.metadata().partition_columns().is_empty() {
+            return Ok(JobOutcome::skipped(
+                "compaction of partitioned tables is not supported",
+            ));
+        }
+        if config.column_mapping_mode() != ColumnMappingMode::None {
+            return Ok(JobOutcome::skipped(
+                "compaction of tables with column mapping is not supported",
+            ));
+        }
+
+        let small_file_bytes = self.scheduler.config.small_file_bytes;
+        let target_file_bytes = self.scheduler.config.target_file_bytes;
+        let scan_files = self.scan_files()?;
+
+        // Bin the small files (without deletion vectors) in log order.
+        let mut bins: Vec<Vec<&str>> = vec![Vec::new()];
+        let mut bin_bytes = 0;
+        let mut compacted: HashSet<&str> = HashSet::new();
+        for file in scan_files
+            .iter()
+            .flat_map(|(_, _, files)| files.iter().flatten())
+        {
+            if file.size >= small_file_bytes || file.has_deletion_vector {
+                continue;
+            }
+            if bin_bytes + file.size > target_file_bytes && !bins.last().unwrap().is_empty() {
+                bins.push(Vec::new());
+                bin_bytes = 0;
+            }
+            bins.last_mut().unwrap().push(&file.path);
+            bin_bytes += file.size;
+        }
+        bins.retain(|bin| bin.len() > 1);
+        if bins.is_empty() {
+            return Ok(JobOutcome::skipped("no small files to compact"));
+        }
+
+        let committer = CoordinatorCommitter::new(
+            self.scheduler.coordinator.clone(),
+            self.table_id.to_string(),
+        );
+        let mut txn = self
+            .snapshot
+            .clone()
+            .transaction(Box::new(committer), &self.engine)
+            .map_err(Error::generic)?
+            .with_engine_info(ENGINE_INFO)
+            .with_data_change(false);
+        let write_context = txn.unpartitioned_write_context().map_err(Error::generic)?;
+
+        let ctx = SessionContext::new();
+        ctx.register_object_store(&self.root, self.object_store.clone());
+        let mut added = 0;
+        for bin in &bins {
+            let urls = bin
+                .iter()
+                .map(|path| self.root.join(path).map(String::from))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(Error::generic)?;
+            let mut rows = ctx
+                .read_parquet(urls, ParquetReadOptions::default())
+                .await
+                .map_err(Error::generic)?
+                .execute_stream()
+                .await
+                .map_err(Error::generic)?;
+            // Rows are streamed from the bin and cut into files of about
+            // `target_file_bytes` in memory, so only one output file's rows are
+            // buffered at a time.
+            let schema = rows.schema();
+            let mut buffered = Vec::new();
+            let mut buffered_bytes = 0;
+            loop {
+                let batch = rows.try_next().await.map_err(Error::generic)?;
+                let full = batch.as_ref().is_none_or(|batch| {
+                    buffered_bytes + batch.get_array_memory_size() as i64 > target_file_bytes
+                });
+                if full && !buffered.is_empty() {
+                    let data = concat_batches(&schema, &std::mem::take(&mut buffered))
+                        .map_err(Error::generic)?;
+                    buffered_bytes = 0;
+                    let add_metadata = self
+                        .engine
+                        .write_parquet(&ArrowEngineData::new(data), &write_context)
+                        .await
+                        .map_err(Error::generic)?;
+                    txn.add_files(add_metadata);
+                    added += 1;
+                }
+                match batch {
+                    Some(batch) if batch.num_rows() > 0 => {
+                        buffered_bytes += batch.get_array_memory_size() as i64;
+                        buffered.push(batch);
+                    }
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+            compacted.extend(bin.iter().copied());
+        }
+
+        let mut removed = 0;
+        for (batch, selection, files) in &scan_files {
+            let remove: Vec<bool> = selection
+                .iter()
+                .zip(files)
+                .map(|(selected, file)| {
+                    *selected
+                        && file
+                            .as_ref()
+                            .is_some_and(|f| compacted.contains(f.path.as_str()))
+                })
+                .collect();
+            if !remove.contains(&true) {
+                continue;
+            }
+            removed += remove.iter().filter(|r| **r).count() as i64;
+            let data =
+                FilteredEngineData::try_new(Box::new(ArrowEngineData::new(batch.clone())), remove)
+                    .map_err(Error::generic)?;
+            txn.remove_files(data);
+        }
+
+        // The committer blocks on the coordinator, so the commit runs on the
+        // blocking pool rather than on a runtime worker thread.
+        let engine = DefaultEngine::builder(self.object_store.clone()).build();
+        let committed = tokio::task::spawn_blocking(move || txn.commit(&engine))
+            .await
+            .map_err(Error::generic)?;
+        let version = match committed.map_err(Error::generic)? {
+            CommitResult::CommittedTransaction(c) => c.commit_version(),
+            _ => {
+                return Err(Error::generic(
+                    "compaction commit conflicted with a concurrent writer",
+                ));
+            }
+        };
+        // Publish right away: the table should not wait for the backfill
+        // publisher before other jobs can run on it again.
+        publish_commits(
+            self.scheduler.coordinator.as_ref(),
+            self.scheduler.factory.as_ref(),
+            self.table_id,
+            self.location,
+            Some(&self.scheduler.lease()),
+        )
+        .await?;
+
+        Ok(JobOutcome {
+            message: Some(format!("compacted {removed} files into {added}")),
+            committed_version: Some(version as i64),
+            num_files_added: Some(added),
+            num_files_removed: Some(removed),
+            ..Default::default()
+        })
+    }
+
+    /// The version of the table's last checkpoint, from `_last_checkpoint`.
+    async fn last_checkpoint_version(&self) -> Result<Option<i64>> {
+        let path = Path::from_url_path(self.root.path())
+            .map_err(object_store::Error::from)?
+            .child("_delta_log")
+            .child("_last_checkpoint");
+        let bytes = match self.object_store.get(&path).await {
+            Ok(result) => result.bytes().await?,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let hint: serde_json::Value = serde_json::from_slice(&bytes).map_err(Error::generic)?;
+        Ok(hint.get("version").and_then(serde_json::Value::as_i64))
+    }
+
+    /// Checkpoint the snapshot, unless a recent checkpoint already exists.
+    async fn checkpoint(&self) -> Result<JobOutcome> {
+        let version = self.snapshot.version() as i64;
+        // Clients may checkpoint catalog-managed tables themselves; recording
+        // their checkpoint keeps the planner from asking again right away.
+        if let Some(existing) = self.last_checkpoint_version().await?
+            && version - existing < self.scheduler.config.checkpoint_interval as i64
+        {
+            return Ok(JobOutcome {
+                message: Some(format!(
+                    "table has a recent checkpoint at version {existing}"
+                )),
+                committed_version: Some(existing),
+                ..Default::default()
+            });
+        }
+        self.snapshot
+            .clone()
+            .checkpoint(&self.engine)
+            .map_err(Error::generic)?;
+        Ok(JobOutcome {
+            message: Some(format!("wrote checkpoint at version {version}")),
+            committed_version: Some(version),
+            ..Default::default()
+        })
+    }
+
+    /// Write a log-compaction file for the commits after the last checkpoint.
+    async fn compact_log(&self) -> Result<JobOutcome> {
+        let end = self.snapshot.version();
+        let start = match self.last_checkpoint_version().await? {
+            Some(checkpoint) => checkpoint as u64 + 1,
+            None => 0,
+        };
+        if end <= start {
+            return Ok(JobOutcome::skipped(
+                "not enough commits since the last checkpoint",
+            ));
+        }
+        let mut writer = self
+            .snapshot
+            .clone()
+            .log_compaction_writer(start, end)
+            .map_err(Error::generic)?;
+        let path = writer.compaction_path().clone();
+        let data = writer
+            .compaction_data(&self.engine)
+            .map_err(Error::generic)?;
+        match self
+            .engine
+            .json_handler()
+            .write_json_file(&path, Box::new(data), false)
+        {
+            Ok(()) | Err(DeltaError::FileAlreadyExists(_)) => {}
+            Err(e) => return Err(Error::generic(e)),
+        }
+        Ok(JobOutcome {
+            message: Some(format!("compacted commits {start} to {end}")),
+            committed_version: Some(end as i64),
+            ..Default::default()
+        })
+    }
+
+    /// The latest `deletionTimestamp` of each file the log removed.
+    ///
+    /// Read from every commit, log compaction, checkpoint and checkpoint
+    /// sidecar still in `_delta_log`; a file removed twice keeps its later
+    /// tombstone.
+    async fn tombstones(&self) -> Result<HashMap<Path, i64>> {
+        let log = Path::from_url_path(self.root.path())
+            .map_err(object_store::Error::from)?
+            .child("_delta_log");
+        let mut json_files = Vec::new();
+        let mut parquet_files = Vec::new();
+        let mut listing = self.object_store.list(Some(&log));
+        while let Some(meta) = listing.try_next().await? {
+            let Some(relative) = meta.location.prefix_match(&log) else {
+                continue;
+            };
+            // Staged commits are not part of the log until they are published.
+            let parts: Vec<_> = relative.collect();
+            let in_log = match parts.as_slice() {
+                [_] => true,
+                [dir, _] => dir.as_ref() == "_sidecars",
+                _ => false,
+            };
+            if !in_log {
+                continue;
+            }
+            if meta.location.as_ref().ends_with(".json") {
+                json_files.push(meta.location);
+            } else if meta.location.as_ref().ends_with(".parquet") {
+                parquet_files.push(meta.location);
+            }
+        }
+
+        let mut tombstones = HashMap::new();
+        let mut record = |path: &str, deleted_at: i64| -> Result<()> {
+            let url = self.root.join(path).map_err(Error::generic)?;
+            let path = Path::from_url_path(url.path()).map_err(object_store::Error::from)?;
+            let latest = tombstones.entry(path).or_insert(deleted_at);
+            *latest = (*latest).max(deleted_at);
+            Ok(())
+        };
+
+        for location in json_files {
+            let bytes = self.object_store.get(&location).await?.bytes().await?;
+            for line in bytes.split(|b| *b == b'\n') {
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                let action: serde_json::Value =
+                    serde_json::from_slice(line).map_err(Error::generic)?;
+                let Some(remove) = action.get("remove") else {
+                    continue;
+                };
+                if let (Some(path), Some(deleted_at)) = (
+                    remove.get("path").and_then(serde_json::Value::as_str),
+                    remove
+                        .get("deletionTimestamp")
+                        .and_then(serde_json::Value::as_i64),
+                ) {
+                    record(path, deleted_at)?;
+                }
+            }
+        }
+
+        let ctx = SessionContext::new();
+        ctx.register_object_store(&self.root, self.object_store.clone());
+        for location in parquet_files {
+            let mut url = self.root.clone();
+            url.set_path(&format!("/{location}"));
+            let df = ctx
+                .read_parquet(url.as_str(), ParquetReadOptions::default())
+                .await
+                .map_err(Error::generic)?;
+            // Checkpoints that only point at sidecars carry no file actions.
+            if df.schema().field_with_unqualified_name("remove").is_err() {
+                continue;
+            }
+            for batch in df.collect().await.map_err(Error::generic)? {
+                let Some(removes) = batch
+                    .column_by_name("remove")
+                    .and_then(|c| c.as_struct_opt())
+                else {
+                    continue;
+                };
+                let (Some(paths), Some(deleted_at)) = (
+                    removes.column_by_name("path"),
+                    removes.column_by_name("deletionTimestamp"),
+                ) else {
+                    continue;
+                };
+                let paths = cast(paths, &DataType::Utf8).map_err(Error::generic)?;
+                let paths = paths.as_string::<i32>();
+                let deleted_at = cast(deleted_at, &DataType::Int64).map_err(Error::generic)?;
+                let deleted_at = deleted_at.as_primitive::<Int64Type>();
+                for i in 0..removes.len() {
+                    if removes.is_valid(i) && paths.is_valid(i) && deleted_at.is_valid(i) {
+                        record(paths.value(i), deleted_at.value(i))?;
+                    }
+                }
+            }
+        }
+        Ok(tombstones)
+    }
+
+    /// Delete data files the snapshot no longer references and whose remove
+    /// tombstones are older than the retention period.
+    ///
+    /// Files the log never removed, such as leftovers of failed writes, are
+    /// kept: without a tombstone there is no telling whether a concurrent
+    /// writer is about to commit them.
+    async fn vacuum(&self) -> Result<JobOutcome> {
+        let referenced: HashSet<Path> = self
+            .scan_files()?
+            .into_iter()
+            .flat_map(|(_, _, files)| files.into_iter().flatten())
+            .map(|file| {
+                let url = self.root.join(&file.path).map_err(Error::generic)?;
+                Ok(Path::from_url_path(url.path()).map_err(object_store::Error::from)?)
+            })
+            .collect::<Result<_>>()?;
+
+        let tombstones = self.tombstones().await?;
+
+        let root = Path::from_url_path(self.root.path()).map_err(object_store::Error::from)?;
+        let retention = i64::try_from(self.scheduler.config.vacuum_retention.as_millis())
+            .map_err(Error::generic)?;
+        let cutoff = now_millis().saturating_sub(retention);
+
+        let mut files = self.object_store.list(Some(&root));
+        let mut removed = 0;
+        let mut bytes = 0;
+        while let Some(meta) = files.try_next().await? {
+            let Some(relative) = meta.location.prefix_match(&root) else {
+                continue;
+            };
+            // Skip the log and other hidden directories and files, like Delta's
+            // VACUUM does, and anything that is not a data file.
+            let hidden = relative.into_iter().any(|part| {
+                let part = part.as_ref();
+                part.starts_with('_') || part.starts_with('.')
+            });
+            if hidden
+                || !meta.location.as_ref().ends_with(".parquet")
+                || referenced.contains(&meta.location)
+                || !tombstones
+                    .get(&meta.location)
+                    .is_some_and(|deleted_at| *deleted_at <= cutoff)
+            {
+                continue;
+            }
+            self.object_store.delete(&meta.location).await?;
+            removed += 1;
+            bytes += meta.size as i64;
+        }
+
+        if removed == 0 {
+            return Ok(JobOutcome::skipped("no unreferenced files to delete"));
+        }
+        Ok(JobOutcome {
+            message: Some(format!("deleted {removed} unreferenced files")),
+            num_files_removed: Some(removed),
+            num_bytes_removed: Some(bytes),
+            ..Default::default()
+        })
+    }
+}
+
+/// A [`Committer`] that ratifies commits through the server's own
+/// [`CommitCoordinator`], for commits written by the server itself.
+///
+/// Like the client-side Unity Catalog committer it writes a staged commit and
+/// then ratifies it; the coordinator call is bridged from the synchronous
+/// [`Committer::commit`] with [`Handle::block_on`](tokio::runtime::Handle::block_on),
+/// so transactions using it must be committed from
+/// [`spawn_blocking`](tokio::task::spawn_blocking), never on a runtime worker.
+struct CoordinatorCommitter {
+    coordinator: Arc<dyn CommitCoordinator>,
+    table_id: String,
+    /// The staged commit of the last attempt, for diagnostics.
+    staged: Mutex<Option<Url>>,
+}
+
+impl CoordinatorCommitter {
+    fn new(coordinator: Arc<dyn CommitCoordinator>, table_id: String) -> Self {
+        Self {
+            coordinator,
+            table_id,
+            staged: Mutex::new(None),
+        }
+    }
+}
+
+impl Committer for CoordinatorCommitter {
+    fn commit(
+        &self,
+        engine: &dyn Engine,
+        actions: DeltaResultIterator<'_, FilteredEngineData>,
+        commit_metadata: CommitMetadata,
+    ) -> DeltaResult<CommitResponse> {
+        if commit_metadata.version() == 0 {
+            return Err(DeltaError::generic(
+                "maintenance commits cannot create a table",
+            ));
+        }
+        let staged = commit_metadata.staged_commit_path()?;
+        engine
+            .json_handler()
+            .write_json_file(&staged, Box::new(actions), false)?;
+        let file_meta = engine.storage_handler().head(&staged)?;
+        *self.staged.lock().expect("committer mutex poisoned") = Some(staged.clone());
+
+        let file_name = staged
+            .path_segments()
+            .and_then(|mut s| s.next_back())
+            .ok_or_else(|| DeltaError::generic("staged commit path had no file name"))?
+            .to_string();
+        let version = i64::try_from(commit_metadata.version())
+            .map_err(|_| DeltaError::generic("commit version does not fit into i64"))?;
+        let file_size = i64::try_from(file_meta.size)
+            .map_err(|_| DeltaError::generic("staged commit size does not fit into i64"))?;
+        let info = CommitInfo {
+            version,
+            timestamp: commit_metadata.in_commit_timestamp(),
+            file_name,
+            file_size,
+            file_modification_timestamp: file_meta.last_modified,
+        };
+
+        let handle = tokio::runtime::Handle::try_current()
+            .map_err(|_| DeltaError::generic("committer must be used within a tokio runtime"))?;
+        let ratified = handle.block_on(self.coordinator.commit(&self.table_id, Some(info), None));
+        match ratified {
+            Ok(()) => Ok(CommitResponse::Committed { file_meta }),
+            Err(CommitError::VersionConflict(_)) => Ok(CommitResponse::Conflict {
+                version: commit_metadata.version(),
+            }),
+            Err(e) => Err(DeltaError::generic(format!(
+                "failed to ratify maintenance commit: {e}"
+            ))),
+        }
+    }
+
+    fn is_catalog_committer(&self) -> bool {
+        true
+    }
+
+    fn publish(&self, engine: &dyn Engine, publish_metadata: PublishMetadata) -> DeltaResult<()> {
+        for catalog_commit in publish_metadata.commits_to_publish() {
+            match engine.storage_handler().copy_atomic(
+                catalog_commit.location(),
+                catalog_commit.published_location(),
+            ) {
+                Ok(()) | Err(DeltaError::FileAlreadyExists(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "memory", not(windows)))]
+mod tests {
+    use datafusion::arrow::array::Int64Array;
+    use datafusion::arrow::datatypes::{Field, Schema};
+    use datafusion::parquet::arrow::ArrowWriter;
+    use serde_json::json;
+    use unitycatalog_common::models::ResourceRef;
+    use unitycatalog_common::models::delta::v1::DeltaCommitReport;
+    use unitycatalog_common::models::tables::v1::TableType;
+    use unitycatalog_common::services::commit_coordinator::ProvidesCommitCoordinator;
+    use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+    use unitycatalog_common::services::maintenance::{PlannedJob, ProvidesMaintenanceStore};
+
+    use super::*;
+    use crate::api::RequestContext;
+    use crate::memory::InMemoryResourceStore;
+    use crate::policy::{ConstantPolicy, Policy};
+    use crate::services::{LocalStoragePolicy, ServerHandler};
+
+    async fn handler_with_table(root: &std::path::Path) -> (ServerHandler<RequestContext>, String) {
+        let encryptor =
+            EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+        let store = Arc::new(InMemoryResourceStore::new(encryptor));
+        let location = url::Url::from_directory_path(root.join("t")).unwrap();
+        let table = Table {
+            name: "t".to_string(),
+            catalog_name: "cat".to_string(),
+            schema_name: "sch".to_string(),
+            table_type: TableType::Managed as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            storage_location: Some(location.to_string()),
+            ..Default::default()
+        };
+        let (_, table_ref) = store.create(table.into()).await.unwrap();
+        let ResourceRef::Uuid(table_id) = table_ref else {
+            panic!("expected uuid");
+        };
+        let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
+        let handler = ServerHandler::try_new_tokio(policy, store.clone(), store)
+            .unwrap()
+            .with_local_storage_policy(LocalStoragePolicy::new([root]).unwrap());
+        (handler, table_id.to_string())
+    }
+
+    /// Plans a single job of the given kind.
+    struct Once(DeltaMaintenanceJobKind);
+
+    impl MaintenancePlanner for Once {
+        fn plan(&self, state: &MaintenanceState<'_>) -> Vec<PlannedJob> {
+            if state.jobs.iter().any(|job| job.kind == self.0) {
+                Vec::new()
+            } else {
+                vec![PlannedJob {
+                    kind: self.0,
+                    reason: "test".to_string(),
+                }]
+            }
+        }
+    }
+
+    const CREATED_AT: i64 = 1_000;
+
+    /// Write `_delta_log/00000000000000000000.json` of a catalog-managed table
+    /// with a single nullable `id` column.
+    fn create_delta_table(root: &std::path::Path, table_id: &str) {
+        let mut configuration: serde_json::Map<String, serde_json::Value> =
+            contract::REQUIRED_FIXED_PROPERTIES
+                .iter()
+                .map(|(k, v)| (k.to_string(), json!(v)))
+                .collect();
+        configuration.insert(contract::PROP_UC_TABLE_ID.into(), json!(table_id));
+        let schema = json!({
+            "type": "struct",
+            "fields": [{"name": "id", "type": "long", "nullable": true, "metadata": {}}],
+        });
+        let actions = [
+            json!({"commitInfo": {
+                "timestamp": CREATED_AT,
+                "inCommitTimestamp": CREATED_AT,
+                "operation": "CREATE TABLE",
+                "operationParameters": {},
+            }}),
+            json!({"protocol": {
+                "minReaderVersion": contract::REQUIRED_MIN_READER_VERSION,
+                "minWriterVersion": contract::REQUIRED_MIN_WRITER_VERSION,
+                "readerFeatures": contract::REQUIRED_READER_FEATURES,
+                "writerFeatures": contract::REQUIRED_WRITER_FEATURES,
+            }}),
+            json!({"metaData": {
+                "id": table_id,
+                "format": {"provider": "parquet", "options": {}},
+                "schemaString": schema.to_string(),
+                "partitionColumns": [],
+                "configuration": configuration,
+                "createdTime": CREATED_AT,
+            }}),
+        ];
+        let log = root.join("t/_delta_log");
+        std::fs::create_dir_all(&log).unwrap();
+        std::fs::write(
+            log.join(format!("{:020}.json", 0)),
+            actions.map(|a| a.to_string()).join("\n"),
+        )
+        .unwrap();
+    }
+
+    /// Write a parquet data file holding `ids` and return its `add` action.
+    fn write_data_file(root: &std::path::Path, name: &str, ids: &[i64]) -> serde_json::Value {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(ids.to_vec()))],
+        )
+        .unwrap();
+        let path = root.join("t").join(name);
+        let mut writer =
+            ArrowWriter::try_new(std::fs::File::create(&path).unwrap(), schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        json!({"add": {
+            "path": name,
+            "partitionValues": {},
+            "size": std::fs::metadata(&path).unwrap().len(),
+            "modificationTime": CREATED_AT,
+            "dataChange": true,
+        }})
+    }
+
+    /// Stage and ratify commit `version` with the given actions.
+    async fn commit_actions(
+        handler: &ServerHandler<RequestContext>,
+        root: &std::path::Path,
+        table_id: &str,
+        version: i64,
+        actions: Vec<serde_json::Value>,
+    ) {
+        let timestamp = CREATED_AT + version;
+        let commit_info = json!({"commitInfo": {
+            "timestamp": timestamp,
+            "inCommitTimestamp": timestamp,
+            "operation": "WRITE",
+            "operationParameters": {"mode": "Append"},
+        }});
+        let body = std::iter::once(commit_info)
+            .chain(actions)
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let staged = root.join("t/_delta_log/_staged_commits");
+        std::fs::create_dir_all(&staged).unwrap();
+        let file_name = format!("{version:020}.{table_id}.json");
+        std::fs::write(staged.join(&file_name), &body).unwrap();
+        handler
+            .commit_coordinator()
+            .commit(
+                table_id,
+                Some(CommitInfo {
+                    version,
+                    timestamp,
+                    file_name,
+                    file_size: body.len() as i64,
+                    file_modification_timestamp: timestamp,
+                }),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// A table with one single-row data file per commit `1..=files`.
+    async fn table_with_files(
+        root: &std::path::Path,
+        files: i64,
+    ) -> (ServerHandler<RequestContext>, String) {
+        let (handler, table_id) = handler_with_table(root).await;
+        create_delta_table(root, &table_id);
+        for version in 1..=files {
+            let add = write_data_file(root, &format!("part-{version}.parquet"), &[version]);
+            commit_actions(&handler, root, &table_id, version, vec![add]).await;
+        }
+        (handler, table_id)
+    }
+
+    /// Plan and run a single job of `kind`, returning it once finished.
+    async fn run_job(
+        handler: &ServerHandler<RequestContext>,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        config: MaintenanceConfig,
+    ) -> DeltaMaintenanceJob {
+        let scheduler = handler
+            .maintenance_scheduler(config)
+            .with_planner(Arc::new(Once(kind)));
+        assert_eq!(scheduler.run_once().await.unwrap(), 1);
+        handler
+            .maintenance_store()
+            .list_jobs(table_id, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|job| job.kind == kind)
+            .unwrap()
+    }
+
+    /// Sorted paths of the files live at `version`, as replayed by the kernel.
+    async fn live_files(
+        handler: &ServerHandler<RequestContext>,
+        root: &std::path::Path,
+        table_id: &str,
+        version: i64,
+    ) -> Vec<String> {
+        let scheduler = handler.maintenance_scheduler(MaintenanceConfig::default());
+        let location = Url::from_directory_path(root.join("t"))
+            .unwrap()
+            .to_string();
+        let executor = JobExecutor::try_new(&scheduler, table_id, &location, version)
+            .await
+            .unwrap();
+        assert_eq!(executor.snapshot.version() as i64, version);
+        let mut paths: Vec<_> = executor
+            .scan_files()
+            .unwrap()
+            .into_iter()
+            .flat_map(|(_, _, files)| files.into_iter().flatten())
+            .map(|file| file.path)
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Sorted file names directly under the table root.
+    fn data_files(root: &std::path::Path) -> Vec<String> {
+        let mut names: Vec<_> = std::fs::read_dir(root.join("t"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.ends_with(".parquet"))
+            .collect();
+        names.sort();
+        names
+    }
+
+    // A current-thread runtime: the compaction commit must not block the
+    // runtime it ratifies on.
+    #[tokio::test]
+    async fn compacts_small_files_and_vacuums_their_tombstones() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = table_with_files(&root, 3).await;
+        let config = MaintenanceConfig {
+            vacuum_retention: Duration::ZERO,
+            ..Default::default()
+        };
+
+        let job = run_job(
+            &handler,
+            &table_id,
+            DeltaMaintenanceJobKind::Compaction,
+            config.clone(),
+        )
+        .await;
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Succeeded, "{job:?}");
+        assert_eq!(job.num_files_added, Some(1));
+        assert_eq!(job.num_files_removed, Some(3));
+        assert_eq!(data_files(&root).len(), 4);
+
+        // A file the log never mentions has no tombstone, however old it is.
+        write_data_file(&root, "orphan.parquet", &[42]);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let job = run_job(&handler, &table_id, DeltaMaintenanceJobKind::Vacuum, config).await;
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Succeeded, "{job:?}");
+        assert_eq!(job.num_files_removed, Some(3));
+        let remaining = data_files(&root);
+        assert_eq!(remaining.len(), 2, "{remaining:?}");
+        assert!(remaining.contains(&"orphan.parquet".to_string()));
+        assert!(!remaining.iter().any(|name| name.starts_with("part-")));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn vacuum_keeps_files_removed_within_the_retention_period() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = table_with_files(&root, 1).await;
+        commit_actions(
+            &handler,
+            &root,
+            &table_id,
+            2,
+            vec![json!({"remove": {
+                "path": "part-1.parquet",
+                "deletionTimestamp": now_millis(),
+                "dataChange": true,
+            }})],
+        )
+        .await;
+
+        let job = run_job(
+            &handler,
+            &table_id,
+            DeltaMaintenanceJobKind::Vacuum,
+            MaintenanceConfig::default(),
+        )
+        .await;
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Skipped, "{job:?}");
+        assert_eq!(data_files(&root), vec!["part-1.parquet".to_string()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn checkpoints_the_published_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = table_with_files(&root, 2).await;
+
+        let job = run_job(
+            &handler,
+            &table_id,
+            DeltaMaintenanceJobKind::Checkpoint,
+            MaintenanceConfig::default(),
+        )
+        .await;
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Succeeded, "{job:?}");
+        assert_eq!(job.committed_version, Some(2));
+        let hint: serde_json::Value = serde_json::from_slice(
+            &std::fs::read(root.join("t/_delta_log/_last_checkpoint")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(hint["version"], 2);
+
+        // Without the commits it covers, the kernel can only find the first
+        // data file through the checkpoint.
+        for version in 0..2 {
+            std::fs::remove_file(root.join(format!("t/_delta_log/{version:020}.json"))).unwrap();
+        }
+        assert_eq!(
+            live_files(&handler, &root, &table_id, 2).await,
+            ["part-1.parquet", "part-2.parquet"]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn compacts_the_log_since_the_last_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = table_with_files(&root, 3).await;
+
+        let job = run_job(
+            &handler,
+            &table_id,
+            DeltaMaintenanceJobKind::LogCompaction,
+            MaintenanceConfig::default(),
+        )
+        .await;
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Succeeded, "{job:?}");
+        assert_eq!(job.committed_version, Some(3));
+
+        let compacted = std::fs::read_to_string(
+            root.join(format!("t/_delta_log/{:020}.{:020}.compacted.json", 0, 3)),
+        )
+        .unwrap();
+        let mut added: Vec<String> = compacted
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter_map(|action| Some(action.get("add")?.get("path")?.as_str()?.to_string()))
+            .collect();
+        added.sort();
+        assert_eq!(
+            added,
+            ["part-1.parquet", "part-2.parquet", "part-3.parquet"]
+        );
+        // The kernel still replays the table with the compaction in the log.
+        assert_eq!(live_files(&handler, &root, &table_id, 3).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn plans_jobs_from_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        let maintenance = handler.maintenance_store();
+        for _ in 0..ThresholdPlanner::default().checkpoint_interval {
+            maintenance
+                .record_report(&table_id, DeltaCommitReport::default())
+                .await
+                .unwrap();
+        }
+
+        let scheduler = handler.maintenance_scheduler(MaintenanceConfig::default());
+        scheduler.plan_table(&table_id).await.unwrap();
+        let jobs = maintenance.list_jobs(&table_id, None).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].kind, DeltaMaintenanceJobKind::Checkpoint);
+        assert_eq!(jobs[0].status, DeltaMaintenanceJobStatus::Pending);
+
+        // The open job keeps the planner from scheduling another one.
+        scheduler.plan_table(&table_id).await.unwrap();
+        assert_eq!(
+            maintenance.list_jobs(&table_id, None).await.unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_jobs_while_commits_cannot_be_published() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        // Ratified, but the staged commit file is missing, so it cannot be
+        // backfilled before the job runs.
+        handler
+            .commit_coordinator()
+            .commit(
+                &table_id,
+                Some(CommitInfo {
+                    version: 1,
+                    timestamp: 1,
+                    file_name: format!("{:020}.{table_id}.json", 1),
+                    file_size: 16,
+                    file_modification_timestamp: 1,
+                }),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let scheduler = handler
+            .maintenance_scheduler(MaintenanceConfig::default())
+            .with_planner(Arc::new(Once(DeltaMaintenanceJobKind::Vacuum)));
+        assert_eq!(scheduler.run_once().await.unwrap(), 1);
+        let jobs = handler
+            .maintenance_store()
+            .list_jobs(&table_id, None)
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].status, DeltaMaintenanceJobStatus::Skipped);
+        assert!(jobs[0].started_at.is_some() && jobs[0].finished_at.is_some());
+    }
+
+    #[test]
+    fn failed_jobs_record_the_error() {
+        let mut job = DeltaMaintenanceJob {
+            job_id: 1,
+            table_id: "t".into(),
+            kind: DeltaMaintenanceJobKind::Vacuum,
+            status: DeltaMaintenanceJobStatus::Running,
+            reason: String::new(),
+            message: None,
+            committed_version: None,
+            num_files_added: None,
+            num_files_removed: None,
+            num_bytes_removed: None,
+            created_at: 0,
+            started_at: Some(0),
+            finished_at: None,
+        };
+        finish_job(&mut job, Err(Error::generic("boom")));
+        assert_eq!(job.status, DeltaMaintenanceJobStatus::Failed);
+        assert!(job.message.as_deref().unwrap().contains("boom"));
+    }
+}
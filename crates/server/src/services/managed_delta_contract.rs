@@ -9,11 +9,13 @@
 
 use std::collections::BTreeMap;
 
-use unitycatalog_common::models::tables::v1::{Column, ColumnTypeName};
+use unitycatalog_common::models::tables::v1::{Column, ColumnTypeName, TableType};
 
+use super::location::StorageLocationUrl;
 use crate::rest::routers::delta::models::{
     DeltaArrayType, DeltaCreateTableRequest, DeltaDataType, DeltaDecimalType,
-    DeltaDomainMetadataUpdates, DeltaMapType, DeltaProtocol, DeltaStructField, DeltaStructType,
+    DeltaDomainMetadataUpdates, DeltaIcebergMetadata, DeltaMapType, DeltaProtocol,
+    DeltaStructField, DeltaStructType, DeltaUniformMetadata,
 };
 use crate::{Error, Result};
 
@@ -73,6 +75,14 @@ const PROP_MIN_READER_VERSION: &str = "delta.minReaderVersion";
 const PROP_MIN_WRITER_VERSION: &str = "delta.minWriterVersion";
 const PROP_CLUSTERING_COLUMNS: &str = "delta.clusteringColumns";
 const PROP_ROW_TRACKING_HIGH_WATER_MARK: &str = "delta.rowTracking.rowIdHighWaterMark";
+pub const PROP_UNIVERSAL_FORMAT_ENABLED_FORMATS: &str = "delta.universalFormat.enabledFormats";
+pub const PROP_ICEBERG_METADATA_LOCATION: &str = "io.unitycatalog.uniform.iceberg.metadataLocation";
+const PROP_ICEBERG_CONVERTED_DELTA_VERSION: &str =
+    "io.unitycatalog.uniform.iceberg.convertedDeltaVersion";
+const PROP_ICEBERG_CONVERTED_DELTA_TIMESTAMP: &str =
+    "io.unitycatalog.uniform.iceberg.convertedDeltaTimestamp";
+const PROP_ICEBERG_BASE_CONVERTED_DELTA_VERSION: &str =
+    "io.unitycatalog.uniform.iceberg.baseConvertedDeltaVersion";
 const FEATURE_PREFIX: &str = "delta.feature.";
 const FEATURE_SUPPORTED: &str = "supported";
 
@@ -129,6 +139,13 @@ pub fn required_properties(table_id: &str) -> BTreeMap<String, Option<String>> {
     props
 }
 
+/// Whether a table of `table_type` is catalog-managed: its Delta log is committed
+/// through the commit coordinator. A materialized view is stored exactly like a
+/// MANAGED table, so it takes commits, backfill and maintenance the same way.
+pub fn is_catalog_managed(table_type: i32) -> bool {
+    table_type == TableType::Managed as i32 || table_type == TableType::MaterializedView as i32
+}
+
 // ===================================================================
 // Contract validation (MANAGED tables only)
 // ===================================================================
@@ -290,6 +307,9 @@ pub fn build_stored_properties(req: &DeltaCreateTableRequest) -> BTreeMap<String
     if let Some(dm) = req.domain_metadata.as_ref() {
         derive_from_domain_metadata(&mut merged, dm);
     }
+    if let Some(uniform) = req.uniform.as_ref() {
+        derive_from_uniform(&mut merged, uniform);
+    }
     merged.insert(
         PROP_LAST_COMMIT_TIMESTAMP.to_string(),
         req.last_commit_timestamp_ms.to_string(),
@@ -341,6 +361,115 @@ pub fn derive_from_domain_metadata(
     }
 }
 
+// ===================================================================
+// UniForm projection
+// ===================================================================
+
+/// Overlay the Iceberg conversion state reported by a UniForm writer. Only the
+/// fields the writer sent are replaced, so a partial report keeps the rest.
+pub fn derive_from_uniform(props: &mut BTreeMap<String, String>, uniform: &DeltaUniformMetadata) {
+    let iceberg = &uniform.iceberg;
+    let fields = [
+        (
+            PROP_ICEBERG_METADATA_LOCATION,
+            iceberg.metadata_location.clone(),
+        ),
+        (
+            PROP_ICEBERG_CONVERTED_DELTA_VERSION,
+            iceberg.converted_delta_version.map(|v| v.to_string()),
+        ),
+        (
+            PROP_ICEBERG_CONVERTED_DELTA_TIMESTAMP,
+            iceberg.converted_delta_timestamp.map(|v| v.to_string()),
+        ),
+        (
+            PROP_ICEBERG_BASE_CONVERTED_DELTA_VERSION,
+            iceberg.base_converted_delta_version.map(|v| v.to_string()),
+        ),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            props.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// The UniForm metadata stored on a table, if a writer has reported an Iceberg
+/// conversion for it.
+pub fn uniform_from_properties(props: &BTreeMap<String, String>) -> Option<DeltaUniformMetadata> {
+    let metadata_location = props.get(PROP_ICEBERG_METADATA_LOCATION)?.clone();
+    let version = |key: &str| props.get(key).and_then(|v| v.parse().ok());
+    Some(DeltaUniformMetadata {
+        iceberg: DeltaIcebergMetadata {
+            metadata_location: Some(metadata_location),
+            converted_delta_version: version(PROP_ICEBERG_CONVERTED_DELTA_VERSION),
+            converted_delta_timestamp: version(PROP_ICEBERG_CONVERTED_DELTA_TIMESTAMP),
+            base_converted_delta_version: version(PROP_ICEBERG_BASE_CONVERTED_DELTA_VERSION),
+        },
+    })
+}
+
+/// Reject an Iceberg metadata location stored in `props` that does not lie
+/// under the table's storage location. See [`validate_iceberg_metadata_location`].
+pub fn validate_iceberg_properties(
+    props: &BTreeMap<String, String>,
+    storage_location: Option<&str>,
+) -> Result<()> {
+    match props.get(PROP_ICEBERG_METADATA_LOCATION) {
+        Some(metadata_location) => {
+            validate_iceberg_metadata_location(metadata_location, storage_location)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Reject an Iceberg metadata location that does not lie under the table's
+/// storage location.
+///
+/// `loadTable` reads the `metadata.json` with the server's own credentials, so
+/// a location elsewhere would let any writer of the table have the server read
+/// files it was never granted.
+pub fn validate_iceberg_metadata_location(
+    metadata_location: &str,
+    storage_location: Option<&str>,
+) -> Result<()> {
+    let outside = || {
+        Error::invalid_argument(format!(
+            "Iceberg metadata location '{metadata_location}' is not under the table's \
+             storage location"
+        ))
+    };
+    let storage_location = storage_location.ok_or_else(outside)?;
+    let root = StorageLocationUrl::parse(storage_location)?;
+    let metadata = StorageLocationUrl::parse(metadata_location)?;
+    let (root, metadata) = (root.location(), metadata.location());
+    let root_path = root.path().trim_end_matches('/');
+    let under_root = metadata
+        .path()
+        .strip_prefix(root_path)
+        .is_some_and(|rest| rest.starts_with('/') && rest.len() > 1);
+    if root.scheme() != metadata.scheme()
+        || root.username() != metadata.username()
+        || root.host_str() != metadata.host_str()
+        || root.port() != metadata.port()
+        || !under_root
+    {
+        return Err(outside());
+    }
+    Ok(())
+}
+
+/// Whether `delta.universalFormat.enabledFormats` lists `iceberg`.
+pub fn iceberg_enabled(props: &BTreeMap<String, String>) -> bool {
+    props
+        .get(PROP_UNIVERSAL_FORMAT_ENABLED_FORMATS)
+        .is_some_and(|formats| {
+            formats
+                .split(',')
+                .any(|f| f.trim().eq_ignore_ascii_case("iceberg"))
+        })
+}
+
 // ===================================================================
 // Column conversion (ColumnUtils)
 // ===================================================================
@@ -392,21 +521,31 @@ pub fn delta_columns_to_uc(
 }
 
 /// Reconstruct the Delta API `columns` from stored UC [`Column`]s for `loadTable`.
-/// Uses each column's stored `type_json` (the original Delta type), falling back to
-/// the bare type name string when absent.
+///
+/// [`delta_columns_to_uc`] stores each whole Delta field as the column's
+/// `type_json`, so its type and metadata (comments, column-mapping ids) come back
+/// unchanged. A `type_json` holding only a data type is accepted too, and the
+/// bare type name is the last resort.
 pub fn uc_columns_to_delta(columns: &[Column]) -> DeltaStructType {
     let mut sorted: Vec<&Column> = columns.iter().collect();
     sorted.sort_by_key(|c| c.position.unwrap_or(i32::MAX));
     let fields = sorted
         .into_iter()
         .map(|c| {
-            let data_type = serde_json::from_str::<DeltaDataType>(&c.type_json)
-                .unwrap_or_else(|_| DeltaDataType::Primitive(c.type_text.clone()));
+            let (data_type, metadata) = match serde_json::from_str::<DeltaStructField>(&c.type_json)
+            {
+                Ok(field) => (field.data_type, field.metadata),
+                Err(_) => (
+                    serde_json::from_str::<DeltaDataType>(&c.type_json)
+                        .unwrap_or_else(|_| DeltaDataType::Primitive(c.type_text.clone())),
+                    Default::default(),
+                ),
+            };
             DeltaStructField {
                 name: c.name.clone(),
                 data_type,
                 nullable: c.nullable.unwrap_or(true),
-                metadata: Default::default(),
+                metadata,
             }
         })
         .collect();
@@ -641,8 +780,7 @@ mod tests {
 
         // Reverse: stored type_json should reconstruct the Delta types.
         let back = uc_columns_to_delta(&uc);
-        assert_eq!(back.fields.len(), 2);
-        assert_eq!(back.fields[0].name, "id");
+        assert_eq!(back, columns);
     }
 
     #[test]
@@ -659,4 +797,71 @@ mod tests {
         let err = delta_columns_to_uc(&columns, Some(&["missing".to_string()])).unwrap_err();
         assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
     }
+
+    #[test]
+    fn uniform_metadata_roundtrips_through_properties() {
+        let mut props = BTreeMap::from([(
+            PROP_UNIVERSAL_FORMAT_ENABLED_FORMATS.to_string(),
+            "iceberg".to_string(),
+        )]);
+        assert!(iceberg_enabled(&props));
+        assert_eq!(uniform_from_properties(&props), None);
+
+        let uniform = DeltaUniformMetadata {
+            iceberg: DeltaIcebergMetadata {
+                metadata_location: Some("s3://b/t/metadata/v3.metadata.json".into()),
+                converted_delta_version: Some(3),
+                converted_delta_timestamp: Some(1700),
+                base_converted_delta_version: None,
+            },
+        };
+        derive_from_uniform(&mut props, &uniform);
+        assert_eq!(uniform_from_properties(&props), Some(uniform));
+
+        // A later report without a timestamp keeps the stored one.
+        derive_from_uniform(
+            &mut props,
+            &DeltaUniformMetadata {
+                iceberg: DeltaIcebergMetadata {
+                    metadata_location: Some("s3://b/t/metadata/v4.metadata.json".into()),
+                    converted_delta_version: Some(4),
+                    converted_delta_timestamp: None,
+                    base_converted_delta_version: Some(3),
+                },
+            },
+        );
+        let iceberg = uniform_from_properties(&props).unwrap().iceberg;
+        assert_eq!(iceberg.converted_delta_version, Some(4));
+        assert_eq!(iceberg.converted_delta_timestamp, Some(1700));
+        assert_eq!(iceberg.base_converted_delta_version, Some(3));
+    }
+
+    #[test]
+    fn iceberg_metadata_must_live_under_the_table() {
+        let root = Some("s3://b/t");
+        assert!(
+            validate_iceberg_metadata_location("s3://b/t/metadata/v1.metadata.json", root).is_ok()
+        );
+        for outside in [
+            "s3://b/other/metadata/v1.metadata.json",
+            "s3://b/t2/metadata/v1.metadata.json",
+            "s3://c/t/metadata/v1.metadata.json",
+            "s3://b/t/../other/v1.metadata.json",
+            "s3://b/t",
+            "file:///etc/passwd",
+        ] {
+            assert!(
+                validate_iceberg_metadata_location(outside, root).is_err(),
+                "{outside}"
+            );
+        }
+        assert!(validate_iceberg_metadata_location("s3://b/t/m.json", None).is_err());
+
+        let props = BTreeMap::from([(
+            PROP_ICEBERG_METADATA_LOCATION.to_string(),
+            "s3://b/other/v1.metadata.json".to_string(),
+        )]);
+        assert!(validate_iceberg_properties(&props, root).is_err());
+        assert!(validate_iceberg_properties(&BTreeMap::new(), None).is_ok());
+    }
 }
@@ -3,30 +3,42 @@ use std::sync::Arc;
 use delta_kernel::{Snapshot, Version};
 
 use session::*;
-use unitycatalog_common::models::tables::v1::DataSourceFormat;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, TableStatistics};
 
+use self::backfill::{BackfillConfig, BackfillPublisher};
 use self::location::StorageLocationUrl;
+use self::maintenance::{MaintenanceConfig, MaintenanceScheduler};
 use self::secrets::{ProvidesSecretManager, SecretManager};
-use crate::Result;
 use crate::api::tables::{TableHandler, TableManager};
 use crate::api::volumes::VolumeHandler;
 use crate::policy::{Decision, Permission, Policy, ProvidesPolicy};
 use crate::store::{ProvidesObjectStore, ProvidesResourceStore, ResourceStore};
+use crate::{Error, Result};
 use unitycatalog_common::ObjectLabel;
 use unitycatalog_common::models::ResourceIdent;
 use unitycatalog_common::services::commit_coordinator::{
     CommitCoordinator, InMemoryCommitCoordinator, ProvidesCommitCoordinator,
 };
+use unitycatalog_common::services::maintenance::{
+    InMemoryMaintenanceStore, MaintenanceStore, ProvidesMaintenanceStore,
+};
 
+pub mod backfill;
 pub mod credential_vending;
+mod iceberg;
 pub(crate) mod kernel;
 pub mod location;
 pub mod location_policy;
+pub mod maintenance;
 pub mod managed_delta_contract;
 pub(crate) mod object_store;
+pub mod schema_evolution;
 pub mod secrets;
 mod session;
+pub mod share_policy;
+mod shared_view;
 mod sharing;
+mod table_history;
 
 pub use location_policy::LocalStoragePolicy;
 
@@ -76,6 +88,9 @@ pub struct ServerHandler<Cx> {
     /// [`UpstreamVolumeHandler`](crate::handlers::upstream::UpstreamVolumeHandler)
     /// to resolve it from an upstream Unity Catalog (side-by-side topology).
     volume_source: Option<Arc<dyn VolumeHandler<Cx>>>,
+    /// Optional signer for the data file urls handed to Delta Sharing
+    /// recipients, replacing the one derived from the storage credential.
+    url_signer: Option<Arc<dyn object_store::signer::Signer>>,
 }
 
 impl<Cx: Send + Sync + 'static> ServerHandler<Cx>
@@ -115,8 +130,39 @@ where
             session,
             table_source: None,
             volume_source: None,
+            url_signer: None,
         })
     }
+
+    /// Build a [`BackfillPublisher`] that publishes ratified Delta commits
+    /// through this handler's store, commit coordinator and object stores.
+    ///
+    /// The publisher does nothing until it is run, e.g. via
+    /// [`BackfillPublisher::spawn`].
+    pub fn backfill_publisher(&self, config: BackfillConfig) -> BackfillPublisher {
+        BackfillPublisher::new(
+            self.handler.store.clone(),
+            self.handler.commit_coordinator.clone(),
+            self.handler.clone(),
+            config,
+        )
+    }
+
+    /// Build a [`MaintenanceScheduler`] that plans and runs maintenance of the
+    /// managed Delta tables from the commit reports in this handler's
+    /// maintenance store.
+    ///
+    /// The scheduler does nothing until it is run, e.g. via
+    /// [`MaintenanceScheduler::spawn`].
+    pub fn maintenance_scheduler(&self, config: MaintenanceConfig) -> MaintenanceScheduler {
+        MaintenanceScheduler::new(
+            self.handler.store.clone(),
+            self.handler.commit_coordinator.clone(),
+            self.handler.maintenance_store.clone(),
+            self.handler.clone(),
+            config,
+        )
+    }
 }
 
 impl<Cx: Send + Sync + 'static> ServerHandler<Cx> {
@@ -153,6 +199,16 @@ impl<Cx: Send + Sync + 'static> ServerHandler<Cx> {
         self.volume_source.as_ref()
     }
 
+    /// Pre-sign the data file urls of shared tables with `signer`.
+    ///
+    /// By default urls are signed with the credential of the external location
+    /// holding the table, which only Azure storage supports. Use this to hand
+    /// out urls of a CDN or file server in front of the storage instead.
+    pub fn with_url_signer(mut self, signer: Arc<dyn object_store::signer::Signer>) -> Self {
+        self.url_signer = Some(signer);
+        self
+    }
+
     /// Set the allowlist governing `file://` storage locations.
     ///
     /// Rebuilds the inner handler with the policy attached. Call at construction
@@ -169,6 +225,7 @@ impl<Cx: Send + Sync + 'static> ServerHandler<Cx> {
             object_store: prev.object_store.clone(),
             secrets: prev.secrets.clone(),
             commit_coordinator: prev.commit_coordinator.clone(),
+            maintenance_store: prev.maintenance_store.clone(),
             local_storage_policy: policy.into(),
             managed_storage_root: prev.managed_storage_root.clone(),
         };
@@ -189,12 +246,34 @@ impl<Cx: Send + Sync + 'static> ServerHandler<Cx> {
             object_store: prev.object_store.clone(),
             secrets: prev.secrets.clone(),
             commit_coordinator: prev.commit_coordinator.clone(),
+            maintenance_store: prev.maintenance_store.clone(),
             local_storage_policy: prev.local_storage_policy.clone(),
             managed_storage_root: root.map(Into::into),
         };
         self.handler = Arc::new(inner);
         self
     }
+
+    /// Set the store for Delta commit reports and maintenance jobs.
+    ///
+    /// Rebuilds the inner handler with the store attached. Call at construction
+    /// time, before the handler is cloned/shared. When unset, reports and jobs
+    /// are kept in memory.
+    pub fn with_maintenance_store(mut self, maintenance_store: Arc<dyn MaintenanceStore>) -> Self {
+        let prev = &self.handler;
+        let inner = ServerHandlerInner {
+            policy: prev.policy.clone(),
+            store: prev.store.clone(),
+            object_store: prev.object_store.clone(),
+            secrets: prev.secrets.clone(),
+            commit_coordinator: prev.commit_coordinator.clone(),
+            maintenance_store,
+            local_storage_policy: prev.local_storage_policy.clone(),
+            managed_storage_root: prev.managed_storage_root.clone(),
+        };
+        self.handler = Arc::new(inner);
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -205,6 +284,8 @@ pub struct ServerHandlerInner<Cx> {
     secrets: Arc<dyn SecretManager>,
     /// Delta catalog-managed commit coordinator (in-memory by default).
     commit_coordinator: Arc<dyn CommitCoordinator>,
+    /// Delta commit reports and maintenance jobs (in-memory by default).
+    maintenance_store: Arc<dyn MaintenanceStore>,
     /// Allowlist governing which host paths may back a `file://` storage
     /// location. Deny-all by default (see [`LocalStoragePolicy`]).
     local_storage_policy: Arc<LocalStoragePolicy>,
@@ -226,6 +307,7 @@ impl<Cx: Send + Sync + 'static> ServerHandlerInner<Cx> {
             object_store: None,
             secrets,
             commit_coordinator: Arc::new(InMemoryCommitCoordinator::default()),
+            maintenance_store: Arc::new(InMemoryMaintenanceStore::default()),
             // Deny all local (file://) storage until a policy is configured.
             local_storage_policy: Arc::new(LocalStoragePolicy::deny_all()),
             // No metastore-level managed storage root by default.
@@ -256,6 +338,12 @@ impl<Cx: Send + Sync + 'static> ServerHandlerInner<Cx> {
         self
     }
 
+    /// Override the store for Delta commit reports and maintenance jobs.
+    pub fn with_maintenance_store(mut self, maintenance_store: Arc<dyn MaintenanceStore>) -> Self {
+        self.maintenance_store = maintenance_store;
+        self
+    }
+
     /// Set the generic object store.
     ///
     /// When provided, the server exposes the untyped `ObjectStore<ObjectLabel>`
@@ -382,6 +470,18 @@ impl<Cx: Send + Sync + 'static> ProvidesCommitCoordinator for ServerHandler<Cx>
     }
 }
 
+impl<Cx: Send + Sync + 'static> ProvidesMaintenanceStore for ServerHandlerInner<Cx> {
+    fn maintenance_store(&self) -> &dyn MaintenanceStore {
+        self.maintenance_store.as_ref()
+    }
+}
+
+impl<Cx: Send + Sync + 'static> ProvidesMaintenanceStore for ServerHandler<Cx> {
+    fn maintenance_store(&self) -> &dyn MaintenanceStore {
+        self.handler.maintenance_store.as_ref()
+    }
+}
+
 impl<Cx: Send + Sync + 'static> ProvidesLocalStoragePolicy for ServerHandlerInner<Cx> {
     fn local_storage_policy(&self) -> &LocalStoragePolicy {
         self.local_storage_policy.as_ref()
@@ -416,4 +516,23 @@ impl<Cx: Send + Sync + 'static> TableManager for ServerHandler<Cx> {
     ) -> Result<Arc<Snapshot>> {
         self.session.read_snapshot(location, format, version).await
     }
+
+    async fn table_statistics(
+        &self,
+        location: &StorageLocationUrl,
+        table_id: Option<&str>,
+    ) -> Result<TableStatistics> {
+        let Some(table_id) = table_id else {
+            return self.session.statistics(location, &[], None).await;
+        };
+        let (ratified, latest) = self
+            .commit_coordinator()
+            .get_commits(table_id, 0, None)
+            .await?;
+        let latest = Version::try_from(latest)
+            .map_err(|_| Error::generic(format!("negative table version {latest}")))?;
+        self.session
+            .statistics(location, &ratified, Some(latest))
+            .await
+    }
 }
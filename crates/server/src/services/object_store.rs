@@ -5,6 +5,7 @@ use datafusion::common::{DataFusionError, Result as DFResult};
 use itertools::Itertools;
 use object_store::azure::MicrosoftAzureBuilder;
 use object_store::local::LocalFileSystem;
+use object_store::signer::Signer;
 use object_store::{DynObjectStore, ObjectStoreScheme};
 use unitycatalog_common::credentials::v1::AzureManagedIdentity;
 use unitycatalog_common::models::credentials::v1::{
@@ -299,13 +300,52 @@ pub(crate) async fn get_object_store(
     ) {
         return get_local_store(location);
     }
+    Ok(Arc::new(azure_builder(location, handler).await?.build()?))
+}
+
+/// Resolve a [`Signer`] issuing pre-signed urls for objects under `location`.
+///
+/// Recipients of shared tables download data files from pre-signed urls, so
+/// the signer is backed by the same credential as [`get_object_store`]. Only
+/// Azure storage is supported; local `file://` storage has nothing to sign
+/// with, and other stores are rejected rather than misconfigured as Azure.
+pub(crate) async fn get_signer(
+    location: &StorageLocationUrl,
+    handler: &dyn RegistryHandler,
+) -> Result<Arc<dyn Signer>> {
+    tracing::debug!("get_signer: {:?}", location.location());
+    match location.scheme() {
+        StorageLocationScheme::ObjectStore(ObjectStoreScheme::MicrosoftAzure)
+        | StorageLocationScheme::Azurite => {
+            Ok(Arc::new(azure_builder(location, handler).await?.build()?))
+        }
+        StorageLocationScheme::ObjectStore(ObjectStoreScheme::Local) => {
+            Err(Error::invalid_argument(format!(
+                "cannot pre-sign urls for local (file://) storage: {}",
+                location.raw()
+            )))
+        }
+        scheme => Err(Error::invalid_argument(format!(
+            "cannot pre-sign urls for '{}' storage: {}; pre-signing is only supported for Azure",
+            scheme.as_ref(),
+            location.raw()
+        ))),
+    }
+}
+
+/// Configure an Azure store for `location` with the credential of the
+/// external location that contains it.
+async fn azure_builder(
+    location: &StorageLocationUrl,
+    handler: &dyn RegistryHandler,
+) -> Result<MicrosoftAzureBuilder> {
     let ext_loc = find_external_location_for_url(location, handler).await?;
     let credential = handler
         .get_credential_internal(GetCredentialRequest {
             name: ext_loc.credential_name.clone(),
         })
         .await?;
-    get_azure_store(
+    get_azure_builder(
         location,
         credential.azure_managed_identity,
         credential.azure_service_principal,
@@ -338,13 +378,13 @@ fn get_local_store(location: &StorageLocationUrl) -> Result<Arc<DynObjectStore>>
     Ok(Arc::new(LocalFileSystem::new()))
 }
 
-fn get_azure_store(
+fn get_azure_builder(
     location: &StorageLocationUrl,
     azure_managed_identity: Option<AzureManagedIdentity>,
     azure_service_principal: Option<AzureServicePrincipal>,
     azure_storage_key: Option<AzureStorageKey>,
-) -> Result<Arc<DynObjectStore>> {
-    tracing::debug!("get_azure_store: {:?}", location.location());
+) -> Result<MicrosoftAzureBuilder> {
+    tracing::debug!("get_azure_builder: {:?}", location.location());
     let url_err = || {
         Error::invalid_argument(
             "emulator URLs must encode the account and container name in the path",
@@ -408,7 +448,7 @@ fn get_azure_store(
         ));
     }
 
-    Ok(Arc::new(builder.build()?))
+    Ok(builder)
 }
 
 #[cfg(test)]
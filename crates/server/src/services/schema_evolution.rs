@@ -0,0 +1,572 @@
+//! Schema-evolution and CHECK-constraint validation for Delta `updateTable`.
+//!
+//! For a catalog-managed table the catalog's columns are authoritative, so a
+//! `set-columns` action must describe a change Delta readers can follow from the
+//! current schema. The rules are the Delta protocol's:
+//!
+//! - New columns (top-level or nested) must be nullable, since existing rows
+//!   have no value for them.
+//! - A column may become nullable but never `NOT NULL`; the catalog cannot check
+//!   existing data.
+//! - Dropping or renaming a column needs column mapping
+//!   (`delta.columnMapping.mode` of `name` or `id`). With mapping, fields are
+//!   matched by `delta.columnMapping.id`, so a rename is not a drop.
+//! - A type may only change by widening (`integer` → `long`, `float` →
+//!   `double`, larger decimals, ...), and only on tables with the
+//!   `typeWidening` feature and `delta.enableTypeWidening=true`.
+//!
+//! CHECK constraints are stored as `delta.constraints.<name>` properties. Each
+//! must parse as a SQL expression over existing columns, and the table's
+//! protocol must support the `checkConstraints` writer feature.
+
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+use datafusion::sql::sqlparser::ast::{Expr, visit_expressions};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+
+use crate::rest::routers::delta::models::{DeltaDataType, DeltaStructField, DeltaStructType};
+use crate::{Error, Result};
+
+/// Property prefix of a table's CHECK constraints.
+pub const CONSTRAINT_PREFIX: &str = "delta.constraints.";
+const PROP_COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+const PROP_ENABLE_TYPE_WIDENING: &str = "delta.enableTypeWidening";
+const PROP_MIN_WRITER_VERSION: &str = "delta.minWriterVersion";
+const FIELD_COLUMN_MAPPING_ID: &str = "delta.columnMapping.id";
+
+/// What the table's properties allow a schema change to do.
+struct EvolutionRules {
+    column_mapping: bool,
+    type_widening: bool,
+}
+
+impl EvolutionRules {
+    fn from_properties(properties: &BTreeMap<String, String>) -> Self {
+        let column_mapping = properties
+            .get(PROP_COLUMN_MAPPING_MODE)
+            .is_some_and(|mode| mode == "name" || mode == "id");
+        let type_widening = supports_feature(properties, "typeWidening", None)
+            && properties
+                .get(PROP_ENABLE_TYPE_WIDENING)
+                .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+        Self {
+            column_mapping,
+            type_widening,
+        }
+    }
+}
+
+/// Whether the table protocol recorded in `properties` supports `feature`: it
+/// is listed as `delta.feature.<feature>`, or — for a legacy feature and a
+/// pre-table-features protocol — the writer version implies it.
+fn supports_feature(
+    properties: &BTreeMap<String, String>,
+    feature: &str,
+    legacy_writer_version: Option<i32>,
+) -> bool {
+    if properties.contains_key(&format!("delta.feature.{feature}")) {
+        return true;
+    }
+    let writer_version = properties
+        .get(PROP_MIN_WRITER_VERSION)
+        .and_then(|v| v.parse::<i32>().ok());
+    matches!(
+        (writer_version, legacy_writer_version),
+        (Some(version), Some(required)) if (required..7).contains(&version)
+    )
+}
+
+/// Check that `proposed` is a valid evolution of `current` under the table's
+/// (post-update) `properties`.
+pub fn validate_schema_change(
+    current: &DeltaStructType,
+    proposed: &DeltaStructType,
+    properties: &BTreeMap<String, String>,
+) -> Result<()> {
+    let rules = EvolutionRules::from_properties(properties);
+    check_fields(&current.fields, &proposed.fields, &rules, "")
+}
+
+fn column_mapping_id(field: &DeltaStructField) -> Option<i64> {
+    field
+        .metadata
+        .get(FIELD_COLUMN_MAPPING_ID)
+        .and_then(|id| id.as_i64())
+}
+
+/// The field of `proposed` that continues `old`: by column-mapping id when
+/// mapping is on and both carry one, else by name.
+fn find_match<'a>(
+    old: &DeltaStructField,
+    proposed: &'a [DeltaStructField],
+    rules: &EvolutionRules,
+) -> Option<&'a DeltaStructField> {
+    let old_id = column_mapping_id(old).filter(|_| rules.column_mapping);
+    proposed.iter().find(|new| {
+        match (
+            old_id,
+            column_mapping_id(new).filter(|_| rules.column_mapping),
+        ) {
+            (Some(old_id), Some(new_id)) => old_id == new_id,
+            _ => new.name.eq_ignore_ascii_case(&old.name),
+        }
+    })
+}
+
+fn check_fields(
+    current: &[DeltaStructField],
+    proposed: &[DeltaStructField],
+    rules: &EvolutionRules,
+    prefix: &str,
+) -> Result<()> {
+    for (i, field) in proposed.iter().enumerate() {
+        if proposed[..i]
+            .iter()
+            .any(|other| other.name.eq_ignore_ascii_case(&field.name))
+        {
+            return Err(Error::invalid_argument(format!(
+                "duplicate column '{prefix}{}'",
+                field.name
+            )));
+        }
+    }
+
+    let mut matched = vec![false; proposed.len()];
+    for old in current {
+        let path = format!("{prefix}{}", old.name);
+        let Some(new) = find_match(old, proposed, rules) else {
+            if !rules.column_mapping {
+                return Err(Error::invalid_argument(format!(
+                    "cannot drop or rename column '{path}': this requires column mapping \
+                     (delta.columnMapping.mode = name or id)"
+                )));
+            }
+            continue;
+        };
+        if let Some(i) = proposed.iter().position(|f| std::ptr::eq(f, new)) {
+            matched[i] = true;
+        }
+        if old.nullable && !new.nullable {
+            return Err(Error::invalid_argument(format!(
+                "cannot make column '{path}' NOT NULL"
+            )));
+        }
+        check_type(&old.data_type, &new.data_type, rules, &path)?;
+    }
+
+    for (field, _) in proposed.iter().zip(&matched).filter(|(_, m)| !**m) {
+        if !field.nullable {
+            return Err(Error::invalid_argument(format!(
+                "added column '{prefix}{}' must be nullable",
+                field.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_type(
+    old: &DeltaDataType,
+    new: &DeltaDataType,
+    rules: &EvolutionRules,
+    path: &str,
+) -> Result<()> {
+    match (old, new) {
+        (DeltaDataType::Struct(old), DeltaDataType::Struct(new)) => {
+            check_fields(&old.fields, &new.fields, rules, &format!("{path}."))
+        }
+        (DeltaDataType::Array(old), DeltaDataType::Array(new)) => {
+            if old.contains_null && !new.contains_null {
+                return Err(Error::invalid_argument(format!(
+                    "cannot make the elements of '{path}' NOT NULL"
+                )));
+            }
+            check_type(
+                &old.element_type,
+                &new.element_type,
+                rules,
+                &format!("{path}.element"),
+            )
+        }
+        (DeltaDataType::Map(old), DeltaDataType::Map(new)) => {
+            if old.value_contains_null && !new.value_contains_null {
+                return Err(Error::invalid_argument(format!(
+                    "cannot make the values of '{path}' NOT NULL"
+                )));
+            }
+            check_type(&old.key_type, &new.key_type, rules, &format!("{path}.key"))?;
+            check_type(
+                &old.value_type,
+                &new.value_type,
+                rules,
+                &format!("{path}.value"),
+            )
+        }
+        _ => {
+            let (from, to) = (Scalar::of(old), Scalar::of(new));
+            if from.is_some() && from == to {
+                return Ok(());
+            }
+            let widening = matches!((from, to), (Some(from), Some(to)) if from.widens_to(to));
+            if widening && rules.type_widening {
+                return Ok(());
+            }
+            let hint = if widening {
+                "; widening needs the typeWidening feature and delta.enableTypeWidening=true"
+            } else {
+                ""
+            };
+            Err(Error::invalid_argument(format!(
+                "cannot change the type of column '{path}' from {} to {}{hint}",
+                type_name(old),
+                type_name(new)
+            )))
+        }
+    }
+}
+
+/// A scalar Delta type, with decimals normalized to precision and scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scalar<'a> {
+    Byte,
+    Short,
+    Integer,
+    Long,
+    Float,
+    Double,
+    Date,
+    TimestampNtz,
+    Decimal(i32, i32),
+    Other(&'a str),
+}
+
+impl<'a> Scalar<'a> {
+    fn of(data_type: &'a DeltaDataType) -> Option<Self> {
+        Some(match data_type {
+            DeltaDataType::Decimal(d) => Self::Decimal(d.precision, d.scale),
+            DeltaDataType::Primitive(p) => match p.as_str() {
+                "byte" => Self::Byte,
+                "short" => Self::Short,
+                "integer" => Self::Integer,
+                "long" => Self::Long,
+                "float" => Self::Float,
+                "double" => Self::Double,
+                "date" => Self::Date,
+                "timestamp_ntz" => Self::TimestampNtz,
+                "decimal" => Self::Decimal(10, 0),
+                other => match parse_decimal(other) {
+                    Some((precision, scale)) => Self::Decimal(precision, scale),
+                    None => Self::Other(other),
+                },
+            },
+            _ => return None,
+        })
+    }
+
+    /// Integer digits (`precision - scale`) the Delta type widening spec
+    /// requires of a decimal an integer type is widened to.
+    fn integer_digits(self) -> Option<i32> {
+        match self {
+            Self::Byte | Self::Short | Self::Integer => Some(10),
+            Self::Long => Some(20),
+            _ => None,
+        }
+    }
+
+    /// Whether Delta type widening allows changing `self` to `to`.
+    fn widens_to(self, to: Self) -> bool {
+        use Scalar::*;
+        match (self, to) {
+            (Byte, Short | Integer | Long | Double)
+            | (Short, Integer | Long | Double)
+            | (Integer, Long | Double)
+            | (Float, Double)
+            | (Date, TimestampNtz) => true,
+            (Decimal(p, s), Decimal(p2, s2)) => p2 >= p && s2 >= s && p2 - s2 >= p - s,
+            (from, Decimal(p, s)) => from.integer_digits().is_some_and(|digits| p - s >= digits),
+            _ => false,
+        }
+    }
+}
+
+/// Parse `decimal(p,s)` (whitespace tolerated) into precision and scale.
+fn parse_decimal(type_name: &str) -> Option<(i32, i32)> {
+    let inner = type_name.strip_prefix("decimal(")?.strip_suffix(')')?;
+    let (precision, scale) = inner.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
+
+fn type_name(data_type: &DeltaDataType) -> String {
+    match data_type {
+        DeltaDataType::Primitive(p) => p.clone(),
+        DeltaDataType::Decimal(d) => format!("decimal({},{})", d.precision, d.scale),
+        DeltaDataType::Array(_) => "array".to_string(),
+        DeltaDataType::Map(_) => "map".to_string(),
+        DeltaDataType::Struct(_) => "struct".to_string(),
+    }
+}
+
+/// Check the table's CHECK constraints against `schema`: every
+/// `delta.constraints.<name>` property must parse as a SQL expression that only
+/// references existing columns, and the protocol must support them.
+pub fn validate_constraints(
+    schema: &DeltaStructType,
+    properties: &BTreeMap<String, String>,
+) -> Result<()> {
+    let constraints: Vec<_> = properties
+        .iter()
+        .filter_map(|(k, v)| k.strip_prefix(CONSTRAINT_PREFIX).map(|name| (name, v)))
+        .collect();
+    if constraints.is_empty() {
+        return Ok(());
+    }
+    if !supports_feature(properties, "checkConstraints", Some(3)) {
+        return Err(Error::invalid_argument(
+            "CHECK constraints require the checkConstraints writer feature in the table protocol",
+        ));
+    }
+
+    for (name, expression) in constraints {
+        if name.is_empty() {
+            return Err(Error::invalid_argument(
+                "CHECK constraint name must not be empty",
+            ));
+        }
+        let expr = Parser::new(&GenericDialect {})
+            .try_with_sql(expression)
+            .and_then(|mut parser| parser.parse_expr())
+            .map_err(|e| {
+                Error::invalid_argument(format!(
+                    "CHECK constraint '{name}' is not a valid expression: {e}"
+                ))
+            })?;
+        let unknown = visit_expressions(&expr, |e| {
+            let column = match e {
+                Expr::Identifier(ident) => Some(&ident.value),
+                Expr::CompoundIdentifier(parts) => parts.first().map(|ident| &ident.value),
+                _ => None,
+            };
+            match column {
+                Some(column)
+                    if !schema
+                        .fields
+                        .iter()
+                        .any(|f| f.name.eq_ignore_ascii_case(column)) =>
+                {
+                    ControlFlow::Break(column.clone())
+                }
+                _ => ControlFlow::Continue(()),
+            }
+        });
+        if let ControlFlow::Break(column) = unknown {
+            return Err(Error::invalid_argument(format!(
+                "CHECK constraint '{name}' references unknown column '{column}'"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::rest::routers::delta::models::{DeltaArrayType, DeltaDecimalType};
+
+    fn field(name: &str, data_type: DeltaDataType, nullable: bool) -> DeltaStructField {
+        DeltaStructField {
+            name: name.into(),
+            data_type,
+            nullable,
+            metadata: Default::default(),
+        }
+    }
+
+    fn primitive(name: &str) -> DeltaDataType {
+        DeltaDataType::Primitive(name.into())
+    }
+
+    fn schema(fields: Vec<DeltaStructField>) -> DeltaStructType {
+        DeltaStructType {
+            type_tag: Default::default(),
+            fields,
+        }
+    }
+
+    fn with_id(mut field: DeltaStructField, id: i64) -> DeltaStructField {
+        field
+            .metadata
+            .insert(FIELD_COLUMN_MAPPING_ID.into(), json!(id));
+        field
+    }
+
+    fn props(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn base() -> DeltaStructType {
+        schema(vec![
+            field("id", primitive("integer"), false),
+            field("name", primitive("string"), true),
+        ])
+    }
+
+    #[test]
+    fn adding_nullable_columns_is_allowed() {
+        let mut proposed = base();
+        proposed
+            .fields
+            .push(field("tags", primitive("string"), true));
+        assert!(validate_schema_change(&base(), &proposed, &props(&[])).is_ok());
+
+        proposed
+            .fields
+            .push(field("score", primitive("double"), false));
+        let err = validate_schema_change(&base(), &proposed, &props(&[])).unwrap_err();
+        assert!(err.to_string().contains("must be nullable"), "{err}");
+    }
+
+    #[test]
+    fn tightening_nullability_is_rejected() {
+        let proposed = schema(vec![
+            field("id", primitive("integer"), true),
+            field("name", primitive("string"), false),
+        ]);
+        let err = validate_schema_change(&base(), &proposed, &props(&[])).unwrap_err();
+        assert!(err.to_string().contains("NOT NULL"), "{err}");
+    }
+
+    #[test]
+    fn drops_need_column_mapping() {
+        let proposed = schema(vec![field("id", primitive("integer"), false)]);
+        assert!(validate_schema_change(&base(), &proposed, &props(&[])).is_err());
+        let mapped = props(&[(PROP_COLUMN_MAPPING_MODE, "name")]);
+        assert!(validate_schema_change(&base(), &proposed, &mapped).is_ok());
+    }
+
+    #[test]
+    fn renames_follow_column_mapping_ids() {
+        let current = schema(vec![with_id(field("name", primitive("string"), true), 2)]);
+        let renamed = schema(vec![with_id(
+            field("full_name", primitive("string"), true),
+            2,
+        )]);
+        let mapped = props(&[(PROP_COLUMN_MAPPING_MODE, "id")]);
+        assert!(validate_schema_change(&current, &renamed, &mapped).is_ok());
+        assert!(validate_schema_change(&current, &renamed, &props(&[])).is_err());
+
+        // Matched by id, a type change on the renamed column is still checked.
+        let retyped = schema(vec![with_id(
+            field("full_name", primitive("long"), true),
+            2,
+        )]);
+        assert!(validate_schema_change(&current, &retyped, &mapped).is_err());
+    }
+
+    #[test]
+    fn widening_needs_the_type_widening_feature() {
+        let proposed = schema(vec![
+            field("id", primitive("long"), false),
+            field("name", primitive("string"), true),
+        ]);
+        let err = validate_schema_change(&base(), &proposed, &props(&[])).unwrap_err();
+        assert!(err.to_string().contains("typeWidening"), "{err}");
+
+        let widening = props(&[
+            ("delta.feature.typeWidening", "supported"),
+            (PROP_ENABLE_TYPE_WIDENING, "true"),
+        ]);
+        assert!(validate_schema_change(&base(), &proposed, &widening).is_ok());
+
+        // Narrowing is never allowed.
+        let err = validate_schema_change(&proposed, &base(), &widening).unwrap_err();
+        assert!(!err.to_string().contains("typeWidening"), "{err}");
+    }
+
+    #[test]
+    fn decimal_widening_keeps_integer_digits() {
+        let decimal = |p, s| {
+            DeltaDataType::Decimal(DeltaDecimalType {
+                type_tag: Default::default(),
+                precision: p,
+                scale: s,
+            })
+        };
+        let widens = |from: DeltaDataType, to: DeltaDataType| {
+            Scalar::of(&from)
+                .unwrap()
+                .widens_to(Scalar::of(&to).unwrap())
+        };
+        assert!(widens(decimal(10, 2), decimal(12, 4)));
+        assert!(widens(primitive("decimal(10,2)"), decimal(12, 2)));
+        assert!(!widens(decimal(10, 2), decimal(10, 4)));
+        assert!(widens(primitive("integer"), decimal(12, 2)));
+        assert!(!widens(primitive("integer"), decimal(11, 2)));
+        // Byte and short need the same ten integer digits as integer.
+        assert!(widens(primitive("byte"), decimal(10, 0)));
+        assert!(!widens(primitive("byte"), decimal(5, 0)));
+        assert!(!widens(primitive("short"), decimal(9, 0)));
+        assert!(widens(primitive("long"), decimal(22, 2)));
+        assert!(!widens(primitive("long"), decimal(20, 2)));
+    }
+
+    #[test]
+    fn nested_fields_follow_the_same_rules() {
+        let nested = |fields| {
+            schema(vec![field(
+                "items",
+                DeltaDataType::Array(Box::new(DeltaArrayType {
+                    type_tag: Default::default(),
+                    element_type: DeltaDataType::Struct(Box::new(schema(fields))),
+                    contains_null: true,
+                })),
+                true,
+            )])
+        };
+        let current = nested(vec![field("sku", primitive("string"), true)]);
+        let added = nested(vec![
+            field("sku", primitive("string"), true),
+            field("qty", primitive("integer"), true),
+        ]);
+        assert!(validate_schema_change(&current, &added, &props(&[])).is_ok());
+
+        let dropped = nested(vec![field("qty", primitive("integer"), true)]);
+        let err = validate_schema_change(&current, &dropped, &props(&[])).unwrap_err();
+        assert!(err.to_string().contains("items.element.sku"), "{err}");
+    }
+
+    #[test]
+    fn constraints_reference_existing_columns() {
+        let mut properties = props(&[
+            ("delta.feature.checkConstraints", "supported"),
+            (
+                "delta.constraints.positive_id",
+                "id > 0 AND name IS NOT NULL",
+            ),
+        ]);
+        assert!(validate_constraints(&base(), &properties).is_ok());
+
+        properties.insert("delta.constraints.bad".into(), "missing > 0".into());
+        let err = validate_constraints(&base(), &properties).unwrap_err();
+        assert!(err.to_string().contains("missing"), "{err}");
+
+        properties.insert("delta.constraints.bad".into(), "id >".into());
+        assert!(validate_constraints(&base(), &properties).is_err());
+    }
+
+    #[test]
+    fn constraints_need_protocol_support() {
+        let constraint = ("delta.constraints.positive_id", "id > 0");
+        assert!(validate_constraints(&base(), &props(&[constraint])).is_err());
+        let legacy = props(&[constraint, (PROP_MIN_WRITER_VERSION, "3")]);
+        assert!(validate_constraints(&base(), &legacy).is_ok());
+        let features = props(&[constraint, (PROP_MIN_WRITER_VERSION, "7")]);
+        assert!(validate_constraints(&base(), &features).is_err());
+    }
+}
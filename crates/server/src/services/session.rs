@@ -1,33 +1,58 @@
-use std::sync::{Arc, LazyLock};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use bytes::Bytes;
-use datafusion::arrow::array::{AsArray, RecordBatch};
+use datafusion::arrow::array::{
+    Array, ArrayRef, AsArray, BooleanArray, Int64Array, RecordBatch, StringArray, StructArray,
+};
+use datafusion::arrow::compute::filter_record_batch;
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef};
 use datafusion::arrow::json::LineDelimitedWriter;
 use datafusion::catalog::{CatalogProvider, MemoryCatalogProvider, MemorySchemaProvider};
-use datafusion::common::TableReference as DfTableReference;
 use datafusion::functions::core::expr_ext::FieldAccessor;
 use datafusion::logical_expr::ColumnarValue;
-use datafusion::physical_plan::PhysicalExpr;
+use datafusion::physical_plan::{PhysicalExpr, SendableRecordBatchStream};
 use datafusion::prelude::SessionContext;
 use datafusion::prelude::{Expr, col, lit, named_struct};
 use delta_kernel::{Snapshot, Version};
-use itertools::Itertools;
-use unitycatalog_common::models::tables::v1::DataSourceFormat;
+use futures::{StreamExt, TryStreamExt};
+use http::Method;
+use object_store::path::Path;
+use object_store::signer::Signer;
+use unitycatalog_common::models::delta_commits::v1::CommitInfo;
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, TableStatistics};
+use url::Url;
 
-use super::kernel::{DeltaLogReplayProvider, ObjectStoreFactory, build_engine};
+use super::kernel::{
+    DeltaLogReplayProvider, ObjectStoreFactory, StatisticsCache, build_engine, catalog_log_tail,
+    compute_statistics,
+};
 use super::location::StorageLocationUrl;
-use super::sharing::SharingTableReference;
+use super::share_policy::SharedTablePolicy;
+use crate::api::sharing::NdJsonStream;
 use crate::api::tables::TableManager;
 use crate::{Error, Result};
 
 const UC_RS_SYSTEM_CATALOG_NAME: &str = "uc_rs_system";
 const UC_RS_LOG_REPLAY_SCHEMA_NAME: &str = "uc_rs_log_replay";
 
-static PQ_FILE_EXTRACT: LazyLock<Expr> = LazyLock::new(|| {
+/// How many tables' statistics the session keeps cached.
+const STATISTICS_CACHE_CAPACITY: usize = 1024;
+
+/// How long the pre-signed url of a shared data file stays valid.
+const PRESIGNED_URL_TTL: Duration = Duration::from_secs(3600);
+
+/// Project a log-replay row onto a Delta Sharing `file` action, less the
+/// pre-signed `url` and its expiry which [`FileUrlSigner`] adds.
+///
+/// The path in the Delta log, relative to the table root, is the file's
+/// stable `id`.
+fn sharing_file_action() -> Expr {
     named_struct(vec![
         lit("file"),
         named_struct(vec![
-            lit("path"),
+            lit("id"),
             col("path"),
             lit("partitionValues"),
             col("\"fileConstantValues\"").field("partitionValues"),
@@ -35,34 +60,85 @@ static PQ_FILE_EXTRACT: LazyLock<Expr> = LazyLock::new(|| {
             col("size"),
         ]),
     ])
-});
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TableReference {
-    Sharing(SharingTableReference),
-    Datafusion(DfTableReference),
 }
 
-struct Extractors {
-    sharing_pq_files: Arc<dyn PhysicalExpr>,
+/// Pre-signs the urls recipients download a shared table's data files from.
+#[derive(Clone)]
+struct FileUrlSigner {
+    signer: Arc<dyn Signer>,
+    /// The table root, with a trailing slash so relative paths join under it.
+    table_root: Url,
 }
 
-impl Extractors {
-    pub fn new(ctx: &SessionContext) -> Result<Self> {
-        let df_schema = DeltaLogReplayProvider::scan_row_schema()
-            .try_into()
-            .map_err(|_| Error::Generic("failed to convert schema".to_string()))?;
-        let sharing_pq_files = ctx
-            .create_physical_expr(PQ_FILE_EXTRACT.clone(), &df_schema)
+impl FileUrlSigner {
+    fn new(signer: Arc<dyn Signer>, table_root: &Url) -> Self {
+        let mut table_root = table_root.clone();
+        if !table_root.path().ends_with('/') {
+            table_root.set_path(&format!("{}/", table_root.path()));
+        }
+        Self { signer, table_root }
+    }
+
+    /// Add a pre-signed `url` and its `expirationTimestamp` to each `file`
+    /// action in `actions`, signing the object named by the action's `id`.
+    async fn sign(&self, actions: &StructArray) -> Result<StructArray> {
+        let malformed = || Error::generic("sharing file actions are malformed");
+        let (fields, mut columns, nulls) = actions
+            .column_by_name("file")
+            .and_then(|file| file.as_struct_opt())
+            .ok_or_else(malformed)?
+            .clone()
+            .into_parts();
+        let ids = fields
+            .iter()
+            .position(|field| field.name() == "id")
+            .and_then(|idx| columns[idx].as_string_opt::<i32>())
+            .ok_or_else(malformed)?;
+        // Delta log paths are URL encoded and relative to the table root.
+        let paths = ids
+            .iter()
+            .map(|id| {
+                let url = self
+                    .table_root
+                    .join(id.unwrap_or_default())
+                    .map_err(|e| Error::Generic(e.to_string()))?;
+                Path::from_url_path(url.path()).map_err(|e| Error::Generic(e.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let expires_at = chrono::Utc::now() + PRESIGNED_URL_TTL;
+        let urls = self
+            .signer
+            .signed_urls(Method::GET, &paths, PRESIGNED_URL_TTL)
+            .await
+            .map_err(|e| Error::Generic(e.to_string()))?;
+
+        let mut fields: Vec<FieldRef> = fields.iter().cloned().collect();
+        fields.insert(0, Arc::new(Field::new("url", DataType::Utf8, false)));
+        columns.insert(
+            0,
+            Arc::new(StringArray::from_iter_values(urls.iter().map(Url::as_str))),
+        );
+        fields.push(Arc::new(Field::new(
+            "expirationTimestamp",
+            DataType::Int64,
+            false,
+        )));
+        columns.push(Arc::new(Int64Array::from_value(
+            expires_at.timestamp_millis(),
+            paths.len(),
+        )));
+        let file = StructArray::try_new(fields.into(), columns, nulls)
             .map_err(|e| Error::Generic(e.to_string()))?;
-        Ok(Self { sharing_pq_files })
+        StructArray::try_from(vec![("file", Arc::new(file) as ArrayRef)])
+            .map_err(|e| Error::Generic(e.to_string()))
     }
 }
 
 pub struct KernelSession {
     ctx: SessionContext,
-    extractors: Extractors,
     factory: Arc<dyn ObjectStoreFactory>,
+    /// Last computed statistics per table root; reused while the version holds.
+    statistics: Mutex<StatisticsCache>,
 }
 
 impl KernelSession {
@@ -78,9 +154,9 @@ impl KernelSession {
         ctx.register_catalog(UC_RS_SYSTEM_CATALOG_NAME, catalog);
 
         Ok(Self {
-            extractors: Extractors::new(&ctx)?,
             ctx,
             factory: object_store_factory,
+            statistics: Mutex::new(StatisticsCache::new(STATISTICS_CACHE_CAPACITY)),
         })
     }
 
@@ -88,70 +164,223 @@ impl KernelSession {
         &self.ctx
     }
 
+    /// Statistics of the latest version of the Delta table at `location`.
+    ///
+    /// For a catalog-managed table, `ratified` are the coordinator's
+    /// ratified but unpublished commits and `max_catalog_version` its latest
+    /// version; the snapshot reads the former as its log tail.
+    pub(crate) async fn statistics(
+        &self,
+        location: &StorageLocationUrl,
+        ratified: &[CommitInfo],
+        max_catalog_version: Option<Version>,
+    ) -> Result<TableStatistics> {
+        let engine = build_engine(self.factory.as_ref(), location.location())
+            .await
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        let table_root = location.location().clone();
+        let log_tail = catalog_log_tail(&table_root, ratified)?;
+        let mut log = table_root.clone();
+        if !log.path().ends_with('/') {
+            log.set_path(&format!("{}/", log.path()));
+        }
+        let log = log
+            .join("_delta_log/")
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        let staged_commits: HashMap<i64, String> = ratified
+            .iter()
+            .map(|commit| (commit.version, commit.file_name.clone()))
+            .collect();
+        let cached = self
+            .statistics
+            .lock()
+            .expect("statistics cache poisoned")
+            .get(&table_root);
+        let root = table_root.clone();
+        let statistics = tokio::task::spawn_blocking(move || {
+            let mut builder = Snapshot::builder_for(root.as_str()).with_log_tail(log_tail);
+            if let Some(version) = max_catalog_version {
+                builder = builder.with_max_catalog_version(version);
+            }
+            let snapshot = builder
+                .build(engine.as_ref())
+                .map_err(|e| Error::Generic(e.to_string()))?;
+            if let Some(stats) = cached
+                && stats.version == snapshot.version() as i64
+            {
+                return Ok(stats);
+            }
+            let version = snapshot.version();
+            let commit_file = match staged_commits.get(&(version as i64)) {
+                Some(file_name) => log.join(&format!("_staged_commits/{file_name}")),
+                None => log.join(&format!("{version:020}.json")),
+            }
+            .map_err(|e| Error::Generic(e.to_string()))?;
+            compute_statistics(&snapshot, &commit_file, engine.as_ref())
+        })
+        .await
+        .map_err(|e| Error::Generic(e.to_string()))??;
+        self.statistics
+            .lock()
+            .expect("statistics cache poisoned")
+            .insert(table_root, statistics);
+        Ok(statistics)
+    }
+
     pub fn system_catalog(&self) -> Arc<dyn CatalogProvider> {
         self.ctx
             .catalog(UC_RS_SYSTEM_CATALOG_NAME)
             .expect("system catalog should be registered in kernel session")
     }
 
+    /// The snapshot of the Delta table at `location` at `version`, or at its
+    /// latest version when unset.
+    ///
+    /// For a catalog-managed table, `ratified` are the coordinator's ratified
+    /// but unpublished commits and `max_catalog_version` its latest version;
+    /// the snapshot reads the former as its log tail.
+    pub(crate) async fn catalog_snapshot(
+        &self,
+        location: &StorageLocationUrl,
+        ratified: &[CommitInfo],
+        max_catalog_version: Option<Version>,
+        version: Option<Version>,
+    ) -> Result<Arc<Snapshot>> {
+        let engine = build_engine(self.factory.as_ref(), location.location())
+            .await
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        let table_root = location.location().clone();
+        let log_tail = catalog_log_tail(&table_root, ratified)?;
+        tokio::task::spawn_blocking(move || {
+            let mut builder = Snapshot::builder_for(table_root.as_str()).with_log_tail(log_tail);
+            if let Some(latest) = max_catalog_version {
+                builder = builder.with_max_catalog_version(latest);
+            }
+            if let Some(version) = version {
+                builder = builder.at_version(version);
+            }
+            builder.build(engine.as_ref())
+        })
+        .await
+        .map_err(|e| Error::Generic(e.to_string()))?
+        .map_err(|e| Error::Generic(e.to_string()))
+    }
+
+    /// Stream the `file` actions of a shared table as NDJSON.
+    ///
+    /// The files are those of `snapshot` — the same snapshot whose protocol
+    /// and metadata open the response — and every file is checked against the
+    /// share's partition restrictions before it is emitted with a url
+    /// pre-signed by `signer`.
     pub(super) async fn extract_sharing_query_response(
         &self,
-        table_ref: &SharingTableReference,
         location: &StorageLocationUrl,
-    ) -> Result<Bytes> {
-        let log_replay_table_name = table_ref.system_table_name();
-        let inner_ref = DfTableReference::full(
-            UC_RS_SYSTEM_CATALOG_NAME,
-            UC_RS_LOG_REPLAY_SCHEMA_NAME,
-            log_replay_table_name,
-        );
-        if !self
-            .ctx
-            .table_exist(inner_ref.clone())
-            .map_err(|e| Error::Generic(e.to_string()))?
-        {
-            let engine = build_engine(self.factory.as_ref(), location.location())
-                .await
-                .map_err(|e| Error::Generic(e.to_string()))?;
-            self.ctx
-                .register_table(
-                    inner_ref.clone(),
-                    Arc::new(
-                        DeltaLogReplayProvider::new(location.location().clone(), engine)
-                            .map_err(|e| Error::Generic(e.to_string()))?,
-                    ),
-                )
-                .map_err(|e| Error::Generic(e.to_string()))?;
-        }
-        let table = self
-            .ctx
-            .table(inner_ref)
+        snapshot: Arc<Snapshot>,
+        policy: SharedTablePolicy,
+        signer: Arc<dyn Signer>,
+    ) -> Result<NdJsonStream> {
+        let signer = FileUrlSigner::new(signer, location.location());
+        let engine = build_engine(self.factory.as_ref(), location.location())
             .await
-            .map_err(|e| Error::Generic(e.to_string()))?
-            .collect()
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        // The replay is read once, by this query, so it is scanned without
+        // registering it on the long-lived session context.
+        let frame = self
+            .ctx
+            .read_table(Arc::new(DeltaLogReplayProvider::new(snapshot, engine)))
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        let stream = frame
+            .execute_stream()
             .await
             .map_err(|e| Error::Generic(e.to_string()))?;
-        let results: Vec<_> = table
-            .iter()
-            .map(|batch| {
-                let res = match self
-                    .extractors
-                    .sharing_pq_files
-                    .evaluate(batch)
-                    .map_err(|e| Error::Generic(e.to_string()))?
-                {
-                    ColumnarValue::Array(arr) => arr,
-                    ColumnarValue::Scalar(scalar) => scalar
-                        .to_array_of_size(batch.num_rows())
-                        .map_err(|e| Error::Generic(e.to_string()))?,
-                };
-                Ok::<_, Error>(RecordBatch::from(res.as_struct()))
-            })
-            .try_collect()?;
-        encode_nd_json(&results) // spellchecker:disable-line
+        let df_schema = DeltaLogReplayProvider::scan_row_schema()
+            .try_into()
+            .map_err(|_| Error::Generic("failed to convert schema".to_string()))?;
+        let extractor = self
+            .ctx
+            .create_physical_expr(sharing_file_action(), &df_schema)
+            .map_err(|e| Error::Generic(e.to_string()))?;
+        Ok(encode_sharing_files(stream, extractor, policy, signer))
     }
 }
 
+/// Project each log-replay batch onto the sharing `file` action, pre-sign its
+/// url and encode it as NDJSON as it is produced.
+///
+/// The returned stream is driven by the consumer (the HTTP response body), so
+/// log replay only advances as fast as the client reads instead of being
+/// collected into memory up front.
+fn encode_sharing_files(
+    batches: SendableRecordBatchStream,
+    extractor: Arc<dyn PhysicalExpr>,
+    policy: SharedTablePolicy,
+    signer: FileUrlSigner,
+) -> NdJsonStream {
+    batches
+        .map_err(|e| Error::Generic(e.to_string()))
+        .and_then(move |batch| {
+            let files = filter_shared_partitions(batch, &policy).and_then(|batch| match extractor
+                .evaluate(&batch)
+                .map_err(|e| Error::Generic(e.to_string()))?
+            {
+                ColumnarValue::Array(arr) => Ok(arr),
+                ColumnarValue::Scalar(scalar) => scalar
+                    .to_array_of_size(batch.num_rows())
+                    .map_err(|e| Error::Generic(e.to_string())),
+            });
+            let signer = signer.clone();
+            async move {
+                let files = files?;
+                if files.is_empty() {
+                    return Ok(Bytes::new());
+                }
+                let files = signer.sign(files.as_struct()).await?;
+                encode_nd_json(&[RecordBatch::from(&files)]) // spellchecker:disable-line
+            }
+        })
+        .try_filter(|chunk| futures::future::ready(!chunk.is_empty()))
+        .boxed()
+}
+
+/// Drop log-replay rows whose partition values fall outside the partitions the
+/// share exposes. A no-op when the shared table is not partition-restricted.
+fn filter_shared_partitions(batch: RecordBatch, policy: &SharedTablePolicy) -> Result<RecordBatch> {
+    if policy.partitions().is_none() {
+        return Ok(batch);
+    }
+    let missing = || Error::generic("log replay rows are missing partition values");
+    let partition_values = batch
+        .column_by_name("fileConstantValues")
+        .and_then(|col| col.as_struct_opt())
+        .and_then(|col| col.column_by_name("partitionValues"))
+        .and_then(|col| col.as_map_opt())
+        .ok_or_else(missing)?;
+
+    let mut mask = Vec::with_capacity(batch.num_rows());
+    for row in 0..batch.num_rows() {
+        if partition_values.is_null(row) {
+            mask.push(policy.allows_partition(|_| None));
+            continue;
+        }
+        let entries = partition_values.value(row);
+        let keys = entries
+            .column(0)
+            .as_string_opt::<i32>()
+            .ok_or_else(missing)?;
+        let values = entries
+            .column(1)
+            .as_string_opt::<i32>()
+            .ok_or_else(missing)?;
+        mask.push(policy.allows_partition(|column| {
+            (0..keys.len())
+                .find(|idx| keys.value(*idx) == column)
+                .map(|idx| (!values.is_null(idx)).then(|| values.value(idx)))
+        }));
+    }
+    filter_record_batch(&batch, &BooleanArray::from(mask))
+        .map_err(|e| Error::Generic(e.to_string()))
+}
+
 #[async_trait::async_trait]
 impl TableManager for KernelSession {
     async fn read_snapshot(
@@ -181,6 +410,15 @@ impl TableManager for KernelSession {
         .map_err(|e| Error::Generic(e.to_string()))?;
         Ok(snapshot)
     }
+
+    async fn table_statistics(
+        &self,
+        location: &StorageLocationUrl,
+        _table_id: Option<&str>,
+    ) -> Result<TableStatistics> {
+        // The session has no commit coordinator, so it only sees published commits.
+        self.statistics(location, &[], None).await
+    }
 }
 
 // spellchecker:ignore-next-line
@@ -193,3 +431,187 @@ pub fn encode_nd_json(data: &[RecordBatch]) -> Result<Bytes> {
     }
     Ok(Bytes::from(writer.into_inner()))
 }
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{MapBuilder, StringBuilder};
+    use datafusion::arrow::datatypes::Schema;
+    use datafusion::physical_expr::expressions::Column;
+    use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+
+    use super::*;
+
+    /// Signs urls as `https://storage.example/<path>?ttl=<seconds>`.
+    #[derive(Debug)]
+    struct FakeSigner;
+
+    #[async_trait::async_trait]
+    impl Signer for FakeSigner {
+        async fn signed_url(
+            &self,
+            _method: Method,
+            path: &Path,
+            expires_in: Duration,
+        ) -> object_store::Result<Url> {
+            Ok(Url::parse(&format!(
+                "https://storage.example/{path}?ttl={}",
+                expires_in.as_secs()
+            ))
+            .unwrap())
+        }
+    }
+
+    fn signer() -> FileUrlSigner {
+        let root = Url::parse("abfss://container@account.dfs.core.windows.net/table").unwrap();
+        FileUrlSigner::new(Arc::new(FakeSigner), &root)
+    }
+
+    fn action_batch(schema: &Arc<Schema>, ids: Vec<&str>) -> RecordBatch {
+        let id: ArrayRef = Arc::new(StringArray::from(ids));
+        let file = StructArray::from(vec![(
+            Arc::new(Field::new("id", DataType::Utf8, false)),
+            id,
+        )]);
+        let action = StructArray::from(vec![(
+            Arc::new(Field::new("file", file.data_type().clone(), false)),
+            Arc::new(file) as ArrayRef,
+        )]);
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(action)]).unwrap()
+    }
+
+    fn lines(chunk: &Bytes) -> Vec<serde_json::Value> {
+        serde_json::Deserializer::from_slice(chunk)
+            .into_iter()
+            .map(|line| line.unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn encodes_one_chunk_per_non_empty_batch() {
+        let file_type = DataType::Struct(vec![Field::new("id", DataType::Utf8, false)].into());
+        let action_type = DataType::Struct(vec![Field::new("file", file_type, false)].into());
+        let schema = Arc::new(Schema::new(vec![Field::new("action", action_type, false)]));
+        let batches = vec![
+            Ok(action_batch(&schema, vec!["a", "b"])),
+            Ok(action_batch(&schema, vec![])),
+            Ok(action_batch(&schema, vec!["c"])),
+        ];
+        let input = Box::pin(RecordBatchStreamAdapter::new(
+            schema.clone(),
+            futures::stream::iter(batches),
+        ));
+        let extractor: Arc<dyn PhysicalExpr> = Arc::new(Column::new("action", 0));
+
+        let chunks: Vec<Bytes> =
+            encode_sharing_files(input, extractor, Default::default(), signer())
+                .try_collect()
+                .await
+                .unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        let ids = |chunk| {
+            lines(chunk)
+                .iter()
+                .map(|line| line["file"]["id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&chunks[0]), vec!["a", "b"]);
+        assert_eq!(ids(&chunks[1]), vec!["c"]);
+    }
+
+    #[tokio::test]
+    async fn file_actions_carry_presigned_urls() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+        builder.keys().append_value("region");
+        builder.values().append_value("eu");
+        builder.append(true).unwrap();
+        let partition_values: ArrayRef = Arc::new(builder.finish());
+        let constants = StructArray::from(vec![(
+            Arc::new(Field::new(
+                "partitionValues",
+                partition_values.data_type().clone(),
+                true,
+            )),
+            partition_values,
+        )]);
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "path",
+                Arc::new(StringArray::from(vec!["region=eu/part%20-0.parquet"])) as ArrayRef,
+            ),
+            ("size", Arc::new(Int64Array::from(vec![42])) as ArrayRef),
+            ("fileConstantValues", Arc::new(constants) as ArrayRef),
+        ])
+        .unwrap();
+
+        let ctx = SessionContext::new();
+        let files = ctx
+            .read_batch(batch)
+            .unwrap()
+            .select(vec![sharing_file_action().alias("action")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let before = chrono::Utc::now().timestamp_millis();
+        let files = signer().sign(files[0].column(0).as_struct()).await.unwrap();
+        let mut line = lines(&encode_nd_json(&[RecordBatch::from(&files)]).unwrap()).remove(0);
+
+        let expires = line["file"]
+            .as_object_mut()
+            .unwrap()
+            .remove("expirationTimestamp")
+            .unwrap()
+            .as_i64()
+            .unwrap();
+        assert!(expires >= before + PRESIGNED_URL_TTL.as_millis() as i64);
+        assert_eq!(
+            line,
+            serde_json::json!({"file": {
+                "url": "https://storage.example/table/region=eu/part%20-0.parquet?ttl=3600",
+                "id": "region=eu/part%20-0.parquet",
+                "partitionValues": {"region": "eu"},
+                "size": 42,
+            }})
+        );
+    }
+
+    #[test]
+    fn filters_rows_outside_shared_partitions() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+        for region in ["eu", "us", "eu"] {
+            builder.keys().append_value("region");
+            builder.values().append_value(region);
+            builder.append(true).unwrap();
+        }
+        let partition_values: ArrayRef = Arc::new(builder.finish());
+        let constants = StructArray::from(vec![(
+            Arc::new(Field::new(
+                "partitionValues",
+                partition_values.data_type().clone(),
+                true,
+            )),
+            partition_values,
+        )]);
+        let size: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("size", size),
+            ("fileConstantValues", Arc::new(constants) as ArrayRef),
+        ])
+        .unwrap();
+
+        let object = unitycatalog_common::models::shares::v1::DataObject {
+            partitions: vec!["region = 'eu'".to_string()],
+            ..Default::default()
+        };
+        let policy = SharedTablePolicy::try_from_data_object(&object).unwrap();
+        let filtered = filter_shared_partitions(batch.clone(), &policy).unwrap();
+        let sizes = filtered
+            .column(0)
+            .as_primitive::<datafusion::arrow::datatypes::Int64Type>();
+        assert_eq!(sizes.values().to_vec(), vec![1, 3]);
+
+        let unrestricted = filter_shared_partitions(batch, &SharedTablePolicy::default()).unwrap();
+        assert_eq!(unrestricted.num_rows(), 3);
+    }
+}
@@ -0,0 +1,486 @@
+//! Provider-side controls on what a recipient may read from a shared table.
+//!
+//! A table added to a share carries a handful of settings on its
+//! [`DataObject`] that narrow what the sharing server hands out:
+//!
+//! - `partitions` — only files whose partition values match one of the listed
+//!   partition specifications are served. This is an access restriction, not a
+//!   pruning hint, so it is enforced on every query response.
+//! - `history_data_sharing_status` — whether recipients may query versions
+//!   other than the latest one (time travel and change data feed).
+//! - `start_version` — the lowest table version a recipient may query.
+//! - `enable_cdf` — whether change-data-feed queries are allowed at all.
+//!
+//! [`SharedTablePolicy`] captures those settings once per request and decides
+//! which snapshot a [`QueryTableRequest`] resolves to.
+//!
+//! # Partition specifications
+//!
+//! Each entry in `partitions` uses the Databricks SQL `PARTITION` clause
+//! syntax, with or without the `PARTITION ( ... )` wrapper:
+//!
+//! ```text
+//! PARTITION (year = 2024, region = 'eu-west')
+//! country = 'DE'
+//! region IS NULL
+//! ```
+//!
+//! Conditions within one specification are AND-ed; the specifications of an
+//! object are OR-ed. Values are compared against the serialized Delta partition
+//! values, so `year = 2024` and `year = '2024'` are equivalent. A file that has
+//! no value for a referenced column never matches, so a specification naming a
+//! column the table is not partitioned by shares nothing rather than everything.
+
+use delta_kernel::Version;
+use unitycatalog_common::models::shares::v1::{DataObject, HistoryStatus};
+use unitycatalog_sharing_client::models::open_sharing::v1::QueryTableRequest;
+
+use crate::{Error, Result};
+
+/// A single `column = value` (or `column IS NULL`) condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartitionCondition {
+    column: String,
+    /// `None` matches a null partition value.
+    value: Option<String>,
+}
+
+/// One entry of a shared table's `partitions` list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionSpec {
+    conditions: Vec<PartitionCondition>,
+}
+
+impl PartitionSpec {
+    /// Parse a partition specification (see the [module docs](self)).
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            Error::invalid_argument(format!(
+                "invalid partition specification '{spec}': {reason}"
+            ))
+        };
+
+        let mut body = spec.trim();
+        if body
+            .get(..9)
+            .is_some_and(|kw| kw.eq_ignore_ascii_case("partition"))
+        {
+            let inner = body[9..].trim_start();
+            body = inner
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .ok_or_else(|| invalid("expected PARTITION ( ... )"))?
+                .trim();
+        }
+
+        let conditions = split_conditions(body)
+            .map_err(invalid)?
+            .into_iter()
+            .map(|cond| parse_condition(cond).map_err(invalid))
+            .collect::<Result<Vec<_>>>()?;
+        if conditions.is_empty() {
+            return Err(invalid("no partition conditions"));
+        }
+        Ok(Self { conditions })
+    }
+
+    /// Whether a file with the given partition values is covered by this spec.
+    ///
+    /// `lookup` returns `None` when the file has no entry for a column and
+    /// `Some(None)` when the entry is present but null.
+    pub fn matches<'a>(&self, lookup: impl Fn(&str) -> Option<Option<&'a str>>) -> bool {
+        self.conditions
+            .iter()
+            .all(|cond| match lookup(&cond.column) {
+                Some(actual) => actual == cond.value.as_deref(),
+                None => false,
+            })
+    }
+}
+
+/// Split a spec body on top-level commas, leaving commas in quoted values alone.
+fn split_conditions(body: &str) -> std::result::Result<Vec<&str>, &'static str> {
+    let mut parts = Vec::new();
+    let mut in_quote = false;
+    let mut start = 0;
+    for (idx, ch) in body.char_indices() {
+        match ch {
+            '\'' => in_quote = !in_quote,
+            ',' if !in_quote => {
+                parts.push(body[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if in_quote {
+        return Err("unterminated string literal");
+    }
+    let last = body[start..].trim();
+    if !last.is_empty() || !parts.is_empty() {
+        parts.push(last);
+    }
+    if parts.iter().any(|p| p.is_empty()) {
+        return Err("empty partition condition");
+    }
+    Ok(parts)
+}
+
+fn parse_condition(cond: &str) -> std::result::Result<PartitionCondition, &'static str> {
+    if let Some((column, value)) = cond.split_once('=') {
+        let column = parse_column(column)?;
+        let value = value.trim();
+        let value = if let Some(quoted) = value.strip_prefix('\'') {
+            let quoted = quoted
+                .strip_suffix('\'')
+                .ok_or("unterminated string literal")?;
+            Some(quoted.replace("''", "'"))
+        } else if value.eq_ignore_ascii_case("null") {
+            None
+        } else if !value.is_empty() && !value.contains(char::is_whitespace) {
+            Some(value.to_string())
+        } else {
+            return Err("expected a literal partition value");
+        };
+        return Ok(PartitionCondition { column, value });
+    }
+
+    let lower = cond.to_ascii_lowercase();
+    if let Some(column) = lower.strip_suffix("is null") {
+        let column = parse_column(&cond[..column.len()])?;
+        return Ok(PartitionCondition {
+            column,
+            value: None,
+        });
+    }
+    Err("expected `column = value` or `column IS NULL`")
+}
+
+fn parse_column(column: &str) -> std::result::Result<String, &'static str> {
+    let column = column.trim();
+    let column = column
+        .strip_prefix('`')
+        .and_then(|c| c.strip_suffix('`'))
+        .unwrap_or(column);
+    if column.is_empty() || column.contains(char::is_whitespace) {
+        return Err("expected a partition column name");
+    }
+    Ok(column.to_string())
+}
+
+/// The table version a sharing query resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedTableVersion {
+    /// The latest version of the table.
+    Latest,
+    /// A specific historical version.
+    At(Version),
+}
+
+impl SharedTableVersion {
+    pub fn version(&self) -> Option<Version> {
+        match self {
+            Self::Latest => None,
+            Self::At(version) => Some(*version),
+        }
+    }
+}
+
+/// The sharing settings of one table object in a share.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SharedTablePolicy {
+    partitions: Vec<PartitionSpec>,
+    history_shared: bool,
+    start_version: Option<Version>,
+    cdf_enabled: bool,
+}
+
+impl SharedTablePolicy {
+    pub fn try_from_data_object(object: &DataObject) -> Result<Self> {
+        let partitions = object
+            .partitions
+            .iter()
+            .map(|spec| PartitionSpec::parse(spec))
+            .collect::<Result<_>>()?;
+        let start_version = object
+            .start_version
+            .map(|v| {
+                Version::try_from(v).map_err(|_| {
+                    Error::invalid_argument(format!("start_version must be >= 0, got {v}"))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            partitions,
+            history_shared: matches!(object.history_data_sharing_status(), HistoryStatus::Enabled),
+            start_version,
+            cdf_enabled: object.enable_cdf.unwrap_or(false),
+        })
+    }
+
+    /// The sharing settings of a view object.
+    ///
+    /// Views are served from a materialized snapshot of their latest result
+    /// (see `services::shared_view`), so settings that refer to table history
+    /// or partition layout cannot be honoured and are rejected.
+    pub fn try_from_view_object(object: &DataObject) -> Result<Self> {
+        let unsupported = |setting: &str| {
+            Err(Error::invalid_argument(format!(
+                "{setting} is not supported for shared view '{}'",
+                object.name
+            )))
+        };
+        if !object.partitions.is_empty() {
+            return unsupported("partitions");
+        }
+        if object.enable_cdf.unwrap_or(false) {
+            return unsupported("enable_cdf");
+        }
+        if matches!(object.history_data_sharing_status(), HistoryStatus::Enabled) {
+            return unsupported("history sharing");
+        }
+        if object.start_version.is_some() {
+            return unsupported("start_version");
+        }
+        Ok(Self::default())
+    }
+
+    /// The partition specifications a served file must match, if restricted.
+    pub fn partitions(&self) -> Option<&[PartitionSpec]> {
+        (!self.partitions.is_empty()).then_some(self.partitions.as_slice())
+    }
+
+    /// Whether a file with the given partition values may be served.
+    pub fn allows_partition<'a>(&self, lookup: impl Fn(&str) -> Option<Option<&'a str>>) -> bool {
+        self.partitions.is_empty() || self.partitions.iter().any(|spec| spec.matches(&lookup))
+    }
+
+    /// Resolve the snapshot a query request reads, rejecting requests the
+    /// share does not permit.
+    pub fn resolve_query(&self, request: &QueryTableRequest) -> Result<SharedTableVersion> {
+        if request.starting_version.is_some() || request.ending_version.is_some() {
+            if !self.cdf_enabled {
+                return Err(Error::invalid_argument(
+                    "change data feed is not enabled for this shared table",
+                ));
+            }
+            self.require_history()?;
+            if let Some(start) = request.starting_version {
+                self.check_version(start)?;
+            }
+            return Err(Error::NotImplemented(
+                "Delta Sharing: change data feed queries",
+            ));
+        }
+
+        if request.timestamp.is_some() || request.starting_timestamp.is_some() {
+            self.require_history()?;
+            return Err(Error::NotImplemented("Delta Sharing: timestamp queries"));
+        }
+
+        match request.version {
+            Some(version) => {
+                self.require_history()?;
+                Ok(SharedTableVersion::At(self.check_version(version)?))
+            }
+            None => Ok(SharedTableVersion::Latest),
+        }
+    }
+
+    fn require_history(&self) -> Result<()> {
+        if self.history_shared {
+            Ok(())
+        } else {
+            Err(Error::invalid_argument(
+                "history sharing is not enabled for this shared table",
+            ))
+        }
+    }
+
+    fn check_version(&self, version: i64) -> Result<Version> {
+        let version = Version::try_from(version)
+            .map_err(|_| Error::invalid_argument(format!("invalid table version {version}")))?;
+        match self.start_version {
+            Some(start) if version < start => Err(Error::invalid_argument(format!(
+                "version {version} is before the shared start version {start}"
+            ))),
+            _ => Ok(version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use unitycatalog_common::models::shares::v1::DataObjectType;
+
+    use super::*;
+
+    fn lookup<'a>(
+        values: &'a HashMap<&'a str, Option<&'a str>>,
+    ) -> impl Fn(&str) -> Option<Option<&'a str>> {
+        move |column| values.get(column).copied()
+    }
+
+    #[test]
+    fn parses_partition_clause_and_bare_conditions() {
+        let spec = PartitionSpec::parse("PARTITION (year = 2024, region = 'eu, west')").unwrap();
+        assert_eq!(
+            spec.conditions,
+            vec![
+                PartitionCondition {
+                    column: "year".to_string(),
+                    value: Some("2024".to_string()),
+                },
+                PartitionCondition {
+                    column: "region".to_string(),
+                    value: Some("eu, west".to_string()),
+                },
+            ]
+        );
+
+        let spec = PartitionSpec::parse("`country` IS NULL").unwrap();
+        assert_eq!(
+            spec.conditions,
+            vec![PartitionCondition {
+                column: "country".to_string(),
+                value: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_specs() {
+        for spec in [
+            "",
+            "PARTITION ()",
+            "PARTITION (year = 2024",
+            "year > 2024",
+            "region = 'eu",
+            "year = 2024,",
+        ] {
+            assert!(
+                PartitionSpec::parse(spec).is_err(),
+                "{spec} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn partition_specs_are_or_of_ands() {
+        let object = DataObject {
+            partitions: vec![
+                "year = 2024, region = 'eu'".to_string(),
+                "year = 2023".to_string(),
+            ],
+            ..Default::default()
+        };
+        let policy = SharedTablePolicy::try_from_data_object(&object).unwrap();
+
+        let eu_2024 = HashMap::from([("year", Some("2024")), ("region", Some("eu"))]);
+        let us_2024 = HashMap::from([("year", Some("2024")), ("region", Some("us"))]);
+        let us_2023 = HashMap::from([("year", Some("2023")), ("region", Some("us"))]);
+        let unpartitioned = HashMap::new();
+        assert!(policy.allows_partition(lookup(&eu_2024)));
+        assert!(!policy.allows_partition(lookup(&us_2024)));
+        assert!(policy.allows_partition(lookup(&us_2023)));
+        assert!(!policy.allows_partition(lookup(&unpartitioned)));
+
+        let open = SharedTablePolicy::default();
+        assert!(open.partitions().is_none());
+        assert!(open.allows_partition(lookup(&unpartitioned)));
+    }
+
+    #[test]
+    fn version_queries_require_history_and_respect_start_version() {
+        let request = QueryTableRequest {
+            version: Some(3),
+            ..Default::default()
+        };
+        let latest = QueryTableRequest::default();
+
+        let no_history = SharedTablePolicy::default();
+        assert_eq!(
+            no_history.resolve_query(&latest).unwrap(),
+            SharedTableVersion::Latest
+        );
+        assert!(no_history.resolve_query(&request).is_err());
+
+        let object = DataObject {
+            history_data_sharing_status: Some(HistoryStatus::Enabled as i32),
+            start_version: Some(2),
+            ..Default::default()
+        };
+        let policy = SharedTablePolicy::try_from_data_object(&object).unwrap();
+        assert_eq!(
+            policy.resolve_query(&request).unwrap(),
+            SharedTableVersion::At(3)
+        );
+        let too_old = QueryTableRequest {
+            version: Some(1),
+            ..Default::default()
+        };
+        assert!(policy.resolve_query(&too_old).is_err());
+    }
+
+    #[test]
+    fn cdf_queries_require_cdf_flag() {
+        let request = QueryTableRequest {
+            starting_version: Some(0),
+            ..Default::default()
+        };
+        let object = DataObject {
+            history_data_sharing_status: Some(HistoryStatus::Enabled as i32),
+            ..Default::default()
+        };
+        let policy = SharedTablePolicy::try_from_data_object(&object).unwrap();
+        assert!(matches!(
+            policy.resolve_query(&request),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let policy = SharedTablePolicy {
+            cdf_enabled: true,
+            ..policy
+        };
+        assert!(matches!(
+            policy.resolve_query(&request),
+            Err(Error::NotImplemented(_))
+        ));
+    }
+
+    #[test]
+    fn views_only_share_their_latest_result() {
+        let view = DataObject {
+            name: "cat.sch.v".to_string(),
+            data_object_type: DataObjectType::View as i32,
+            ..Default::default()
+        };
+        let policy = SharedTablePolicy::try_from_view_object(&view).unwrap();
+        assert_eq!(
+            policy.resolve_query(&QueryTableRequest::default()).unwrap(),
+            SharedTableVersion::Latest
+        );
+
+        for object in [
+            DataObject {
+                partitions: vec!["year = 2024".to_string()],
+                ..view.clone()
+            },
+            DataObject {
+                history_data_sharing_status: Some(HistoryStatus::Enabled as i32),
+                ..view.clone()
+            },
+            DataObject {
+                start_version: Some(1),
+                ..view.clone()
+            },
+        ] {
+            assert!(matches!(
+                SharedTablePolicy::try_from_view_object(&object),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,725 @@
+//! Serving shared SQL views through the Delta Sharing protocol.
+//!
+//! Delta Sharing recipients only read Delta tables, so a `VIEW` data object is
+//! served from a materialized snapshot: the server evaluates the view's SQL
+//! with DataFusion against the Delta tables it depends on and writes the result
+//! as a Delta table under the view schema's managed storage:
+//!
+//! ```text
+//! <managed parent>/shared_views/<view_id>/<fingerprint>
+//! ```
+//!
+//! The fingerprint covers the view definition and the version of every source
+//! table, so a materialization is reused until a source table commits (or the
+//! view is redefined) and recipients never observe a half-refreshed view.
+//! Replicas racing on the same fingerprint are resolved by the Delta log
+//! itself: only one writer can commit each version, and the loser adopts the
+//! winner's snapshot.
+//!
+//! Sources are read through a [`DeltaScanProvider`] at their pinned version, so
+//! only the columns and files the view needs are read, and the result is
+//! streamed into the new table rather than collected. A superseded
+//! materialization is deleted once its successor is older than
+//! [`SUPERSEDED_RETENTION`], leaving recipients time to finish reading it.
+//!
+//! Each time a different materialization starts serving the view, a pointer
+//! `_versions/<n>.json` naming its fingerprint is created next to the
+//! materializations, and `n` is the version reported to recipients (see
+//! [`MaterializedView::version`]) in the version endpoint and in the metadata
+//! of the metadata and query responses alike. Pointers are created with
+//! put-if-absent, so the version only ever grows: on every source commit and
+//! on every redefinition of the view. Views do not expose history.
+//!
+//! The version endpoint never materializes: it reports the latest pointer and,
+//! when that is stale, refreshes the view in the background. The metadata and
+//! query endpoints materialize on demand, since they must serve current data.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use datafusion::catalog::{CatalogProvider, MemoryCatalogProvider, MemorySchemaProvider};
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::prelude::SessionContext;
+use delta_kernel::committer::FileSystemCommitter;
+use delta_kernel::engine::arrow_conversion::TryIntoKernel as _;
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::engine::default::DefaultEngineBuilder;
+use delta_kernel::schema::StructType;
+use delta_kernel::transaction::CommitResult;
+use delta_kernel::transaction::create_table::create_table;
+use delta_kernel::{Snapshot, Version};
+use futures::TryStreamExt;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStoreExt, PutMode};
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, Table, TableType, dependency};
+
+use super::kernel::{DeltaScanProvider, ObjectStoreFactory, build_engine};
+use super::location::StorageLocationUrl;
+use super::{ProvidesManagedStorageRoot, ServerHandler, TableManager};
+use crate::api::RequestContext;
+use crate::api::staging_tables::{child_location, managed_prefix, resolve_managed_parent_location};
+use crate::{Error, Result};
+
+/// Engine identifier recorded in the `commitInfo` of materialized snapshots.
+const ENGINE_INFO: &str = concat!("unitycatalog-rs/", env!("CARGO_PKG_VERSION"));
+
+/// How long a superseded materialization outlives the one that replaced it.
+const SUPERSEDED_RETENTION: TimeDelta = TimeDelta::hours(24);
+
+/// Directory, next to the materializations, holding the version pointers.
+const VERSIONS_DIR: &str = "_versions";
+
+/// How many times publishing a version pointer is retried after losing a race
+/// with another replica before the request fails.
+const PUBLISH_ATTEMPTS: usize = 3;
+
+/// Materializations being refreshed in the background by this process, keyed
+/// by target location, so repeated version probes start only one refresh.
+static REFRESHING: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// A shared view resolved to the snapshot that currently backs it.
+#[derive(Debug, Clone)]
+pub(super) struct MaterializedView {
+    /// Root of the materialized Delta table.
+    pub(super) location: StorageLocationUrl,
+    /// Fingerprint of the definition and source versions the snapshot reflects.
+    pub(super) fingerprint: String,
+    /// Version reported to recipients: the number of the pointer that made
+    /// this materialization current. The materialized table's own version is
+    /// an implementation detail.
+    pub(super) version: Version,
+}
+
+/// The materialization a view needs for its current definition and sources.
+struct ViewPlan {
+    definition: String,
+    sources: Vec<ViewSource>,
+    fingerprint: String,
+    /// Directory holding every materialization of the view.
+    materializations: StorageLocationUrl,
+}
+
+impl ViewPlan {
+    /// Root of the materialization for [`fingerprint`](Self::fingerprint).
+    fn target(&self) -> Result<StorageLocationUrl> {
+        StorageLocationUrl::parse(format!(
+            "{}/{}",
+            self.materializations
+                .location()
+                .as_str()
+                .trim_end_matches('/'),
+            self.fingerprint
+        ))
+    }
+}
+
+/// A source table of a view, pinned to the version it is read at.
+#[derive(Debug, Clone)]
+struct ViewSource {
+    full_name: String,
+    location: StorageLocationUrl,
+    version: Version,
+}
+
+impl ServerHandler<RequestContext> {
+    /// Resolve the materialized snapshot backing a shared view, materializing
+    /// it first if no snapshot exists for the current source versions.
+    pub(super) async fn materialize_shared_view(
+        &self,
+        view: &Table,
+        context: &RequestContext,
+    ) -> Result<MaterializedView> {
+        for _ in 0..PUBLISH_ATTEMPTS {
+            let plan = self.plan_view(view, context).await?;
+            let target = plan.target()?;
+            let latest = self.latest_view_version(&plan.materializations).await?;
+            if let Some((version, fingerprint)) = &latest
+                && *fingerprint == plan.fingerprint
+            {
+                return Ok(MaterializedView {
+                    location: target,
+                    fingerprint: plan.fingerprint,
+                    version: *version,
+                });
+            }
+
+            if !self.is_materialized(&target).await? {
+                tracing::debug!(
+                    view = %view.full_name, fingerprint = %plan.fingerprint,
+                    "materializing shared view"
+                );
+                let rows = self.evaluate_view(&plan.definition, &plan.sources).await?;
+                self.write_materialization(&target, rows).await?;
+            }
+            let version = latest.map_or(1, |(version, _)| version + 1);
+            if !self
+                .publish_view_version(&plan.materializations, version, &plan.fingerprint)
+                .await?
+            {
+                // Another replica published this version first; start over
+                // from its pointer.
+                continue;
+            }
+            if let Err(error) = self
+                .prune_materializations(&plan.materializations, &plan.fingerprint)
+                .await
+            {
+                tracing::warn!(
+                    view = %view.full_name, %error,
+                    "failed to delete superseded shared view materializations"
+                );
+            }
+            return Ok(MaterializedView {
+                location: target,
+                fingerprint: plan.fingerprint,
+                version,
+            });
+        }
+        Err(Error::generic(format!(
+            "gave up refreshing shared view '{}' after losing {PUBLISH_ATTEMPTS} races \
+             with other replicas",
+            view.full_name
+        )))
+    }
+
+    /// The version of a shared view recipients currently see, without
+    /// materializing it.
+    ///
+    /// When the latest materialization is stale, a refresh is started in the
+    /// background and the current version is reported until it lands. A view
+    /// that was never materialized is at version 0.
+    pub(super) async fn shared_view_version(
+        &self,
+        view: &Table,
+        context: &RequestContext,
+    ) -> Result<Version> {
+        let plan = self.plan_view(view, context).await?;
+        let latest = self.latest_view_version(&plan.materializations).await?;
+        if latest
+            .as_ref()
+            .is_none_or(|(_, fingerprint)| *fingerprint != plan.fingerprint)
+        {
+            self.refresh_in_background(view, context, &plan)?;
+        }
+        Ok(latest.map_or(0, |(version, _)| version))
+    }
+
+    /// Materialize `view` on a background task unless this process is
+    /// already doing so for the same target.
+    fn refresh_in_background(
+        &self,
+        view: &Table,
+        context: &RequestContext,
+        plan: &ViewPlan,
+    ) -> Result<()> {
+        let key = plan.target()?.location().to_string();
+        let refreshing = REFRESHING.get_or_init(Default::default);
+        if !refreshing
+            .lock()
+            .expect("refresh registry poisoned")
+            .insert(key.clone())
+        {
+            return Ok(());
+        }
+        let (handler, view, context) = (self.clone(), view.clone(), context.clone());
+        tokio::spawn(async move {
+            if let Err(error) = handler.materialize_shared_view(&view, &context).await {
+                tracing::warn!(view = %view.full_name, %error, "failed to refresh shared view");
+            }
+            refreshing
+                .lock()
+                .expect("refresh registry poisoned")
+                .remove(&key);
+        });
+        Ok(())
+    }
+
+    /// Pin the sources of a view at their latest versions and locate the
+    /// materialization that reflects them.
+    async fn plan_view(&self, view: &Table, context: &RequestContext) -> Result<ViewPlan> {
+        let view_definition = view.view_definition.as_deref().ok_or_else(|| {
+            Error::generic(format!("view '{}' has no definition", view.full_name))
+        })?;
+        let view_id = view
+            .table_id
+            .as_deref()
+            .ok_or_else(|| Error::generic(format!("view '{}' has no id", view.full_name)))?;
+
+        let mut sources = Vec::new();
+        for full_name in view_source_names(view) {
+            let table = self.lookup_table(&full_name, context).await?;
+            if table.table_type() == TableType::View {
+                return Err(Error::invalid_argument(format!(
+                    "shared view '{}' reads view '{full_name}'; only views over tables can be shared",
+                    view.full_name
+                )));
+            }
+            if table.data_source_format() != DataSourceFormat::Delta {
+                return Err(Error::invalid_argument(format!(
+                    "shared view '{}' reads non-Delta table '{full_name}'",
+                    view.full_name
+                )));
+            }
+            let location = table.storage_location.as_deref().ok_or_else(|| {
+                Error::generic(format!("table '{full_name}' has no storage location"))
+            })?;
+            let location = StorageLocationUrl::parse(location)?;
+            let snapshot = self
+                .read_snapshot(&location, &DataSourceFormat::Delta, None)
+                .await?;
+            sources.push(ViewSource {
+                full_name,
+                location,
+                version: snapshot.version(),
+            });
+        }
+
+        let parent = self
+            .shared_view_parent_location(&view.catalog_name, &view.schema_name)
+            .await?;
+        Ok(ViewPlan {
+            definition: view_definition.to_string(),
+            fingerprint: fingerprint(view_id, view_definition, &sources),
+            sources,
+            materializations: StorageLocationUrl::parse(child_location(
+                &parent,
+                "shared_views",
+                view_id,
+            ))?,
+        })
+    }
+
+    /// Managed location under which a view's materializations are written.
+    ///
+    /// In the side-by-side topology the view's schema lives in the upstream
+    /// catalog, so the metastore-level managed root is used instead.
+    async fn shared_view_parent_location(&self, catalog: &str, schema: &str) -> Result<String> {
+        if self.table_source().is_some() {
+            let root = self.managed_storage_root().ok_or_else(|| {
+                Error::invalid_argument("sharing views requires a managed storage root")
+            })?;
+            return Ok(managed_prefix(root));
+        }
+        resolve_managed_parent_location(self, catalog, schema).await
+    }
+
+    /// The latest version pointer of a view and the fingerprint it names, or
+    /// `None` if no materialization was ever published.
+    async fn latest_view_version(
+        &self,
+        materializations: &StorageLocationUrl,
+    ) -> Result<Option<(Version, String)>> {
+        let store = self
+            .handler
+            .create_object_store(materializations.location())
+            .await
+            .map_err(df_error)?;
+        let versions = Path::from_url_path(materializations.location().path())
+            .map_err(df_error)?
+            .child(VERSIONS_DIR);
+        let pointers: Vec<ObjectMeta> = store
+            .list(Some(&versions))
+            .try_collect()
+            .await
+            .map_err(df_error)?;
+        let Some((version, location)) = pointers
+            .into_iter()
+            .filter_map(|meta| {
+                let version = meta
+                    .location
+                    .filename()?
+                    .strip_suffix(".json")?
+                    .parse()
+                    .ok()?;
+                Some((version, meta.location))
+            })
+            .max_by_key(|(version, _)| *version)
+        else {
+            return Ok(None);
+        };
+        let bytes = store
+            .get(&location)
+            .await
+            .map_err(df_error)?
+            .bytes()
+            .await
+            .map_err(df_error)?;
+        let pointer: serde_json::Value = serde_json::from_slice(&bytes)?;
+        let fingerprint = pointer["fingerprint"].as_str().ok_or_else(|| {
+            Error::generic(format!(
+                "shared view version pointer '{location}' is malformed"
+            ))
+        })?;
+        Ok(Some((version, fingerprint.to_string())))
+    }
+
+    /// Create the pointer making `fingerprint` the materialization served at
+    /// `version`. Returns `false` if another replica created it first.
+    async fn publish_view_version(
+        &self,
+        materializations: &StorageLocationUrl,
+        version: Version,
+        fingerprint: &str,
+    ) -> Result<bool> {
+        let store = self
+            .handler
+            .create_object_store(materializations.location())
+            .await
+            .map_err(df_error)?;
+        let pointer = Path::from_url_path(materializations.location().path())
+            .map_err(df_error)?
+            .child(VERSIONS_DIR)
+            .child(format!("{version:020}.json"));
+        let body = serde_json::to_vec(&serde_json::json!({ "fingerprint": fingerprint }))?;
+        match store
+            .put_opts(&pointer, body.into(), PutMode::Create.into())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+            Err(e) => Err(df_error(e)),
+        }
+    }
+
+    /// Whether a complete materialization exists at `target`.
+    ///
+    /// Version 0 only creates the table; the data lands in version 1, so a
+    /// materialization is complete once its version 1 commit exists.
+    async fn is_materialized(&self, target: &StorageLocationUrl) -> Result<bool> {
+        let store = self
+            .handler
+            .create_object_store(target.location())
+            .await
+            .map_err(df_error)?;
+        let data_commit = Path::from_url_path(target.location().path())
+            .map_err(df_error)?
+            .child("_delta_log")
+            .child(format!("{:020}.json", 1));
+        match store.head(&data_commit).await {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(df_error(e)),
+        }
+    }
+
+    /// Run the view query against the pinned source snapshots, streaming its
+    /// result.
+    async fn evaluate_view(
+        &self,
+        view_definition: &str,
+        sources: &[ViewSource],
+    ) -> Result<SendableRecordBatchStream> {
+        let ctx = SessionContext::new();
+        for source in sources {
+            let provider = self.source_provider(source).await?;
+            register_source(&ctx, &source.full_name, Arc::new(provider))?;
+        }
+        let frame = ctx.sql(view_definition).await.map_err(df_error)?;
+        frame.execute_stream().await.map_err(df_error)
+    }
+
+    /// A provider reading a source table at its pinned version.
+    async fn source_provider(&self, source: &ViewSource) -> Result<DeltaScanProvider> {
+        let engine = build_engine(self.handler.as_ref(), source.location.location())
+            .await
+            .map_err(df_error)?;
+        let table_root = source.location.location().clone();
+        let version = source.version;
+        let snapshot_engine = engine.clone();
+        let snapshot = tokio::task::spawn_blocking(move || {
+            Snapshot::builder_for(table_root.as_str())
+                .at_version(version)
+                .build(snapshot_engine.as_ref())
+        })
+        .await
+        .map_err(|e| Error::Generic(e.to_string()))?
+        .map_err(|e| Error::Generic(e.to_string()))?;
+        DeltaScanProvider::try_new(engine, snapshot).map_err(df_error)
+    }
+
+    /// Write a view result as a new Delta table at `target`.
+    ///
+    /// Version 0 creates the table and version 1 adds the data. Either commit
+    /// losing to a concurrent replica is fine: the winner wrote the same
+    /// result for the same fingerprint.
+    async fn write_materialization(
+        &self,
+        target: &StorageLocationUrl,
+        mut rows: SendableRecordBatchStream,
+    ) -> Result<()> {
+        let kernel_error = |e: delta_kernel::Error| Error::Generic(e.to_string());
+        let store = self
+            .handler
+            .create_object_store(target.location())
+            .await
+            .map_err(df_error)?;
+        let engine = DefaultEngineBuilder::new(store).build();
+        let table_root = target.location().as_str();
+
+        let kernel_schema: StructType = rows
+            .schema()
+            .as_ref()
+            .try_into_kernel()
+            .map_err(kernel_error)?;
+        let created = create_table(table_root, Arc::new(kernel_schema), ENGINE_INFO)
+            .build(&engine, Box::new(FileSystemCommitter::new()))
+            .and_then(|txn| txn.commit(&engine))
+            .map_err(kernel_error)?;
+        if let CommitResult::RetryableTransaction(txn) = created {
+            return Err(kernel_error(txn.error));
+        }
+
+        let snapshot = Snapshot::builder_for(table_root)
+            .build(&engine)
+            .map_err(kernel_error)?;
+        if snapshot.version() > 0 {
+            return Ok(());
+        }
+        let mut txn = snapshot
+            .transaction(Box::new(FileSystemCommitter::new()), &engine)
+            .map_err(kernel_error)?
+            .with_engine_info(ENGINE_INFO);
+        let write_context = txn.unpartitioned_write_context().map_err(kernel_error)?;
+        while let Some(batch) = rows.try_next().await.map_err(df_error)? {
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let add_metadata = engine
+                .write_parquet(&ArrowEngineData::new(batch), &write_context)
+                .await
+                .map_err(kernel_error)?;
+            txn.add_files(add_metadata);
+        }
+        match txn.commit(&engine).map_err(kernel_error)? {
+            CommitResult::CommittedTransaction(_) | CommitResult::ConflictedTransaction(_) => {
+                Ok(())
+            }
+            CommitResult::RetryableTransaction(txn) => Err(kernel_error(txn.error)),
+        }
+    }
+
+    /// Delete the materializations of a view superseded for longer than
+    /// [`SUPERSEDED_RETENTION`]; `current` is never deleted.
+    ///
+    /// A materialization is superseded when a newer one is written. Recipients
+    /// may still be reading its files for a while after that, so it is only
+    /// deleted once its successor has aged past the retention period.
+    async fn prune_materializations(
+        &self,
+        materializations: &StorageLocationUrl,
+        current: &str,
+    ) -> Result<()> {
+        let store = self
+            .handler
+            .create_object_store(materializations.location())
+            .await
+            .map_err(df_error)?;
+        let prefix = Path::from_url_path(materializations.location().path()).map_err(df_error)?;
+        let objects: Vec<ObjectMeta> = store
+            .list(Some(&prefix))
+            .try_collect()
+            .await
+            .map_err(df_error)?;
+        let fingerprint_of = |meta: &ObjectMeta| {
+            meta.location
+                .prefix_match(&prefix)
+                .and_then(|mut parts| parts.next())
+                .map(|part| part.as_ref().to_string())
+                // The version pointers are kept for good.
+                .filter(|part| part != VERSIONS_DIR)
+        };
+
+        let mut created: HashMap<String, DateTime<Utc>> = HashMap::new();
+        for meta in &objects {
+            if let Some(fingerprint) = fingerprint_of(meta) {
+                let at = created.entry(fingerprint).or_insert(meta.last_modified);
+                *at = (*at).min(meta.last_modified);
+            }
+        }
+        let stale = superseded(&created, current, Utc::now() - SUPERSEDED_RETENTION);
+        for meta in objects {
+            if fingerprint_of(&meta).is_some_and(|fingerprint| stale.contains(&fingerprint)) {
+                store.delete(&meta.location).await.map_err(df_error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The `catalog.schema.table` names a view reads, in dependency order.
+fn view_source_names(view: &Table) -> Vec<String> {
+    view.view_dependencies
+        .iter()
+        .flat_map(|deps| deps.dependencies.iter())
+        .filter_map(|dep| match &dep.dependency {
+            Some(dependency::Dependency::Table(table)) => Some(table.table_full_name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The materializations, keyed by fingerprint with their creation time, that a
+/// newer materialization created before `cutoff` replaced. `current` is kept.
+fn superseded(
+    created: &HashMap<String, DateTime<Utc>>,
+    current: &str,
+    cutoff: DateTime<Utc>,
+) -> Vec<String> {
+    created
+        .iter()
+        .filter(|(fingerprint, at)| {
+            fingerprint.as_str() != current
+                && created
+                    .values()
+                    .any(|other| other > *at && *other <= cutoff)
+        })
+        .map(|(fingerprint, _)| fingerprint.clone())
+        .collect()
+}
+
+/// Register `table` in `ctx` under its three-part `full_name`, creating the
+/// in-memory catalog and schema on first use.
+fn register_source(
+    ctx: &SessionContext,
+    full_name: &str,
+    table: Arc<dyn datafusion::catalog::TableProvider>,
+) -> Result<()> {
+    let parts: Vec<&str> = full_name.split('.').collect();
+    let [catalog_name, schema_name, table_name] = parts.as_slice() else {
+        return Err(Error::invalid_argument(format!(
+            "view dependency '{full_name}' is not a catalog.schema.table name"
+        )));
+    };
+    let catalog = ctx.catalog(catalog_name).unwrap_or_else(|| {
+        let catalog = Arc::new(MemoryCatalogProvider::new());
+        ctx.register_catalog(catalog_name, catalog.clone());
+        catalog
+    });
+    let schema = match catalog.schema(schema_name) {
+        Some(schema) => schema,
+        None => {
+            let schema = Arc::new(MemorySchemaProvider::new());
+            catalog
+                .register_schema(schema_name, schema.clone())
+                .map_err(df_error)?;
+            schema
+        }
+    };
+    schema
+        .register_table(table_name.to_string(), table)
+        .map_err(df_error)?;
+    Ok(())
+}
+
+/// Stable fingerprint of a view definition and the source versions it is
+/// evaluated at (64-bit FNV-1a, hex encoded).
+///
+/// Sources are hashed in name order so the fingerprint does not depend on the
+/// order dependencies were recorded in.
+fn fingerprint(view_id: &str, view_definition: &str, sources: &[ViewSource]) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET;
+    let mut feed = |bytes: &[u8]| {
+        for byte in bytes.iter().chain([&0u8]) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+    feed(view_id.as_bytes());
+    feed(view_definition.as_bytes());
+    let mut pinned: Vec<_> = sources
+        .iter()
+        .map(|s| (s.full_name.as_str(), s.version))
+        .collect();
+    pinned.sort_unstable();
+    for (name, version) in pinned {
+        feed(name.as_bytes());
+        feed(&version.to_le_bytes());
+    }
+    format!("{hash:016x}")
+}
+
+fn df_error(e: impl std::fmt::Display) -> Error {
+    Error::Generic(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::datasource::MemTable;
+
+    use super::*;
+
+    fn source(name: &str, version: Version) -> ViewSource {
+        ViewSource {
+            full_name: name.to_string(),
+            location: StorageLocationUrl::parse("s3://bucket/table").unwrap(),
+            version,
+        }
+    }
+
+    #[test]
+    fn fingerprint_tracks_definition_and_source_versions() {
+        let sources = [source("c.s.a", 3), source("c.s.b", 7)];
+        let base = fingerprint("id", "SELECT 1", &sources);
+        assert_eq!(base.len(), 16);
+
+        let reordered = [source("c.s.b", 7), source("c.s.a", 3)];
+        assert_eq!(base, fingerprint("id", "SELECT 1", &reordered));
+
+        let bumped = [source("c.s.a", 4), source("c.s.b", 7)];
+        assert_ne!(base, fingerprint("id", "SELECT 1", &bumped));
+        assert_ne!(base, fingerprint("id", "SELECT 2", &sources));
+        assert_ne!(base, fingerprint("other", "SELECT 1", &sources));
+    }
+
+    #[test]
+    fn superseded_materializations_age_out_after_their_successor() {
+        let at = |hours: i64| DateTime::<Utc>::UNIX_EPOCH + TimeDelta::hours(hours);
+        let created: HashMap<String, DateTime<Utc>> = [("a", 0), ("b", 10), ("c", 20)]
+            .into_iter()
+            .map(|(fingerprint, hours)| (fingerprint.to_string(), at(hours)))
+            .collect();
+
+        // Nothing replaced `c`, and `b` only replaced `a` at hour 10.
+        assert!(superseded(&created, "c", at(5)).is_empty());
+        assert_eq!(superseded(&created, "c", at(15)), vec!["a".to_string()]);
+        let mut stale = superseded(&created, "c", at(25));
+        stale.sort();
+        assert_eq!(stale, vec!["a".to_string(), "b".to_string()]);
+        // The materialization being served is kept even if a newer one exists.
+        assert_eq!(superseded(&created, "a", at(25)), vec!["b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn registers_sources_under_three_part_names() {
+        let ctx = SessionContext::new();
+        let schema = Arc::new(datafusion::arrow::datatypes::Schema::new(vec![
+            datafusion::arrow::datatypes::Field::new(
+                "id",
+                datafusion::arrow::datatypes::DataType::Int64,
+                false,
+            ),
+        ]));
+        for name in ["c.s.a", "c.s.b", "c.t.a"] {
+            let table = MemTable::try_new(schema.clone(), vec![vec![]]).unwrap();
+            register_source(&ctx, name, Arc::new(table)).unwrap();
+        }
+        let batches = ctx
+            .sql("SELECT count(*) FROM c.s.a JOIN c.t.a USING (id)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(batches[0].num_rows(), 1);
+
+        let err = register_source(
+            &ctx,
+            "s.a",
+            Arc::new(MemTable::try_new(schema, vec![vec![]]).unwrap()),
+        );
+        assert!(matches!(err, Err(Error::InvalidArgument(_))));
+    }
+}
@@ -1,7 +1,11 @@
+use std::sync::Arc;
+
 use bytes::Bytes;
+use delta_kernel::{Snapshot, Version};
+use futures::StreamExt;
 
 use unitycatalog_common::models::shares::v1::{
-    DataObjectType, GetShareRequest as SharesGetShareRequest,
+    DataObject, DataObjectType, GetShareRequest as SharesGetShareRequest,
 };
 use unitycatalog_common::models::tables::v1::{DataSourceFormat, GetTableRequest, Table};
 use unitycatalog_common::models::temporary_credentials::v1::{
@@ -14,11 +18,15 @@ use unitycatalog_sharing_client::models::open_sharing::v1::{
 };
 
 use super::credential_vending::{VendOperation, vend_credential};
-use super::object_store::find_external_location_for_url;
+use super::managed_delta_contract as contract;
+use super::object_store::{find_external_location_for_url, get_signer};
+use super::share_policy::SharedTablePolicy;
+use super::shared_view::MaterializedView;
 use super::{Policy, ServerHandler, StorageLocationUrl, TableManager};
 use crate::api::credentials::CredentialHandlerExt;
 use crate::api::sharing::{
-    MetadataResponse, MetadataResponseData, ProtocolResponseData, SharingQueryHandler,
+    MetadataResponse, MetadataResponseData, NdJsonStream, ParquetMetadata, ProtocolResponseData,
+    SharingQueryHandler,
 };
 use crate::api::{RequestContext, SecuredAction};
 use crate::error::{Error, Result};
@@ -33,9 +41,25 @@ pub struct SharingTableReference {
     table: String,
 }
 
-impl SharingTableReference {
-    pub(super) fn system_table_name(&self) -> String {
-        format!("{}__{}__{}", self.share, self.schema, self.table)
+/// A shared table or view resolved to the Delta table that serves it.
+pub(super) struct SharedTableTarget {
+    pub(super) policy: SharedTablePolicy,
+    pub(super) location: StorageLocationUrl,
+    /// The materialized snapshot serving a shared view; `None` for tables.
+    pub(super) view: Option<MaterializedView>,
+    /// The catalog id of a catalog-managed table, whose newest commits may be
+    /// ratified by the coordinator but not yet published to the log.
+    pub(super) table_id: Option<String>,
+}
+
+impl SharedTableTarget {
+    /// The version reported to recipients for `snapshot` of the served table:
+    /// its own version for a table, the view's version for a view.
+    pub(super) fn reported_version(&self, snapshot: &Snapshot) -> Version {
+        match &self.view {
+            Some(view) => view.version,
+            None => snapshot.version(),
+        }
     }
 }
 
@@ -57,39 +81,134 @@ impl ServerHandler<RequestContext> {
     /// through the configured [`table_source`](ServerHandler::table_source) when
     /// present — so in the side-by-side topology it is fetched from the upstream
     /// Unity Catalog rather than the local store — and falls back to a local
-    /// store lookup otherwise.
+    /// store lookup otherwise. Shared views resolve to their materialized
+    /// snapshot.
     pub(super) async fn resolve_table_location(
         &self,
         table_ref: &SharingTableReference,
         context: &RequestContext,
     ) -> Result<StorageLocationUrl> {
+        Ok(self
+            .resolve_shared_table(table_ref, context)
+            .await?
+            .location)
+    }
+
+    /// Resolve a shared table or view's sharing settings alongside the Delta
+    /// table that serves it.
+    ///
+    /// See [`resolve_table_location`](Self::resolve_table_location) for how the
+    /// backing Table primitive is found.
+    pub(super) async fn resolve_shared_table(
+        &self,
+        table_ref: &SharingTableReference,
+        context: &RequestContext,
+    ) -> Result<SharedTableTarget> {
+        let (table_object, table_info) = self.lookup_shared_table(table_ref, context).await?;
+        self.shared_table_target(&table_object, table_info, context)
+            .await
+    }
+
+    /// The Delta table serving a share object and its backing Table primitive,
+    /// materializing a shared view first.
+    async fn shared_table_target(
+        &self,
+        table_object: &DataObject,
+        table_info: Table,
+        context: &RequestContext,
+    ) -> Result<SharedTableTarget> {
+        if table_object.data_object_type() == DataObjectType::View {
+            let policy = SharedTablePolicy::try_from_view_object(table_object)?;
+            let view = self.materialize_shared_view(&table_info, context).await?;
+            return Ok(SharedTableTarget {
+                policy,
+                location: view.location.clone(),
+                view: Some(view),
+                table_id: None,
+            });
+        }
+
+        let policy = SharedTablePolicy::try_from_data_object(table_object)?;
+        let table_id = contract::is_catalog_managed(table_info.table_type)
+            .then_some(table_info.table_id)
+            .flatten();
+        let location = table_info.storage_location.ok_or(Error::NotFound)?;
+        Ok(SharedTableTarget {
+            policy,
+            location: StorageLocationUrl::parse(&location)?,
+            view: None,
+            table_id,
+        })
+    }
+
+    /// The snapshot of the Delta table serving `target` at `version`, or at
+    /// its latest version when unset. For a catalog-managed table this reads
+    /// through the coordinator's ratified commits, so recipients see the same
+    /// latest version as catalog clients.
+    pub(super) async fn read_shared_snapshot(
+        &self,
+        target: &SharedTableTarget,
+        version: Option<Version>,
+    ) -> Result<Arc<Snapshot>> {
+        let Some(table_id) = target.table_id.as_deref() else {
+            return self
+                .read_snapshot(&target.location, &DataSourceFormat::Delta, version)
+                .await;
+        };
+        let (ratified, latest) = self
+            .commit_coordinator()
+            .get_commits(table_id, 0, None)
+            .await?;
+        let latest = Version::try_from(latest)
+            .map_err(|_| Error::generic(format!("negative table version {latest}")))?;
+        self.session
+            .catalog_snapshot(&target.location, &ratified, Some(latest), version)
+            .await
+    }
+
+    /// Find the table or view a share exposes as `table_ref`, returning its
+    /// share object alongside the backing Table primitive.
+    async fn lookup_shared_table(
+        &self,
+        table_ref: &SharingTableReference,
+        context: &RequestContext,
+    ) -> Result<(DataObject, Table)> {
         let share_ident = ResourceIdent::share(ResourceName::new([table_ref.share.as_str()]));
         let share_info: Share = self.get(&share_ident).await?.0.try_into()?;
-        let Some(table_object) = share_info
-            .objects
-            .iter()
-            .find(|o| o.shared_as() == format!("{}.{}", table_ref.schema, table_ref.table))
-        else {
+        let shared_as = format!("{}.{}", table_ref.schema, table_ref.table);
+        let Some(table_object) = share_info.objects.into_iter().find(|o| {
+            o.shared_as() == shared_as
+                && matches!(
+                    o.data_object_type(),
+                    DataObjectType::Table | DataObjectType::View
+                )
+        }) else {
             return Err(Error::NotFound);
         };
+        let table_info = self.lookup_table(&table_object.name, context).await?;
+        Ok((table_object, table_info))
+    }
 
-        let table_info: Table = if let Some(table_source) = self.table_source() {
+    /// Look up a Table primitive by its full name.
+    pub(super) async fn lookup_table(
+        &self,
+        full_name: &str,
+        context: &RequestContext,
+    ) -> Result<Table> {
+        if let Some(table_source) = self.table_source() {
             // Side-by-side topology: resolve the Table primitive through the
             // routed handler (e.g. upstream Unity Catalog), keyed by full name.
             let request = GetTableRequest {
-                full_name: table_object.name.clone(),
+                full_name: full_name.to_string(),
                 ..Default::default()
             };
-            table_source.get_table(request, context.clone()).await?
+            table_source.get_table(request, context.clone()).await
         } else {
             // Self-contained topology: the Table primitive lives in the local
             // store alongside the Share.
-            let table_ident = ResourceIdent::table(ResourceName::new(table_object.name.split(".")));
-            self.get(&table_ident).await?.0.try_into()?
-        };
-
-        let location = table_info.storage_location.ok_or(Error::NotFound)?;
-        Ok(StorageLocationUrl::parse(&location)?)
+            let table_ident = ResourceIdent::table(ResourceName::new(full_name.split(".")));
+            Ok(self.get(&table_ident).await?.0.try_into()?)
+        }
     }
 
     /// Resolve the storage location of a shared volume or agent skill.
@@ -151,12 +270,18 @@ impl SharingQueryHandler for ServerHandler<RequestContext> {
             schema: request.schema,
             table: request.name,
         };
-        let location = self.resolve_table_location(&table_ref, &context).await?;
-        let snapshot = self
-            .read_snapshot(&location, &DataSourceFormat::Delta, None)
-            .await?;
+        // Probing a view's version must stay cheap, so it never materializes.
+        let (table_object, table_info) = self.lookup_shared_table(&table_ref, &context).await?;
+        let version = if table_object.data_object_type() == DataObjectType::View {
+            self.shared_view_version(&table_info, &context).await?
+        } else {
+            let target = self
+                .shared_table_target(&table_object, table_info, &context)
+                .await?;
+            self.read_shared_snapshot(&target, None).await?.version()
+        };
         Ok(GetTableVersionResponse {
-            version: snapshot.version() as i64,
+            version: version as i64,
         })
     }
 
@@ -171,43 +296,64 @@ impl SharingQueryHandler for ServerHandler<RequestContext> {
             schema: request.schema,
             table: request.name,
         };
-        let location = self.resolve_table_location(&table_ref, &context).await?;
-        let snapshot = self
-            .read_snapshot(&location, &DataSourceFormat::Delta, None)
-            .await?;
-
-        let table_config = snapshot.table_configuration();
-        let mut response = serde_json::to_vec(&MetadataResponse::MetaData(
-            MetadataResponseData::ParquetMetadata(table_config.metadata().try_into()?),
-        ))?;
-        response.push(b'\n');
-        response.extend(serde_json::to_vec(&MetadataResponse::Protocol(
-            ProtocolResponseData::ParquetProtocol(table_config.protocol().into()),
-        ))?);
-
-        Ok(Bytes::from(response))
+        let target = self.resolve_shared_table(&table_ref, &context).await?;
+        let snapshot = self.read_shared_snapshot(&target, None).await?;
+        table_metadata_lines(&snapshot, target.reported_version(&snapshot))
     }
 
     async fn query_table(
         &self,
         request: QueryTableRequest,
         context: RequestContext,
-    ) -> Result<Bytes> {
+    ) -> Result<NdJsonStream> {
         self.check_required(&request, &context).await?;
         let table_ref = SharingTableReference {
-            share: request.share,
-            schema: request.schema,
-            table: request.name,
+            share: request.share.clone(),
+            schema: request.schema.clone(),
+            table: request.name.clone(),
         };
-        let location = self.resolve_table_location(&table_ref, &context).await?;
-        let data = self
+        let target = self.resolve_shared_table(&table_ref, &context).await?;
+        let version = target.policy.resolve_query(&request)?;
+        // The response opens with the protocol and metadata of the queried
+        // snapshot, followed by one `file` line per data file of that same
+        // snapshot.
+        let snapshot = self
+            .read_shared_snapshot(&target, version.version())
+            .await?;
+        let header = table_metadata_lines(&snapshot, target.reported_version(&snapshot))?;
+        // Recipients download the data files from pre-signed urls.
+        let signer = match &self.url_signer {
+            Some(signer) => signer.clone(),
+            None => get_signer(&target.location, self).await?,
+        };
+        let files = self
             .session
-            .extract_sharing_query_response(&table_ref, &location)
+            .extract_sharing_query_response(&target.location, snapshot, target.policy, signer)
             .await?;
-        Ok(data)
+        Ok(futures::stream::once(futures::future::ready(Ok(header)))
+            .chain(files)
+            .boxed())
     }
 }
 
+/// Encode a snapshot's protocol and metadata as the two NDJSON lines that open
+/// both the metadata and the query responses, reporting `version` (see
+/// [`SharedTableTarget::reported_version`]).
+fn table_metadata_lines(snapshot: &Snapshot, version: Version) -> Result<Bytes> {
+    let table_config = snapshot.table_configuration();
+    let mut metadata: ParquetMetadata = table_config.metadata().try_into()?;
+    metadata.version = Some(version as i64);
+    let mut response = serde_json::to_vec(&MetadataResponse::Protocol(
+        ProtocolResponseData::ParquetProtocol(table_config.protocol().into()),
+    ))?;
+    response.push(b'\n');
+    response.extend(serde_json::to_vec(&MetadataResponse::MetaData(
+        MetadataResponseData::ParquetMetadata(metadata),
+    ))?);
+    response.push(b'\n');
+    Ok(Bytes::from(response))
+}
+
 /// Map a Unity Catalog [`TemporaryCredential`] to the Open Sharing
 /// [`SharingTemporaryCredentials`] envelope. The two carry the same
 /// provider-specific payloads; only the message names differ.
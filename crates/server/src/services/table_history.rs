@@ -0,0 +1,562 @@
+//! [`TableHistoryHandler`] for the server: lists the commits of a Delta table.
+//!
+//! The history is assembled from two sources. Published commits are the
+//! `_delta_log/<version>.json` files still present in the log — log cleanup
+//! bounds how far back the history reaches. For catalog-managed tables, the
+//! [`CommitCoordinator`] adds the commits it has ratified that are not
+//! published yet; those are read from `_delta_log/_staged_commits/`. When a
+//! version exists in both places, the published file wins.
+//!
+//! Each entry is built from the `commitInfo` action of the commit file. Pages
+//! are ordered newest first, and the page token is the highest version of the
+//! next page.
+
+use std::collections::BTreeMap;
+
+use futures::{StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{DynObjectStore, ObjectStoreExt};
+use serde::Deserialize;
+
+use unitycatalog_common::models::table_history::v1::{
+    GetTableHistoryRequest, GetTableHistoryResponse, TableHistoryEntry,
+};
+use unitycatalog_common::models::tables::v1::{DataSourceFormat, GetTableRequest};
+use unitycatalog_common::services::commit_coordinator::{
+    CommitCoordinator, ProvidesCommitCoordinator,
+};
+
+use super::ServerHandler;
+use super::kernel::ObjectStoreFactory;
+use super::location::StorageLocationUrl;
+use super::managed_delta_contract as contract;
+use crate::api::{RequestContext, TableHandler, TableHistoryHandler};
+use crate::{Error, Result};
+
+/// Page size when the request does not set `max_results`.
+const DEFAULT_MAX_RESULTS: usize = 50;
+/// Upper bound on `max_results`.
+const MAX_RESULTS_LIMIT: usize = 1000;
+/// Number of commit files read concurrently while building a page.
+const READ_CONCURRENCY: usize = 8;
+
+/// A commit file found in the log or reported by the coordinator.
+#[derive(Debug)]
+struct CommitFile {
+    path: Path,
+    published: bool,
+    /// Timestamp to report if the commit carries none of its own.
+    timestamp: i64,
+}
+
+/// The `commitInfo` fields surfaced in the history.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitInfoAction {
+    timestamp: Option<i64>,
+    in_commit_timestamp: Option<i64>,
+    operation: Option<String>,
+    #[serde(default)]
+    operation_parameters: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    operation_metrics: BTreeMap<String, serde_json::Value>,
+    engine_info: Option<String>,
+    user_id: Option<String>,
+    user_name: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl TableHistoryHandler for ServerHandler<RequestContext> {
+    async fn get_table_history(
+        &self,
+        request: GetTableHistoryRequest,
+        context: RequestContext,
+    ) -> Result<GetTableHistoryResponse> {
+        let max_results = match request.max_results {
+            None | Some(0) => DEFAULT_MAX_RESULTS,
+            Some(n) if n < 0 => {
+                return Err(Error::invalid_argument("max_results must not be negative"));
+            }
+            Some(n) => (n as usize).min(MAX_RESULTS_LIMIT),
+        };
+        let start_version = request.start_version.unwrap_or(0);
+        if start_version < 0 {
+            return Err(Error::invalid_argument(
+                "start_version must not be negative",
+            ));
+        }
+        let mut end_version = request.end_version;
+        if let Some(token) = request.page_token.filter(|t| !t.is_empty()) {
+            let next: i64 = token
+                .parse()
+                .map_err(|_| Error::invalid_argument("invalid page_token"))?;
+            end_version = Some(end_version.map_or(next, |end| end.min(next)));
+        }
+        if end_version.is_some_and(|end| end < start_version) {
+            return Ok(GetTableHistoryResponse::default());
+        }
+
+        let table = TableHandler::get_table(
+            self,
+            GetTableRequest {
+                full_name: request.full_name,
+                include_delta_metadata: None,
+                include_browse: None,
+                include_manifest_capabilities: None,
+            },
+            context,
+        )
+        .await?;
+        if table.data_source_format != DataSourceFormat::Delta as i32 {
+            return Err(Error::invalid_argument(format!(
+                "table '{}' is not a Delta table",
+                table.full_name
+            )));
+        }
+        let location = table.storage_location.as_deref().ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "table '{}' has no storage location",
+                table.full_name
+            ))
+        })?;
+        let location = StorageLocationUrl::parse(location)?;
+        let store = self
+            .handler
+            .create_object_store(location.location())
+            .await
+            .map_err(Error::generic)?;
+        let log = Path::from_url_path(location.location().path())
+            .map_err(object_store::Error::from)?
+            .child("_delta_log");
+
+        // The local file system lists in no particular order; other stores
+        // list keys in lexicographic order.
+        let ordered = location.location().scheme() != "file";
+        let mut commits =
+            published_commits(store.as_ref(), &log, start_version, end_version, ordered).await?;
+        if contract::is_catalog_managed(table.table_type)
+            && let Some(table_id) = table.table_id.as_deref()
+        {
+            add_ratified_commits(
+                self.commit_coordinator(),
+                table_id,
+                &log,
+                start_version,
+                end_version,
+                &mut commits,
+            )
+            .await?;
+        }
+
+        let mut in_range = commits
+            .range(start_version..=end_version.unwrap_or(i64::MAX))
+            .rev();
+        let page: Vec<_> = in_range.by_ref().take(max_results).collect();
+        let next_page_token = in_range.next().map(|(version, _)| version.to_string());
+
+        let entries = futures::stream::iter(page)
+            .map(|(version, file)| read_entry(store.as_ref(), *version, file))
+            .buffered(READ_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(GetTableHistoryResponse {
+            entries,
+            next_page_token,
+        })
+    }
+}
+
+/// The published `<version>.json` commits in `[start_version, end_version]`,
+/// keyed by version.
+///
+/// The listing starts at `start_version`. When the store lists in order, it
+/// stops at the first key past `end_version`; zero-padded versions sort
+/// numerically, and the `_`-prefixed directories sort after them.
+async fn published_commits(
+    store: &DynObjectStore,
+    log: &Path,
+    start_version: i64,
+    end_version: Option<i64>,
+    ordered: bool,
+) -> Result<BTreeMap<i64, CommitFile>> {
+    let offset = log.child(format!("{start_version:020}"));
+    let mut listing = store.list_with_offset(Some(log), &offset);
+    let mut commits = BTreeMap::new();
+    while let Some(meta) = listing.try_next().await? {
+        let Some(name) = meta
+            .location
+            .prefix_match(log)
+            .and_then(|mut parts| parts.next())
+        else {
+            continue;
+        };
+        let past_end = match version_prefix(name.as_ref()) {
+            Some(version) => end_version.is_some_and(|end| version > end),
+            None => true,
+        };
+        if past_end {
+            if ordered {
+                break;
+            }
+            continue;
+        }
+        let Some(version) = commit_version(name.as_ref()) else {
+            continue;
+        };
+        if version < start_version {
+            continue;
+        }
+        commits.insert(
+            version,
+            CommitFile {
+                path: meta.location,
+                published: true,
+                timestamp: meta.last_modified.timestamp_millis(),
+            },
+        );
+    }
+    Ok(commits)
+}
+
+/// Add the ratified commits the coordinator tracks for `table_id` that are not
+/// published yet.
+async fn add_ratified_commits(
+    coordinator: &dyn CommitCoordinator,
+    table_id: &str,
+    log: &Path,
+    start_version: i64,
+    end_version: Option<i64>,
+    commits: &mut BTreeMap<i64, CommitFile>,
+) -> Result<()> {
+    let (ratified, _) = coordinator
+        .get_commits(table_id, start_version, end_version)
+        .await?;
+    for commit in ratified {
+        commits.entry(commit.version).or_insert_with(|| CommitFile {
+            path: log
+                .child("_staged_commits")
+                .child(commit.file_name.as_str()),
+            published: false,
+            timestamp: commit.timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// The version a log file name starts with, e.g. 7 for
+/// `00000000000000000007.checkpoint.parquet`.
+fn version_prefix(file_name: &str) -> Option<i64> {
+    let digits = file_name.get(..20)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Parse the version out of a published commit file name (`<20 digits>.json`).
+fn commit_version(file_name: &str) -> Option<i64> {
+    let digits = file_name.strip_suffix(".json")?;
+    if digits.len() != 20 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Build the history entry for one commit from its `commitInfo` action.
+async fn read_entry(
+    store: &DynObjectStore,
+    version: i64,
+    file: &CommitFile,
+) -> Result<TableHistoryEntry> {
+    let info = match store.get(&file.path).await {
+        Ok(result) => parse_commit_info(&result.bytes().await?)?,
+        // The file may have been published or cleaned up since it was listed;
+        // report the commit without its details rather than fail the page.
+        Err(object_store::Error::NotFound { .. }) => None,
+        Err(e) => return Err(e.into()),
+    }
+    .unwrap_or_default();
+    Ok(TableHistoryEntry {
+        version,
+        timestamp: info
+            .in_commit_timestamp
+            .or(info.timestamp)
+            .unwrap_or(file.timestamp),
+        operation: info.operation,
+        operation_parameters: info.operation_parameters,
+        operation_metrics: info.operation_metrics,
+        engine_info: info.engine_info,
+        user_id: info.user_id,
+        user_name: info.user_name,
+        published: file.published,
+    })
+}
+
+/// Find the `commitInfo` action among the newline-delimited actions of a
+/// commit file.
+fn parse_commit_info(bytes: &[u8]) -> Result<Option<CommitInfoAction>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Action {
+        commit_info: Option<CommitInfoAction>,
+    }
+
+    for line in bytes.split(|b| *b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        if let Some(info) = serde_json::from_slice::<Action>(line)?.commit_info {
+            return Ok(Some(info));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(all(test, feature = "memory", not(windows)))]
+mod tests {
+    use std::sync::Arc;
+
+    use object_store::PutPayload;
+    use object_store::local::LocalFileSystem;
+    use unitycatalog_common::models::ResourceRef;
+    use unitycatalog_common::models::delta_commits::v1::CommitInfo;
+    use unitycatalog_common::models::tables::v1::{Table, TableType};
+    use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::policy::{ConstantPolicy, Policy, Principal};
+    use crate::services::LocalStoragePolicy;
+    use crate::store::ResourceStore;
+
+    fn ctx() -> RequestContext {
+        RequestContext {
+            recipient: Principal::anonymous(),
+        }
+    }
+
+    async fn handler_with_table(root: &std::path::Path) -> (ServerHandler<RequestContext>, String) {
+        let encryptor =
+            EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+        let store = Arc::new(InMemoryResourceStore::new(encryptor));
+        let location = url::Url::from_directory_path(root.join("t")).unwrap();
+        let table = Table {
+            name: "t".to_string(),
+            catalog_name: "cat".to_string(),
+            schema_name: "sch".to_string(),
+            full_name: "cat.sch.t".to_string(),
+            table_type: TableType::Managed as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            storage_location: Some(location.to_string()),
+            ..Default::default()
+        };
+        let (_, table_ref) = store.create(table.into()).await.unwrap();
+        let ResourceRef::Uuid(table_id) = table_ref else {
+            panic!("expected uuid");
+        };
+        let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
+        let handler = ServerHandler::try_new_tokio(policy, store.clone(), store)
+            .unwrap()
+            .with_local_storage_policy(LocalStoragePolicy::new([root]).unwrap());
+        (handler, table_id.to_string())
+    }
+
+    fn commit_body(version: i64) -> String {
+        format!(
+            concat!(
+                r#"{{"commitInfo":{{"timestamp":{},"inCommitTimestamp":{},"operation":"WRITE","#,
+                r#""operationParameters":{{"mode":"Append"}},"#,
+                r#""operationMetrics":{{"numOutputRows":"{}"}},"engineInfo":"test-engine"}}}}"#,
+                "\n",
+                r#"{{"add":{{"path":"part-{}.parquet"}}}}"#,
+                "\n"
+            ),
+            1000 + version,
+            5000 + version,
+            version * 10,
+            version
+        )
+    }
+
+    async fn put(root: &std::path::Path, relative: &str, body: String) {
+        let path = Path::from_url_path(
+            url::Url::from_file_path(root.join(relative))
+                .unwrap()
+                .path(),
+        )
+        .unwrap();
+        LocalFileSystem::new()
+            .put(&path, PutPayload::from(body))
+            .await
+            .unwrap();
+    }
+
+    /// Publish commits `0..=2`, then stage and ratify `3` and `4`.
+    async fn write_history(
+        handler: &ServerHandler<RequestContext>,
+        root: &std::path::Path,
+        table_id: &str,
+    ) {
+        for version in 0..=2 {
+            put(
+                root,
+                &format!("t/_delta_log/{version:020}.json"),
+                commit_body(version),
+            )
+            .await;
+        }
+        // Checkpoints and other log files are not commits.
+        put(
+            root,
+            "t/_delta_log/00000000000000000002.checkpoint.parquet",
+            String::new(),
+        )
+        .await;
+        for version in 3..=4 {
+            let file_name = format!("{version:020}.{table_id}.json");
+            let body = commit_body(version);
+            put(
+                root,
+                &format!("t/_delta_log/_staged_commits/{file_name}"),
+                body.clone(),
+            )
+            .await;
+            handler
+                .commit_coordinator()
+                .commit(
+                    table_id,
+                    Some(CommitInfo {
+                        version,
+                        timestamp: 1000 + version,
+                        file_name,
+                        file_size: body.len() as i64,
+                        file_modification_timestamp: 2000 + version,
+                    }),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    fn request() -> GetTableHistoryRequest {
+        GetTableHistoryRequest {
+            full_name: "cat.sch.t".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn merges_published_and_ratified_commits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        write_history(&handler, &root, &table_id).await;
+
+        let history = handler.get_table_history(request(), ctx()).await.unwrap();
+        assert!(history.next_page_token.is_none());
+        assert_eq!(
+            history
+                .entries
+                .iter()
+                .map(|e| (e.version, e.published))
+                .collect::<Vec<_>>(),
+            vec![(4, false), (3, false), (2, true), (1, true), (0, true)]
+        );
+        let latest = &history.entries[0];
+        assert_eq!(latest.timestamp, 5004);
+        assert_eq!(latest.operation.as_deref(), Some("WRITE"));
+        assert_eq!(latest.operation_metrics["numOutputRows"], "40");
+        assert_eq!(latest.operation_parameters["mode"], "Append");
+        assert_eq!(latest.engine_info.as_deref(), Some("test-engine"));
+    }
+
+    #[tokio::test]
+    async fn pages_within_a_version_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (handler, table_id) = handler_with_table(&root).await;
+        write_history(&handler, &root, &table_id).await;
+
+        let mut request = GetTableHistoryRequest {
+            start_version: Some(1),
+            end_version: Some(3),
+            max_results: Some(2),
+            ..request()
+        };
+        let first = handler
+            .get_table_history(request.clone(), ctx())
+            .await
+            .unwrap();
+        assert_eq!(
+            first.entries.iter().map(|e| e.version).collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        assert_eq!(first.next_page_token.as_deref(), Some("1"));
+
+        request.page_token = first.next_page_token;
+        let second = handler.get_table_history(request, ctx()).await.unwrap();
+        assert_eq!(
+            second.entries.iter().map(|e| e.version).collect::<Vec<_>>(),
+            vec![1]
+        );
+        assert!(second.next_page_token.is_none());
+
+        let err = handler
+            .get_table_history(
+                GetTableHistoryRequest {
+                    page_token: Some("not-a-version".to_string()),
+                    ..self::request()
+                },
+                ctx(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn lists_only_the_page_range() {
+        let store = object_store::memory::InMemory::new();
+        let log = Path::from("t/_delta_log");
+        for name in [
+            "00000000000000000000.json",
+            "00000000000000000001.json",
+            "00000000000000000002.checkpoint.parquet",
+            "00000000000000000002.json",
+            "00000000000000000003.json",
+            "00000000000000000004.json",
+            "_last_checkpoint",
+            "_staged_commits/00000000000000000005.uuid.json",
+        ] {
+            store
+                .put(&log.child(name), bytes::Bytes::new().into())
+                .await
+                .unwrap();
+        }
+        for ordered in [true, false] {
+            let commits = published_commits(&store, &log, 1, Some(3), ordered)
+                .await
+                .unwrap();
+            assert_eq!(commits.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        }
+        let commits = published_commits(&store, &log, 3, None, true)
+            .await
+            .unwrap();
+        assert_eq!(commits.keys().copied().collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn recognizes_only_published_commit_files() {
+        assert_eq!(commit_version("00000000000000000007.json"), Some(7));
+        assert_eq!(
+            commit_version("00000000000000000007.checkpoint.parquet"),
+            None
+        );
+        assert_eq!(
+            commit_version("00000000000000000001.00000000000000000003.compacted.json"),
+            None
+        );
+        assert_eq!(commit_version("_last_checkpoint"), None);
+    }
+}
@@ -4,6 +4,6 @@
 //! them without depending on this server crate. They are re-exported here to keep
 //! the historical `unitycatalog_server::store::*` paths working.
 pub use unitycatalog_common::store::{
-    ObjectStoreAdapter, ProvidesObjectStore, ProvidesResourceStore, ResourceStore,
+    ObjectStoreAdapter, ProvidesObjectStore, ProvidesResourceStore, RenameObject, ResourceStore,
     ResourceStoreReader,
 };
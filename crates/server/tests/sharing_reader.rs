@@ -0,0 +1,366 @@
+//! End-to-end test of the Delta Sharing table reader against this server.
+//!
+//! Writes a small partitioned Delta table to a temp dir, registers and shares
+//! it, serves the Delta Sharing REST API on a local port and reads the table
+//! back with `unitycatalog_sharing_client::reader`, both directly and through
+//! DataFusion. Local storage cannot issue pre-signed urls, so the server is
+//! given a signer handing out urls of a plain file server over the temp dir;
+//! without one, querying the table's files is refused.
+#![cfg(all(feature = "axum", not(windows)))]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use datafusion::arrow::array::{AsArray, Int64Array, RecordBatch, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Int64Type, Schema};
+use datafusion::arrow::util::pretty::pretty_format_batches;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::prelude::SessionContext;
+use futures::TryStreamExt;
+use http::Method;
+use object_store::path::Path;
+use object_store::signer::Signer;
+use olai_http::CloudClient;
+use unitycatalog_common::models::credentials::v1::{
+    AwsIamRoleConfig, CreateCredentialRequest, Purpose,
+};
+use unitycatalog_common::models::external_locations::v1::CreateExternalLocationRequest;
+use unitycatalog_common::models::shares::v1::{
+    Action, CreateShareRequest, DataObject, DataObjectType, DataObjectUpdate, HistoryStatus,
+    UpdateShareRequest,
+};
+use unitycatalog_common::models::tables::v1::{CreateTableRequest, DataSourceFormat, TableType};
+use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+use unitycatalog_server::api::{
+    CredentialHandler, ExternalLocationHandler, RequestContext, ShareHandler, TableHandler,
+};
+use unitycatalog_server::memory::InMemoryResourceStore;
+use unitycatalog_server::policy::{ConstantPolicy, Policy, Principal};
+use unitycatalog_server::rest::create_sharing_router;
+use unitycatalog_server::services::{LocalStoragePolicy, ServerHandler};
+use unitycatalog_sharing_client::client::DeltaSharingClient;
+use unitycatalog_sharing_client::models::open_sharing::v1::QueryTableRequest;
+use unitycatalog_sharing_client::reader::SharedTableProvider;
+
+fn ctx() -> RequestContext {
+    RequestContext {
+        recipient: Principal::anonymous(),
+    }
+}
+
+fn handler(root: &std::path::Path) -> ServerHandler<RequestContext> {
+    let encryptor =
+        EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+    let store = Arc::new(InMemoryResourceStore::new(encryptor));
+    let policy: Arc<dyn Policy<RequestContext>> = Arc::new(ConstantPolicy::default());
+    ServerHandler::try_new_tokio(policy, store.clone(), store)
+        .unwrap()
+        .with_local_storage_policy(LocalStoragePolicy::new([root]).unwrap())
+}
+
+/// Signs urls as `<base>/<path>`, for the file server started by [`serve`].
+#[derive(Debug)]
+struct FileServerSigner {
+    base: url::Url,
+}
+
+#[async_trait::async_trait]
+impl Signer for FileServerSigner {
+    async fn signed_url(
+        &self,
+        _method: Method,
+        path: &Path,
+        _expires_in: Duration,
+    ) -> object_store::Result<url::Url> {
+        Ok(self.base.join(path.as_ref()).unwrap())
+    }
+}
+
+/// Write a Delta table partitioned by `region` at `location`: version 0
+/// creates it with the `eu` rows and version 1 adds the `us` rows.
+///
+/// The log is written by hand so the partition values are plain to see.
+fn write_table(location: &std::path::Path) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let schema_string = serde_json::json!({
+        "type": "struct",
+        "fields": [
+            {"name": "id", "type": "long", "nullable": false, "metadata": {}},
+            {"name": "name", "type": "string", "nullable": true, "metadata": {}},
+            {"name": "region", "type": "string", "nullable": true, "metadata": {}},
+        ],
+    });
+    std::fs::create_dir_all(location.join("_delta_log")).unwrap();
+    for (version, region, ids, names) in [
+        (0, "eu", vec![1, 2], vec!["a", "b"]),
+        (1, "us", vec![3], vec!["c"]),
+    ] {
+        let path = format!("region={region}/part-{version}.parquet");
+        std::fs::create_dir_all(location.join(format!("region={region}"))).unwrap();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap();
+        let file = std::fs::File::create(location.join(&path)).unwrap();
+        let mut writer = ArrowWriter::try_new(file, schema.clone(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let size = std::fs::metadata(location.join(&path)).unwrap().len();
+
+        let mut actions = Vec::new();
+        if version == 0 {
+            actions.push(serde_json::json!({
+                "protocol": {"minReaderVersion": 1, "minWriterVersion": 2}
+            }));
+            actions.push(serde_json::json!({"metaData": {
+                "id": "5f3c0a52-8d2b-4a7e-9a0f-3f1f6f0f9d10",
+                "format": {"provider": "parquet", "options": {}},
+                "schemaString": schema_string.to_string(),
+                "partitionColumns": ["region"],
+                "configuration": {},
+                "createdTime": 0,
+            }}));
+        }
+        actions.push(serde_json::json!({"add": {
+            "path": path,
+            "partitionValues": {"region": region},
+            "size": size,
+            "modificationTime": 0,
+            "dataChange": true,
+        }}));
+        let commit: String = actions.iter().map(|a| format!("{a}\n")).collect();
+        std::fs::write(
+            location.join(format!("_delta_log/{version:020}.json")),
+            commit,
+        )
+        .unwrap();
+    }
+}
+
+/// Register the table at `location` as `cat.sch.t` and share it as `sch.t` in
+/// the share `share`.
+async fn share_table(h: &ServerHandler<RequestContext>, root: &url::Url, location: &url::Url) {
+    h.create_credential(
+        CreateCredentialRequest {
+            name: "cred".to_string(),
+            purpose: Purpose::Storage as i32,
+            aws_iam_role: Some(AwsIamRoleConfig {
+                role_arn: "arn:aws:iam::123456789012:role/test".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ctx(),
+    )
+    .await
+    .unwrap();
+    h.create_external_location(
+        CreateExternalLocationRequest {
+            name: "local".to_string(),
+            url: root.to_string(),
+            credential_name: "cred".to_string(),
+            ..Default::default()
+        },
+        ctx(),
+    )
+    .await
+    .unwrap();
+    h.create_table(
+        CreateTableRequest {
+            name: "t".to_string(),
+            schema_name: "sch".to_string(),
+            catalog_name: "cat".to_string(),
+            table_type: TableType::External as i32,
+            data_source_format: DataSourceFormat::Delta as i32,
+            storage_location: Some(location.to_string()),
+            ..Default::default()
+        },
+        ctx(),
+    )
+    .await
+    .unwrap();
+    h.create_share(
+        CreateShareRequest {
+            name: "share".to_string(),
+            ..Default::default()
+        },
+        ctx(),
+    )
+    .await
+    .unwrap();
+    h.update_share(
+        UpdateShareRequest {
+            name: "share".to_string(),
+            updates: vec![DataObjectUpdate {
+                action: Action::Add as i32,
+                data_object: Some(DataObject {
+                    name: "cat.sch.t".to_string(),
+                    data_object_type: DataObjectType::Table as i32,
+                    shared_as: Some("sch.t".to_string()),
+                    history_data_sharing_status: Some(HistoryStatus::Enabled as i32),
+                    ..Default::default()
+                }),
+            }],
+            ..Default::default()
+        },
+        ctx(),
+    )
+    .await
+    .unwrap();
+}
+
+/// Serve the Delta Sharing API under `/api/v1/delta-sharing`, and every
+/// local file under `/files`, on `listener`.
+fn serve(listener: tokio::net::TcpListener, h: ServerHandler<RequestContext>) {
+    let files = axum::routing::get(
+        |axum::extract::Path(path): axum::extract::Path<String>| async move {
+            std::fs::read(format!("/{path}")).map_err(|_| http::StatusCode::NOT_FOUND)
+        },
+    );
+    let router = axum::Router::new()
+        .nest("/api/v1/delta-sharing", create_sharing_router(h))
+        .route("/files/{*path}", files);
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+}
+
+/// Share the table written under `root` and serve it on a free local port,
+/// returning a client of the server. With `signed`, data file urls point at
+/// the server's `/files` route.
+async fn setup(root: &std::path::Path, signed: bool) -> DeltaSharingClient {
+    let root_url = url::Url::from_directory_path(root).unwrap();
+    let location = root_url.join("t/").unwrap();
+    write_table(&root.join("t"));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = url::Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let mut h = handler(root);
+    if signed {
+        h = h.with_url_signer(Arc::new(FileServerSigner {
+            base: base.join("files/").unwrap(),
+        }));
+    }
+    share_table(&h, &root_url, &location).await;
+    serve(listener, h);
+    DeltaSharingClient::new(CloudClient::new_unauthenticated(), base)
+}
+
+fn sorted_rows(batches: &[RecordBatch]) -> Vec<(i64, String, String)> {
+    let mut rows = Vec::new();
+    for batch in batches {
+        let column = |name: &str| batch.column(batch.schema().index_of(name).unwrap()).clone();
+        let (ids, names, regions) = (column("id"), column("name"), column("region"));
+        for row in 0..batch.num_rows() {
+            rows.push((
+                ids.as_primitive::<Int64Type>().value(row),
+                names.as_string::<i32>().value(row).to_string(),
+                regions.as_string::<i32>().value(row).to_string(),
+            ));
+        }
+    }
+    rows.sort();
+    rows
+}
+
+#[tokio::test]
+async fn shared_table_is_read_with_partition_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let client = setup(&root, true).await;
+
+    let reader = client
+        .table_reader("share", "sch", "t")
+        .with_concurrency(2)
+        .with_allow_http(true);
+    let schema = reader.schema().await.unwrap();
+    let fields: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(fields, vec!["id", "name", "region"]);
+
+    let response = reader.query(QueryTableRequest::default()).await.unwrap();
+    let mut partitions: Vec<_> = response
+        .files
+        .iter()
+        .map(|f| f.action.partition_values["region"].clone())
+        .collect();
+    partitions.sort();
+    assert_eq!(
+        partitions,
+        vec![Some("eu".to_string()), Some("us".to_string())]
+    );
+
+    let batches: Vec<RecordBatch> = reader
+        .read(QueryTableRequest::default())
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let expected = |rows: &[(i64, &str, &str)]| {
+        rows.iter()
+            .map(|(id, name, region)| (*id, name.to_string(), region.to_string()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        sorted_rows(&batches),
+        expected(&[(1, "a", "eu"), (2, "b", "eu"), (3, "c", "us")])
+    );
+
+    // A query at a version serves the files of exactly that version.
+    let batches: Vec<RecordBatch> = reader
+        .read(QueryTableRequest {
+            version: Some(0),
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        sorted_rows(&batches),
+        expected(&[(1, "a", "eu"), (2, "b", "eu")])
+    );
+
+    let provider = SharedTableProvider::try_new(reader).await.unwrap();
+    let session = SessionContext::new();
+    session.register_table("t", Arc::new(provider)).unwrap();
+    let batches = session
+        .sql("SELECT id, name, region FROM t WHERE id > 1 ORDER BY id")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        pretty_format_batches(&batches).unwrap().to_string(),
+        [
+            "+----+------+--------+",
+            "| id | name | region |",
+            "+----+------+--------+",
+            "| 2  | b    | eu     |",
+            "| 3  | c    | us     |",
+            "+----+------+--------+",
+        ]
+        .join("\n")
+    );
+}
+
+#[tokio::test]
+async fn local_shared_table_without_a_signer_serves_metadata_but_not_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let client = setup(&root, false).await;
+
+    let reader = client
+        .table_reader("share", "sch", "t")
+        .with_allow_http(true);
+    let schema = reader.schema().await.unwrap();
+    assert_eq!(schema.fields().len(), 3);
+    assert!(reader.query(QueryTableRequest::default()).await.is_err());
+}
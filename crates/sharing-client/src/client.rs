@@ -1,31 +1,61 @@
+use std::path::Path;
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
 use futures::stream::BoxStream;
 use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use olai_http::CloudClient;
 
 use super::utils::stream_paginated;
-use crate::codegen::sharing::SharingClient;
-use crate::codegen::sharing::builders::GetShareBuilder;
 use crate::models::open_sharing::v1::*;
 use crate::models::{MetadataResponse, MetadataResponseData, ProtocolResponseData};
+use crate::profile::{
+    Connection, OAuthConnector, ProfileCredentials, SharingProfile, check_expiry,
+};
 use crate::{Error, Result};
 
 #[derive(Clone)]
 pub struct DeltaSharingClient {
-    client: CloudClient,
     base_url: url::Url,
-    discovery: SharingClient,
+    auth: Auth,
+}
+
+/// Builder for [`DeltaSharingClient::get_share`]; await it to fetch the share.
+pub struct GetShareBuilder {
+    client: DeltaSharingClient,
+    request: GetShareRequest,
+}
+
+impl IntoFuture for GetShareBuilder {
+    type Output = Result<Share>;
+    type IntoFuture = BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let connection = self.client.connection().await?;
+            connection.discovery.get_share(&self.request).await
+        })
+    }
+}
+
+/// Where a client gets the credentials for its requests from.
+#[derive(Clone)]
+enum Auth {
+    /// A fixed client, optionally only valid until `expires_at`.
+    Static {
+        connection: Arc<Connection>,
+        expires_at: Option<DateTime<Utc>>,
+    },
+    /// Access tokens fetched (and refreshed) via OAuth client credentials.
+    OAuth(Arc<OAuthConnector>),
 }
 
 impl DeltaSharingClient {
     pub fn new(client: CloudClient, base_url: url::Url) -> Self {
         let base_url = base_url.join("api/v1/delta-sharing/").unwrap();
-        Self {
-            discovery: SharingClient::new(client.clone(), base_url.clone()),
-            client,
-            base_url,
-        }
+        Self::new_static(client, base_url, None)
     }
 
     pub fn new_with_prefix(
@@ -39,13 +69,78 @@ impl DeltaSharingClient {
         } else {
             base_url.join(&prefix).unwrap()
         };
+        Self::new_static(client, base_url, None)
+    }
+
+    /// Create a client from a Delta Sharing profile file (`config.share`).
+    ///
+    /// Fails if the profile's bearer token has already expired. Requests made
+    /// after it expires fail with [`Error::ProfileExpired`]. OAuth profiles
+    /// fetch an access token on first use and refresh it before it expires.
+    pub fn from_profile(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_sharing_profile(&SharingProfile::from_path(path)?)
+    }
+
+    /// Create a client from parsed profile contents.
+    ///
+    /// See [`DeltaSharingClient::from_profile`].
+    pub fn from_sharing_profile(profile: &SharingProfile) -> Result<Self> {
+        let base_url = profile.endpoint()?;
+        match profile.credentials()? {
+            ProfileCredentials::BearerToken { token, expires_at } => {
+                check_expiry(expires_at)?;
+                Ok(Self::new_static(
+                    CloudClient::new_with_token(token),
+                    base_url,
+                    expires_at,
+                ))
+            }
+            ProfileCredentials::OAuthClientCredentials {
+                token_endpoint,
+                client_id,
+                client_secret,
+                scope,
+            } => Ok(Self {
+                auth: Auth::OAuth(Arc::new(OAuthConnector::new(
+                    token_endpoint,
+                    client_id,
+                    client_secret,
+                    scope,
+                    base_url.clone(),
+                ))),
+                base_url,
+            }),
+        }
+    }
+
+    fn new_static(
+        client: CloudClient,
+        base_url: url::Url,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
         Self {
-            discovery: SharingClient::new(client.clone(), base_url.clone()),
-            client,
+            auth: Auth::Static {
+                connection: Arc::new(Connection::new(client, base_url.clone())),
+                expires_at,
+            },
             base_url,
         }
     }
 
+    /// The clients to issue the next request with.
+    async fn connection(&self) -> Result<Arc<Connection>> {
+        match &self.auth {
+            Auth::Static {
+                connection,
+                expires_at,
+            } => {
+                check_expiry(*expires_at)?;
+                Ok(connection.clone())
+            }
+            Auth::OAuth(connector) => connector.connection().await,
+        }
+    }
+
     pub fn list_shares(&self, max_results: impl Into<Option<i32>>) -> BoxStream<'_, Result<Share>> {
         let max_results = max_results.into();
         stream_paginated(max_results, move |mut max_results, page_token| async move {
@@ -53,7 +148,12 @@ impl DeltaSharingClient {
                 max_results,
                 page_token,
             };
-            let res = self.discovery.list_shares(&request).await?;
+            let res = self
+                .connection()
+                .await?
+                .discovery
+                .list_shares(&request)
+                .await?;
 
             // Update max_results for next page based on items received
             if let Some(ref mut remaining) = max_results {
@@ -71,7 +171,10 @@ impl DeltaSharingClient {
     }
 
     pub fn get_share(&self, name: impl Into<String>) -> GetShareBuilder {
-        GetShareBuilder::new(self.discovery.clone(), name)
+        GetShareBuilder {
+            client: self.clone(),
+            request: GetShareRequest { name: name.into() },
+        }
     }
 
     pub fn list_share_schemas(
@@ -90,6 +193,8 @@ impl DeltaSharingClient {
                     page_token,
                 };
                 let res = self
+                    .connection()
+                    .await?
                     .discovery
                     .list_schemas(&request)
                     .await
@@ -127,6 +232,8 @@ impl DeltaSharingClient {
                     page_token,
                 };
                 let res = self
+                    .connection()
+                    .await?
                     .discovery
                     .list_all_tables(&request)
                     .await
@@ -167,6 +274,8 @@ impl DeltaSharingClient {
                     page_token,
                 };
                 let res = self
+                    .connection()
+                    .await?
                     .discovery
                     .list_tables(&request)
                     .await
@@ -205,7 +314,7 @@ impl DeltaSharingClient {
             url.query_pairs_mut()
                 .append_pair("startingTimestamp", &ts.to_rfc3339());
         }
-        let response = self.client.get(url).send().await?;
+        let response = self.connection().await?.client.get(url).send().await?;
         response.error_for_status_ref()?;
         let headers = response.headers();
         let version = headers
@@ -231,12 +340,13 @@ impl DeltaSharingClient {
             schema.into(),
             name.into()
         ))?;
-        let response = self.client.get(url).send().await?;
+        let response = self.connection().await?.client.get(url).send().await?;
         response.error_for_status_ref()?;
         let result = response.bytes().await?;
         // split newlines and parse each as json
         let line_data: Vec<MetadataResponse> = result
             .split(|c| *c == b'\n')
+            .filter(|line| !line.is_empty())
             .map(::serde_json::from_slice)
             .try_collect()?;
         let mut protocol = None;
@@ -274,7 +384,14 @@ impl DeltaSharingClient {
             schema.into(),
             name.into()
         ))?;
-        let response = self.client.post(url).json(request).send().await?;
+        let response = self
+            .connection()
+            .await?
+            .client
+            .post(url)
+            .json(request)
+            .send()
+            .await?;
         response.error_for_status_ref()?;
         Ok(response.bytes().await?.to_vec())
     }
@@ -41,6 +41,9 @@ pub enum Error {
     #[error("Invalid predicate: {0}")]
     InvalidPredicate(String),
 
+    #[error("Sharing profile expired at {0}")]
+    ProfileExpired(chrono::DateTime<chrono::Utc>),
+
     #[error("Generic error: {0}")]
     Generic(String),
 }
@@ -77,6 +80,7 @@ impl Error {
                 "INVALID_PARAMETER_VALUE"
             }
             Error::Common { source } => source.error_code(),
+            Error::ProfileExpired(_) => "UNAUTHENTICATED",
             _ => "INTERNAL_ERROR",
         }
     }
@@ -148,6 +152,13 @@ mod server {
                         "Invalid predicate provided in the request.",
                     )
                 }
+                Error::ProfileExpired(expired_at) => {
+                    error!("Sharing profile expired at {}", expired_at);
+                    (
+                        StatusCode::UNAUTHORIZED,
+                        "The sharing profile's bearer token has expired.",
+                    )
+                }
                 Error::Generic(message) => {
                     error!("Generic error: {}", message);
                     INTERNAL_ERROR
@@ -165,3 +176,14 @@ mod server {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_profiles_are_authentication_errors() {
+        let err = Error::ProfileExpired(chrono::DateTime::UNIX_EPOCH);
+        assert_eq!(err.error_code(), "UNAUTHENTICATED");
+    }
+}
@@ -9,6 +9,9 @@ pub mod client;
 mod codegen;
 pub mod error;
 pub mod models;
+pub mod profile;
+#[cfg(feature = "reader")]
+pub mod reader;
 mod utils;
 
 // The generated axum request extractors live under `codegen/extractors` (a
@@ -316,6 +316,13 @@ impl MetadataResponseData {
         }
     }
 
+    pub fn schema_string(&self) -> &str {
+        match self {
+            MetadataResponseData::DeltaMetadata(metadata) => &metadata.delta_metadata.schema_string,
+            MetadataResponseData::ParquetMetadata(metadata) => &metadata.schema_string,
+        }
+    }
+
     pub fn partition_columns(&self) -> &[String] {
         match self {
             MetadataResponseData::DeltaMetadata(metadata) => {
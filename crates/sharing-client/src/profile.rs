@@ -0,0 +1,384 @@
+//! Delta Sharing profile files.
+//!
+//! Providers hand recipients a profile file (conventionally `config.share`)
+//! naming the sharing endpoint and the credentials to reach it. Two shapes
+//! are supported:
+//!
+//! - a bearer token, optionally with an `expirationTime`;
+//! - OAuth client credentials (`"type": "oauth_client_credentials"`), where
+//!   access tokens are fetched from `tokenEndpoint` and refreshed as they
+//!   approach expiry.
+//!
+//! See the [profile file format].
+//!
+//! [profile file format]: https://github.com/delta-io/delta-sharing/blob/main/PROTOCOL.md#profile-file-format
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use olai_http::{CloudClient, TemporaryToken, TokenCache};
+use serde::Deserialize;
+use url::Url;
+
+use crate::codegen::sharing::SharingClient;
+use crate::{Error, Result};
+
+const BEARER_TOKEN: &str = "bearer_token";
+const OAUTH_CLIENT_CREDENTIALS: &str = "oauth_client_credentials";
+
+/// The contents of a Delta Sharing profile file.
+#[derive(Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharingProfile {
+    /// Version of the profile format.
+    pub share_credentials_version: i32,
+    /// Credential type for version 2 profiles; version 1 profiles always use
+    /// a bearer token.
+    #[serde(default, rename = "type")]
+    pub credential_type: Option<String>,
+    /// Base url of the sharing server.
+    pub endpoint: String,
+    #[serde(default)]
+    pub bearer_token: Option<String>,
+    /// When the bearer token expires, as an ISO 8601 timestamp.
+    #[serde(default)]
+    pub expiration_time: Option<String>,
+    #[serde(default)]
+    pub token_endpoint: Option<String>,
+    #[serde(default)]
+    pub client_id: Option<String>,
+    #[serde(default)]
+    pub client_secret: Option<String>,
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl std::fmt::Debug for SharingProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharingProfile")
+            .field("share_credentials_version", &self.share_credentials_version)
+            .field("credential_type", &self.credential_type)
+            .field("endpoint", &self.endpoint)
+            .field("expiration_time", &self.expiration_time)
+            .field("token_endpoint", &self.token_endpoint)
+            .field("client_id", &self.client_id)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How requests made with a profile are authenticated.
+#[derive(Clone, PartialEq, Eq)]
+pub enum ProfileCredentials {
+    /// A static bearer token.
+    BearerToken {
+        token: String,
+        expires_at: Option<DateTime<Utc>>,
+    },
+    /// OAuth 2.0 client credentials grant.
+    OAuthClientCredentials {
+        token_endpoint: Url,
+        client_id: String,
+        client_secret: String,
+        scope: Option<String>,
+    },
+}
+
+impl std::fmt::Debug for ProfileCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BearerToken { expires_at, .. } => f
+                .debug_struct("BearerToken")
+                .field("expires_at", expires_at)
+                .finish_non_exhaustive(),
+            Self::OAuthClientCredentials {
+                token_endpoint,
+                client_id,
+                scope,
+                ..
+            } => f
+                .debug_struct("OAuthClientCredentials")
+                .field("token_endpoint", token_endpoint)
+                .field("client_id", client_id)
+                .field("scope", scope)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl SharingProfile {
+    /// Read a profile file.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path).map_err(|e| {
+            Error::InvalidArgument(format!(
+                "failed to read sharing profile '{}': {e}",
+                path.display()
+            ))
+        })?;
+        Self::from_slice(&data)
+    }
+
+    /// Parse the JSON contents of a profile file.
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(data)?)
+    }
+
+    /// The sharing endpoint, with a trailing slash so request paths resolve
+    /// beneath it.
+    pub fn endpoint(&self) -> Result<Url> {
+        let mut endpoint = Url::parse(&self.endpoint)?;
+        if !endpoint.path().ends_with('/') {
+            endpoint.set_path(&format!("{}/", endpoint.path()));
+        }
+        Ok(endpoint)
+    }
+
+    /// Validate the profile and extract its credentials.
+    pub fn credentials(&self) -> Result<ProfileCredentials> {
+        let credential_type = match self.share_credentials_version {
+            1 => BEARER_TOKEN,
+            2 => self.credential_type.as_deref().unwrap_or(BEARER_TOKEN),
+            version => {
+                return Err(Error::InvalidArgument(format!(
+                    "unsupported shareCredentialsVersion {version}"
+                )));
+            }
+        };
+        match credential_type {
+            BEARER_TOKEN => Ok(ProfileCredentials::BearerToken {
+                token: required(&self.bearer_token, "bearerToken")?.to_string(),
+                expires_at: self
+                    .expiration_time
+                    .as_deref()
+                    .map(parse_expiration_time)
+                    .transpose()?,
+            }),
+            OAUTH_CLIENT_CREDENTIALS => Ok(ProfileCredentials::OAuthClientCredentials {
+                token_endpoint: Url::parse(required(&self.token_endpoint, "tokenEndpoint")?)?,
+                client_id: required(&self.client_id, "clientId")?.to_string(),
+                client_secret: required(&self.client_secret, "clientSecret")?.to_string(),
+                scope: self.scope.clone(),
+            }),
+            other => Err(Error::InvalidArgument(format!(
+                "unsupported sharing profile type '{other}'"
+            ))),
+        }
+    }
+}
+
+fn required<'a>(value: &'a Option<String>, field: &str) -> Result<&'a str> {
+    value
+        .as_deref()
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| Error::InvalidArgument(format!("sharing profile is missing '{field}'")))
+}
+
+fn parse_expiration_time(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|ts| ts.with_timezone(&Utc))
+        .map_err(|e| Error::InvalidArgument(format!("invalid expirationTime '{value}': {e}")))
+}
+
+/// Fail if a bearer token expiring at `expires_at` is no longer valid.
+pub(crate) fn check_expiry(expires_at: Option<DateTime<Utc>>) -> Result<()> {
+    match expires_at {
+        Some(expires_at) if expires_at <= Utc::now() => Err(Error::ProfileExpired(expires_at)),
+        _ => Ok(()),
+    }
+}
+
+/// The HTTP clients used for requests authenticated with one access token.
+#[derive(Clone)]
+pub(crate) struct Connection {
+    pub(crate) client: CloudClient,
+    pub(crate) discovery: SharingClient,
+}
+
+impl Connection {
+    pub(crate) fn new(client: CloudClient, base_url: Url) -> Self {
+        Self {
+            discovery: SharingClient::new(client.clone(), base_url),
+            client,
+        }
+    }
+}
+
+/// Fetches OAuth access tokens for a profile and caches a [`Connection`] per
+/// token, so a fresh one is only built once the previous token nears expiry.
+pub(crate) struct OAuthConnector {
+    http: reqwest::Client,
+    token_endpoint: Url,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    base_url: Url,
+    cache: TokenCache<Arc<Connection>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl OAuthConnector {
+    pub(crate) fn new(
+        token_endpoint: Url,
+        client_id: String,
+        client_secret: String,
+        scope: Option<String>,
+        base_url: Url,
+    ) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            token_endpoint,
+            client_id,
+            client_secret,
+            scope,
+            base_url,
+            cache: TokenCache::default(),
+        }
+    }
+
+    /// A connection authenticated with a currently valid access token.
+    pub(crate) async fn connection(&self) -> Result<Arc<Connection>> {
+        self.cache
+            .get_or_insert_with(|| async {
+                let token = self.fetch_token().await?;
+                let connection = Connection::new(
+                    CloudClient::new_with_token(token.access_token),
+                    self.base_url.clone(),
+                );
+                Ok::<_, Error>(TemporaryToken {
+                    token: Arc::new(connection),
+                    expiry: token
+                        .expires_in
+                        .map(|secs| Instant::now() + Duration::from_secs(secs)),
+                })
+            })
+            .await
+    }
+
+    async fn fetch_token(&self) -> Result<TokenResponse> {
+        let mut form = url::form_urlencoded::Serializer::new(String::new());
+        form.append_pair("grant_type", "client_credentials")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("client_secret", &self.client_secret);
+        if let Some(scope) = &self.scope {
+            form.append_pair("scope", scope);
+        }
+        let response = self
+            .http
+            .post(self.token_endpoint.clone())
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(form.finish())
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(crate::error::parse_error_response(response).await);
+        }
+        Ok(serde_json::from_slice(&response.bytes().await?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bearer_profile() {
+        let profile = SharingProfile::from_slice(
+            br#"{
+                "shareCredentialsVersion": 1,
+                "endpoint": "https://sharing.example.com/delta-sharing",
+                "bearerToken": "tok",
+                "expirationTime": "2021-11-12T00:12:29.0Z"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            profile.endpoint().unwrap().as_str(),
+            "https://sharing.example.com/delta-sharing/"
+        );
+        let ProfileCredentials::BearerToken { token, expires_at } = profile.credentials().unwrap()
+        else {
+            panic!("expected a bearer token");
+        };
+        assert_eq!(token, "tok");
+        let expires_at = expires_at.unwrap();
+        assert_eq!(expires_at.to_rfc3339(), "2021-11-12T00:12:29+00:00");
+        assert!(matches!(
+            check_expiry(Some(expires_at)),
+            Err(Error::ProfileExpired(_))
+        ));
+        assert!(matches!(
+            crate::client::DeltaSharingClient::from_sharing_profile(&profile),
+            Err(Error::ProfileExpired(_))
+        ));
+        check_expiry(None).unwrap();
+        check_expiry(Some(Utc::now() + chrono::Duration::hours(1))).unwrap();
+    }
+
+    #[test]
+    fn parses_oauth_profile() {
+        let profile = SharingProfile::from_slice(
+            br#"{
+                "shareCredentialsVersion": 2,
+                "type": "oauth_client_credentials",
+                "endpoint": "https://sharing.example.com/delta-sharing/",
+                "tokenEndpoint": "https://login.example.com/oauth2/token",
+                "clientId": "client",
+                "clientSecret": "secret",
+                "scope": "sharing"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            profile.credentials().unwrap(),
+            ProfileCredentials::OAuthClientCredentials {
+                token_endpoint: Url::parse("https://login.example.com/oauth2/token").unwrap(),
+                client_id: "client".to_string(),
+                client_secret: "secret".to_string(),
+                scope: Some("sharing".to_string()),
+            }
+        );
+        assert!(!format!("{profile:?}").contains("secret\""));
+    }
+
+    #[test]
+    fn rejects_incomplete_profiles() {
+        let missing_token = SharingProfile::from_slice(
+            br#"{"shareCredentialsVersion": 1, "endpoint": "https://host/"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            missing_token.credentials(),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let unknown_type = SharingProfile::from_slice(
+            br#"{"shareCredentialsVersion": 2, "type": "basic", "endpoint": "https://host/"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            unknown_type.credentials(),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let future_version = SharingProfile::from_slice(
+            br#"{"shareCredentialsVersion": 3, "endpoint": "https://host/", "bearerToken": "t"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            future_version.credentials(),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
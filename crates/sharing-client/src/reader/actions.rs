@@ -0,0 +1,207 @@
+//! Parsing the newline-delimited JSON body of a `query_table` response.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{MetadataResponseData, ProtocolResponseData};
+use crate::{Error, Result};
+
+/// A data file a sharing server hands out for a query.
+///
+/// Snapshot queries return `file` actions; change-data-feed queries return
+/// `add`, `cdf` and `remove` actions, which share this shape and additionally
+/// carry the commit `version` and `timestamp` they belong to.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAction {
+    /// Location to download the file from, usually a pre-signed URL.
+    pub url: String,
+    /// Stable identifier of the file within the table.
+    pub id: String,
+    /// Partition values of the file, serialized as Delta partition strings.
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    /// Size of the file in bytes.
+    pub size: i64,
+    /// File statistics as a JSON string, if the server includes them.
+    #[serde(default)]
+    pub stats: Option<String>,
+    /// Table version the action was committed in.
+    #[serde(default)]
+    pub version: Option<i64>,
+    /// Commit timestamp in milliseconds since the Unix epoch.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+    /// When `url` stops being valid, in milliseconds since the Unix epoch.
+    #[serde(default)]
+    pub expiration_timestamp: Option<i64>,
+}
+
+/// How a file contributes to a query result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileActionKind {
+    /// A data file of the queried snapshot.
+    File,
+    /// A data file added by a commit (change data feed).
+    Add,
+    /// A change data file written by a commit (change data feed).
+    Cdf,
+    /// A data file removed by a commit (change data feed).
+    Remove,
+}
+
+impl FileActionKind {
+    /// The `_change_type` rows of this file carry in a change data feed, if it
+    /// is implied by the action rather than stored in the file.
+    pub fn change_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Add => Some("insert"),
+            Self::Remove => Some("delete"),
+            Self::File | Self::Cdf => None,
+        }
+    }
+}
+
+/// A [`FileAction`] tagged with the line type it was received as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedFile {
+    pub kind: FileActionKind,
+    pub action: FileAction,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum QueryResponseLine {
+    Protocol(ProtocolResponseData),
+    MetaData(MetadataResponseData),
+    File(FileAction),
+    Add(FileAction),
+    Cdf(FileAction),
+    Remove(FileAction),
+    EndStreamAction(serde_json::Value),
+}
+
+/// A parsed `query_table` response.
+#[derive(Debug)]
+pub struct QueryResponse {
+    pub protocol: ProtocolResponseData,
+    pub metadata: MetadataResponseData,
+    pub files: Vec<SharedFile>,
+}
+
+impl QueryResponse {
+    /// Parse the NDJSON body of a `query_table` response.
+    ///
+    /// The protocol and metadata lines are required; blank lines are ignored.
+    pub fn parse(body: &[u8]) -> Result<Self> {
+        let mut protocol = None;
+        let mut metadata = None;
+        let mut files = Vec::new();
+        for line in body.split(|c| *c == b'\n') {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let (kind, action) = match serde_json::from_slice(line)? {
+                QueryResponseLine::Protocol(p) => {
+                    protocol = Some(p);
+                    continue;
+                }
+                QueryResponseLine::MetaData(m) => {
+                    metadata = Some(m);
+                    continue;
+                }
+                QueryResponseLine::EndStreamAction(_) => continue,
+                QueryResponseLine::File(a) => (FileActionKind::File, a),
+                QueryResponseLine::Add(a) => (FileActionKind::Add, a),
+                QueryResponseLine::Cdf(a) => (FileActionKind::Cdf, a),
+                QueryResponseLine::Remove(a) => (FileActionKind::Remove, a),
+            };
+            files.push(SharedFile { kind, action });
+        }
+        Ok(Self {
+            protocol: protocol.ok_or_else(|| Error::generic("Protocol not found"))?,
+            metadata: metadata.ok_or_else(|| Error::generic("Metadata not found"))?,
+            files,
+        })
+    }
+
+    /// Whether the response describes a change data feed rather than a
+    /// snapshot.
+    pub fn is_change_feed(&self) -> bool {
+        self.files.iter().any(|f| f.kind != FileActionKind::File)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}"#;
+
+    fn metadata_line() -> String {
+        format!(
+            r#"{{"metaData":{{"id":"t1","format":{{"provider":"parquet"}},"schemaString":"{SCHEMA}","partitionColumns":["date"],"configuration":{{}}}}}}"#
+        )
+    }
+
+    #[test]
+    fn parses_snapshot_response() {
+        let body = format!(
+            "{}\n{}\n{}\n\n",
+            r#"{"protocol":{"minReaderVersion":1}}"#,
+            metadata_line(),
+            r#"{"file":{"url":"https://host/f1.parquet?sig=1","id":"f1","partitionValues":{"date":"2024-01-01"},"size":10,"expirationTimestamp":1700000000000}}"#,
+        );
+        let response = QueryResponse::parse(body.as_bytes()).unwrap();
+        assert_eq!(response.protocol.min_reader_version(), 1);
+        assert_eq!(response.metadata.id(), "t1");
+        assert_eq!(response.metadata.partition_columns(), ["date".to_string()]);
+        assert!(!response.is_change_feed());
+        assert_eq!(response.files.len(), 1);
+        let file = &response.files[0];
+        assert_eq!(file.kind, FileActionKind::File);
+        assert_eq!(
+            file.action.partition_values.get("date"),
+            Some(&Some("2024-01-01".to_string()))
+        );
+        assert_eq!(file.action.expiration_timestamp, Some(1700000000000));
+    }
+
+    #[test]
+    fn parses_change_feed_response() {
+        let body = [
+            r#"{"protocol":{"minReaderVersion":1}}"#.to_string(),
+            metadata_line(),
+            r#"{"add":{"url":"u1","id":"a","partitionValues":{},"size":1,"version":2,"timestamp":1000}}"#.to_string(),
+            r#"{"cdf":{"url":"u2","id":"c","partitionValues":{},"size":1,"version":3,"timestamp":2000}}"#.to_string(),
+            r#"{"remove":{"url":"u3","id":"r","partitionValues":{"date":null},"size":1,"version":4,"timestamp":3000}}"#.to_string(),
+            r#"{"endStreamAction":{"refreshToken":"tok"}}"#.to_string(),
+        ]
+        .join("\n");
+        let response = QueryResponse::parse(body.as_bytes()).unwrap();
+        assert!(response.is_change_feed());
+        let kinds: Vec<_> = response.files.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                FileActionKind::Add,
+                FileActionKind::Cdf,
+                FileActionKind::Remove
+            ]
+        );
+        assert_eq!(
+            response.files[2].action.partition_values.get("date"),
+            Some(&None)
+        );
+        assert_eq!(FileActionKind::Remove.change_type(), Some("delete"));
+        assert_eq!(FileActionKind::Cdf.change_type(), None);
+    }
+
+    #[test]
+    fn requires_protocol_and_metadata() {
+        let err = QueryResponse::parse(metadata_line().as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("Protocol not found"), "{err}");
+    }
+}
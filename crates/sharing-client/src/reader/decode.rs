@@ -0,0 +1,249 @@
+//! Turning downloaded Parquet data files into batches of the table schema.
+//!
+//! Shared data files only hold the table's non-partition columns. Partition
+//! values travel on the file action instead, so every decoded batch is
+//! conformed to the full table schema: partition columns are filled from the
+//! action, and for change data feed reads the `_change_type`,
+//! `_commit_version` and `_commit_timestamp` columns are appended.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use datafusion::arrow::array::{
+    ArrayRef, Int64Array, RecordBatch, StringArray, TimestampMicrosecondArray, new_null_array,
+};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use delta_kernel::engine::arrow_conversion::TryIntoArrow as _;
+use delta_kernel::schema::StructType;
+
+use super::actions::SharedFile;
+use crate::models::MetadataResponseData;
+use crate::{Error, Result};
+
+/// Change type of each row in a change data feed read.
+pub const CHANGE_TYPE_COLUMN: &str = "_change_type";
+/// Table version that committed each row of a change data feed read.
+pub const COMMIT_VERSION_COLUMN: &str = "_commit_version";
+/// Commit timestamp of each row of a change data feed read.
+pub const COMMIT_TIMESTAMP_COLUMN: &str = "_commit_timestamp";
+
+const COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+
+/// The Arrow schema of a shared table, optionally extended with the change
+/// data feed columns.
+pub(crate) fn table_schema(
+    metadata: &MetadataResponseData,
+    change_feed: bool,
+) -> Result<SchemaRef> {
+    // With column mapping the files use physical column names, which the
+    // sharing metadata does not expose in a form we can map back.
+    if let Some(mode) = metadata.configuration().get(COLUMN_MAPPING_MODE)
+        && !mode.eq_ignore_ascii_case("none")
+    {
+        return Err(Error::InvalidArgument(format!(
+            "reading shared tables with column mapping mode '{mode}' is not supported"
+        )));
+    }
+    let schema: StructType = serde_json::from_str(metadata.schema_string())?;
+    let schema: Schema = (&schema).try_into_arrow().map_err(Error::generic)?;
+    if !change_feed {
+        return Ok(Arc::new(schema));
+    }
+    let mut fields: Vec<_> = schema.fields().iter().cloned().collect();
+    fields.extend([
+        Arc::new(Field::new(CHANGE_TYPE_COLUMN, DataType::Utf8, true)),
+        Arc::new(Field::new(COMMIT_VERSION_COLUMN, DataType::Int64, true)),
+        Arc::new(Field::new(
+            COMMIT_TIMESTAMP_COLUMN,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            true,
+        )),
+    ]);
+    Ok(Arc::new(Schema::new_with_metadata(
+        fields,
+        schema.metadata().clone(),
+    )))
+}
+
+/// Decode a downloaded Parquet file into batches of `schema`.
+pub(crate) fn decode_file(
+    data: Bytes,
+    file: &SharedFile,
+    schema: &SchemaRef,
+) -> Result<Vec<RecordBatch>> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+        .and_then(|builder| builder.build())
+        .map_err(Error::generic)?;
+    reader
+        .map(|batch| conform_batch(&batch.map_err(Error::generic)?, file, schema))
+        .collect()
+}
+
+/// Project a file's batch onto the table schema.
+pub(crate) fn conform_batch(
+    batch: &RecordBatch,
+    file: &SharedFile,
+    schema: &SchemaRef,
+) -> Result<RecordBatch> {
+    let rows = batch.num_rows();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            if let Some(value) = file.action.partition_values.get(field.name()) {
+                return constant_column(value.as_deref(), field.data_type(), rows);
+            }
+            if let Some(column) = batch.column_by_name(field.name()) {
+                if column.data_type() == field.data_type() {
+                    return Ok(column.clone());
+                }
+                return cast(column, field.data_type()).map_err(Error::generic);
+            }
+            match field.name().as_str() {
+                CHANGE_TYPE_COLUMN => {
+                    constant_column(file.kind.change_type(), field.data_type(), rows)
+                }
+                COMMIT_VERSION_COLUMN => {
+                    Ok(Arc::new(Int64Array::from(vec![file.action.version; rows])) as ArrayRef)
+                }
+                COMMIT_TIMESTAMP_COLUMN => {
+                    let micros = file.action.timestamp.map(|ms| ms * 1000);
+                    Ok(Arc::new(
+                        TimestampMicrosecondArray::from(vec![micros; rows]).with_timezone("UTC"),
+                    ) as ArrayRef)
+                }
+                _ => Ok(new_null_array(field.data_type(), rows)),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema.clone(), columns).map_err(Error::generic)
+}
+
+/// A column repeating one serialized Delta partition value, cast to `data_type`.
+fn constant_column(value: Option<&str>, data_type: &DataType, rows: usize) -> Result<ArrayRef> {
+    let strings: ArrayRef = Arc::new(StringArray::from(vec![value; rows]));
+    if data_type == &DataType::Utf8 {
+        return Ok(strings);
+    }
+    cast(&strings, data_type).map_err(Error::generic)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use datafusion::arrow::array::{AsArray, Int32Array};
+    use datafusion::arrow::datatypes::{Date32Type, Int32Type, Int64Type};
+    use datafusion::parquet::arrow::ArrowWriter;
+
+    use super::*;
+    use crate::models::ParquetMetadata;
+    use crate::reader::actions::{FileAction, FileActionKind};
+
+    fn metadata(configuration: HashMap<String, String>) -> MetadataResponseData {
+        MetadataResponseData::ParquetMetadata(ParquetMetadata {
+            id: "t".to_string(),
+            name: None,
+            description: None,
+            format: Default::default(),
+            schema_string: r#"{"type":"struct","fields":[
+                {"name":"id","type":"integer","nullable":true,"metadata":{}},
+                {"name":"date","type":"date","nullable":true,"metadata":{}}]}"#
+                .to_string(),
+            partition_columns: vec!["date".to_string()],
+            configuration,
+            version: None,
+            size: None,
+            num_files: None,
+        })
+    }
+
+    fn file(kind: FileActionKind, date: Option<&str>) -> SharedFile {
+        SharedFile {
+            kind,
+            action: FileAction {
+                url: "file:///f.parquet".to_string(),
+                id: "f".to_string(),
+                partition_values: HashMap::from([("date".to_string(), date.map(String::from))]),
+                size: 0,
+                stats: None,
+                version: Some(7),
+                timestamp: Some(1_000),
+                expiration_timestamp: None,
+            },
+        }
+    }
+
+    fn parquet(ids: Vec<i32>) -> Bytes {
+        let batch =
+            RecordBatch::try_from_iter(vec![("id", Arc::new(Int32Array::from(ids)) as ArrayRef)])
+                .unwrap();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    #[test]
+    fn fills_partition_values_from_the_file_action() {
+        let schema = table_schema(&metadata(HashMap::new()), false).unwrap();
+        let batches = decode_file(
+            parquet(vec![1, 2]),
+            &file(FileActionKind::File, Some("2024-01-02")),
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec(),
+            vec![1, 2]
+        );
+        let dates = batch.column(1).as_primitive::<Date32Type>();
+        assert_eq!(dates.value(0), 19724);
+        assert_eq!(dates.value(1), 19724);
+
+        let batches =
+            decode_file(parquet(vec![3]), &file(FileActionKind::File, None), &schema).unwrap();
+        assert!(batches[0].column(1).is_null(0));
+    }
+
+    #[test]
+    fn appends_change_feed_columns() {
+        let schema = table_schema(&metadata(HashMap::new()), true).unwrap();
+        assert_eq!(schema.fields().len(), 5);
+        let batches = decode_file(
+            parquet(vec![1]),
+            &file(FileActionKind::Remove, Some("2024-01-02")),
+            &schema,
+        )
+        .unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.column(2).as_string::<i32>().value(0), "delete");
+        assert_eq!(batch.column(3).as_primitive::<Int64Type>().value(0), 7);
+        assert_eq!(
+            batch
+                .column(4)
+                .as_primitive::<datafusion::arrow::datatypes::TimestampMicrosecondType>()
+                .value(0),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn rejects_column_mapping() {
+        let config = HashMap::from([(COLUMN_MAPPING_MODE.to_string(), "name".to_string())]);
+        assert!(matches!(
+            table_schema(&metadata(config), false),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
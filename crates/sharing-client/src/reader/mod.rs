@@ -0,0 +1,246 @@
+//! Reading shared tables into Arrow record batches.
+//!
+//! A [`SharedTableReader`] runs a `query_table` request, downloads the data
+//! files the server hands out and decodes them into batches of the table
+//! schema. [`SharedTableProvider`] exposes the same reads to DataFusion.
+//!
+//! ```no_run
+//! # async fn example(client: unitycatalog_sharing_client::client::DeltaSharingClient) -> unitycatalog_sharing_client::Result<()> {
+//! use futures::TryStreamExt;
+//! use unitycatalog_sharing_client::models::open_sharing::v1::QueryTableRequest;
+//!
+//! let reader = client.table_reader("share", "schema", "table");
+//! let batches: Vec<_> = reader
+//!     .read(QueryTableRequest::default())
+//!     .await?
+//!     .try_collect()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::error::DataFusionError;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use futures::{StreamExt, TryStreamExt, stream};
+use url::Url;
+
+use crate::client::DeltaSharingClient;
+use crate::models::open_sharing::v1::QueryTableRequest;
+use crate::{Error, Result};
+
+pub use self::actions::{FileAction, FileActionKind, QueryResponse, SharedFile};
+pub use self::decode::{CHANGE_TYPE_COLUMN, COMMIT_TIMESTAMP_COLUMN, COMMIT_VERSION_COLUMN};
+pub use self::provider::SharedTableProvider;
+
+pub mod actions;
+mod decode;
+mod provider;
+
+/// Number of data files downloaded at the same time by default.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Reads the data of a single shared table.
+#[derive(Clone)]
+pub struct SharedTableReader {
+    client: DeltaSharingClient,
+    http: reqwest::Client,
+    share: String,
+    schema: String,
+    table: String,
+    concurrency: usize,
+    allow_http: bool,
+}
+
+impl std::fmt::Debug for SharedTableReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedTableReader")
+            .field("share", &self.share)
+            .field("schema", &self.schema)
+            .field("table", &self.table)
+            .field("concurrency", &self.concurrency)
+            .field("allow_http", &self.allow_http)
+            .finish()
+    }
+}
+
+impl DeltaSharingClient {
+    /// A reader for the table `share.schema.table`.
+    pub fn table_reader(
+        &self,
+        share: impl Into<String>,
+        schema: impl Into<String>,
+        table: impl Into<String>,
+    ) -> SharedTableReader {
+        SharedTableReader {
+            client: self.clone(),
+            http: reqwest::Client::new(),
+            share: share.into(),
+            schema: schema.into(),
+            table: table.into(),
+            concurrency: DEFAULT_CONCURRENCY,
+            allow_http: false,
+        }
+    }
+}
+
+impl SharedTableReader {
+    /// Download at most `concurrency` data files at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Use `http` to download data files.
+    ///
+    /// File URLs are pre-signed, so this client must not attach the sharing
+    /// server's credentials.
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Also download data files from plain `http` urls.
+    ///
+    /// Data files are only fetched over `https` by default. Enable this for
+    /// servers handing out urls of a local storage emulator.
+    pub fn with_allow_http(mut self, allow_http: bool) -> Self {
+        self.allow_http = allow_http;
+        self
+    }
+
+    /// Run a `query_table` request and parse the response.
+    ///
+    /// The share, schema and table name of `request` are set from the reader.
+    pub async fn query(&self, mut request: QueryTableRequest) -> Result<QueryResponse> {
+        request.share = self.share.clone();
+        request.schema = self.schema.clone();
+        request.name = self.table.clone();
+        let body = self
+            .client
+            .query_table(&self.share, &self.schema, &self.table, &request)
+            .await?;
+        QueryResponse::parse(&body)
+    }
+
+    /// The Arrow schema of the table's latest version.
+    pub async fn schema(&self) -> Result<SchemaRef> {
+        let (_, metadata) = self
+            .client
+            .get_table_metadata(&self.share, &self.schema, &self.table)
+            .await?;
+        decode::table_schema(&metadata, false)
+    }
+
+    /// Read the files selected by `request` as a stream of record batches.
+    ///
+    /// Change data feed responses yield the table columns followed by
+    /// [`CHANGE_TYPE_COLUMN`], [`COMMIT_VERSION_COLUMN`] and
+    /// [`COMMIT_TIMESTAMP_COLUMN`].
+    pub async fn read(&self, request: QueryTableRequest) -> Result<SendableRecordBatchStream> {
+        let change_feed = request.starting_version.is_some();
+        let response = self.query(request).await?;
+        let schema =
+            decode::table_schema(&response.metadata, change_feed || response.is_change_feed())?;
+
+        let http = self.http.clone();
+        let allow_http = self.allow_http;
+        let file_schema = schema.clone();
+        let batches = stream::iter(response.files)
+            .map(move |file| {
+                let http = http.clone();
+                let schema = file_schema.clone();
+                async move {
+                    let data = fetch(&http, &file.action, allow_http).await?;
+                    tokio::task::spawn_blocking(move || decode::decode_file(data, &file, &schema))
+                        .await
+                        .map_err(Error::generic)?
+                }
+            })
+            .buffered(self.concurrency)
+            .map_ok(|batches| stream::iter(batches.into_iter().map(Ok)))
+            .try_flatten()
+            .map_err(|e| DataFusionError::External(Box::new(e)));
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, batches)))
+    }
+
+    /// Read the change data feed between two versions, both inclusive.
+    pub async fn read_changes(
+        &self,
+        starting_version: i64,
+        ending_version: impl Into<Option<i64>>,
+    ) -> Result<SendableRecordBatchStream> {
+        self.read(QueryTableRequest {
+            starting_version: Some(starting_version),
+            ending_version: ending_version.into(),
+            ..Default::default()
+        })
+        .await
+    }
+}
+
+/// Download the contents of a data file from its pre-signed url.
+///
+/// Only `https` urls are followed, and `http` ones when `allow_http` is set.
+async fn fetch(http: &reqwest::Client, action: &FileAction, allow_http: bool) -> Result<Bytes> {
+    if let Some(expires) = action.expiration_timestamp
+        && expires <= chrono::Utc::now().timestamp_millis()
+    {
+        return Err(Error::generic(format!(
+            "url for file '{}' expired; query the table again",
+            action.id
+        )));
+    }
+    let url = Url::parse(&action.url)?;
+    match url.scheme() {
+        "https" => {}
+        "http" if allow_http => {}
+        "http" => {
+            return Err(Error::InvalidArgument(format!(
+                "refusing to download file '{}' over plain http; see \
+                 `SharedTableReader::with_allow_http`",
+                action.id
+            )));
+        }
+        scheme => {
+            return Err(Error::InvalidArgument(format!(
+                "unsupported data file url scheme '{scheme}'"
+            )));
+        }
+    }
+    let response = http.get(url).send().await?.error_for_status()?;
+    Ok(response.bytes().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(url: &str) -> FileAction {
+        serde_json::from_value(serde_json::json!({
+            "url": url,
+            "id": "f1",
+            "partitionValues": {},
+            "size": 10,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_only_follows_https_urls() {
+        let http = reqwest::Client::new();
+        for url in ["file:///tmp/f1.parquet", "s3://bucket/f1.parquet"] {
+            let err = fetch(&http, &action(url), true).await.unwrap_err();
+            assert!(matches!(err, Error::InvalidArgument(_)), "{url}: {err}");
+        }
+        let err = fetch(&http, &action("http://localhost/f1.parquet"), false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("plain http"), "{err}");
+    }
+}
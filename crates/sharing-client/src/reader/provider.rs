@@ -0,0 +1,113 @@
+//! A DataFusion [`TableProvider`] over a shared table.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::Session;
+use datafusion::catalog::streaming::StreamingTable;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use futures::{TryStreamExt, stream};
+
+use super::SharedTableReader;
+use crate::Result;
+use crate::models::open_sharing::v1::QueryTableRequest;
+
+/// Exposes a shared table to DataFusion.
+///
+/// Every scan issues a fresh `query_table` request, so pre-signed file URLs
+/// never outlive the query that uses them. Filters are evaluated by
+/// DataFusion; a scan limit is forwarded to the server as a hint.
+#[derive(Debug)]
+pub struct SharedTableProvider {
+    reader: SharedTableReader,
+    schema: SchemaRef,
+    version: Option<i64>,
+}
+
+impl SharedTableProvider {
+    /// A provider reading the latest version of the reader's table.
+    pub async fn try_new(reader: SharedTableReader) -> Result<Self> {
+        let schema = reader.schema().await?;
+        Ok(Self {
+            reader,
+            schema,
+            version: None,
+        })
+    }
+
+    /// Read the table as of `version` instead of the latest version.
+    pub fn with_version(mut self, version: i64) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl TableProvider for SharedTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        let partition = SharedTablePartition {
+            reader: self.reader.clone(),
+            schema: self.schema.clone(),
+            request: QueryTableRequest {
+                version: self.version,
+                limit_hint: limit.and_then(|l| i32::try_from(l).ok()),
+                ..Default::default()
+            },
+        };
+        StreamingTable::try_new(self.schema.clone(), vec![Arc::new(partition)])?
+            .scan(state, projection, filters, limit)
+            .await
+    }
+}
+
+#[derive(Debug)]
+struct SharedTablePartition {
+    reader: SharedTableReader,
+    schema: SchemaRef,
+    request: QueryTableRequest,
+}
+
+impl PartitionStream for SharedTablePartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let reader = self.reader.clone();
+        let request = self.request.clone();
+        let batches = stream::once(async move {
+            reader
+                .read(request)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))
+        })
+        .try_flatten();
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), batches))
+    }
+}
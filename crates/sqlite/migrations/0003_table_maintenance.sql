@@ -0,0 +1,44 @@
+-- Backfill leases, commit reports and maintenance jobs (SQLite).
+--
+-- SQLite translation of the Postgres `delta_commit_leases`,
+-- `delta_commit_reports` and `table_maintenance_jobs` tables. Timestamps are
+-- INTEGER epoch millis, `table_id` is a BLOB of the UUID's 16 raw bytes, and
+-- reports are stored as JSON text.
+
+-- Per-table leases held by the backfill publisher and the maintenance
+-- scheduler, so only one runner works on a table at a time.
+CREATE TABLE delta_commit_leases (
+    table_id BLOB PRIMARY KEY NOT NULL,
+    owner TEXT NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+
+CREATE TABLE delta_commit_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    table_id BLOB NOT NULL,
+    -- The `DeltaCommitReport` as sent by the client.
+    report TEXT NOT NULL,
+    reported_at INTEGER NOT NULL
+);
+
+CREATE INDEX delta_commit_reports_table_index ON delta_commit_reports (table_id, reported_at);
+
+CREATE TABLE table_maintenance_jobs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    table_id BLOB NOT NULL,
+    -- CHECKPOINT, COMPACTION, LOG_COMPACTION or VACUUM.
+    kind TEXT NOT NULL,
+    -- PENDING, RUNNING, SUCCEEDED, FAILED or SKIPPED.
+    status TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    message TEXT,
+    committed_version INTEGER,
+    num_files_added INTEGER,
+    num_files_removed INTEGER,
+    num_bytes_removed INTEGER,
+    created_at INTEGER NOT NULL,
+    started_at INTEGER,
+    finished_at INTEGER
+);
+
+CREATE INDEX table_maintenance_jobs_table_index ON table_maintenance_jobs (table_id, id);
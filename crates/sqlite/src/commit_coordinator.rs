@@ -6,16 +6,25 @@
 //! `delta_commits` table. The unique constraint on `(table_id, commit_version)`
 //! is the first-writer-wins arbiter: each `commit` runs in a transaction, and a
 //! racing insert for the same version fails with a unique violation that maps to
-//! [`CommitError::VersionConflict`].
+//! [`CommitError::VersionConflict`]. A batch commit runs every table's state
+//! machine inside one such transaction, so it is recorded whole or not at all.
 //!
 //! Timestamps are stored as INTEGER epoch-millis — the same representation used
 //! on the wire — so no timezone conversion is needed.
+//!
+//! Backfill leases live in `delta_commit_leases`, one row per table. A lease is
+//! taken or renewed under `BEGIN IMMEDIATE`, so two runners sharing the
+//! database file never both hold it.
+
+use std::time::Duration;
 
+use sqlx::Row;
 use unitycatalog_common::models::delta_commits::v1::CommitInfo;
 use unitycatalog_common::services::commit_coordinator::{
-    CommitCoordinator, CommitError, CommitResult, DEFAULT_MAX_UNBACKFILLED_COMMITS,
-    validate_commit_info,
+    CommitCoordinator, CommitError, CommitResult, DEFAULT_MAX_UNBACKFILLED_COMMITS, TableCommit,
+    validate_batch, validate_commit_info,
 };
+use unitycatalog_common::services::maintenance::now_millis;
 use uuid::Uuid;
 
 use crate::SqliteStore;
@@ -215,6 +224,46 @@ impl SqliteStore {
     }
 }
 
+impl SqliteStore {
+    /// Take or renew the lease on `table_id` for `owner`, inside a transaction.
+    ///
+    /// Returns `false` without writing if another owner's lease has not expired.
+    async fn lease_txn(
+        conn: &mut sqlx::SqliteConnection,
+        table_id: &[u8],
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        let now = now_millis();
+        let holder =
+            sqlx::query("SELECT owner, expires_at FROM delta_commit_leases WHERE table_id = ?")
+                .bind(table_id)
+                .fetch_optional(&mut *conn)
+                .await
+                .map_err(map_sqlx_err)?;
+        if let Some(row) = holder {
+            let holder: String = row.try_get("owner").map_err(map_sqlx_err)?;
+            let expires_at: i64 = row.try_get("expires_at").map_err(map_sqlx_err)?;
+            if holder != owner && expires_at > now {
+                return Ok(false);
+            }
+        }
+        let expires_at = now.saturating_add(ttl.as_millis().min(i64::MAX as u128) as i64);
+        sqlx::query(
+            "INSERT INTO delta_commit_leases (table_id, owner, expires_at) VALUES (?, ?, ?) \
+             ON CONFLICT (table_id) DO UPDATE \
+               SET owner = excluded.owner, expires_at = excluded.expires_at",
+        )
+        .bind(table_id)
+        .bind(owner)
+        .bind(expires_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(true)
+    }
+}
+
 #[async_trait::async_trait]
 impl CommitCoordinator for SqliteStore {
     async fn commit(
@@ -266,6 +315,49 @@ impl CommitCoordinator for SqliteStore {
         result
     }
 
+    async fn commit_batch(&self, mut commits: Vec<TableCommit>) -> CommitResult<()> {
+        validate_batch(&mut commits)?;
+        let table_ids = commits
+            .iter()
+            .map(|c| parse_table_id(&c.table_id).map_err(|e| e.for_table(&c.table_id)))
+            .collect::<CommitResult<Vec<_>>>()?;
+
+        // One `BEGIN IMMEDIATE` transaction for the whole batch; see `commit`.
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx_err)?;
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let mut result = Ok(());
+        for (commit, table_id) in commits.into_iter().zip(&table_ids) {
+            result = Self::commit_txn(
+                &mut conn,
+                table_id,
+                Some(commit.commit_info),
+                commit.latest_backfilled_version,
+            )
+            .await
+            .map_err(|e| e.for_table(&commit.table_id));
+            if result.is_err() {
+                break;
+            }
+        }
+
+        match &result {
+            Ok(()) => {
+                sqlx::query("COMMIT")
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(map_sqlx_err)?;
+            }
+            Err(_) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            }
+        }
+        result
+    }
+
     async fn get_commits(
         &self,
         table_id: &str,
@@ -341,4 +433,47 @@ impl CommitCoordinator for SqliteStore {
 
         Ok((commits, latest_table_version))
     }
+
+    async fn acquire_backfill_lease(
+        &self,
+        table_id: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> CommitResult<bool> {
+        let table_id = parse_table_id(table_id)?;
+
+        // Read the current holder and write the new one under the write lock,
+        // so a racing runner waits and then sees our lease; see `commit`.
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx_err)?;
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let result = Self::lease_txn(&mut conn, &table_id, owner, ttl).await;
+
+        match &result {
+            Ok(_) => {
+                sqlx::query("COMMIT")
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(map_sqlx_err)?;
+            }
+            Err(_) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            }
+        }
+        result
+    }
+
+    async fn release_backfill_lease(&self, table_id: &str, owner: &str) -> CommitResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        sqlx::query("DELETE FROM delta_commit_leases WHERE table_id = ? AND owner = ?")
+            .bind(table_id)
+            .bind(owner)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_err)?;
+        Ok(())
+    }
 }
@@ -8,8 +8,9 @@
 //! [`olai_store::AssociationStore`] traits (over the project's `ObjectLabel`),
 //! which the blanket `ObjectStoreAdapter` in `unitycatalog-common` lifts to the
 //! high-level `ResourceStore` API, plus the `SecretManager` trait for sealed
-//! secrets at rest and the `CommitCoordinator` trait for durable Delta
-//! catalog-managed commits.
+//! secrets at rest, the `CommitCoordinator` trait for durable Delta
+//! catalog-managed commits and backfill leases, and the `MaintenanceStore`
+//! trait for commit reports and maintenance jobs.
 //!
 //! ## Known gaps relative to the Postgres backend
 //!
@@ -24,6 +25,7 @@ pub use crate::store::SqliteStore;
 mod commit_coordinator;
 mod constants;
 mod error;
+mod maintenance;
 mod pagination;
 mod secrets;
 mod store;
@@ -0,0 +1,198 @@
+//! SQLite-backed [`MaintenanceStore`].
+//!
+//! Mirrors the Postgres store: commit reports are kept verbatim as JSON text in
+//! `delta_commit_reports`; jobs live in `table_maintenance_jobs`, keyed by an
+//! `AUTOINCREMENT` id so job ids increase in scheduling order. Job kinds and
+//! statuses are stored as their wire (SCREAMING_SNAKE_CASE) names, and
+//! timestamps as INTEGER epoch millis.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use unitycatalog_common::models::delta::v1::{
+    DeltaCommitReport, DeltaMaintenanceJob, DeltaMaintenanceJobKind,
+};
+use unitycatalog_common::services::maintenance::{
+    MaintenanceError, MaintenanceResult, MaintenanceStore, StoredCommitReport, now_millis,
+};
+use uuid::Uuid;
+
+use crate::SqliteStore;
+
+/// The job columns read by [`job_from_row`].
+const JOB_COLUMNS: &str = "id, table_id, kind, status, reason, message, committed_version, \
+                           num_files_added, num_files_removed, num_bytes_removed, \
+                           created_at, started_at, finished_at";
+
+fn backend<E: std::fmt::Display>(e: E) -> MaintenanceError {
+    MaintenanceError::Backend(e.to_string())
+}
+
+fn parse_table_id(table_id: &str) -> MaintenanceResult<Vec<u8>> {
+    Uuid::parse_str(table_id)
+        .map(|id| id.as_bytes().to_vec())
+        .map_err(|_| backend("table_id is not a valid UUID"))
+}
+
+/// The wire name of a unit enum variant, e.g. `COMPACTION`.
+fn enum_to_text<T: Serialize>(value: T) -> MaintenanceResult<String> {
+    match serde_json::to_value(value).map_err(backend)? {
+        serde_json::Value::String(s) => Ok(s),
+        other => Err(backend(format!("unexpected enum encoding: {other}"))),
+    }
+}
+
+fn enum_from_text<T: DeserializeOwned>(text: String) -> MaintenanceResult<T> {
+    serde_json::from_value(serde_json::Value::String(text)).map_err(backend)
+}
+
+fn job_from_row(row: SqliteRow) -> MaintenanceResult<DeltaMaintenanceJob> {
+    let table_id: Vec<u8> = row.try_get("table_id").map_err(backend)?;
+    let table_id = Uuid::from_slice(&table_id).map_err(backend)?;
+    Ok(DeltaMaintenanceJob {
+        job_id: row.try_get("id").map_err(backend)?,
+        table_id: table_id.hyphenated().to_string(),
+        kind: enum_from_text(row.try_get("kind").map_err(backend)?)?,
+        status: enum_from_text(row.try_get("status").map_err(backend)?)?,
+        reason: row.try_get("reason").map_err(backend)?,
+        message: row.try_get("message").map_err(backend)?,
+        committed_version: row.try_get("committed_version").map_err(backend)?,
+        num_files_added: row.try_get("num_files_added").map_err(backend)?,
+        num_files_removed: row.try_get("num_files_removed").map_err(backend)?,
+        num_bytes_removed: row.try_get("num_bytes_removed").map_err(backend)?,
+        created_at: row.try_get("created_at").map_err(backend)?,
+        started_at: row.try_get("started_at").map_err(backend)?,
+        finished_at: row.try_get("finished_at").map_err(backend)?,
+    })
+}
+
+#[async_trait::async_trait]
+impl MaintenanceStore for SqliteStore {
+    async fn record_report(
+        &self,
+        table_id: &str,
+        report: DeltaCommitReport,
+    ) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        let report = serde_json::to_string(&report).map_err(backend)?;
+        sqlx::query(
+            "INSERT INTO delta_commit_reports (table_id, report, reported_at) VALUES (?, ?, ?)",
+        )
+        .bind(table_id)
+        .bind(report)
+        .bind(now_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(backend)?;
+        Ok(())
+    }
+
+    async fn list_reports(
+        &self,
+        table_id: &str,
+        since: Option<i64>,
+    ) -> MaintenanceResult<Vec<StoredCommitReport>> {
+        let table_uuid = parse_table_id(table_id)?;
+        let rows = sqlx::query(
+            "SELECT report, reported_at FROM delta_commit_reports \
+             WHERE table_id = ? AND (? IS NULL OR reported_at > ?) \
+             ORDER BY reported_at ASC, id ASC",
+        )
+        .bind(table_uuid)
+        .bind(since)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend)?;
+        rows.into_iter()
+            .map(|row| {
+                let report: String = row.try_get("report").map_err(backend)?;
+                Ok(StoredCommitReport {
+                    table_id: table_id.to_string(),
+                    reported_at: row.try_get("reported_at").map_err(backend)?,
+                    report: serde_json::from_str(&report).map_err(backend)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn prune_reports(&self, table_id: &str, before: i64) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(table_id)?;
+        sqlx::query("DELETE FROM delta_commit_reports WHERE table_id = ? AND reported_at < ?")
+            .bind(table_id)
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(backend)?;
+        Ok(())
+    }
+
+    async fn create_job(
+        &self,
+        table_id: &str,
+        kind: DeltaMaintenanceJobKind,
+        reason: String,
+    ) -> MaintenanceResult<DeltaMaintenanceJob> {
+        let table_id = parse_table_id(table_id)?;
+        let row = sqlx::query(&format!(
+            "INSERT INTO table_maintenance_jobs (table_id, kind, status, reason, created_at) \
+             VALUES (?, ?, 'PENDING', ?, ?) RETURNING {JOB_COLUMNS}"
+        ))
+        .bind(table_id)
+        .bind(enum_to_text(kind)?)
+        .bind(reason)
+        .bind(now_millis())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(backend)?;
+        job_from_row(row)
+    }
+
+    async fn update_job(&self, job: &DeltaMaintenanceJob) -> MaintenanceResult<()> {
+        let table_id = parse_table_id(&job.table_id)?;
+        let result = sqlx::query(
+            "UPDATE table_maintenance_jobs \
+             SET status = ?, message = ?, committed_version = ?, num_files_added = ?, \
+                 num_files_removed = ?, num_bytes_removed = ?, started_at = ?, finished_at = ? \
+             WHERE id = ? AND table_id = ?",
+        )
+        .bind(enum_to_text(job.status)?)
+        .bind(&job.message)
+        .bind(job.committed_version)
+        .bind(job.num_files_added)
+        .bind(job.num_files_removed)
+        .bind(job.num_bytes_removed)
+        .bind(job.started_at)
+        .bind(job.finished_at)
+        .bind(job.job_id)
+        .bind(table_id)
+        .execute(&self.pool)
+        .await
+        .map_err(backend)?;
+        if result.rows_affected() == 0 {
+            return Err(MaintenanceError::JobNotFound(job.job_id));
+        }
+        Ok(())
+    }
+
+    async fn list_jobs(
+        &self,
+        table_id: &str,
+        limit: Option<usize>,
+    ) -> MaintenanceResult<Vec<DeltaMaintenanceJob>> {
+        let table_id = parse_table_id(table_id)?;
+        // SQLite treats a negative LIMIT as "no limit".
+        let limit = limit.map_or(-1, |l| l.min(i64::MAX as usize) as i64);
+        let rows = sqlx::query(&format!(
+            "SELECT {JOB_COLUMNS} FROM table_maintenance_jobs WHERE table_id = ? \
+             ORDER BY id DESC LIMIT ?"
+        ))
+        .bind(table_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend)?;
+        rows.into_iter().map(job_from_row).collect()
+    }
+}
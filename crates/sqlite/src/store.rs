@@ -14,10 +14,11 @@
 use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
-use sqlx::SqlitePool;
 use sqlx::migrate::Migrator;
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sqlx::{SqliteConnection, SqlitePool};
 use unitycatalog_common::services::encryption::EnvelopeEncryptor;
+use unitycatalog_common::store::RenameObject;
 use unitycatalog_common::{AssociationLabel, Object, ObjectLabel};
 use uuid::Uuid;
 
@@ -265,6 +266,16 @@ impl SqliteStore {
         &self,
         id: &Uuid,
         properties: Option<serde_json::Value>,
+    ) -> Result<Object> {
+        let mut conn = self.pool.acquire().await?;
+        Self::update_properties(&mut conn, id, properties).await
+    }
+
+    /// Replace an object's properties on `conn`.
+    async fn update_properties(
+        conn: &mut SqliteConnection,
+        id: &Uuid,
+        properties: Option<serde_json::Value>,
     ) -> Result<Object> {
         let id_bytes = id.as_bytes().to_vec();
         let properties_str = properties.as_ref().map(json_to_string).transpose()?;
@@ -288,11 +299,61 @@ impl SqliteStore {
             updated_at,
             id_bytes,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(conn)
         .await?;
         row.into_object()
     }
 
+    /// Move an object to a new name, keeping its id and associations, and
+    /// replace the properties of the `dependents` in the same transaction.
+    ///
+    /// The `(label, name)` uniqueness constraint rejects a rename onto a taken
+    /// name, which surfaces as [`Error::AlreadyExists`].
+    pub async fn rename_object(
+        &self,
+        id: &Uuid,
+        name: &ResourceName,
+        properties: Option<serde_json::Value>,
+        dependents: &[(Uuid, Option<serde_json::Value>)],
+    ) -> Result<Object> {
+        let id_bytes = id.as_bytes().to_vec();
+        let name_str = name.to_string();
+        let namespace_str = namespace_of(name);
+        let properties_str = properties.as_ref().map(json_to_string).transpose()?;
+        let updated_at = Utc::now().timestamp_micros();
+        let mut txn = self.pool.begin().await?;
+        let row = sqlx::query_as!(
+            ObjectRow,
+            r#"
+            UPDATE objects
+            SET name = ?,
+                namespace = ?,
+                properties = COALESCE(?, properties),
+                updated_at = ?
+            WHERE id = ?
+            RETURNING
+                id AS "id!",
+                label AS "label!",
+                name AS "name!",
+                properties,
+                created_at AS "created_at!",
+                updated_at
+            "#,
+            name_str,
+            namespace_str,
+            properties_str,
+            updated_at,
+            id_bytes,
+        )
+        .fetch_one(&mut *txn)
+        .await?;
+        for (id, properties) in dependents {
+            Self::update_properties(&mut txn, id, properties.clone()).await?;
+        }
+        txn.commit().await?;
+        row.into_object()
+    }
+
     pub async fn delete_object(&self, id: &Uuid) -> Result<()> {
         let id_bytes = id.as_bytes().to_vec();
         let mut txn = self.pool.begin().await?;
@@ -619,6 +680,19 @@ impl olai_store::ObjectStore<ObjectLabel> for SqliteStore {
     }
 }
 
+#[async_trait::async_trait]
+impl RenameObject for SqliteStore {
+    async fn rename(
+        &self,
+        id: &Uuid,
+        name: &ResourceName,
+        properties: Option<serde_json::Value>,
+        dependents: &[(Uuid, Option<serde_json::Value>)],
+    ) -> olai_store::Result<Object> {
+        Ok(self.rename_object(id, name, properties, dependents).await?)
+    }
+}
+
 // --- AssociationStore<ObjectLabel> -----------------------------------------
 
 #[async_trait::async_trait]
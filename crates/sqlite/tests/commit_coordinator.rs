@@ -7,7 +7,9 @@ use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use unitycatalog_common::models::delta_commits::v1::CommitInfo;
-use unitycatalog_common::services::commit_coordinator::{CommitCoordinator, CommitError};
+use unitycatalog_common::services::commit_coordinator::{
+    CommitCoordinator, CommitError, TableCommit,
+};
 use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
 use unitycatalog_sqlite::SqliteStore;
 use uuid::Uuid;
@@ -200,6 +202,36 @@ async fn commits_persist_across_reopen() {
     );
 }
 
+fn table_commit(table_id: &str, version: i64) -> TableCommit {
+    TableCommit {
+        table_id: table_id.to_string(),
+        commit_info: commit_info(version),
+        latest_backfilled_version: None,
+    }
+}
+
+#[tokio::test]
+async fn batch_commit_is_all_or_nothing() {
+    let temp = TempDb::new("batch");
+    let cc = store(&temp).await;
+    let (fact, dim) = (table_id(), table_id());
+    cc.commit(&fact, Some(commit_info(1)), None).await.unwrap();
+
+    cc.commit_batch(vec![table_commit(&fact, 2), table_commit(&dim, 1)])
+        .await
+        .unwrap();
+    assert_eq!(cc.get_commits(&fact, 0, None).await.unwrap().1, 2);
+    assert_eq!(cc.get_commits(&dim, 0, None).await.unwrap().1, 1);
+
+    // `dim` v1 is taken, so `fact` v3 must be rolled back with it.
+    let err = cc
+        .commit_batch(vec![table_commit(&fact, 3), table_commit(&dim, 1)])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CommitError::VersionConflict(ref m) if m.contains(&dim)));
+    assert_eq!(cc.get_commits(&fact, 0, None).await.unwrap().1, 2);
+}
+
 #[tokio::test]
 async fn first_writer_wins_under_concurrency() {
     let temp = TempDb::new("concurrency");
@@ -228,3 +260,40 @@ async fn first_writer_wins_under_concurrency() {
     assert_eq!(wins, 1, "exactly one writer wins version 2");
     assert_eq!(conflicts, 7);
 }
+
+#[tokio::test]
+async fn backfill_lease_is_exclusive() {
+    let temp = TempDb::new("lease");
+    let cc = store(&temp).await;
+    let t = table_id();
+    let ttl = std::time::Duration::from_secs(60);
+    assert!(cc.acquire_backfill_lease(&t, "a", ttl).await.unwrap());
+    assert!(cc.acquire_backfill_lease(&t, "a", ttl).await.unwrap());
+    assert!(!cc.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+
+    cc.release_backfill_lease(&t, "b").await.unwrap();
+    assert!(!cc.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+    cc.release_backfill_lease(&t, "a").await.unwrap();
+    assert!(cc.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+
+    // An expired lease can be taken over.
+    let u = table_id();
+    assert!(
+        cc.acquire_backfill_lease(&u, "a", std::time::Duration::ZERO)
+            .await
+            .unwrap()
+    );
+    assert!(cc.acquire_backfill_lease(&u, "b", ttl).await.unwrap());
+}
+
+#[tokio::test]
+async fn backfill_lease_is_held_across_connections() {
+    // Two stores over the same file stand in for two server processes.
+    let temp = TempDb::new("lease-shared");
+    let first = store(&temp).await;
+    let second = store(&temp).await;
+    let t = table_id();
+    let ttl = std::time::Duration::from_secs(60);
+    assert!(first.acquire_backfill_lease(&t, "a", ttl).await.unwrap());
+    assert!(!second.acquire_backfill_lease(&t, "b", ttl).await.unwrap());
+}
@@ -0,0 +1,121 @@
+//! Integration tests for the SQLite-backed maintenance store.
+//!
+//! Ported from the Postgres backend's `maintenance` tests; each test runs
+//! against an isolated temp-file SQLite database that is cleaned up on drop.
+
+use std::path::PathBuf;
+
+use unitycatalog_common::models::delta::v1::{
+    DeltaCommitReport, DeltaMaintenanceJobKind, DeltaMaintenanceJobStatus,
+};
+use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
+use unitycatalog_common::services::maintenance::{MaintenanceError, MaintenanceStore};
+use unitycatalog_sqlite::SqliteStore;
+
+/// A temp-file SQLite path that cleans up its files on drop.
+struct TempDb {
+    path: PathBuf,
+}
+
+impl TempDb {
+    fn new(tag: &str) -> Self {
+        let mut path = std::env::temp_dir();
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        path.push(format!(
+            "uc-sqlite-maint-{tag}-{}-{nanos}.db",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        TempDb { path }
+    }
+
+    fn path(&self) -> String {
+        self.path.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", self.path.display()));
+        }
+    }
+}
+
+async fn store(temp: &TempDb) -> SqliteStore {
+    let encryptor =
+        EnvelopeEncryptor::local(LocalKeyProvider::single("test", vec![0x42; 32]).unwrap());
+    let store = SqliteStore::connect(temp.path(), encryptor)
+        .await
+        .expect("connect");
+    store.migrate().await.expect("migrate");
+    store
+}
+
+const TABLE: &str = "00000000-0000-0000-0000-000000000001";
+
+#[tokio::test]
+async fn reports_roundtrip() {
+    let temp = TempDb::new("reports");
+    let ms = store(&temp).await;
+    let report = DeltaCommitReport {
+        num_files_added: Some(3),
+        num_bytes_added: Some(300),
+        ..Default::default()
+    };
+    ms.record_report(TABLE, report.clone()).await.unwrap();
+    ms.record_report(TABLE, DeltaCommitReport::default())
+        .await
+        .unwrap();
+
+    let reports = ms.list_reports(TABLE, None).await.unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].report, report);
+    assert_eq!(reports[0].table_id, TABLE);
+
+    ms.prune_reports(TABLE, reports[1].reported_at + 1)
+        .await
+        .unwrap();
+    assert!(ms.list_reports(TABLE, None).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn jobs_roundtrip_newest_first_and_persist() {
+    let temp = TempDb::new("jobs");
+    let ms = store(&temp).await;
+    let first = ms
+        .create_job(TABLE, DeltaMaintenanceJobKind::Checkpoint, "a".into())
+        .await
+        .unwrap();
+    let mut second = ms
+        .create_job(TABLE, DeltaMaintenanceJobKind::Vacuum, "b".into())
+        .await
+        .unwrap();
+    assert_eq!(first.status, DeltaMaintenanceJobStatus::Pending);
+    assert_eq!(first.table_id, TABLE);
+
+    second.status = DeltaMaintenanceJobStatus::Succeeded;
+    second.num_files_removed = Some(4);
+    second.started_at = Some(second.created_at);
+    second.finished_at = Some(second.created_at + 10);
+    ms.update_job(&second).await.unwrap();
+    drop(ms);
+
+    // The job history survives reopening the database.
+    let ms = store(&temp).await;
+    let jobs = ms.list_jobs(TABLE, None).await.unwrap();
+    assert_eq!(jobs, vec![second.clone(), first]);
+    assert_eq!(
+        ms.list_jobs(TABLE, Some(1)).await.unwrap(),
+        vec![second.clone()]
+    );
+
+    second.job_id += 100;
+    assert!(matches!(
+        ms.update_job(&second).await,
+        Err(MaintenanceError::JobNotFound(_))
+    ));
+}
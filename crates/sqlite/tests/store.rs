@@ -13,6 +13,7 @@ use olai_store::{AssociationStore, AssociationStoreReader, ObjectStore, ObjectSt
 use unitycatalog_common::models::ObjectLabel;
 use unitycatalog_common::services::encryption::{EnvelopeEncryptor, LocalKeyProvider};
 use unitycatalog_common::services::secrets::SecretManager;
+use unitycatalog_common::store::RenameObject;
 use unitycatalog_sqlite::SqliteStore;
 
 /// A temp-file SQLite path that cleans up its files on drop.
@@ -129,6 +130,91 @@ async fn update_and_delete() {
     assert!(matches!(err, olai_store::Error::NotFound));
 }
 
+#[tokio::test]
+async fn rename_moves_name_and_namespace() {
+    let temp = TempDb::new("rename");
+    let s = store(&temp).await;
+
+    let table = ObjectStore::create(
+        &s,
+        ObjectLabel::Table,
+        &name(&["main", "default", "a"]),
+        Some(serde_json::json!({"k": "v"})),
+        None,
+    )
+    .await
+    .unwrap();
+    ObjectStore::create(
+        &s,
+        ObjectLabel::Table,
+        &name(&["main", "default", "b"]),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let view = ObjectStore::create(
+        &s,
+        ObjectLabel::Table,
+        &name(&["main", "default", "v"]),
+        Some(serde_json::json!({"reads": "a"})),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Renaming onto a taken name fails and leaves the object and its
+    // dependents in place.
+    let dependents = [(view.id, Some(serde_json::json!({"reads": "c"})))];
+    let err = RenameObject::rename(
+        &s,
+        &table.id,
+        &name(&["main", "default", "b"]),
+        None,
+        &dependents,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, olai_store::Error::AlreadyExists));
+    let unchanged = ObjectStoreReader::get(&s, &view.id).await.unwrap();
+    assert_eq!(
+        unchanged.properties,
+        Some(serde_json::json!({"reads": "a"}))
+    );
+
+    let renamed = RenameObject::rename(
+        &s,
+        &table.id,
+        &name(&["main", "default", "c"]),
+        None,
+        &dependents,
+    )
+    .await
+    .unwrap();
+    assert_eq!(renamed.id, table.id);
+    assert_eq!(renamed.name, name(&["main", "default", "c"]));
+    assert_eq!(renamed.properties, Some(serde_json::json!({"k": "v"})));
+
+    let err =
+        ObjectStoreReader::get_by_name(&s, ObjectLabel::Table, &name(&["main", "default", "a"]))
+            .await
+            .unwrap_err();
+    assert!(matches!(err, olai_store::Error::NotFound));
+    let (listed, _) = ObjectStoreReader::list(
+        &s,
+        ObjectLabel::Table,
+        Some(&name(&["main", "default"])),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(listed.len(), 3);
+    let updated = ObjectStoreReader::get(&s, &view.id).await.unwrap();
+    assert_eq!(updated.properties, Some(serde_json::json!({"reads": "c"})));
+}
+
 #[tokio::test]
 async fn list_by_namespace_with_pagination() {
     let temp = TempDb::new("list");
@@ -12,7 +12,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file unitycatalog/shares/v1/models.proto.
  */
 export const file_unitycatalog_shares_v1_models: GenFile = /*@__PURE__*/
-  fileDesc("CiN1bml0eWNhdGFsb2cvc2hhcmVzL3YxL21vZGVscy5wcm90bxIWdW5pdHljYXRhbG9nLnNoYXJlcy52MSL0AwoKRGF0YU9iamVjdBIRCgRuYW1lGAEgASgJQgPgQQISRQoQZGF0YV9vYmplY3RfdHlwZRgCIAEoDjImLnVuaXR5Y2F0YWxvZy5zaGFyZXMudjEuRGF0YU9iamVjdFR5cGVCA+BBAhIaCghhZGRlZF9hdBgDIAEoA0ID4EEDSACIAQESGgoIYWRkZWRfYnkYBCABKAlCA+BBA0gBiAEBEhkKB2NvbW1lbnQYBSABKAlCA+BBAUgCiAEBEhsKCXNoYXJlZF9hcxgGIAEoCUID4EEBSAOIAQESEgoKcGFydGl0aW9ucxgHIAMoCRIcCgplbmFibGVfY2RmGAggASgIQgPgQQFIBIgBARJUChtoaXN0b3J5X2RhdGFfc2hhcmluZ19zdGF0dXMYCSABKA4yJS51bml0eWNhdGFsb2cuc2hhcmVzLnYxLkhpc3RvcnlTdGF0dXNCA+BBAUgFiAEBEh8KDXN0YXJ0X3ZlcnNpb24YCiABKANCA+BBAUgGiAEBQgsKCV9hZGRlZF9hdEILCglfYWRkZWRfYnlCCgoIX2NvbW1lbnRCDAoKX3NoYXJlZF9hc0INCgtfZW5hYmxlX2NkZkIeChxfaGlzdG9yeV9kYXRhX3NoYXJpbmdfc3RhdHVzQhAKDl9zdGFydF92ZXJzaW9uIpcECgVTaGFyZRIXCgJpZBhkIAEoCUIG4EED4EEISACIAQESEQoEbmFtZRgBIAEoCUID4EECEjgKB29iamVjdHMYBiADKAsyIi51bml0eWNhdGFsb2cuc2hhcmVzLnYxLkRhdGFPYmplY3RCA+BBARIXCgVvd25lchgCIAEoCUID4EEBSAGIAQESGQoHY29tbWVudBgDIAEoCUID4EEBSAKIAQESIgoQc3RvcmFnZV9sb2NhdGlvbhgEIAEoCUID4EEDSAOIAQESHgoMc3RvcmFnZV9yb290GAUgASgJQgPgQQNIBIgBARIcCgpjcmVhdGVkX2F0GAcgASgDQgPgQQNIBYgBARIcCgpjcmVhdGVkX2J5GAggASgJQgPgQQNIBogBARIcCgp1cGRhdGVkX2F0GAkgASgDQgPgQQNIB4gBARIcCgp1cGRhdGVkX2J5GAogASgJQgPgQQNICIgBATo56kE2ChV1bml0eWNhdGFsb2cuaW8vU2hhcmUSDnNoYXJlcy97c2hhcmV9KgZzaGFyZXMyBXNoYXJlQgUKA19pZEIICgZfb3duZXJCCgoIX2NvbW1lbnRCEwoRX3N0b3JhZ2VfbG9jYXRpb25CDwoNX3N0b3JhZ2Vfcm9vdEINCgtfY3JlYXRlZF9hdEINCgtfY3JlYXRlZF9ieUINCgtfdXBkYXRlZF9hdEINCgtfdXBkYXRlZF9ieSI8ChNQcml2aWxlZ2VBc3NpZ25tZW50EhEKCXByaW5jaXBhbBgBIAEoCRISCgpwcml2aWxlZ2VzGAIgAygJIkMKEVBlcm1pc3Npb25zQ2hhbmdlEhEKCXByaW5jaXBhbBgBIAEoCRILCgNhZGQYAiADKAkSDgoGcmVtb3ZlGAMgAygJKmYKDkRhdGFPYmplY3RUeXBlEiAKHERBVEFfT0JKRUNUX1RZUEVfVU5TUEVDSUZJRUQQABIJCgVUQUJMRRABEgoKBlNDSEVNQRACEgoKBlZPTFVNRRAKEg8KC0FHRU5UX1NLSUxMEAsqKgoNSGlzdG9yeVN0YXR1cxIMCghESVNBQkxFRBAAEgsKB0VOQUJMRUQQAULzAQoaY29tLnVuaXR5Y2F0YWxvZy5zaGFyZXMudjFCC01vZGVsc1Byb3RvUAFaTmdpdGh1Yi5jb20vZGVsdGEtaW5jdWJhdG9yL2RlbHRhLXNoYXJpbmctcnMvZ28vdW5pdHljYXRhbG9nL3NoYXJlcy92MTtzaGFyZXN2MaICA1VTWKoCFlVuaXR5Y2F0YWxvZy5TaGFyZXMuVjHKAhZVbml0eWNhdGFsb2dcU2hhcmVzXFYx4gIiVW5pdHljYXRhbG9nXFNoYXJlc1xWMVxHUEJNZXRhZGF0YeoCGFVuaXR5Y2F0YWxvZzo6U2hhcmVzOjpWMWIGcHJvdG8z", [file_google_api_field_behavior, file_google_api_resource]);
+  fileDesc("CiN1bml0eWNhdGFsb2cvc2hhcmVzL3YxL21vZGVscy5wcm90bxIWdW5pdHljYXRhbG9nLnNoYXJlcy52MSL0AwoKRGF0YU9iamVjdBIRCgRuYW1lGAEgASgJQgPgQQISRQoQZGF0YV9vYmplY3RfdHlwZRgCIAEoDjImLnVuaXR5Y2F0YWxvZy5zaGFyZXMudjEuRGF0YU9iamVjdFR5cGVCA+BBAhIaCghhZGRlZF9hdBgDIAEoA0ID4EEDSACIAQESGgoIYWRkZWRfYnkYBCABKAlCA+BBA0gBiAEBEhkKB2NvbW1lbnQYBSABKAlCA+BBAUgCiAEBEhsKCXNoYXJlZF9hcxgGIAEoCUID4EEBSAOIAQESEgoKcGFydGl0aW9ucxgHIAMoCRIcCgplbmFibGVfY2RmGAggASgIQgPgQQFIBIgBARJUChtoaXN0b3J5X2RhdGFfc2hhcmluZ19zdGF0dXMYCSABKA4yJS51bml0eWNhdGFsb2cuc2hhcmVzLnYxLkhpc3RvcnlTdGF0dXNCA+BBAUgFiAEBEh8KDXN0YXJ0X3ZlcnNpb24YCiABKANCA+BBAUgGiAEBQgsKCV9hZGRlZF9hdEILCglfYWRkZWRfYnlCCgoIX2NvbW1lbnRCDAoKX3NoYXJlZF9hc0INCgtfZW5hYmxlX2NkZkIeChxfaGlzdG9yeV9kYXRhX3NoYXJpbmdfc3RhdHVzQhAKDl9zdGFydF92ZXJzaW9uIpcECgVTaGFyZRIXCgJpZBhkIAEoCUIG4EED4EEISACIAQESEQoEbmFtZRgBIAEoCUID4EECEjgKB29iamVjdHMYBiADKAsyIi51bml0eWNhdGFsb2cuc2hhcmVzLnYxLkRhdGFPYmplY3RCA+BBARIXCgVvd25lchgCIAEoCUID4EEBSAGIAQESGQoHY29tbWVudBgDIAEoCUID4EEBSAKIAQESIgoQc3RvcmFnZV9sb2NhdGlvbhgEIAEoCUID4EEDSAOIAQESHgoMc3RvcmFnZV9yb290GAUgASgJQgPgQQNIBIgBARIcCgpjcmVhdGVkX2F0GAcgASgDQgPgQQNIBYgBARIcCgpjcmVhdGVkX2J5GAggASgJQgPgQQNIBogBARIcCgp1cGRhdGVkX2F0GAkgASgDQgPgQQNIB4gBARIcCgp1cGRhdGVkX2J5GAogASgJQgPgQQNICIgBATo56kE2ChV1bml0eWNhdGFsb2cuaW8vU2hhcmUSDnNoYXJlcy97c2hhcmV9KgZzaGFyZXMyBXNoYXJlQgUKA19pZEIICgZfb3duZXJCCgoIX2NvbW1lbnRCEwoRX3N0b3JhZ2VfbG9jYXRpb25CDwoNX3N0b3JhZ2Vfcm9vdEINCgtfY3JlYXRlZF9hdEINCgtfY3JlYXRlZF9ieUINCgtfdXBkYXRlZF9hdEINCgtfdXBkYXRlZF9ieSI8ChNQcml2aWxlZ2VBc3NpZ25tZW50EhEKCXByaW5jaXBhbBgBIAEoCRISCgpwcml2aWxlZ2VzGAIgAygJIkMKEVBlcm1pc3Npb25zQ2hhbmdlEhEKCXByaW5jaXBhbBgBIAEoCRILCgNhZGQYAiADKAkSDgoGcmVtb3ZlGAMgAygJKnAKDkRhdGFPYmplY3RUeXBlEiAKHERBVEFfT0JKRUNUX1RZUEVfVU5TUEVDSUZJRUQQABIJCgVUQUJMRRABEgoKBlNDSEVNQRACEggKBFZJRVcQAxIKCgZWT0xVTUUQChIPCgtBR0VOVF9TS0lMTBALKioKDUhpc3RvcnlTdGF0dXMSDAoIRElTQUJMRUQQABILCgdFTkFCTEVEEAFC8wEKGmNvbS51bml0eWNhdGFsb2cuc2hhcmVzLnYxQgtNb2RlbHNQcm90b1ABWk5naXRodWIuY29tL2RlbHRhLWluY3ViYXRvci9kZWx0YS1zaGFyaW5nLXJzL2dvL3VuaXR5Y2F0YWxvZy9zaGFyZXMvdjE7c2hhcmVzdjGiAgNVU1iqAhZVbml0eWNhdGFsb2cuU2hhcmVzLlYxygIWVW5pdHljYXRhbG9nXFNoYXJlc1xWMeICIlVuaXR5Y2F0YWxvZ1xTaGFyZXNcVjFcR1BCTWV0YWRhdGHqAhhVbml0eWNhdGFsb2c6OlNoYXJlczo6VjFiBnByb3RvMw==", [file_google_api_field_behavior, file_google_api_resource]);
 
 /**
  * @generated from message unitycatalog.shares.v1.DataObject
@@ -279,6 +279,13 @@ export enum DataObjectType {
    */
   SCHEMA = 2,
 
+  /**
+   * A SQL view, served to recipients from a materialized snapshot.
+   *
+   * @generated from enum value: VIEW = 3;
+   */
+  VIEW = 3,
+
   /**
    * A Unity Catalog volume shared as a storage-backed asset (Open Sharing).
    *
@@ -1228,6 +1228,11 @@ class Table:
     Tables and functions the view-like table reads. For metric views this is derived from the
     view_definition by the server (the definition is the single source of truth).
     """
+    statistics: Optional[TableStatistics]
+    """
+    Statistics of the latest table version. Only set for Delta tables, and only when the
+    request asks for delta metadata.
+    """
 
     def __init__(
         self,
@@ -1251,6 +1256,7 @@ class Table:
         updated_by: Optional[str] = None,
         view_definition: Optional[str] = None,
         view_dependencies: Optional[DependencyList] = None,
+        statistics: Optional[TableStatistics] = None,
     ) -> None: ...
 
 class TableDependency:
@@ -1261,6 +1267,29 @@ class TableDependency:
 
     def __init__(self, table_full_name: str) -> None: ...
 
+class TableStatistics:
+    """Size and shape of a Delta table at one version, derived from its snapshot."""
+
+    last_modified: int
+    """Time of the commit that produced the version, in epoch milliseconds."""
+    num_files: int
+    """Number of live data files."""
+    num_rows: Optional[int]
+    """Number of rows. Omitted when some data file carries no row count."""
+    size_in_bytes: int
+    """Total size of the table's live data files, in bytes."""
+    version: int
+    """Table version the statistics describe."""
+
+    def __init__(
+        self,
+        last_modified: int,
+        num_files: int,
+        size_in_bytes: int,
+        version: int,
+        num_rows: Optional[int] = None,
+    ) -> None: ...
+
 class TableSummary:
     full_name: str
     """The full name of the table."""
@@ -1474,6 +1503,8 @@ class DataObjectType(enum.Enum):
     """Unknown data object type."""
     SCHEMA = "SCHEMA"
     TABLE = "TABLE"
+    VIEW = "VIEW"
+    """A SQL view, served to recipients from a materialized snapshot."""
     VOLUME = "VOLUME"
     """A Unity Catalog volume shared as a storage-backed asset (Open Sharing)."""
 
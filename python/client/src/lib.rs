@@ -15,7 +15,7 @@ use unitycatalog_common::models::shares::v1::{
     Action, DataObject, DataObjectType, DataObjectUpdate, HistoryStatus, Share,
 };
 use unitycatalog_common::models::tables::v1::{
-    Column, ColumnTypeName, DataSourceFormat, Table, TableType,
+    Column, ColumnTypeName, DataSourceFormat, Table, TableStatistics, TableType,
 };
 use unitycatalog_common::models::tags::v1::{TagPolicy, Value};
 use unitycatalog_common::models::temporary_credentials::v1::TemporaryCredential;
@@ -55,6 +55,7 @@ fn _client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<HistoryStatus>()?;
     m.add_class::<Action>()?;
     m.add_class::<Table>()?;
+    m.add_class::<TableStatistics>()?;
     m.add_class::<TableType>()?;
     m.add_class::<Column>()?;
     m.add_class::<ColumnTypeName>()?;